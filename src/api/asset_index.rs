@@ -0,0 +1,201 @@
+use std::{
+    collections::BTreeSet,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    api::{
+        EnumerateResources, LoadResource, ResourceCategory, ResourceIdentifier, ResourceKind,
+        Result,
+    },
+    schemas::AssetIndex,
+};
+
+/// A [`ResourceProvider`] that provides resources from the launcher's hashed
+/// `assets/objects/` store, as described by an [`AssetIndex`].
+///
+/// Sounds and most languages are not in the client jar; the launcher
+/// downloads them into `assets/objects/` instead, under file names that are
+/// their SHA-1 hashes. The asset index maps virtual paths such as
+/// `minecraft/sounds/ambient/cave/cave1.ogg` to those files, which this
+/// provider uses to make them reachable as regular resources.
+///
+/// Only resources in the `assets/` category can be provided. Stack this
+/// provider on top of the client jar with a [`LayeredResourceProvider`] to
+/// get every vanilla asset.
+///
+/// [`ResourceProvider`]: crate::api::ResourceProvider
+/// [`LayeredResourceProvider`]: crate::api::LayeredResourceProvider
+///
+/// # Example
+///
+/// ```no_run
+/// # use minecraft_assets::api::*;
+/// let provider = AssetIndexProvider::open("~/.minecraft/assets/", "1.18").unwrap();
+/// let assets = AssetPack::new(provider);
+///
+/// let sounds = assets.load_sounds("minecraft").unwrap();
+/// let german = assets.load_language("de_de").unwrap();
+/// ```
+pub struct AssetIndexProvider {
+    index: AssetIndex,
+    objects_dir: PathBuf,
+}
+
+impl AssetIndexProvider {
+    /// Returns a new provider that provides resources from the asset index
+    /// with the given id (e.g., `"1.18"`) in the given launcher `assets/`
+    /// directory.
+    ///
+    /// The index is read from `<assets_dir>/indexes/<index_id>.json` and the
+    /// objects from `<assets_dir>/objects/`.
+    pub fn open(assets_dir: impl AsRef<Path>, index_id: &str) -> Result<Self> {
+        let assets_dir = assets_dir.as_ref();
+
+        let index_path = assets_dir
+            .join("indexes")
+            .join(format!("{}.json", index_id));
+        let index = serde_json::from_slice(&fs::read(index_path)?)?;
+
+        Ok(Self::new(index, assets_dir.join("objects")))
+    }
+
+    /// Returns a new provider that provides resources from the given asset
+    /// index, whose objects are stored in the given `objects/` directory.
+    pub fn new(index: AssetIndex, objects_dir: impl AsRef<Path>) -> Self {
+        Self {
+            index,
+            objects_dir: PathBuf::from(objects_dir.as_ref()),
+        }
+    }
+
+    /// Returns the asset index of this provider.
+    pub fn index(&self) -> &AssetIndex {
+        &self.index
+    }
+
+    /// Returns the virtual path of the directory (with a trailing `/`) that
+    /// contains resources of the given kind.
+    fn kind_path(namespace: &str, kind: ResourceKind) -> String {
+        let mut path = format!("{}/", namespace);
+        if !kind.directory().is_empty() {
+            path.push_str(kind.directory());
+            path.push('/');
+        }
+        path
+    }
+
+    /// Returns the virtual path of the given resource, or `None` if it is not
+    /// an asset.
+    fn virtual_path(id: &ResourceIdentifier) -> Option<String> {
+        if id.kind().category() != ResourceCategory::Assets {
+            return None;
+        }
+
+        let mut path = Self::kind_path(id.namespace(), id.kind());
+        path.push_str(id.path());
+        path.push('.');
+        path.push_str(id.kind().extension());
+        Some(path)
+    }
+
+    fn object_file(&self, virtual_path: &str) -> Result<PathBuf, io::Error> {
+        let object = self
+            .index
+            .objects
+            .get(virtual_path)
+            .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))?;
+
+        Ok(self.objects_dir.join(object.object_path()))
+    }
+
+    fn read_object(&self, virtual_path: &str) -> Result<Vec<u8>, io::Error> {
+        fs::read(self.object_file(virtual_path)?)
+    }
+}
+
+impl EnumerateResources for AssetIndexProvider {
+    fn enumerate_resources(
+        &self,
+        namespace: &str,
+        kind: ResourceKind,
+    ) -> Result<Vec<ResourceIdentifier<'static>>, io::Error> {
+        if kind.category() != ResourceCategory::Assets {
+            return Ok(vec![]);
+        }
+
+        if let Some(name) = kind.single_file() {
+            let id = ResourceIdentifier::new_owned(kind, format!("{}:{}", namespace, name));
+            let exists =
+                Self::virtual_path(&id).is_some_and(|path| self.index.objects.contains_key(&path));
+
+            return Ok(if exists { vec![id] } else { vec![] });
+        }
+
+        let directory = Self::kind_path(namespace, kind);
+        let suffix = format!(".{}", kind.extension());
+
+        let mut ids: Vec<_> = self
+            .index
+            .objects
+            .keys()
+            .filter_map(|name| {
+                let path = name.strip_prefix(&directory)?.strip_suffix(&suffix)?;
+
+                // Skip over files starting with '_', like the file system
+                // provider does.
+                let file_name = path.rsplit('/').next().unwrap_or(path);
+                if file_name.is_empty() || file_name.starts_with('_') {
+                    return None;
+                }
+
+                Some(ResourceIdentifier::in_namespace(kind, namespace, path))
+            })
+            .collect();
+
+        // The index is a hash map, so sort for a stable order.
+        ids.sort_by(|a, b| a.path().cmp(b.path()));
+
+        Ok(ids)
+    }
+
+    fn enumerate_namespaces(&self, category: ResourceCategory) -> Result<Vec<String>, io::Error> {
+        if category != ResourceCategory::Assets {
+            return Ok(vec![]);
+        }
+
+        // Root files like `icons/icon_16x16.png` have a directory, too, but
+        // only namespaces have resources in subdirectories or a `sounds.json`.
+        let namespaces: BTreeSet<&str> = self
+            .index
+            .objects
+            .keys()
+            .filter_map(|name| {
+                let (namespace, rest) = name.split_once('/')?;
+                (rest.contains('/') || rest == "sounds.json").then_some(namespace)
+            })
+            .collect();
+
+        Ok(namespaces.into_iter().map(String::from).collect())
+    }
+}
+
+impl LoadResource for AssetIndexProvider {
+    fn load_resource(&self, id: &ResourceIdentifier) -> Result<Vec<u8>, io::Error> {
+        let path =
+            Self::virtual_path(id).ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))?;
+        self.read_object(&path)
+    }
+
+    fn load_root_file(&self, name: &str) -> Result<Vec<u8>, io::Error> {
+        self.read_object(name)
+    }
+
+    fn open_resource(&self, id: &ResourceIdentifier) -> Result<Box<dyn Read + '_>, io::Error> {
+        let path =
+            Self::virtual_path(id).ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))?;
+        Ok(Box::new(fs::File::open(self.object_file(&path)?)?))
+    }
+}
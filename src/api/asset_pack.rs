@@ -1,17 +1,36 @@
-use std::{ops::Deref, path::Path};
+use std::{
+    io::{self, Read},
+    ops::Deref,
+    path::Path,
+};
 
 use serde::de::DeserializeOwned;
 
 use crate::{
     api::{
-        FileSystemResourceProvider, ModelIdentifier, ResourceIdentifier, ResourceProvider, Result,
+        parse_error::{parse_resource, parse_root_file},
+        validate::{Diagnostic, Validator},
+        Error, FileSystemResourceProvider, ModelResolver, RawResource, ResourceCategory,
+        ResourceIdentifier, ResourceKind, ResourceProvider, Result, ShaderImportResolver,
+        TargetVersion,
+    },
+    schemas::{
+        glyphs::GlyphSizes,
+        optifine::{CitProperties, CtmProperties},
+        shader::{PostChain, PostEffect, ShaderProgram},
+        text::{Credits, EndPoem, Splashes},
+        texture::Texture,
+        Advancement, Atlas, BannerPattern, BlockStates, ChatType, DamageType, Enchantment,
+        Equipment, Font, Function, GpuWarnlist, ItemDefinition, ItemModifier, JukeboxSong,
+        LanguageFile, LootTable, Model, PackMeta, PaintingVariant, Particle, Predicate, Recipe,
+        RegionalCompliancies, SoundsJson, Tag, TrimMaterial, TrimPattern, WolfVariant,
     },
-    schemas::{BlockStates, Model},
 };
 
 /// Top-level API for accessing Minecraft assets.
 pub struct AssetPack {
     provider: Box<dyn ResourceProvider>,
+    version: Option<TargetVersion>,
 }
 
 impl AssetPack {
@@ -38,9 +57,46 @@ impl AssetPack {
     /// ```
     pub fn at_path(root_dir: impl AsRef<Path>) -> Self {
         let provider = FileSystemResourceProvider::new(root_dir);
-        Self {
-            provider: Box::new(provider),
-        }
+        Self::new(provider)
+    }
+
+    /// Returns a new [`AssetPack`] that can read data from the given directory,
+    /// which uses the layout of the given [`TargetVersion`].
+    ///
+    /// See [`with_target_version()`][Self::with_target_version].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// let assets = AssetPack::at_path_for_version("~/my_data_pack/", TargetVersion::V1_21);
+    ///
+    /// // Loaded from `data/minecraft/loot_table/blocks/stone.json`.
+    /// let loot_table = assets.load_loot_table("blocks/stone").unwrap();
+    /// ```
+    pub fn at_path_for_version(root_dir: impl AsRef<Path>, version: TargetVersion) -> Self {
+        let provider = FileSystemResourceProvider::new(root_dir).with_target_version(version);
+        Self::new(provider).with_target_version(version)
+    }
+
+    /// Returns a new [`AssetPack`] that can read data from the given `.zip`
+    /// resource pack or client `.jar` file.
+    ///
+    /// This method requires the `zip` feature.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use minecraft_assets::api::AssetPack;
+    ///
+    /// let assets = AssetPack::at_zip("~/.minecraft/versions/1.18.2/1.18.2.jar").unwrap();
+    ///
+    /// let states = assets.load_blockstates("oak_planks").unwrap();
+    /// ```
+    #[cfg(feature = "zip")]
+    pub fn at_zip(path: impl AsRef<Path>) -> Result<Self> {
+        let provider = crate::api::ZipResourceProvider::open(path)?;
+        Ok(Self::new(provider))
     }
 
     /// Returns a new [`AssetPack`] that uses the given [`ResourceProvider`].
@@ -50,7 +106,225 @@ impl AssetPack {
     {
         Self {
             provider: Box::new(provider),
+            version: None,
+        }
+    }
+
+    /// Sets the [`TargetVersion`] of the pack and returns `self`.
+    ///
+    /// Loading a kind of resource that does not exist in the target version
+    /// fails with [`Error::UnsupportedKind`]. The provider determines where
+    /// resources are stored, so it should be given the same version, e.g.,
+    /// through [`FileSystemResourceProvider::with_target_version()`].
+    ///
+    /// [`Error::UnsupportedKind`]: crate::api::Error::UnsupportedKind
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let version = TargetVersion::V1_21_4;
+    /// let mut provider = MemoryResourceProvider::new().with_target_version(version);
+    /// provider.insert("data/minecraft/tags/block/logs.json", br#"{ "values": [] }"#.to_vec());
+    ///
+    /// let assets = AssetPack::new(provider).with_target_version(version);
+    /// assert!(assets.load_tag(ResourceKind::BlockTag, "logs").is_ok());
+    ///
+    /// let error = assets.load_post_chain("spider").unwrap_err();
+    /// assert!(matches!(error, Error::UnsupportedKind { .. }));
+    /// ```
+    pub fn with_target_version(mut self, version: TargetVersion) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Returns the [`TargetVersion`] of the pack, if any.
+    pub fn target_version(&self) -> Option<TargetVersion> {
+        self.version
+    }
+
+    /// Enumerates every resource of the given [`ResourceKind`] in the given
+    /// namespace, including those in subdirectories (e.g., `block/` models or
+    /// `chests/` loot tables), sorted by path.
+    ///
+    /// Unlike [`EnumerateResources::enumerate_resources()`], a namespace that
+    /// has no resources of the given kind yields an empty list instead of an
+    /// error. Use [`EnumerateResources::list()`] to enumerate the resources of
+    /// every namespace.
+    ///
+    /// [`EnumerateResources::enumerate_resources()`]: crate::api::EnumerateResources::enumerate_resources
+    /// [`EnumerateResources::list()`]: crate::api::EnumerateResources::list
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// let assets = AssetPack::at_path("~/.minecraft/");
+    ///
+    /// for id in assets.enumerate(ResourceKind::LootTable, "minecraft").unwrap() {
+    ///     // e.g., "chests/simple_dungeon"
+    ///     println!("{}", id.path());
+    /// }
+    /// ```
+    pub fn enumerate(
+        &self,
+        kind: ResourceKind,
+        namespace: &str,
+    ) -> Result<Vec<ResourceIdentifier<'static>>> {
+        let mut ids = match self.provider.enumerate_resources(namespace, kind) {
+            Ok(ids) => ids,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => return Err(e.into()),
+        };
+
+        ids.sort_by(|a, b| a.path().cmp(b.path()));
+        Ok(ids)
+    }
+
+    /// Returns the namespaces that are present in the given
+    /// [`ResourceCategory`] (i.e., the directories under `assets/` or
+    /// `data/`), in sorted order.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// let assets = AssetPack::at_path("~/.minecraft/resourcepacks/my_mod_pack/");
+    ///
+    /// for namespace in assets.namespaces(ResourceCategory::Assets).unwrap() {
+    ///     let models = assets.enumerate(ResourceKind::BlockModel, &namespace).unwrap();
+    ///     println!("{}: {} block models", namespace, models.len());
+    /// }
+    /// ```
+    pub fn namespaces(&self, category: ResourceCategory) -> Result<Vec<String>> {
+        Ok(self.provider.enumerate_namespaces(category)?)
+    }
+
+    /// Returns the namespaces that are present in either `assets/` or
+    /// `data/`, in sorted order.
+    ///
+    /// See [`namespaces()`][Self::namespaces].
+    pub fn all_namespaces(&self) -> Result<Vec<String>> {
+        let mut namespaces = Vec::new();
+        for category in ResourceCategory::ALL {
+            namespaces.extend(self.namespaces(category)?);
         }
+
+        namespaces.sort();
+        namespaces.dedup();
+        Ok(namespaces)
+    }
+
+    /// Checks the pack for references to resources that do not exist, like
+    /// models with missing textures.
+    ///
+    /// See the [`validate`][crate::api::validate] module for what is checked.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// let assets = AssetPack::at_path("~/.minecraft/resourcepacks/my_pack/");
+    ///
+    /// for diagnostic in assets.validate().unwrap() {
+    ///     println!("{}", diagnostic);
+    /// }
+    /// ```
+    pub fn validate(&self) -> Result<Vec<Diagnostic>> {
+        Validator::new(&**self).validate()
+    }
+
+    /// Loads the [`PackMeta`] from the `pack.mcmeta` file at the root of the
+    /// pack.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// let assets = AssetPack::at_path("~/.minecraft/resourcepacks/my_pack/");
+    ///
+    /// let meta = assets.load_pack_meta().unwrap();
+    /// println!("pack format: {}", meta.pack.pack_format);
+    /// ```
+    pub fn load_pack_meta(&self) -> Result<PackMeta> {
+        let bytes = self.provider.load_root_file("pack.mcmeta")?;
+        Ok(parse_root_file("pack.mcmeta", &bytes)?)
+    }
+
+    /// Loads the raw bytes of the resource referenced by the given
+    /// [`ResourceIdentifier`], without parsing them.
+    ///
+    /// This works for any [`ResourceKind`], including those without a
+    /// dedicated method.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// let assets = AssetPack::at_path("~/.minecraft/");
+    ///
+    /// let png = assets.load_bytes(&ResourceIdentifier::texture("block/stone")).unwrap();
+    /// assert!(png.starts_with(b"\x89PNG"));
+    /// ```
+    pub fn load_bytes(&self, resource: &ResourceIdentifier) -> Result<Vec<u8>> {
+        self.load_raw(resource)
+    }
+
+    /// Opens a reader over the raw bytes of the resource referenced by the
+    /// given [`ResourceIdentifier`], so that large resources like sounds or
+    /// structures can be streamed without reading them into memory first.
+    ///
+    /// Whether the resource is actually streamed depends on the provider, see
+    /// [`LoadResource::open_resource()`].
+    ///
+    /// [`LoadResource::open_resource()`]: crate::api::LoadResource::open_resource
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// use std::io::Read;
+    ///
+    /// let mut provider = MemoryResourceProvider::new();
+    /// provider.insert("assets/minecraft/sounds/ambient/cave/cave1.ogg", b"OggS...".to_vec());
+    /// let assets = AssetPack::new(provider);
+    ///
+    /// let mut reader = assets.open_reader(&ResourceIdentifier::sound("ambient/cave/cave1")).unwrap();
+    /// let mut magic = [0; 4];
+    /// reader.read_exact(&mut magic).unwrap();
+    /// assert_eq!(&magic, b"OggS");
+    /// ```
+    pub fn open_reader(&self, resource: &ResourceIdentifier) -> Result<impl Read + '_> {
+        self.check_supported(resource.kind())?;
+        Ok(self.provider.open_resource(resource)?)
+    }
+
+    /// Loads the JSON resource referenced by the given [`ResourceIdentifier`]
+    /// without parsing it, so that it can be parsed later or only some of its
+    /// fields can be read.
+    ///
+    /// See [`RawResource`].
+    pub fn load_raw_resource(&self, resource: &ResourceIdentifier) -> Result<RawResource> {
+        let bytes = self.load_raw(resource)?;
+        RawResource::new(resource.to_owned(), bytes)
+    }
+
+    /// Loads every JSON resource of the given [`ResourceKind`] in every
+    /// namespace without parsing them, sorted by namespace and path.
+    ///
+    /// See [`RawResource`].
+    pub fn load_raw_resources(&self, kind: ResourceKind) -> Result<Vec<RawResource>> {
+        self.check_supported(kind)?;
+
+        let mut ids = self.provider.list(kind)?;
+        ids.sort_by(|a, b| (a.namespace(), a.path()).cmp(&(b.namespace(), b.path())));
+
+        ids.into_iter()
+            .map(|id| {
+                let bytes = self.load_raw(&id)?;
+                RawResource::new(id, bytes)
+            })
+            .collect()
     }
 
     /// Loads the [`BlockStates`] of the block with the provided id.
@@ -106,6 +380,25 @@ impl AssetPack {
         self.load_model_recursive(&ResourceIdentifier::block_model(model))
     }
 
+    /// Loads the block [`Model`] identified by the given name or path and all
+    /// of its parents, and returns the fully resolved model.
+    ///
+    /// See [`ModelResolver::resolve_model()`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let model = assets.load_block_model_resolved("block/stone").unwrap();
+    ///
+    /// assert_eq!(model.parent, None);
+    /// assert!(model.elements.is_some());
+    /// ```
+    pub fn load_block_model_resolved(&self, model: &str) -> Result<Model> {
+        self.load_model_resolved(&ResourceIdentifier::block_model(model))
+    }
+
     /// Loads the item [`Model`] identified by the given name or path.
     ///
     /// # Example
@@ -145,59 +438,1019 @@ impl AssetPack {
         self.load_model_recursive(&ResourceIdentifier::item_model(model))
     }
 
-    fn load_resource<T>(&self, resource: &ResourceIdentifier) -> Result<T>
-    where
-        T: DeserializeOwned,
-    {
-        let bytes = self.provider.load_resource(resource)?;
-        Ok(serde_json::from_reader(&bytes[..])?)
+    /// Loads the item [`Model`] identified by the given name or path and all
+    /// of its parents, and returns the fully resolved model.
+    ///
+    /// See [`ModelResolver::resolve_model()`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let model = assets.load_item_model_resolved("item/diamond_sword").unwrap();
+    ///
+    /// let textures = model.textures.unwrap();
+    /// assert_eq!(textures["layer0"].location().unwrap(), "minecraft:item/diamond_sword");
+    /// ```
+    pub fn load_item_model_resolved(&self, model: &str) -> Result<Model> {
+        self.load_model_resolved(&ResourceIdentifier::item_model(model))
     }
 
-    fn load_model_recursive(&self, resource: &ResourceIdentifier) -> Result<Vec<Model>> {
-        let mut models = Vec::new();
+    /// Loads the item [`Model`] identified by the given name or path and all
+    /// of its parents, and returns the fully resolved model, with its elements
+    /// generated from the textures of its layers if it inherits
+    /// `builtin/generated`.
+    ///
+    /// This method requires the `image` feature.
+    ///
+    /// Like in the game, missing textures are generated as fully opaque 16x16
+    /// sprites. See [`generate_item_model()`] for how the elements are
+    /// generated.
+    ///
+    /// [`generate_item_model()`]: crate::api::item_generator::generate_item_model
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let model = assets.load_item_model_generated("item/stick").unwrap();
+    /// let mesh = mesh::ModelMesh::from_model(&model);
+    /// ```
+    #[cfg(feature = "image")]
+    pub fn load_item_model_generated(&self, model: &str) -> Result<Model> {
+        use crate::api::item_generator::{generate_item_model, is_generated, SpriteAlpha};
 
-        Self::for_each_parent(
-            resource.clone(),
-            |model| models.push(model),
-            |next_id| self.load_resource(next_id),
-        )?;
+        let models = self.load_model_recursive(&ResourceIdentifier::item_model(model))?;
+        let resolved = ModelResolver::resolve_model(models.iter());
+        if !is_generated(&models) {
+            return Ok(resolved);
+        }
 
-        Ok(models)
+        generate_item_model(&resolved, |texture| {
+            let image = match self.load_texture_image(texture) {
+                Ok(image) => image,
+                Err(Error::IoError(e)) if super::provider::is_missing(&e) => {
+                    return Ok(SpriteAlpha::opaque(16, 16))
+                }
+                Err(e) => return Err(e),
+            };
+            let animation = match self.load_texture_meta(texture) {
+                Ok(meta) => meta.animation,
+                Err(Error::IoError(e)) if super::provider::is_missing(&e) => None,
+                Err(e) => return Err(e),
+            };
+            Ok(SpriteAlpha::from_image(&image, animation.as_ref()))
+        })
     }
 
-    pub(crate) fn for_each_parent<F, L, E>(
-        mut current: ResourceIdentifier,
-        mut op: F,
-        mut load_model: L,
-    ) -> Result<(), E>
-    where
-        F: FnMut(Model),
-        L: FnMut(&ResourceIdentifier) -> Result<Model, E>,
-    {
-        loop {
-            let model = load_model(&current)?;
+    /// Loads and decodes the texture located at the given path, relative to
+    /// `textures/` and without the `.png` extension.
+    ///
+    /// This method requires the `image` feature.
+    ///
+    /// See [`TextureDecoder`][crate::api::TextureDecoder] for how the texture
+    /// is converted to RGBA.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let image = assets.load_texture_image("block/stone").unwrap();
+    /// assert_eq!(image.dimensions(), (16, 16));
+    /// ```
+    #[cfg(feature = "image")]
+    pub fn load_texture_image(&self, path: &str) -> Result<image::RgbaImage> {
+        let bytes = self.load_raw(&ResourceIdentifier::texture(path))?;
+        crate::api::TextureDecoder::decode(&bytes)
+    }
 
-            let parent_owned = model.parent.clone();
+    /// Loads the [`Texture`] metadata (`.png.mcmeta`) of the texture located
+    /// at the given path.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let meta = assets.load_texture_meta("block/water_still");
+    /// let meta = assets.load_texture_meta("gui/sprites/widget/button");
+    /// ```
+    pub fn load_texture_meta(&self, path: &str) -> Result<Texture> {
+        self.load_resource(&ResourceIdentifier::texture_meta(path))
+    }
 
-            op(model);
+    /// Loads the [`LootTable`] located at the given path.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let table = assets.load_loot_table("blocks/stone");
+    /// let table = assets.load_loot_table("minecraft:entities/zombie");
+    /// ```
+    pub fn load_loot_table(&self, path: &str) -> Result<LootTable> {
+        self.load_resource(&ResourceIdentifier::loot_table(path))
+    }
 
-            match parent_owned {
-                Some(parent) if !ModelIdentifier::is_builtin(&parent) => {
-                    //println!("{}", parent.as_str());
-                    current = ResourceIdentifier::new_owned(current.kind(), parent);
-                }
-                _ => break,
-            }
-        }
+    /// Loads the [`Recipe`] located at the given path.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let value = assets.load_recipe("stick");
+    /// let value = assets.load_recipe("minecraft:iron_ingot_from_blasting_iron_ore");
+    /// ```
+    pub fn load_recipe(&self, path: &str) -> Result<Recipe> {
+        self.load_resource(&ResourceIdentifier::recipe(path))
+    }
 
-        Ok(())
+    /// Loads the [`Advancement`] located at the given path.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let value = assets.load_advancement("story/root");
+    /// let value = assets.load_advancement("minecraft:nether/find_fortress");
+    /// ```
+    pub fn load_advancement(&self, path: &str) -> Result<Advancement> {
+        self.load_resource(&ResourceIdentifier::advancement(path))
     }
-}
 
-impl Deref for AssetPack {
-    type Target = dyn ResourceProvider;
+    /// Loads the [`Tag`] of the given kind with the given id.
+    ///
+    /// A leading `#` in the id is ignored.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let tag = assets.load_tag(ResourceKind::BlockTag, "logs");
+    /// let tag = assets.load_tag(ResourceKind::ItemTag, "#minecraft:planks");
+    /// ```
+    pub fn load_tag(&self, kind: ResourceKind, tag_id: &str) -> Result<Tag> {
+        self.load_resource(&ResourceIdentifier::tag(kind, tag_id))
+    }
 
-    fn deref(&self) -> &Self::Target {
-        &*self.provider
+    /// Loads the [`Predicate`] located at the given path.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let value = assets.load_predicate("is_sneaking");
+    /// let value = assets.load_predicate("mypack:holding_stick");
+    /// ```
+    pub fn load_predicate(&self, path: &str) -> Result<Predicate> {
+        self.load_resource(&ResourceIdentifier::predicate(path))
+    }
+
+    /// Loads the [`ItemModifier`] located at the given path.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let value = assets.load_item_modifier("double_count");
+    /// let value = assets.load_item_modifier("mypack:enchant_sword");
+    /// ```
+    pub fn load_item_modifier(&self, path: &str) -> Result<ItemModifier> {
+        self.load_resource(&ResourceIdentifier::item_modifier(path))
+    }
+
+    /// Loads the [`DamageType`] located at the given path.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let value = assets.load_damage_type("in_fire");
+    /// let value = assets.load_damage_type("mypack:radiation");
+    /// ```
+    pub fn load_damage_type(&self, path: &str) -> Result<DamageType> {
+        self.load_resource(&ResourceIdentifier::damage_type(path))
+    }
+
+    /// Loads the [`ChatType`] located at the given path.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let value = assets.load_chat_type("chat");
+    /// let value = assets.load_chat_type("mypack:shout");
+    /// ```
+    pub fn load_chat_type(&self, path: &str) -> Result<ChatType> {
+        self.load_resource(&ResourceIdentifier::chat_type(path))
+    }
+
+    /// Loads the [`TrimMaterial`] located at the given path.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let value = assets.load_trim_material("gold");
+    /// let value = assets.load_trim_material("mypack:ruby");
+    /// ```
+    pub fn load_trim_material(&self, path: &str) -> Result<TrimMaterial> {
+        self.load_resource(&ResourceIdentifier::trim_material(path))
+    }
+
+    /// Loads the [`TrimPattern`] located at the given path.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let value = assets.load_trim_pattern("coast");
+    /// let value = assets.load_trim_pattern("mypack:stripes");
+    /// ```
+    pub fn load_trim_pattern(&self, path: &str) -> Result<TrimPattern> {
+        self.load_resource(&ResourceIdentifier::trim_pattern(path))
+    }
+
+    /// Loads the [`Enchantment`] located at the given path.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let value = assets.load_enchantment("sharpness");
+    /// let value = assets.load_enchantment("mypack:lifesteal");
+    /// ```
+    pub fn load_enchantment(&self, path: &str) -> Result<Enchantment> {
+        self.load_resource(&ResourceIdentifier::enchantment(path))
+    }
+
+    /// Loads the [`PaintingVariant`] located at the given path.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let value = assets.load_painting_variant("kebab");
+    /// let value = assets.load_painting_variant("mypack:sunset");
+    /// ```
+    pub fn load_painting_variant(&self, path: &str) -> Result<PaintingVariant> {
+        self.load_resource(&ResourceIdentifier::painting_variant(path))
+    }
+
+    /// Loads the [`WolfVariant`] located at the given path.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let value = assets.load_wolf_variant("ashen");
+    /// let value = assets.load_wolf_variant("mypack:arctic");
+    /// ```
+    pub fn load_wolf_variant(&self, path: &str) -> Result<WolfVariant> {
+        self.load_resource(&ResourceIdentifier::wolf_variant(path))
+    }
+
+    /// Loads the [`BannerPattern`] located at the given path.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let value = assets.load_banner_pattern("creeper");
+    /// let value = assets.load_banner_pattern("mypack:dragon");
+    /// ```
+    pub fn load_banner_pattern(&self, path: &str) -> Result<BannerPattern> {
+        self.load_resource(&ResourceIdentifier::banner_pattern(path))
+    }
+
+    /// Loads the [`JukeboxSong`] located at the given path.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let value = assets.load_jukebox_song("cat");
+    /// let value = assets.load_jukebox_song("mypack:my_song");
+    /// ```
+    pub fn load_jukebox_song(&self, path: &str) -> Result<JukeboxSong> {
+        self.load_resource(&ResourceIdentifier::jukebox_song(path))
+    }
+
+    /// Loads the [`StructureTemplate`] located at the given path.
+    ///
+    /// Requires the `nbt` feature.
+    ///
+    /// [`StructureTemplate`]: crate::schemas::StructureTemplate
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let value = assets.load_structure("igloo/top");
+    /// let value = assets.load_structure("mypack:house");
+    /// ```
+    #[cfg(feature = "nbt")]
+    pub fn load_structure(&self, path: &str) -> Result<crate::schemas::StructureTemplate> {
+        let bytes = self
+            .provider
+            .load_resource(&ResourceIdentifier::structure(path))?;
+        crate::schemas::StructureTemplate::from_bytes(&bytes)
+    }
+
+    /// Loads the [`Function`] located at the given path.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let value = assets.load_function("tick");
+    /// let value = assets.load_function("mypack:setup/init");
+    /// ```
+    pub fn load_function(&self, path: &str) -> Result<Function> {
+        let source = self.load_text(&ResourceIdentifier::function(path))?;
+        Ok(Function::parse(&source))
+    }
+
+    /// Loads the title screen [`Splashes`] from
+    /// `assets/minecraft/texts/splashes.txt`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let splashes = assets.load_splashes().unwrap();
+    /// println!("{}", splashes.splashes[0]);
+    /// ```
+    pub fn load_splashes(&self) -> Result<Splashes> {
+        let source = self.load_text(&ResourceIdentifier::text("splashes"))?;
+        Ok(Splashes::parse(&source))
+    }
+
+    /// Loads the [`EndPoem`] from `assets/minecraft/texts/end.txt`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let poem = assets.load_end_poem().unwrap();
+    /// let lines = poem.with_player_name("Steve");
+    /// ```
+    pub fn load_end_poem(&self) -> Result<EndPoem> {
+        let source = self.load_text(&ResourceIdentifier::text("end"))?;
+        Ok(EndPoem::parse(&source))
+    }
+
+    /// Loads the [`Credits`] from `assets/minecraft/texts/credits.json`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let credits = assets.load_credits().unwrap();
+    /// println!("{}", credits.sections[0].section);
+    /// ```
+    pub fn load_credits(&self) -> Result<Credits> {
+        self.load_resource(&ResourceIdentifier::text_json("credits"))
+    }
+
+    /// Loads the [`SoundsJson`] of the given namespace.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let sounds = assets.load_sounds("minecraft");
+    /// let sounds = assets.load_sounds("mypack");
+    /// ```
+    pub fn load_sounds(&self, namespace: &str) -> Result<SoundsJson> {
+        self.load_resource(&ResourceIdentifier::sounds(namespace))
+    }
+
+    /// Opens a reader over the sound file (`.ogg`) located at the given path,
+    /// relative to `sounds/` and without the extension, like the names of
+    /// the sounds in `sounds.json`.
+    ///
+    /// See [`open_reader()`][Self::open_reader].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let reader = assets.open_sound("ambient/cave/cave1").unwrap();
+    /// ```
+    pub fn open_sound(&self, path: &str) -> Result<impl Read + '_> {
+        self.open_reader(&ResourceIdentifier::sound(path))
+    }
+
+    /// Reads the [`SoundInfo`][crate::api::SoundInfo] of the sound file
+    /// located at the given path, e.g., to check that it is a valid Ogg
+    /// Vorbis file, without reading it into memory.
+    ///
+    /// This method requires the `ogg` feature.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let info = assets.load_sound_info("ambient/cave/cave1").unwrap();
+    /// println!("{:?}", info.duration());
+    /// ```
+    #[cfg(feature = "ogg")]
+    pub fn load_sound_info(&self, path: &str) -> Result<crate::api::SoundInfo> {
+        crate::api::SoundInfo::read(self.open_sound(path)?)
+    }
+
+    /// Loads the [`GpuWarnlist`] from `assets/minecraft/gpu_warnlist.json`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let warnlist = assets.load_gpu_warnlist();
+    /// ```
+    pub fn load_gpu_warnlist(&self) -> Result<GpuWarnlist> {
+        self.load_resource(&ResourceIdentifier::gpu_warnlist())
+    }
+
+    /// Loads the [`RegionalCompliancies`] from
+    /// `assets/minecraft/regional_compliancies.json`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let compliancies = assets.load_regional_compliancies();
+    /// ```
+    pub fn load_regional_compliancies(&self) -> Result<RegionalCompliancies> {
+        self.load_resource(&ResourceIdentifier::regional_compliancies())
+    }
+
+    /// Loads the [`LanguageFile`] for the given language code (e.g., `en_us`).
+    ///
+    /// See [`Translator`][crate::api::Translator] for looking up and
+    /// formatting translated strings.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let lang = assets.load_language("en_us");
+    /// let lang = assets.load_language("mypack:de_de");
+    /// ```
+    pub fn load_language(&self, code: &str) -> Result<LanguageFile> {
+        match self.version {
+            Some(version) if !version.supports(ResourceKind::Language) => {
+                self.load_legacy_language(code)
+            }
+            _ => self.load_resource(&ResourceIdentifier::language(code)),
+        }
+    }
+
+    /// Loads the [`LanguageFile`] for the given language code (e.g., `en_US`)
+    /// from a legacy `.lang` file, used before 1.13.
+    ///
+    /// [`load_language()`][Self::load_language] calls this method if the
+    /// target version of the pack is older than 1.13.
+    ///
+    /// The files are named `en_US.lang` before 1.11 and `en_us.lang` after,
+    /// so if no file has the given code, the code with an upper case region
+    /// is tried (e.g., `de_DE` for `de_de`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let mut provider = MemoryResourceProvider::new();
+    /// provider.insert("assets/minecraft/lang/en_US.lang", b"tile.stone.stone.name=Stone".to_vec());
+    /// let assets = AssetPack::new(provider).with_target_version(TargetVersion::V1_12_2);
+    ///
+    /// let lang = assets.load_language("en_US").unwrap();
+    /// assert_eq!(lang.get("tile.stone.stone.name"), Some("Stone"));
+    ///
+    /// let lang = assets.load_language("en_us").unwrap();
+    /// assert_eq!(lang.get("tile.stone.stone.name"), Some("Stone"));
+    /// ```
+    pub fn load_legacy_language(&self, code: &str) -> Result<LanguageFile> {
+        let bytes = match self.load_raw(&ResourceIdentifier::legacy_language(code)) {
+            Err(Error::IoError(e)) if super::provider::is_missing(&e) => {
+                match legacy_language_code(code) {
+                    Some(code) => self.load_raw(&ResourceIdentifier::legacy_language(&code))?,
+                    None => return Err(Error::IoError(e)),
+                }
+            }
+            result => result?,
+        };
+        Ok(LanguageFile::from_legacy(&String::from_utf8_lossy(&bytes)))
+    }
+
+    /// Loads the [`Font`] located at the given path.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let value = assets.load_font("default");
+    /// let value = assets.load_font("mypack:runes");
+    /// ```
+    pub fn load_font(&self, path: &str) -> Result<Font> {
+        self.load_resource(&ResourceIdentifier::font(path))
+    }
+
+    /// Loads the legacy unicode [`GlyphSizes`] located at the given path,
+    /// relative to `font/` and without the `.bin` extension.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let sizes = assets.load_glyph_sizes("glyph_sizes");
+    /// let sizes = assets.load_glyph_sizes("mypack:sizes");
+    /// ```
+    pub fn load_glyph_sizes(&self, path: &str) -> Result<GlyphSizes> {
+        let bytes = self
+            .provider
+            .load_resource(&ResourceIdentifier::glyph_sizes(path))?;
+        Ok(GlyphSizes::from_bytes(&bytes))
+    }
+
+    /// Loads the OptiFine connected textures rule (`.properties`) located at
+    /// the given path, relative to `optifine/ctm/`.
+    ///
+    /// A rule that matches neither blocks nor tiles matches the block or tile
+    /// named after its file (see [`CtmProperties::match_file_name()`]). The
+    /// textures of its tiles can be found with [`optifine::ctm_textures()`].
+    ///
+    /// [`optifine::ctm_textures()`]: crate::api::optifine::ctm_textures
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// use minecraft_assets::schemas::optifine::ctm::CtmMethod;
+    ///
+    /// let mut provider = MemoryResourceProvider::new();
+    /// provider.insert(
+    ///     "assets/minecraft/optifine/ctm/glass/glass.properties",
+    ///     b"method=ctm\ntiles=0-46".to_vec(),
+    /// );
+    /// let assets = AssetPack::new(provider);
+    ///
+    /// let ctm = assets.load_ctm_properties("glass/glass").unwrap();
+    /// assert_eq!(ctm.method, Some(CtmMethod::Ctm));
+    /// assert_eq!(ctm.tiles.len(), 47);
+    /// assert_eq!(ctm.match_tiles, ["glass"]);
+    /// ```
+    pub fn load_ctm_properties(&self, path: &str) -> Result<CtmProperties> {
+        let id = ResourceIdentifier::ctm_properties(path);
+        let bytes = self.load_raw(&id)?;
+        let mut ctm = CtmProperties::parse(&String::from_utf8_lossy(&bytes));
+        ctm.match_file_name(id.path().rsplit('/').next().unwrap_or_default());
+        Ok(ctm)
+    }
+
+    /// Loads the OptiFine custom item textures rule (`.properties`) located
+    /// at the given path, relative to `optifine/cit/`.
+    ///
+    /// Its replacement textures can be found with
+    /// [`optifine::cit_textures()`].
+    ///
+    /// [`optifine::cit_textures()`]: crate::api::optifine::cit_textures
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let cit = assets.load_cit_properties("swords/fire_sword");
+    /// let cit = assets.load_cit_properties("mypack:bows/longbow");
+    /// ```
+    pub fn load_cit_properties(&self, path: &str) -> Result<CitProperties> {
+        let bytes = self.load_raw(&ResourceIdentifier::cit_properties(path))?;
+        Ok(CitProperties::parse(&String::from_utf8_lossy(&bytes)))
+    }
+
+    /// Loads the [`UnihexGlyphs`] from the zip archive located at the given
+    /// path, relative to `font/` and without the `.zip` extension.
+    ///
+    /// This method requires the `zip` feature.
+    ///
+    /// [`UnihexGlyphs`]: crate::schemas::glyphs::UnihexGlyphs
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let glyphs = assets.load_unihex_glyphs("unifont");
+    /// let glyphs = assets.load_unihex_glyphs("mypack:glyphs");
+    /// ```
+    #[cfg(feature = "zip")]
+    pub fn load_unihex_glyphs(&self, path: &str) -> Result<crate::schemas::glyphs::UnihexGlyphs> {
+        let bytes = self
+            .provider
+            .load_resource(&ResourceIdentifier::unihex_archive(path))?;
+        crate::schemas::glyphs::UnihexGlyphs::from_zip(&bytes)
+    }
+
+    /// Loads the [`Particle`] definition located at the given path.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let value = assets.load_particle("flame");
+    /// let value = assets.load_particle("mypack:sparkle");
+    /// ```
+    pub fn load_particle(&self, path: &str) -> Result<Particle> {
+        self.load_resource(&ResourceIdentifier::particle(path))
+    }
+
+    /// Loads the [`Atlas`] configuration located at the given path.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let value = assets.load_atlas("blocks");
+    /// let value = assets.load_atlas("mypack:gui");
+    /// ```
+    pub fn load_atlas(&self, path: &str) -> Result<Atlas> {
+        self.load_resource(&ResourceIdentifier::atlas(path))
+    }
+
+    /// Loads the core [`ShaderProgram`] located at the given path.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let value = assets.load_core_shader("position_tex");
+    /// let value = assets.load_core_shader("mypack:rendertype_glow");
+    /// ```
+    pub fn load_core_shader(&self, path: &str) -> Result<ShaderProgram> {
+        self.load_resource(&ResourceIdentifier::core_shader(path))
+    }
+
+    /// Loads the post-processing [`ShaderProgram`] located at the given path.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let value = assets.load_program_shader("blur");
+    /// let value = assets.load_program_shader("mypack:outline");
+    /// ```
+    pub fn load_program_shader(&self, path: &str) -> Result<ShaderProgram> {
+        self.load_resource(&ResourceIdentifier::program_shader(path))
+    }
+
+    /// Loads the [`PostChain`] located at the given path.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let value = assets.load_post_chain("spider");
+    /// let value = assets.load_post_chain("mypack:wobble");
+    /// ```
+    pub fn load_post_chain(&self, path: &str) -> Result<PostChain> {
+        self.load_resource(&ResourceIdentifier::post_chain(path))
+    }
+
+    /// Loads the [`PostEffect`] located at the given path.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let value = assets.load_post_effect("spider");
+    /// let value = assets.load_post_effect("mypack:wobble");
+    /// ```
+    pub fn load_post_effect(&self, path: &str) -> Result<PostEffect> {
+        self.load_resource(&ResourceIdentifier::post_effect(path))
+    }
+
+    /// Loads the GLSL source of the vertex shader located at the given path,
+    /// relative to `shaders/`, with its `#moj_import` directives resolved.
+    ///
+    /// See [`ShaderImportResolver`] for how imports are resolved.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let source = assets.load_vertex_shader("core/position_tex");
+    /// let source = assets.load_vertex_shader("mypack:core/rendertype_glow");
+    /// ```
+    pub fn load_vertex_shader(&self, path: &str) -> Result<String> {
+        self.load_shader_source(&ResourceIdentifier::vertex_shader(path))
+    }
+
+    /// Loads the GLSL source of the fragment shader located at the given path,
+    /// relative to `shaders/`, with its `#moj_import` directives resolved.
+    ///
+    /// See [`ShaderImportResolver`] for how imports are resolved.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let source = assets.load_fragment_shader("core/position_tex");
+    /// let source = assets.load_fragment_shader("mypack:post/wobble");
+    /// ```
+    pub fn load_fragment_shader(&self, path: &str) -> Result<String> {
+        self.load_shader_source(&ResourceIdentifier::fragment_shader(path))
+    }
+
+    /// Loads the GLSL shader source referenced by the given
+    /// [`ResourceIdentifier`], with its `#moj_import` directives resolved
+    /// against this pack.
+    ///
+    /// See [`ShaderImportResolver`] for how imports are resolved.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let id = ResourceIdentifier::shader_include("include/fog");
+    /// let source = assets.load_shader_source(&id);
+    /// ```
+    pub fn load_shader_source(&self, resource: &ResourceIdentifier) -> Result<String> {
+        ShaderImportResolver::resolve(resource, |id| self.load_text(id))
+    }
+
+    /// Loads the [`Equipment`] asset located at the given path.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let value = assets.load_equipment("diamond");
+    /// let value = assets.load_equipment("mypack:ruby");
+    /// ```
+    pub fn load_equipment(&self, path: &str) -> Result<Equipment> {
+        self.load_resource(&ResourceIdentifier::equipment(path))
+    }
+
+    /// Loads the [`ItemDefinition`] of the item with the provided id.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let value = assets.load_item_definition("compass");
+    /// let value = assets.load_item_definition("minecraft:bow");
+    /// ```
+    pub fn load_item_definition(&self, item_id: &str) -> Result<ItemDefinition> {
+        self.load_resource(&ResourceIdentifier::item_definition(item_id))
+    }
+
+    /// Loads every JSON resource of the given [`ResourceKind`] in every
+    /// namespace.
+    ///
+    /// The resources are ordered by namespace and then by path. If any
+    /// resource fails to load, the first error is returned. See
+    /// [`load_all_reporting()`][Self::load_all_reporting] to load the other
+    /// resources anyway.
+    ///
+    /// With the `rayon` feature, the resources are parsed in parallel: the
+    /// files are read on the calling thread, since providers are not required
+    /// to be [`Sync`], and then parsed on rayon's thread pool.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// use minecraft_assets::schemas::Model;
+    ///
+    /// let mut provider = MemoryResourceProvider::new();
+    /// provider.insert("assets/minecraft/models/block/cube.json", br#"{ "elements": [] }"#.to_vec());
+    /// provider.insert("assets/minecraft/models/block/cube_all.json", br#"{ "parent": "block/cube" }"#.to_vec());
+    /// provider.insert("assets/mypack/models/block/ruby.json", br#"{ "parent": "block/cube_all" }"#.to_vec());
+    ///
+    /// let assets = AssetPack::new(provider);
+    /// let models = assets.load_all::<Model>(ResourceKind::BlockModel).unwrap();
+    ///
+    /// let ids: Vec<String> = models.keys().map(|id| id.to_string()).collect();
+    /// assert_eq!(ids, ["minecraft:block/cube", "minecraft:block/cube_all", "mypack:block/ruby"]);
+    /// assert_eq!(
+    ///     models[&ResourceIdentifier::block_model("mypack:ruby")].parent.as_ref().unwrap(),
+    ///     "block/cube_all",
+    /// );
+    /// ```
+    pub fn load_all<T>(
+        &self,
+        kind: ResourceKind,
+    ) -> Result<indexmap::IndexMap<ResourceIdentifier<'static>, T>>
+    where
+        T: DeserializeOwned + Send,
+    {
+        self.load_each(kind)?
+            .into_iter()
+            .map(|(id, resource)| Ok((id, resource?)))
+            .collect()
+    }
+
+    /// Loads every JSON resource of the given [`ResourceKind`] in every
+    /// namespace like [`load_all()`][Self::load_all], but continues past the
+    /// resources that fail to load and reports their errors.
+    ///
+    /// Community packs often contain a few malformed files, which should not
+    /// prevent loading the rest of the pack. An error is only returned if the
+    /// resources cannot be listed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// use minecraft_assets::schemas::Model;
+    ///
+    /// let mut provider = MemoryResourceProvider::new();
+    /// provider.insert("assets/minecraft/models/block/cube.json", br#"{ "elements": [] }"#.to_vec());
+    /// provider.insert("assets/minecraft/models/block/broken.json", br#"{ "parent": "#.to_vec());
+    /// provider.insert("assets/minecraft/models/block/stone.json", br#"{ "parent": "block/cube" }"#.to_vec());
+    ///
+    /// let assets = AssetPack::new(provider);
+    /// assert!(assets.load_all::<Model>(ResourceKind::BlockModel).is_err());
+    ///
+    /// let report = assets.load_all_reporting::<Model>(ResourceKind::BlockModel).unwrap();
+    /// assert_eq!(report.resources.len(), 2);
+    /// assert_eq!(report.errors.len(), 1);
+    ///
+    /// let (id, error) = &report.errors[0];
+    /// assert_eq!(id, &ResourceIdentifier::block_model("broken"));
+    /// assert!(matches!(error, Error::ResourceParseError(_)));
+    /// ```
+    pub fn load_all_reporting<T>(&self, kind: ResourceKind) -> Result<LoadReport<T>>
+    where
+        T: DeserializeOwned + Send,
+    {
+        let mut report = LoadReport {
+            resources: indexmap::IndexMap::new(),
+            errors: Vec::new(),
+        };
+
+        for (id, resource) in self.load_each(kind)? {
+            match resource {
+                Ok(resource) => {
+                    report.resources.insert(id, resource);
+                }
+                Err(e) => report.errors.push((id, e)),
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Loads every JSON resource of the given kind, sorted by namespace and
+    /// path, and parses them (in parallel with the `rayon` feature).
+    fn load_each<T>(
+        &self,
+        kind: ResourceKind,
+    ) -> Result<Vec<(ResourceIdentifier<'static>, Result<T>)>>
+    where
+        T: DeserializeOwned + Send,
+    {
+        #[cfg(feature = "rayon")]
+        use rayon::prelude::*;
+
+        let mut ids = self.provider.list(kind)?;
+        ids.sort_by(|a, b| (a.namespace(), a.path()).cmp(&(b.namespace(), b.path())));
+
+        let files: Vec<_> = ids
+            .into_iter()
+            .map(|id| {
+                let bytes = self.load_raw(&id);
+                (id, bytes)
+            })
+            .collect();
+
+        #[cfg(feature = "rayon")]
+        let files = files.into_par_iter();
+        #[cfg(not(feature = "rayon"))]
+        let files = files.into_iter();
+
+        let version = self.version;
+        Ok(files
+            .map(|(id, bytes)| {
+                let resource = bytes.and_then(|bytes| Ok(parse_resource(&id, version, &bytes)?));
+                (id, resource)
+            })
+            .collect())
+    }
+
+    pub(crate) fn load_resource<T>(&self, resource: &ResourceIdentifier) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        let bytes = self.load_raw(resource)?;
+        Ok(parse_resource(resource, self.version, &bytes)?)
+    }
+
+    fn load_raw(&self, resource: &ResourceIdentifier) -> Result<Vec<u8>> {
+        self.check_supported(resource.kind())?;
+        Ok(self.provider.load_resource(resource)?)
+    }
+
+    fn check_supported(&self, kind: ResourceKind) -> Result<()> {
+        match self.version {
+            Some(version) if !version.supports(kind) => {
+                Err(Error::UnsupportedKind { kind, version })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn load_text(&self, resource: &ResourceIdentifier) -> Result<String> {
+        let bytes = self.load_raw(resource)?;
+        let source =
+            String::from_utf8(bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        Ok(source)
+    }
+
+    fn load_model_recursive(&self, resource: &ResourceIdentifier) -> Result<Vec<Model>> {
+        ModelResolver::load_parents(resource, |id| self.load_resource(id))
+    }
+
+    fn load_model_resolved(&self, resource: &ResourceIdentifier) -> Result<Model> {
+        ModelResolver::load_resolved(resource, |id| self.load_resource(id))
+    }
+}
+
+impl Deref for AssetPack {
+    type Target = dyn ResourceProvider;
+
+    fn deref(&self) -> &Self::Target {
+        &*self.provider
+    }
+}
+
+/// Returns the given language code with an upper case region, like the legacy
+/// `.lang` files before 1.11, or `None` if it is already in that case.
+fn legacy_language_code(code: &str) -> Option<String> {
+    let (prefix, region) = code.rsplit_once('_')?;
+    let region = region.to_ascii_uppercase();
+    let legacy = format!("{}_{}", prefix, region);
+    (legacy != code).then_some(legacy)
+}
+
+/// The resources loaded by [`AssetPack::load_all_reporting()`], and the
+/// errors of those that failed to load.
+#[derive(Debug)]
+pub struct LoadReport<T> {
+    /// The resources that were loaded, ordered by namespace and then by path.
+    pub resources: indexmap::IndexMap<ResourceIdentifier<'static>, T>,
+
+    /// The resources that failed to load and their errors, ordered by
+    /// namespace and then by path.
+    pub errors: Vec<(ResourceIdentifier<'static>, Error)>,
+}
+
+impl<T> LoadReport<T> {
+    /// Returns `true` if every resource was loaded.
+    pub fn is_complete(&self) -> bool {
+        self.errors.is_empty()
     }
 }
@@ -0,0 +1,212 @@
+#[cfg(feature = "tokio")]
+use std::path::Path;
+use std::{io, ops::Deref};
+
+use serde::de::DeserializeOwned;
+
+use crate::{
+    api::{
+        parse_error::{parse_resource, parse_root_file},
+        AsyncResourceProvider, ResourceCategory, ResourceIdentifier, ResourceKind, Result,
+    },
+    schemas::{
+        texture::Texture, Advancement, Atlas, BlockStates, Font, ItemDefinition, LanguageFile,
+        LootTable, Model, PackMeta, Recipe, SoundsJson, Tag,
+    },
+};
+
+/// The asynchronous counterpart of [`AssetPack`], for services that load
+/// resources without blocking.
+///
+/// Every method returns a future instead of blocking on the underlying
+/// [`AsyncResourceProvider`].
+///
+/// Only the most commonly used resources have a dedicated method. The
+/// bytes of any other resource can be loaded through the provider, which this
+/// type dereferences to, and then parsed like [`AssetPack`] does.
+///
+/// This type requires the `async` feature.
+///
+/// [`AssetPack`]: crate::api::AssetPack
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::api::*;
+/// # let root = std::env::temp_dir().join("minecraft-assets-async-example");
+/// # let mut writer = ResourceWriter::at_path(&root);
+/// # let states = serde_json::from_str(r#"{ "variants": { "": { "model": "block/oak_planks" } } }"#).unwrap();
+/// # writer.write_blockstates("oak_planks", &states).unwrap();
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let assets = AsyncAssetPack::at_path(&root);
+///
+/// let states = assets.load_blockstates("oak_planks").await.unwrap();
+/// let blocks = assets.enumerate(ResourceKind::BlockStates, "minecraft").await.unwrap();
+///
+/// assert_eq!(states.variants().unwrap()[""].models()[0].model, "block/oak_planks");
+/// assert_eq!(blocks, [ResourceIdentifier::blockstates("oak_planks")]);
+/// # });
+/// # std::fs::remove_dir_all(&root).unwrap();
+/// ```
+pub struct AsyncAssetPack {
+    provider: Box<dyn AsyncResourceProvider>,
+}
+
+impl AsyncAssetPack {
+    /// Returns a new [`AsyncAssetPack`] that can read data from the given
+    /// directory.
+    ///
+    /// The provided `root_dir` should be the directory that contains the
+    /// `assets/` and/or `data/` directories.
+    ///
+    /// This method requires the `tokio` feature.
+    #[cfg(feature = "tokio")]
+    pub fn at_path(root_dir: impl AsRef<Path>) -> Self {
+        Self::new(crate::api::FileSystemResourceProvider::new(root_dir))
+    }
+
+    /// Returns a new [`AsyncAssetPack`] that uses the given
+    /// [`AsyncResourceProvider`].
+    pub fn new<P>(provider: P) -> Self
+    where
+        P: AsyncResourceProvider + 'static,
+    {
+        Self {
+            provider: Box::new(provider),
+        }
+    }
+
+    /// Enumerates every resource of the given [`ResourceKind`] in the given
+    /// namespace, sorted by path.
+    ///
+    /// See [`AssetPack::enumerate()`][crate::api::AssetPack::enumerate].
+    pub async fn enumerate(
+        &self,
+        kind: ResourceKind,
+        namespace: &str,
+    ) -> Result<Vec<ResourceIdentifier<'static>>> {
+        let mut ids = match self
+            .provider
+            .enumerate_resources_async(namespace, kind)
+            .await
+        {
+            Ok(ids) => ids,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => return Err(e.into()),
+        };
+
+        ids.sort_by(|a, b| a.path().cmp(b.path()));
+        Ok(ids)
+    }
+
+    /// Returns the namespaces that are present in the given
+    /// [`ResourceCategory`], in sorted order.
+    pub async fn namespaces(&self, category: ResourceCategory) -> Result<Vec<String>> {
+        Ok(self.provider.enumerate_namespaces_async(category).await?)
+    }
+
+    /// Loads the [`PackMeta`] from the `pack.mcmeta` file at the root of the
+    /// pack.
+    pub async fn load_pack_meta(&self) -> Result<PackMeta> {
+        let bytes = self.provider.load_root_file_async("pack.mcmeta").await?;
+        Ok(parse_root_file("pack.mcmeta", &bytes)?)
+    }
+
+    /// Loads the [`BlockStates`] of the block with the provided id.
+    pub async fn load_blockstates(&self, block_id: &str) -> Result<BlockStates> {
+        self.load_resource(&ResourceIdentifier::blockstates(block_id))
+            .await
+    }
+
+    /// Loads the block [`Model`] identified by the given name or path.
+    ///
+    /// Its parents are not loaded. Load them one by one and combine them with
+    /// [`ModelResolver::resolve_model()`][crate::api::ModelResolver::resolve_model].
+    pub async fn load_block_model(&self, model: &str) -> Result<Model> {
+        self.load_resource(&ResourceIdentifier::block_model(model))
+            .await
+    }
+
+    /// Loads the item [`Model`] identified by the given name or path.
+    ///
+    /// Its parents are not loaded. Load them one by one and combine them with
+    /// [`ModelResolver::resolve_model()`][crate::api::ModelResolver::resolve_model].
+    pub async fn load_item_model(&self, model: &str) -> Result<Model> {
+        self.load_resource(&ResourceIdentifier::item_model(model))
+            .await
+    }
+
+    /// Loads the [`ItemDefinition`] of the item with the provided id.
+    pub async fn load_item_definition(&self, item_id: &str) -> Result<ItemDefinition> {
+        self.load_resource(&ResourceIdentifier::item_definition(item_id))
+            .await
+    }
+
+    /// Loads the [`Texture`] metadata (`.png.mcmeta`) of the texture located
+    /// at the given path.
+    pub async fn load_texture_meta(&self, path: &str) -> Result<Texture> {
+        self.load_resource(&ResourceIdentifier::texture_meta(path))
+            .await
+    }
+
+    /// Loads the [`Atlas`] located at the given path.
+    pub async fn load_atlas(&self, path: &str) -> Result<Atlas> {
+        self.load_resource(&ResourceIdentifier::atlas(path)).await
+    }
+
+    /// Loads the [`Font`] located at the given path.
+    pub async fn load_font(&self, path: &str) -> Result<Font> {
+        self.load_resource(&ResourceIdentifier::font(path)).await
+    }
+
+    /// Loads the [`LanguageFile`] for the language with the given code.
+    pub async fn load_language(&self, code: &str) -> Result<LanguageFile> {
+        self.load_resource(&ResourceIdentifier::language(code))
+            .await
+    }
+
+    /// Loads the `sounds.json` file of the given namespace.
+    pub async fn load_sounds(&self, namespace: &str) -> Result<SoundsJson> {
+        self.load_resource(&ResourceIdentifier::sounds(namespace))
+            .await
+    }
+
+    /// Loads the [`LootTable`] located at the given path.
+    pub async fn load_loot_table(&self, path: &str) -> Result<LootTable> {
+        self.load_resource(&ResourceIdentifier::loot_table(path))
+            .await
+    }
+
+    /// Loads the [`Recipe`] located at the given path.
+    pub async fn load_recipe(&self, path: &str) -> Result<Recipe> {
+        self.load_resource(&ResourceIdentifier::recipe(path)).await
+    }
+
+    /// Loads the [`Advancement`] located at the given path.
+    pub async fn load_advancement(&self, path: &str) -> Result<Advancement> {
+        self.load_resource(&ResourceIdentifier::advancement(path))
+            .await
+    }
+
+    /// Loads the [`Tag`] of the given kind with the given id.
+    pub async fn load_tag(&self, kind: ResourceKind, tag_id: &str) -> Result<Tag> {
+        self.load_resource(&ResourceIdentifier::tag(kind, tag_id))
+            .await
+    }
+
+    async fn load_resource<T>(&self, resource: &ResourceIdentifier<'_>) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        let bytes = self.provider.load_resource_async(resource).await?;
+        Ok(parse_resource(resource, None, &bytes)?)
+    }
+}
+
+impl Deref for AsyncAssetPack {
+    type Target = dyn AsyncResourceProvider;
+
+    fn deref(&self) -> &Self::Target {
+        &*self.provider
+    }
+}
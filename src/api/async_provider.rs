@@ -0,0 +1,314 @@
+use std::{future::Future, io, pin::Pin};
+
+#[cfg(feature = "tokio")]
+use crate::api::{EnumerateResources, FileSystemResourceProvider};
+use crate::api::{ResourceCategory, ResourceIdentifier, ResourceKind};
+
+/// A boxed [`Future`] returned by the methods of [`AsyncResourceProvider`].
+///
+/// On WebAssembly, where the futures of browser APIs are not [`Send`], this is
+/// a [`LocalBoxFuture`].
+#[cfg(not(target_arch = "wasm32"))]
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// A boxed [`Future`] returned by the methods of [`AsyncResourceProvider`].
+///
+/// On WebAssembly, where the futures of browser APIs are not [`Send`], this is
+/// a [`LocalBoxFuture`].
+#[cfg(target_arch = "wasm32")]
+pub type BoxFuture<'a, T> = LocalBoxFuture<'a, T>;
+
+/// A boxed [`Future`] that does not have to be [`Send`].
+pub type LocalBoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + 'a>>;
+
+/// A bound that is [`Send`], except on WebAssembly, where the browser runs
+/// everything on a single thread.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait MaybeSend: Send {}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: Send + ?Sized> MaybeSend for T {}
+
+/// A bound that is [`Send`], except on WebAssembly, where the browser runs
+/// everything on a single thread.
+#[cfg(target_arch = "wasm32")]
+pub trait MaybeSend {}
+
+#[cfg(target_arch = "wasm32")]
+impl<T: ?Sized> MaybeSend for T {}
+
+/// A bound that is [`Sync`], except on WebAssembly, where the browser runs
+/// everything on a single thread.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait MaybeSync: Sync {}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: Sync + ?Sized> MaybeSync for T {}
+
+/// A bound that is [`Sync`], except on WebAssembly, where the browser runs
+/// everything on a single thread.
+#[cfg(target_arch = "wasm32")]
+pub trait MaybeSync {}
+
+#[cfg(target_arch = "wasm32")]
+impl<T: ?Sized> MaybeSync for T {}
+
+/// The asynchronous counterpart of [`ResourceProvider`], for loading
+/// resources without blocking, e.g., from network storage.
+///
+/// The methods return boxed futures so that the trait can be used as a trait
+/// object, like [`AsyncAssetPack`] does. Providers and their futures must be
+/// [`Send`] and [`Sync`], except on WebAssembly.
+///
+/// This trait requires the `async` feature.
+///
+/// [`ResourceProvider`]: crate::api::ResourceProvider
+/// [`AsyncAssetPack`]: crate::api::AsyncAssetPack
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::api::*;
+/// use std::{collections::HashMap, io};
+///
+/// /// Serves resources from memory, e.g., after fetching them from a remote
+/// /// server.
+/// struct Remote(HashMap<String, Vec<u8>>);
+///
+/// impl AsyncResourceProvider for Remote {
+///     fn load_resource_async<'a>(
+///         &'a self,
+///         id: &'a ResourceIdentifier<'a>,
+///     ) -> BoxFuture<'a, Result<Vec<u8>, io::Error>> {
+///         Box::pin(async move {
+///             self.0
+///                 .get(&id.to_string())
+///                 .cloned()
+///                 .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))
+///         })
+///     }
+///
+///     fn enumerate_resources_async<'a>(
+///         &'a self,
+///         _namespace: &'a str,
+///         _kind: ResourceKind,
+///     ) -> BoxFuture<'a, Result<Vec<ResourceIdentifier<'static>>, io::Error>> {
+///         Box::pin(async { Err(io::Error::from(io::ErrorKind::Unsupported)) })
+///     }
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let mut resources = HashMap::new();
+/// resources.insert(
+///     String::from("minecraft:stone"),
+///     br#"{ "variants": { "": { "model": "block/stone" } } }"#.to_vec(),
+/// );
+///
+/// let assets = AsyncAssetPack::new(Remote(resources));
+/// let states = assets.load_blockstates("stone").await.unwrap();
+/// assert_eq!(states.variants().unwrap()[""].models()[0].model, "block/stone");
+/// # });
+/// ```
+pub trait AsyncResourceProvider: MaybeSend + MaybeSync {
+    /// Returns the raw bytes of the resource referenced by the given
+    /// [`ResourceIdentifier`].
+    fn load_resource_async<'a>(
+        &'a self,
+        id: &'a ResourceIdentifier<'a>,
+    ) -> BoxFuture<'a, Result<Vec<u8>, io::Error>>;
+
+    /// Returns the raw bytes of the file with the given name at the root of
+    /// the pack, such as `pack.mcmeta`.
+    ///
+    /// The default implementation returns an error of kind
+    /// [`io::ErrorKind::Unsupported`].
+    fn load_root_file_async<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> BoxFuture<'a, Result<Vec<u8>, io::Error>> {
+        let _ = name;
+        Box::pin(async { Err(io::Error::from(io::ErrorKind::Unsupported)) })
+    }
+
+    /// Enumerates the available resources of the given [`ResourceKind`] in the
+    /// given namespace, including those in subdirectories.
+    fn enumerate_resources_async<'a>(
+        &'a self,
+        namespace: &'a str,
+        kind: ResourceKind,
+    ) -> BoxFuture<'a, Result<Vec<ResourceIdentifier<'static>>, io::Error>>;
+
+    /// Enumerates the namespaces that are present in the given
+    /// [`ResourceCategory`], in sorted order.
+    ///
+    /// The default implementation returns an error of kind
+    /// [`io::ErrorKind::Unsupported`].
+    fn enumerate_namespaces_async(
+        &self,
+        category: ResourceCategory,
+    ) -> BoxFuture<'_, Result<Vec<String>, io::Error>> {
+        let _ = category;
+        Box::pin(async { Err(io::Error::from(io::ErrorKind::Unsupported)) })
+    }
+}
+
+/// Files are read with [`tokio::fs`], and directories are enumerated on
+/// tokio's blocking thread pool. Both require a tokio runtime.
+///
+/// This implementation requires the `tokio` feature.
+#[cfg(feature = "tokio")]
+impl AsyncResourceProvider for FileSystemResourceProvider {
+    fn load_resource_async<'a>(
+        &'a self,
+        id: &'a ResourceIdentifier<'a>,
+    ) -> BoxFuture<'a, Result<Vec<u8>, io::Error>> {
+        let path = self.resource_path(id);
+        Box::pin(tokio::fs::read(path))
+    }
+
+    fn load_root_file_async<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> BoxFuture<'a, Result<Vec<u8>, io::Error>> {
+        Box::pin(tokio::fs::read(self.root().join(name)))
+    }
+
+    fn enumerate_resources_async<'a>(
+        &'a self,
+        namespace: &'a str,
+        kind: ResourceKind,
+    ) -> BoxFuture<'a, Result<Vec<ResourceIdentifier<'static>>, io::Error>> {
+        let provider = self.clone();
+        let namespace = String::from(namespace);
+        Box::pin(spawn_blocking(move || {
+            provider.enumerate_resources(&namespace, kind)
+        }))
+    }
+
+    fn enumerate_namespaces_async(
+        &self,
+        category: ResourceCategory,
+    ) -> BoxFuture<'_, Result<Vec<String>, io::Error>> {
+        let provider = self.clone();
+        Box::pin(spawn_blocking(move || {
+            provider.enumerate_namespaces(category)
+        }))
+    }
+}
+
+#[cfg(feature = "tokio")]
+async fn spawn_blocking<T, F>(f: F) -> Result<T, io::Error>
+where
+    F: FnOnce() -> Result<T, io::Error> + Send + 'static,
+    T: Send + 'static,
+{
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(io::Error::other)?
+}
+
+/// An [`AsyncResourceProvider`] that fetches the files of a pack from a web
+/// server, e.g., with the browser's `fetch()` API when compiled to
+/// WebAssembly.
+///
+/// The provider maps every resource to a URL below the base URL of the pack
+/// (e.g., `https://example.com/pack/assets/minecraft/blockstates/stone.json`)
+/// and hands it to the given fetch function, so that it does not depend on any
+/// particular HTTP client. Web servers cannot list directories, so resources
+/// cannot be enumerated.
+///
+/// The fetch function should fail with an error of kind
+/// [`io::ErrorKind::NotFound`] if the server responds with `404 Not Found`.
+/// On WebAssembly, the fetch function and its futures do not have to be
+/// [`Send`], so the futures of browser APIs (e.g., `JsFuture` of
+/// `wasm_bindgen_futures`) can be returned as they are.
+///
+/// This type requires the `async` feature.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::api::*;
+/// use std::io;
+///
+/// let provider = FetchResourceProvider::new("https://example.com/pack/", |url: String| -> BoxFuture<'static, _> {
+///     // e.g., `web_sys::window().unwrap().fetch_with_str(&url)`
+///     Box::pin(async move {
+///         match url.as_str() {
+///             "https://example.com/pack/assets/minecraft/blockstates/stone.json" => {
+///                 Ok(br#"{ "variants": { "": { "model": "block/stone" } } }"#.to_vec())
+///             }
+///             _ => Err(io::Error::from(io::ErrorKind::NotFound)),
+///         }
+///     })
+/// });
+///
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let assets = AsyncAssetPack::new(provider);
+/// let states = assets.load_blockstates("stone").await.unwrap();
+/// assert_eq!(states.variants().unwrap()[""].models()[0].model, "block/stone");
+/// # });
+/// ```
+pub struct FetchResourceProvider<F> {
+    base_url: String,
+    fetch: F,
+}
+
+impl<F> FetchResourceProvider<F>
+where
+    F: Fn(String) -> BoxFuture<'static, Result<Vec<u8>, io::Error>> + MaybeSend + MaybeSync,
+{
+    /// Returns a new provider that fetches the files of the pack at the given
+    /// base URL with the given fetch function.
+    ///
+    /// The base URL should point to the directory that contains the `assets/`
+    /// and/or `data/` directories.
+    pub fn new(base_url: impl Into<String>, fetch: F) -> Self {
+        let mut base_url = base_url.into();
+        if !base_url.ends_with('/') {
+            base_url.push('/');
+        }
+
+        Self { base_url, fetch }
+    }
+
+    /// Returns the base URL of the pack, with a trailing `/`.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Returns the URL that the resource referenced by the given
+    /// [`ResourceIdentifier`] is fetched from.
+    pub fn url_for_resource(&self, id: &ResourceIdentifier) -> String {
+        format!("{}{}", self.base_url, id.pack_path())
+    }
+}
+
+impl<F> AsyncResourceProvider for FetchResourceProvider<F>
+where
+    F: Fn(String) -> BoxFuture<'static, Result<Vec<u8>, io::Error>> + MaybeSend + MaybeSync,
+{
+    fn load_resource_async<'a>(
+        &'a self,
+        id: &'a ResourceIdentifier<'a>,
+    ) -> BoxFuture<'a, Result<Vec<u8>, io::Error>> {
+        (self.fetch)(self.url_for_resource(id))
+    }
+
+    fn load_root_file_async<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> BoxFuture<'a, Result<Vec<u8>, io::Error>> {
+        (self.fetch)(format!("{}{}", self.base_url, name))
+    }
+
+    /// Always returns an error of kind [`io::ErrorKind::Unsupported`].
+    fn enumerate_resources_async<'a>(
+        &'a self,
+        namespace: &'a str,
+        kind: ResourceKind,
+    ) -> BoxFuture<'a, Result<Vec<ResourceIdentifier<'static>>, io::Error>> {
+        let _ = (namespace, kind);
+        Box::pin(async { Err(io::Error::from(io::ErrorKind::Unsupported)) })
+    }
+}
@@ -0,0 +1,934 @@
+//! Stitching the sprites of texture atlases into images, like the game does.
+//!
+//! Start here: [`AtlasStitcher`].
+//!
+//! Since 1.19.3, the sprites of each texture atlas (e.g., the blocks atlas) are
+//! listed by the atlas configurations in `assets/<namespace>/atlases/`. The
+//! [`AtlasStitcher`] expands the [sources] of a configuration into sprites,
+//! loads them, and packs them into a single image with the same algorithm as
+//! the game, so that the resulting UV coordinates match the game's.
+//!
+//! Animated textures take up the size of a single frame in the atlas, and the
+//! atlas image shows their first frame. Other frames can be copied into the
+//! atlas with [`StitchedAtlas::upload_frame()`].
+//!
+//! The atlas configurations of every pack in a stack are combined, which can
+//! be done with [`load_config()`].
+//!
+//! This module requires the `image` feature.
+//!
+//! [sources]: crate::schemas::atlas::AtlasSource
+
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap},
+    io,
+};
+
+use image::{imageops, GenericImage, Rgba, RgbaImage};
+
+use crate::{
+    api::{
+        Error, LayeredResourceProvider, ResourceIdentifier, ResourceKind, ResourceProvider, Result,
+        TextureDecoder,
+    },
+    schemas::{
+        atlas::{Atlas, AtlasSource},
+        texture::{AnimationFrame, Texture, TextureAnimation},
+    },
+};
+
+use super::{parse_error::parse_resource, provider::is_missing, tags::canonical};
+
+/// The name of the sprite that the game adds to every atlas, and that it uses
+/// in place of missing textures.
+pub const MISSING_SPRITE: &str = "minecraft:missingno";
+
+/// Loads the atlas configuration with the given ID (e.g., `blocks`) from every
+/// layer of the given stack, and combines their sources from the bottom up.
+///
+/// The configurations hidden by the `filter` of a layer above them are
+/// skipped.
+///
+/// Returns an error of kind [`io::ErrorKind::NotFound`] if no layer has the
+/// configuration.
+pub fn load_config(layers: &LayeredResourceProvider, atlas: &str) -> Result<Atlas> {
+    let id = ResourceIdentifier::atlas(atlas);
+    let mut config: Option<Atlas> = None;
+
+    for (i, layer) in layers.layers().enumerate() {
+        if layers.is_filtered(i, &id) {
+            continue;
+        }
+
+        let bytes = match layer.load_resource(&id) {
+            Ok(bytes) => bytes,
+            Err(e) if is_missing(&e) => continue,
+            Err(e) => return Err(e.into()),
+        };
+
+        let layer_config: Atlas = parse_resource(&id, None, &bytes)?;
+        config
+            .get_or_insert_with(Atlas::default)
+            .sources
+            .extend(layer_config.sources);
+    }
+
+    config.ok_or_else(|| io::Error::from(io::ErrorKind::NotFound).into())
+}
+
+/// A sprite in a [`StitchedAtlas`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct AtlasSprite {
+    /// The namespaced name of the sprite, e.g., `minecraft:block/stone`.
+    pub name: String,
+
+    /// The x coordinate of the sprite's left edge in the atlas, in pixels.
+    pub x: u32,
+
+    /// The y coordinate of the sprite's top edge in the atlas, in pixels.
+    pub y: u32,
+
+    /// The width of the sprite (i.e., of a single frame), in pixels.
+    pub width: u32,
+
+    /// The height of the sprite (i.e., of a single frame), in pixels.
+    pub height: u32,
+
+    /// The UV coordinates of the sprite's left edge, from 0 to 1.
+    pub u0: f32,
+
+    /// The UV coordinates of the sprite's top edge, from 0 to 1.
+    pub v0: f32,
+
+    /// The UV coordinates of the sprite's right edge, from 0 to 1.
+    pub u1: f32,
+
+    /// The UV coordinates of the sprite's bottom edge, from 0 to 1.
+    pub v1: f32,
+
+    /// The number of frames in the sprite's texture, which is `1` if it is not
+    /// animated.
+    pub frame_count: u32,
+
+    /// The animation of the sprite, as given by its texture's `.mcmeta` file.
+    pub animation: Option<TextureAnimation>,
+}
+
+impl AtlasSprite {
+    /// Returns the frame of the sprite shown at the given tick, counted from
+    /// the start of its animation, or `None` if the sprite is not animated.
+    ///
+    /// See [`TextureAnimation::frame_at()`]. The frame can be copied into the
+    /// atlas image with [`StitchedAtlas::upload_frame()`].
+    pub fn frame_at(&self, tick: u64) -> Option<AnimationFrame> {
+        self.animation
+            .as_ref()
+            .map(|animation| animation.frame_at(self.frame_count, tick))
+    }
+}
+
+/// A texture atlas stitched by an [`AtlasStitcher`].
+#[derive(Debug, Clone)]
+pub struct StitchedAtlas {
+    image: RgbaImage,
+    mipmap_levels: u32,
+    sprites: BTreeMap<String, AtlasSprite>,
+    /// The full textures of the animated sprites.
+    animations: HashMap<String, RgbaImage>,
+}
+
+impl StitchedAtlas {
+    /// Returns the atlas image.
+    pub fn image(&self) -> &RgbaImage {
+        &self.image
+    }
+
+    /// Returns the atlas image, consuming the atlas.
+    pub fn into_image(self) -> RgbaImage {
+        self.image
+    }
+
+    /// Returns the width of the atlas image, in pixels.
+    pub fn width(&self) -> u32 {
+        self.image.width()
+    }
+
+    /// Returns the height of the atlas image, in pixels.
+    pub fn height(&self) -> u32 {
+        self.image.height()
+    }
+
+    /// Returns the number of mipmap levels that the sprites support, which may
+    /// be lower than requested if some sprites are too small.
+    pub fn mipmap_levels(&self) -> u32 {
+        self.mipmap_levels
+    }
+
+    /// Returns the sprite with the given name, or `None` if the atlas has no
+    /// such sprite.
+    ///
+    /// Names without a namespace are in the `minecraft` namespace.
+    pub fn sprite(&self, name: &str) -> Option<&AtlasSprite> {
+        self.sprites.get(&canonical(name))
+    }
+
+    /// Returns every sprite of the atlas, sorted by name.
+    pub fn sprites(&self) -> impl Iterator<Item = &AtlasSprite> {
+        self.sprites.values()
+    }
+
+    /// Copies the given frame of an animated sprite into the atlas image.
+    ///
+    /// `frame` is the index of the frame in the sprite's texture, as used by
+    /// the [`frames`][TextureAnimation::frames] of its animation. Returns
+    /// `false` if the sprite is not animated or has no such frame.
+    pub fn upload_frame(&mut self, sprite: &str, frame: u32) -> bool {
+        let sprite = match self.sprites.get(&canonical(sprite)) {
+            Some(sprite) if frame < sprite.frame_count => sprite,
+            _ => return false,
+        };
+        let texture = match self.animations.get(&sprite.name) {
+            Some(texture) => texture,
+            None => return false,
+        };
+
+        let (x, y) = frame_origin(texture.width(), sprite.width, sprite.height, frame);
+        let view = imageops::crop_imm(texture, x, y, sprite.width, sprite.height);
+        // The sprite's area always fits into the atlas.
+        self.image.copy_from(&*view, sprite.x, sprite.y).is_ok()
+    }
+}
+
+/// Stitches the sprites listed by atlas configurations into [`StitchedAtlas`]
+/// images.
+///
+/// The sprites are packed like the game does: from the tallest to the
+/// shortest, into an atlas whose sides are powers of two. Every atlas also
+/// contains the [`MISSING_SPRITE`].
+///
+/// Sprites whose textures are missing are skipped, like in the game.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::api::*;
+/// use minecraft_assets::api::atlas::AtlasStitcher;
+/// use minecraft_assets::schemas::Atlas;
+///
+/// # fn png(width: u32, height: u32) -> Vec<u8> {
+/// #     let mut bytes = Vec::new();
+/// #     let mut encoder = png::Encoder::new(&mut bytes, width, height);
+/// #     encoder.set_color(png::ColorType::Rgba);
+/// #     let mut writer = encoder.write_header().unwrap();
+/// #     writer.write_image_data(&vec![0xff; (width * height * 4) as usize]).unwrap();
+/// #     drop(writer);
+/// #     bytes
+/// # }
+/// let mut pack = MemoryResourceProvider::new();
+/// pack.insert("assets/minecraft/textures/block/stone.png", png(16, 16));
+/// pack.insert("assets/minecraft/textures/block/lantern.png", png(16, 32));
+/// pack.insert("assets/minecraft/textures/block/lantern.png.mcmeta", br#"{ "animation": {} }"#.to_vec());
+///
+/// let config: Atlas = serde_json::from_str(r#"
+///     { "sources": [{ "type": "directory", "source": "block", "prefix": "block/" }] }
+/// "#).unwrap();
+///
+/// let atlas = AtlasStitcher::new(&pack).stitch(&config).unwrap();
+/// assert_eq!((atlas.width(), atlas.height()), (32, 32));
+///
+/// let stone = atlas.sprite("block/stone").unwrap();
+/// assert_eq!((stone.x, stone.y), (16, 0));
+/// assert_eq!((stone.u0, stone.v0, stone.u1, stone.v1), (0.5, 0.0, 1.0, 0.5));
+///
+/// // Only one frame of the animated texture is part of the atlas.
+/// let lantern = atlas.sprite("block/lantern").unwrap();
+/// assert_eq!((lantern.width, lantern.height, lantern.frame_count), (16, 16, 2));
+/// ```
+pub struct AtlasStitcher<'a> {
+    provider: &'a dyn ResourceProvider,
+    mipmap_levels: u32,
+    max_size: u32,
+}
+
+impl<'a> AtlasStitcher<'a> {
+    /// Returns a new [`AtlasStitcher`] that loads sprites from the given
+    /// provider, such as the provider of an [`AssetPack`] (i.e.,
+    /// `&*asset_pack`) or a [`LayeredResourceProvider`].
+    ///
+    /// [`AssetPack`]: crate::api::AssetPack
+    pub fn new(provider: &'a dyn ResourceProvider) -> Self {
+        Self {
+            provider,
+            mipmap_levels: 0,
+            max_size: 1 << 16,
+        }
+    }
+
+    /// Sets the number of mipmap levels and returns `self`. Defaults to `0`.
+    ///
+    /// Like in the game, the sides of the sprites are rounded up to multiples
+    /// of `2^levels` pixels when packing them, which spaces them out.
+    pub fn with_mipmap_levels(mut self, levels: u32) -> Self {
+        self.mipmap_levels = levels.min(16);
+        self
+    }
+
+    /// Sets the maximum width and height of the atlas image and returns
+    /// `self`. Defaults to `65536`.
+    ///
+    /// The game uses the maximum texture size of the graphics card.
+    pub fn with_max_size(mut self, size: u32) -> Self {
+        self.max_size = size;
+        self
+    }
+
+    /// Stitches the sprites listed by the given atlas configuration.
+    ///
+    /// Returns [`Error::AtlasTooLarge`] if the sprites do not fit into the
+    /// maximum size.
+    pub fn stitch(&self, config: &Atlas) -> Result<StitchedAtlas> {
+        let mut contents = Vec::new();
+        for (name, input) in self.list_sprites(config)? {
+            if let Some(sprite) = self.load_sprite(name, input)? {
+                contents.push(sprite);
+            }
+        }
+        contents.push(missing_sprite());
+
+        // See `net.minecraft.client.renderer.texture.Stitcher`.
+        contents.sort_by(|a, b| {
+            (b.height, b.width)
+                .cmp(&(a.height, a.width))
+                .then_with(|| compare_names(&a.name, &b.name))
+        });
+
+        let texel = 1 << self.mipmap_levels;
+        let holders: Vec<_> = contents
+            .iter()
+            .map(|sprite| {
+                (
+                    round_up(sprite.width, texel),
+                    round_up(sprite.height, texel),
+                )
+            })
+            .collect();
+        let Packing {
+            width,
+            height,
+            positions,
+        } = pack(&holders, self.max_size).ok_or(Error::AtlasTooLarge {
+            max_size: self.max_size,
+        })?;
+
+        // Sprites cannot be scaled down further than their smallest side or
+        // their lowest set bit.
+        let mipmap_levels = contents
+            .iter()
+            .map(|sprite| {
+                let (width, height) = (sprite.width, sprite.height);
+                width
+                    .min(height)
+                    .ilog2()
+                    .min(width.trailing_zeros())
+                    .min(height.trailing_zeros())
+            })
+            .fold(self.mipmap_levels, u32::min);
+
+        let mut atlas = StitchedAtlas {
+            image: RgbaImage::new(width, height),
+            mipmap_levels,
+            sprites: BTreeMap::new(),
+            animations: HashMap::new(),
+        };
+
+        for (sprite, (x, y)) in contents.into_iter().zip(positions) {
+            let first_frame = sprite
+                .animation
+                .as_ref()
+                .and_then(|animation| animation.frames.as_ref()?.first())
+                .map_or(0, |frame| frame.index());
+            let (frame_x, frame_y) = frame_origin(
+                sprite.image.width(),
+                sprite.width,
+                sprite.height,
+                first_frame,
+            );
+            let view =
+                imageops::crop_imm(&sprite.image, frame_x, frame_y, sprite.width, sprite.height);
+            imageops::replace(&mut atlas.image, &*view, x.into(), y.into());
+
+            let frame_count =
+                (sprite.image.width() / sprite.width) * (sprite.image.height() / sprite.height);
+            if sprite.animation.is_some() {
+                atlas.animations.insert(sprite.name.clone(), sprite.image);
+            }
+
+            atlas.sprites.insert(
+                sprite.name.clone(),
+                AtlasSprite {
+                    x,
+                    y,
+                    width: sprite.width,
+                    height: sprite.height,
+                    u0: x as f32 / width as f32,
+                    v0: y as f32 / height as f32,
+                    u1: (x + sprite.width) as f32 / width as f32,
+                    v1: (y + sprite.height) as f32 / height as f32,
+                    frame_count,
+                    animation: sprite.animation,
+                    name: sprite.name,
+                },
+            );
+        }
+
+        Ok(atlas)
+    }
+
+    /// Expands the sources of the given configuration into sprites, by name.
+    fn list_sprites(&self, config: &Atlas) -> Result<BTreeMap<String, SpriteInput>> {
+        let mut sprites = BTreeMap::new();
+        let mut textures = None;
+
+        for source in &config.sources {
+            match source {
+                AtlasSource::Directory { source, prefix } => {
+                    if textures.is_none() {
+                        textures = Some(self.provider.list(ResourceKind::Texture)?);
+                    }
+
+                    let directory = format!("{}/", source.trim_end_matches('/'));
+                    for texture in textures.iter().flatten() {
+                        if let Some(rest) = texture.path().strip_prefix(&directory) {
+                            let name = format!("{}:{}{}", texture.namespace(), prefix, rest);
+                            let location = format!("{}:{}", texture.namespace(), texture.path());
+                            sprites.insert(name, SpriteInput::Texture(location));
+                        }
+                    }
+                }
+                AtlasSource::Single { resource, sprite } => {
+                    let name = canonical(sprite.as_deref().unwrap_or(resource.as_str()));
+                    sprites.insert(name, SpriteInput::Texture(canonical(resource.as_str())));
+                }
+                AtlasSource::Filter { pattern } => {
+                    sprites.retain(|name: &String, _| {
+                        let (namespace, path) = name.split_once(':').unwrap_or(("", name));
+                        !pattern.matches(namespace, path)
+                    });
+                }
+                AtlasSource::Unstitch {
+                    resource,
+                    divisor_x,
+                    divisor_y,
+                    regions,
+                } => {
+                    for region in regions {
+                        let input = SpriteInput::Region {
+                            texture: canonical(resource.as_str()),
+                            x: region.x / divisor_x,
+                            y: region.y / divisor_y,
+                            width: region.width / divisor_x,
+                            height: region.height / divisor_y,
+                        };
+                        sprites.insert(canonical(&region.sprite), input);
+                    }
+                }
+                AtlasSource::PalettedPermutations {
+                    textures,
+                    palette_key,
+                    permutations,
+                } => {
+                    for texture in textures {
+                        for (suffix, palette) in permutations {
+                            let input = SpriteInput::Paletted {
+                                texture: canonical(texture.as_str()),
+                                key: canonical(palette_key.as_str()),
+                                palette: canonical(palette),
+                            };
+                            sprites.insert(canonical(&format!("{}_{}", texture, suffix)), input);
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(sprites)
+    }
+
+    /// Loads the contents of a sprite, or `None` if its texture is missing.
+    fn load_sprite(&self, name: String, input: SpriteInput) -> Result<Option<SpriteContents>> {
+        let (image, animation) = match input {
+            SpriteInput::Texture(location) => {
+                let image = match self.load_image(&location)? {
+                    Some(image) => image,
+                    None => return Ok(None),
+                };
+                let animation = self.load_animation(&location)?;
+                (image, animation)
+            }
+            SpriteInput::Region {
+                texture,
+                x,
+                y,
+                width,
+                height,
+            } => {
+                let image = match self.load_image(&texture)? {
+                    Some(image) => image,
+                    None => return Ok(None),
+                };
+
+                // The region is given as fractions of the texture's size.
+                let scale = |fraction: f64, size: u32| (fraction * size as f64).floor() as u32;
+                let (x, width) = (scale(x, image.width()), scale(width, image.width()));
+                let (y, height) = (scale(y, image.height()), scale(height, image.height()));
+                if x + width > image.width() || y + height > image.height() {
+                    return Err(invalid_sprite(&name, "region is outside of its texture"));
+                }
+
+                (
+                    imageops::crop_imm(&image, x, y, width, height).to_image(),
+                    None,
+                )
+            }
+            SpriteInput::Paletted {
+                texture,
+                key,
+                palette,
+            } => {
+                let (image, key, palette) = match (
+                    self.load_image(&texture)?,
+                    self.load_image(&key)?,
+                    self.load_image(&palette)?,
+                ) {
+                    (Some(image), Some(key), Some(palette)) => (image, key, palette),
+                    _ => return Ok(None),
+                };
+
+                (recolor(image, &key, &palette), None)
+            }
+        };
+
+        let (width, height) = frame_size(&image, animation.as_ref());
+        if width == 0 || height == 0 || image.width() % width != 0 || image.height() % height != 0 {
+            return Err(invalid_sprite(
+                &name,
+                "texture size is not a multiple of its frame size",
+            ));
+        }
+
+        Ok(Some(SpriteContents {
+            name,
+            width,
+            height,
+            image,
+            animation,
+        }))
+    }
+
+    fn load_image(&self, location: &str) -> Result<Option<RgbaImage>> {
+        match self
+            .provider
+            .load_resource(&ResourceIdentifier::texture(location))
+        {
+            Ok(bytes) => TextureDecoder::decode(&bytes).map(Some),
+            Err(e) if is_missing(&e) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn load_animation(&self, location: &str) -> Result<Option<TextureAnimation>> {
+        match self
+            .provider
+            .load_resource(&ResourceIdentifier::texture_meta(location))
+        {
+            Ok(bytes) => Ok(serde_json::from_slice::<Texture>(&bytes)?.animation),
+            Err(e) if is_missing(&e) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/// Where the image of a sprite comes from.
+enum SpriteInput {
+    /// A whole texture.
+    Texture(String),
+
+    /// A region of a texture, given as fractions of its size.
+    Region {
+        texture: String,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    },
+
+    /// A texture recolored with a palette.
+    Paletted {
+        texture: String,
+        key: String,
+        palette: String,
+    },
+}
+
+/// A loaded sprite, with the size of a single frame.
+struct SpriteContents {
+    name: String,
+    width: u32,
+    height: u32,
+    image: RgbaImage,
+    animation: Option<TextureAnimation>,
+}
+
+/// Returns the 16x16 black and magenta checkerboard of the missing sprite.
+fn missing_sprite() -> SpriteContents {
+    let image = RgbaImage::from_fn(16, 16, |x, y| {
+        if (x < 8) ^ (y < 8) {
+            Rgba([0xf8, 0x00, 0xf8, 0xff])
+        } else {
+            Rgba([0x00, 0x00, 0x00, 0xff])
+        }
+    });
+
+    SpriteContents {
+        name: String::from(MISSING_SPRITE),
+        width: 16,
+        height: 16,
+        image,
+        animation: None,
+    }
+}
+
+/// Returns the size of a frame of the given texture.
+pub(super) fn frame_size(image: &RgbaImage, animation: Option<&TextureAnimation>) -> (u32, u32) {
+    let (width, height) = image.dimensions();
+    match animation {
+        None => (width, height),
+        Some(animation) => match (animation.width, animation.height) {
+            (Some(frame_width), Some(frame_height)) => (frame_width, frame_height),
+            (Some(frame_width), None) => (frame_width, height),
+            (None, Some(frame_height)) => (width, frame_height),
+            (None, None) => (width.min(height), width.min(height)),
+        },
+    }
+}
+
+/// Returns the top-left corner of a frame in a texture, whose frames are
+/// ordered from left to right and then from top to bottom.
+pub(super) fn frame_origin(texture_width: u32, width: u32, height: u32, frame: u32) -> (u32, u32) {
+    let columns = (texture_width / width).max(1);
+    ((frame % columns) * width, (frame / columns) * height)
+}
+
+/// Replaces the colors of the palette key with those of the palette, like
+/// the `paletted_permutations` source of the game.
+fn recolor(mut image: RgbaImage, key: &RgbaImage, palette: &RgbaImage) -> RgbaImage {
+    if key.len() != palette.len() {
+        return image;
+    }
+
+    let mut colors = HashMap::new();
+    for (from, to) in key.pixels().zip(palette.pixels()) {
+        if from[3] != 0 {
+            colors.insert([from[0], from[1], from[2]], *to);
+        }
+    }
+
+    for pixel in image.pixels_mut() {
+        let alpha = pixel[3];
+        if alpha == 0 {
+            continue;
+        }
+
+        let rgb = [pixel[0], pixel[1], pixel[2]];
+        let to = colors
+            .get(&rgb)
+            .copied()
+            .unwrap_or(Rgba([rgb[0], rgb[1], rgb[2], 0xff]));
+        let to_alpha = (alpha as u32 * to[3] as u32 / 255) as u8;
+        *pixel = Rgba([to[0], to[1], to[2], to_alpha]);
+    }
+
+    image
+}
+
+/// Compares sprite names like `ResourceLocation`s: by path, then by
+/// namespace.
+fn compare_names(a: &str, b: &str) -> Ordering {
+    fn split(name: &str) -> (&str, &str) {
+        let (namespace, path) = name.split_once(':').unwrap_or(("", name));
+        (path, namespace)
+    }
+
+    split(a).cmp(&split(b))
+}
+
+fn round_up(size: u32, texel: u32) -> u32 {
+    size.div_ceil(texel) * texel
+}
+
+/// Returns the smallest power of two that is at least `size`, or `0` if
+/// `size` is `0`.
+fn smallest_power_of_two(size: u64) -> u64 {
+    if size == 0 {
+        0
+    } else {
+        size.next_power_of_two()
+    }
+}
+
+/// The result of [`pack()`].
+struct Packing {
+    width: u32,
+    height: u32,
+    /// The position of each rectangle, in order.
+    positions: Vec<(u32, u32)>,
+}
+
+/// Packs rectangles of the given sizes, in order, or returns `None` if they do
+/// not fit into the maximum size.
+///
+/// See `net.minecraft.client.renderer.texture.Stitcher`.
+fn pack(sizes: &[(u32, u32)], max_size: u32) -> Option<Packing> {
+    let max_size = u64::from(max_size);
+    let mut storage: Vec<Region> = Vec::new();
+    let (mut storage_width, mut storage_height) = (0u64, 0u64);
+
+    for (index, &(width, height)) in sizes.iter().enumerate() {
+        let holder = Holder {
+            index,
+            width: width.into(),
+            height: height.into(),
+        };
+        if storage.iter_mut().any(|region| region.add(&holder)) {
+            continue;
+        }
+
+        // Expand the atlas to the right or to the bottom.
+        let width_before = smallest_power_of_two(storage_width);
+        let height_before = smallest_power_of_two(storage_height);
+        let width_after = smallest_power_of_two(storage_width + holder.width);
+        let height_after = smallest_power_of_two(storage_height + holder.height);
+
+        let can_grow_width = width_after <= max_size;
+        let can_grow_height = height_after <= max_size;
+        if !can_grow_width && !can_grow_height {
+            return None;
+        }
+
+        let width_changes = can_grow_width && width_before != width_after;
+        let height_changes = can_grow_height && height_before != height_after;
+        let grow_width = if width_changes ^ height_changes {
+            width_changes
+        } else {
+            can_grow_width && width_before <= height_before
+        };
+
+        let mut region = if grow_width {
+            if storage_height == 0 {
+                storage_height = height_after;
+            }
+            let region = Region::new(
+                storage_width,
+                0,
+                width_after - storage_width,
+                storage_height,
+            );
+            storage_width = width_after;
+            region
+        } else {
+            let region = Region::new(
+                0,
+                storage_height,
+                storage_width,
+                height_after - storage_height,
+            );
+            storage_height = height_after;
+            region
+        };
+
+        region.add(&holder);
+        storage.push(region);
+    }
+
+    let mut positions = vec![(0, 0); sizes.len()];
+    for region in &storage {
+        region.walk(&mut |index, x, y| positions[index] = (x as u32, y as u32));
+    }
+
+    Some(Packing {
+        width: storage_width as u32,
+        height: storage_height as u32,
+        positions,
+    })
+}
+
+/// A rectangle to pack.
+struct Holder {
+    index: usize,
+    width: u64,
+    height: u64,
+}
+
+/// An area of the atlas that holds a single rectangle, or is split into
+/// smaller areas.
+struct Region {
+    x: u64,
+    y: u64,
+    width: u64,
+    height: u64,
+    holder: Option<usize>,
+    sub_regions: Option<Vec<Region>>,
+}
+
+impl Region {
+    fn new(x: u64, y: u64, width: u64, height: u64) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+            holder: None,
+            sub_regions: None,
+        }
+    }
+
+    fn add(&mut self, holder: &Holder) -> bool {
+        if self.holder.is_some() || holder.width > self.width || holder.height > self.height {
+            return false;
+        }
+        if holder.width == self.width && holder.height == self.height {
+            self.holder = Some(holder.index);
+            return true;
+        }
+
+        let (x, y) = (self.x, self.y);
+        let (width, height) = (self.width, self.height);
+        let sub_regions = self.sub_regions.get_or_insert_with(|| {
+            let mut sub_regions = vec![Region::new(x, y, holder.width, holder.height)];
+            let rest_width = width - holder.width;
+            let rest_height = height - holder.height;
+
+            if rest_width > 0 && rest_height > 0 {
+                if height.max(rest_width) >= width.max(rest_height) {
+                    sub_regions.push(Region::new(x, y + holder.height, holder.width, rest_height));
+                    sub_regions.push(Region::new(x + holder.width, y, rest_width, height));
+                } else {
+                    sub_regions.push(Region::new(x + holder.width, y, rest_width, holder.height));
+                    sub_regions.push(Region::new(x, y + holder.height, width, rest_height));
+                }
+            } else if rest_width == 0 {
+                sub_regions.push(Region::new(x, y + holder.height, holder.width, rest_height));
+            } else {
+                sub_regions.push(Region::new(x + holder.width, y, rest_width, holder.height));
+            }
+
+            sub_regions
+        });
+
+        sub_regions.iter_mut().any(|region| region.add(holder))
+    }
+
+    /// Calls the given function with the index and position of every packed
+    /// rectangle.
+    fn walk(&self, f: &mut impl FnMut(usize, u64, u64)) {
+        if let Some(index) = self.holder {
+            f(index, self.x, self.y);
+        }
+        for region in self.sub_regions.iter().flatten() {
+            region.walk(f);
+        }
+    }
+}
+
+fn invalid_sprite(name: &str, message: &str) -> Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("invalid sprite {}: {}", name, message),
+    )
+    .into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::api::MemoryResourceProvider;
+
+    fn png(width: u32, height: u32, pixel: [u8; 4]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        let mut encoder = png::Encoder::new(&mut bytes, width, height);
+        encoder.set_color(png::ColorType::Rgba);
+        let mut writer = encoder.write_header().unwrap();
+        let data: Vec<u8> = (0..width * height).flat_map(|_| pixel).collect();
+        writer.write_image_data(&data).unwrap();
+        drop(writer);
+        bytes
+    }
+
+    #[test]
+    fn packs_like_the_game() {
+        let sizes = [(32, 32), (16, 16), (16, 16), (16, 16), (16, 8)];
+        let packing = pack(&sizes, 1024).unwrap();
+
+        assert_eq!((packing.width, packing.height), (64, 32));
+        assert_eq!(
+            packing.positions,
+            [(0, 0), (32, 0), (32, 16), (48, 0), (48, 16)]
+        );
+
+        assert!(pack(&[(64, 64)], 32).is_none());
+    }
+
+    #[test]
+    fn sources_are_expanded_in_order() {
+        let mut pack = MemoryResourceProvider::new();
+        let white = [0xff, 0xff, 0xff, 0xff];
+        pack.insert("assets/minecraft/textures/block/a.png", png(16, 16, white));
+        pack.insert(
+            "assets/minecraft/textures/block/debug.png",
+            png(16, 16, white),
+        );
+        pack.insert("assets/minecraft/textures/sheet.png", png(32, 16, white));
+        pack.insert(
+            "assets/minecraft/textures/trim.png",
+            png(16, 16, [1, 2, 3, 0x80]),
+        );
+        pack.insert(
+            "assets/minecraft/textures/key.png",
+            png(1, 1, [1, 2, 3, 0xff]),
+        );
+        pack.insert(
+            "assets/minecraft/textures/gold.png",
+            png(1, 1, [9, 9, 9, 0xff]),
+        );
+
+        let config: Atlas = serde_json::from_str(
+            r#"{ "sources": [
+                { "type": "directory", "source": "block", "prefix": "b/" },
+                { "type": "filter", "pattern": { "path": "b/debug" } },
+                { "type": "single", "resource": "missing" },
+                { "type": "unstitch", "resource": "sheet", "divisor_x": 2, "divisor_y": 1,
+                  "regions": [{ "sprite": "right", "x": 1, "y": 0, "width": 1, "height": 1 }] },
+                { "type": "paletted_permutations", "textures": ["trim"], "palette_key": "key",
+                  "permutations": { "gold": "gold" } }
+            ] }"#,
+        )
+        .unwrap();
+
+        let atlas = AtlasStitcher::new(&pack).stitch(&config).unwrap();
+        let names: Vec<_> = atlas.sprites().map(|sprite| sprite.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "minecraft:b/a",
+                "minecraft:missingno",
+                "minecraft:right",
+                "minecraft:trim_gold"
+            ]
+        );
+
+        let trim = atlas.sprite("trim_gold").unwrap();
+        assert_eq!(atlas.image().get_pixel(trim.x, trim.y).0, [9, 9, 9, 0x80]);
+    }
+}
@@ -0,0 +1,457 @@
+//! Reading Bedrock Edition resource packs, e.g., for tools that convert packs
+//! between the Java and Bedrock editions.
+//!
+//! Start here: [`BedrockPack`].
+//!
+//! Bedrock packs have no namespaces or kinds of resources: their files are
+//! found at fixed paths from the root of the pack, which any provider can
+//! load as root files (see [`LoadResource::load_root_file()`]). The schemas of
+//! these files are in the [`schemas::bedrock`] module.
+//!
+//! The paths of textures differ between the two editions, e.g.,
+//! `block/oak_planks` in Java is `textures/blocks/planks_oak` in Bedrock.
+//! [`java_to_bedrock_texture()`] and [`bedrock_to_java_texture()`] map them on
+//! a best-effort basis: the directories of blocks, items, entities, etc. are
+//! mapped, and so are the names of the most common blocks and items that were
+//! renamed. Other names are kept as they are, so the result is not
+//! guaranteed to exist in the other edition.
+//!
+//! [`LoadResource::load_root_file()`]: crate::api::LoadResource::load_root_file
+//! [`schemas::bedrock`]: crate::schemas::bedrock
+
+use std::path::Path;
+
+use crate::{
+    api::{
+        parse_error::parse_root_file, provider::is_missing, FileSystemResourceProvider,
+        ResourceLocation, ResourceProvider, Result, MINECRAFT_NAMESPACE,
+    },
+    schemas::bedrock::{geometry::Geometry, BlocksJson, GeometryFile, Manifest, TextureAtlas},
+};
+
+/// API for accessing the files of a Bedrock Edition resource pack.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::api::*;
+/// use minecraft_assets::api::bedrock::{bedrock_to_java_texture, BedrockPack};
+/// use minecraft_assets::schemas::models::BlockFace;
+///
+/// let mut provider = MemoryResourceProvider::new();
+/// provider.insert("blocks.json", br#"{ "planks": { "textures": "planks" } }"#.to_vec());
+/// provider.insert(
+///     "textures/terrain_texture.json",
+///     br#"{
+///         "texture_name": "atlas.terrain",
+///         "texture_data": {
+///             "planks": { "textures": ["textures/blocks/planks_oak", "textures/blocks/planks_spruce"] }
+///         }
+///     }"#
+///     .to_vec(),
+/// );
+///
+/// let pack = BedrockPack::new(provider);
+/// let blocks = pack.load_blocks().unwrap();
+/// let terrain = pack.load_terrain_texture().unwrap();
+///
+/// let name = blocks.blocks["planks"].textures.as_ref().unwrap().face(BlockFace::Up).unwrap();
+/// let path = terrain.path(name).unwrap();
+/// assert_eq!(path, "textures/blocks/planks_oak");
+///
+/// let java = bedrock_to_java_texture(path).unwrap();
+/// assert_eq!(java.as_str(), "block/oak_planks");
+/// ```
+pub struct BedrockPack {
+    provider: Box<dyn ResourceProvider>,
+}
+
+impl BedrockPack {
+    /// Returns a new [`BedrockPack`] that can read data from the given
+    /// directory, which contains the `manifest.json` of the pack.
+    pub fn at_path(root_dir: impl AsRef<Path>) -> Self {
+        Self::new(FileSystemResourceProvider::new(root_dir))
+    }
+
+    /// Returns a new [`BedrockPack`] that uses the given [`ResourceProvider`],
+    /// e.g., a `ZipResourceProvider` for a `.mcpack` archive.
+    pub fn new(provider: impl ResourceProvider + 'static) -> Self {
+        Self {
+            provider: Box::new(provider),
+        }
+    }
+
+    /// Returns `true` if the pack has a `manifest.json` file, i.e., it looks
+    /// like a Bedrock pack.
+    pub fn has_manifest(&self) -> Result<bool> {
+        match self.provider.load_root_file("manifest.json") {
+            Ok(_) => Ok(true),
+            Err(e) if is_missing(&e) => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Loads the `manifest.json` file of the pack.
+    pub fn load_manifest(&self) -> Result<Manifest> {
+        self.load_json("manifest.json")
+    }
+
+    /// Loads the `blocks.json` file of the pack.
+    pub fn load_blocks(&self) -> Result<BlocksJson> {
+        self.load_json("blocks.json")
+    }
+
+    /// Loads the atlas of the textures of blocks, in
+    /// `textures/terrain_texture.json`.
+    pub fn load_terrain_texture(&self) -> Result<TextureAtlas> {
+        self.load_json("textures/terrain_texture.json")
+    }
+
+    /// Loads the atlas of the textures of items, in
+    /// `textures/item_texture.json`.
+    pub fn load_item_texture(&self) -> Result<TextureAtlas> {
+        self.load_json("textures/item_texture.json")
+    }
+
+    /// Loads the geometries of the `.geo.json` file at the given path from the
+    /// root of the pack, e.g., `models/entity/cow.geo.json`.
+    ///
+    /// Geometries in the legacy format are converted into the current format
+    /// (see [`GeometryFile::into_geometries()`]).
+    pub fn load_geometry(&self, path: &str) -> Result<Vec<Geometry>> {
+        self.load_json::<GeometryFile>(path)
+            .map(GeometryFile::into_geometries)
+    }
+
+    /// Loads the raw bytes of the texture at the given path from the root of
+    /// the pack, without its extension, as found in a [`TextureAtlas`].
+    ///
+    /// Like the game, the `.png` file is loaded if there is one, and the
+    /// `.tga` file otherwise. Returns the path of the file that was loaded,
+    /// with its extension, and its bytes.
+    pub fn load_texture_bytes(&self, path: &str) -> Result<(String, Vec<u8>)> {
+        let png = format!("{}.png", path);
+        match self.provider.load_root_file(&png) {
+            Ok(bytes) => return Ok((png, bytes)),
+            Err(e) if is_missing(&e) => {}
+            Err(e) => return Err(e.into()),
+        }
+
+        let tga = format!("{}.tga", path);
+        let bytes = self.provider.load_root_file(&tga)?;
+        Ok((tga, bytes))
+    }
+
+    /// Loads the raw bytes of the file at the given path from the root of the
+    /// pack.
+    pub fn load_bytes(&self, path: &str) -> Result<Vec<u8>> {
+        Ok(self.provider.load_root_file(path)?)
+    }
+
+    fn load_json<T>(&self, path: &str) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let bytes = self.provider.load_root_file(path)?;
+        Ok(parse_root_file(path, &bytes)?)
+    }
+}
+
+/// The directories of textures in Java (relative to `textures/`) and in
+/// Bedrock (relative to the root of the pack).
+const DIRECTORIES: &[(&str, &str)] = &[
+    ("block", "textures/blocks"),
+    ("item", "textures/items"),
+    ("entity", "textures/entity"),
+    ("environment", "textures/environment"),
+    ("painting", "textures/painting"),
+    ("particle", "textures/particle"),
+    ("misc", "textures/misc"),
+    ("map", "textures/map"),
+    ("colormap", "textures/colormap"),
+    ("models/armor", "textures/models/armor"),
+];
+
+/// The names of the textures of blocks that differ, in Java and in Bedrock.
+const RENAMED_BLOCKS: &[(&str, &str)] = &[
+    ("grass_block_top", "grass_top"),
+    ("granite", "stone_granite"),
+    ("diorite", "stone_diorite"),
+    ("andesite", "stone_andesite"),
+    ("polished_granite", "stone_granite_smooth"),
+    ("polished_diorite", "stone_diorite_smooth"),
+    ("polished_andesite", "stone_andesite_smooth"),
+    ("stone_bricks", "stonebrick"),
+    ("mossy_stone_bricks", "stonebrick_mossy"),
+    ("cracked_stone_bricks", "stonebrick_cracked"),
+    ("chiseled_stone_bricks", "stonebrick_carved"),
+    ("mossy_cobblestone", "cobblestone_mossy"),
+    ("bricks", "brick"),
+    ("terracotta", "hardened_clay"),
+    ("nether_quartz_ore", "quartz_ore"),
+    ("sugar_cane", "reeds"),
+];
+
+/// The names of the textures of items that differ, in Java and in Bedrock.
+const RENAMED_ITEMS: &[(&str, &str)] = &[
+    ("beef", "beef_raw"),
+    ("cooked_beef", "beef_cooked"),
+    ("porkchop", "porkchop_raw"),
+    ("cooked_porkchop", "porkchop_cooked"),
+    ("chicken", "chicken_raw"),
+    ("cooked_chicken", "chicken_cooked"),
+];
+
+/// The names of the colors of dyed blocks, in Java and in Bedrock.
+const COLORS: &[(&str, &str)] = &[
+    ("white", "white"),
+    ("orange", "orange"),
+    ("magenta", "magenta"),
+    ("light_blue", "light_blue"),
+    ("yellow", "yellow"),
+    ("lime", "lime"),
+    ("pink", "pink"),
+    ("gray", "gray"),
+    ("light_gray", "silver"),
+    ("cyan", "cyan"),
+    ("purple", "purple"),
+    ("blue", "blue"),
+    ("brown", "brown"),
+    ("green", "green"),
+    ("red", "red"),
+    ("black", "black"),
+];
+
+/// The names of the kinds of wood, in Java and in Bedrock.
+const WOODS: &[(&str, &str)] = &[
+    ("oak", "oak"),
+    ("spruce", "spruce"),
+    ("birch", "birch"),
+    ("jungle", "jungle"),
+    ("acacia", "acacia"),
+    ("dark_oak", "big_oak"),
+];
+
+/// The names of the materials of tools, in Java and in Bedrock.
+const TOOL_MATERIALS: &[(&str, &str)] = &[("wooden", "wood"), ("golden", "gold")];
+
+/// The tools that are named after their material.
+const TOOLS: &[&str] = &["sword", "shovel", "pickaxe", "axe", "hoe"];
+
+/// Returns the Java and Bedrock names of the textures of blocks that differ.
+fn block_renames() -> impl Iterator<Item = (String, String)> {
+    let fixed = RENAMED_BLOCKS
+        .iter()
+        .map(|&(java, bedrock)| (java.to_string(), bedrock.to_string()));
+
+    let dyed = COLORS.iter().flat_map(|&(java, bedrock)| {
+        [
+            (
+                format!("{}_wool", java),
+                format!("wool_colored_{}", bedrock),
+            ),
+            (
+                format!("{}_concrete", java),
+                format!("concrete_{}", bedrock),
+            ),
+            (
+                format!("{}_concrete_powder", java),
+                format!("concrete_powder_{}", bedrock),
+            ),
+            (
+                format!("{}_stained_glass", java),
+                format!("glass_{}", bedrock),
+            ),
+            (
+                format!("{}_terracotta", java),
+                format!("hardened_clay_stained_{}", bedrock),
+            ),
+        ]
+    });
+
+    let wooden = WOODS.iter().flat_map(|&(java, bedrock)| {
+        [
+            (format!("{}_planks", java), format!("planks_{}", bedrock)),
+            (format!("{}_log", java), format!("log_{}", bedrock)),
+            (format!("{}_log_top", java), format!("log_{}_top", bedrock)),
+            (format!("{}_leaves", java), format!("leaves_{}", bedrock)),
+        ]
+    });
+
+    fixed.chain(dyed).chain(wooden)
+}
+
+/// Returns the Java and Bedrock names of the textures of items that differ.
+fn item_renames() -> impl Iterator<Item = (String, String)> {
+    let fixed = RENAMED_ITEMS
+        .iter()
+        .map(|&(java, bedrock)| (java.to_string(), bedrock.to_string()));
+
+    let tools = TOOL_MATERIALS.iter().flat_map(|&(java, bedrock)| {
+        TOOLS.iter().map(move |tool| {
+            (
+                format!("{}_{}", java, tool),
+                format!("{}_{}", bedrock, tool),
+            )
+        })
+    });
+
+    fixed.chain(tools)
+}
+
+/// Returns the path of the Bedrock texture (from the root of the pack,
+/// without its extension) that corresponds to the given Java texture
+/// location, e.g., `textures/blocks/planks_oak` for `block/oak_planks`.
+///
+/// Returns `None` if the location is invalid, is not in the `minecraft`
+/// namespace, or is in a directory that has no counterpart in Bedrock. See
+/// the [module documentation](self) for the limits of the mapping.
+///
+/// # Example
+///
+/// ```
+/// use minecraft_assets::api::bedrock::java_to_bedrock_texture;
+///
+/// let path = java_to_bedrock_texture("minecraft:block/light_gray_wool");
+/// assert_eq!(path.as_deref(), Some("textures/blocks/wool_colored_silver"));
+///
+/// let path = java_to_bedrock_texture("item/diamond");
+/// assert_eq!(path.as_deref(), Some("textures/items/diamond"));
+///
+/// assert_eq!(java_to_bedrock_texture("mymod:block/ruby"), None);
+/// ```
+pub fn java_to_bedrock_texture(location: &str) -> Option<String> {
+    let location = ResourceLocation::new(location).ok()?;
+    if location.namespace() != MINECRAFT_NAMESPACE {
+        return None;
+    }
+
+    let (java_dir, bedrock_dir, name) = DIRECTORIES.iter().find_map(|&(java, bedrock)| {
+        let name = location.path().strip_prefix(java)?.strip_prefix('/')?;
+        Some((java, bedrock, name))
+    })?;
+
+    let renamed = match java_dir {
+        "block" => block_renames().find(|(java, _)| java == name),
+        "item" => item_renames().find(|(java, _)| java == name),
+        _ => None,
+    };
+    let name = renamed.map_or_else(|| name.to_string(), |(_, bedrock)| bedrock);
+
+    Some(format!("{}/{}", bedrock_dir, name))
+}
+
+/// Returns the Java texture location that corresponds to the given path of a
+/// Bedrock texture (from the root of the pack, with or without its
+/// extension), e.g., `block/oak_planks` for `textures/blocks/planks_oak`.
+///
+/// Returns `None` if the path is in a directory that has no counterpart in
+/// Java. See the [module documentation](self) for the limits of the mapping.
+///
+/// # Example
+///
+/// ```
+/// use minecraft_assets::api::bedrock::bedrock_to_java_texture;
+///
+/// let location = bedrock_to_java_texture("textures/blocks/log_big_oak_top.png").unwrap();
+/// assert_eq!(location.as_str(), "block/dark_oak_log_top");
+///
+/// let location = bedrock_to_java_texture("textures/items/gold_pickaxe").unwrap();
+/// assert_eq!(location.as_str(), "item/golden_pickaxe");
+///
+/// assert!(bedrock_to_java_texture("textures/ui/hotbar").is_none());
+/// ```
+pub fn bedrock_to_java_texture(path: &str) -> Option<ResourceLocation<'static>> {
+    let path = path
+        .strip_suffix(".png")
+        .or_else(|| path.strip_suffix(".tga"))
+        .unwrap_or(path);
+
+    let (java_dir, name) = DIRECTORIES.iter().find_map(|&(java, bedrock)| {
+        let name = path.strip_prefix(bedrock)?.strip_prefix('/')?;
+        Some((java, name))
+    })?;
+
+    let renamed = match java_dir {
+        "block" => block_renames().find(|(_, bedrock)| bedrock == name),
+        "item" => item_renames().find(|(_, bedrock)| bedrock == name),
+        _ => None,
+    };
+    let name = renamed.map_or_else(|| name.to_string(), |(java, _)| java);
+
+    ResourceLocation::new_owned(format!("{}/{}", java_dir, name)).ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::api::{Error, MemoryResourceProvider};
+
+    #[test]
+    fn maps_texture_paths_both_ways() {
+        for (java, bedrock) in [
+            ("block/stone", "textures/blocks/stone"),
+            ("block/oak_planks", "textures/blocks/planks_oak"),
+            ("block/dark_oak_log", "textures/blocks/log_big_oak"),
+            (
+                "block/light_gray_wool",
+                "textures/blocks/wool_colored_silver",
+            ),
+            (
+                "block/red_terracotta",
+                "textures/blocks/hardened_clay_stained_red",
+            ),
+            ("item/beef", "textures/items/beef_raw"),
+            ("item/wooden_sword", "textures/items/wood_sword"),
+            ("entity/cow/cow", "textures/entity/cow/cow"),
+            (
+                "models/armor/iron_layer_1",
+                "textures/models/armor/iron_layer_1",
+            ),
+        ] {
+            assert_eq!(java_to_bedrock_texture(java).as_deref(), Some(bedrock));
+            assert_eq!(bedrock_to_java_texture(bedrock).unwrap().as_str(), java);
+        }
+
+        assert_eq!(java_to_bedrock_texture("gui/title/minecraft"), None);
+        assert_eq!(java_to_bedrock_texture("blockstone"), None);
+        assert!(bedrock_to_java_texture("blocks/stone").is_none());
+    }
+
+    #[test]
+    fn loads_files_from_the_root_of_the_pack() {
+        let mut provider = MemoryResourceProvider::new();
+        provider.insert(
+            "manifest.json",
+            br#"{
+                "format_version": 2,
+                "header": { "name": "Test", "uuid": "a", "version": [1, 0, 0] },
+                "modules": [{ "type": "resources", "uuid": "b", "version": [1, 0, 0] }]
+            }"#
+            .to_vec(),
+        );
+        provider.insert("textures/blocks/stone.tga", b"tga".to_vec());
+        provider.insert(
+            "models/entity/broken.geo.json",
+            br#"{ "format_version": "1.12.0", "minecraft:geometry": [{ "bones": [] }] }"#.to_vec(),
+        );
+
+        let pack = BedrockPack::new(provider);
+        assert!(pack.has_manifest().unwrap());
+        assert!(pack.load_manifest().unwrap().is_resource_pack());
+
+        let (path, bytes) = pack.load_texture_bytes("textures/blocks/stone").unwrap();
+        assert_eq!(path, "textures/blocks/stone.tga");
+        assert_eq!(bytes, b"tga");
+
+        let Err(Error::ResourceParseError(error)) =
+            pack.load_geometry("models/entity/broken.geo.json")
+        else {
+            panic!("expected a parse error");
+        };
+        assert_eq!(error.path(), "models/entity/broken.geo.json");
+        assert_eq!(error.json_path(), "/minecraft:geometry/0");
+
+        assert!(matches!(pack.load_blocks(), Err(Error::IoError(_))));
+    }
+}
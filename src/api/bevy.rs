@@ -0,0 +1,187 @@
+//! Loading resources as [Bevy] assets.
+//!
+//! Start here: [`MinecraftAssetsPlugin`].
+//!
+//! This module requires the `bevy` feature.
+//!
+//! The plugin registers the [`Model`], [`BlockStates`], and [`CustomeBiome`]
+//! schemas as Bevy assets, with a [`JsonAssetLoader`] for each of them. They
+//! are all `.json` files, so Bevy picks the loader from the type of the asset
+//! that is requested. The asset paths are relative to the root of the pack,
+//! e.g., `assets/minecraft/models/block/stone.json`, so that a pack can be
+//! mounted as an asset source. Files that fail to parse are reported with a
+//! [`ResourceParseError`].
+//!
+//! Providers are not required to be [`Send`], so an [`AssetPack`] is used as
+//! a non-send resource, e.g., for enumerating resources or for loading kinds
+//! that have no loader. Systems access it through [`NonSend`].
+//!
+//! [Bevy]: https://bevyengine.org
+//! [`AssetPack`]: crate::api::AssetPack
+//! [`NonSend`]: bevy::ecs::system::NonSend
+//!
+//! # Example
+//!
+//! ```no_run
+//! use bevy::{app::prelude::*, asset::prelude::*, ecs::prelude::*};
+//! use minecraft_assets::{
+//!     api::{bevy::MinecraftAssetsPlugin, AssetPack, ResourceIdentifier, ResourceKind},
+//!     schemas::Model,
+//! };
+//!
+//! #[derive(Resource)]
+//! struct Models(Vec<Handle<Model>>);
+//!
+//! fn load_models(mut commands: Commands, pack: NonSend<AssetPack>, server: Res<AssetServer>) {
+//!     let ids = pack.list(ResourceKind::BlockModel).unwrap();
+//!     let handles = ids.iter().map(|id| server.load(id.pack_path())).collect();
+//!     commands.insert_resource(Models(handles));
+//! }
+//!
+//! App::new()
+//!     .add_plugins((TaskPoolPlugin::default(), AssetPlugin::default(), MinecraftAssetsPlugin))
+//!     .insert_non_send_resource(AssetPack::at_path("assets/"))
+//!     .add_systems(Startup, load_models)
+//!     .run();
+//! ```
+
+use std::marker::PhantomData;
+
+use bevy::{
+    app::{App, Plugin},
+    asset::{io::Reader, Asset, AssetApp, AssetLoader, LoadContext},
+    reflect::TypePath,
+};
+use serde::de::DeserializeOwned;
+
+use crate::{
+    api::{parse_error::parse_json, Error, ResourceParseError},
+    schemas::{worldgen::biome::CustomeBiome, BlockStates, Model},
+};
+
+/// A Bevy plugin that loads the [`Model`], [`BlockStates`], and
+/// [`CustomeBiome`] schemas as assets.
+///
+/// See the [module documentation](self).
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MinecraftAssetsPlugin;
+
+impl Plugin for MinecraftAssetsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<Model>()
+            .init_asset::<BlockStates>()
+            .init_asset::<CustomeBiome>()
+            .register_asset_loader(JsonAssetLoader::<Model>::new())
+            .register_asset_loader(JsonAssetLoader::<BlockStates>::new())
+            .register_asset_loader(JsonAssetLoader::<CustomeBiome>::new());
+    }
+}
+
+/// A Bevy [`AssetLoader`] that parses `.json` files into a schema.
+#[derive(TypePath)]
+pub struct JsonAssetLoader<T> {
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> JsonAssetLoader<T> {
+    /// Returns a new loader.
+    pub fn new() -> Self {
+        Self {
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for JsonAssetLoader<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> AssetLoader for JsonAssetLoader<T>
+where
+    T: Asset + DeserializeOwned,
+{
+    type Asset = T;
+    type Settings = ();
+    type Error = Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<T, Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        let path = load_context
+            .path()
+            .path()
+            .to_string_lossy()
+            .replace('\\', "/");
+        Ok(parse_json(&bytes).map_err(|e| ResourceParseError::for_file(&path, e))?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["json"]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use bevy::{
+        app::TaskPoolPlugin,
+        asset::{AssetPlugin, AssetServer, Assets, LoadState},
+    };
+
+    use super::*;
+
+    #[test]
+    fn loads_schemas_by_asset_type() {
+        let root = std::env::temp_dir().join("minecraft-assets-bevy-test");
+        let _ = fs::remove_dir_all(&root);
+        let models = root.join("assets/minecraft/models/block");
+        fs::create_dir_all(&models).unwrap();
+        fs::write(
+            models.join("stone.json"),
+            r#"{ "parent": "block/cube_all" }"#,
+        )
+        .unwrap();
+        fs::write(models.join("broken.json"), r#"{ "parent": 3 }"#).unwrap();
+
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin {
+                file_path: root.to_string_lossy().into_owned(),
+                ..Default::default()
+            },
+            MinecraftAssetsPlugin,
+        ));
+
+        let server = app.world().resource::<AssetServer>().clone();
+        let stone = server.load::<Model>("assets/minecraft/models/block/stone.json");
+        let broken = server.load::<Model>("assets/minecraft/models/block/broken.json");
+
+        for _ in 0..1000 {
+            app.update();
+            let loading = |handle| matches!(server.load_state(handle), LoadState::Loading);
+            if !loading(&stone) && !loading(&broken) {
+                break;
+            }
+        }
+
+        let model = app.world().resource::<Assets<Model>>().get(&stone).unwrap();
+        assert_eq!(model.parent.as_ref().unwrap(), "block/cube_all");
+
+        let LoadState::Failed(error) = server.load_state(&broken) else {
+            panic!("expected the broken model to fail");
+        };
+        assert!(error.to_string().contains("/parent"), "{}", error);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
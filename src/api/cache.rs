@@ -0,0 +1,341 @@
+use std::{
+    any::{Any, TypeId},
+    collections::{BTreeMap, HashMap},
+    ops::Deref,
+    sync::{Arc, Mutex},
+};
+
+use serde::de::DeserializeOwned;
+
+use crate::{
+    api::{AssetPack, ModelResolver, ResourceIdentifier, Result},
+    schemas::{texture::Texture, BlockStates, Model},
+};
+
+/// A wrapper around an [`AssetPack`] that memoizes parsed resources.
+///
+/// Renderers tend to load the same parent models, blockstates, and textures
+/// over and over again. This type keeps the most recently used resources in a
+/// cache of configurable size and hands out shared [`Arc`]s to them, evicting
+/// the least recently used resource when the cache is full.
+///
+/// The cache is keyed by the [`ResourceIdentifier`] (i.e., both the kind and
+/// the id of the resource) and the type that the resource was parsed into. A
+/// resolved model is cached separately from the model it was resolved from.
+///
+/// Resources that were not loaded successfully are not cached. Methods of the
+/// wrapped [`AssetPack`] that have no cached counterpart can be called through
+/// [`Deref`].
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::api::*;
+/// use std::sync::Arc;
+///
+/// let mut provider = MemoryResourceProvider::new();
+/// provider.insert(
+///     "assets/minecraft/models/block/cube_all.json",
+///     br##"{ "parent": "block/cube", "textures": { "particle": "#all" } }"##.to_vec(),
+/// );
+/// provider.insert(
+///     "assets/minecraft/models/block/cube.json",
+///     br#"{ "elements": [] }"#.to_vec(),
+/// );
+///
+/// let assets = CachedAssetPack::new(AssetPack::new(provider), 128);
+///
+/// let first = assets.load_block_model("cube_all").unwrap();
+/// let second = assets.load_block_model("block/cube_all").unwrap();
+/// assert!(Arc::ptr_eq(&first, &second));
+///
+/// // Resolving the model also caches its parent.
+/// let resolved = assets.load_block_model_resolved("cube_all").unwrap();
+/// assert_eq!(resolved.elements, Some(vec![]));
+/// assert_eq!(assets.len(), 3);
+/// ```
+pub struct CachedAssetPack {
+    pack: AssetPack,
+    cache: Mutex<Lru>,
+}
+
+/// Distinguishes resolved models from the models they were resolved from in
+/// the cache.
+struct Resolved;
+
+impl CachedAssetPack {
+    /// Returns a new [`CachedAssetPack`] that caches up to `capacity` parsed
+    /// resources of the given [`AssetPack`].
+    ///
+    /// A capacity of 0 disables the cache.
+    pub fn new(pack: AssetPack, capacity: usize) -> Self {
+        Self {
+            pack,
+            cache: Mutex::new(Lru::new(capacity)),
+        }
+    }
+
+    /// Returns the maximum number of resources held by the cache.
+    pub fn capacity(&self) -> usize {
+        self.lock().capacity
+    }
+
+    /// Returns the number of resources currently held by the cache.
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// Returns `true` if the cache holds no resources.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes every resource from the cache, e.g., after the underlying files
+    /// have changed.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Removes the resource referenced by the given [`ResourceIdentifier`] from
+    /// the cache, e.g., after its file has changed.
+    ///
+    /// Since a model may be the parent of any other model, invalidating a model
+    /// also removes every resolved model from the cache.
+    pub fn invalidate(&self, id: &ResourceIdentifier) {
+        let id = id.to_canonical();
+        let resolved = TypeId::of::<Resolved>();
+
+        self.lock()
+            .retain(|(key, tag)| *key != id && !(id.is_model() && *tag == resolved));
+    }
+
+    /// Consumes `self` and returns the wrapped [`AssetPack`].
+    pub fn into_inner(self) -> AssetPack {
+        self.pack
+    }
+
+    /// Loads the JSON resource referenced by the given [`ResourceIdentifier`]
+    /// into the given type, or returns the cached value if it was loaded
+    /// before.
+    ///
+    /// This can be used to cache any JSON resource, including those without a
+    /// dedicated method.
+    pub fn load<T>(&self, id: &ResourceIdentifier) -> Result<Arc<T>>
+    where
+        T: DeserializeOwned + Send + Sync + 'static,
+    {
+        self.cached::<T, T, _>(id, || self.pack.load_resource(id))
+    }
+
+    /// Loads the [`BlockStates`] of the block with the provided id.
+    pub fn load_blockstates(&self, block_id: &str) -> Result<Arc<BlockStates>> {
+        self.load(&ResourceIdentifier::blockstates(block_id))
+    }
+
+    /// Loads the block [`Model`] identified by the given name or path.
+    pub fn load_block_model(&self, model: &str) -> Result<Arc<Model>> {
+        self.load(&ResourceIdentifier::block_model(model))
+    }
+
+    /// Loads the block [`Model`] identified by the given name or path and all
+    /// of its parents, and returns the fully resolved model.
+    ///
+    /// Both the resolved model and every model of its chain of parents are
+    /// cached.
+    pub fn load_block_model_resolved(&self, model: &str) -> Result<Arc<Model>> {
+        self.load_model_resolved(&ResourceIdentifier::block_model(model))
+    }
+
+    /// Loads the item [`Model`] identified by the given name or path.
+    pub fn load_item_model(&self, model: &str) -> Result<Arc<Model>> {
+        self.load(&ResourceIdentifier::item_model(model))
+    }
+
+    /// Loads the item [`Model`] identified by the given name or path and all
+    /// of its parents, and returns the fully resolved model.
+    ///
+    /// Both the resolved model and every model of its chain of parents are
+    /// cached.
+    pub fn load_item_model_resolved(&self, model: &str) -> Result<Arc<Model>> {
+        self.load_model_resolved(&ResourceIdentifier::item_model(model))
+    }
+
+    /// Loads the [`Texture`] metadata (`.png.mcmeta`) of the texture located
+    /// at the given path.
+    pub fn load_texture_meta(&self, path: &str) -> Result<Arc<Texture>> {
+        self.load(&ResourceIdentifier::texture_meta(path))
+    }
+
+    /// Loads and decodes the texture located at the given path.
+    ///
+    /// This method requires the `image` feature.
+    ///
+    /// See [`AssetPack::load_texture_image()`].
+    #[cfg(feature = "image")]
+    pub fn load_texture_image(&self, path: &str) -> Result<Arc<image::RgbaImage>> {
+        let id = ResourceIdentifier::texture(path);
+        self.cached::<image::RgbaImage, _, _>(&id, || self.pack.load_texture_image(path))
+    }
+
+    fn load_model_resolved(&self, id: &ResourceIdentifier) -> Result<Arc<Model>> {
+        self.cached::<Resolved, _, _>(id, || {
+            ModelResolver::load_resolved(id, |parent| {
+                self.load::<Model>(parent).map(|model| Model::clone(&model))
+            })
+        })
+    }
+
+    /// Returns the cached value for the given id and tag type, or loads and
+    /// caches it.
+    ///
+    /// The cache is not locked while loading, since loading a value may load
+    /// other values (e.g., the parents of a model).
+    fn cached<Tag, T, F>(&self, id: &ResourceIdentifier, load: F) -> Result<Arc<T>>
+    where
+        Tag: 'static,
+        T: Send + Sync + 'static,
+        F: FnOnce() -> Result<T>,
+    {
+        // Different spellings of the same id (e.g., `stone` and
+        // `minecraft:block/stone`) share one entry.
+        let key = (id.to_canonical().to_owned(), TypeId::of::<Tag>());
+
+        if let Some(value) = self.lock().get(&key) {
+            if let Ok(value) = value.downcast::<T>() {
+                return Ok(value);
+            }
+        }
+
+        let value = Arc::new(load()?);
+        self.lock().insert(key, value.clone());
+        Ok(value)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Lru> {
+        // The cache is always left in a consistent state, so a panic while it
+        // was locked does not matter.
+        self.cache.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Deref for CachedAssetPack {
+    type Target = AssetPack;
+
+    fn deref(&self) -> &Self::Target {
+        &self.pack
+    }
+}
+
+type Key = (ResourceIdentifier<'static>, TypeId);
+type Value = Arc<dyn Any + Send + Sync>;
+
+/// A least-recently-used cache.
+struct Lru {
+    capacity: usize,
+    /// Incremented on every access, to order the entries by their last use.
+    clock: u64,
+    entries: HashMap<Key, (Value, u64)>,
+    /// The keys of all entries by their last use.
+    order: BTreeMap<u64, Key>,
+}
+
+impl Lru {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            clock: 0,
+            entries: HashMap::new(),
+            order: BTreeMap::new(),
+        }
+    }
+
+    fn get(&mut self, key: &Key) -> Option<Value> {
+        let (value, last_used) = self.entries.get_mut(key)?;
+
+        self.clock += 1;
+        let key = self.order.remove(last_used)?;
+        self.order.insert(self.clock, key);
+        *last_used = self.clock;
+
+        Some(value.clone())
+    }
+
+    fn insert(&mut self, key: Key, value: Value) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if let Some((_, last_used)) = self.entries.remove(&key) {
+            self.order.remove(&last_used);
+        } else if self.entries.len() >= self.capacity {
+            if let Some((_, oldest)) = self.order.pop_first() {
+                self.entries.remove(&oldest);
+            }
+        }
+
+        self.clock += 1;
+        self.order.insert(self.clock, key.clone());
+        self.entries.insert(key, (value, self.clock));
+    }
+
+    fn retain<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(&Key) -> bool,
+    {
+        self.entries.retain(|key, _| keep(key));
+        self.order.retain(|_, key| keep(key));
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn key(path: &str) -> Key {
+        let id = ResourceIdentifier::blockstates(path).to_owned();
+        (id, TypeId::of::<()>())
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let mut lru = Lru::new(2);
+        lru.insert(key("a"), Arc::new(1));
+        lru.insert(key("b"), Arc::new(2));
+
+        // Using `a` makes `b` the least recently used entry.
+        assert!(lru.get(&key("a")).is_some());
+        lru.insert(key("c"), Arc::new(3));
+
+        assert!(lru.get(&key("a")).is_some());
+        assert!(lru.get(&key("b")).is_none());
+        assert!(lru.get(&key("c")).is_some());
+        assert_eq!(lru.entries.len(), 2);
+        assert_eq!(lru.order.len(), 2);
+    }
+
+    #[test]
+    fn retain_removes_entries() {
+        let mut lru = Lru::new(3);
+        lru.insert(key("a"), Arc::new(1));
+        lru.insert(key("b"), Arc::new(2));
+
+        lru.retain(|k| *k != key("a"));
+
+        assert!(lru.get(&key("a")).is_none());
+        assert!(lru.get(&key("b")).is_some());
+        assert_eq!(lru.order.len(), 1);
+    }
+
+    #[test]
+    fn zero_capacity_caches_nothing() {
+        let mut lru = Lru::new(0);
+        lru.insert(key("a"), Arc::new(1));
+
+        assert!(lru.get(&key("a")).is_none());
+    }
+}
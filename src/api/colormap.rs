@@ -0,0 +1,320 @@
+//! Computing the grass and foliage colors of biomes, like the game does.
+//!
+//! Start here: [`BiomeColors`].
+//!
+//! Unless a biome overrides them, its grass and foliage colors are sampled
+//! from the `textures/colormap/grass.png` and `textures/colormap/foliage.png`
+//! colormaps at its temperature and downfall. The grass color is then
+//! modified by the biome's [grass color modifier], which depends on the
+//! position of the block in swamps.
+//!
+//! Colors are returned as [`Rgb`] values, like the colors of [`Effects`].
+//!
+//! This module requires the `image` feature.
+//!
+//! [grass color modifier]: EffectsGrassColorModifier
+//! [`Effects`]: crate::schemas::worldgen::biome::Effects
+
+use std::sync::OnceLock;
+
+use image::RgbaImage;
+
+use crate::{
+    api::{random::LegacyRandom, AssetPack, Result},
+    schemas::{
+        color::Rgb,
+        worldgen::biome::{CustomeBiome, EffectsGrassColorModifier},
+    },
+};
+
+/// A colormap texture, indexed by temperature and downfall.
+///
+/// # Example
+///
+/// ```
+/// use image::{Rgba, RgbaImage};
+/// use minecraft_assets::api::colormap::Colormap;
+/// use minecraft_assets::schemas::Rgb;
+///
+/// let image = RgbaImage::from_fn(256, 256, |x, y| Rgba([x as u8, y as u8, 0, 255]));
+/// let colormap = Colormap::new(image, Colormap::GRASS_FALLBACK);
+///
+/// // The temperature selects the column, and the downfall scaled by the
+/// // temperature selects the row.
+/// assert_eq!(colormap.sample(0.8, 0.4), Rgb(0x32ad00));
+/// assert_eq!(colormap.sample(1.0, 1.0), Rgb::new(0, 0, 0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Colormap {
+    image: RgbaImage,
+    fallback: Rgb,
+}
+
+impl Colormap {
+    /// The color used by the game for samples outside of the grass colormap.
+    pub const GRASS_FALLBACK: Rgb = Rgb(0xff00ff);
+
+    /// The color used by the game for samples outside of the foliage colormap.
+    pub const FOLIAGE_FALLBACK: Rgb = Rgb(0x48b518);
+
+    /// Returns a colormap from the given image, which is normally 256x256
+    /// pixels, and the color used for samples outside of it.
+    pub fn new(image: RgbaImage, fallback: Rgb) -> Self {
+        Self { image, fallback }
+    }
+
+    /// Loads the `colormap/grass` texture of the given pack.
+    pub fn load_grass(assets: &AssetPack) -> Result<Self> {
+        let image = assets.load_texture_image("colormap/grass")?;
+        Ok(Self::new(image, Self::GRASS_FALLBACK))
+    }
+
+    /// Loads the `colormap/foliage` texture of the given pack.
+    pub fn load_foliage(assets: &AssetPack) -> Result<Self> {
+        let image = assets.load_texture_image("colormap/foliage")?;
+        Ok(Self::new(image, Self::FOLIAGE_FALLBACK))
+    }
+
+    /// Returns the image of the colormap.
+    pub fn image(&self) -> &RgbaImage {
+        &self.image
+    }
+
+    /// Returns the color of the colormap at the given temperature and
+    /// downfall, which are clamped between `0.0` and `1.0`.
+    ///
+    /// See `net.minecraft.world.level.GrassColor.get()`.
+    pub fn sample(&self, temperature: f32, downfall: f32) -> Rgb {
+        let temperature = f64::from(temperature.clamp(0.0, 1.0));
+        let downfall = f64::from(downfall.clamp(0.0, 1.0)) * temperature;
+
+        let x = ((1.0 - temperature) * 255.0) as u32;
+        let y = ((1.0 - downfall) * 255.0) as u32;
+        match self.image.get_pixel_checked(x, y) {
+            Some(pixel) => Rgb::new(pixel[0], pixel[1], pixel[2]),
+            None => self.fallback,
+        }
+    }
+}
+
+/// The grass and foliage colormaps of a pack.
+///
+/// # Example
+///
+/// ```
+/// use image::{Rgba, RgbaImage};
+/// use minecraft_assets::api::colormap::{BiomeColors, Colormap};
+/// use minecraft_assets::schemas::{worldgen::biome::*, Rgb};
+///
+/// let grass = RgbaImage::from_pixel(256, 256, Rgba([0x91, 0xbd, 0x59, 255]));
+/// let foliage = RgbaImage::from_pixel(256, 256, Rgba([0x77, 0xab, 0x2f, 255]));
+/// let colors = BiomeColors::new(
+///     Colormap::new(grass, Colormap::GRASS_FALLBACK),
+///     Colormap::new(foliage, Colormap::FOLIAGE_FALLBACK),
+/// );
+///
+/// let mut biome = CustomeBiome {
+///     temperature: 0.8,
+///     downfall: 0.4,
+///     ..Default::default()
+/// };
+/// assert_eq!(colors.grass_color(&biome, 0.0, 0.0), Rgb(0x91bd59));
+/// assert_eq!(colors.foliage_color(&biome), Rgb(0x77ab2f));
+///
+/// biome.effects.grass_color_modifier = EffectsGrassColorModifier::DarkForest;
+/// assert_eq!(colors.grass_color(&biome, 0.0, 0.0), Rgb(0x5c7831));
+///
+/// biome.effects.foliage_color = Some(Rgb(0x6a7039));
+/// assert_eq!(colors.foliage_color(&biome), Rgb(0x6a7039));
+/// ```
+#[derive(Debug, Clone)]
+pub struct BiomeColors {
+    grass: Colormap,
+    foliage: Colormap,
+}
+
+impl BiomeColors {
+    /// Returns new [`BiomeColors`] from the given grass and foliage colormaps.
+    pub fn new(grass: Colormap, foliage: Colormap) -> Self {
+        Self { grass, foliage }
+    }
+
+    /// Loads the grass and foliage colormaps of the given pack.
+    pub fn load(assets: &AssetPack) -> Result<Self> {
+        Ok(Self::new(
+            Colormap::load_grass(assets)?,
+            Colormap::load_foliage(assets)?,
+        ))
+    }
+
+    /// Returns the grass colormap.
+    pub fn grass(&self) -> &Colormap {
+        &self.grass
+    }
+
+    /// Returns the foliage colormap.
+    pub fn foliage(&self) -> &Colormap {
+        &self.foliage
+    }
+
+    /// Returns the color of grass in the given biome at the given block
+    /// coordinates.
+    ///
+    /// The coordinates only matter for the swamp modifier.
+    ///
+    /// See `net.minecraft.world.level.biome.Biome.getGrassColor()`.
+    pub fn grass_color(&self, biome: &CustomeBiome, x: f64, z: f64) -> Rgb {
+        let color = biome
+            .effects
+            .grass_color
+            .unwrap_or_else(|| self.grass.sample(biome.temperature, biome.downfall));
+
+        modify_grass_color(biome.effects.grass_color_modifier, color, x, z)
+    }
+
+    /// Returns the color of leaves and vines in the given biome.
+    ///
+    /// See `net.minecraft.world.level.biome.Biome.getFoliageColor()`.
+    pub fn foliage_color(&self, biome: &CustomeBiome) -> Rgb {
+        biome
+            .effects
+            .foliage_color
+            .unwrap_or_else(|| self.foliage.sample(biome.temperature, biome.downfall))
+    }
+}
+
+/// Applies the given grass color modifier to a color at the given block
+/// coordinates.
+///
+/// See `net.minecraft.world.level.biome.BiomeSpecialEffects.GrassColorModifier`.
+pub fn modify_grass_color(modifier: EffectsGrassColorModifier, color: Rgb, x: f64, z: f64) -> Rgb {
+    match modifier {
+        EffectsGrassColorModifier::None => color,
+        EffectsGrassColorModifier::DarkForest => Rgb(((color.0 & 0xfefefe) + 0x28340a) >> 1),
+        EffectsGrassColorModifier::Swamp => {
+            let noise = biome_info_noise().value(x * 0.0225, z * 0.0225);
+            if noise < -0.1 {
+                Rgb(0x4c763c)
+            } else {
+                Rgb(0x6a7039)
+            }
+        }
+    }
+}
+
+/// Returns the noise used by the game for the swamp grass color, i.e.,
+/// `Biome.BIOME_INFO_NOISE`, which has a single octave seeded with `2345`.
+fn biome_info_noise() -> &'static SimplexNoise {
+    static NOISE: OnceLock<SimplexNoise> = OnceLock::new();
+    NOISE.get_or_init(|| SimplexNoise::new(&mut LegacyRandom::new(2345)))
+}
+
+/// The game's 2D simplex noise.
+///
+/// See `net.minecraft.world.level.levelgen.synth.SimplexNoise`.
+struct SimplexNoise {
+    permutations: [u8; 256],
+}
+
+impl SimplexNoise {
+    const GRADIENTS: [[f64; 2]; 16] = [
+        [1.0, 1.0],
+        [-1.0, 1.0],
+        [1.0, -1.0],
+        [-1.0, -1.0],
+        [1.0, 0.0],
+        [-1.0, 0.0],
+        [1.0, 0.0],
+        [-1.0, 0.0],
+        [0.0, 1.0],
+        [0.0, -1.0],
+        [0.0, 1.0],
+        [0.0, -1.0],
+        [1.0, 1.0],
+        [0.0, -1.0],
+        [-1.0, 1.0],
+        [0.0, -1.0],
+    ];
+
+    fn new(random: &mut LegacyRandom) -> Self {
+        // The offsets are only used by 3D noise, but still advance the
+        // generator.
+        for _ in 0..3 {
+            random.next_double();
+        }
+
+        let mut permutations = [0; 256];
+        for (i, p) in permutations.iter_mut().enumerate() {
+            *p = i as u8;
+        }
+        for i in 0..256 {
+            let j = random.next_int(256 - i as i32) as usize;
+            permutations.swap(i, i + j);
+        }
+
+        Self { permutations }
+    }
+
+    fn p(&self, i: i32) -> i32 {
+        i32::from(self.permutations[(i & 255) as usize])
+    }
+
+    fn corner(gradient: usize, x: f64, y: f64) -> f64 {
+        let t = 0.5 - x * x - y * y;
+        if t < 0.0 {
+            0.0
+        } else {
+            let [gx, gy] = Self::GRADIENTS[gradient];
+            t * t * t * t * (gx * x + gy * y)
+        }
+    }
+
+    fn value(&self, x: f64, y: f64) -> f64 {
+        let sqrt_3 = 3.0_f64.sqrt();
+        let f2 = 0.5 * (sqrt_3 - 1.0);
+        let g2 = (3.0 - sqrt_3) / 6.0;
+
+        let s = (x + y) * f2;
+        let i = (x + s).floor() as i32;
+        let j = (y + s).floor() as i32;
+        let t = f64::from(i + j) * g2;
+        let x0 = x - (f64::from(i) - t);
+        let y0 = y - (f64::from(j) - t);
+
+        let (i1, j1) = if x0 > y0 { (1, 0) } else { (0, 1) };
+        let x1 = x0 - f64::from(i1) + g2;
+        let y1 = y0 - f64::from(j1) + g2;
+        let x2 = x0 - 1.0 + 2.0 * g2;
+        let y2 = y0 - 1.0 + 2.0 * g2;
+
+        let ii = i & 255;
+        let jj = j & 255;
+        let g0 = self.p(ii + self.p(jj)) % 12;
+        let g1 = self.p(ii + i1 + self.p(jj + j1)) % 12;
+        let g2_ = self.p(ii + 1 + self.p(jj + 1)) % 12;
+
+        70.0 * (Self::corner(g0 as usize, x0, y0)
+            + Self::corner(g1 as usize, x1, y1)
+            + Self::corner(g2_ as usize, x2, y2))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn swamp_grass_depends_on_position() {
+        let colors: Vec<_> = (0..64)
+            .map(|x| {
+                let x = f64::from(x) * 16.0;
+                modify_grass_color(EffectsGrassColorModifier::Swamp, Rgb(0), x, 0.0)
+            })
+            .collect();
+
+        assert!(colors
+            .iter()
+            .all(|&c| c == Rgb(0x4c763c) || c == Rgb(0x6a7039)));
+        assert!(colors.contains(&Rgb(0x4c763c)) && colors.contains(&Rgb(0x6a7039)));
+    }
+}
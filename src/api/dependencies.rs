@@ -0,0 +1,712 @@
+//! Finding which resources of a pack reference which other resources.
+//!
+//! Start here: [`DependencyIndex`].
+//!
+//! The index records the references of the following resources:
+//!
+//! * Blockstates files to their models.
+//! * Block and item models to their parents, textures, and override models.
+//! * Item definitions to their models.
+//! * Biomes to their placed features and configured carvers.
+//! * Placed features to their configured features.
+//! * `sounds.json` files to their `.ogg` files.
+//! * OptiFine connected textures and custom item textures rules to their
+//!   textures (see the [`optifine`] module).
+//!
+//! References to tags (e.g., `#minecraft:cave`), inline definitions, and
+//! built-in models like `builtin/generated` are not recorded. Resources that
+//! cannot be parsed have no references; the [`validate`] module reports them.
+//!
+//! The whole graph of references can also be exported as JSON or as a
+//! Graphviz DOT file, to visualize the structure of a pack.
+//!
+//! [`optifine`]: crate::api::optifine
+//! [`validate`]: crate::api::validate
+
+use std::{
+    collections::{HashMap, HashSet},
+    io::{self, Write},
+};
+
+use serde::de::DeserializeOwned;
+
+use crate::{
+    api::{
+        optifine, ModelIdentifier, ModelResolver, ResourceIdentifier, ResourceKind,
+        ResourceLocation, ResourceProvider, Result,
+    },
+    schemas::{
+        blockstates::Variant,
+        item_definition::ItemModel,
+        models::Texture,
+        optifine::{CitProperties, CtmProperties},
+        reference::{MaybeReference, MaybeReferenceList},
+        sounds::SoundKind,
+        worldgen::biome::CustomeBiome,
+        BlockStates, ItemDefinition, Model, SoundsJson,
+    },
+};
+
+/// The kinds of resources whose references are recorded by a
+/// [`DependencyIndex`], in the order in which they are indexed.
+const INDEXED_KINDS: [ResourceKind; 9] = [
+    ResourceKind::BlockStates,
+    ResourceKind::BlockModel,
+    ResourceKind::ItemModel,
+    ResourceKind::ItemDefinition,
+    ResourceKind::WorldGen_Biome,
+    ResourceKind::WorldGen_PlacedFeature,
+    ResourceKind::Sounds,
+    ResourceKind::CtmProperties,
+    ResourceKind::CitProperties,
+];
+
+/// The kinds of resources that are only referenced by the
+/// [`INDEXED_KINDS`], and are listed so that unused ones can be found.
+const REFERENCED_KINDS: [ResourceKind; 5] = [
+    ResourceKind::Texture,
+    ResourceKind::OptifineTexture,
+    ResourceKind::WorldGen_ConfiguredCarver,
+    ResourceKind::WorldGen_ConfiguredFeature,
+    ResourceKind::Sound,
+];
+
+/// An index of the references between the resources of a pack, which answers
+/// both which resources a resource uses and which resources use it.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::api::*;
+/// use minecraft_assets::api::dependencies::DependencyIndex;
+///
+/// let mut pack = MemoryResourceProvider::new();
+/// pack.insert(
+///     "assets/minecraft/blockstates/stone.json",
+///     br#"{ "variants": { "": { "model": "block/stone" } } }"#.to_vec(),
+/// );
+/// pack.insert(
+///     "assets/minecraft/models/block/stone.json",
+///     br#"{ "parent": "block/cube_all", "textures": { "all": "block/stone" } }"#.to_vec(),
+/// );
+/// pack.insert("assets/minecraft/textures/block/stone.png", Vec::new());
+/// pack.insert("assets/minecraft/textures/block/granite.png", Vec::new());
+///
+/// let index = DependencyIndex::build(&pack).unwrap();
+///
+/// let texture = ResourceIdentifier::texture("block/stone");
+/// assert_eq!(index.dependents(&texture), [ResourceIdentifier::block_model("stone")]);
+///
+/// let model = ResourceIdentifier::block_model("stone");
+/// assert_eq!(index.dependents(&model), [ResourceIdentifier::blockstates("stone")]);
+/// assert_eq!(
+///     index.dependencies(&model),
+///     [ResourceIdentifier::block_model("cube_all"), ResourceIdentifier::texture("block/stone")]
+/// );
+///
+/// assert_eq!(
+///     index.unused(ResourceKind::Texture),
+///     [ResourceIdentifier::texture("block/granite")]
+/// );
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct DependencyIndex {
+    /// Every resource of the pack that was listed, in the order in which it
+    /// was listed.
+    resources: Vec<ResourceIdentifier<'static>>,
+
+    dependencies: HashMap<ResourceIdentifier<'static>, Vec<ResourceIdentifier<'static>>>,
+    dependents: HashMap<ResourceIdentifier<'static>, Vec<ResourceIdentifier<'static>>>,
+}
+
+impl DependencyIndex {
+    /// Records the references of every resource of the pack of the given
+    /// provider, such as the provider of an [`AssetPack`] (i.e.,
+    /// `&*asset_pack`).
+    ///
+    /// [`AssetPack`]: crate::api::AssetPack
+    pub fn build(provider: &dyn ResourceProvider) -> Result<Self> {
+        let mut index = Self::default();
+
+        for kind in INDEXED_KINDS.into_iter().chain(REFERENCED_KINDS) {
+            let mut ids = match provider.list(kind) {
+                Ok(ids) => ids,
+                Err(e) if e.kind() == io::ErrorKind::Unsupported => Vec::new(),
+                Err(e) => return Err(e.into()),
+            };
+            ids.sort_by(|a, b| (a.namespace(), a.path()).cmp(&(b.namespace(), b.path())));
+
+            for id in ids {
+                if INDEXED_KINDS.contains(&kind) {
+                    let references = references(provider, &id)?;
+                    index.insert(&id, references);
+                }
+                index.resources.push(id);
+            }
+        }
+
+        Ok(index)
+    }
+
+    /// Returns the resources referenced by the resource with the given id, in
+    /// the order in which they first appear in it.
+    ///
+    /// Referenced resources are included even if they are not part of the
+    /// pack.
+    pub fn dependencies(&self, id: &ResourceIdentifier) -> &[ResourceIdentifier<'static>] {
+        self.dependencies
+            .get(&id.to_owned())
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the resources that reference the resource with the given id,
+    /// grouped by kind and sorted by id.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// use minecraft_assets::api::dependencies::DependencyIndex;
+    ///
+    /// let mut pack = MemoryResourceProvider::new();
+    /// pack.insert(
+    ///     "data/minecraft/worldgen/placed_feature/patch_grass_plain.json",
+    ///     br#"{ "feature": "minecraft:patch_grass", "placement": [] }"#.to_vec(),
+    /// );
+    ///
+    /// let index = DependencyIndex::build(&pack).unwrap();
+    ///
+    /// let feature = ResourceIdentifier::configured_feature("patch_grass");
+    /// assert_eq!(
+    ///     index.dependents(&feature),
+    ///     [ResourceIdentifier::placed_feature("patch_grass_plain")]
+    /// );
+    /// ```
+    pub fn dependents(&self, id: &ResourceIdentifier) -> &[ResourceIdentifier<'static>] {
+        self.dependents
+            .get(&id.to_owned())
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the resources of the given kind that reference the resource
+    /// with the given id.
+    pub fn dependents_of_kind<'s>(
+        &'s self,
+        id: &ResourceIdentifier,
+        kind: ResourceKind,
+    ) -> impl Iterator<Item = &'s ResourceIdentifier<'static>> {
+        self.dependents(id)
+            .iter()
+            .filter(move |dependent| dependent.kind() == kind)
+    }
+
+    /// Returns every resource of the given kind in the pack that no other
+    /// resource of the pack references, sorted by id.
+    ///
+    /// Only the references listed in the [module documentation](self) are
+    /// known, so resources that are used by the game directly (e.g., the
+    /// blockstates files, or the textures of entities) are always unused.
+    pub fn unused(&self, kind: ResourceKind) -> Vec<ResourceIdentifier<'static>> {
+        self.resources
+            .iter()
+            .filter(|id| id.kind() == kind && self.dependents(id).is_empty())
+            .cloned()
+            .collect()
+    }
+
+    /// Returns every resource of the pack that was listed while building the
+    /// index, grouped by kind and sorted by id.
+    pub fn resources(&self) -> &[ResourceIdentifier<'static>] {
+        &self.resources
+    }
+
+    /// Returns the dependency graph as a JSON value, with a node for every
+    /// resource and an edge from every resource to each of its dependencies.
+    ///
+    /// Nodes are identified by the paths of their resources within the pack.
+    /// Resources that are referenced but not part of the pack are marked as
+    /// `missing`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// use minecraft_assets::api::dependencies::DependencyIndex;
+    ///
+    /// let mut pack = MemoryResourceProvider::new();
+    /// pack.insert(
+    ///     "assets/minecraft/models/block/stone.json",
+    ///     br#"{ "textures": { "all": "block/stone" } }"#.to_vec(),
+    /// );
+    ///
+    /// let graph = DependencyIndex::build(&pack).unwrap().to_json();
+    /// assert_eq!(graph, serde_json::json!({
+    ///     "nodes": [
+    ///         {
+    ///             "id": "assets/minecraft/models/block/stone.json",
+    ///             "kind": "BlockModel",
+    ///             "resource": "minecraft:block/stone",
+    ///             "missing": false,
+    ///         },
+    ///         {
+    ///             "id": "assets/minecraft/textures/block/stone.png",
+    ///             "kind": "Texture",
+    ///             "resource": "minecraft:block/stone",
+    ///             "missing": true,
+    ///         },
+    ///     ],
+    ///     "edges": [
+    ///         {
+    ///             "from": "assets/minecraft/models/block/stone.json",
+    ///             "to": "assets/minecraft/textures/block/stone.png",
+    ///         },
+    ///     ],
+    /// }));
+    /// ```
+    pub fn to_json(&self) -> serde_json::Value {
+        use serde_json::json;
+
+        let nodes: Vec<_> = self
+            .nodes()
+            .into_iter()
+            .map(|(id, missing)| {
+                json!({
+                    "id": id.pack_path(),
+                    "kind": format!("{:?}", id.kind()),
+                    "resource": id.to_string(),
+                    "missing": missing,
+                })
+            })
+            .collect();
+        let edges: Vec<_> = self
+            .edges()
+            .map(|(from, to)| json!({ "from": from.pack_path(), "to": to.pack_path() }))
+            .collect();
+
+        json!({ "nodes": nodes, "edges": edges })
+    }
+
+    /// Writes the dependency graph as a JSON file.
+    ///
+    /// See [`to_json()`][Self::to_json].
+    pub fn write_json<W: Write>(&self, writer: W) -> io::Result<()> {
+        serde_json::to_writer(writer, &self.to_json()).map_err(io::Error::from)
+    }
+
+    /// Writes the dependency graph as a Graphviz DOT file, e.g., to be
+    /// rendered with `dot -Tsvg`.
+    ///
+    /// Nodes are named after the paths of their resources within the pack,
+    /// and labeled with their kinds and ids. Resources that are referenced but
+    /// not part of the pack are drawn with dashed outlines.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// use minecraft_assets::api::dependencies::DependencyIndex;
+    ///
+    /// let mut pack = MemoryResourceProvider::new();
+    /// pack.insert(
+    ///     "assets/minecraft/blockstates/stone.json",
+    ///     br#"{ "variants": { "": { "model": "block/stone" } } }"#.to_vec(),
+    /// );
+    ///
+    /// let mut dot = Vec::new();
+    /// DependencyIndex::build(&pack).unwrap().write_dot(&mut dot).unwrap();
+    /// assert_eq!(String::from_utf8(dot).unwrap(), r#"digraph dependencies {
+    ///     "assets/minecraft/blockstates/stone.json" [label="BlockStates\nminecraft:stone"];
+    ///     "assets/minecraft/models/block/stone.json" [label="BlockModel\nminecraft:block/stone", style=dashed];
+    ///     "assets/minecraft/blockstates/stone.json" -> "assets/minecraft/models/block/stone.json";
+    /// }
+    /// "#);
+    /// ```
+    pub fn write_dot<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writeln!(writer, "digraph dependencies {{")?;
+
+        for (id, missing) in self.nodes() {
+            let label = format!("{:?}\\n{}", id.kind(), id);
+            write!(
+                writer,
+                "    {} [label={}",
+                dot_string(&id.pack_path()),
+                dot_string(&label)
+            )?;
+            if missing {
+                write!(writer, ", style=dashed")?;
+            }
+            writeln!(writer, "];")?;
+        }
+        for (from, to) in self.edges() {
+            writeln!(
+                writer,
+                "    {} -> {};",
+                dot_string(&from.pack_path()),
+                dot_string(&to.pack_path())
+            )?;
+        }
+
+        writeln!(writer, "}}")
+    }
+
+    /// Returns every resource of the pack, followed by the resources that are
+    /// referenced but not part of the pack, in the order in which they are
+    /// first referenced, along with whether they are missing.
+    fn nodes(&self) -> Vec<(&ResourceIdentifier<'static>, bool)> {
+        let listed: HashSet<_> = self.resources.iter().collect();
+        let mut missing = HashSet::new();
+
+        let mut nodes: Vec<_> = self.resources.iter().map(|id| (id, false)).collect();
+        for (_, to) in self.edges() {
+            if !listed.contains(to) && missing.insert(to) {
+                nodes.push((to, true));
+            }
+        }
+
+        nodes
+    }
+
+    /// Returns the edges from every resource to each of its dependencies, in
+    /// the order of [`resources()`][Self::resources].
+    fn edges(
+        &self,
+    ) -> impl Iterator<Item = (&ResourceIdentifier<'static>, &ResourceIdentifier<'static>)> {
+        self.resources.iter().flat_map(move |from| {
+            self.dependencies
+                .get(from)
+                .into_iter()
+                .flatten()
+                .map(move |to| (from, to))
+        })
+    }
+
+    fn insert(
+        &mut self,
+        id: &ResourceIdentifier<'static>,
+        references: Vec<ResourceIdentifier<'static>>,
+    ) {
+        let mut unique: Vec<ResourceIdentifier<'static>> = Vec::with_capacity(references.len());
+        for reference in references {
+            if !unique.contains(&reference) {
+                unique.push(reference);
+            }
+        }
+
+        for reference in &unique {
+            self.dependents
+                .entry(reference.clone())
+                .or_default()
+                .push(id.clone());
+        }
+        if !unique.is_empty() {
+            self.dependencies.insert(id.clone(), unique);
+        }
+    }
+}
+
+/// Returns the resources referenced by the resource with the given id, in the
+/// order in which they appear in it.
+fn references(
+    provider: &dyn ResourceProvider,
+    id: &ResourceIdentifier,
+) -> Result<Vec<ResourceIdentifier<'static>>> {
+    let mut references = Vec::new();
+
+    match id.kind() {
+        ResourceKind::BlockStates => {
+            let states = match parse::<BlockStates>(provider, id)? {
+                Some(states) => states,
+                None => return Ok(references),
+            };
+            let variants: Vec<&Variant> = match &states {
+                BlockStates::Variants { variants } => {
+                    let mut names: Vec<_> = variants.keys().collect();
+                    names.sort();
+                    names.into_iter().map(|name| &variants[name]).collect()
+                }
+                BlockStates::Multipart { cases } => cases.iter().map(|case| &case.apply).collect(),
+            };
+            for properties in variants.into_iter().flat_map(Variant::models) {
+                references.push(ModelResolver::model_id(
+                    &properties.model,
+                    ResourceKind::BlockModel,
+                ));
+            }
+        }
+        ResourceKind::BlockModel | ResourceKind::ItemModel => {
+            let model = match parse::<Model>(provider, id)? {
+                Some(model) => model,
+                None => return Ok(references),
+            };
+            if let Some(parent) = &model.parent {
+                if !ModelIdentifier::is_builtin(parent.as_str()) {
+                    references.push(ModelResolver::model_id(parent, id.kind()));
+                }
+            }
+            if let Some(textures) = &model.textures {
+                let mut names: Vec<_> = textures.keys().collect();
+                names.sort();
+                references.extend(
+                    names
+                        .into_iter()
+                        .filter_map(|name| texture_id(&textures[name])),
+                );
+            }
+            for element in model.elements.iter().flatten() {
+                let mut faces: Vec<_> = element.faces.iter().collect();
+                faces.sort_by_key(|(face, _)| **face as u8);
+                references.extend(
+                    faces
+                        .into_iter()
+                        .filter_map(|(_, face)| texture_id(&face.texture)),
+                );
+            }
+            for case in model.overrides.iter().flatten() {
+                references.push(ModelResolver::model_id(&case.model, id.kind()));
+            }
+        }
+        ResourceKind::ItemDefinition => {
+            let definition = match parse::<ItemDefinition>(provider, id)? {
+                Some(definition) => definition,
+                None => return Ok(references),
+            };
+            item_model_references(&definition.model, &mut references);
+        }
+        ResourceKind::WorldGen_Biome => {
+            let biome = match parse::<CustomeBiome>(provider, id)? {
+                Some(biome) => biome,
+                None => return Ok(references),
+            };
+            for features in &biome.features {
+                references.extend(
+                    list_references(features)
+                        .map(|feature| ResourceIdentifier::placed_feature(feature).to_owned()),
+                );
+            }
+            for carvers in [&biome.carvers.air, &biome.carvers.liquid] {
+                references.extend(
+                    list_references(carvers)
+                        .map(|carver| ResourceIdentifier::configured_carver(carver).to_owned()),
+                );
+            }
+        }
+        ResourceKind::WorldGen_PlacedFeature => {
+            // Placed features have no schema in this crate.
+            let feature = match parse::<serde_json::Value>(provider, id)? {
+                Some(feature) => feature,
+                None => return Ok(references),
+            };
+            if let Some(configured) = feature["feature"].as_str() {
+                if !configured.starts_with('#') {
+                    references.push(ResourceIdentifier::configured_feature(configured).to_owned());
+                }
+            }
+        }
+        ResourceKind::Sounds => {
+            let sounds = match parse::<SoundsJson>(provider, id)? {
+                Some(sounds) => sounds,
+                None => return Ok(references),
+            };
+            let mut events: Vec<_> = sounds.events.iter().collect();
+            events.sort_by_key(|(name, _)| *name);
+
+            for (_, definition) in events {
+                references.extend(
+                    definition
+                        .sounds
+                        .iter()
+                        .filter(|sound| sound.kind() == SoundKind::File)
+                        .map(|sound| ResourceIdentifier::sound(sound.name()).to_owned()),
+                );
+            }
+        }
+        ResourceKind::CtmProperties => {
+            let text = String::from_utf8_lossy(&provider.load_resource(id)?).into_owned();
+            references.extend(optifine::ctm_textures(id, &CtmProperties::parse(&text)));
+        }
+        ResourceKind::CitProperties => {
+            let text = String::from_utf8_lossy(&provider.load_resource(id)?).into_owned();
+            references.extend(optifine::cit_textures(id, &CitProperties::parse(&text)));
+        }
+        _ => {}
+    }
+
+    Ok(references)
+}
+
+fn item_model_references(model: &ItemModel, references: &mut Vec<ResourceIdentifier<'static>>) {
+    let mut push = |model: &ResourceLocation| {
+        references.push(ModelResolver::model_id(model, ResourceKind::ItemModel))
+    };
+
+    match model {
+        ItemModel::Model { model, .. } => push(model),
+        ItemModel::Special { base, .. } => push(base),
+        ItemModel::Composite { models } => {
+            for model in models {
+                item_model_references(model, references);
+            }
+        }
+        ItemModel::Condition {
+            on_true, on_false, ..
+        } => {
+            item_model_references(on_true, references);
+            item_model_references(on_false, references);
+        }
+        ItemModel::Select {
+            cases, fallback, ..
+        } => {
+            for case in cases {
+                item_model_references(&case.model, references);
+            }
+            if let Some(fallback) = fallback {
+                item_model_references(fallback, references);
+            }
+        }
+        ItemModel::RangeDispatch {
+            entries, fallback, ..
+        } => {
+            for entry in entries {
+                item_model_references(&entry.model, references);
+            }
+            if let Some(fallback) = fallback {
+                item_model_references(fallback, references);
+            }
+        }
+        ItemModel::Empty | ItemModel::BundleSelectedItem => {}
+    }
+}
+
+/// Returns the id of the texture file referenced by the given texture, unless
+/// it references a texture variable.
+fn texture_id(texture: &Texture) -> Option<ResourceIdentifier<'static>> {
+    texture
+        .location()
+        .map(|location| ResourceIdentifier::texture(location).to_owned())
+}
+
+/// Returns the ids referenced by the given list, excluding tags and inline
+/// definitions.
+fn list_references<T>(list: &MaybeReferenceList<T>) -> impl Iterator<Item = &str> {
+    list.iter().filter_map(|value| match value {
+        MaybeReference::Reference(id) if !value.is_tag() => Some(id.as_str()),
+        _ => None,
+    })
+}
+
+/// Returns the given string as a quoted DOT identifier.
+///
+/// Backslashes are kept, so that escape sequences like `\n` can be used in
+/// labels.
+fn dot_string(s: &str) -> String {
+    format!("\"{}\"", s.replace('"', "\\\""))
+}
+
+/// Parses the given resource, or returns `None` if it cannot be parsed.
+fn parse<T: DeserializeOwned>(
+    provider: &dyn ResourceProvider,
+    id: &ResourceIdentifier,
+) -> Result<Option<T>> {
+    let bytes = provider.load_resource(id)?;
+    Ok(serde_json::from_slice(&bytes).ok())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::api::MemoryResourceProvider;
+
+    #[test]
+    fn indexes_biomes_and_item_definitions() {
+        let mut pack = MemoryResourceProvider::new();
+        pack.insert(
+            "data/minecraft/worldgen/biome/plains.json",
+            br##"{
+                "has_precipitation": true,
+                "temperature": 0.8,
+                "downfall": 0.4,
+                "effects": {
+                    "fog_color": 12638463,
+                    "sky_color": 7907327,
+                    "water_color": 4159204,
+                    "water_fog_color": 329011
+                },
+                "carvers": { "air": "minecraft:cave" },
+                "features": [[], ["minecraft:patch_grass_plain", "minecraft:ore_dirt"]],
+                "spawners": {}
+            }"##
+            .to_vec(),
+        );
+        pack.insert(
+            "assets/minecraft/items/bow.json",
+            br#"{
+                "model": {
+                    "type": "minecraft:condition",
+                    "property": "minecraft:using_item",
+                    "on_true": { "type": "minecraft:model", "model": "minecraft:item/bow_pulling_0" },
+                    "on_false": { "type": "minecraft:model", "model": "minecraft:item/bow" }
+                }
+            }"#
+            .to_vec(),
+        );
+
+        let index = DependencyIndex::build(&pack).unwrap();
+
+        let biome = ResourceIdentifier::new(ResourceKind::WorldGen_Biome, "plains");
+        assert_eq!(
+            index.dependencies(&biome),
+            [
+                ResourceIdentifier::placed_feature("patch_grass_plain"),
+                ResourceIdentifier::placed_feature("ore_dirt"),
+                ResourceIdentifier::configured_carver("cave"),
+            ]
+        );
+        assert_eq!(
+            index.dependents(&ResourceIdentifier::placed_feature("ore_dirt")),
+            [biome]
+        );
+
+        let bow = ResourceIdentifier::item_model("minecraft:item/bow");
+        let definition = ResourceIdentifier::new(ResourceKind::ItemDefinition, "bow");
+        assert_eq!(index.dependencies(&definition).len(), 2);
+        assert_eq!(index.dependents(&bow), [definition]);
+        assert_eq!(
+            index
+                .dependents_of_kind(&bow, ResourceKind::BlockStates)
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn indexes_the_textures_of_optifine_rules() {
+        let mut pack = MemoryResourceProvider::new();
+        pack.insert(
+            "assets/minecraft/optifine/ctm/glass/glass.properties",
+            b"method=horizontal\ntiles=0-1 textures/block/stone".to_vec(),
+        );
+        for tile in ["0", "1", "unused"] {
+            pack.insert(
+                format!("assets/minecraft/optifine/ctm/glass/{}.png", tile),
+                Vec::new(),
+            );
+        }
+
+        let index = DependencyIndex::build(&pack).unwrap();
+
+        let glass = ResourceIdentifier::ctm_properties("glass/glass");
+        assert_eq!(
+            index.dependencies(&glass),
+            [
+                ResourceIdentifier::optifine_texture("ctm/glass/0"),
+                ResourceIdentifier::optifine_texture("ctm/glass/1"),
+                ResourceIdentifier::texture("block/stone"),
+            ]
+        );
+        assert_eq!(
+            index.unused(ResourceKind::OptifineTexture),
+            [ResourceIdentifier::optifine_texture("ctm/glass/unused")]
+        );
+    }
+}
@@ -0,0 +1,419 @@
+//! Comparing the resources of two packs, e.g., of two versions of the game.
+//!
+//! Start here: [`PackDiff`].
+//!
+//! Every resource of the two packs is compared by its contents. JSON resources
+//! (including `.mcmeta` files) are compared structurally, so that changes to
+//! their formatting or to the order of their fields are not reported, and the
+//! values that changed are reported as [`JsonChange`]s. Other resources, like
+//! textures and sounds, are compared byte by byte.
+//!
+//! Files at the root of the packs, like `pack.mcmeta`, are not compared.
+
+use std::{collections::BTreeMap, fmt, io};
+
+use serde_json::Value;
+
+use crate::api::{ResourceIdentifier, ResourceKind, ResourceProvider, Result};
+
+/// The differences between the resources of two packs.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::api::*;
+/// use minecraft_assets::api::diff::{ChangeKind, PackDiff};
+///
+/// let mut old = MemoryResourceProvider::new();
+/// old.insert("assets/minecraft/models/block/stone.json", br#"{ "parent": "block/cube_all" }"#.to_vec());
+/// old.insert("assets/minecraft/models/block/granite.json", br#"{}"#.to_vec());
+///
+/// let mut new = MemoryResourceProvider::new();
+/// new.insert("assets/minecraft/models/block/stone.json", br#"{ "parent": "block/cube" }"#.to_vec());
+/// new.insert("assets/minecraft/models/block/diorite.json", br#"{}"#.to_vec());
+///
+/// let diff = PackDiff::compare(&old, &new).unwrap();
+///
+/// let summary = diff.summary();
+/// assert_eq!(summary[0].kind, ResourceKind::BlockModel);
+/// assert_eq!((summary[0].added, summary[0].removed, summary[0].changed), (1, 1, 1));
+///
+/// let stone = diff.get(&ResourceIdentifier::block_model("stone")).unwrap();
+/// let ChangeKind::Changed(changes) = &stone.change else { panic!() };
+/// assert_eq!(changes[0].to_string(), r#"/parent: "block/cube_all" -> "block/cube""#);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PackDiff {
+    /// The resources that were added, removed, or changed, ordered by kind
+    /// (like [`ResourceKind::ALL`]), then by namespace and path.
+    pub changes: Vec<ResourceChange>,
+}
+
+impl PackDiff {
+    /// Compares the resources of every kind of the given packs.
+    pub fn compare(old: &dyn ResourceProvider, new: &dyn ResourceProvider) -> Result<Self> {
+        Self::compare_kinds(old, new, ResourceKind::ALL)
+    }
+
+    /// Compares the resources of the given kinds of the given packs.
+    pub fn compare_kinds(
+        old: &dyn ResourceProvider,
+        new: &dyn ResourceProvider,
+        kinds: &[ResourceKind],
+    ) -> Result<Self> {
+        let mut changes = Vec::new();
+
+        for &kind in kinds {
+            let old_ids = list(old, kind)?;
+            let mut new_ids = list(new, kind)?;
+
+            for (key, id) in old_ids {
+                let change = match new_ids.remove(&key) {
+                    Some(_) => match compare_resource(old, new, &id)? {
+                        Some(json_changes) => ChangeKind::Changed(json_changes),
+                        None => continue,
+                    },
+                    None => ChangeKind::Removed,
+                };
+                changes.push((
+                    key,
+                    ResourceChange {
+                        resource: id,
+                        change,
+                    },
+                ));
+            }
+            for (key, id) in new_ids {
+                changes.push((
+                    key,
+                    ResourceChange {
+                        resource: id,
+                        change: ChangeKind::Added,
+                    },
+                ));
+            }
+        }
+
+        changes.sort_by(|a, b| a.0.cmp(&b.0));
+        let changes = changes.into_iter().map(|(_, change)| change).collect();
+        Ok(Self { changes })
+    }
+
+    /// Returns `true` if the packs have the same resources.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Returns the change to the resource with the given id, if it changed.
+    pub fn get(&self, id: &ResourceIdentifier) -> Option<&ResourceChange> {
+        self.changes.iter().find(|change| &change.resource == id)
+    }
+
+    /// Returns the changes to the resources of the given kind.
+    pub fn changes_of_kind(&self, kind: ResourceKind) -> impl Iterator<Item = &ResourceChange> {
+        self.changes
+            .iter()
+            .filter(move |change| change.resource.kind() == kind)
+    }
+
+    /// Returns the number of added, removed, and changed resources of each
+    /// kind that has any, in the order of [`changes`][Self::changes].
+    pub fn summary(&self) -> Vec<KindSummary> {
+        let mut summary: Vec<KindSummary> = Vec::new();
+
+        for change in &self.changes {
+            let kind = change.resource.kind();
+            let index = match summary.iter().position(|s| s.kind == kind) {
+                Some(index) => index,
+                None => {
+                    summary.push(KindSummary {
+                        kind,
+                        added: 0,
+                        removed: 0,
+                        changed: 0,
+                    });
+                    summary.len() - 1
+                }
+            };
+
+            let counts = &mut summary[index];
+            match change.change {
+                ChangeKind::Added => counts.added += 1,
+                ChangeKind::Removed => counts.removed += 1,
+                ChangeKind::Changed(_) => counts.changed += 1,
+            }
+        }
+
+        summary
+    }
+}
+
+/// A resource that was added, removed, or changed between two packs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResourceChange {
+    /// The resource that changed.
+    pub resource: ResourceIdentifier<'static>,
+
+    /// How the resource changed.
+    pub change: ChangeKind,
+}
+
+/// Formats the change as a sign (`+`, `-`, or `~`) followed by the path of
+/// the resource within the pack.
+impl fmt::Display for ResourceChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = match self.change {
+            ChangeKind::Added => '+',
+            ChangeKind::Removed => '-',
+            ChangeKind::Changed(_) => '~',
+        };
+        write!(f, "{} {}", sign, self.resource.pack_path())
+    }
+}
+
+/// How a [`ResourceChange`] changed its resource.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChangeKind {
+    /// The resource is only in the new pack.
+    Added,
+
+    /// The resource is only in the old pack.
+    Removed,
+
+    /// The resource is in both packs, with different contents.
+    ///
+    /// Holds the values that changed, if the resource is JSON in both packs.
+    /// Otherwise, this is empty.
+    Changed(Vec<JsonChange>),
+}
+
+/// A value that was added, removed, or changed within a JSON resource.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonChange {
+    /// The location of the value within the resource, as a [JSON pointer]
+    /// (e.g., `/textures/all`).
+    ///
+    /// This is empty if the resource changed from or to something other than
+    /// an object or an array.
+    ///
+    /// [JSON pointer]: <https://datatracker.ietf.org/doc/html/rfc6901>
+    pub json_path: String,
+
+    /// The value in the old pack, or `None` if the value was added.
+    pub old: Option<Value>,
+
+    /// The value in the new pack, or `None` if the value was removed.
+    pub new: Option<Value>,
+}
+
+impl fmt::Display for JsonChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.json_path.is_empty() {
+            write!(f, "{}: ", self.json_path)?;
+        }
+
+        match (&self.old, &self.new) {
+            (Some(old), Some(new)) => write!(f, "{} -> {}", old, new),
+            (None, Some(new)) => write!(f, "added {}", new),
+            (Some(old), None) => write!(f, "removed {}", old),
+            (None, None) => write!(f, "unchanged"),
+        }
+    }
+}
+
+/// The number of resources of one kind that changed, as returned by
+/// [`PackDiff::summary()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KindSummary {
+    /// The kind of the resources.
+    pub kind: ResourceKind,
+
+    /// The number of added resources.
+    pub added: usize,
+
+    /// The number of removed resources.
+    pub removed: usize,
+
+    /// The number of changed resources.
+    pub changed: usize,
+}
+
+/// Returns the values that differ between two JSON values.
+///
+/// Objects are compared field by field, and arrays element by element, so
+/// that inserting an element into an array reports every element after it.
+///
+/// # Example
+///
+/// ```
+/// use minecraft_assets::api::diff::diff_json;
+///
+/// let old = serde_json::json!({ "values": ["a", "b"], "replace": false });
+/// let new = serde_json::json!({ "values": ["a", "c", "d"], "replace": false });
+///
+/// let changes: Vec<_> = diff_json(&old, &new).iter().map(ToString::to_string).collect();
+/// assert_eq!(changes, [r#"/values/1: "b" -> "c""#, r#"/values/2: added "d""#]);
+/// ```
+pub fn diff_json(old: &Value, new: &Value) -> Vec<JsonChange> {
+    let mut changes = Vec::new();
+    diff_values(&mut String::new(), old, new, &mut changes);
+    changes
+}
+
+fn diff_values(path: &mut String, old: &Value, new: &Value, changes: &mut Vec<JsonChange>) {
+    match (old, new) {
+        (Value::Object(old), Value::Object(new)) => {
+            for (key, old_value) in old {
+                let len = path.len();
+                push_token(path, key);
+                match new.get(key) {
+                    Some(new_value) => diff_values(path, old_value, new_value, changes),
+                    None => changes.push(change(path, Some(old_value), None)),
+                }
+                path.truncate(len);
+            }
+            for (key, new_value) in new {
+                if !old.contains_key(key) {
+                    let len = path.len();
+                    push_token(path, key);
+                    changes.push(change(path, None, Some(new_value)));
+                    path.truncate(len);
+                }
+            }
+        }
+        (Value::Array(old), Value::Array(new)) => {
+            for i in 0..old.len().max(new.len()) {
+                let len = path.len();
+                push_token(path, &i.to_string());
+                match (old.get(i), new.get(i)) {
+                    (Some(old_value), Some(new_value)) => {
+                        diff_values(path, old_value, new_value, changes)
+                    }
+                    (old_value, new_value) => changes.push(change(path, old_value, new_value)),
+                }
+                path.truncate(len);
+            }
+        }
+        (old, new) if old != new => changes.push(change(path, Some(old), Some(new))),
+        _ => {}
+    }
+}
+
+fn change(path: &str, old: Option<&Value>, new: Option<&Value>) -> JsonChange {
+    JsonChange {
+        json_path: path.to_string(),
+        old: old.cloned(),
+        new: new.cloned(),
+    }
+}
+
+/// Appends a token to a JSON pointer, escaping it.
+fn push_token(path: &mut String, token: &str) {
+    path.push('/');
+    path.push_str(&token.replace('~', "~0").replace('/', "~1"));
+}
+
+/// The key that orders the changes of a [`PackDiff`].
+type SortKey = (usize, String, String);
+
+/// Lists the resources of the given kind, keyed in sorted order.
+///
+/// Kinds that the provider does not support (e.g., because of its target
+/// version) have no resources.
+fn list(
+    provider: &dyn ResourceProvider,
+    kind: ResourceKind,
+) -> Result<BTreeMap<SortKey, ResourceIdentifier<'static>>> {
+    let ids = match provider.list(kind) {
+        Ok(ids) => ids,
+        Err(e) if e.kind() == io::ErrorKind::Unsupported => Vec::new(),
+        Err(e) => return Err(e.into()),
+    };
+
+    let index = ResourceKind::ALL
+        .iter()
+        .position(|k| *k == kind)
+        .unwrap_or(usize::MAX);
+    Ok(ids
+        .into_iter()
+        .map(|id| {
+            let key = (index, id.namespace().to_string(), id.path().to_string());
+            (key, id)
+        })
+        .collect())
+}
+
+/// Compares the resource with the given id in both packs, and returns the
+/// values that changed, or `None` if it did not change.
+fn compare_resource(
+    old: &dyn ResourceProvider,
+    new: &dyn ResourceProvider,
+    id: &ResourceIdentifier,
+) -> Result<Option<Vec<JsonChange>>> {
+    let old_data = old.load_resource(id)?;
+    let new_data = new.load_resource(id)?;
+    if old_data == new_data {
+        return Ok(None);
+    }
+
+    if is_json(id.kind()) {
+        let old_value = serde_json::from_slice::<Value>(&old_data);
+        let new_value = serde_json::from_slice::<Value>(&new_data);
+        if let (Ok(old_value), Ok(new_value)) = (old_value, new_value) {
+            let changes = diff_json(&old_value, &new_value);
+            return Ok(if changes.is_empty() {
+                None
+            } else {
+                Some(changes)
+            });
+        }
+    }
+
+    Ok(Some(Vec::new()))
+}
+
+fn is_json(kind: ResourceKind) -> bool {
+    let extension = kind.extension();
+    extension.ends_with("json") || extension.ends_with("mcmeta")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::api::MemoryResourceProvider;
+
+    #[test]
+    fn ignores_formatting_and_compares_bytes() {
+        let mut old = MemoryResourceProvider::new();
+        old.insert(
+            "assets/minecraft/models/block/stone.json",
+            br#"{"parent":"block/cube_all","textures":{"all":"block/stone"}}"#.to_vec(),
+        );
+        old.insert("assets/minecraft/textures/block/stone.png", vec![1, 2, 3]);
+
+        let mut new = MemoryResourceProvider::new();
+        new.insert(
+            "assets/minecraft/models/block/stone.json",
+            br#"{
+                "textures": { "all": "block/stone" },
+                "parent": "block/cube_all"
+            }"#
+            .to_vec(),
+        );
+        new.insert("assets/minecraft/textures/block/stone.png", vec![1, 2, 4]);
+
+        let diff = PackDiff::compare(&old, &new).unwrap();
+        assert_eq!(
+            diff.changes,
+            [ResourceChange {
+                resource: ResourceIdentifier::texture("block/stone"),
+                change: ChangeKind::Changed(Vec::new()),
+            }]
+        );
+        assert_eq!(
+            diff.changes[0].to_string(),
+            "~ assets/minecraft/textures/block/stone.png"
+        );
+    }
+}
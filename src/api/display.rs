@@ -0,0 +1,120 @@
+//! Converting the display transforms of models into matrices.
+//!
+//! Start here: [`DisplayTransforms`].
+//!
+//! The transforms are applied like the game applies them when it renders an
+//! item: the translation (in 1/16ths of a block, clamped to ±80) first, then
+//! the rotation around the X, Y, and Z axes, in that order, then the scale
+//! (clamped to ±4). In the left hand, the transform is mirrored along the X
+//! axis.
+//!
+//! The matrices transform the vertices of a model in blocks, centered on the
+//! origin, i.e., the game translates every vertex by `(-0.5, -0.5, -0.5)`
+//! (like the positions of a [`ModelMesh`]) before applying them.
+//!
+//! [`ModelMesh`]: crate::api::mesh::ModelMesh
+
+use glam::{EulerRot, Mat4, Quat, Vec3};
+
+use crate::schemas::models::{DisplayContext, GuiLightMode, Model, Transform};
+
+/// The display transforms of a model as matrices, for every
+/// [`DisplayContext`].
+///
+/// The model should be [resolved] first, so that the display transforms and
+/// GUI light mode of its parents are included.
+///
+/// [resolved]: crate::api::ModelResolver::resolve_model
+///
+/// # Example
+///
+/// ```
+/// use glam::Vec3;
+/// use minecraft_assets::api::display::DisplayTransforms;
+/// use minecraft_assets::schemas::models::{DisplayContext, GuiLightMode, Model};
+///
+/// let model: Model = serde_json::from_str(r#"{
+///     "gui_light": "front",
+///     "display": {
+///         "firstperson_righthand": { "rotation": [0, -90, 25], "translation": [1.13, 3.2, 1.13], "scale": [0.68, 0.68, 0.68] },
+///         "ground": { "translation": [0, 2, 0], "scale": [0.5, 0.5, 0.5] }
+///     }
+/// }"#).unwrap();
+///
+/// let transforms = DisplayTransforms::from_model(&model);
+/// assert_eq!(transforms.gui_light, GuiLightMode::Front);
+///
+/// let ground = transforms.matrix(DisplayContext::Ground);
+/// assert_eq!(ground.transform_point3(Vec3::new(0.5, 0.5, 0.5)), Vec3::new(0.25, 0.375, 0.25));
+///
+/// // The left hand mirrors the right hand.
+/// let right = transforms.matrix(DisplayContext::FirstPersonRightHand);
+/// let left = transforms.matrix(DisplayContext::FirstPersonLeftHand);
+/// let point = Vec3::new(0.5, 0.0, 0.0);
+/// let mirrored = left.transform_point3(point * Vec3::new(-1.0, 1.0, 1.0));
+/// assert!(right.transform_point3(point).abs_diff_eq(mirrored * Vec3::new(-1.0, 1.0, 1.0), 1e-6));
+///
+/// assert_eq!(transforms.matrix(DisplayContext::Gui), glam::Mat4::IDENTITY);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DisplayTransforms {
+    /// The matrices of the contexts, in the order of [`DisplayContext::ALL`].
+    matrices: [Mat4; 8],
+
+    /// How the model is shaded in the GUI. Defaults to
+    /// [`GuiLightMode::Side`].
+    pub gui_light: GuiLightMode,
+}
+
+impl DisplayTransforms {
+    /// Returns the display transforms of the given model.
+    ///
+    /// Contexts without a transform use the identity matrix.
+    pub fn from_model(model: &Model) -> Self {
+        let matrices = DisplayContext::ALL.map(|context| {
+            model
+                .display
+                .as_ref()
+                .and_then(|display| display.get(context))
+                .map_or(Mat4::IDENTITY, |transform| {
+                    transform_matrix(transform, context.is_left_hand())
+                })
+        });
+
+        Self {
+            matrices,
+            gui_light: model.gui_light_mode.unwrap_or(GuiLightMode::Side),
+        }
+    }
+
+    /// Returns the matrix of the given context.
+    pub fn matrix(&self, context: DisplayContext) -> Mat4 {
+        let index = DisplayContext::ALL
+            .iter()
+            .position(|c| *c == context)
+            .unwrap_or_default();
+        self.matrices[index]
+    }
+}
+
+/// Returns the matrix of the given transform, mirrored along the X axis if
+/// the model is held in the left hand.
+pub fn transform_matrix(transform: &Transform, left_hand: bool) -> Mat4 {
+    let [x, y, z] = transform.translation.map(|t| (t / 16.0).clamp(-5.0, 5.0));
+    let [rx, ry, rz] = transform.rotation.map(f32::to_radians);
+    let scale = transform.scale.map(|s| s.clamp(-4.0, 4.0));
+
+    let (translation, rotation) = if left_hand {
+        (
+            Vec3::new(-x, y, z),
+            Quat::from_euler(EulerRot::XYZ, rx, -ry, -rz),
+        )
+    } else {
+        (
+            Vec3::new(x, y, z),
+            Quat::from_euler(EulerRot::XYZ, rx, ry, rz),
+        )
+    };
+
+    Mat4::from_scale_rotation_translation(Vec3::from(scale), rotation, translation)
+}
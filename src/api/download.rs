@@ -0,0 +1,329 @@
+//! Downloading vanilla client jars using the launcher's version manifest.
+//!
+//! Start here: [`Downloader`].
+//!
+//! This module requires the `download` feature.
+//!
+//! See <https://minecraft.fandom.com/wiki/Version_manifest.json>.
+
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::api::{pack_hash::PackHash, Result, ZipResourceProvider};
+use serde::{Deserialize, Serialize};
+
+/// The URL of the launcher's version manifest.
+pub const VERSION_MANIFEST_URL: &str =
+    "https://piston-meta.mojang.com/mc/game/version_manifest_v2.json";
+
+/// Downloads vanilla client jars into a cache directory and provides
+/// resources from them.
+///
+/// Jars are cached in the same layout as the launcher's `versions/`
+/// directory, i.e., `<cache_dir>/versions/<id>/<id>.jar` next to the
+/// version's `<id>.json`, so a `.minecraft/` directory may be used as the
+/// cache directory. Cached jars are verified against their SHA-1 hash and only
+/// downloaded again if they do not match.
+///
+/// # Example
+///
+/// ```no_run
+/// use minecraft_assets::api::{download::Downloader, AssetPack};
+///
+/// let downloader = Downloader::new("~/.cache/minecraft-assets/");
+///
+/// let manifest = downloader.fetch_manifest().unwrap();
+/// let provider = downloader.client_provider(&manifest.latest.release).unwrap();
+///
+/// let assets = AssetPack::new(provider);
+/// let states = assets.load_blockstates("oak_planks").unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct Downloader {
+    cache_dir: PathBuf,
+}
+
+impl Downloader {
+    /// Returns a new downloader that caches files in the given directory.
+    pub fn new(cache_dir: impl AsRef<Path>) -> Self {
+        Self {
+            cache_dir: PathBuf::from(cache_dir.as_ref()),
+        }
+    }
+
+    /// Returns the directory in which files are cached.
+    pub fn cache_dir(&self) -> &Path {
+        &self.cache_dir
+    }
+
+    /// Fetches the launcher's [`VersionManifest`].
+    pub fn fetch_manifest(&self) -> Result<VersionManifest> {
+        let bytes = fetch(VERSION_MANIFEST_URL)?;
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+
+    /// Fetches the [`VersionMeta`] of the given version of the manifest.
+    ///
+    /// The file is verified against the SHA-1 hash in the manifest, if there
+    /// is one, and then saved to the cache directory.
+    pub fn fetch_version_meta(&self, version: &ManifestVersion) -> Result<VersionMeta> {
+        let bytes = fetch(&version.url)?;
+        if let Some(sha1) = &version.sha1 {
+            PackHash::from_bytes(&bytes).verify(sha1)?;
+        }
+
+        let meta = serde_json::from_slice(&bytes)?;
+        write_file(&self.version_file(&version.id, "json"), &bytes)?;
+
+        Ok(meta)
+    }
+
+    /// Returns the path of the client jar of the given version (e.g.,
+    /// `"1.18.2"`), downloading it first if it is not cached yet.
+    ///
+    /// Returns an error of kind [`io::ErrorKind::NotFound`] if the version is
+    /// not in the manifest, or of kind [`io::ErrorKind::InvalidData`] if the
+    /// downloaded jar does not match its SHA-1 hash.
+    pub fn download_client(&self, version_id: &str) -> Result<PathBuf> {
+        let jar_path = self.version_file(version_id, "jar");
+
+        // Reuse the cached jar if it matches the cached version meta.
+        if let Some(meta) = self.cached_version_meta(version_id) {
+            if let Ok(bytes) = fs::read(&jar_path) {
+                if PackHash::from_bytes(&bytes)
+                    .verify(&meta.downloads.client.sha1)
+                    .is_ok()
+                {
+                    return Ok(jar_path);
+                }
+            }
+        }
+
+        let manifest = self.fetch_manifest()?;
+        let version = manifest.get(version_id).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("version {:?} is not in the version manifest", version_id),
+            )
+        })?;
+        let meta = self.fetch_version_meta(version)?;
+
+        let client = &meta.downloads.client;
+        let bytes = fetch(&client.url)?;
+        PackHash::from_bytes(&bytes).verify(&client.sha1)?;
+        write_file(&jar_path, &bytes)?;
+
+        Ok(jar_path)
+    }
+
+    /// Returns a [`ZipResourceProvider`] for the client jar of the given
+    /// version, downloading it first if it is not cached yet.
+    ///
+    /// See [`download_client()`][Self::download_client].
+    pub fn client_provider(&self, version_id: &str) -> Result<ZipResourceProvider> {
+        let jar_path = self.download_client(version_id)?;
+        Ok(ZipResourceProvider::open(jar_path)?)
+    }
+
+    fn cached_version_meta(&self, version_id: &str) -> Option<VersionMeta> {
+        let bytes = fs::read(self.version_file(version_id, "json")).ok()?;
+        serde_json::from_slice(&bytes).ok()
+    }
+
+    fn version_file(&self, version_id: &str, extension: &str) -> PathBuf {
+        self.cache_dir
+            .join("versions")
+            .join(version_id)
+            .join(format!("{}.{}", version_id, extension))
+    }
+}
+
+/// The launcher's list of every available version, as stored in
+/// `version_manifest_v2.json`.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::api::download::*;
+/// let manifest: VersionManifest = serde_json::from_str(r#"{
+///     "latest": { "release": "1.18.2", "snapshot": "22w12a" },
+///     "versions": [
+///         {
+///             "id": "1.18.2",
+///             "type": "release",
+///             "url": "https://piston-meta.mojang.com/v1/packages/1fd5fb8d.../1.18.2.json",
+///             "time": "2022-02-28T10:42:45+00:00",
+///             "releaseTime": "2022-02-28T10:42:45+00:00",
+///             "sha1": "1fd5fb8d3685e8ab9e5a8f8a2e4cd1e24b0bfc25",
+///             "complianceLevel": 1
+///         }
+///     ]
+/// }"#).unwrap();
+///
+/// let version = manifest.get(&manifest.latest.release).unwrap();
+/// assert_eq!(version.kind, VersionType::Release);
+/// assert_eq!(version.compliance_level, Some(1));
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct VersionManifest {
+    /// The ids of the latest versions.
+    pub latest: LatestVersions,
+
+    /// Every available version, from newest to oldest.
+    pub versions: Vec<ManifestVersion>,
+}
+
+impl VersionManifest {
+    /// Returns the version with the given id, if there is one.
+    pub fn get(&self, version_id: &str) -> Option<&ManifestVersion> {
+        self.versions
+            .iter()
+            .find(|version| version.id == version_id)
+    }
+}
+
+/// The ids of the latest versions in a [`VersionManifest`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct LatestVersions {
+    /// The id of the latest release.
+    pub release: String,
+
+    /// The id of the latest snapshot.
+    pub snapshot: String,
+}
+
+/// A version in a [`VersionManifest`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestVersion {
+    /// The id of the version, e.g., `"1.18.2"`.
+    pub id: String,
+
+    /// The type of the version.
+    #[serde(rename = "type")]
+    pub kind: VersionType,
+
+    /// The URL of the version's [`VersionMeta`].
+    pub url: String,
+
+    /// The time at which the version was last updated.
+    pub time: String,
+
+    /// The time at which the version was released.
+    pub release_time: String,
+
+    /// The SHA-1 hash of the version's [`VersionMeta`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sha1: Option<String>,
+
+    /// Whether the version supports the player safety features of 1.16.4.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compliance_level: Option<u32>,
+}
+
+/// The type of a [`ManifestVersion`].
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum VersionType {
+    /// A full release.
+    Release,
+
+    /// A snapshot, pre-release, or release candidate.
+    Snapshot,
+
+    /// A Beta version.
+    OldBeta,
+
+    /// An Alpha version.
+    OldAlpha,
+}
+
+/// The metadata of a single version, as stored in `versions/<id>/<id>.json`.
+///
+/// Only the parts that are needed to download the version's assets are
+/// included.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct VersionMeta {
+    /// The id of the version, e.g., `"1.18.2"`.
+    pub id: String,
+
+    /// The type of the version.
+    #[serde(rename = "type")]
+    pub kind: VersionType,
+
+    /// The files that can be downloaded for the version.
+    pub downloads: VersionDownloads,
+
+    /// The asset index that lists the version's hashed assets.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_index: Option<AssetIndexInfo>,
+}
+
+/// The files that can be downloaded for a [`VersionMeta`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct VersionDownloads {
+    /// The client jar.
+    pub client: DownloadInfo,
+
+    /// The server jar.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server: Option<DownloadInfo>,
+}
+
+/// A file that can be downloaded.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct DownloadInfo {
+    /// The SHA-1 hash of the file.
+    pub sha1: String,
+
+    /// The size of the file, in bytes.
+    pub size: u64,
+
+    /// The URL of the file.
+    pub url: String,
+}
+
+/// The asset index of a [`VersionMeta`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetIndexInfo {
+    /// The id of the asset index, e.g., `"1.18"`.
+    pub id: String,
+
+    /// The SHA-1 hash of the asset index.
+    pub sha1: String,
+
+    /// The size of the asset index, in bytes.
+    pub size: u64,
+
+    /// The total size of every asset in the index, in bytes.
+    pub total_size: u64,
+
+    /// The URL of the asset index.
+    pub url: String,
+}
+
+/// Returns the body of a `GET` request to the given URL.
+fn fetch(url: &str) -> Result<Vec<u8>, io::Error> {
+    let response = ureq::get(url).call().map_err(io::Error::other)?;
+
+    let mut bytes = Vec::new();
+    response.into_reader().read_to_end(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Writes the given bytes to a temporary file next to the given path and then
+/// renames it, so that interrupted downloads never leave a partial file.
+fn write_file(path: &Path, bytes: &[u8]) -> Result<(), io::Error> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let temp_path = path.with_extension("part");
+    fs::write(&temp_path, bytes)?;
+    fs::rename(temp_path, path)
+}
@@ -0,0 +1,340 @@
+//! Assembling complete resource packs and data packs in memory.
+//!
+//! Start here: [`PackBuilder`].
+
+use std::{fs, path::Path};
+
+use serde::Serialize;
+
+use crate::{
+    api::{
+        writer, MemoryResourceProvider, ResourceIdentifier, ResourceKind, ResourceWriter, Result,
+        TargetVersion,
+    },
+    schemas::{
+        texture::Texture, worldgen::biome::CustomeBiome, Advancement, BlockStates, LanguageFile,
+        LootTable, Model, PackMeta, Recipe, SoundsJson, Tag,
+    },
+};
+
+/// Assembles a resource pack or data pack in memory, and writes it out as a
+/// directory or a `.zip` archive.
+///
+/// Resources are added through the namespace that they belong to, returned
+/// by [`namespace()`][Self::namespace], and are serialized as soon as they are
+/// added. The `pack.mcmeta` file is written from the [`PackMeta`] of the
+/// builder when the pack is written out.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::api::{generator::*, *};
+/// use minecraft_assets::schemas::{
+///     blockstates::{BlockStatesBuilder, ModelProperties},
+///     models::ModelBuilder,
+/// };
+///
+/// let mut pack = PackBuilder::resource_pack("Rubies", TargetVersion::V1_21);
+/// pack.namespace("rubies")
+///     .blockstates("ruby_block", &BlockStatesBuilder::single(ModelProperties::new("rubies:block/ruby_block")?).build())?
+///     .block_model("ruby_block", &ModelBuilder::cube_all("rubies:block/ruby_block").build())?
+///     .texture("block/ruby_block", b"(PNG data)")?;
+///
+/// let assets = AssetPack::new(pack.build()?);
+/// assert_eq!(assets.load_pack_meta()?.pack.pack_format, 34);
+///
+/// let model = assets.load_block_model("rubies:ruby_block")?;
+/// assert_eq!(model.parent.unwrap(), "block/cube_all");
+/// # Ok::<(), Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct PackBuilder {
+    meta: PackMeta,
+    files: MemoryResourceProvider,
+    canonical: bool,
+}
+
+impl PackBuilder {
+    /// Returns a builder for a pack with the given metadata, using the
+    /// default directory layout.
+    pub fn new(meta: PackMeta) -> Self {
+        Self {
+            meta,
+            files: MemoryResourceProvider::new(),
+            canonical: false,
+        }
+    }
+
+    /// Returns a builder for a resource pack for the given version, using its
+    /// resource pack format and directory layout.
+    pub fn resource_pack(description: impl Into<String>, version: TargetVersion) -> Self {
+        let meta = PackMeta::new(version.resource_pack_format(), description);
+        Self::new(meta).with_target_version(version)
+    }
+
+    /// Returns a builder for a data pack for the given version, using its
+    /// data pack format and directory layout.
+    pub fn data_pack(description: impl Into<String>, version: TargetVersion) -> Self {
+        let meta = PackMeta::new(version.data_pack_format(), description);
+        Self::new(meta).with_target_version(version)
+    }
+
+    /// Uses the directory layout of the given [`TargetVersion`] for the
+    /// resources added afterwards, and returns `self`.
+    pub fn with_target_version(mut self, version: TargetVersion) -> Self {
+        self.files = self.files.with_target_version(version);
+        self
+    }
+
+    /// Serializes the resources added afterwards in the [canonical] format of
+    /// the game's data generator, and returns `self`.
+    ///
+    /// [canonical]: crate::schemas::canonical
+    pub fn with_canonical_json(mut self) -> Self {
+        self.canonical = true;
+        self
+    }
+
+    /// Returns the metadata written to `pack.mcmeta`.
+    pub fn meta(&self) -> &PackMeta {
+        &self.meta
+    }
+
+    /// Returns the metadata written to `pack.mcmeta`, e.g., to add supported
+    /// formats or overlays.
+    pub fn meta_mut(&mut self) -> &mut PackMeta {
+        &mut self.meta
+    }
+
+    /// Returns a handle for adding resources to the given namespace.
+    pub fn namespace(&mut self, namespace: impl Into<String>) -> PackNamespace<'_> {
+        PackNamespace {
+            pack: self,
+            namespace: namespace.into(),
+        }
+    }
+
+    /// Serializes the given value and adds it as the resource referenced by
+    /// the given [`ResourceIdentifier`].
+    pub fn add_json<T>(&mut self, id: &ResourceIdentifier, value: &T) -> Result<&mut Self>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.writer().write_json(id, value)?;
+        Ok(self)
+    }
+
+    /// Adds the given raw bytes as the resource referenced by the given
+    /// [`ResourceIdentifier`].
+    pub fn add_bytes(&mut self, id: &ResourceIdentifier, data: &[u8]) -> Result<&mut Self> {
+        self.writer().write_bytes(id, data)?;
+        Ok(self)
+    }
+
+    /// Adds a file at the root of the pack, such as `pack.png`.
+    pub fn add_root_file(&mut self, name: &str, data: &[u8]) -> &mut Self {
+        self.files.insert(name, data.to_vec());
+        self
+    }
+
+    /// Returns the paths of the files of the pack relative to its root, in
+    /// sorted order, not including `pack.mcmeta`.
+    pub fn paths(&self) -> impl Iterator<Item = &str> {
+        self.files().map(|(path, _)| path)
+    }
+
+    /// Returns a provider with the files of the pack, including
+    /// `pack.mcmeta`.
+    pub fn build(mut self) -> Result<MemoryResourceProvider> {
+        let meta = self.meta_json()?;
+        self.files.insert("pack.mcmeta", meta);
+        Ok(self.files)
+    }
+
+    /// Writes the pack into the given directory, creating it and any
+    /// subdirectories as needed.
+    ///
+    /// Files that are already in the directory but not in the pack are left
+    /// alone.
+    pub fn write_to_dir(&self, root_dir: impl AsRef<Path>) -> Result<()> {
+        let root_dir = root_dir.as_ref();
+
+        for (path, data) in self.files() {
+            let path = root_dir.join(path);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(path, data)?;
+        }
+
+        fs::create_dir_all(root_dir)?;
+        fs::write(root_dir.join("pack.mcmeta"), self.meta_json()?)?;
+        Ok(())
+    }
+
+    /// Writes the pack as a `.zip` archive into the given writer, and returns
+    /// the writer.
+    ///
+    /// The archive is written by a [`ZipPackWriter`] with its default
+    /// options, so it is reproducible.
+    ///
+    /// Requires the `zip` feature.
+    ///
+    /// [`ZipPackWriter`]: crate::api::ZipPackWriter
+    #[cfg(feature = "zip")]
+    pub fn write_zip<W>(&self, writer: W) -> Result<W>
+    where
+        W: std::io::Write + std::io::Seek,
+    {
+        let mut zip = crate::api::ZipPackWriter::new();
+        zip.insert("pack.mcmeta", self.meta_json()?);
+        for (path, data) in self.files() {
+            zip.insert(path, data.to_vec());
+        }
+
+        zip.finish(writer)
+    }
+
+    fn files(&self) -> impl Iterator<Item = (&str, &[u8])> {
+        self.files
+            .paths()
+            .filter(|path| *path != "pack.mcmeta")
+            .filter_map(|path| Some((path, self.files.get(path)?)))
+    }
+
+    fn writer(&mut self) -> ResourceWriter<'_> {
+        let writer = ResourceWriter::new(&mut self.files);
+        if self.canonical {
+            writer.with_canonical_json()
+        } else {
+            writer
+        }
+    }
+
+    fn meta_json(&self) -> Result<Vec<u8>> {
+        writer::to_json(&self.meta, self.canonical)
+    }
+}
+
+/// A handle for adding resources to one namespace of a [`PackBuilder`].
+///
+/// The names and paths given to its methods are relative to the namespace,
+/// e.g., `ruby_block` for the block model at
+/// `assets/<namespace>/models/block/ruby_block.json`. Each method returns the
+/// handle again, so that calls can be chained.
+#[derive(Debug)]
+pub struct PackNamespace<'a> {
+    pack: &'a mut PackBuilder,
+    namespace: String,
+}
+
+impl<'a> PackNamespace<'a> {
+    /// Returns the namespace.
+    pub fn name(&self) -> &str {
+        &self.namespace
+    }
+
+    fn id(&self, kind: ResourceKind, path: &str) -> ResourceIdentifier<'static> {
+        ResourceIdentifier::new_owned(kind, format!("{}:{}", self.namespace, path))
+    }
+
+    /// Serializes the given value and adds it as the resource of the given
+    /// kind at the given path.
+    ///
+    /// This can be used to add any JSON resource, including those without a
+    /// dedicated method.
+    pub fn json<T>(&mut self, kind: ResourceKind, path: &str, value: &T) -> Result<&mut Self>
+    where
+        T: Serialize + ?Sized,
+    {
+        let id = self.id(kind, path);
+        self.pack.add_json(&id, value)?;
+        Ok(self)
+    }
+
+    /// Adds the given raw bytes as the resource of the given kind at the given
+    /// path.
+    pub fn bytes(&mut self, kind: ResourceKind, path: &str, data: &[u8]) -> Result<&mut Self> {
+        let id = self.id(kind, path);
+        self.pack.add_bytes(&id, data)?;
+        Ok(self)
+    }
+
+    /// Adds the [`BlockStates`] of the block with the given name.
+    pub fn blockstates(&mut self, block: &str, states: &BlockStates) -> Result<&mut Self> {
+        self.json(ResourceKind::BlockStates, block, states)
+    }
+
+    /// Adds the block [`Model`] with the given name or path.
+    pub fn block_model(&mut self, model: &str, value: &Model) -> Result<&mut Self> {
+        self.json(ResourceKind::BlockModel, model, value)
+    }
+
+    /// Adds the item [`Model`] with the given name or path.
+    pub fn item_model(&mut self, model: &str, value: &Model) -> Result<&mut Self> {
+        self.json(ResourceKind::ItemModel, model, value)
+    }
+
+    /// Adds the `.png` texture at the given path, e.g., `block/ruby_block`.
+    pub fn texture(&mut self, path: &str, png: &[u8]) -> Result<&mut Self> {
+        self.bytes(ResourceKind::Texture, path, png)
+    }
+
+    /// Adds the [`Texture`] metadata (`.png.mcmeta`) of the texture at the
+    /// given path.
+    pub fn texture_meta(&mut self, path: &str, meta: &Texture) -> Result<&mut Self> {
+        self.json(ResourceKind::TextureMeta, path, meta)
+    }
+
+    /// Adds the [`LanguageFile`] for the language with the given code.
+    pub fn language(&mut self, code: &str, language: &LanguageFile) -> Result<&mut Self> {
+        self.json(ResourceKind::Language, code, language)
+    }
+
+    /// Adds the [`LanguageFile`] for the language with the given code as a
+    /// legacy `.lang` file, used before 1.13.
+    pub fn legacy_language(&mut self, code: &str, language: &LanguageFile) -> Result<&mut Self> {
+        self.bytes(
+            ResourceKind::LegacyLanguage,
+            code,
+            language.to_legacy().as_bytes(),
+        )
+    }
+
+    /// Adds the `sounds.json` file of the namespace.
+    pub fn sounds(&mut self, sounds: &SoundsJson) -> Result<&mut Self> {
+        let id = ResourceIdentifier::sounds(&self.namespace);
+        self.pack.add_json(&id, sounds)?;
+        Ok(self)
+    }
+
+    /// Adds the [`LootTable`] at the given path.
+    pub fn loot_table(&mut self, path: &str, loot_table: &LootTable) -> Result<&mut Self> {
+        self.json(ResourceKind::LootTable, path, loot_table)
+    }
+
+    /// Adds the [`Recipe`] at the given path.
+    pub fn recipe(&mut self, path: &str, recipe: &Recipe) -> Result<&mut Self> {
+        self.json(ResourceKind::Recipe, path, recipe)
+    }
+
+    /// Adds the [`Advancement`] at the given path.
+    pub fn advancement(&mut self, path: &str, advancement: &Advancement) -> Result<&mut Self> {
+        self.json(ResourceKind::Advancement, path, advancement)
+    }
+
+    /// Adds the [`Tag`] of the given kind at the given path.
+    pub fn tag(&mut self, kind: ResourceKind, path: &str, tag: &Tag) -> Result<&mut Self> {
+        self.json(kind, path, tag)
+    }
+
+    /// Adds the `.mcfunction` function at the given path.
+    pub fn function(&mut self, path: &str, source: &str) -> Result<&mut Self> {
+        self.bytes(ResourceKind::Function, path, source.as_bytes())
+    }
+
+    /// Adds the [`CustomeBiome`] at the given path.
+    pub fn biome(&mut self, path: &str, biome: &CustomeBiome) -> Result<&mut Self> {
+        self.json(ResourceKind::WorldGen_Biome, path, biome)
+    }
+}
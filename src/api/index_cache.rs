@@ -0,0 +1,360 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    time::UNIX_EPOCH,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::{
+    provider::is_missing, EnumerateResources, LoadResource, ResourceCategory, ResourceIdentifier,
+    ResourceKind, ResourceProvider, Result,
+};
+
+/// A [`ResourceProvider`] that answers enumeration queries from an index of
+/// the resources of another provider, which is cached on disk between runs.
+///
+/// Enumerating the resources of many packs, like the dozens of mod jars of a
+/// modpack, means scanning every directory or archive again at each startup.
+/// This provider enumerates every namespace and kind of resource of the
+/// wrapped provider once, and saves the listing as a JSON file in a cache
+/// directory. Later runs load the listing instead, as long as the source of
+/// the pack has not changed.
+///
+/// The source is the directory, `.zip`, or `.jar` file that the wrapped
+/// provider reads. It has changed if the size or modification time of the
+/// file, or of any file or directory inside the directory, is different, or
+/// if files were added or removed. An index written by another version of
+/// this crate is rebuilt too.
+///
+/// Resources are always loaded from the wrapped provider. The index does not
+/// know about the [`TargetVersion`] of the wrapped provider, so providers
+/// with different layouts for the same source should use different cache
+/// directories.
+///
+/// [`TargetVersion`]: crate::api::TargetVersion
+///
+/// # Example
+///
+/// ```no_run
+/// # use minecraft_assets::api::*;
+/// let cache_dir = "~/.cache/minecraft-assets/index/";
+///
+/// let mut layers = LayeredResourceProvider::new();
+/// for pack in ["resourcepacks/a/", "resourcepacks/b/"] {
+///     let provider = FileSystemResourceProvider::new(pack);
+///     layers.push_layer(IndexedResourceProvider::new(provider, pack, cache_dir).unwrap());
+/// }
+///
+/// let models = layers.list(ResourceKind::BlockModel).unwrap();
+/// ```
+pub struct IndexedResourceProvider<P> {
+    provider: P,
+    index: ResourceIndex,
+    cached: bool,
+}
+
+impl<P: ResourceProvider> IndexedResourceProvider<P> {
+    /// Returns a provider for the given provider, which reads the pack at the
+    /// given source path, with the index cached in the given directory.
+    ///
+    /// The index is built and written to the cache directory (creating it if
+    /// needed) if it is missing or out of date.
+    pub fn new(provider: P, source: impl AsRef<Path>, cache_dir: impl AsRef<Path>) -> Result<Self> {
+        let source = fs::canonicalize(source)?;
+        let fingerprint = Fingerprint::of(&source)?;
+
+        let cache_path = cache_dir.as_ref().join(format!(
+            "{:016x}.json",
+            fnv1a(source.to_string_lossy().as_bytes())
+        ));
+
+        let cached = fs::read(&cache_path)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice::<IndexFile>(&bytes).ok())
+            .filter(|file| {
+                file.version == env!("CARGO_PKG_VERSION")
+                    && file.source == source
+                    && file.fingerprint == fingerprint
+            });
+
+        if let Some(file) = cached {
+            return Ok(Self {
+                provider,
+                index: ResourceIndex::from_file(file),
+                cached: true,
+            });
+        }
+
+        let file = IndexFile::build(&provider, source, fingerprint)?;
+        if let Some(parent) = cache_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&cache_path, serde_json::to_vec(&file)?)?;
+
+        Ok(Self {
+            provider,
+            index: ResourceIndex::from_file(file),
+            cached: false,
+        })
+    }
+}
+
+impl<P> IndexedResourceProvider<P> {
+    /// Returns `true` if the index was loaded from the cache, or `false` if it
+    /// was built by enumerating the wrapped provider.
+    pub fn is_cached(&self) -> bool {
+        self.cached
+    }
+
+    /// Returns the wrapped provider.
+    pub fn into_inner(self) -> P {
+        self.provider
+    }
+}
+
+impl<P: EnumerateResources> EnumerateResources for IndexedResourceProvider<P> {
+    fn enumerate_resources(
+        &self,
+        namespace: &str,
+        kind: ResourceKind,
+    ) -> Result<Vec<ResourceIdentifier<'static>>, io::Error> {
+        if let Some(namespaces) = self.index.namespaces.get(&kind.category()) {
+            if !namespaces.iter().any(|n| n == namespace) {
+                return Err(io::Error::from(io::ErrorKind::NotFound));
+            }
+        }
+
+        match self.index.resources.get(&(kind, namespace.to_string())) {
+            Some(Some(ids)) => Ok(ids.clone()),
+            Some(None) => Err(io::Error::from(io::ErrorKind::NotFound)),
+            None => self.provider.enumerate_resources(namespace, kind),
+        }
+    }
+
+    fn enumerate_namespaces(&self, category: ResourceCategory) -> Result<Vec<String>, io::Error> {
+        match self.index.namespaces.get(&category) {
+            Some(namespaces) => Ok(namespaces.clone()),
+            None => self.provider.enumerate_namespaces(category),
+        }
+    }
+}
+
+impl<P: LoadResource> LoadResource for IndexedResourceProvider<P> {
+    fn load_resource(&self, id: &ResourceIdentifier) -> Result<Vec<u8>, io::Error> {
+        self.provider.load_resource(id)
+    }
+
+    fn load_root_file(&self, name: &str) -> Result<Vec<u8>, io::Error> {
+        self.provider.load_root_file(name)
+    }
+
+    fn open_resource(&self, id: &ResourceIdentifier) -> Result<Box<dyn Read + '_>, io::Error> {
+        self.provider.open_resource(id)
+    }
+}
+
+/// The listing of a provider, in memory.
+///
+/// Namespaces and kinds that the provider could not enumerate (e.g., kinds
+/// that its target version does not have) are left out, and are enumerated by
+/// the provider instead. Kinds that it has no directory for are `None`.
+struct ResourceIndex {
+    namespaces: HashMap<ResourceCategory, Vec<String>>,
+    resources: HashMap<(ResourceKind, String), Option<Vec<ResourceIdentifier<'static>>>>,
+}
+
+impl ResourceIndex {
+    fn from_file(file: IndexFile) -> Self {
+        let mut namespaces = HashMap::new();
+        for category in [ResourceCategory::Assets, ResourceCategory::Data] {
+            if let Some(list) = file.namespaces.get(category.directory()) {
+                namespaces.insert(category, list.clone());
+            }
+        }
+
+        let mut resources = HashMap::new();
+        for &kind in ResourceKind::ALL {
+            let Some(by_namespace) = file.resources.get(&format!("{:?}", kind)) else {
+                continue;
+            };
+            for (namespace, ids) in by_namespace {
+                let ids = ids.as_ref().map(|ids| {
+                    ids.iter()
+                        .map(|id| ResourceIdentifier::new_owned(kind, id.clone()))
+                        .collect()
+                });
+                resources.insert((kind, namespace.clone()), ids);
+            }
+        }
+
+        Self {
+            namespaces,
+            resources,
+        }
+    }
+}
+
+/// The listing of a provider, as saved in the cache directory.
+#[derive(Serialize, Deserialize)]
+struct IndexFile {
+    /// The version of this crate, which may have different kinds.
+    version: String,
+    source: PathBuf,
+    fingerprint: Fingerprint,
+    /// The namespaces, by category directory.
+    namespaces: BTreeMap<String, Vec<String>>,
+    /// The IDs of the resources, by kind name and then by namespace.
+    resources: BTreeMap<String, BTreeMap<String, Option<Vec<String>>>>,
+}
+
+impl IndexFile {
+    fn build(
+        provider: &dyn ResourceProvider,
+        source: PathBuf,
+        fingerprint: Fingerprint,
+    ) -> Result<Self, io::Error> {
+        let mut namespaces = BTreeMap::new();
+        let mut resources: BTreeMap<_, BTreeMap<_, _>> = BTreeMap::new();
+
+        for category in [ResourceCategory::Assets, ResourceCategory::Data] {
+            let category_namespaces = match provider.enumerate_namespaces(category) {
+                Ok(category_namespaces) => category_namespaces,
+                Err(e) if is_missing(&e) => continue,
+                Err(e) => return Err(e),
+            };
+
+            for namespace in category_namespaces.iter() {
+                for &kind in ResourceKind::ALL {
+                    if kind.category() != category {
+                        continue;
+                    }
+
+                    let ids = match provider.enumerate_resources(namespace, kind) {
+                        Ok(ids) => Some(ids.iter().map(|id| id.as_str().to_string()).collect()),
+                        Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+                        Err(e) if is_missing(&e) => continue,
+                        Err(e) => return Err(e),
+                    };
+
+                    resources
+                        .entry(format!("{:?}", kind))
+                        .or_default()
+                        .insert(namespace.clone(), ids);
+                }
+            }
+
+            namespaces.insert(category.directory().to_string(), category_namespaces);
+        }
+
+        Ok(Self {
+            version: String::from(env!("CARGO_PKG_VERSION")),
+            source,
+            fingerprint,
+            namespaces,
+            resources,
+        })
+    }
+}
+
+/// A summary of the metadata of the files of a source, which changes when
+/// any of them changes.
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq)]
+struct Fingerprint {
+    entries: u64,
+    total_len: u64,
+    /// The latest modification time, in seconds and nanoseconds since the
+    /// Unix epoch.
+    modified: (u64, u32),
+}
+
+impl Fingerprint {
+    fn of(path: &Path) -> Result<Self, io::Error> {
+        let mut fingerprint = Self::default();
+        fingerprint.add(path, &fs::metadata(path)?)?;
+        Ok(fingerprint)
+    }
+
+    fn add(&mut self, path: &Path, metadata: &fs::Metadata) -> Result<(), io::Error> {
+        self.entries += 1;
+        self.total_len = self.total_len.wrapping_add(metadata.len());
+
+        if let Ok(since_epoch) = metadata.modified()?.duration_since(UNIX_EPOCH) {
+            let modified = (since_epoch.as_secs(), since_epoch.subsec_nanos());
+            self.modified = self.modified.max(modified);
+        }
+
+        if metadata.is_dir() {
+            for entry in fs::read_dir(path)? {
+                let entry = entry?;
+                self.add(&entry.path(), &entry.metadata()?)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The 64-bit FNV-1a hash, which unlike the hasher of the standard library is
+/// stable between releases, so it can name cache files.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::api::{FileSystemResourceProvider, MINECRAFT_NAMESPACE};
+
+    #[test]
+    fn rebuilds_the_index_when_the_source_changes() {
+        let root = std::env::temp_dir().join("minecraft-assets-index-cache-test");
+        let _ = fs::remove_dir_all(&root);
+        let pack = root.join("pack");
+        let cache = root.join("cache");
+
+        let lang = pack.join("assets/minecraft/lang");
+        fs::create_dir_all(&lang).unwrap();
+        fs::write(lang.join("en_us.json"), "{}").unwrap();
+
+        let open = || {
+            let provider = FileSystemResourceProvider::new(&pack);
+            IndexedResourceProvider::new(provider, &pack, &cache).unwrap()
+        };
+
+        let first = open();
+        assert!(!first.is_cached());
+        let second = open();
+        assert!(second.is_cached());
+        for provider in [&first, &second] {
+            let ids = provider
+                .enumerate_resources(MINECRAFT_NAMESPACE, ResourceKind::Language)
+                .unwrap();
+            assert_eq!(ids, [ResourceIdentifier::language("en_us")]);
+            assert_eq!(
+                provider
+                    .enumerate_namespaces(ResourceCategory::Assets)
+                    .unwrap(),
+                [MINECRAFT_NAMESPACE]
+            );
+            let error = provider
+                .enumerate_resources(MINECRAFT_NAMESPACE, ResourceKind::BlockModel)
+                .unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::NotFound);
+        }
+
+        fs::write(lang.join("fr_fr.json"), "{}").unwrap();
+        let third = open();
+        assert!(!third.is_cached());
+        let ids = third
+            .enumerate_resources(MINECRAFT_NAMESPACE, ResourceKind::Language)
+            .unwrap();
+        assert_eq!(ids.len(), 2);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,443 @@
+//! Generating the geometry of item models that inherit `builtin/generated`.
+//!
+//! Start here: [`generate_item_model()`].
+//!
+//! Items like sticks and swords have no elements of their own: their models
+//! inherit the built-in `builtin/generated` model, from which the game
+//! generates a flat element for each of the `layer0` to `layer4` textures and
+//! extrudes the edges of their opaque pixels into thin side faces. The
+//! generated [`Model`] can then be converted into a mesh like any other model
+//! with [`ModelMesh`].
+//!
+//! See `net.minecraft.client.renderer.block.model.ItemModelGenerator`.
+//!
+//! [`ModelMesh`]: crate::api::mesh::ModelMesh
+
+use std::collections::HashMap;
+
+use crate::{
+    api::Result,
+    schemas::models::{BlockFace, Element, ElementFace, Model, Texture},
+};
+
+/// The texture variables of the layers of a generated item model, in order.
+///
+/// Layers after the first missing variable are ignored.
+pub const LAYERS: [&str; 5] = ["layer0", "layer1", "layer2", "layer3", "layer4"];
+
+/// The alpha channel of the frames of a sprite, from which the side faces of
+/// a generated item model are extruded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpriteAlpha {
+    width: u32,
+    height: u32,
+    frames: Vec<Vec<u8>>,
+}
+
+impl SpriteAlpha {
+    /// Returns the alpha channel of a sprite of the given size, with the
+    /// alpha values of each frame given row by row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no frames, or if a frame does not have exactly
+    /// `width * height` values.
+    pub fn new(width: u32, height: u32, frames: Vec<Vec<u8>>) -> Self {
+        assert!(!frames.is_empty(), "a sprite has at least one frame");
+        for frame in &frames {
+            assert_eq!(frame.len(), (width * height) as usize, "wrong frame size");
+        }
+
+        Self {
+            width,
+            height,
+            frames,
+        }
+    }
+
+    /// Returns the alpha channel of a fully opaque sprite of the given size,
+    /// like the sprite that the game uses in place of missing textures.
+    pub fn opaque(width: u32, height: u32) -> Self {
+        Self::new(width, height, vec![vec![0xff; (width * height) as usize]])
+    }
+
+    /// Returns the alpha channel of the given texture.
+    ///
+    /// If the texture is animated, only the frames that its animation shows
+    /// are included, like in the game.
+    ///
+    /// This method requires the `image` feature.
+    #[cfg(feature = "image")]
+    pub fn from_image(
+        image: &image::RgbaImage,
+        animation: Option<&crate::schemas::texture::TextureAnimation>,
+    ) -> Self {
+        use super::atlas::{frame_origin, frame_size};
+
+        let (width, height) = frame_size(image, animation);
+        if width == 0 || height == 0 {
+            return Self::opaque(16, 16);
+        }
+
+        let frame_count = (image.width() / width) * (image.height() / height);
+        let mut indices = Vec::new();
+        if let Some(animation) = animation {
+            for (index, _) in animation.frame_times(frame_count) {
+                if !indices.contains(&index) {
+                    indices.push(index);
+                }
+            }
+        }
+        if indices.is_empty() {
+            indices.push(0);
+        }
+
+        let frames = indices
+            .into_iter()
+            .map(|index| {
+                let (x0, y0) = frame_origin(image.width(), width, height, index);
+                (0..height)
+                    .flat_map(|y| (0..width).map(move |x| image.get_pixel(x0 + x, y0 + y)[3]))
+                    .collect()
+            })
+            .collect();
+
+        Self::new(width, height, frames)
+    }
+
+    /// Returns the width of a frame, in pixels.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the height of a frame, in pixels.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns whether the given pixel of the given frame is fully
+    /// transparent. Pixels outside of the frame are transparent.
+    fn is_transparent(&self, frame: &[u8], x: i64, y: i64) -> bool {
+        if x < 0 || y < 0 || x >= i64::from(self.width) || y >= i64::from(self.height) {
+            return true;
+        }
+        frame[(y * i64::from(self.width) + x) as usize] == 0
+    }
+}
+
+/// Returns whether the topmost parent of the given chain of models (as
+/// returned by [`ModelResolver::load_parents()`]) is `builtin/generated`.
+///
+/// [`ModelResolver::load_parents()`]: crate::api::ModelResolver::load_parents
+pub fn is_generated(models: &[Model]) -> bool {
+    match models.last().and_then(|model| model.parent.as_ref()) {
+        Some(parent) => parent == "builtin/generated",
+        None => false,
+    }
+}
+
+/// Generates the elements of the given resolved item model from the sprites
+/// of its layers, like the game does for models that inherit
+/// `builtin/generated`.
+///
+/// The given function returns the alpha channel of the texture with the given
+/// location (e.g., `minecraft:item/stick`). The faces of the elements use the
+/// textures of the layers directly, and are tinted with the index of their
+/// layer. Like in the game, the `particle` texture defaults to `layer0`, and
+/// ambient occlusion is disabled.
+///
+/// # Example
+///
+/// ```
+/// use minecraft_assets::api::item_generator::*;
+/// use minecraft_assets::api::mesh::ModelMesh;
+/// use minecraft_assets::schemas::models::{BlockFace, Model};
+///
+/// let model: Model = serde_json::from_str(r#"{
+///     "textures": { "layer0": "item/dot" }
+/// }"#).unwrap();
+///
+/// // A 4x4 texture with a single opaque pixel at (1, 2).
+/// let mut alpha = vec![0; 16];
+/// alpha[2 * 4 + 1] = 0xff;
+///
+/// let generated = generate_item_model(&model, |_| Ok(SpriteAlpha::new(4, 4, vec![alpha.clone()]))).unwrap();
+/// let elements = generated.elements.as_ref().unwrap();
+///
+/// // The front and back of the layer, and a side face for each edge of the pixel.
+/// assert_eq!(elements.len(), 5);
+/// assert_eq!(elements[0].from, [0.0, 0.0, 7.5]);
+/// assert_eq!(elements[0].to, [16.0, 16.0, 8.5]);
+///
+/// let up = &elements[1].faces[&BlockFace::Up];
+/// assert_eq!(up.uv, Some([4.0, 8.0, 8.0, 12.0]));
+/// assert_eq!(up.tint_index, 0);
+/// assert_eq!(elements[1].from, [4.0, 8.0, 7.5]);
+///
+/// assert_eq!(generated.textures.as_ref().unwrap()["particle"].0, "item/dot");
+///
+/// let mesh = ModelMesh::from_model(&generated);
+/// assert_eq!(mesh.groups.len(), 1);
+/// assert_eq!(mesh.groups[0].positions.len(), 6 * 4);
+/// ```
+pub fn generate_item_model<F>(model: &Model, mut sprite: F) -> Result<Model>
+where
+    F: FnMut(&str) -> Result<SpriteAlpha>,
+{
+    let mut textures = model.textures.clone().unwrap_or_default();
+    let mut elements = Vec::new();
+
+    for (layer, name) in LAYERS.iter().enumerate() {
+        let texture = match textures.get(*name) {
+            Some(texture) => texture.clone(),
+            None => break,
+        };
+
+        let alpha = match texture.location() {
+            Some(location) => sprite(location)?,
+            None => SpriteAlpha::opaque(16, 16),
+        };
+        elements.extend(layer_elements(layer as i32, &texture, &alpha));
+    }
+
+    if !textures.contains_key("particle") {
+        if let Some(layer0) = textures.get(LAYERS[0]).cloned() {
+            textures.insert(String::from("particle"), layer0);
+        }
+    }
+
+    Ok(Model {
+        parent: None,
+        display: model.display.clone(),
+        textures: Some(textures),
+        elements: Some(elements),
+        ambient_occlusion: Some(false),
+        gui_light_mode: model.gui_light_mode,
+        overrides: model.overrides.clone(),
+        #[cfg(feature = "preserve-unknown")]
+        extra: model.extra.clone(),
+    })
+}
+
+/// Returns the elements generated for a single layer: the front and back of
+/// the layer, followed by the side faces extruded from its sprite.
+pub fn layer_elements(tint_index: i32, texture: &Texture, alpha: &SpriteAlpha) -> Vec<Element> {
+    let face = |uv: [f32; 4]| ElementFace {
+        uv: Some(uv),
+        texture: texture.clone(),
+        tint_index,
+        ..Default::default()
+    };
+
+    let mut elements = vec![Element {
+        from: [0.0, 0.0, 7.5],
+        to: [16.0, 16.0, 8.5],
+        faces: HashMap::from([
+            (BlockFace::South, face([0.0, 0.0, 16.0, 16.0])),
+            (BlockFace::North, face([0.0, 0.0, 16.0, 16.0])),
+        ]),
+        ..Default::default()
+    }];
+
+    let scale_x = 16.0 / alpha.width as f32;
+    let scale_y = 16.0 / alpha.height as f32;
+
+    for span in spans(alpha) {
+        let min = span.min as f32;
+        let max = span.max as f32 + 1.0;
+        let anchor = span.anchor as f32;
+
+        // The corners of the (flat) element and of its UVs, in pixels.
+        let (x0, y0, x1, y1, uv) = match span.facing {
+            SpanFacing::Up => (min, anchor, max, anchor, [min, anchor, max, anchor + 1.0]),
+            SpanFacing::Down => (
+                min,
+                anchor + 1.0,
+                max,
+                anchor + 1.0,
+                [min, anchor, max, anchor + 1.0],
+            ),
+            SpanFacing::Left => (anchor, min, anchor, max, [anchor, max, anchor + 1.0, min]),
+            SpanFacing::Right => (
+                anchor + 1.0,
+                min,
+                anchor + 1.0,
+                max,
+                [anchor, max, anchor + 1.0, min],
+            ),
+        };
+
+        let uv = [
+            uv[0] * scale_x,
+            uv[1] * scale_y,
+            uv[2] * scale_x,
+            uv[3] * scale_y,
+        ];
+
+        // The Y axis of the model points up, while the rows of the sprite go
+        // down, which also flips the winding of the side faces outwards.
+        elements.push(Element {
+            from: [x0 * scale_x, 16.0 - y0 * scale_y, 7.5],
+            to: [x1 * scale_x, 16.0 - y1 * scale_y, 8.5],
+            faces: HashMap::from([(span.facing.face(), face(uv))]),
+            ..Default::default()
+        });
+    }
+
+    elements
+}
+
+/// The edge of the sprite that a span of pixels borders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SpanFacing {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl SpanFacing {
+    const ALL: [Self; 4] = [Self::Up, Self::Down, Self::Left, Self::Right];
+
+    /// Returns the face of the element generated for spans of this facing.
+    fn face(self) -> BlockFace {
+        match self {
+            Self::Up => BlockFace::Up,
+            Self::Down => BlockFace::Down,
+            Self::Left => BlockFace::East,
+            Self::Right => BlockFace::West,
+        }
+    }
+
+    /// Returns the offset of the neighbor that must be transparent.
+    fn offset(self) -> (i64, i64) {
+        match self {
+            Self::Up => (0, -1),
+            Self::Down => (0, 1),
+            Self::Left => (-1, 0),
+            Self::Right => (1, 0),
+        }
+    }
+
+    /// Returns whether spans of this facing are rows rather than columns.
+    fn is_horizontal(self) -> bool {
+        matches!(self, Self::Up | Self::Down)
+    }
+}
+
+/// A row or column of opaque pixels whose neighbors on one side are
+/// transparent.
+#[derive(Debug)]
+struct Span {
+    facing: SpanFacing,
+    /// The row of a horizontal span, or the column of a vertical span.
+    anchor: u32,
+    min: u32,
+    max: u32,
+}
+
+/// Returns the spans of the opaque pixels of every frame of the sprite.
+///
+/// Like in the game, the pixels of the same row (or column) and facing are
+/// merged into a single span, even if they are not contiguous.
+fn spans(alpha: &SpriteAlpha) -> Vec<Span> {
+    let mut spans: Vec<Span> = Vec::new();
+
+    for frame in &alpha.frames {
+        for y in 0..alpha.height {
+            for x in 0..alpha.width {
+                if alpha.is_transparent(frame, i64::from(x), i64::from(y)) {
+                    continue;
+                }
+
+                for facing in SpanFacing::ALL {
+                    let (dx, dy) = facing.offset();
+                    if !alpha.is_transparent(frame, i64::from(x) + dx, i64::from(y) + dy) {
+                        continue;
+                    }
+
+                    let (anchor, position) = if facing.is_horizontal() {
+                        (y, x)
+                    } else {
+                        (x, y)
+                    };
+
+                    match spans
+                        .iter_mut()
+                        .find(|span| span.facing == facing && span.anchor == anchor)
+                    {
+                        Some(span) => {
+                            span.min = span.min.min(position);
+                            span.max = span.max.max(position);
+                        }
+                        None => spans.push(Span {
+                            facing,
+                            anchor,
+                            min: position,
+                            max: position,
+                        }),
+                    }
+                }
+            }
+        }
+    }
+
+    spans
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn side_faces_point_outwards() {
+        use crate::api::mesh::ModelMesh;
+
+        let texture = Texture::from("item/dot");
+        let elements = layer_elements(0, &texture, &SpriteAlpha::opaque(1, 1));
+        let model = Model {
+            elements: Some(elements),
+            ..Default::default()
+        };
+        let mesh = ModelMesh::from_model(&model);
+        let group = &mesh.groups[0];
+
+        // Every quad of a side face must wind counter-clockwise around its
+        // normal, i.e., away from the center of the sprite.
+        for quad in group.indices.chunks_exact(6).skip(2) {
+            let [a, b, c] = [quad[0], quad[1], quad[2]].map(|i| group.positions[i as usize]);
+            let u = [b[0] - a[0], b[1] - a[1], b[2] - a[2]];
+            let v = [c[0] - a[0], c[1] - a[1], c[2] - a[2]];
+            let cross = [
+                u[1] * v[2] - u[2] * v[1],
+                u[2] * v[0] - u[0] * v[2],
+                u[0] * v[1] - u[1] * v[0],
+            ];
+            let center = [0.5, 0.5, 0.5];
+            let outward = [a[0] - center[0], a[1] - center[1], a[2] - center[2]];
+            let dot: f32 = (0..3).map(|i| cross[i] * outward[i]).sum();
+            assert!(dot > 0.0, "{:?}", [a, b, c]);
+        }
+    }
+
+    #[test]
+    fn spans_merge_rows_across_gaps() {
+        // A single row with two opaque pixels separated by a transparent one.
+        let alpha = SpriteAlpha::new(3, 1, vec![vec![0xff, 0, 0xff]]);
+        let spans = spans(&alpha);
+
+        let up: Vec<_> = spans
+            .iter()
+            .filter(|span| span.facing == SpanFacing::Up)
+            .collect();
+        assert_eq!(up.len(), 1);
+        assert_eq!((up[0].min, up[0].max), (0, 2));
+        assert_eq!(
+            spans
+                .iter()
+                .filter(|span| span.facing == SpanFacing::Left)
+                .count(),
+            2
+        );
+    }
+}
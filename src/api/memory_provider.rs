@@ -0,0 +1,263 @@
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet},
+    io::{self, Read},
+};
+
+use crate::api::{
+    version::kind_directory, EnumerateResources, LoadResource, ResourceCategory,
+    ResourceIdentifier, ResourceKind, TargetVersion, WriteResource,
+};
+
+/// A [`ResourceProvider`] that provides resources from files held in memory.
+///
+/// Files are stored by their path relative to the root of the pack, using `/`
+/// as the separator (e.g., `assets/minecraft/blockstates/stone.json` or
+/// `pack.mcmeta`). It also implements [`WriteResource`], so packs can be
+/// generated in memory and then read back without touching the file system.
+///
+/// Files embedded into the binary with [`include_bytes!`] can be provided
+/// without copying them through [`from_static()`][Self::from_static].
+///
+/// [`ResourceProvider`]: crate::api::ResourceProvider
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::api::*;
+/// let mut provider = MemoryResourceProvider::new();
+/// provider.insert(
+///     "assets/minecraft/blockstates/stone.json",
+///     br#"{ "variants": { "": { "model": "block/stone" } } }"#.to_vec(),
+/// );
+///
+/// let assets = AssetPack::new(provider);
+/// let states = assets.load_blockstates("stone").unwrap();
+/// assert_eq!(states.variants().unwrap()[""].models()[0].model, "block/stone");
+/// ```
+///
+/// Generating a pack in memory:
+///
+/// ```
+/// # use minecraft_assets::api::*;
+/// use minecraft_assets::schemas::Model;
+///
+/// let mut provider = MemoryResourceProvider::new();
+///
+/// let model = Model {
+///     parent: Some("block/cube_all".parse().unwrap()),
+///     ..Default::default()
+/// };
+/// ResourceWriter::new(&mut provider).write_block_model("my_block", &model).unwrap();
+///
+/// let assets = AssetPack::new(provider);
+/// assert_eq!(assets.load_block_model("my_block").unwrap(), model);
+/// assert_eq!(
+///     assets.enumerate(ResourceKind::BlockModel, "minecraft").unwrap(),
+///     [ResourceIdentifier::block_model("my_block")],
+/// );
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct MemoryResourceProvider {
+    files: BTreeMap<String, Cow<'static, [u8]>>,
+    version: Option<TargetVersion>,
+}
+
+impl MemoryResourceProvider {
+    /// Returns a new provider without any files.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a new provider with the given files, which are not copied.
+    ///
+    /// Each file is given as a pair of its path relative to the root of the
+    /// pack and its contents.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// static FILES: &[(&str, &[u8])] = &[
+    ///     ("pack.mcmeta", br#"{ "pack": { "pack_format": 8, "description": "" } }"#),
+    ///     // e.g., include_bytes!("../assets/minecraft/lang/en_us.json")
+    ///     ("assets/minecraft/lang/en_us.json", br#"{ "block.minecraft.stone": "Stone" }"#),
+    /// ];
+    ///
+    /// let assets = AssetPack::new(MemoryResourceProvider::from_static(FILES));
+    /// assert_eq!(assets.load_pack_meta().unwrap().pack.pack_format, 8);
+    /// let language = assets.load_language("en_us").unwrap();
+    /// assert_eq!(language.get("block.minecraft.stone"), Some("Stone"));
+    /// ```
+    pub fn from_static(files: &'static [(&'static str, &'static [u8])]) -> Self {
+        let files = files
+            .iter()
+            .map(|(path, data)| (String::from(*path), Cow::Borrowed(*data)))
+            .collect();
+        Self {
+            files,
+            version: None,
+        }
+    }
+
+    /// Uses the directory layout of the given [`TargetVersion`] and returns
+    /// `self`.
+    pub fn with_target_version(mut self, version: TargetVersion) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Inserts a file with the given path relative to the root of the pack,
+    /// replacing any previous file with the same path.
+    pub fn insert(&mut self, path: impl Into<String>, data: impl Into<Cow<'static, [u8]>>) {
+        self.files.insert(path.into(), data.into());
+    }
+
+    /// Inserts the file of the resource referenced by the given
+    /// [`ResourceIdentifier`], replacing any previous file with the same path.
+    pub fn insert_resource(
+        &mut self,
+        id: &ResourceIdentifier,
+        data: impl Into<Cow<'static, [u8]>>,
+    ) {
+        self.files.insert(self.resource_path(id), data.into());
+    }
+
+    /// Returns the contents of the file with the given path relative to the
+    /// root of the pack, if it is present.
+    pub fn get(&self, path: &str) -> Option<&[u8]> {
+        self.files.get(path).map(|data| &data[..])
+    }
+
+    /// Removes the file with the given path relative to the root of the pack,
+    /// returning its contents if it was present.
+    pub fn remove(&mut self, path: &str) -> Option<Cow<'static, [u8]>> {
+        self.files.remove(path)
+    }
+
+    /// Returns the paths of all files, in sorted order.
+    pub fn paths(&self) -> impl Iterator<Item = &str> {
+        self.files.keys().map(String::as_str)
+    }
+
+    /// Returns the number of files.
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    /// Returns `true` if there are no files.
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Returns the path (with a trailing `/`) of the directory that contains
+    /// resources of the given kind.
+    fn kind_path(&self, namespace: &str, kind: ResourceKind) -> String {
+        let mut path = format!("{}/{}/", kind.category().directory(), namespace);
+        let directory = kind_directory(kind, self.version);
+        if !directory.is_empty() {
+            path.push_str(directory);
+            path.push('/');
+        }
+        path
+    }
+
+    fn resource_path(&self, id: &ResourceIdentifier) -> String {
+        let mut path = self.kind_path(id.namespace(), id.kind());
+        path.push_str(id.path());
+        path.push('.');
+        path.push_str(id.kind().extension());
+        path
+    }
+
+    fn read(&self, path: &str) -> Result<Vec<u8>, io::Error> {
+        self.files
+            .get(path)
+            .map(|data| data.to_vec())
+            .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))
+    }
+}
+
+impl EnumerateResources for MemoryResourceProvider {
+    fn enumerate_resources(
+        &self,
+        namespace: &str,
+        kind: ResourceKind,
+    ) -> Result<Vec<ResourceIdentifier<'static>>, io::Error> {
+        if let Some(name) = kind.single_file() {
+            let id = ResourceIdentifier::new_owned(kind, format!("{}:{}", namespace, name));
+            let exists = self.files.contains_key(&self.resource_path(&id));
+
+            return Ok(if exists { vec![id] } else { vec![] });
+        }
+
+        let directory = self.kind_path(namespace, kind);
+        let suffix = format!(".{}", kind.extension());
+
+        let ids = self
+            .files
+            .range(directory.clone()..)
+            .map(|(name, _)| name)
+            .take_while(|name| name.starts_with(&directory))
+            .filter_map(|name| {
+                let path = name.strip_prefix(&directory)?.strip_suffix(&suffix)?;
+
+                // Skip over files starting with '_', like the file system
+                // provider does.
+                let file_name = path.rsplit('/').next().unwrap_or(path);
+                if file_name.is_empty() || file_name.starts_with('_') {
+                    return None;
+                }
+
+                Some(ResourceIdentifier::in_namespace(kind, namespace, path))
+            })
+            .collect();
+
+        Ok(ids)
+    }
+
+    fn enumerate_namespaces(&self, category: ResourceCategory) -> Result<Vec<String>, io::Error> {
+        let prefix = format!("{}/", category.directory());
+
+        let namespaces: BTreeSet<&str> = self
+            .files
+            .keys()
+            .filter_map(|name| {
+                let (namespace, _) = name.strip_prefix(&prefix)?.split_once('/')?;
+                Some(namespace)
+            })
+            .collect();
+
+        Ok(namespaces.into_iter().map(String::from).collect())
+    }
+}
+
+impl LoadResource for MemoryResourceProvider {
+    fn load_resource(&self, id: &ResourceIdentifier) -> Result<Vec<u8>, io::Error> {
+        self.read(&self.resource_path(id))
+    }
+
+    fn load_root_file(&self, name: &str) -> Result<Vec<u8>, io::Error> {
+        self.read(name)
+    }
+
+    fn open_resource(&self, id: &ResourceIdentifier) -> Result<Box<dyn Read + '_>, io::Error> {
+        let data = self
+            .files
+            .get(&self.resource_path(id))
+            .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))?;
+        Ok(Box::new(&data[..]))
+    }
+}
+
+impl WriteResource for MemoryResourceProvider {
+    fn write_resource(&mut self, id: &ResourceIdentifier, data: &[u8]) -> Result<(), io::Error> {
+        self.insert_resource(id, data.to_vec());
+        Ok(())
+    }
+
+    fn write_root_file(&mut self, name: &str, data: &[u8]) -> Result<(), io::Error> {
+        self.insert(name, data.to_vec());
+        Ok(())
+    }
+}
@@ -0,0 +1,559 @@
+//! Converting block models into triangle meshes, and exporting them as
+//! Wavefront OBJ or glTF files.
+//!
+//! Start here: [`ModelMesh`].
+//!
+//! The elements of a model are turned into quads like the game does when it
+//! bakes models: the coordinates, default UVs, face UV rotations, and element
+//! rotations (with `rescale`) are all taken into account. Each quad is split
+//! into two triangles.
+//!
+//! Models should be [resolved] first, so that their parents' elements are
+//! included and their texture variables are substituted.
+//!
+//! [resolved]: crate::api::ModelResolver::resolve_model
+
+use std::{
+    collections::HashMap,
+    io::{self, Write},
+};
+
+use crate::{
+    api::ResourceIdentifier,
+    schemas::models::{Axis, BlockFace, Element, ElementFace, Model},
+};
+
+/// The triangles of a model that use the same texture and tint index.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MeshGroup {
+    /// The texture of the faces, e.g., `block/stone`.
+    ///
+    /// This is a texture variable (e.g., `#all`) if the model was not fully
+    /// resolved.
+    pub texture: String,
+
+    /// The tint index of the faces, or `-1` if they are not tinted.
+    pub tint_index: i32,
+
+    /// The vertex positions, in blocks (i.e., from `0.0` to `1.0` for the
+    /// full block), with `+X` east, `+Y` up, and `+Z` south.
+    pub positions: Vec<[f32; 3]>,
+
+    /// The vertex normals.
+    pub normals: Vec<[f32; 3]>,
+
+    /// The vertex texture coordinates, from `0.0` to `1.0` with `(0.0, 0.0)`
+    /// at the top left of the texture.
+    pub uvs: Vec<[f32; 2]>,
+
+    /// The vertex indices of the triangles, in counter-clockwise order when
+    /// looking at their front.
+    pub indices: Vec<u32>,
+}
+
+/// The triangle mesh of a block model.
+///
+/// The textures of the mesh are referenced by their path relative to the
+/// root of the pack (e.g., `assets/minecraft/textures/block/stone.png`),
+/// prefixed with a given directory.
+///
+/// # Example
+///
+/// ```
+/// use minecraft_assets::api::mesh::ModelMesh;
+/// use minecraft_assets::schemas::Model;
+///
+/// let model: Model = serde_json::from_str(r#"{
+///     "elements": [{
+///         "from": [0, 0, 0],
+///         "to": [16, 8, 16],
+///         "faces": {
+///             "up": { "texture": "block/oak_planks" },
+///             "down": { "texture": "block/oak_planks", "cullface": "down" }
+///         }
+///     }]
+/// }"#).unwrap();
+///
+/// let mesh = ModelMesh::from_model(&model);
+/// assert_eq!(mesh.groups.len(), 1);
+/// assert_eq!(mesh.groups[0].positions.len(), 8);
+/// assert_eq!(mesh.groups[0].indices.len(), 12);
+///
+/// let mut obj = Vec::new();
+/// mesh.write_obj(&mut obj, Some("slab.mtl")).unwrap();
+/// let obj = String::from_utf8(obj).unwrap();
+/// assert!(obj.contains("usemtl minecraft:block/oak_planks"));
+///
+/// let mut mtl = Vec::new();
+/// mesh.write_mtl(&mut mtl, "").unwrap();
+/// let mtl = String::from_utf8(mtl).unwrap();
+/// assert!(mtl.contains("map_Kd assets/minecraft/textures/block/oak_planks.png"));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ModelMesh {
+    /// The groups of triangles, in the order in which their textures are first
+    /// used by the model.
+    pub groups: Vec<MeshGroup>,
+}
+
+impl ModelMesh {
+    /// Returns the mesh of the elements of the given model.
+    pub fn from_model(model: &Model) -> Self {
+        let mut mesh = Self::default();
+        let mut groups = HashMap::new();
+
+        for element in model.elements.iter().flatten() {
+            for face in FACES {
+                let element_face = match element.faces.get(&face) {
+                    Some(element_face) => element_face,
+                    None => continue,
+                };
+
+                let key = (element_face.texture.0.clone(), element_face.tint_index);
+                let index = *groups.entry(key).or_insert_with(|| {
+                    mesh.groups.push(MeshGroup {
+                        texture: element_face.texture.0.clone(),
+                        tint_index: element_face.tint_index,
+                        ..Default::default()
+                    });
+                    mesh.groups.len() - 1
+                });
+
+                add_quad(&mut mesh.groups[index], element, face, element_face);
+            }
+        }
+
+        mesh
+    }
+
+    /// Writes the mesh as a Wavefront OBJ file, with a group per texture.
+    ///
+    /// If `mtl_file` is given, the file references it as its material
+    /// library, which can be written with [`write_mtl()`][Self::write_mtl].
+    /// The materials are named after the textures.
+    ///
+    /// Since OBJ texture coordinates start at the bottom left, the `v`
+    /// coordinates are flipped.
+    pub fn write_obj<W: Write>(&self, mut writer: W, mtl_file: Option<&str>) -> io::Result<()> {
+        if let Some(mtl_file) = mtl_file {
+            writeln!(writer, "mtllib {}", mtl_file)?;
+        }
+
+        // OBJ indices are shared by the whole file and start at 1.
+        let mut offset = 1;
+        for (i, group) in self.groups.iter().enumerate() {
+            writeln!(writer, "g group_{}", i)?;
+            writeln!(writer, "usemtl {}", material_name(&group.texture))?;
+
+            for [x, y, z] in &group.positions {
+                writeln!(writer, "v {} {} {}", x, y, z)?;
+            }
+            for [u, v] in &group.uvs {
+                writeln!(writer, "vt {} {}", u, 1.0 - v)?;
+            }
+            for [x, y, z] in &group.normals {
+                writeln!(writer, "vn {} {} {}", x, y, z)?;
+            }
+
+            for triangle in group.indices.chunks_exact(3) {
+                write!(writer, "f")?;
+                for index in triangle {
+                    let index = index + offset;
+                    write!(writer, " {0}/{0}/{0}", index)?;
+                }
+                writeln!(writer)?;
+            }
+
+            offset += group.positions.len() as u32;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the materials referenced by [`write_obj()`][Self::write_obj] as
+    /// a Wavefront MTL file.
+    ///
+    /// The paths of the textures are prefixed with `texture_root`, which should
+    /// be the path of the pack's root directory relative to the MTL file (or
+    /// empty if the file is written to the pack's root directory).
+    pub fn write_mtl<W: Write>(&self, mut writer: W, texture_root: &str) -> io::Result<()> {
+        let mut written = Vec::new();
+        for group in &self.groups {
+            let name = material_name(&group.texture);
+            if written.contains(&name) {
+                continue;
+            }
+
+            writeln!(writer, "newmtl {}", name)?;
+            writeln!(writer, "Kd 1 1 1")?;
+            writeln!(
+                writer,
+                "map_Kd {}{}",
+                texture_root,
+                texture_path(&group.texture)
+            )?;
+            writeln!(writer)?;
+            written.push(name);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the mesh as a glTF 2.0 asset, with its buffer embedded as a
+    /// base64 data URI.
+    ///
+    /// Each group is a primitive with its own material, whose base color
+    /// texture is sampled without filtering like in the game. The paths of
+    /// the textures are prefixed with `texture_root`, like in
+    /// [`write_mtl()`][Self::write_mtl].
+    pub fn to_gltf(&self, texture_root: &str) -> serde_json::Value {
+        use serde_json::json;
+
+        const ARRAY_BUFFER: u32 = 34962;
+        const ELEMENT_ARRAY_BUFFER: u32 = 34963;
+        const FLOAT: u32 = 5126;
+        const UNSIGNED_INT: u32 = 5125;
+        const NEAREST: u32 = 9728;
+
+        let mut buffer = Vec::new();
+        let mut views = Vec::new();
+        let mut accessors = Vec::new();
+        let mut primitives = Vec::new();
+        let mut materials = Vec::new();
+        let mut images: Vec<serde_json::Value> = Vec::new();
+        let mut textures = Vec::new();
+        let mut material_indices = HashMap::new();
+
+        let mut push_view = |buffer: &mut Vec<u8>, bytes: Vec<u8>, target: u32| {
+            views.push(json!({
+                "buffer": 0,
+                "byteOffset": buffer.len(),
+                "byteLength": bytes.len(),
+                "target": target,
+            }));
+            buffer.extend(bytes);
+            views.len() - 1
+        };
+
+        for group in &self.groups {
+            let floats = |values: &[f32]| -> Vec<u8> {
+                values
+                    .iter()
+                    .flat_map(|value| value.to_le_bytes())
+                    .collect()
+            };
+
+            let positions = floats(group.positions.as_flattened());
+            let view = push_view(&mut buffer, positions, ARRAY_BUFFER);
+            let (min, max) = bounds(&group.positions);
+            accessors.push(json!({
+                "bufferView": view,
+                "componentType": FLOAT,
+                "count": group.positions.len(),
+                "type": "VEC3",
+                "min": min,
+                "max": max,
+            }));
+
+            let normals = floats(group.normals.as_flattened());
+            let view = push_view(&mut buffer, normals, ARRAY_BUFFER);
+            accessors.push(json!({
+                "bufferView": view,
+                "componentType": FLOAT,
+                "count": group.normals.len(),
+                "type": "VEC3",
+            }));
+
+            let uvs = floats(group.uvs.as_flattened());
+            let view = push_view(&mut buffer, uvs, ARRAY_BUFFER);
+            accessors.push(json!({
+                "bufferView": view,
+                "componentType": FLOAT,
+                "count": group.uvs.len(),
+                "type": "VEC2",
+            }));
+
+            let indices = group.indices.iter().flat_map(|i| i.to_le_bytes()).collect();
+            let view = push_view(&mut buffer, indices, ELEMENT_ARRAY_BUFFER);
+            accessors.push(json!({
+                "bufferView": view,
+                "componentType": UNSIGNED_INT,
+                "count": group.indices.len(),
+                "type": "SCALAR",
+            }));
+
+            let material = *material_indices
+                .entry(material_name(&group.texture))
+                .or_insert_with(|| {
+                    images.push(json!({
+                        "uri": format!("{}{}", texture_root, texture_path(&group.texture)),
+                    }));
+                    textures.push(json!({ "source": images.len() - 1, "sampler": 0 }));
+                    materials.push(json!({
+                        "name": material_name(&group.texture),
+                        "pbrMetallicRoughness": {
+                            "baseColorTexture": { "index": textures.len() - 1 },
+                            "metallicFactor": 0.0,
+                        },
+                        "alphaMode": "MASK",
+                    }));
+                    materials.len() - 1
+                });
+
+            let first = accessors.len() - 4;
+            primitives.push(json!({
+                "attributes": {
+                    "POSITION": first,
+                    "NORMAL": first + 1,
+                    "TEXCOORD_0": first + 2,
+                },
+                "indices": first + 3,
+                "material": material,
+            }));
+        }
+
+        json!({
+            "asset": { "version": "2.0", "generator": "minecraft-assets" },
+            "scene": 0,
+            "scenes": [{ "nodes": [0] }],
+            "nodes": [{ "mesh": 0 }],
+            "meshes": [{ "primitives": primitives }],
+            "materials": materials,
+            "textures": textures,
+            "images": images,
+            "samplers": [{ "magFilter": NEAREST, "minFilter": NEAREST }],
+            "accessors": accessors,
+            "bufferViews": views,
+            "buffers": [{
+                "byteLength": buffer.len(),
+                "uri": format!("data:application/octet-stream;base64,{}", base64(&buffer)),
+            }],
+        })
+    }
+
+    /// Writes the mesh as a glTF 2.0 (`.gltf`) file.
+    ///
+    /// See [`to_gltf()`][Self::to_gltf].
+    pub fn write_gltf<W: Write>(&self, writer: W, texture_root: &str) -> io::Result<()> {
+        serde_json::to_writer(writer, &self.to_gltf(texture_root)).map_err(io::Error::from)
+    }
+}
+
+const FACES: [BlockFace; 6] = [
+    BlockFace::Down,
+    BlockFace::Up,
+    BlockFace::North,
+    BlockFace::South,
+    BlockFace::West,
+    BlockFace::East,
+];
+
+/// Adds the quad of the given face of an element to the group.
+///
+/// See `net.minecraft.client.renderer.block.model.FaceBakery`.
+fn add_quad(group: &mut MeshGroup, element: &Element, face: BlockFace, element_face: &ElementFace) {
+    let [x0, y0, z0] = element.from;
+    let [x1, y1, z1] = element.to;
+
+    // The corners of the face, in the order used by the game.
+    let (corners, normal) = match face {
+        BlockFace::Down => (
+            [[x0, y0, z1], [x0, y0, z0], [x1, y0, z0], [x1, y0, z1]],
+            [0.0, -1.0, 0.0],
+        ),
+        BlockFace::Up => (
+            [[x0, y1, z0], [x0, y1, z1], [x1, y1, z1], [x1, y1, z0]],
+            [0.0, 1.0, 0.0],
+        ),
+        BlockFace::North => (
+            [[x1, y1, z0], [x1, y0, z0], [x0, y0, z0], [x0, y1, z0]],
+            [0.0, 0.0, -1.0],
+        ),
+        BlockFace::South => (
+            [[x0, y1, z1], [x0, y0, z1], [x1, y0, z1], [x1, y1, z1]],
+            [0.0, 0.0, 1.0],
+        ),
+        BlockFace::West => (
+            [[x0, y1, z0], [x0, y0, z0], [x0, y0, z1], [x0, y1, z1]],
+            [-1.0, 0.0, 0.0],
+        ),
+        BlockFace::East => (
+            [[x1, y1, z1], [x1, y0, z1], [x1, y0, z0], [x1, y1, z0]],
+            [1.0, 0.0, 0.0],
+        ),
+    };
+
+    let uv = element_face.uv.unwrap_or(match face {
+        BlockFace::Down => [x0, 16.0 - z1, x1, 16.0 - z0],
+        BlockFace::Up => [x0, z0, x1, z1],
+        BlockFace::North => [16.0 - x1, 16.0 - y1, 16.0 - x0, 16.0 - y0],
+        BlockFace::South => [x0, 16.0 - y1, x1, 16.0 - y0],
+        BlockFace::West => [z0, 16.0 - y1, z1, 16.0 - y0],
+        BlockFace::East => [16.0 - z1, 16.0 - y1, 16.0 - z0, 16.0 - y0],
+    });
+
+    let first = group.positions.len() as u32;
+    let rotation = &element.rotation;
+    for (i, corner) in corners.into_iter().enumerate() {
+        let position = rotate(
+            corner,
+            rotation.origin,
+            rotation.axis,
+            rotation.angle,
+            rotation.rescale,
+        );
+        group.positions.push(position.map(|c| c / 16.0));
+        group.normals.push(rotate(
+            normal,
+            [0.0; 3],
+            rotation.axis,
+            rotation.angle,
+            false,
+        ));
+
+        let shifted = (i + (element_face.rotation / 90) as usize) % 4;
+        let u = if shifted < 2 { uv[0] } else { uv[2] };
+        let v = if shifted == 0 || shifted == 3 {
+            uv[1]
+        } else {
+            uv[3]
+        };
+        group.uvs.push([u / 16.0, v / 16.0]);
+    }
+
+    group
+        .indices
+        .extend([first, first + 1, first + 2, first, first + 2, first + 3]);
+}
+
+/// Rotates the given point around the origin by the given angle in degrees,
+/// and scales it up along the other axes if `rescale` is `true`.
+fn rotate(point: [f32; 3], origin: [f32; 3], axis: Axis, angle: f32, rescale: bool) -> [f32; 3] {
+    if angle == 0.0 {
+        return point;
+    }
+
+    let (sin, cos) = angle.to_radians().sin_cos();
+    let [x, y, z] = [0, 1, 2].map(|i| point[i] - origin[i]);
+    let (mut rotated, axis) = match axis {
+        Axis::X => ([x, y * cos - z * sin, y * sin + z * cos], 0),
+        Axis::Y => ([x * cos + z * sin, y, z * cos - x * sin], 1),
+        Axis::Z => ([x * cos - y * sin, x * sin + y * cos, z], 2),
+    };
+
+    if rescale {
+        // The game only allows multiples of 22.5 degrees up to 45 degrees.
+        let scale = if angle.abs() == 22.5 {
+            1.0 / std::f32::consts::FRAC_PI_8.cos()
+        } else {
+            1.0 / std::f32::consts::FRAC_PI_4.cos()
+        };
+        for (i, coordinate) in rotated.iter_mut().enumerate() {
+            if i != axis {
+                *coordinate *= scale;
+            }
+        }
+    }
+
+    [0, 1, 2].map(|i| rotated[i] + origin[i])
+}
+
+/// Returns the name of the material of the given texture.
+fn material_name(texture: &str) -> String {
+    ResourceIdentifier::texture(texture).to_string()
+}
+
+/// Returns the path of the given texture relative to the root of its pack.
+fn texture_path(texture: &str) -> String {
+    ResourceIdentifier::texture(texture).pack_path()
+}
+
+fn bounds(positions: &[[f32; 3]]) -> ([f32; 3], [f32; 3]) {
+    let mut min = [f32::INFINITY; 3];
+    let mut max = [f32::NEG_INFINITY; 3];
+    for position in positions {
+        for i in 0..3 {
+            min[i] = min[i].min(position[i]);
+            max[i] = max[i].max(position[i]);
+        }
+    }
+    (min, max)
+}
+
+/// Encodes the given bytes with the standard base64 alphabet, with padding.
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = u32::from_be_bytes([0, b[0], b[1], b[2]]);
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn cube(faces: &str) -> Model {
+        serde_json::from_str(&format!(
+            r#"{{ "elements": [{{ "from": [0, 0, 0], "to": [16, 16, 16], "faces": {} }}] }}"#,
+            faces
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn faces_follow_the_game_layout() {
+        let model = cube(r#"{ "north": { "texture": "a", "uv": [0, 0, 8, 16], "rotation": 90 } }"#);
+        let group = &ModelMesh::from_model(&model).groups[0];
+
+        assert_eq!(
+            group.positions,
+            [
+                [1.0, 1.0, 0.0],
+                [1.0, 0.0, 0.0],
+                [0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0]
+            ]
+        );
+        assert_eq!(group.normals[0], [0.0, 0.0, -1.0]);
+        // A rotation of 90 degrees shifts the UVs by one corner.
+        assert_eq!(group.uvs, [[0.0, 1.0], [0.5, 1.0], [0.5, 0.0], [0.0, 0.0]]);
+    }
+
+    #[test]
+    fn rotated_elements_are_rescaled() {
+        let mut model = cube(r#"{ "up": { "texture": "a" } }"#);
+        let element = &mut model.elements.as_mut().unwrap()[0];
+        element.rotation.origin = [8.0, 8.0, 8.0];
+        element.rotation.axis = Axis::Y;
+        element.rotation.angle = 45.0;
+        element.rotation.rescale = true;
+
+        let group = &ModelMesh::from_model(&model).groups[0];
+        let [x, y, z] = group.positions[0];
+        assert!((x + 0.5).abs() < 1e-5 && (y - 1.0).abs() < 1e-5 && (z - 0.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn base64_is_padded() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+    }
+}
@@ -4,34 +4,264 @@
 //! ## Resource Identifiers
 //!
 //! Every resource is associated with a unique [`ResourceIdentifier`], which is a
-//! combination of a [`ResourceKind`] and a *namespaced identifier*.
+//! combination of a [`ResourceKind`] and a *namespaced identifier*. Ids that
+//! appear inside of resources, which have no kind yet, are represented by a
+//! [`ResourceLocation`].
 //!
 //! ## Providers
 //!
 //! Resources can be enumerated and loaded using the [`ResourceProvider`] trait.
 //! This crate provides the [`FileSystemResourceProvider`] as a convenient
-//! implementation of this trait.
+//! implementation of this trait. With the `zip` feature, the
+//! `ZipResourceProvider` reads resources straight out of a `.zip` resource
+//! pack or the vanilla client `.jar`, which it can memory-map with the `mmap`
+//! feature. Multiple providers can be stacked with
+//! the [`LayeredResourceProvider`], like the game stacks resource packs on top
+//! of the vanilla assets. The [`AssetIndexProvider`] reads the sounds and
+//! languages that the launcher stores in its hashed `assets/objects/`
+//! directory. The [`MemoryResourceProvider`] holds the files of a pack in
+//! memory, e.g., for tests or for files embedded into the binary. The
+//! [`OverlayResourceProvider`] applies the overlays that a pack declares in its
+//! `pack.mcmeta` for a given pack format. With the `notify` feature, the
+//! `WatchingResourceProvider` reports changes to the files of a pack, so that
+//! tools can live-reload them. The [`IndexedResourceProvider`] caches the
+//! listing of a pack on disk, so that large packs are not scanned again at
+//! every startup.
+//!
+//! With the `download` feature, the `download` module fetches the vanilla
+//! client jar of any version into a local cache.
+//!
+//! With the `hash` feature, the `pack_hash` module computes the SHA-1 hash of a
+//! pack's `.zip` file for the `resource-pack-sha1` server property, and
+//! verifies downloaded packs against it.
 //!
 //! ## Asset Pack
 //!
-//! Resources can be ergonomically loaded through the [`AssetPack`] API.
+//! Resources can be ergonomically loaded through the [`AssetPack`] API. The
+//! [`CachedAssetPack`] wraps it to keep recently used resources parsed.
+//! [`AssetPack::load_all()`] loads every resource of a kind, in parallel with
+//! the `rayon` feature. Resources can also be loaded as [`RawResource`]s, which are
+//! only parsed on demand, e.g., to read a few fields of many resources.
+//! Resources that do not match their schema fail with a
+//! [`ResourceParseError`], which tells the file and the JSON path of the
+//! failure.
+//!
+//! With the `async` feature, the `AsyncAssetPack` API loads resources without
+//! blocking from an `AsyncResourceProvider`, such as the
+//! `FetchResourceProvider`, which fetches resources over HTTP (e.g., in the
+//! browser). The `tokio` feature implements `AsyncResourceProvider` for the
+//! [`FileSystemResourceProvider`].
+//!
+//! ## Game Versions
+//!
+//! The layout of packs differs between versions of the game, e.g., 1.21
+//! renamed the directories of data packs to singular names. A
+//! [`TargetVersion`] given to the providers and the [`AssetPack`] selects the
+//! layout of a version and refuses kinds of resources that it does not have.
+//!
+//! ## Bedrock Edition
+//!
+//! The `manifest.json`, `blocks.json`, texture atlases, and `.geo.json`
+//! geometries of Bedrock Edition resource packs can be loaded through the
+//! [`bedrock`] module, which also maps the paths of textures between the Java
+//! and Bedrock editions, e.g., for tools that convert packs.
+//!
+//! ## Bevy
+//!
+//! With the `bevy` feature, models, block states, and biomes can be loaded as
+//! Bevy assets, and an [`AssetPack`] can be used as a Bevy resource, through
+//! the `bevy` module.
+//!
+//! ## WebAssembly
+//!
+//! The schemas and the API compile for `wasm32-unknown-unknown`, where there is
+//! no file system. Packs can be provided from memory by the
+//! [`MemoryResourceProvider`], from the bytes of a `.zip` archive by the
+//! `ZipResourceProvider`, or fetched by the `FetchResourceProvider`. The
+//! `download`, `notify`, and `tokio` features are not supported there.
+//!
+//! ## Writing Packs
+//!
+//! Resources can be written to a pack through the [`ResourceWriter`] API,
+//! which uses the [`WriteResource`] trait. Whole packs, including their
+//! `pack.mcmeta`, can be assembled in memory and written out as a directory or
+//! a `.zip` archive through the [`generator`] module. With the `zip` feature,
+//! the `ZipPackWriter` writes reproducible `.zip` archives, ready to be
+//! distributed, from schema values or from the resources of another provider.
+//!
+//! ## Comparing Packs
+//!
+//! The resources that were added, removed, or changed between two packs (e.g.,
+//! two versions of the game) can be listed, with the values that changed
+//! within JSON resources, through the [`diff`] module.
+//!
+//! ## Dependencies
+//!
+//! The resources that use a resource, like the models that use a texture or
+//! the biomes that use a placed feature, can be looked up through the
+//! [`dependencies`] module, e.g., to find the resources that no other resource
+//! uses. The whole graph can be exported as JSON or Graphviz DOT.
+//!
+//! ## Meshes
+//!
+//! The elements of block models can be converted into triangle meshes and
+//! exported as Wavefront OBJ or glTF files, referencing the textures of the
+//! pack, through the [`mesh`] module. The elements of item models that
+//! inherit `builtin/generated` can be generated from the textures of their
+//! layers, like in the game, through the [`item_generator`] module.
+//!
+//! With the `glam` feature, the display transforms of models (e.g., in the GUI
+//! or in the hand) can be converted into matrices through the `display`
+//! module.
+//!
+//! ## OptiFine
+//!
+//! The connected textures (CTM) and custom item textures (CIT) rules of
+//! OptiFine packs, which mods like Continuity and CIT Resewn read too, can be
+//! loaded with `AssetPack::load_ctm_properties()` and
+//! `AssetPack::load_cit_properties()`. The textures that they reference can be
+//! resolved through the [`optifine`] module.
+//!
+//! ## Shaders
+//!
+//! The `#moj_import` directives of GLSL shader sources can be resolved through
+//! the [`ShaderImportResolver`] API.
+//!
+//! ## Sounds
+//!
+//! Sound files can be streamed with `AssetPack::open_sound()`. With the `ogg`
+//! feature, their channels, sample rate, and duration can be read without
+//! decoding them through the `SoundInfo` API.
+//!
+//! ## Tags
+//!
+//! The tag files of stacked data packs can be merged and expanded into the
+//! final sets of IDs through the [`TagResolver`] API.
+//!
+//! ## Textures
+//!
+//! With the `image` feature, texture `.png` files can be decoded into
+//! `image::RgbaImage`s through the `TextureDecoder` API or
+//! `AssetPack::load_texture_image()`.
+//!
+//! The sprites of texture atlases can also be stitched into atlas images, with
+//! the same layout as in the game, through the `atlas` module.
+//!
+//! The grass and foliage colors of biomes can be computed from the colormap
+//! textures, like in the game, through the `colormap` module.
+//!
+//! ## Translations
+//!
+//! Translated strings from language files can be looked up and formatted
+//! through the [`Translator`] API.
+//!
+//! ## Validation
+//!
+//! Broken references between the resources of a pack, like models with
+//! missing textures, can be found through the [`validate`] module.
+//! Given the [`Registries`] of a version of the game, loaded from the reports
+//! of its data generator, it also checks the IDs of blocks, items, sound
+//! events, biomes, etc. used by the pack.
 
 use std::io;
 
+mod asset_index;
 mod asset_pack;
+#[cfg(feature = "async")]
+mod async_asset_pack;
+#[cfg(feature = "async")]
+mod async_provider;
+#[cfg(feature = "image")]
+pub mod atlas;
+pub mod bedrock;
+#[cfg(feature = "bevy")]
+pub mod bevy;
+mod cache;
+#[cfg(feature = "image")]
+pub mod colormap;
+pub mod dependencies;
+pub mod diff;
+#[cfg(feature = "glam")]
+pub mod display;
+#[cfg(feature = "download")]
+pub mod download;
+pub mod generator;
+mod index_cache;
+pub mod item_generator;
+mod memory_provider;
+pub mod mesh;
+pub mod optifine;
+mod overlay_provider;
+#[cfg(feature = "hash")]
+pub mod pack_hash;
+mod parse_error;
 mod provider;
+pub(crate) mod random;
+mod raw_resource;
+mod registries;
 mod resolve;
 mod resource;
+mod shader;
+#[cfg(feature = "ogg")]
+mod sound_info;
+mod tags;
+#[cfg(feature = "image")]
+mod texture_decoder;
+mod translate;
+pub mod validate;
+mod version;
+#[cfg(feature = "notify")]
+mod watching_provider;
+mod writer;
+#[cfg(feature = "zip")]
+mod zip_provider;
+#[cfg(feature = "zip")]
+mod zip_writer;
 
+pub use asset_index::AssetIndexProvider;
 pub use asset_pack::AssetPack;
+pub use asset_pack::LoadReport;
+#[cfg(feature = "async")]
+pub use async_asset_pack::AsyncAssetPack;
+#[cfg(feature = "async")]
+pub use async_provider::{
+    AsyncResourceProvider, BoxFuture, FetchResourceProvider, LocalBoxFuture, MaybeSend, MaybeSync,
+};
+pub use cache::CachedAssetPack;
+pub use index_cache::IndexedResourceProvider;
+pub use memory_provider::MemoryResourceProvider;
+pub use overlay_provider::OverlayResourceProvider;
+pub use parse_error::ResourceParseError;
 pub use provider::{
-    EnumerateResources, FileSystemResourceProvider, LoadResource, ResourceProvider,
+    EnumerateResources, FileSystemResourceProvider, LayeredResourceProvider, LoadResource,
+    ResourceProvider, WriteResource,
 };
+pub use raw_resource::RawResource;
+pub use registries::Registries;
 pub use resolve::ModelResolver;
 pub use resource::{
-    ModelIdentifier, ResourceCategory, ResourceIdentifier, ResourceKind, ResourcePath,
-    MINECRAFT_NAMESPACE,
+    InvalidIdentifier, ModelIdentifier, ResourceCategory, ResourceIdentifier, ResourceKind,
+    ResourceLocation, ResourcePath, MINECRAFT_NAMESPACE,
+};
+pub use shader::ShaderImportResolver;
+#[cfg(feature = "ogg")]
+pub use sound_info::SoundInfo;
+pub use tags::{ResolvedTags, TagResolver};
+#[cfg(feature = "image")]
+pub use texture_decoder::TextureDecoder;
+pub use translate::{Translator, DEFAULT_LANGUAGE};
+pub use version::TargetVersion;
+#[cfg(feature = "notify")]
+pub use watching_provider::{
+    ChangeKind, ResourceChange, ResourceChanges, WatchingResourceProvider,
 };
+pub use writer::ResourceWriter;
+#[cfg(feature = "mmap")]
+pub use zip_provider::MappedFile;
+#[cfg(feature = "zip")]
+pub use zip_provider::ZipResourceProvider;
+#[cfg(feature = "zip")]
+pub use zip_writer::ZipPackWriter;
 
 /// Error types that can be returned from API methods.
 #[derive(Debug, thiserror::Error)]
@@ -42,6 +272,45 @@ pub enum Error {
 
     #[error(transparent)]
     ParseError(#[from] serde_json::Error),
+
+    #[error(transparent)]
+    ResourceParseError(#[from] ResourceParseError),
+
+    #[error(transparent)]
+    InvalidIdentifier(#[from] InvalidIdentifier),
+
+    #[error("{kind:?} resources do not exist in {version}")]
+    UnsupportedKind {
+        kind: ResourceKind,
+        version: TargetVersion,
+    },
+
+    #[error("model {model} has a cycle in its parents")]
+    ModelParentCycle { model: String },
+
+    #[error("parent {parent} of model {model} could not be found")]
+    MissingModelParent { model: String, parent: String },
+
+    #[error("sprites do not fit into an atlas of at most {max_size}x{max_size} pixels")]
+    AtlasTooLarge { max_size: u32 },
+
+    #[error("tag {tag} references itself through its entries")]
+    TagCycle { tag: String },
+
+    #[error("entry {entry} of tag {tag} could not be found")]
+    MissingTagEntry { tag: String, entry: String },
+
+    #[cfg(feature = "nbt")]
+    #[error(transparent)]
+    NbtError(#[from] fastnbt::error::Error),
+
+    #[cfg(feature = "image")]
+    #[error(transparent)]
+    PngError(#[from] png::DecodingError),
+
+    #[cfg(feature = "zip")]
+    #[error(transparent)]
+    ZipError(#[from] zip::result::ZipError),
 }
 
 /// Result alias for convenience.
@@ -0,0 +1,204 @@
+//! Resolving the textures that the OptiFine `.properties` files of a pack
+//! reference.
+//!
+//! The rules of connected textures (CTM) and custom item textures (CIT) name
+//! their textures like OptiFine resolves them:
+//!
+//! * `name` or `./name`: in the directory of the `.properties` file;
+//! * `~/path`: in `assets/minecraft/optifine/`;
+//! * `namespace:path`: in `assets/<namespace>/`;
+//! * `path/with/slashes`: in `assets/minecraft/`, e.g.,
+//!   `textures/block/stone`.
+//!
+//! The `.png` extension is optional. Textures in `textures/` resolve to
+//! [`Texture`] resources, and textures in `optifine/` resolve to
+//! [`OptifineTexture`] resources. Other locations (like the legacy
+//! `mcpatcher/` directory) are not resources of this crate, and are left out.
+//!
+//! The rules themselves are loaded with [`AssetPack::load_ctm_properties()`]
+//! and [`AssetPack::load_cit_properties()`].
+//!
+//! [`Texture`]: ResourceKind::Texture
+//! [`OptifineTexture`]: ResourceKind::OptifineTexture
+//! [`AssetPack::load_ctm_properties()`]: crate::api::AssetPack::load_ctm_properties
+//! [`AssetPack::load_cit_properties()`]: crate::api::AssetPack::load_cit_properties
+
+use crate::{
+    api::{ResourceIdentifier, ResourceKind, MINECRAFT_NAMESPACE},
+    schemas::optifine::{ctm::CtmTile, CitProperties, CtmProperties},
+};
+
+/// Returns the texture that the given name refers to, in the `.properties`
+/// file with the given ID, or `None` if it is not in `textures/` or
+/// `optifine/`.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::api::*;
+/// use minecraft_assets::api::optifine::resolve_texture;
+///
+/// let glass = ResourceIdentifier::ctm_properties("glass/glass");
+///
+/// let tile = resolve_texture(&glass, "0").unwrap();
+/// assert_eq!(tile, ResourceIdentifier::optifine_texture("ctm/glass/0"));
+///
+/// let tile = resolve_texture(&glass, "textures/block/stone.png").unwrap();
+/// assert_eq!(tile, ResourceIdentifier::texture("block/stone"));
+///
+/// let tile = resolve_texture(&glass, "~/ctm/shared/frame").unwrap();
+/// assert_eq!(tile, ResourceIdentifier::optifine_texture("ctm/shared/frame"));
+///
+/// assert_eq!(resolve_texture(&glass, "mcpatcher/ctm/glass/0"), None);
+/// ```
+pub fn resolve_texture(
+    properties: &ResourceIdentifier,
+    name: &str,
+) -> Option<ResourceIdentifier<'static>> {
+    let name = name.trim();
+    if name.is_empty() {
+        return None;
+    }
+
+    let (namespace, path) = if let Some(path) = name.strip_prefix("~/") {
+        (MINECRAFT_NAMESPACE, format!("optifine/{}", path))
+    } else if let Some((namespace, path)) = name.split_once(':') {
+        (namespace, path.to_string())
+    } else if name.contains('/') && !name.starts_with("./") {
+        (MINECRAFT_NAMESPACE, name.to_string())
+    } else {
+        let name = name.strip_prefix("./").unwrap_or(name);
+        let directory = match properties.path().rsplit_once('/') {
+            Some((parent, _)) => format!("{}/{}", properties.kind().directory(), parent),
+            None => properties.kind().directory().to_string(),
+        };
+        (properties.namespace(), format!("{}/{}", directory, name))
+    };
+
+    let extension = if path.ends_with(".png") { "" } else { ".png" };
+    let id =
+        ResourceIdentifier::from_pack_path(&format!("assets/{}/{}{}", namespace, path, extension))?;
+
+    matches!(
+        id.kind(),
+        ResourceKind::Texture | ResourceKind::OptifineTexture
+    )
+    .then_some(id)
+}
+
+/// Returns the textures of the tiles of the given connected textures rule,
+/// stored in the `.properties` file with the given ID, in order and without
+/// duplicates.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::api::*;
+/// use minecraft_assets::api::optifine::ctm_textures;
+/// use minecraft_assets::schemas::optifine::CtmProperties;
+///
+/// let id = ResourceIdentifier::ctm_properties("glass/glass");
+/// let ctm = CtmProperties::parse("method=ctm\ntiles=0-2 <default>");
+///
+/// let textures = ctm_textures(&id, &ctm);
+/// assert_eq!(textures.len(), 3);
+/// assert_eq!(textures[2].pack_path(), "assets/minecraft/optifine/ctm/glass/2.png");
+/// ```
+pub fn ctm_textures(
+    id: &ResourceIdentifier,
+    ctm: &CtmProperties,
+) -> Vec<ResourceIdentifier<'static>> {
+    let names = ctm.tiles.iter().filter_map(|tile| match tile {
+        CtmTile::Texture(name) => Some(name.as_str()),
+        CtmTile::Skip | CtmTile::Default => None,
+    });
+    resolve_all(id, names)
+}
+
+/// Returns the replacement textures of the given custom item textures rule,
+/// stored in the `.properties` file with the given ID, in order and without
+/// duplicates.
+///
+/// If the rule has neither a texture nor a model, this is the texture with
+/// the name of the `.properties` file, like in OptiFine.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::api::*;
+/// use minecraft_assets::api::optifine::cit_textures;
+/// use minecraft_assets::schemas::optifine::CitProperties;
+///
+/// let id = ResourceIdentifier::cit_properties("swords/fire_sword");
+///
+/// let cit = CitProperties::parse("items=diamond_sword");
+/// let textures = cit_textures(&id, &cit);
+/// assert_eq!(textures, [ResourceIdentifier::optifine_texture("cit/swords/fire_sword")]);
+///
+/// let cit = CitProperties::parse("type=armor\ntexture.diamond_layer_1=fire_layer_1");
+/// let textures = cit_textures(&id, &cit);
+/// assert_eq!(textures, [ResourceIdentifier::optifine_texture("cit/swords/fire_layer_1")]);
+/// ```
+pub fn cit_textures(
+    id: &ResourceIdentifier,
+    cit: &CitProperties,
+) -> Vec<ResourceIdentifier<'static>> {
+    let has_replacement = cit.texture.is_some()
+        || !cit.textures.is_empty()
+        || cit.model.is_some()
+        || !cit.models.is_empty();
+    let file_name = id.path().rsplit('/').next().unwrap_or_default();
+
+    let names = cit
+        .texture
+        .as_deref()
+        .into_iter()
+        .chain(cit.textures.values().map(String::as_str))
+        .chain((!has_replacement).then_some(file_name));
+    resolve_all(id, names)
+}
+
+fn resolve_all<'a>(
+    id: &ResourceIdentifier,
+    names: impl Iterator<Item = &'a str>,
+) -> Vec<ResourceIdentifier<'static>> {
+    let mut textures: Vec<ResourceIdentifier<'static>> = Vec::new();
+    for texture in names.filter_map(|name| resolve_texture(id, name)) {
+        if !textures.contains(&texture) {
+            textures.push(texture);
+        }
+    }
+    textures
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn resolves_names_like_optifine() {
+        let id = ResourceIdentifier::ctm_properties("mypack:stone");
+        for (name, expected) in [
+            (
+                "0",
+                Some(ResourceIdentifier::optifine_texture("mypack:ctm/0")),
+            ),
+            (
+                "./edge.png",
+                Some(ResourceIdentifier::optifine_texture("mypack:ctm/edge")),
+            ),
+            (
+                "mypack:textures/block/ruby",
+                Some(ResourceIdentifier::texture("mypack:block/ruby")),
+            ),
+            (
+                "optifine/ctm/shared/1",
+                Some(ResourceIdentifier::optifine_texture("ctm/shared/1")),
+            ),
+            ("block/stone", None),
+            ("", None),
+        ] {
+            assert_eq!(resolve_texture(&id, name), expected, "{}", name);
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use std::{
+    io::{self, Read},
+    path::Path,
+};
+
+use crate::{
+    api::{
+        EnumerateResources, FileSystemResourceProvider, LayeredResourceProvider, LoadResource,
+        ResourceCategory, ResourceIdentifier, ResourceKind, ResourceProvider, Result,
+    },
+    schemas::PackMeta,
+};
+
+use super::{parse_error::parse_root_file, provider::is_missing};
+
+/// A [`ResourceProvider`] that applies the overlays declared in a pack's
+/// `pack.mcmeta` on top of the pack, like the game does since 1.20.2.
+///
+/// Each overlay is a directory at the root of the pack with its own `assets/`
+/// and/or `data/` directories. Only the overlays whose `formats` include the
+/// target pack format are applied. Resources are looked up in the applicable
+/// overlays in order of decreasing priority (i.e., the last entry in
+/// `pack.mcmeta` first), and then in the pack itself.
+///
+/// Root files like `pack.mcmeta` are always loaded from the pack itself.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::api::*;
+/// let mut pack = MemoryResourceProvider::new();
+/// pack.insert(
+///     "pack.mcmeta",
+///     br#"{
+///         "pack": { "pack_format": 15, "supported_formats": [15, 34], "description": "" },
+///         "overlays": { "entries": [{ "formats": [18, 34], "directory": "modern" }] }
+///     }"#.to_vec(),
+/// );
+/// pack.insert("assets/minecraft/lang/en_us.json", br#"{ "greeting": "Hi" }"#.to_vec());
+/// pack.insert("modern/assets/minecraft/lang/en_us.json", br#"{ "greeting": "Hello" }"#.to_vec());
+///
+/// // Each overlay is provided by a provider rooted at its directory.
+/// let open_overlay = |pack: &MemoryResourceProvider, directory: &str| {
+///     let prefix = format!("{}/", directory);
+///     let mut overlay = MemoryResourceProvider::new();
+///     for path in pack.paths() {
+///         if let Some(name) = path.strip_prefix(&prefix) {
+///             overlay.insert(name, pack.load_root_file(path).unwrap());
+///         }
+///     }
+///     overlay
+/// };
+///
+/// let old = OverlayResourceProvider::new(pack.clone(), 15, |dir| open_overlay(&pack, dir)).unwrap();
+/// assert!(old.overlays().is_empty());
+///
+/// let new = OverlayResourceProvider::new(pack.clone(), 34, |dir| open_overlay(&pack, dir)).unwrap();
+/// assert_eq!(new.overlays(), ["modern"]);
+///
+/// let language = AssetPack::new(new).load_language("en_us").unwrap();
+/// assert_eq!(language.get("greeting"), Some("Hello"));
+/// ```
+pub struct OverlayResourceProvider {
+    layers: LayeredResourceProvider,
+    overlays: Vec<String>,
+}
+
+impl OverlayResourceProvider {
+    /// Returns a new provider for the pack in the given directory, with the
+    /// overlays that apply to the given pack format.
+    ///
+    /// See [`new()`][Self::new].
+    pub fn at_path(root_dir: impl AsRef<Path>, pack_format: u32) -> Result<Self> {
+        let root_dir = root_dir.as_ref();
+        Self::new(
+            FileSystemResourceProvider::new(root_dir),
+            pack_format,
+            |dir| FileSystemResourceProvider::new(root_dir.join(dir)),
+        )
+    }
+
+    /// Returns a new provider for the pack provided by `base`, with the
+    /// overlays that apply to the given pack format.
+    ///
+    /// The `pack.mcmeta` file is read from `base`, and `open_overlay` is called
+    /// with the directory of each applicable overlay to get a provider rooted
+    /// at that directory. A pack without a `pack.mcmeta` file has no overlays.
+    pub fn new<P, F, O>(base: P, pack_format: u32, mut open_overlay: F) -> Result<Self>
+    where
+        P: ResourceProvider + 'static,
+        F: FnMut(&str) -> O,
+        O: ResourceProvider + 'static,
+    {
+        let meta = match base.load_root_file("pack.mcmeta") {
+            Ok(bytes) => Some(parse_root_file::<PackMeta>("pack.mcmeta", &bytes)?),
+            Err(e) if is_missing(&e) => None,
+            Err(e) => return Err(e.into()),
+        };
+
+        let overlays: Vec<String> = meta
+            .as_ref()
+            .and_then(|meta| meta.overlays.as_ref())
+            .map(|overlays| {
+                overlays
+                    .applicable(pack_format)
+                    .map(|entry| entry.directory.clone())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut layers = LayeredResourceProvider::new().with_layer(base);
+        for directory in overlays.iter() {
+            layers.push_layer(Overlay(open_overlay(directory)));
+        }
+
+        Ok(Self { layers, overlays })
+    }
+
+    /// Returns the directories of the applied overlays, in order of increasing
+    /// priority.
+    pub fn overlays(&self) -> &[String] {
+        &self.overlays
+    }
+}
+
+impl EnumerateResources for OverlayResourceProvider {
+    fn enumerate_resources(
+        &self,
+        namespace: &str,
+        kind: ResourceKind,
+    ) -> Result<Vec<ResourceIdentifier<'static>>, io::Error> {
+        self.layers.enumerate_resources(namespace, kind)
+    }
+
+    fn enumerate_namespaces(&self, category: ResourceCategory) -> Result<Vec<String>, io::Error> {
+        self.layers.enumerate_namespaces(category)
+    }
+}
+
+impl LoadResource for OverlayResourceProvider {
+    fn load_resource(&self, id: &ResourceIdentifier) -> Result<Vec<u8>, io::Error> {
+        self.layers.load_resource(id)
+    }
+
+    fn load_root_file(&self, name: &str) -> Result<Vec<u8>, io::Error> {
+        self.layers.load_root_file(name)
+    }
+
+    fn open_resource(&self, id: &ResourceIdentifier) -> Result<Box<dyn Read + '_>, io::Error> {
+        self.layers.open_resource(id)
+    }
+}
+
+/// An overlay layer, which provides resources but no root files.
+struct Overlay<P>(P);
+
+impl<P: EnumerateResources> EnumerateResources for Overlay<P> {
+    fn enumerate_resources(
+        &self,
+        namespace: &str,
+        kind: ResourceKind,
+    ) -> Result<Vec<ResourceIdentifier<'static>>, io::Error> {
+        self.0.enumerate_resources(namespace, kind)
+    }
+
+    fn enumerate_namespaces(&self, category: ResourceCategory) -> Result<Vec<String>, io::Error> {
+        self.0.enumerate_namespaces(category)
+    }
+}
+
+impl<P: LoadResource> LoadResource for Overlay<P> {
+    fn load_resource(&self, id: &ResourceIdentifier) -> Result<Vec<u8>, io::Error> {
+        self.0.load_resource(id)
+    }
+
+    fn open_resource(&self, id: &ResourceIdentifier) -> Result<Box<dyn Read + '_>, io::Error> {
+        self.0.open_resource(id)
+    }
+}
@@ -0,0 +1,183 @@
+//! Hashing resource pack `.zip` files for servers.
+//!
+//! Start here: [`PackHash`].
+//!
+//! This module requires the `hash` feature.
+//!
+//! A server that sends a resource pack to its players, with the
+//! `resource-pack` and `resource-pack-sha1` properties of `server.properties`
+//! or with a resource pack push packet, gives the SHA-1 hash of the `.zip`
+//! file as 40 hexadecimal digits. The client refuses the downloaded pack if
+//! its hash does not match.
+//!
+//! See <https://minecraft.wiki/w/Server.properties#resource-pack-sha1>.
+
+use std::{
+    fmt::Write as _,
+    fs,
+    io::{self, Read},
+    path::Path,
+};
+
+use sha1::{Digest, Sha1};
+use sha2::Sha256;
+
+use crate::api::Result;
+
+/// The SHA-1 and SHA-256 hashes of a resource pack `.zip` file.
+///
+/// # Example
+///
+/// ```
+/// use minecraft_assets::api::pack_hash::{is_sha1_hex, PackHash};
+///
+/// let zip: &[u8] = b"(zip data)";
+///
+/// // The value of the `resource-pack-sha1` property.
+/// let hash = PackHash::from_bytes(zip);
+/// let sha1 = hash.sha1_hex();
+/// assert!(is_sha1_hex(&sha1));
+///
+/// // Checking a downloaded pack against the hash given by the server.
+/// let downloaded = PackHash::read(zip).unwrap();
+/// assert!(downloaded.verify(&sha1.to_uppercase()).is_ok());
+/// assert!(downloaded.verify(&hash.sha256_hex()).is_ok());
+/// assert!(downloaded.verify(&"0".repeat(40)).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PackHash {
+    /// The SHA-1 hash, used by the game.
+    pub sha1: [u8; 20],
+
+    /// The SHA-256 hash, e.g., for publishing next to the pack.
+    pub sha256: [u8; 32],
+}
+
+impl PackHash {
+    /// Returns the hashes of the given contents of a `.zip` file.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self {
+            sha1: Sha1::digest(bytes).into(),
+            sha256: Sha256::digest(bytes).into(),
+        }
+    }
+
+    /// Returns the hashes of the `.zip` file read from the given reader, which
+    /// is streamed rather than read into memory.
+    pub fn read<R: Read>(mut reader: R) -> Result<Self> {
+        let mut sha1 = Sha1::new();
+        let mut sha256 = Sha256::new();
+
+        let mut buffer = vec![0; 64 * 1024];
+        loop {
+            let len = match reader.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(len) => len,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e.into()),
+            };
+            sha1.update(&buffer[..len]);
+            sha256.update(&buffer[..len]);
+        }
+
+        Ok(Self {
+            sha1: sha1.finalize().into(),
+            sha256: sha256.finalize().into(),
+        })
+    }
+
+    /// Returns the hashes of the `.zip` file at the given path.
+    pub fn at_path(path: impl AsRef<Path>) -> Result<Self> {
+        Self::read(fs::File::open(path)?)
+    }
+
+    /// Returns the SHA-1 hash as 40 lowercase hexadecimal digits, in the form
+    /// of the `resource-pack-sha1` property and of the resource pack push
+    /// packet.
+    pub fn sha1_hex(&self) -> String {
+        to_hex(&self.sha1)
+    }
+
+    /// Returns the SHA-256 hash as 64 lowercase hexadecimal digits.
+    pub fn sha256_hex(&self) -> String {
+        to_hex(&self.sha256)
+    }
+
+    /// Returns `Ok(())` if the given hexadecimal SHA-1 or SHA-256 hash, in
+    /// any case, matches the pack.
+    ///
+    /// The algorithm is chosen by the length of the hash. Returns an error of
+    /// kind [`io::ErrorKind::InvalidInput`] if the hash is neither 40 nor 64
+    /// hexadecimal digits, and of kind [`io::ErrorKind::InvalidData`] if it
+    /// does not match.
+    pub fn verify(&self, expected: &str) -> Result<(), io::Error> {
+        let (name, actual) = match expected.len() {
+            40 if is_hex(expected) => ("SHA-1", self.sha1_hex()),
+            64 if is_hex(expected) => ("SHA-256", self.sha256_hex()),
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("not a SHA-1 or SHA-256 hash: {:?}", expected),
+                ))
+            }
+        };
+
+        if actual.eq_ignore_ascii_case(expected) {
+            Ok(())
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{} mismatch: expected {}, got {}", name, expected, actual),
+            ))
+        }
+    }
+}
+
+/// Returns `true` if the given string is a valid value for the
+/// `resource-pack-sha1` property, i.e., 40 hexadecimal digits.
+///
+/// The server only warns about other values, but clients then refuse the
+/// pack.
+pub fn is_sha1_hex(hash: &str) -> bool {
+    hash.len() == 40 && is_hex(hash)
+}
+
+fn is_hex(s: &str) -> bool {
+    s.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut hex, byte| {
+        let _ = write!(hex, "{:02x}", byte);
+        hex
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn hashes_known_values() {
+        let hash = PackHash::from_bytes(b"abc");
+        assert_eq!(hash.sha1_hex(), "a9993e364706816aba3e25717850c26c9cd0d89d");
+        assert_eq!(
+            hash.sha256_hex(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+
+        let streamed = PackHash::read(io::Cursor::new(vec![b'a'; 200_000])).unwrap();
+        assert_eq!(streamed, PackHash::from_bytes(&[b'a'; 200_000]));
+    }
+
+    #[test]
+    fn rejects_malformed_hashes() {
+        let hash = PackHash::from_bytes(b"abc");
+        for expected in ["", "a9993e36", &"z".repeat(40)] {
+            let error = hash.verify(expected).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        }
+        assert!(!is_sha1_hex("a9993e36"));
+        assert!(is_sha1_hex("A9993E364706816ABA3E25717850C26C9CD0D89D"));
+    }
+}
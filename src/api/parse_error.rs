@@ -0,0 +1,227 @@
+use std::fmt;
+
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_path_to_error::Segment;
+
+use crate::api::{ResourceIdentifier, ResourceKind, TargetVersion};
+
+/// An error from parsing the JSON of a resource, with the resource and the
+/// location in the file where it occurred.
+///
+/// The [`AssetPack`] API returns this error (as
+/// [`Error::ResourceParseError`]) for every resource that does not match its
+/// schema, so that a failure among thousands of files can be traced back to
+/// its file and field.
+///
+/// [`AssetPack`]: crate::api::AssetPack
+/// [`Error::ResourceParseError`]: crate::api::Error::ResourceParseError
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::api::*;
+/// let mut provider = MemoryResourceProvider::new();
+/// provider.insert(
+///     "assets/minecraft/sounds.json",
+///     br#"{
+///         "block.anvil.break": {
+///             "sounds": [{ "stream": true }]
+///         }
+///     }"#
+///     .to_vec(),
+/// );
+///
+/// let assets = AssetPack::new(provider);
+/// let Err(Error::ResourceParseError(error)) = assets.load_sounds("minecraft") else {
+///     panic!("expected a parse error");
+/// };
+///
+/// assert_eq!(error.kind(), Some(ResourceKind::Sounds));
+/// assert_eq!(error.path(), "assets/minecraft/sounds.json");
+/// assert_eq!(error.json_path(), "/block.anvil.break/sounds/0");
+/// assert_eq!(error.line(), 3);
+/// assert_eq!(
+///     error.to_string(),
+///     "failed to parse assets/minecraft/sounds.json at /block.anvil.break/sounds/0: \
+///      data did not match any variant of untagged enum Sound at line 3 column 42"
+/// );
+/// ```
+#[derive(Debug)]
+pub struct ResourceParseError {
+    id: Option<ResourceIdentifier<'static>>,
+    path: String,
+    json_path: String,
+    error: serde_json::Error,
+}
+
+impl ResourceParseError {
+    /// Returns the ID of the resource, or `None` for a file at the root of the
+    /// pack, like `pack.mcmeta`.
+    pub fn id(&self) -> Option<&ResourceIdentifier<'static>> {
+        self.id.as_ref()
+    }
+
+    /// Returns the kind of the resource, or `None` for a file at the root of
+    /// the pack.
+    pub fn kind(&self) -> Option<ResourceKind> {
+        self.id.as_ref().map(ResourceIdentifier::kind)
+    }
+
+    /// Returns the path of the file relative to the root of the pack, e.g.,
+    /// `assets/minecraft/sounds.json`.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns the location of the value that failed to parse, as a [JSON
+    /// pointer] (e.g., `/elements/0/faces`), or an empty string for the whole
+    /// file.
+    ///
+    /// The location is as deep as the schema can tell: values of enums that
+    /// are told apart by their contents (like the variants and multipart
+    /// forms of block states) are reported at the enum.
+    ///
+    /// [JSON pointer]: <https://datatracker.ietf.org/doc/html/rfc6901>
+    pub fn json_path(&self) -> &str {
+        &self.json_path
+    }
+
+    /// Returns the line of the file where the error occurred, starting at 1.
+    pub fn line(&self) -> usize {
+        self.error.line()
+    }
+
+    /// Returns the column of the line where the error occurred, starting at 1.
+    pub fn column(&self) -> usize {
+        self.error.column()
+    }
+
+    /// Returns the underlying JSON error.
+    pub fn json_error(&self) -> &serde_json::Error {
+        &self.error
+    }
+
+    /// Returns the error of the resource with the given ID, in a pack with
+    /// the layout of the given version.
+    pub(crate) fn for_resource(
+        id: &ResourceIdentifier,
+        version: Option<TargetVersion>,
+        (json_path, error): (String, serde_json::Error),
+    ) -> Self {
+        Self {
+            id: Some(id.to_owned()),
+            path: id.pack_path_in(version),
+            json_path,
+            error,
+        }
+    }
+
+    /// Returns the error of the file at the given path relative to the root of
+    /// a pack, which may or may not be a resource.
+    #[cfg(feature = "bevy")]
+    pub(crate) fn for_file(path: &str, (json_path, error): (String, serde_json::Error)) -> Self {
+        Self {
+            id: ResourceIdentifier::from_pack_path(path),
+            path: path.to_string(),
+            json_path,
+            error,
+        }
+    }
+
+    /// Returns the error of the file with the given name at the root of the
+    /// pack.
+    pub(crate) fn for_root_file(
+        name: &str,
+        (json_path, error): (String, serde_json::Error),
+    ) -> Self {
+        Self {
+            id: None,
+            path: name.to_string(),
+            json_path,
+            error,
+        }
+    }
+}
+
+impl fmt::Display for ResourceParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to parse {}", self.path)?;
+        if !self.json_path.is_empty() {
+            write!(f, " at {}", self.json_path)?;
+        }
+        write!(f, ": {}", self.error)
+    }
+}
+
+impl std::error::Error for ResourceParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Parses the given JSON resource, and returns the error with the JSON
+/// pointer of the value that failed to parse otherwise.
+pub(crate) fn parse_json<'de, T>(bytes: &'de [u8]) -> Result<T, (String, serde_json::Error)>
+where
+    T: Deserialize<'de>,
+{
+    let mut deserializer = serde_json::Deserializer::from_slice(bytes);
+    let value = serde_path_to_error::deserialize(&mut deserializer).map_err(|e| {
+        let json_path = json_pointer(e.path());
+        (json_path, e.into_inner())
+    })?;
+    deserializer.end().map_err(|e| (String::new(), e))?;
+    Ok(value)
+}
+
+/// Parses the given JSON resource with the given ID.
+pub(crate) fn parse_resource<T>(
+    id: &ResourceIdentifier,
+    version: Option<TargetVersion>,
+    bytes: &[u8],
+) -> Result<T, ResourceParseError>
+where
+    T: DeserializeOwned,
+{
+    parse_json(bytes).map_err(|e| ResourceParseError::for_resource(id, version, e))
+}
+
+/// Parses the given JSON file at the root of a pack.
+pub(crate) fn parse_root_file<T>(name: &str, bytes: &[u8]) -> Result<T, ResourceParseError>
+where
+    T: DeserializeOwned,
+{
+    parse_json(bytes).map_err(|e| ResourceParseError::for_root_file(name, e))
+}
+
+fn json_pointer(path: &serde_path_to_error::Path) -> String {
+    let mut pointer = String::new();
+    for segment in path.iter() {
+        pointer.push('/');
+        match segment {
+            Segment::Seq { index } => pointer.push_str(&index.to_string()),
+            Segment::Map { key } => pointer.push_str(&key.replace('~', "~0").replace('/', "~1")),
+            Segment::Enum { variant } => pointer.push_str(variant),
+            Segment::Unknown => pointer.push('?'),
+        }
+    }
+    pointer
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::schemas::Model;
+
+    #[test]
+    fn reports_the_json_pointer_of_the_failure() {
+        let json = br#"{ "overrides": [{ "predicate": { "my/mod~": "x" }, "model": "a" }] }"#;
+        let (json_path, _) = parse_json::<Model>(json).unwrap_err();
+        assert_eq!(json_path, "/overrides/0/predicate/my~1mod~0");
+
+        let json = br#"{ "elements": [] } trailing"#;
+        let (json_path, error) = parse_json::<Model>(json).unwrap_err();
+        assert_eq!(json_path, "");
+        assert_eq!(error.line(), 1);
+    }
+}
@@ -1,9 +1,20 @@
 use std::{
-    fs, io,
+    collections::{BTreeSet, HashSet},
+    fs,
+    io::{self, Read},
     path::{Path, PathBuf},
 };
 
-use crate::api::{ResourceIdentifier, ResourceKind, ResourcePath};
+use regex::Regex;
+use serde::Deserialize;
+
+use crate::{
+    api::{
+        ResourceCategory, ResourceIdentifier, ResourceKind, ResourcePath, TargetVersion,
+        MINECRAFT_NAMESPACE,
+    },
+    schemas::pack_meta::PackFilter,
+};
 
 /*
  dMMMMMMP dMMMMb  .aMMMb  dMP dMMMMMMP .dMMMb
@@ -16,12 +27,50 @@ dMP   dMP dMP dMP dMP dMP    dMP    VMMMP"
 /// Indicates that a type can enumerate available resources.
 pub trait EnumerateResources {
     /// Enumerates the available resources of the given [`ResourceKind`] in the
-    /// given namespace.
+    /// given namespace, including those in subdirectories.
     fn enumerate_resources(
         &self,
         namespace: &str,
         kind: ResourceKind,
     ) -> Result<Vec<ResourceIdentifier<'static>>, io::Error>;
+
+    /// Enumerates the namespaces that are present in the given
+    /// [`ResourceCategory`] (i.e., the directories under `assets/` or
+    /// `data/`), in sorted order.
+    ///
+    /// The default implementation returns an error of kind
+    /// [`io::ErrorKind::Unsupported`].
+    fn enumerate_namespaces(&self, category: ResourceCategory) -> Result<Vec<String>, io::Error> {
+        let _ = category;
+        Err(io::Error::from(io::ErrorKind::Unsupported))
+    }
+
+    /// Enumerates the available resources of the given [`ResourceKind`] in
+    /// every namespace.
+    ///
+    /// If the namespaces cannot be enumerated, only the `minecraft` namespace
+    /// is searched. Namespaces that have no resources of the given kind are
+    /// skipped.
+    fn list(&self, kind: ResourceKind) -> Result<Vec<ResourceIdentifier<'static>>, io::Error> {
+        let namespaces = match self.enumerate_namespaces(kind.category()) {
+            Ok(namespaces) => namespaces,
+            Err(e) if e.kind() == io::ErrorKind::Unsupported => {
+                vec![String::from(MINECRAFT_NAMESPACE)]
+            }
+            Err(e) => return Err(e),
+        };
+
+        let mut ids = Vec::new();
+        for namespace in namespaces {
+            match self.enumerate_resources(&namespace, kind) {
+                Ok(namespace_ids) => ids.extend(namespace_ids),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(ids)
+    }
 }
 
 /// Indicates that a type can load provide the raw data of resources.
@@ -29,6 +78,57 @@ pub trait LoadResource {
     /// Returns the raw bytes of the resource referenced by the given
     /// [`ResourceIdentifier`].
     fn load_resource(&self, id: &ResourceIdentifier) -> Result<Vec<u8>, io::Error>;
+
+    /// Returns the raw bytes of the file with the given name at the root of
+    /// the pack (i.e., next to the `assets/` and `data/` directories), such as
+    /// `pack.mcmeta`.
+    ///
+    /// The default implementation returns an error of kind
+    /// [`io::ErrorKind::Unsupported`].
+    fn load_root_file(&self, name: &str) -> Result<Vec<u8>, io::Error> {
+        let _ = name;
+        Err(io::Error::from(io::ErrorKind::Unsupported))
+    }
+
+    /// Returns a reader over the raw bytes of the resource referenced by the
+    /// given [`ResourceIdentifier`], so that large resources can be streamed.
+    ///
+    /// The default implementation reads the whole resource with
+    /// [`load_resource()`][Self::load_resource].
+    fn open_resource(&self, id: &ResourceIdentifier) -> Result<Box<dyn Read + '_>, io::Error> {
+        Ok(Box::new(io::Cursor::new(self.load_resource(id)?)))
+    }
+}
+
+/// Indicates that a type can write the raw data of resources.
+///
+/// This is the counterpart of [`LoadResource`], used by the
+/// [`ResourceWriter`][crate::api::ResourceWriter] API.
+pub trait WriteResource {
+    /// Writes the given raw bytes as the resource referenced by the given
+    /// [`ResourceIdentifier`], replacing it if it already exists.
+    fn write_resource(&mut self, id: &ResourceIdentifier, data: &[u8]) -> Result<(), io::Error>;
+
+    /// Writes the given raw bytes as the file with the given name at the root
+    /// of the pack (i.e., next to the `assets/` and `data/` directories), such
+    /// as `pack.mcmeta`.
+    ///
+    /// The default implementation returns an error of kind
+    /// [`io::ErrorKind::Unsupported`].
+    fn write_root_file(&mut self, name: &str, data: &[u8]) -> Result<(), io::Error> {
+        let _ = (name, data);
+        Err(io::Error::from(io::ErrorKind::Unsupported))
+    }
+}
+
+impl<W: WriteResource + ?Sized> WriteResource for &mut W {
+    fn write_resource(&mut self, id: &ResourceIdentifier, data: &[u8]) -> Result<(), io::Error> {
+        (**self).write_resource(id, data)
+    }
+
+    fn write_root_file(&mut self, name: &str, data: &[u8]) -> Result<(), io::Error> {
+        (**self).write_root_file(name, data)
+    }
 }
 
 /// Marker trait for types that are [`EnumerateResources`] and [`LoadResource`].
@@ -52,8 +152,12 @@ dMP     dMP dMP  VMMMP"    VP"  dMP dMMMMP" dMMMMMP dMP dMP
 */
 
 /// A [`ResourceProvider`] that provides resources from the local file system.
+///
+/// It also implements [`WriteResource`], so it can be used to write packs.
+#[derive(Debug, Clone)]
 pub struct FileSystemResourceProvider {
     root: PathBuf,
+    version: Option<TargetVersion>,
 }
 
 impl FileSystemResourceProvider {
@@ -64,6 +168,50 @@ impl FileSystemResourceProvider {
     pub fn new(root: impl AsRef<Path>) -> Self {
         Self {
             root: PathBuf::from(root.as_ref()),
+            version: None,
+        }
+    }
+
+    /// Uses the directory layout of the given [`TargetVersion`] and returns
+    /// `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// use std::path::Path;
+    ///
+    /// let provider = FileSystemResourceProvider::new("pack").with_target_version(TargetVersion::V1_21);
+    /// let path = provider.resource_path(&ResourceIdentifier::recipe("stick"));
+    /// assert_eq!(&*path, Path::new("pack/data/minecraft/recipe/stick.json"));
+    /// ```
+    pub fn with_target_version(mut self, version: TargetVersion) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Returns the root directory of this provider.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Returns the [`TargetVersion`] whose directory layout is used, if any.
+    pub fn target_version(&self) -> Option<TargetVersion> {
+        self.version
+    }
+
+    /// Returns the path of the file that stores the given resource.
+    pub fn resource_path(&self, id: &ResourceIdentifier) -> ResourcePath {
+        match self.version {
+            Some(version) => ResourcePath::for_resource_in(&self.root, id, version),
+            None => ResourcePath::for_resource(&self.root, id),
+        }
+    }
+
+    fn kind_path(&self, namespace: &str, kind: ResourceKind) -> ResourcePath {
+        match self.version {
+            Some(version) => ResourcePath::for_kind_in(&self.root, namespace, kind, version),
+            None => ResourcePath::for_kind(&self.root, namespace, kind),
         }
     }
 }
@@ -74,16 +222,363 @@ impl EnumerateResources for FileSystemResourceProvider {
         namespace: &str,
         kind: ResourceKind,
     ) -> Result<Vec<ResourceIdentifier<'static>>, io::Error> {
-        let directory = ResourcePath::for_kind(&self.root, namespace, kind);
-        Ok(ResourceIter::new(directory, kind)?.collect())
+        if let Some(name) = kind.single_file() {
+            let id = ResourceIdentifier::new_owned(kind, format!("{}:{}", namespace, name));
+            let path = self.resource_path(&id);
+
+            return Ok(if path.is_file() { vec![id] } else { vec![] });
+        }
+
+        let directory = self.kind_path(namespace, kind);
+        Ok(ResourceIter::new(directory, namespace, kind)?.collect())
+    }
+
+    fn enumerate_namespaces(&self, category: ResourceCategory) -> Result<Vec<String>, io::Error> {
+        let dir_iter = match fs::read_dir(self.root.join(category.directory())) {
+            Ok(dir_iter) => dir_iter,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => return Err(e),
+        };
+
+        let mut namespaces = Vec::new();
+        for dir_entry in dir_iter {
+            let dir_entry = dir_entry?;
+
+            // Skip over files and UTF-8 errors.
+            if dir_entry.file_type()?.is_dir() {
+                if let Some(name) = dir_entry.file_name().to_str() {
+                    namespaces.push(String::from(name));
+                }
+            }
+        }
+
+        namespaces.sort();
+        Ok(namespaces)
     }
 }
 
 impl LoadResource for FileSystemResourceProvider {
     fn load_resource(&self, id: &ResourceIdentifier) -> Result<Vec<u8>, io::Error> {
-        let path = ResourcePath::for_resource(&self.root, id);
+        let path = self.resource_path(id);
         fs::read(path)
     }
+
+    fn load_root_file(&self, name: &str) -> Result<Vec<u8>, io::Error> {
+        fs::read(self.root.join(name))
+    }
+
+    fn open_resource(&self, id: &ResourceIdentifier) -> Result<Box<dyn Read + '_>, io::Error> {
+        let path = self.resource_path(id);
+        Ok(Box::new(fs::File::open(path)?))
+    }
+}
+
+impl WriteResource for FileSystemResourceProvider {
+    fn write_resource(&mut self, id: &ResourceIdentifier, data: &[u8]) -> Result<(), io::Error> {
+        let path = self.resource_path(id);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(path, data)
+    }
+
+    fn write_root_file(&mut self, name: &str, data: &[u8]) -> Result<(), io::Error> {
+        fs::create_dir_all(&self.root)?;
+        fs::write(self.root.join(name), data)
+    }
+}
+
+/*
+    dMP     .aMMMb  dMP dMP dMMMMMP dMMMMb  dMMMMMP dMMMMb
+   dMP     dMP"dMP dMP.dMP dMP     dMP.dMP dMP     dMP VMP
+  dMP     dMMMMMP VMMMMP  dMMMP   dMMMMK" dMMMP   dMP dMP
+ dMP     dMP dMP dA .dMP dMP     dMP"AMF dMP     dMP.aMP
+dMMMMMP dMP dMP VMMMP"  dMMMMMP dMP dMP dMMMMMP dMMMMP"
+
+    dMMMMb  dMMMMb  .aMMMb  dMP dMP dMP dMMMMb  dMMMMMP dMMMMb
+   dMP.dMP dMP.dMP dMP"dMP dMP dMP amr dMP VMP dMP     dMP.dMP
+  dMMMMP" dMMMMK" dMP dMP dMP dMP dMP dMP dMP dMMMP   dMMMMK"
+ dMP     dMP"AMF dMP.aMP  YMvAP" dMP dMP.aMP dMP     dMP"AMF
+dMP     dMP dMP  VMMMP"    VP"  dMP dMMMMP" dMMMMMP dMP dMP
+
+*/
+
+/// A [`ResourceProvider`] that stacks multiple providers on top of each
+/// other, like the game does with the vanilla assets and enabled resource
+/// packs.
+///
+/// Layers are added from the bottom up, so the vanilla assets should be added
+/// first and the highest priority resource pack last. Each resource is loaded
+/// from the topmost layer that has it, and enumerating resources or
+/// namespaces merges the results of all layers.
+///
+/// Like in the game, the `filter` section of a layer's `pack.mcmeta` hides
+/// the matching resources of every layer below it. The section is read when
+/// the layer is added; a layer without a readable `pack.mcmeta` file hides
+/// nothing.
+///
+/// # Example
+///
+/// ```no_run
+/// # use minecraft_assets::api::*;
+/// let provider = LayeredResourceProvider::new()
+///     .with_layer(FileSystemResourceProvider::new("~/.minecraft/"))
+///     .with_layer(FileSystemResourceProvider::new("~/.minecraft/resourcepacks/my_pack/"));
+///
+/// let assets = AssetPack::new(provider);
+///
+/// // Loaded from `my_pack` if it overrides the model, or from the vanilla
+/// // assets otherwise.
+/// let model = assets.load_block_model("oak_planks").unwrap();
+/// ```
+#[derive(Default)]
+pub struct LayeredResourceProvider {
+    // Ordered from the bottom layer to the top layer.
+    layers: Vec<Box<dyn ResourceProvider>>,
+    // The filter of each layer, in the same order.
+    filters: Vec<LayerFilter>,
+}
+
+impl LayeredResourceProvider {
+    /// Returns a new provider with no layers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the given provider as the new top layer and returns `self`.
+    pub fn with_layer<P>(mut self, provider: P) -> Self
+    where
+        P: ResourceProvider + 'static,
+    {
+        self.push_layer(provider);
+        self
+    }
+
+    /// Adds the given provider as the new top layer.
+    pub fn push_layer<P>(&mut self, provider: P)
+    where
+        P: ResourceProvider + 'static,
+    {
+        self.filters.push(LayerFilter::of(&provider));
+        self.layers.push(Box::new(provider));
+    }
+
+    /// Returns the number of layers in the stack.
+    pub fn num_layers(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Returns the layers in the stack, from the bottom up.
+    ///
+    /// The layers are returned as is: use
+    /// [`is_filtered()`][Self::is_filtered] to skip the resources that are
+    /// hidden by the layers above them.
+    pub fn layers(&self) -> impl Iterator<Item = &dyn ResourceProvider> {
+        self.layers.iter().map(|layer| layer.as_ref())
+    }
+
+    /// Returns `true` if the given resource of the layer at the given index
+    /// (from the bottom up) is hidden by the `filter` of a layer above it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let mut vanilla = MemoryResourceProvider::new();
+    /// vanilla.insert("assets/minecraft/lang/en_us.json", br#"{ "greeting": "Hi" }"#.to_vec());
+    /// vanilla.insert("assets/minecraft/lang/fr_fr.json", br#"{ "greeting": "Salut" }"#.to_vec());
+    ///
+    /// let mut pack = MemoryResourceProvider::new();
+    /// pack.insert(
+    ///     "pack.mcmeta",
+    ///     br#"{
+    ///         "pack": { "pack_format": 34, "description": "" },
+    ///         "filter": { "block": [{ "namespace": "minecraft", "path": "lang/.*" }] }
+    ///     }"#.to_vec(),
+    /// );
+    /// pack.insert("assets/minecraft/lang/en_us.json", br#"{ "greeting": "Hello" }"#.to_vec());
+    ///
+    /// let layers = LayeredResourceProvider::new().with_layer(vanilla).with_layer(pack);
+    ///
+    /// let en_us = ResourceIdentifier::language("en_us");
+    /// assert!(layers.is_filtered(0, &en_us));
+    /// assert!(!layers.is_filtered(1, &en_us));
+    ///
+    /// // Only the pack's own languages are left.
+    /// let languages = layers.enumerate_resources("minecraft", ResourceKind::Language).unwrap();
+    /// assert_eq!(languages, [en_us]);
+    /// assert!(layers.load_resource(&ResourceIdentifier::language("fr_fr")).is_err());
+    /// ```
+    pub fn is_filtered(&self, layer: usize, id: &ResourceIdentifier) -> bool {
+        self.filters
+            .get(layer + 1..)
+            .is_some_and(|above| above.iter().any(|filter| filter.blocks(id)))
+    }
+
+    /// Returns the layers in which the given resource is visible, from the top
+    /// down.
+    fn visible_layers(&self, id: &ResourceIdentifier) -> &[Box<dyn ResourceProvider>] {
+        let bottom = self
+            .filters
+            .iter()
+            .rposition(|filter| filter.blocks(id))
+            .unwrap_or(0);
+        &self.layers[bottom..]
+    }
+
+    /// Returns the first successful result of the given function, trying each
+    /// of the given layers from the top down.
+    ///
+    /// Errors of kind [`io::ErrorKind::NotFound`] and
+    /// [`io::ErrorKind::Unsupported`] fall through to the next layer; any
+    /// other error is returned immediately.
+    fn load_from_top<'a, F, T>(
+        layers: &'a [Box<dyn ResourceProvider>],
+        mut load: F,
+    ) -> Result<T, io::Error>
+    where
+        F: FnMut(&'a dyn ResourceProvider) -> Result<T, io::Error>,
+    {
+        for layer in layers.iter().rev() {
+            match load(layer.as_ref()) {
+                Err(e) if is_missing(&e) => continue,
+                result => return result,
+            }
+        }
+
+        Err(io::Error::from(io::ErrorKind::NotFound))
+    }
+}
+
+impl EnumerateResources for LayeredResourceProvider {
+    fn enumerate_resources(
+        &self,
+        namespace: &str,
+        kind: ResourceKind,
+    ) -> Result<Vec<ResourceIdentifier<'static>>, io::Error> {
+        let mut seen = HashSet::new();
+        let mut ids = Vec::new();
+
+        for (i, layer) in self.layers.iter().enumerate().rev() {
+            let layer_ids = match layer.enumerate_resources(namespace, kind) {
+                Ok(layer_ids) => layer_ids,
+                Err(e) if is_missing(&e) => continue,
+                Err(e) => return Err(e),
+            };
+
+            for id in layer_ids {
+                if !self.is_filtered(i, &id) && seen.insert(id.clone()) {
+                    ids.push(id);
+                }
+            }
+        }
+
+        Ok(ids)
+    }
+
+    fn enumerate_namespaces(&self, category: ResourceCategory) -> Result<Vec<String>, io::Error> {
+        let mut namespaces = BTreeSet::new();
+
+        for layer in self.layers.iter() {
+            match layer.enumerate_namespaces(category) {
+                Ok(layer_namespaces) => namespaces.extend(layer_namespaces),
+                Err(e) if is_missing(&e) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(namespaces.into_iter().collect())
+    }
+}
+
+impl LoadResource for LayeredResourceProvider {
+    fn load_resource(&self, id: &ResourceIdentifier) -> Result<Vec<u8>, io::Error> {
+        Self::load_from_top(self.visible_layers(id), |layer| layer.load_resource(id))
+    }
+
+    fn load_root_file(&self, name: &str) -> Result<Vec<u8>, io::Error> {
+        Self::load_from_top(&self.layers, |layer| layer.load_root_file(name))
+    }
+
+    fn open_resource(&self, id: &ResourceIdentifier) -> Result<Box<dyn Read + '_>, io::Error> {
+        Self::load_from_top(self.visible_layers(id), |layer| layer.open_resource(id))
+    }
+}
+
+/// The compiled `filter` section of a layer's `pack.mcmeta`.
+#[derive(Default)]
+struct LayerFilter {
+    // The namespace and path regular expressions of each pattern, matching
+    // whole strings. Patterns with an invalid regular expression are left
+    // out, since they match nothing.
+    patterns: Vec<(Option<Regex>, Option<Regex>)>,
+}
+
+impl LayerFilter {
+    /// Reads the filter of the given layer, if it has one.
+    fn of(layer: &dyn ResourceProvider) -> Self {
+        #[derive(Deserialize)]
+        struct FilterSection {
+            filter: Option<PackFilter>,
+        }
+
+        let filter = layer
+            .load_root_file("pack.mcmeta")
+            .ok()
+            .and_then(|bytes| serde_json::from_slice::<FilterSection>(&bytes).ok())
+            .and_then(|section| section.filter);
+
+        let Some(filter) = filter else {
+            return Self::default();
+        };
+
+        let compile = |pattern: &Option<String>| match pattern {
+            Some(pattern) => Regex::new(&format!("^(?:{})$", pattern)).map(Some),
+            None => Ok(None),
+        };
+
+        let patterns = filter
+            .block
+            .iter()
+            .filter_map(|pattern| {
+                Some((
+                    compile(&pattern.namespace).ok()?,
+                    compile(&pattern.path).ok()?,
+                ))
+            })
+            .collect();
+
+        Self { patterns }
+    }
+
+    /// Returns `true` if the filter hides the given resource.
+    ///
+    /// Like in the game, paths are matched relative to the namespace
+    /// directory and with their extension, e.g., `textures/block/stone.png`.
+    fn blocks(&self, id: &ResourceIdentifier) -> bool {
+        if self.patterns.is_empty() {
+            return false;
+        }
+
+        let pack_path = id.pack_path();
+        let path = pack_path.splitn(3, '/').nth(2).unwrap_or_default();
+
+        self.patterns.iter().any(|(namespace, path_regex)| {
+            namespace
+                .as_ref()
+                .is_none_or(|regex| regex.is_match(id.namespace()))
+                && path_regex.as_ref().is_none_or(|regex| regex.is_match(path))
+        })
+    }
+}
+
+/// Returns true if the error means that a layer does not have a resource.
+pub(crate) fn is_missing(error: &io::Error) -> bool {
+    matches!(
+        error.kind(),
+        io::ErrorKind::NotFound | io::ErrorKind::Unsupported
+    )
 }
 
 /*
@@ -102,6 +597,7 @@ pub struct ResourceIter {
     dir_iters: Vec<fs::ReadDir>,
     // Stack of directory names.
     dir_names: Vec<String>,
+    namespace: String,
     kind: ResourceKind,
 }
 
@@ -111,12 +607,17 @@ enum DirOrResource {
 }
 
 impl ResourceIter {
-    pub fn new(directory: impl AsRef<Path>, kind: ResourceKind) -> Result<Self, io::Error> {
+    pub fn new(
+        directory: impl AsRef<Path>,
+        namespace: &str,
+        kind: ResourceKind,
+    ) -> Result<Self, io::Error> {
         let dir_iter = fs::read_dir(directory)?;
 
         Ok(Self {
             dir_iters: vec![dir_iter],
             dir_names: vec![],
+            namespace: String::from(namespace),
             kind,
         })
     }
@@ -171,8 +672,11 @@ impl ResourceIter {
 
                                     let resource_path = components.join("/");
 
-                                    let id =
-                                        ResourceIdentifier::new_owned(self.kind, resource_path);
+                                    let id = ResourceIdentifier::in_namespace(
+                                        self.kind,
+                                        &self.namespace,
+                                        &resource_path,
+                                    );
                                     DirOrResource::Resource(id)
                                 })
                             })
@@ -0,0 +1,77 @@
+//! The random generator of the game.
+
+/// The linear congruential generator of `java.util.Random`, as used by the
+/// game's `LegacyRandomSource`, e.g., to pick the models of blocks and to seed
+/// the noise of biome colors.
+pub(crate) struct LegacyRandom {
+    seed: i64,
+}
+
+impl LegacyRandom {
+    const MULTIPLIER: i64 = 0x5DEECE66D;
+    const MASK: i64 = (1 << 48) - 1;
+
+    pub(crate) fn new(seed: i64) -> Self {
+        Self {
+            seed: (seed ^ Self::MULTIPLIER) & Self::MASK,
+        }
+    }
+
+    fn next(&mut self, bits: u32) -> i32 {
+        self.seed = self.seed.wrapping_mul(Self::MULTIPLIER).wrapping_add(0xB) & Self::MASK;
+        (self.seed >> (48 - bits)) as i32
+    }
+
+    #[cfg_attr(not(feature = "image"), allow(dead_code))]
+    pub(crate) fn next_int(&mut self, bound: i32) -> i32 {
+        if bound & (bound - 1) == 0 {
+            return ((i64::from(bound) * i64::from(self.next(31))) >> 31) as i32;
+        }
+
+        loop {
+            let bits = self.next(31);
+            let value = bits % bound;
+            if bits - value + (bound - 1) >= 0 {
+                return value;
+            }
+        }
+    }
+
+    pub(crate) fn next_long(&mut self) -> i64 {
+        (i64::from(self.next(32)) << 32).wrapping_add(i64::from(self.next(32)))
+    }
+
+    #[cfg_attr(not(feature = "image"), allow(dead_code))]
+    pub(crate) fn next_double(&mut self) -> f64 {
+        // The game multiplies by a float constant rather than by 2^-53.
+        const MULTIPLIER: f64 = 1.110223e-16_f32 as f64;
+
+        let high = i64::from(self.next(26)) << 27;
+        let low = i64::from(self.next(27));
+        (high + low) as f64 * MULTIPLIER
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn matches_java_util_random() {
+        let mut random = LegacyRandom::new(0);
+        assert_eq!(random.next(32), -1155484576);
+
+        let mut random = LegacyRandom::new(0);
+        let ints: Vec<_> = (0..5).map(|_| random.next_int(100)).collect();
+        assert_eq!(ints, [60, 48, 29, 47, 15]);
+
+        let mut random = LegacyRandom::new(42);
+        random.next_int(10);
+        random.next_int(10);
+        assert_eq!(random.next_long(), -5843495416241995736);
+
+        // Close to `java.util.Random`, up to the game's multiplier.
+        let mut random = LegacyRandom::new(0);
+        assert!((random.next_double() - 0.730967787376657).abs() < 1e-6);
+    }
+}
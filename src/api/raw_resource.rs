@@ -0,0 +1,159 @@
+use std::{fmt, io, marker::PhantomData};
+
+use serde::{
+    de::{DeserializeSeed, IgnoredAny, MapAccess, Visitor},
+    Deserialize, Deserializer,
+};
+use serde_json::value::RawValue;
+
+use crate::api::{parse_error::parse_json, ResourceIdentifier, ResourceParseError, Result};
+
+/// A JSON resource that has been loaded but not parsed yet.
+///
+/// Only the syntax of the JSON is checked when the resource is loaded, which
+/// does not build any values. The resource can then be parsed into a schema
+/// with [`parse()`][Self::parse], or only a few of its top-level fields can
+/// be read with [`field()`][Self::field], skipping over the others. This
+/// makes it cheap to inspect a few fields of thousands of resources.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::api::*;
+/// use minecraft_assets::schemas::Model;
+///
+/// let mut provider = MemoryResourceProvider::new();
+/// provider.insert(
+///     "assets/minecraft/models/block/stone.json",
+///     br#"{ "parent": "block/cube_all", "textures": { "all": "block/stone" } }"#.to_vec(),
+/// );
+/// provider.insert("assets/minecraft/models/block/cube_all.json", br#"{ "elements": [] }"#.to_vec());
+///
+/// let assets = AssetPack::new(provider);
+/// let models = assets.load_raw_resources(ResourceKind::BlockModel).unwrap();
+///
+/// // Only the parents are read.
+/// let children: Vec<&str> = models
+///     .iter()
+///     .filter(|model| model.field::<&str>("parent").unwrap() == Some("block/cube_all"))
+///     .map(|model| model.id().path())
+///     .collect();
+/// assert_eq!(children, ["stone"]);
+///
+/// // The whole model is parsed on demand.
+/// let stone: Model = models[1].parse().unwrap();
+/// assert_eq!(stone.textures.unwrap()["all"].location(), Some("block/stone"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RawResource {
+    id: ResourceIdentifier<'static>,
+    json: Box<RawValue>,
+}
+
+impl RawResource {
+    /// Returns the raw resource with the given ID and contents.
+    ///
+    /// Returns an error if the contents are not valid UTF-8 or not valid JSON.
+    pub fn new(id: ResourceIdentifier<'static>, bytes: Vec<u8>) -> Result<Self> {
+        let text =
+            String::from_utf8(bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let json = RawValue::from_string(text)
+            .map_err(|e| ResourceParseError::for_resource(&id, None, (String::new(), e)))?;
+        Ok(Self { id, json })
+    }
+
+    /// Returns the ID of the resource.
+    pub fn id(&self) -> &ResourceIdentifier<'static> {
+        &self.id
+    }
+
+    /// Returns the JSON text of the resource.
+    pub fn json(&self) -> &str {
+        self.json.get()
+    }
+
+    /// Returns the JSON of the resource as a [`RawValue`], e.g., to embed it
+    /// into another value without parsing it.
+    pub fn raw_value(&self) -> &RawValue {
+        &self.json
+    }
+
+    /// Parses the resource into the given type, which may borrow strings
+    /// from the resource.
+    pub fn parse<'a, T>(&'a self) -> Result<T>
+    where
+        T: Deserialize<'a>,
+    {
+        parse_json(self.json.get().as_bytes())
+            .map_err(|e| ResourceParseError::for_resource(&self.id, None, e).into())
+    }
+
+    /// Parses only the top-level field with the given name into the given
+    /// type, or returns `None` if the resource has no such field.
+    ///
+    /// The other fields are skipped without building their values. Returns an
+    /// error if the resource is not a JSON object.
+    pub fn field<'a, T>(&'a self, name: &str) -> Result<Option<T>>
+    where
+        T: Deserialize<'a>,
+    {
+        let mut deserializer = serde_json::Deserializer::from_str(self.json.get());
+        let value = deserializer
+            .deserialize_map(FieldVisitor {
+                name,
+                marker: PhantomData,
+            })
+            .map_err(|e| ResourceParseError::for_resource(&self.id, None, (String::new(), e)))?;
+        Ok(value)
+    }
+}
+
+/// Visits a JSON object and parses the value of one of its fields.
+struct FieldVisitor<'n, T> {
+    name: &'n str,
+    marker: PhantomData<T>,
+}
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for FieldVisitor<'_, T> {
+    type Value = Option<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a JSON object")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut value = None;
+        while let Some(matches) = map.next_key_seed(KeyMatches(self.name))? {
+            if matches && value.is_none() {
+                value = Some(map.next_value()?);
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+        Ok(value)
+    }
+}
+
+/// Deserializes a key into whether it is equal to the given name, without
+/// allocating it.
+struct KeyMatches<'n>(&'n str);
+
+impl<'de> DeserializeSeed<'de> for KeyMatches<'_> {
+    type Value = bool;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<bool, D::Error> {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<'de> Visitor<'de> for KeyMatches<'_> {
+    type Value = bool;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a field name")
+    }
+
+    fn visit_str<E>(self, key: &str) -> Result<bool, E> {
+        Ok(key == self.0)
+    }
+}
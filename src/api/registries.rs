@@ -0,0 +1,294 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::Path,
+};
+
+use crate::{
+    api::{ResourceKind, ResourceProvider, Result, TagResolver},
+    schemas::{BlocksReport, RegistriesReport},
+};
+
+use super::{provider::is_missing, tags::canonical};
+
+/// The IDs registered by a version of the game, as listed by the reports of
+/// its data generator.
+///
+/// The [`registries.json`] report lists the built-in registries, like blocks,
+/// items, and sound events, and the [`blocks.json`] report lists the state
+/// properties of every block. Registries that are defined by data packs, like
+/// biomes, can be added from the vanilla data pack (or the data generator's
+/// `generated/data/` output) with [`add_pack()`][Self::add_pack].
+///
+/// Registry names and IDs without a namespace are in the `minecraft`
+/// namespace.
+///
+/// The [`Validator`] checks the IDs used by a pack against these registries
+/// when given them with [`Validator::with_registries()`].
+///
+/// [`registries.json`]: crate::schemas::RegistriesReport
+/// [`blocks.json`]: crate::schemas::BlocksReport
+/// [`Validator`]: crate::api::validate::Validator
+/// [`Validator::with_registries()`]: crate::api::validate::Validator::with_registries
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::api::*;
+/// use minecraft_assets::schemas::RegistriesReport;
+///
+/// let report: RegistriesReport = serde_json::from_str(r#"
+///     {
+///         "minecraft:item": {
+///             "default": "minecraft:air",
+///             "protocol_id": 6,
+///             "entries": {
+///                 "minecraft:air": { "protocol_id": 0 },
+///                 "minecraft:stick": { "protocol_id": 1 }
+///             }
+///         }
+///     }
+/// "#).unwrap();
+///
+/// let mut registries = Registries::from_reports(&report, None);
+/// assert!(registries.contains("item", "stick"));
+/// assert!(!registries.contains("minecraft:item", "minecraft:ruby"));
+///
+/// let mut pack = MemoryResourceProvider::new();
+/// pack.insert("data/minecraft/worldgen/biome/plains.json", b"{}".to_vec());
+/// registries.add_pack(&pack).unwrap();
+/// assert!(registries.contains("worldgen/biome", "minecraft:plains"));
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Registries {
+    registries: HashMap<String, HashSet<String>>,
+    block_properties: HashMap<String, HashMap<String, Vec<String>>>,
+}
+
+impl Registries {
+    /// Returns new [`Registries`] without any registries.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the registries listed by the given reports.
+    pub fn from_reports(registries: &RegistriesReport, blocks: Option<&BlocksReport>) -> Self {
+        let mut this = Self::new();
+
+        for (name, registry) in &registries.registries {
+            this.add_entries(name, registry.entries.keys());
+        }
+
+        for (block, report) in blocks.into_iter().flat_map(|blocks| &blocks.blocks) {
+            this.block_properties
+                .insert(canonical(block), report.properties.clone());
+        }
+
+        this
+    }
+
+    /// Loads the `registries.json` and `blocks.json` reports from the given
+    /// directory, e.g. `generated/reports/`.
+    ///
+    /// `blocks.json` is optional.
+    pub fn load(reports_dir: impl AsRef<Path>) -> Result<Self> {
+        let reports_dir = reports_dir.as_ref();
+
+        let bytes = fs::read(reports_dir.join("registries.json"))?;
+        let registries: RegistriesReport = serde_json::from_slice(&bytes)?;
+
+        let blocks: Option<BlocksReport> = match fs::read(reports_dir.join("blocks.json")) {
+            Ok(bytes) => Some(serde_json::from_slice(&bytes)?),
+            Err(e) if is_missing(&e) => None,
+            Err(e) => return Err(e.into()),
+        };
+
+        Ok(Self::from_reports(&registries, blocks.as_ref()))
+    }
+
+    /// Adds the given IDs to the registry with the given name (e.g.,
+    /// `minecraft:worldgen/biome`), creating it if needed.
+    pub fn add_entries<I, S>(&mut self, registry: &str, ids: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.registries
+            .entry(canonical(registry))
+            .or_default()
+            .extend(ids.into_iter().map(|id| canonical(id.as_ref())));
+    }
+
+    /// Adds the IDs of the data-driven resources in the given pack (e.g., its
+    /// biomes, damage types, and enchantments) to their registries.
+    ///
+    /// An [`AssetPack`] can be passed with `&*assets`.
+    ///
+    /// [`AssetPack`]: crate::api::AssetPack
+    pub fn add_pack(&mut self, pack: &dyn ResourceProvider) -> Result<()> {
+        for &(kind, registry) in DATA_DRIVEN_REGISTRIES {
+            let ids = match pack.list(kind) {
+                Ok(ids) => ids,
+                Err(e) if is_missing(&e) => continue,
+                Err(e) => return Err(e.into()),
+            };
+
+            let ids = ids
+                .iter()
+                .map(|id| format!("{}:{}", id.namespace(), id.path()));
+            self.add_entries(registry, ids);
+        }
+
+        Ok(())
+    }
+
+    /// Returns `true` if the registry with the given name is known.
+    pub fn has_registry(&self, registry: &str) -> bool {
+        self.registries.contains_key(&canonical(registry))
+    }
+
+    /// Returns `true` if the given ID is in the registry with the given name.
+    ///
+    /// Returns `false` if the registry is not known.
+    pub fn contains(&self, registry: &str, id: &str) -> bool {
+        self.registries
+            .get(&canonical(registry))
+            .is_some_and(|ids| ids.contains(&canonical(id)))
+    }
+
+    /// Returns the namespaced IDs in the registry with the given name, in no
+    /// particular order, or `None` if the registry is not known.
+    pub fn entries(&self, registry: &str) -> Option<impl Iterator<Item = &str>> {
+        self.registries
+            .get(&canonical(registry))
+            .map(|ids| ids.iter().map(String::as_str))
+    }
+
+    /// Returns the state properties of the given block and their possible
+    /// values, or `None` if the block is not in the `blocks.json` report.
+    pub fn block_properties(&self, block: &str) -> Option<&HashMap<String, Vec<String>>> {
+        self.block_properties.get(&canonical(block))
+    }
+
+    /// Returns a [`TagResolver`] for tags of the given kind that checks their
+    /// IDs against the corresponding registry, if it is known.
+    pub fn tag_resolver(&self, kind: ResourceKind) -> TagResolver {
+        let resolver = TagResolver::new(kind);
+        match tag_registry(kind).and_then(|(registry, _)| self.entries(registry)) {
+            Some(ids) => resolver.with_registry(ids),
+            None => resolver,
+        }
+    }
+}
+
+/// The kinds of data pack resources that define the entries of a registry.
+const DATA_DRIVEN_REGISTRIES: &[(ResourceKind, &str)] = &[
+    (ResourceKind::WorldGen_Biome, "minecraft:worldgen/biome"),
+    (
+        ResourceKind::WorldGen_Structure,
+        "minecraft:worldgen/structure",
+    ),
+    (
+        ResourceKind::WorldGen_WorldPreset,
+        "minecraft:worldgen/world_preset",
+    ),
+    (
+        ResourceKind::WorldGen_FlatLevelGeneratorPreset,
+        "minecraft:worldgen/flat_level_generator_preset",
+    ),
+    (ResourceKind::DamageType, "minecraft:damage_type"),
+    (ResourceKind::Enchantment, "minecraft:enchantment"),
+    (ResourceKind::BannerPattern, "minecraft:banner_pattern"),
+    (ResourceKind::PaintingVariant, "minecraft:painting_variant"),
+];
+
+/// Returns the name of the registry whose IDs are grouped by tags of the given
+/// kind, along with the kind of resource that defines them if the registry is
+/// data-driven.
+pub(crate) fn tag_registry(kind: ResourceKind) -> Option<(&'static str, Option<ResourceKind>)> {
+    let element = match kind {
+        ResourceKind::BlockTag => return Some(("minecraft:block", None)),
+        ResourceKind::ItemTag => return Some(("minecraft:item", None)),
+        ResourceKind::EntityTypeTag => return Some(("minecraft:entity_type", None)),
+        ResourceKind::FluidTag => return Some(("minecraft:fluid", None)),
+        ResourceKind::GameEventTag => return Some(("minecraft:game_event", None)),
+        ResourceKind::WorldGen_BiomeTag => ResourceKind::WorldGen_Biome,
+        ResourceKind::WorldGen_StructureTag => ResourceKind::WorldGen_Structure,
+        ResourceKind::WorldGen_WorldPresetTag => ResourceKind::WorldGen_WorldPreset,
+        ResourceKind::WorldGen_FlatLevelGeneratorPresetTag => {
+            ResourceKind::WorldGen_FlatLevelGeneratorPreset
+        }
+        ResourceKind::DamageTypeTag => ResourceKind::DamageType,
+        ResourceKind::EnchantmentTag => ResourceKind::Enchantment,
+        ResourceKind::BannerPatternTag => ResourceKind::BannerPattern,
+        ResourceKind::PaintingVariantTag => ResourceKind::PaintingVariant,
+        _ => return None,
+    };
+
+    DATA_DRIVEN_REGISTRIES
+        .iter()
+        .find(|(kind, _)| *kind == element)
+        .map(|&(kind, registry)| (registry, Some(kind)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::api::{
+        validate::{Problem, Validator},
+        MemoryResourceProvider,
+    };
+
+    #[test]
+    fn blockstates_are_checked_against_blocks_report() {
+        let blocks: BlocksReport = serde_json::from_str(
+            r#"{
+                "minecraft:lever": {
+                    "properties": { "face": ["floor", "wall"], "powered": ["true", "false"] },
+                    "states": []
+                }
+            }"#,
+        )
+        .unwrap();
+        let mut registries = Registries::from_reports(&RegistriesReport::default(), Some(&blocks));
+        registries.add_entries("block", ["lever"]);
+
+        let mut pack = MemoryResourceProvider::new();
+        pack.insert(
+            "assets/minecraft/blockstates/lever.json",
+            br#"{ "multipart": [
+                { "when": { "OR": [{ "face": "wall|side" }, { "powered": true }] }, "apply": { "model": "block/lever" } }
+            ] }"#
+                .to_vec(),
+        );
+        pack.insert(
+            "assets/minecraft/blockstates/ruby_block.json",
+            br#"{ "variants": { "": { "model": "block/ruby_block" } } }"#.to_vec(),
+        );
+        pack.insert("assets/minecraft/models/block/lever.json", b"{}".to_vec());
+        pack.insert(
+            "assets/minecraft/models/block/ruby_block.json",
+            b"{}".to_vec(),
+        );
+
+        let diagnostics = Validator::new(&pack)
+            .with_registries(&registries)
+            .validate()
+            .unwrap();
+
+        let problems: Vec<_> = diagnostics
+            .iter()
+            .map(|d| (d.json_path.as_str(), &d.problem))
+            .collect();
+        assert_eq!(
+            problems,
+            [
+                (
+                    "/multipart/0/when/OR/0/face",
+                    &Problem::UnknownBlockState("face=side".into())
+                ),
+                ("", &Problem::UnknownBlock("minecraft:ruby_block".into())),
+            ]
+        );
+    }
+}
@@ -1,10 +1,140 @@
-use crate::schemas::models::{Display, Element, GuiLightMode, Model, Texture, Textures};
+use std::{collections::HashSet, io};
+
+use crate::{
+    api::{Error, ModelIdentifier, ResourceIdentifier, ResourceKind, ResourceLocation, Result},
+    schemas::models::{Display, Element, GuiLightMode, Model, Texture, Textures},
+};
 
 /// Methods for resolving the properties of a [`Model`] with respect to its
 /// parents.
 pub struct ModelResolver;
 
 impl ModelResolver {
+    /// Loads the [`Model`] referenced by the given [`ResourceIdentifier`] and
+    /// then follows its chain of parents, loading each of them too.
+    ///
+    /// The models are returned as a list, with the first element being the
+    /// model that was originally requested, the next element being its parent,
+    /// and so on with the last element being the topmost parent. Built-in
+    /// parents like `builtin/generated` are not loaded.
+    ///
+    /// The given function is used to load each model. Parents that start with
+    /// `block/` or `item/` are requested as [`ResourceKind::BlockModel`] or
+    /// [`ResourceKind::ItemModel`] resources, respectively, and any other
+    /// parent as the same kind as its child.
+    ///
+    /// Returns [`Error::ModelParentCycle`] if a model is its own ancestor, and
+    /// [`Error::MissingModelParent`] if a parent cannot be found.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// use maplit::hashmap;
+    /// use minecraft_assets::schemas::models::Model;
+    ///
+    /// let models = hashmap! {
+    ///     "diamond_sword" => r#"{ "parent": "item/handheld" }"#,
+    ///     "handheld" => r#"{ "parent": "item/generated" }"#,
+    ///     "generated" => r#"{ "parent": "builtin/generated" }"#,
+    ///     "loop_a" => r#"{ "parent": "item/loop_b" }"#,
+    ///     "loop_b" => r#"{ "parent": "item/loop_a" }"#,
+    ///     "orphan" => r#"{ "parent": "item/nonexistent" }"#,
+    /// };
+    ///
+    /// let load = |id: &ResourceIdentifier| -> Result<Model> {
+    ///     match models.get(id.path()) {
+    ///         Some(json) => Ok(serde_json::from_str(json)?),
+    ///         None => Err(std::io::Error::from(std::io::ErrorKind::NotFound).into()),
+    ///     }
+    /// };
+    ///
+    /// let chain = ModelResolver::load_parents(&ResourceIdentifier::item_model("diamond_sword"), load).unwrap();
+    /// assert_eq!(chain.len(), 3);
+    ///
+    /// let result = ModelResolver::load_parents(&ResourceIdentifier::item_model("loop_a"), load);
+    /// assert!(matches!(result, Err(Error::ModelParentCycle { .. })));
+    ///
+    /// let result = ModelResolver::load_parents(&ResourceIdentifier::item_model("orphan"), load);
+    /// assert!(matches!(result, Err(Error::MissingModelParent { .. })));
+    /// ```
+    pub fn load_parents<F>(id: &ResourceIdentifier, mut load: F) -> Result<Vec<Model>>
+    where
+        F: FnMut(&ResourceIdentifier) -> Result<Model>,
+    {
+        let mut models = Vec::new();
+        let mut visited = HashSet::new();
+
+        let mut current = id.to_owned();
+        let mut child: Option<ResourceIdentifier<'static>> = None;
+
+        loop {
+            if !visited.insert(current.clone()) {
+                return Err(Error::ModelParentCycle {
+                    model: id.to_string(),
+                });
+            }
+
+            let model = match (load(&current), &child) {
+                (Ok(model), _) => model,
+                (Err(Error::IoError(e)), Some(child)) if e.kind() == io::ErrorKind::NotFound => {
+                    return Err(Error::MissingModelParent {
+                        model: child.to_string(),
+                        parent: current.to_string(),
+                    });
+                }
+                (Err(e), _) => return Err(e),
+            };
+
+            let parent = model.parent.clone();
+            models.push(model);
+
+            match parent {
+                Some(parent) if !ModelIdentifier::is_builtin(parent.as_str()) => {
+                    let parent = Self::model_id(&parent, current.kind());
+                    child = Some(std::mem::replace(&mut current, parent));
+                }
+                _ => break,
+            }
+        }
+
+        Ok(models)
+    }
+
+    /// Loads the [`Model`] referenced by the given [`ResourceIdentifier`] and
+    /// all of its parents, and then resolves it with
+    /// [`resolve_model()`][Self::resolve_model].
+    ///
+    /// See [`load_parents()`][Self::load_parents] for how the models are
+    /// loaded.
+    pub fn load_resolved<F>(id: &ResourceIdentifier, load: F) -> Result<Model>
+    where
+        F: FnMut(&ResourceIdentifier) -> Result<Model>,
+    {
+        let models = Self::load_parents(id, load)?;
+        Ok(Self::resolve_model(models.iter()))
+    }
+
+    /// Returns the identifier of the model referenced by the given location.
+    ///
+    /// References that start with `block/` or `item/` are block or item
+    /// models, respectively, and any other reference is of the given kind.
+    pub(crate) fn model_id(
+        reference: &ResourceLocation,
+        default: ResourceKind,
+    ) -> ResourceIdentifier<'static> {
+        let path = reference.path();
+        let kind = if path.starts_with("block/") {
+            ResourceKind::BlockModel
+        } else if path.starts_with("item/") {
+            ResourceKind::ItemModel
+        } else {
+            default
+        };
+
+        reference.with_kind(kind).to_owned()
+    }
+
     /// Iterates through a [`Model`] and all of its parents to resolve all of
     /// the model's properties in a way that reflects the intended inheritance
     /// and/or override behavior of the Minecraft model format.
@@ -107,7 +237,7 @@ impl ModelResolver {
         let display = Self::resolve_display(models.clone());
         let ambient_occlusion = Self::resolve_ambient_occlusion(models.clone());
         let gui_light_mode = Self::resolve_gui_light_mode(models.clone());
-        let overrides = models.into_iter().next().unwrap().overrides.clone();
+        let child = models.into_iter().next().unwrap();
 
         Model {
             parent: None,
@@ -116,7 +246,9 @@ impl ModelResolver {
             elements,
             ambient_occlusion,
             gui_light_mode,
-            overrides,
+            overrides: child.overrides.clone(),
+            #[cfg(feature = "preserve-unknown")]
+            extra: child.extra.clone(),
         }
     }
 
@@ -9,6 +9,9 @@ pub enum ResourceCategory {
 }
 
 impl ResourceCategory {
+    /// Every resource category.
+    pub const ALL: [Self; 2] = [Self::Assets, Self::Data];
+
     /// Returns the name of the top-level directory containing this category of
     /// resource.
     ///
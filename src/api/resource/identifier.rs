@@ -3,7 +3,9 @@ use std::{borrow::Cow, fmt, hash::Hash};
 #[allow(missing_docs)]
 pub const MINECRAFT_NAMESPACE: &str = "minecraft";
 
-use crate::api::{ModelIdentifier, ResourceKind};
+use crate::api::{
+    version::kind_directory, InvalidIdentifier, ModelIdentifier, ResourceKind, TargetVersion,
+};
 
 /// A namespaced, typed resource identifier.
 ///
@@ -91,6 +93,140 @@ impl<'a> ResourceIdentifier<'a> {
         }
     }
 
+    /// Like [`new()`], but checks that the id only contains characters that are
+    /// allowed by the game, and strips a leading `#` from tag ids.
+    ///
+    /// [`new()`]: Self::new
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::parse(ResourceKind::BlockTag, "#minecraft:logs").unwrap();
+    /// assert_eq!(id.as_str(), "minecraft:logs");
+    ///
+    /// let error = ResourceIdentifier::parse(ResourceKind::BlockModel, "block/Oak Stairs");
+    /// assert!(error.is_err());
+    /// ```
+    pub fn parse(kind: ResourceKind, id: &'a str) -> Result<Self, InvalidIdentifier> {
+        let location = crate::api::ResourceLocation::new(id)?;
+        Ok(location.with_kind(kind))
+    }
+
+    /// Checks that the id only contains characters that are allowed by the
+    /// game.
+    ///
+    /// Ids constructed with [`new()`][Self::new] are not validated, since the
+    /// game itself accepts some invalid ids in older packs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// assert!(ResourceIdentifier::texture("block/stone").validate().is_ok());
+    ///
+    /// let error = ResourceIdentifier::texture("block/Stone").validate().unwrap_err();
+    /// assert_eq!(error.to_string(), "invalid character 'S' at position 6 of resource location \"block/Stone\" (only a-z, 0-9, '_', '-', '.', and '/' in paths are allowed)");
+    /// ```
+    pub fn validate(&self) -> Result<(), InvalidIdentifier> {
+        super::location::validate(&self.id)
+    }
+
+    /// Returns an owned [`ResourceIdentifier`] for the given path in the given
+    /// namespace, leaving out the namespace if it is `minecraft`.
+    pub(crate) fn in_namespace(
+        kind: ResourceKind,
+        namespace: &str,
+        path: &str,
+    ) -> ResourceIdentifier<'static> {
+        if namespace == MINECRAFT_NAMESPACE {
+            Self::new_owned(kind, String::from(path))
+        } else {
+            Self::new_owned(kind, format!("{}:{}", namespace, path))
+        }
+    }
+
+    /// Returns the [`ResourceIdentifier`] of the resource stored at the given
+    /// path relative to the root of a pack, using `/` as the separator, or
+    /// `None` if no kind of resource is stored there.
+    ///
+    /// If several kinds of resources share a directory, the kind with the
+    /// most specific directory wins.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::from_pack_path("assets/minecraft/models/block/stone.json");
+    /// assert_eq!(id, Some(ResourceIdentifier::block_model("stone")));
+    ///
+    /// let id = ResourceIdentifier::from_pack_path("assets/foo/textures/block/bar.png.mcmeta");
+    /// assert_eq!(id, Some(ResourceIdentifier::texture_meta("foo:block/bar")));
+    ///
+    /// let id = ResourceIdentifier::from_pack_path("assets/minecraft/sounds.json");
+    /// assert_eq!(id, Some(ResourceIdentifier::sounds("minecraft")));
+    ///
+    /// assert_eq!(ResourceIdentifier::from_pack_path("pack.mcmeta"), None);
+    /// ```
+    pub fn from_pack_path(path: &str) -> Option<ResourceIdentifier<'static>> {
+        let (category, rest) = path.split_once('/')?;
+        let (namespace, rest) = rest.split_once('/')?;
+
+        ResourceKind::ALL
+            .iter()
+            .filter(|kind| kind.category().directory() == category)
+            .filter_map(|&kind| {
+                let path = if kind.directory().is_empty() {
+                    rest
+                } else {
+                    rest.strip_prefix(kind.directory())?.strip_prefix('/')?
+                };
+                let path = path.strip_suffix(kind.extension())?.strip_suffix('.')?;
+
+                let valid = match kind.single_file() {
+                    Some(name) => path == name,
+                    None => !kind.directory().is_empty() && !path.is_empty(),
+                };
+                valid.then_some((kind, path))
+            })
+            .max_by_key(|(kind, _)| kind.directory().len())
+            .map(|(kind, path)| Self::in_namespace(kind, namespace, path))
+    }
+
+    /// Returns the path of the resource relative to the root of a pack, using
+    /// `/` as the separator.
+    ///
+    /// This is the inverse of [`from_pack_path()`][Self::from_pack_path].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::block_model("minecraft:block/stone");
+    /// assert_eq!(id.pack_path(), "assets/minecraft/models/block/stone.json");
+    ///
+    /// let id = ResourceIdentifier::sounds("foo");
+    /// assert_eq!(id.pack_path(), "assets/foo/sounds.json");
+    /// ```
+    pub fn pack_path(&self) -> String {
+        self.pack_path_in(None)
+    }
+
+    /// Returns the path of the resource in a pack with the layout of the given
+    /// version, or the default layout without a target version.
+    pub(crate) fn pack_path_in(&self, version: Option<TargetVersion>) -> String {
+        let directory = kind_directory(self.kind, version);
+        let mut path = format!("{}/{}/", self.kind.category().directory(), self.namespace());
+        if !directory.is_empty() {
+            path.push_str(directory);
+            path.push('/');
+        }
+        path.push_str(self.path());
+        path.push('.');
+        path.push_str(self.kind.extension());
+        path
+    }
+
     /// Constructs a new [`ResourceIdentifier`] referencing the [`BlockStates`] of
     /// the given block id.
     ///
@@ -138,6 +274,927 @@ impl<'a> ResourceIdentifier<'a> {
         Self::new(ResourceKind::Texture, path)
     }
 
+    /// Constructs a new [`ResourceIdentifier`] referencing the [`TextureMeta`]
+    /// of the texture located at the given path.
+    ///
+    /// [`TextureMeta`]: ResourceKind::TextureMeta
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::texture_meta("block/water_still");
+    /// let id = ResourceIdentifier::texture_meta("gui/sprites/widget/button");
+    /// ```
+    pub fn texture_meta(path: &'a str) -> Self {
+        Self::new(ResourceKind::TextureMeta, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the [`LootTable`]
+    /// located at the given path.
+    ///
+    /// [`LootTable`]: ResourceKind::LootTable
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::loot_table("blocks/stone");
+    /// let id = ResourceIdentifier::loot_table("minecraft:chests/simple_dungeon");
+    /// ```
+    pub fn loot_table(path: &'a str) -> Self {
+        Self::new(ResourceKind::LootTable, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the [`Recipe`]
+    /// located at the given path.
+    ///
+    /// [`Recipe`]: ResourceKind::Recipe
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::recipe("stick");
+    /// let id = ResourceIdentifier::recipe("minecraft:iron_ingot_from_blasting_iron_ore");
+    /// ```
+    pub fn recipe(path: &'a str) -> Self {
+        Self::new(ResourceKind::Recipe, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the [`Advancement`]
+    /// located at the given path.
+    ///
+    /// [`Advancement`]: ResourceKind::Advancement
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::advancement("story/root");
+    /// let id = ResourceIdentifier::advancement("minecraft:nether/find_fortress");
+    /// ```
+    pub fn advancement(path: &'a str) -> Self {
+        Self::new(ResourceKind::Advancement, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the tag of the given
+    /// kind with the given id.
+    ///
+    /// A leading `#` in the id is ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::tag(ResourceKind::BlockTag, "minecraft:logs");
+    /// let id = ResourceIdentifier::tag(ResourceKind::ItemTag, "#minecraft:planks");
+    ///
+    /// assert_eq!(id.as_str(), "minecraft:planks");
+    /// ```
+    pub fn tag(kind: ResourceKind, tag_id: &'a str) -> Self {
+        debug_assert!(kind.is_tag(), "{:?} is not a tag kind", kind);
+        Self::new(kind, tag_id.strip_prefix('#').unwrap_or(tag_id))
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the [`Predicate`]
+    /// located at the given path.
+    ///
+    /// [`Predicate`]: ResourceKind::Predicate
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::predicate("is_sneaking");
+    /// let id = ResourceIdentifier::predicate("mypack:holding_stick");
+    /// ```
+    pub fn predicate(path: &'a str) -> Self {
+        Self::new(ResourceKind::Predicate, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the [`ItemModifier`]
+    /// located at the given path.
+    ///
+    /// [`ItemModifier`]: ResourceKind::ItemModifier
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::item_modifier("double_count");
+    /// let id = ResourceIdentifier::item_modifier("mypack:enchant_sword");
+    /// ```
+    pub fn item_modifier(path: &'a str) -> Self {
+        Self::new(ResourceKind::ItemModifier, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the [`DamageType`]
+    /// located at the given path.
+    ///
+    /// [`DamageType`]: ResourceKind::DamageType
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::damage_type("in_fire");
+    /// let id = ResourceIdentifier::damage_type("mypack:radiation");
+    /// ```
+    pub fn damage_type(path: &'a str) -> Self {
+        Self::new(ResourceKind::DamageType, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the [`ChatType`]
+    /// located at the given path.
+    ///
+    /// [`ChatType`]: ResourceKind::ChatType
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::chat_type("chat");
+    /// let id = ResourceIdentifier::chat_type("mypack:shout");
+    /// ```
+    pub fn chat_type(path: &'a str) -> Self {
+        Self::new(ResourceKind::ChatType, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the [`TrimMaterial`]
+    /// located at the given path.
+    ///
+    /// [`TrimMaterial`]: ResourceKind::TrimMaterial
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::trim_material("gold");
+    /// let id = ResourceIdentifier::trim_material("mypack:ruby");
+    /// ```
+    pub fn trim_material(path: &'a str) -> Self {
+        Self::new(ResourceKind::TrimMaterial, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the [`TrimPattern`]
+    /// located at the given path.
+    ///
+    /// [`TrimPattern`]: ResourceKind::TrimPattern
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::trim_pattern("coast");
+    /// let id = ResourceIdentifier::trim_pattern("mypack:stripes");
+    /// ```
+    pub fn trim_pattern(path: &'a str) -> Self {
+        Self::new(ResourceKind::TrimPattern, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the [`Enchantment`]
+    /// located at the given path.
+    ///
+    /// [`Enchantment`]: ResourceKind::Enchantment
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::enchantment("sharpness");
+    /// let id = ResourceIdentifier::enchantment("mypack:lifesteal");
+    /// ```
+    pub fn enchantment(path: &'a str) -> Self {
+        Self::new(ResourceKind::Enchantment, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the [`PaintingVariant`]
+    /// located at the given path.
+    ///
+    /// [`PaintingVariant`]: ResourceKind::PaintingVariant
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::painting_variant("kebab");
+    /// let id = ResourceIdentifier::painting_variant("mypack:sunset");
+    /// ```
+    pub fn painting_variant(path: &'a str) -> Self {
+        Self::new(ResourceKind::PaintingVariant, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the [`WolfVariant`]
+    /// located at the given path.
+    ///
+    /// [`WolfVariant`]: ResourceKind::WolfVariant
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::wolf_variant("ashen");
+    /// let id = ResourceIdentifier::wolf_variant("mypack:arctic");
+    /// ```
+    pub fn wolf_variant(path: &'a str) -> Self {
+        Self::new(ResourceKind::WolfVariant, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the [`BannerPattern`]
+    /// located at the given path.
+    ///
+    /// [`BannerPattern`]: ResourceKind::BannerPattern
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::banner_pattern("creeper");
+    /// let id = ResourceIdentifier::banner_pattern("mypack:dragon");
+    /// ```
+    pub fn banner_pattern(path: &'a str) -> Self {
+        Self::new(ResourceKind::BannerPattern, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the [`JukeboxSong`]
+    /// located at the given path.
+    ///
+    /// [`JukeboxSong`]: ResourceKind::JukeboxSong
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::jukebox_song("cat");
+    /// let id = ResourceIdentifier::jukebox_song("mypack:my_song");
+    /// ```
+    pub fn jukebox_song(path: &'a str) -> Self {
+        Self::new(ResourceKind::JukeboxSong, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the
+    /// [`WorldGen_PlacedFeature`] located at the given path.
+    ///
+    /// [`WorldGen_PlacedFeature`]: ResourceKind::WorldGen_PlacedFeature
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::placed_feature("ore_dirt");
+    /// let id = ResourceIdentifier::placed_feature("mypack:my_tree");
+    /// ```
+    pub fn placed_feature(path: &'a str) -> Self {
+        Self::new(ResourceKind::WorldGen_PlacedFeature, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the
+    /// [`WorldGen_ConfiguredCarver`] located at the given path.
+    ///
+    /// [`WorldGen_ConfiguredCarver`]: ResourceKind::WorldGen_ConfiguredCarver
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::configured_carver("cave");
+    /// let id = ResourceIdentifier::configured_carver("mypack:my_canyon");
+    /// ```
+    pub fn configured_carver(path: &'a str) -> Self {
+        Self::new(ResourceKind::WorldGen_ConfiguredCarver, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the
+    /// [`WorldGen_ConfiguredFeature`] located at the given path.
+    ///
+    /// [`WorldGen_ConfiguredFeature`]: ResourceKind::WorldGen_ConfiguredFeature
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::configured_feature("ore_diamond");
+    /// let id = ResourceIdentifier::configured_feature("mypack:my_tree");
+    /// ```
+    pub fn configured_feature(path: &'a str) -> Self {
+        Self::new(ResourceKind::WorldGen_ConfiguredFeature, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the
+    /// [`WorldGen_DensityFunction`] located at the given path.
+    ///
+    /// [`WorldGen_DensityFunction`]: ResourceKind::WorldGen_DensityFunction
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::density_function("overworld/continents");
+    /// let id = ResourceIdentifier::density_function("mypack:erosion");
+    /// ```
+    pub fn density_function(path: &'a str) -> Self {
+        Self::new(ResourceKind::WorldGen_DensityFunction, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the
+    /// [`WorldGen_Noise`] located at the given path.
+    ///
+    /// [`WorldGen_Noise`]: ResourceKind::WorldGen_Noise
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::noise("continentalness");
+    /// let id = ResourceIdentifier::noise("mypack:ridges");
+    /// ```
+    pub fn noise(path: &'a str) -> Self {
+        Self::new(ResourceKind::WorldGen_Noise, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the
+    /// [`WorldGen_NoiseSettings`] located at the given path.
+    ///
+    /// [`WorldGen_NoiseSettings`]: ResourceKind::WorldGen_NoiseSettings
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::noise_settings("overworld");
+    /// let id = ResourceIdentifier::noise_settings("mypack:floating_islands");
+    /// ```
+    pub fn noise_settings(path: &'a str) -> Self {
+        Self::new(ResourceKind::WorldGen_NoiseSettings, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the
+    /// [`WorldGen_Structure`] located at the given path.
+    ///
+    /// [`WorldGen_Structure`]: ResourceKind::WorldGen_Structure
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::worldgen_structure("village_plains");
+    /// let id = ResourceIdentifier::worldgen_structure("mypack:tower");
+    /// ```
+    pub fn worldgen_structure(path: &'a str) -> Self {
+        Self::new(ResourceKind::WorldGen_Structure, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the
+    /// [`WorldGen_StructureSet`] located at the given path.
+    ///
+    /// [`WorldGen_StructureSet`]: ResourceKind::WorldGen_StructureSet
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::structure_set("villages");
+    /// let id = ResourceIdentifier::structure_set("mypack:towers");
+    /// ```
+    pub fn structure_set(path: &'a str) -> Self {
+        Self::new(ResourceKind::WorldGen_StructureSet, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the
+    /// [`WorldGen_TemplatePool`] located at the given path.
+    ///
+    /// [`WorldGen_TemplatePool`]: ResourceKind::WorldGen_TemplatePool
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::template_pool("village/plains/houses");
+    /// let id = ResourceIdentifier::template_pool("mypack:tower/tops");
+    /// ```
+    pub fn template_pool(path: &'a str) -> Self {
+        Self::new(ResourceKind::WorldGen_TemplatePool, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the
+    /// [`WorldGen_ProcessorList`] located at the given path.
+    ///
+    /// [`WorldGen_ProcessorList`]: ResourceKind::WorldGen_ProcessorList
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::processor_list("mossify_10_percent");
+    /// let id = ResourceIdentifier::processor_list("mypack:weathering");
+    /// ```
+    pub fn processor_list(path: &'a str) -> Self {
+        Self::new(ResourceKind::WorldGen_ProcessorList, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the
+    /// [`WorldGen_WorldPreset`] located at the given path.
+    ///
+    /// [`WorldGen_WorldPreset`]: ResourceKind::WorldGen_WorldPreset
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::world_preset("amplified");
+    /// let id = ResourceIdentifier::world_preset("mypack:islands");
+    /// ```
+    pub fn world_preset(path: &'a str) -> Self {
+        Self::new(ResourceKind::WorldGen_WorldPreset, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the
+    /// [`WorldGen_FlatLevelGeneratorPreset`] located at the given path.
+    ///
+    /// [`WorldGen_FlatLevelGeneratorPreset`]: ResourceKind::WorldGen_FlatLevelGeneratorPreset
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::flat_level_generator_preset("classic_flat");
+    /// let id = ResourceIdentifier::flat_level_generator_preset("mypack:glass_world");
+    /// ```
+    pub fn flat_level_generator_preset(path: &'a str) -> Self {
+        Self::new(ResourceKind::WorldGen_FlatLevelGeneratorPreset, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the
+    /// [`Dimension`] located at the given path.
+    ///
+    /// [`Dimension`]: ResourceKind::Dimension
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::dimension("overworld");
+    /// let id = ResourceIdentifier::dimension("mypack:void");
+    /// ```
+    pub fn dimension(path: &'a str) -> Self {
+        Self::new(ResourceKind::Dimension, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the
+    /// [`DimensionType`] located at the given path.
+    ///
+    /// [`DimensionType`]: ResourceKind::DimensionType
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::dimension_type("the_nether");
+    /// let id = ResourceIdentifier::dimension_type("mypack:void");
+    /// ```
+    pub fn dimension_type(path: &'a str) -> Self {
+        Self::new(ResourceKind::DimensionType, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the [`Structure`]
+    /// located at the given path.
+    ///
+    /// [`Structure`]: ResourceKind::Structure
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::structure("igloo/top");
+    /// let id = ResourceIdentifier::structure("mypack:house");
+    /// ```
+    pub fn structure(path: &'a str) -> Self {
+        Self::new(ResourceKind::Structure, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the [`Function`]
+    /// located at the given path.
+    ///
+    /// [`Function`]: ResourceKind::Function
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::function("tick");
+    /// let id = ResourceIdentifier::function("mypack:setup/init");
+    /// ```
+    pub fn function(path: &'a str) -> Self {
+        Self::new(ResourceKind::Function, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the plain [`Text`]
+    /// file (e.g., `splashes`) located at the given path.
+    ///
+    /// [`Text`]: ResourceKind::Text
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::text("splashes");
+    /// let id = ResourceIdentifier::text("minecraft:end");
+    /// ```
+    pub fn text(path: &'a str) -> Self {
+        Self::new(ResourceKind::Text, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the JSON
+    /// [`TextJson`] file (e.g., `credits`) located at the given path.
+    ///
+    /// [`TextJson`]: ResourceKind::TextJson
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::text_json("credits");
+    /// let id = ResourceIdentifier::text_json("minecraft:credits");
+    /// ```
+    pub fn text_json(path: &'a str) -> Self {
+        Self::new(ResourceKind::TextJson, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the [`Sounds`] file
+    /// of the given namespace.
+    ///
+    /// [`Sounds`]: ResourceKind::Sounds
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::sounds("minecraft");
+    /// assert_eq!(id.as_str(), "minecraft:sounds");
+    /// ```
+    pub fn sounds(namespace: &str) -> ResourceIdentifier<'static> {
+        ResourceIdentifier::new_owned(ResourceKind::Sounds, format!("{}:sounds", namespace))
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the [`Sound`] file
+    /// located at the given path.
+    ///
+    /// [`Sound`]: ResourceKind::Sound
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::sound("ambient/cave/cave1");
+    /// let id = ResourceIdentifier::sound("mypack:music/theme");
+    /// ```
+    pub fn sound(path: &'a str) -> Self {
+        Self::new(ResourceKind::Sound, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the
+    /// [`GpuWarnlist`] file of the `minecraft` namespace.
+    ///
+    /// [`GpuWarnlist`]: ResourceKind::GpuWarnlist
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::gpu_warnlist();
+    /// assert_eq!(id.as_str(), "minecraft:gpu_warnlist");
+    /// ```
+    pub fn gpu_warnlist() -> ResourceIdentifier<'static> {
+        ResourceIdentifier::new(ResourceKind::GpuWarnlist, "minecraft:gpu_warnlist")
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the
+    /// [`RegionalCompliancies`] file of the `minecraft` namespace.
+    ///
+    /// [`RegionalCompliancies`]: ResourceKind::RegionalCompliancies
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::regional_compliancies();
+    /// assert_eq!(id.as_str(), "minecraft:regional_compliancies");
+    /// ```
+    pub fn regional_compliancies() -> ResourceIdentifier<'static> {
+        ResourceIdentifier::new(
+            ResourceKind::RegionalCompliancies,
+            "minecraft:regional_compliancies",
+        )
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the [`Language`]
+    /// located at the given path.
+    ///
+    /// [`Language`]: ResourceKind::Language
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::language("en_us");
+    /// let id = ResourceIdentifier::language("mypack:de_de");
+    /// ```
+    pub fn language(code: &'a str) -> Self {
+        Self::new(ResourceKind::Language, code)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the
+    /// [`LegacyLanguage`] file located at the given path.
+    ///
+    /// [`LegacyLanguage`]: ResourceKind::LegacyLanguage
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::legacy_language("en_US");
+    /// assert_eq!(id.pack_path(), "assets/minecraft/lang/en_US.lang");
+    /// ```
+    pub fn legacy_language(code: &'a str) -> Self {
+        Self::new(ResourceKind::LegacyLanguage, code)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the [`Font`]
+    /// located at the given path.
+    ///
+    /// [`Font`]: ResourceKind::Font
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::font("default");
+    /// let id = ResourceIdentifier::font("mypack:runes");
+    /// ```
+    pub fn font(path: &'a str) -> Self {
+        Self::new(ResourceKind::Font, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the [`Particle`]
+    /// located at the given path.
+    ///
+    /// [`Particle`]: ResourceKind::Particle
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::particle("flame");
+    /// let id = ResourceIdentifier::particle("mypack:sparkle");
+    /// ```
+    pub fn particle(path: &'a str) -> Self {
+        Self::new(ResourceKind::Particle, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the [`Atlas`]
+    /// located at the given path.
+    ///
+    /// [`Atlas`]: ResourceKind::Atlas
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::atlas("blocks");
+    /// let id = ResourceIdentifier::atlas("mypack:gui");
+    /// ```
+    pub fn atlas(path: &'a str) -> Self {
+        Self::new(ResourceKind::Atlas, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the [`CoreShader`]
+    /// located at the given path.
+    ///
+    /// [`CoreShader`]: ResourceKind::CoreShader
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::core_shader("position_tex");
+    /// let id = ResourceIdentifier::core_shader("mypack:rendertype_glow");
+    /// ```
+    pub fn core_shader(path: &'a str) -> Self {
+        Self::new(ResourceKind::CoreShader, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the [`ProgramShader`]
+    /// located at the given path.
+    ///
+    /// [`ProgramShader`]: ResourceKind::ProgramShader
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::program_shader("blur");
+    /// let id = ResourceIdentifier::program_shader("mypack:outline");
+    /// ```
+    pub fn program_shader(path: &'a str) -> Self {
+        Self::new(ResourceKind::ProgramShader, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the [`PostChain`]
+    /// located at the given path.
+    ///
+    /// [`PostChain`]: ResourceKind::PostChain
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::post_chain("spider");
+    /// let id = ResourceIdentifier::post_chain("mypack:wobble");
+    /// ```
+    pub fn post_chain(path: &'a str) -> Self {
+        Self::new(ResourceKind::PostChain, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the [`PostEffect`]
+    /// located at the given path.
+    ///
+    /// [`PostEffect`]: ResourceKind::PostEffect
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::post_effect("spider");
+    /// let id = ResourceIdentifier::post_effect("mypack:wobble");
+    /// ```
+    pub fn post_effect(path: &'a str) -> Self {
+        Self::new(ResourceKind::PostEffect, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the
+    /// [`VertexShader`] source located at the given path, relative to `shaders/`.
+    ///
+    /// [`VertexShader`]: ResourceKind::VertexShader
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::vertex_shader("core/position_tex");
+    /// let id = ResourceIdentifier::vertex_shader("mypack:core/rendertype_glow");
+    /// ```
+    pub fn vertex_shader(path: &'a str) -> Self {
+        Self::new(ResourceKind::VertexShader, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the
+    /// [`FragmentShader`] source located at the given path, relative to `shaders/`.
+    ///
+    /// [`FragmentShader`]: ResourceKind::FragmentShader
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::fragment_shader("core/position_tex");
+    /// let id = ResourceIdentifier::fragment_shader("mypack:post/wobble");
+    /// ```
+    pub fn fragment_shader(path: &'a str) -> Self {
+        Self::new(ResourceKind::FragmentShader, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the
+    /// [`ShaderInclude`] source located at the given path, relative to `shaders/`.
+    ///
+    /// [`ShaderInclude`]: ResourceKind::ShaderInclude
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::shader_include("include/fog");
+    /// let id = ResourceIdentifier::shader_include("mypack:include/noise");
+    /// ```
+    pub fn shader_include(path: &'a str) -> Self {
+        Self::new(ResourceKind::ShaderInclude, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the [`Equipment`]
+    /// located at the given path.
+    ///
+    /// [`Equipment`]: ResourceKind::Equipment
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::equipment("diamond");
+    /// let id = ResourceIdentifier::equipment("mypack:ruby");
+    /// ```
+    pub fn equipment(path: &'a str) -> Self {
+        Self::new(ResourceKind::Equipment, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the [`ItemDefinition`]
+    /// located at the given path.
+    ///
+    /// [`ItemDefinition`]: ResourceKind::ItemDefinition
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::item_definition("compass");
+    /// let id = ResourceIdentifier::item_definition("minecraft:bow");
+    /// ```
+    pub fn item_definition(item_id: &'a str) -> Self {
+        Self::new(ResourceKind::ItemDefinition, item_id)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the [`GlyphSizes`]
+    /// located at the given path, relative to `font/`.
+    ///
+    /// [`GlyphSizes`]: ResourceKind::GlyphSizes
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::glyph_sizes("glyph_sizes");
+    /// let id = ResourceIdentifier::glyph_sizes("mypack:sizes");
+    /// ```
+    pub fn glyph_sizes(path: &'a str) -> Self {
+        Self::new(ResourceKind::GlyphSizes, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the
+    /// [`UnihexArchive`] located at the given path, relative to `font/`.
+    ///
+    /// [`UnihexArchive`]: ResourceKind::UnihexArchive
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::unihex_archive("unifont");
+    /// let id = ResourceIdentifier::unihex_archive("mypack:glyphs");
+    /// ```
+    pub fn unihex_archive(path: &'a str) -> Self {
+        Self::new(ResourceKind::UnihexArchive, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the
+    /// [`CtmProperties`] located at the given path, relative to
+    /// `optifine/ctm/`.
+    ///
+    /// [`CtmProperties`]: ResourceKind::CtmProperties
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::ctm_properties("glass/glass");
+    /// assert_eq!(id.pack_path(), "assets/minecraft/optifine/ctm/glass/glass.properties");
+    /// ```
+    pub fn ctm_properties(path: &'a str) -> Self {
+        Self::new(ResourceKind::CtmProperties, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the
+    /// [`CitProperties`] located at the given path, relative to
+    /// `optifine/cit/`.
+    ///
+    /// [`CitProperties`]: ResourceKind::CitProperties
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::cit_properties("swords/fire_sword");
+    /// assert_eq!(id.pack_path(), "assets/minecraft/optifine/cit/swords/fire_sword.properties");
+    /// ```
+    pub fn cit_properties(path: &'a str) -> Self {
+        Self::new(ResourceKind::CitProperties, path)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the
+    /// [`OptifineTexture`] located at the given path, relative to
+    /// `optifine/`.
+    ///
+    /// [`OptifineTexture`]: ResourceKind::OptifineTexture
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::optifine_texture("ctm/glass/0");
+    /// assert_eq!(id.pack_path(), "assets/minecraft/optifine/ctm/glass/0.png");
+    /// ```
+    pub fn optifine_texture(path: &'a str) -> Self {
+        Self::new(ResourceKind::OptifineTexture, path)
+    }
+
     /// Returns the underlying identifier as a string slice.
     ///
     /// # Example
@@ -366,7 +1423,7 @@ impl<'a> ResourceIdentifier<'a> {
     }
 
     fn colon_position(&self) -> Option<usize> {
-        self.id.chars().position(|c| c == ':')
+        self.id.find(':')
     }
 }
 
@@ -2,6 +2,7 @@ use crate::api::ResourceCategory;
 
 /// The type of a resource.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
 pub enum ResourceKind {
     /// Resources (`.json`) in `assets/<namespace>/blockstates/`.
     BlockStates,
@@ -20,9 +21,385 @@ pub enum ResourceKind {
 
     /// Resources (`.json`) in `data/<namespace>/worldgen/biome/`.
     WorldGen_Biome,
+
+    /// Resources (`.json`) in
+    /// `data/<namespace>/worldgen/multi_noise_biome_source_parameter_list/`.
+    WorldGen_MultiNoiseBiomeSourceParameterList,
+
+    /// Resources (`.json`) in `data/<namespace>/worldgen/placed_feature/`.
+    WorldGen_PlacedFeature,
+
+    /// Resources (`.json`) in `data/<namespace>/worldgen/configured_carver/`.
+    WorldGen_ConfiguredCarver,
+
+    /// Resources (`.json`) in `data/<namespace>/worldgen/configured_feature/`.
+    WorldGen_ConfiguredFeature,
+
+    /// Resources (`.json`) in `data/<namespace>/worldgen/density_function/`.
+    WorldGen_DensityFunction,
+
+    /// Resources (`.json`) in `data/<namespace>/worldgen/noise/`.
+    WorldGen_Noise,
+
+    /// Resources (`.json`) in `data/<namespace>/worldgen/noise_settings/`.
+    WorldGen_NoiseSettings,
+
+    /// Resources (`.json`) in `data/<namespace>/worldgen/structure/`.
+    ///
+    /// These configure how structures generate, and are not to be confused
+    /// with the [`Structure`][Self::Structure] templates they place.
+    WorldGen_Structure,
+
+    /// Resources (`.json`) in `data/<namespace>/worldgen/structure_set/`.
+    WorldGen_StructureSet,
+
+    /// Resources (`.json`) in `data/<namespace>/worldgen/template_pool/`.
+    WorldGen_TemplatePool,
+
+    /// Resources (`.json`) in `data/<namespace>/worldgen/processor_list/`.
+    WorldGen_ProcessorList,
+
+    /// Resources (`.json`) in `data/<namespace>/worldgen/world_preset/`.
+    WorldGen_WorldPreset,
+
+    /// Resources (`.json`) in `data/<namespace>/worldgen/flat_level_generator_preset/`.
+    WorldGen_FlatLevelGeneratorPreset,
+
+    /// Resources (`.json`) in `data/<namespace>/dimension/`.
+    Dimension,
+
+    /// Resources (`.json`) in `data/<namespace>/dimension_type/`.
+    DimensionType,
+
+    /// Resources (`.json`) in `data/<namespace>/loot_tables/`.
+    ///
+    /// Loot tables are nested in subdirectories like `blocks/`, `chests/`, or
+    /// `entities/`, which are part of the resource's path (e.g.,
+    /// `blocks/stone`).
+    LootTable,
+
+    /// Resources (`.json`) in `data/<namespace>/recipes/`.
+    Recipe,
+
+    /// Resources (`.json`) in `data/<namespace>/advancements/`.
+    ///
+    /// Advancements are nested in subdirectories like `story/` or `nether/`,
+    /// which are part of the resource's path (e.g., `story/mine_stone`).
+    Advancement,
+
+    /// Resources (`.json`) in `data/<namespace>/tags/blocks/`.
+    BlockTag,
+
+    /// Resources (`.json`) in `data/<namespace>/tags/items/`.
+    ItemTag,
+
+    /// Resources (`.json`) in `data/<namespace>/tags/entity_types/`.
+    EntityTypeTag,
+
+    /// Resources (`.json`) in `data/<namespace>/tags/fluids/`.
+    FluidTag,
+
+    /// Resources (`.json`) in `data/<namespace>/tags/functions/`.
+    FunctionTag,
+
+    /// Resources (`.json`) in `data/<namespace>/tags/game_events/`.
+    GameEventTag,
+
+    /// Resources (`.json`) in `data/<namespace>/tags/worldgen/biome/`.
+    WorldGen_BiomeTag,
+
+    /// Resources (`.json`) in `data/<namespace>/tags/worldgen/structure/`.
+    WorldGen_StructureTag,
+
+    /// Resources (`.json`) in `data/<namespace>/tags/worldgen/world_preset/`.
+    WorldGen_WorldPresetTag,
+
+    /// Resources (`.json`) in `data/<namespace>/tags/worldgen/flat_level_generator_preset/`.
+    WorldGen_FlatLevelGeneratorPresetTag,
+
+    /// Resources (`.json`) in `data/<namespace>/tags/damage_type/`.
+    ///
+    /// Added in 1.19.4.
+    DamageTypeTag,
+
+    /// Resources (`.json`) in `data/<namespace>/tags/enchantment/`.
+    ///
+    /// Added in 1.21.
+    EnchantmentTag,
+
+    /// Resources (`.json`) in `data/<namespace>/tags/banner_pattern/`.
+    ///
+    /// Added in 1.20.5.
+    BannerPatternTag,
+
+    /// Resources (`.json`) in `data/<namespace>/tags/painting_variant/`.
+    ///
+    /// Added in 1.19.
+    PaintingVariantTag,
+
+    /// Resources (`.json`) in `data/<namespace>/predicates/`.
+    Predicate,
+
+    /// Resources (`.json`) in `data/<namespace>/item_modifiers/`.
+    ItemModifier,
+
+    /// Resources (`.json`) in `data/<namespace>/damage_type/`.
+    ///
+    /// Added in 1.19.4.
+    DamageType,
+
+    /// Resources (`.json`) in `data/<namespace>/chat_type/`.
+    ///
+    /// Added in 1.19.
+    ChatType,
+
+    /// Resources (`.json`) in `data/<namespace>/trim_material/`.
+    ///
+    /// Added in 1.19.4.
+    TrimMaterial,
+
+    /// Resources (`.json`) in `data/<namespace>/trim_pattern/`.
+    ///
+    /// Added in 1.19.4.
+    TrimPattern,
+
+    /// Resources (`.json`) in `data/<namespace>/enchantment/`.
+    ///
+    /// Added in 1.21.
+    Enchantment,
+
+    /// Resources (`.json`) in `data/<namespace>/painting_variant/`.
+    ///
+    /// Added in 1.21.
+    PaintingVariant,
+
+    /// Resources (`.json`) in `data/<namespace>/wolf_variant/`.
+    ///
+    /// Added in 1.20.5.
+    WolfVariant,
+
+    /// Resources (`.json`) in `data/<namespace>/banner_pattern/`.
+    ///
+    /// Added in 1.20.5.
+    BannerPattern,
+
+    /// Resources (`.json`) in `data/<namespace>/jukebox_song/`.
+    ///
+    /// Added in 1.21.
+    JukeboxSong,
+
+    /// Resources (`.nbt`) in `data/<namespace>/structures/`.
+    Structure,
+
+    /// Resources (`.mcfunction`) in `data/<namespace>/functions/`.
+    Function,
+
+    /// The `assets/<namespace>/sounds.json` file.
+    ///
+    /// There is at most one resource of this kind per namespace, with the path
+    /// `sounds`.
+    Sounds,
+
+    /// Sound files (`.ogg`) in `assets/<namespace>/sounds/`.
+    Sound,
+
+    /// Resources (`.json`) in `assets/<namespace>/lang/`.
+    ///
+    /// Added in 1.13, replacing [`LegacyLanguage`][Self::LegacyLanguage].
+    Language,
+
+    /// Legacy `key=value` language files (`.lang`) in
+    /// `assets/<namespace>/lang/`.
+    ///
+    /// Removed in 1.13.
+    LegacyLanguage,
+
+    /// Resources (`.json`) in `assets/<namespace>/font/`.
+    Font,
+
+    /// Resources (`.json`) in `assets/<namespace>/particles/`.
+    Particle,
+
+    /// Resources (`.json`) in `assets/<namespace>/atlases/`.
+    Atlas,
+
+    /// Plain text resources (`.txt`) in `assets/<namespace>/texts/`, e.g.,
+    /// `splashes.txt` and `end.txt`.
+    Text,
+
+    /// JSON resources (`.json`) in `assets/<namespace>/texts/`, e.g.,
+    /// `credits.json`.
+    TextJson,
+
+    /// Resources (`.json`) in `assets/<namespace>/shaders/core/`.
+    CoreShader,
+
+    /// Resources (`.json`) in `assets/<namespace>/shaders/program/`.
+    ///
+    /// Removed in 1.21.2.
+    ProgramShader,
+
+    /// Resources (`.json`) in `assets/<namespace>/shaders/post/`.
+    ///
+    /// Removed in 1.21.2.
+    PostChain,
+
+    /// Resources (`.json`) in `assets/<namespace>/post_effect/`.
+    ///
+    /// Added in 1.21.2.
+    PostEffect,
+
+    /// Vertex shader sources (`.vsh`) in `assets/<namespace>/shaders/`.
+    VertexShader,
+
+    /// Fragment shader sources (`.fsh`) in `assets/<namespace>/shaders/`.
+    FragmentShader,
+
+    /// Shader sources (`.glsl`) in `assets/<namespace>/shaders/`, usually in
+    /// `shaders/include/`, that are imported by other shader sources.
+    ShaderInclude,
+
+    /// The `assets/minecraft/gpu_warnlist.json` file.
+    ///
+    /// There is at most one resource of this kind per namespace, with the path
+    /// `gpu_warnlist`.
+    GpuWarnlist,
+
+    /// The `assets/minecraft/regional_compliancies.json` file.
+    ///
+    /// There is at most one resource of this kind per namespace, with the path
+    /// `regional_compliancies`.
+    RegionalCompliancies,
+
+    /// Resources (`.json`) in `assets/<namespace>/equipment/`.
+    ///
+    /// Added in 1.21.2 (in `models/equipment/` until 1.21.4).
+    Equipment,
+
+    /// Resources (`.json`) in `assets/<namespace>/items/`.
+    ///
+    /// Added in 1.21.4.
+    ItemDefinition,
+
+    /// Legacy unicode glyph sizes (`.bin`) in `assets/<namespace>/font/`.
+    ///
+    /// Removed in 1.20.
+    GlyphSizes,
+
+    /// Zip archives of unihex glyphs (`.zip`) in `assets/<namespace>/font/`.
+    ///
+    /// Added in 1.20.
+    UnihexArchive,
+
+    /// OptiFine connected textures rules (`.properties`) in
+    /// `assets/<namespace>/optifine/ctm/`.
+    ///
+    /// Read by OptiFine and mods like Continuity, not by the game itself.
+    CtmProperties,
+
+    /// OptiFine custom item textures rules (`.properties`) in
+    /// `assets/<namespace>/optifine/cit/`.
+    ///
+    /// Read by OptiFine and mods like CIT Resewn, not by the game itself.
+    CitProperties,
+
+    /// Textures (`.png`) in `assets/<namespace>/optifine/`, e.g., the tiles
+    /// of connected textures next to their `.properties` file.
+    OptifineTexture,
 }
 
 impl ResourceKind {
+    /// Every kind of resource, in declaration order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let data_kinds = ResourceKind::ALL
+    ///     .iter()
+    ///     .filter(|kind| kind.category() == ResourceCategory::Data)
+    ///     .count();
+    /// assert!(data_kinds > 0);
+    /// ```
+    pub const ALL: &'static [ResourceKind] = &[
+        Self::BlockStates,
+        Self::BlockModel,
+        Self::ItemModel,
+        Self::Texture,
+        Self::TextureMeta,
+        Self::WorldGen_Biome,
+        Self::WorldGen_MultiNoiseBiomeSourceParameterList,
+        Self::WorldGen_PlacedFeature,
+        Self::WorldGen_ConfiguredCarver,
+        Self::WorldGen_ConfiguredFeature,
+        Self::WorldGen_DensityFunction,
+        Self::WorldGen_Noise,
+        Self::WorldGen_NoiseSettings,
+        Self::WorldGen_Structure,
+        Self::WorldGen_StructureSet,
+        Self::WorldGen_TemplatePool,
+        Self::WorldGen_ProcessorList,
+        Self::WorldGen_WorldPreset,
+        Self::WorldGen_FlatLevelGeneratorPreset,
+        Self::Dimension,
+        Self::DimensionType,
+        Self::LootTable,
+        Self::Recipe,
+        Self::Advancement,
+        Self::BlockTag,
+        Self::ItemTag,
+        Self::EntityTypeTag,
+        Self::FluidTag,
+        Self::FunctionTag,
+        Self::GameEventTag,
+        Self::WorldGen_BiomeTag,
+        Self::WorldGen_StructureTag,
+        Self::WorldGen_WorldPresetTag,
+        Self::WorldGen_FlatLevelGeneratorPresetTag,
+        Self::DamageTypeTag,
+        Self::EnchantmentTag,
+        Self::BannerPatternTag,
+        Self::PaintingVariantTag,
+        Self::Predicate,
+        Self::ItemModifier,
+        Self::DamageType,
+        Self::ChatType,
+        Self::TrimMaterial,
+        Self::TrimPattern,
+        Self::Enchantment,
+        Self::PaintingVariant,
+        Self::WolfVariant,
+        Self::BannerPattern,
+        Self::JukeboxSong,
+        Self::Structure,
+        Self::Function,
+        Self::Sounds,
+        Self::Sound,
+        Self::Language,
+        Self::LegacyLanguage,
+        Self::Font,
+        Self::Particle,
+        Self::Atlas,
+        Self::Text,
+        Self::TextJson,
+        Self::CoreShader,
+        Self::ProgramShader,
+        Self::PostChain,
+        Self::PostEffect,
+        Self::VertexShader,
+        Self::FragmentShader,
+        Self::ShaderInclude,
+        Self::GpuWarnlist,
+        Self::RegionalCompliancies,
+        Self::Equipment,
+        Self::ItemDefinition,
+        Self::GlyphSizes,
+        Self::UnihexArchive,
+        Self::CtmProperties,
+        Self::CitProperties,
+        Self::OptifineTexture,
+    ];
+
     /// Returns the category of this resource type (assets or data).
     pub fn category(&self) -> ResourceCategory {
         match self {
@@ -30,8 +407,130 @@ impl ResourceKind {
             | Self::BlockModel
             | Self::ItemModel
             | Self::Texture
-            | Self::TextureMeta => ResourceCategory::Assets,
-            Self::WorldGen_Biome => ResourceCategory::Data,
+            | Self::TextureMeta
+            | Self::Sounds
+            | Self::Sound
+            | Self::Language
+            | Self::LegacyLanguage
+            | Self::Font
+            | Self::Particle
+            | Self::Atlas
+            | Self::Text
+            | Self::TextJson
+            | Self::CoreShader
+            | Self::ProgramShader
+            | Self::PostChain
+            | Self::PostEffect
+            | Self::VertexShader
+            | Self::FragmentShader
+            | Self::ShaderInclude
+            | Self::GpuWarnlist
+            | Self::RegionalCompliancies
+            | Self::Equipment
+            | Self::ItemDefinition
+            | Self::GlyphSizes
+            | Self::UnihexArchive
+            | Self::CtmProperties
+            | Self::CitProperties
+            | Self::OptifineTexture => ResourceCategory::Assets,
+            Self::WorldGen_Biome
+            | Self::WorldGen_MultiNoiseBiomeSourceParameterList
+            | Self::WorldGen_PlacedFeature
+            | Self::WorldGen_ConfiguredCarver
+            | Self::WorldGen_ConfiguredFeature
+            | Self::WorldGen_DensityFunction
+            | Self::WorldGen_Noise
+            | Self::WorldGen_NoiseSettings
+            | Self::WorldGen_Structure
+            | Self::WorldGen_StructureSet
+            | Self::WorldGen_TemplatePool
+            | Self::WorldGen_ProcessorList
+            | Self::WorldGen_WorldPreset
+            | Self::WorldGen_FlatLevelGeneratorPreset
+            | Self::Dimension
+            | Self::DimensionType
+            | Self::LootTable
+            | Self::Recipe
+            | Self::Advancement
+            | Self::BlockTag
+            | Self::ItemTag
+            | Self::EntityTypeTag
+            | Self::FluidTag
+            | Self::FunctionTag
+            | Self::GameEventTag
+            | Self::WorldGen_BiomeTag
+            | Self::WorldGen_StructureTag
+            | Self::WorldGen_WorldPresetTag
+            | Self::WorldGen_FlatLevelGeneratorPresetTag
+            | Self::DamageTypeTag
+            | Self::EnchantmentTag
+            | Self::BannerPatternTag
+            | Self::PaintingVariantTag
+            | Self::Predicate
+            | Self::ItemModifier
+            | Self::DamageType
+            | Self::ChatType
+            | Self::TrimMaterial
+            | Self::TrimPattern
+            | Self::Enchantment
+            | Self::PaintingVariant
+            | Self::WolfVariant
+            | Self::BannerPattern
+            | Self::JukeboxSong
+            | Self::Structure
+            | Self::Function => ResourceCategory::Data,
+        }
+    }
+
+    /// Returns `true` if resources of this type are [`Tag`]s.
+    ///
+    /// [`Tag`]: crate::schemas::tags::Tag
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// assert!(ResourceKind::BlockTag.is_tag());
+    /// assert!(ResourceKind::WorldGen_BiomeTag.is_tag());
+    /// assert!(!ResourceKind::WorldGen_Biome.is_tag());
+    /// ```
+    pub fn is_tag(&self) -> bool {
+        matches!(
+            self,
+            Self::BlockTag
+                | Self::ItemTag
+                | Self::EntityTypeTag
+                | Self::FluidTag
+                | Self::FunctionTag
+                | Self::GameEventTag
+                | Self::WorldGen_BiomeTag
+                | Self::WorldGen_StructureTag
+                | Self::WorldGen_WorldPresetTag
+                | Self::WorldGen_FlatLevelGeneratorPresetTag
+                | Self::DamageTypeTag
+                | Self::EnchantmentTag
+                | Self::BannerPatternTag
+                | Self::PaintingVariantTag
+        )
+    }
+
+    /// Returns the path (without extension) of the only resource of this type
+    /// in each namespace, or `None` if there may be any number of resources of
+    /// this type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// assert_eq!(ResourceKind::Sounds.single_file(), Some("sounds"));
+    /// assert_eq!(ResourceKind::BlockStates.single_file(), None);
+    /// ```
+    pub fn single_file(&self) -> Option<&'static str> {
+        match self {
+            Self::Sounds => Some("sounds"),
+            Self::GpuWarnlist => Some("gpu_warnlist"),
+            Self::RegionalCompliancies => Some("regional_compliancies"),
+            _ => None,
         }
     }
 
@@ -52,14 +551,88 @@ impl ResourceKind {
     /// ```
     pub fn extension(&self) -> &'static str {
         match self {
-            Self::BlockStates | Self::BlockModel | Self::ItemModel | Self::WorldGen_Biome => "json",
-            Self::Texture => "png",
+            Self::BlockStates
+            | Self::BlockModel
+            | Self::ItemModel
+            | Self::WorldGen_Biome
+            | Self::WorldGen_MultiNoiseBiomeSourceParameterList
+            | Self::WorldGen_PlacedFeature
+            | Self::WorldGen_ConfiguredCarver
+            | Self::WorldGen_ConfiguredFeature
+            | Self::WorldGen_DensityFunction
+            | Self::WorldGen_Noise
+            | Self::WorldGen_NoiseSettings
+            | Self::WorldGen_Structure
+            | Self::WorldGen_StructureSet
+            | Self::WorldGen_TemplatePool
+            | Self::WorldGen_ProcessorList
+            | Self::WorldGen_WorldPreset
+            | Self::WorldGen_FlatLevelGeneratorPreset
+            | Self::Dimension
+            | Self::DimensionType
+            | Self::LootTable
+            | Self::Recipe
+            | Self::Advancement
+            | Self::BlockTag
+            | Self::ItemTag
+            | Self::EntityTypeTag
+            | Self::FluidTag
+            | Self::FunctionTag
+            | Self::GameEventTag
+            | Self::WorldGen_BiomeTag
+            | Self::WorldGen_StructureTag
+            | Self::WorldGen_WorldPresetTag
+            | Self::WorldGen_FlatLevelGeneratorPresetTag
+            | Self::DamageTypeTag
+            | Self::EnchantmentTag
+            | Self::BannerPatternTag
+            | Self::PaintingVariantTag
+            | Self::Predicate
+            | Self::ItemModifier
+            | Self::DamageType
+            | Self::ChatType
+            | Self::TrimMaterial
+            | Self::TrimPattern
+            | Self::Enchantment
+            | Self::PaintingVariant
+            | Self::WolfVariant
+            | Self::BannerPattern
+            | Self::JukeboxSong
+            | Self::Sounds
+            | Self::Language
+            | Self::Font
+            | Self::Particle
+            | Self::Atlas
+            | Self::TextJson
+            | Self::CoreShader
+            | Self::ProgramShader
+            | Self::PostChain
+            | Self::PostEffect
+            | Self::GpuWarnlist
+            | Self::RegionalCompliancies
+            | Self::Equipment
+            | Self::ItemDefinition => "json",
+            Self::Texture | Self::OptifineTexture => "png",
             Self::TextureMeta => "png.mcmeta",
+            Self::Sound => "ogg",
+            Self::Structure => "nbt",
+            Self::Function => "mcfunction",
+            Self::Text => "txt",
+            Self::VertexShader => "vsh",
+            Self::FragmentShader => "fsh",
+            Self::ShaderInclude => "glsl",
+            Self::LegacyLanguage => "lang",
+            Self::GlyphSizes => "bin",
+            Self::UnihexArchive => "zip",
+            Self::CtmProperties | Self::CitProperties => "properties",
         }
     }
 
     /// Returns the path relative to `assets/<namespace>/` or
     /// `data/<namespace>/` in which resources of this type reside.
+    ///
+    /// This is empty for [`single_file`][Self::single_file] resources that
+    /// reside directly in the namespace directory.
     pub fn directory(&self) -> &'static str {
         match self {
             Self::BlockStates => "blockstates",
@@ -67,6 +640,91 @@ impl ResourceKind {
             Self::ItemModel => "models/item",
             Self::Texture | Self::TextureMeta => "textures",
             Self::WorldGen_Biome => "worldgen/biome",
+            Self::WorldGen_MultiNoiseBiomeSourceParameterList => {
+                "worldgen/multi_noise_biome_source_parameter_list"
+            }
+            Self::WorldGen_PlacedFeature => "worldgen/placed_feature",
+            Self::WorldGen_ConfiguredCarver => "worldgen/configured_carver",
+            Self::WorldGen_ConfiguredFeature => "worldgen/configured_feature",
+            Self::WorldGen_DensityFunction => "worldgen/density_function",
+            Self::WorldGen_Noise => "worldgen/noise",
+            Self::WorldGen_NoiseSettings => "worldgen/noise_settings",
+            Self::WorldGen_Structure => "worldgen/structure",
+            Self::WorldGen_StructureSet => "worldgen/structure_set",
+            Self::WorldGen_TemplatePool => "worldgen/template_pool",
+            Self::WorldGen_ProcessorList => "worldgen/processor_list",
+            Self::WorldGen_WorldPreset => "worldgen/world_preset",
+            Self::WorldGen_FlatLevelGeneratorPreset => "worldgen/flat_level_generator_preset",
+            Self::Dimension => "dimension",
+            Self::DimensionType => "dimension_type",
+            Self::LootTable => "loot_tables",
+            Self::Recipe => "recipes",
+            Self::Advancement => "advancements",
+            Self::BlockTag => "tags/blocks",
+            Self::ItemTag => "tags/items",
+            Self::EntityTypeTag => "tags/entity_types",
+            Self::FluidTag => "tags/fluids",
+            Self::FunctionTag => "tags/functions",
+            Self::GameEventTag => "tags/game_events",
+            Self::WorldGen_BiomeTag => "tags/worldgen/biome",
+            Self::WorldGen_StructureTag => "tags/worldgen/structure",
+            Self::WorldGen_WorldPresetTag => "tags/worldgen/world_preset",
+            Self::WorldGen_FlatLevelGeneratorPresetTag => {
+                "tags/worldgen/flat_level_generator_preset"
+            }
+            Self::DamageTypeTag => "tags/damage_type",
+            Self::EnchantmentTag => "tags/enchantment",
+            Self::BannerPatternTag => "tags/banner_pattern",
+            Self::PaintingVariantTag => "tags/painting_variant",
+            Self::Predicate => "predicates",
+            Self::ItemModifier => "item_modifiers",
+            Self::DamageType => "damage_type",
+            Self::ChatType => "chat_type",
+            Self::TrimMaterial => "trim_material",
+            Self::TrimPattern => "trim_pattern",
+            Self::Enchantment => "enchantment",
+            Self::PaintingVariant => "painting_variant",
+            Self::WolfVariant => "wolf_variant",
+            Self::BannerPattern => "banner_pattern",
+            Self::JukeboxSong => "jukebox_song",
+            Self::Structure => "structures",
+            Self::Function => "functions",
+            Self::Sounds | Self::GpuWarnlist | Self::RegionalCompliancies => "",
+            Self::Sound => "sounds",
+            Self::Language | Self::LegacyLanguage => "lang",
+            Self::Font | Self::GlyphSizes | Self::UnihexArchive => "font",
+            Self::Particle => "particles",
+            Self::Atlas => "atlases",
+            Self::Text | Self::TextJson => "texts",
+            Self::CoreShader => "shaders/core",
+            Self::ProgramShader => "shaders/program",
+            Self::PostChain => "shaders/post",
+            Self::PostEffect => "post_effect",
+            Self::VertexShader | Self::FragmentShader | Self::ShaderInclude => "shaders",
+            Self::Equipment => "equipment",
+            Self::ItemDefinition => "items",
+            Self::CtmProperties => "optifine/ctm",
+            Self::CitProperties => "optifine/cit",
+            Self::OptifineTexture => "optifine",
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn kinds_have_distinct_locations() {
+        let mut locations = std::collections::HashSet::new();
+        for kind in ResourceKind::ALL {
+            let location = (
+                kind.category(),
+                kind.directory(),
+                kind.extension(),
+                kind.single_file(),
+            );
+            assert!(locations.insert(location), "{:?} is not distinct", kind);
         }
     }
 }
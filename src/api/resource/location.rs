@@ -0,0 +1,527 @@
+use std::{borrow::Cow, cell::RefCell, fmt, hash::Hash, str::FromStr};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::api::{ResourceIdentifier, ResourceKind, MINECRAFT_NAMESPACE};
+
+/// A namespaced identifier that is not tied to a [`ResourceKind`], like the
+/// ids found inside of resources (e.g., `"minecraft:stone"` or
+/// `"#minecraft:logs"`).
+///
+/// Unlike [`ResourceIdentifier`], this type can be parsed from a string with
+/// [`FromStr`] and (de)serialized with serde, which is how the schemas store
+/// the ids of other resources and of registry entries (e.g.,
+/// [`Model::parent`]).
+///
+/// The constructors validate the id, see [`InvalidIdentifier`].
+/// Deserializing is lenient like the game prior to 1.13, which accepted
+/// uppercase names: it keeps the id as written, and only the
+/// [strict mode](crate::schemas::strict) and
+/// [`validate()`][Self::validate] check it.
+///
+/// A location consists of:
+///
+/// * an optional `#` prefix, which marks a reference to a tag,
+/// * an optional namespace followed by `:`, which defaults to `minecraft`
+///   (also when it is empty, e.g., `:stone`),
+/// * and a non-empty path.
+///
+/// Like [`ResourceIdentifier`], it either borrows or owns the underlying
+/// string. Deserializing copies the id, so that owned schemas can hold it;
+/// [`deserialize_borrowed()`][Self::deserialize_borrowed] borrows it from the
+/// input instead.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::api::*;
+/// let location: ResourceLocation = "#logs".parse().unwrap();
+/// assert!(location.is_tag());
+/// assert_eq!(location.namespace(), "minecraft");
+/// assert_eq!(location.path(), "logs");
+/// assert_eq!(location.to_string(), "#minecraft:logs");
+///
+/// let id = ResourceLocation::new("mypack:block/ruby").unwrap().with_kind(ResourceKind::BlockModel);
+/// assert_eq!(id, ResourceIdentifier::block_model("mypack:ruby"));
+///
+/// let error = ResourceLocation::new("minecraft:Stone").unwrap_err();
+/// assert_eq!(error.character(), Some('S'));
+/// assert_eq!(error.position(), 10);
+///
+/// // Legacy packs may use uppercase names.
+/// let location: ResourceLocation = serde_json::from_str(r#""block/MyCube""#).unwrap();
+/// assert_eq!(location.path(), "block/MyCube");
+/// assert!(location.validate().is_err());
+/// ```
+///
+/// [`Model::parent`]: crate::schemas::Model::parent
+#[derive(Clone)]
+pub struct ResourceLocation<'a> {
+    id: Cow<'a, str>,
+}
+
+impl<'a> ResourceLocation<'a> {
+    /// Constructs a new [`ResourceLocation`] that **borrows** the given id, or
+    /// returns an error if the id is not valid.
+    pub fn new(id: &'a str) -> Result<Self, InvalidIdentifier> {
+        validate(id)?;
+        Ok(Self {
+            id: Cow::Borrowed(id),
+        })
+    }
+
+    /// Like [`new()`][Self::new], but returns a [`ResourceLocation`] that owns
+    /// its internal string.
+    pub fn new_owned(id: String) -> Result<ResourceLocation<'static>, InvalidIdentifier> {
+        validate(&id)?;
+        Ok(ResourceLocation { id: Cow::Owned(id) })
+    }
+
+    /// Constructs a [`ResourceLocation`] from an id that is known to be
+    /// valid, e.g., the parents of the vanilla models.
+    pub(crate) const fn from_static(id: &'static str) -> ResourceLocation<'static> {
+        ResourceLocation {
+            id: Cow::Borrowed(id),
+        }
+    }
+
+    /// Constructs a [`ResourceLocation`] without validating it, like
+    /// deserializing does.
+    pub(crate) fn new_unchecked(id: String) -> ResourceLocation<'static> {
+        ResourceLocation { id: Cow::Owned(id) }
+    }
+
+    /// Checks that the id only contains the characters allowed by the game
+    /// since 1.13, and that its path is not empty.
+    ///
+    /// Deserialized locations are not validated, see the
+    /// [type-level docs](Self).
+    pub fn validate(&self) -> Result<(), InvalidIdentifier> {
+        validate(&self.id)
+    }
+
+    /// Returns the underlying id as a string slice, exactly as it was given.
+    pub fn as_str(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns `true` if the location references a tag, i.e., starts with
+    /// `#`.
+    pub fn is_tag(&self) -> bool {
+        self.id.starts_with('#')
+    }
+
+    /// Returns whether or not the location includes an explicit namespace.
+    pub fn has_namespace(&self) -> bool {
+        split(&self.id).1.is_some()
+    }
+
+    /// Returns the namespace portion of the location, or `"minecraft"` if it
+    /// does not have an explicit namespace.
+    pub fn namespace(&self) -> &str {
+        split(&self.id).1.unwrap_or(MINECRAFT_NAMESPACE)
+    }
+
+    /// Returns the path portion of the location.
+    pub fn path(&self) -> &str {
+        split(&self.id).2
+    }
+
+    /// Returns a [`ResourceIdentifier`] of the given kind that references the
+    /// same id, without the `#` prefix.
+    ///
+    /// This is a cheap copy if `self` borrows its string.
+    pub fn with_kind(&self, kind: ResourceKind) -> ResourceIdentifier<'a> {
+        match &self.id {
+            Cow::Borrowed(id) => ResourceIdentifier::new(kind, strip_tag(id)),
+            Cow::Owned(id) => ResourceIdentifier::new_owned(kind, String::from(strip_tag(id))),
+        }
+    }
+
+    /// Returns a new [`ResourceLocation`] that owns the underlying string.
+    pub fn to_owned(&self) -> ResourceLocation<'static> {
+        ResourceLocation {
+            id: Cow::Owned(self.id.clone().into_owned()),
+        }
+    }
+
+    /// Returns `true` if the location borrows its string, e.g., from the text
+    /// that it was deserialized from.
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self.id, Cow::Borrowed(_))
+    }
+
+    /// Deserializes a location that borrows from the input whenever it can,
+    /// for use with `#[serde(borrow, deserialize_with = "...")]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let mut deserializer = serde_json::Deserializer::from_str(r#""block/stone""#);
+    /// let location = ResourceLocation::deserialize_borrowed(&mut deserializer).unwrap();
+    /// assert!(location.is_borrowed());
+    /// ```
+    pub fn deserialize_borrowed<'de: 'a, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(BorrowingVisitor)
+    }
+}
+
+/// Splits an id into whether it is a tag, its explicit namespace, and its
+/// path.
+///
+/// An empty namespace (e.g., `:stone`) is not explicit, like in the game.
+pub(crate) fn split(id: &str) -> (bool, Option<&str>, &str) {
+    let (is_tag, id) = match id.strip_prefix('#') {
+        Some(id) => (true, id),
+        None => (false, id),
+    };
+
+    match id.split_once(':') {
+        Some(("", path)) => (is_tag, None, path),
+        Some((namespace, path)) => (is_tag, Some(namespace), path),
+        None => (is_tag, None, id),
+    }
+}
+
+fn strip_tag(id: &str) -> &str {
+    id.strip_prefix('#').unwrap_or(id)
+}
+
+/// Checks that the given id only contains the characters allowed by the game,
+/// and that its path is not empty.
+pub(crate) fn validate(id: &str) -> Result<(), InvalidIdentifier> {
+    let (is_tag, namespace, path) = split(id);
+    let tag_len = usize::from(is_tag);
+
+    let invalid = |position: usize, character: Option<char>| InvalidIdentifier {
+        id: String::from(id),
+        position,
+        character,
+    };
+
+    if let Some(namespace) = namespace {
+        if let Some((i, c)) = namespace
+            .char_indices()
+            .find(|(_, c)| !is_namespace_char(*c))
+        {
+            return Err(invalid(tag_len + i, Some(c)));
+        }
+    }
+
+    let path_start = id.len() - path.len();
+    if path.is_empty() {
+        return Err(invalid(path_start, None));
+    }
+    if let Some((i, c)) = path.char_indices().find(|(_, c)| !is_path_char(*c)) {
+        return Err(invalid(path_start + i, Some(c)));
+    }
+
+    Ok(())
+}
+
+fn is_namespace_char(c: char) -> bool {
+    matches!(c, 'a'..='z' | '0'..='9' | '_' | '-' | '.')
+}
+
+fn is_path_char(c: char) -> bool {
+    is_namespace_char(c) || c == '/'
+}
+
+impl FromStr for ResourceLocation<'static> {
+    type Err = InvalidIdentifier;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ResourceLocation::new_owned(String::from(s))
+    }
+}
+
+impl TryFrom<String> for ResourceLocation<'static> {
+    type Error = InvalidIdentifier;
+
+    fn try_from(source: String) -> Result<Self, Self::Error> {
+        ResourceLocation::new_owned(source)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for ResourceLocation<'static> {
+    type Error = InvalidIdentifier;
+
+    fn try_from(source: &'a str) -> Result<Self, Self::Error> {
+        source.parse()
+    }
+}
+
+impl<'a> PartialEq for ResourceLocation<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_tag() == other.is_tag()
+            && self.namespace() == other.namespace()
+            && self.path() == other.path()
+    }
+}
+
+/// Compares the location with an id, like two locations, i.e., the
+/// `minecraft:` namespace is optional.
+impl<'a> PartialEq<str> for ResourceLocation<'a> {
+    fn eq(&self, other: &str) -> bool {
+        let (is_tag, namespace, path) = split(other);
+        self.is_tag() == is_tag
+            && self.namespace() == namespace.unwrap_or(MINECRAFT_NAMESPACE)
+            && self.path() == path
+    }
+}
+
+impl<'a, 'b> PartialEq<&'b str> for ResourceLocation<'a> {
+    fn eq(&self, other: &&'b str) -> bool {
+        self == *other
+    }
+}
+
+impl<'a> Eq for ResourceLocation<'a> {}
+
+impl<'a> Hash for ResourceLocation<'a> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.is_tag().hash(state);
+        self.namespace().hash(state);
+        self.path().hash(state);
+    }
+}
+
+impl<'a> AsRef<str> for ResourceLocation<'a> {
+    fn as_ref(&self) -> &str {
+        &self.id
+    }
+}
+
+impl<'a> fmt::Debug for ResourceLocation<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ResourceLocation({:?})", &self.id)
+    }
+}
+
+/// Formats the location with an explicit namespace, e.g.,
+/// `#minecraft:logs`.
+impl<'a> fmt::Display for ResourceLocation<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tag = if self.is_tag() { "#" } else { "" };
+        write!(f, "{}{}:{}", tag, self.namespace(), self.path())
+    }
+}
+
+/// Serializes the location exactly as it was given.
+impl<'a> Serialize for ResourceLocation<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.id)
+    }
+}
+
+/// Deserializes an owned location without validating it, see
+/// [`deserialize_borrowed()`][ResourceLocation::deserialize_borrowed] to
+/// borrow it instead.
+impl<'de, 'a> Deserialize<'de> for ResourceLocation<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_string(OwningVisitor)
+    }
+}
+
+struct OwningVisitor;
+
+impl<'de> de::Visitor<'de> for OwningVisitor {
+    type Value = ResourceLocation<'static>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a resource location")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(deserialized(Cow::Owned(String::from(v))))
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+        Ok(deserialized(Cow::Owned(v)))
+    }
+}
+
+struct BorrowingVisitor;
+
+impl<'de> de::Visitor<'de> for BorrowingVisitor {
+    type Value = ResourceLocation<'de>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a resource location")
+    }
+
+    fn visit_borrowed_str<E: de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+        Ok(deserialized(Cow::Borrowed(v)))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(deserialized(Cow::Owned(String::from(v))))
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+        Ok(deserialized(Cow::Owned(v)))
+    }
+}
+
+thread_local! {
+    /// The invalid ids deserialized by [`ResourceLocation::collect_invalid()`],
+    /// if it is running.
+    static INVALID: RefCell<Option<Vec<InvalidIdentifier>>> = const { RefCell::new(None) };
+}
+
+/// Returns a deserialized location, recording it if it is invalid and
+/// [`ResourceLocation::collect_invalid()`] is running.
+fn deserialized(id: Cow<str>) -> ResourceLocation {
+    INVALID.with(|invalid| {
+        if let Some(invalid) = invalid.borrow_mut().as_mut() {
+            if let Err(e) = validate(&id) {
+                invalid.push(e);
+            }
+        }
+    });
+    ResourceLocation { id }
+}
+
+impl ResourceLocation<'static> {
+    /// Runs `f`, and returns the invalid ids that it deserialized, for the
+    /// strict mode.
+    pub(crate) fn collect_invalid<T>(f: impl FnOnce() -> T) -> (T, Vec<InvalidIdentifier>) {
+        let previous = INVALID.with(|invalid| invalid.replace(Some(Vec::new())));
+        let value = f();
+        let collected = INVALID.with(|invalid| invalid.replace(previous));
+        (value, collected.unwrap_or_default())
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for ResourceLocation<'_> {
+    fn schema_name() -> Cow<'static, str> {
+        "ResourceLocation".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "A namespaced id, which may be a `#`-prefixed tag.",
+            "type": "string",
+            "pattern": "^#?([a-z0-9_.-]*:)?[a-z0-9_./-]+$",
+        })
+    }
+}
+
+/// The error returned when an id contains a character that the game does not
+/// allow, or has an empty path.
+///
+/// Namespaces may only contain lowercase ASCII letters, digits, `_`, `-`, and
+/// `.`. Paths may additionally contain `/`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidIdentifier {
+    id: String,
+    position: usize,
+    character: Option<char>,
+}
+
+impl InvalidIdentifier {
+    /// Returns the invalid id.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the byte position of the first invalid character in the id, or
+    /// of its empty path.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the first invalid character in the id, or `None` if its path
+    /// is empty.
+    pub fn character(&self) -> Option<char> {
+        self.character
+    }
+}
+
+impl fmt::Display for InvalidIdentifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.character {
+            Some(character) => write!(
+                f,
+                "invalid character {:?} at position {} of resource location {:?} \
+                (only a-z, 0-9, '_', '-', '.', and '/' in paths are allowed)",
+                character, self.position, self.id
+            ),
+            None => write!(f, "empty path in resource location {:?}", self.id),
+        }
+    }
+}
+
+impl std::error::Error for InvalidIdentifier {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deserialize_borrows() {
+        let mut deserializer = serde_json::Deserializer::from_str(r##""#minecraft:logs""##);
+        let location = ResourceLocation::deserialize_borrowed(&mut deserializer).unwrap();
+        assert!(matches!(location.id, Cow::Borrowed(_)));
+        assert_eq!(
+            serde_json::to_string(&location).unwrap(),
+            r##""#minecraft:logs""##
+        );
+    }
+
+    #[test]
+    fn deserialize_is_lenient() {
+        let location: ResourceLocation = serde_json::from_str(r#""minecraft:Logs""#).unwrap();
+        assert_eq!(location.path(), "Logs");
+
+        let (location, invalid) = ResourceLocation::collect_invalid(|| {
+            serde_json::from_str::<ResourceLocation>(r#""minecraft:Logs""#)
+        });
+        assert_eq!(location.unwrap().as_str(), "minecraft:Logs");
+        assert!(invalid[0].to_string().contains("'L' at position 10"));
+    }
+
+    #[test]
+    fn schemas_accept_legacy_uppercase_ids() {
+        let model: crate::schemas::Model =
+            serde_json::from_str(r#"{ "parent": "block/MyCube" }"#).unwrap();
+        assert_eq!(model.parent.unwrap().as_str(), "block/MyCube");
+    }
+
+    #[test]
+    fn error_positions() {
+        let error = validate("#my pack:logs").unwrap_err();
+        assert_eq!((error.position(), error.character()), (3, Some(' ')));
+
+        let error = validate("#minecraft:logs:oak").unwrap_err();
+        assert_eq!((error.position(), error.character()), (15, Some(':')));
+
+        assert!(validate("my_pack.v2:block/ruby-ore").is_ok());
+    }
+
+    #[test]
+    fn rejects_empty_paths() {
+        for id in ["", "#", "minecraft:", "#minecraft:", ":"] {
+            let error = validate(id).unwrap_err();
+            assert_eq!(
+                (error.position(), error.character()),
+                (id.len(), None),
+                "{}",
+                id
+            );
+        }
+    }
+
+    #[test]
+    fn empty_namespace_is_minecraft() {
+        let location: ResourceLocation = ":stone".parse().unwrap();
+        assert!(!location.has_namespace());
+        assert_eq!(location.namespace(), "minecraft");
+        assert_eq!(location, "minecraft:stone");
+        assert_eq!(location.to_string(), "minecraft:stone");
+    }
+}
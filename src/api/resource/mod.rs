@@ -1,11 +1,13 @@
 mod category;
 mod identifier;
 mod kind;
+mod location;
 mod model_identifier;
 mod path;
 
 pub use category::ResourceCategory;
 pub use identifier::{ResourceIdentifier, MINECRAFT_NAMESPACE};
 pub use kind::ResourceKind;
+pub use location::{InvalidIdentifier, ResourceLocation};
 pub use model_identifier::ModelIdentifier;
 pub use path::ResourcePath;
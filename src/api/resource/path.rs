@@ -3,7 +3,7 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use crate::api::{ResourceIdentifier, ResourceKind};
+use crate::api::{ResourceIdentifier, ResourceKind, TargetVersion};
 
 /// Represents the full path to a resource, e.g., on the local file system.
 pub struct ResourcePath(PathBuf);
@@ -35,12 +35,57 @@ impl ResourcePath {
     /// not point to an existing directory. This method simply computes what the
     /// path should be for a given resource type.
     pub fn for_kind(root: impl AsRef<Path>, namespace: &str, kind: ResourceKind) -> Self {
+        Self::for_kind_directory(root, namespace, kind, kind.directory())
+    }
+
+    /// Like [`for_resource()`][Self::for_resource], but uses the layout of the
+    /// given [`TargetVersion`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// use std::path::Path;
+    ///
+    /// let id = ResourceIdentifier::loot_table("blocks/stone");
+    /// let path = ResourcePath::for_resource_in("pack", &id, TargetVersion::V1_21);
+    /// assert_eq!(&*path, Path::new("pack/data/minecraft/loot_table/blocks/stone.json"));
+    /// ```
+    pub fn for_resource_in(
+        root: impl AsRef<Path>,
+        resource: &ResourceIdentifier,
+        version: TargetVersion,
+    ) -> Self {
+        let mut path = Self::for_kind_in(root, resource.namespace(), resource.kind(), version);
+
+        path.push(resource.path());
+
+        Self(path.with_extension(resource.kind().extension()))
+    }
+
+    /// Like [`for_kind()`][Self::for_kind], but uses the layout of the given
+    /// [`TargetVersion`].
+    pub fn for_kind_in(
+        root: impl AsRef<Path>,
+        namespace: &str,
+        kind: ResourceKind,
+        version: TargetVersion,
+    ) -> Self {
+        Self::for_kind_directory(root, namespace, kind, version.directory(kind))
+    }
+
+    fn for_kind_directory(
+        root: impl AsRef<Path>,
+        namespace: &str,
+        kind: ResourceKind,
+        directory: &str,
+    ) -> Self {
         let mut path = PathBuf::from(root.as_ref());
 
         // `assets/` or `data/`.
         path.push(kind.category().directory());
         path.push(namespace);
-        path.push(kind.directory());
+        path.push(directory);
 
         Self(path)
     }
@@ -0,0 +1,153 @@
+use std::collections::HashSet;
+
+use crate::api::{ResourceIdentifier, ResourceKind, Result};
+
+/// Methods for resolving the `#moj_import` directives of GLSL shader sources.
+///
+/// Minecraft's shader sources may import other sources with one of two forms
+/// of the `#moj_import` directive:
+///
+/// * `#moj_import <fog.glsl>` imports `shaders/include/fog.glsl`. Since
+///   1.21.2 the name may have a namespace, e.g., `<minecraft:fog.glsl>`.
+/// * `#moj_import "utils.glsl"` imports `utils.glsl` relative to the
+///   directory of the importing source.
+///
+/// Each source is only imported once; later imports of the same source are
+/// removed, which also breaks import cycles.
+pub struct ShaderImportResolver;
+
+impl ShaderImportResolver {
+    /// Loads the shader source referenced by the given [`ResourceIdentifier`]
+    /// and replaces its `#moj_import` directives with the (recursively
+    /// resolved) imported sources.
+    ///
+    /// The given function is used to load each source. Imported sources are
+    /// requested as [`ResourceKind::ShaderInclude`] resources, whose paths are
+    /// relative to `shaders/` and have no extension (e.g., `include/fog`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// use maplit::hashmap;
+    ///
+    /// let sources = hashmap! {
+    ///     "core/position" => "#version 150\n#moj_import <fog.glsl>\nvoid main() {}",
+    ///     "include/fog" => "#moj_import \"light.glsl\"\nfloat fog() { return 1.0; }",
+    ///     "include/light" => "#moj_import <fog.glsl>\nfloat light() { return 1.0; }",
+    /// };
+    ///
+    /// let source = ShaderImportResolver::resolve(
+    ///     &ResourceIdentifier::vertex_shader("core/position"),
+    ///     |id| Ok(String::from(sources[id.path()])),
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(
+    ///     source,
+    ///     "#version 150\n\
+    ///      float light() { return 1.0; }\n\
+    ///      float fog() { return 1.0; }\n\
+    ///      void main() {}\n"
+    /// );
+    /// ```
+    pub fn resolve<F>(id: &ResourceIdentifier, mut load: F) -> Result<String>
+    where
+        F: FnMut(&ResourceIdentifier) -> Result<String>,
+    {
+        let mut output = String::new();
+        let mut imported = HashSet::new();
+        imported.insert(Self::import_key(id));
+
+        Self::resolve_into(id, &mut load, &mut imported, &mut output)?;
+
+        Ok(output)
+    }
+
+    fn resolve_into<F>(
+        id: &ResourceIdentifier,
+        load: &mut F,
+        imported: &mut HashSet<String>,
+        output: &mut String,
+    ) -> Result<()>
+    where
+        F: FnMut(&ResourceIdentifier) -> Result<String>,
+    {
+        let source = load(id)?;
+
+        for line in source.lines() {
+            match Self::parse_import(line, id) {
+                Some(import) => {
+                    if imported.insert(Self::import_key(&import)) {
+                        Self::resolve_into(&import, load, imported, output)?;
+                    }
+                }
+                None => {
+                    output.push_str(line);
+                    output.push('\n');
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the identifier imported by the given line, if it is a
+    /// `#moj_import` directive.
+    fn parse_import(
+        line: &str,
+        importer: &ResourceIdentifier,
+    ) -> Option<ResourceIdentifier<'static>> {
+        let rest = line.trim().strip_prefix("#moj_import")?.trim();
+
+        let (namespace, path) = if let Some(name) = rest
+            .strip_prefix('<')
+            .and_then(|rest| rest.strip_suffix('>'))
+        {
+            let (namespace, name) = match name.split_once(':') {
+                Some((namespace, name)) => (namespace, name),
+                None => (importer.namespace(), name),
+            };
+            (namespace, format!("include/{}", name))
+        } else if let Some(name) = rest
+            .strip_prefix('"')
+            .and_then(|rest| rest.strip_suffix('"'))
+        {
+            let directory = match importer.path().rsplit_once('/') {
+                Some((directory, _)) => directory,
+                None => "",
+            };
+            (importer.namespace(), Self::join_path(directory, name))
+        } else {
+            return None;
+        };
+
+        let path = path.strip_suffix(".glsl").unwrap_or(&path);
+
+        Some(ResourceIdentifier::new_owned(
+            ResourceKind::ShaderInclude,
+            format!("{}:{}", namespace, path),
+        ))
+    }
+
+    /// Joins a relative path onto a directory, resolving `.` and `..`.
+    fn join_path(directory: &str, relative: &str) -> String {
+        let mut components: Vec<&str> = directory.split('/').filter(|c| !c.is_empty()).collect();
+
+        for component in relative.split('/') {
+            match component {
+                "" | "." => {}
+                ".." => {
+                    components.pop();
+                }
+                component => components.push(component),
+            }
+        }
+
+        components.join("/")
+    }
+
+    fn import_key(id: &ResourceIdentifier) -> String {
+        format!("{}:{}.{}", id.namespace(), id.path(), id.kind().extension())
+    }
+}
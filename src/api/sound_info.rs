@@ -0,0 +1,226 @@
+use std::{
+    io::{self, Read},
+    time::Duration,
+};
+
+use crate::api::Result;
+
+/// The basic metadata of an Ogg Vorbis sound file, read from its headers
+/// without decoding the audio.
+///
+/// This type requires the `ogg` feature.
+///
+/// Only the first Vorbis stream of the file is read, like the game does.
+///
+/// # Example
+///
+/// ```no_run
+/// # use minecraft_assets::api::*;
+/// let assets = AssetPack::at_path("~/.minecraft/");
+///
+/// let info = assets.load_sound_info("ambient/cave/cave1").unwrap();
+/// assert_eq!(info.channels, 1);
+/// assert_eq!(info.sample_rate, 44100);
+/// println!("{:?}", info.duration());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SoundInfo {
+    /// The number of audio channels, e.g., `1` for mono or `2` for stereo.
+    ///
+    /// Only mono sounds are positioned in the world by the game.
+    pub channels: u8,
+
+    /// The number of samples per second, per channel.
+    pub sample_rate: u32,
+
+    /// The total number of samples, per channel.
+    pub samples: u64,
+
+    /// The average bitrate, in bits per second, if the encoder set it.
+    pub nominal_bitrate: Option<u32>,
+}
+
+impl SoundInfo {
+    /// Reads the metadata of the given contents of an `.ogg` file.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        Self::read(bytes)
+    }
+
+    /// Reads the metadata of an `.ogg` file from the given reader.
+    ///
+    /// Every page of the file is read to find its length, but only the
+    /// headers of the pages after the first one are parsed, so that the
+    /// file can be streamed.
+    ///
+    /// Returns an error of kind [`io::ErrorKind::InvalidData`] if the file is
+    /// not an Ogg Vorbis file.
+    pub fn read<R: Read>(mut reader: R) -> Result<Self> {
+        let first = read_page_header(&mut reader)?.ok_or_else(|| invalid("empty file"))?;
+        let mut body = Vec::new();
+        reader
+            .by_ref()
+            .take(first.body_len)
+            .read_to_end(&mut body)?;
+
+        let mut info = parse_identification_header(&body)?;
+        let serial = first.serial;
+
+        while let Some(page) = read_page_header(&mut reader)? {
+            if page.serial == serial && page.granule_position != u64::MAX {
+                info.samples = page.granule_position;
+            }
+            let skipped = io::copy(&mut reader.by_ref().take(page.body_len), &mut io::sink())?;
+            if skipped != page.body_len {
+                return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+            }
+        }
+
+        Ok(info)
+    }
+
+    /// Returns the duration of the sound.
+    pub fn duration(&self) -> Duration {
+        if self.sample_rate == 0 {
+            return Duration::ZERO;
+        }
+
+        let rate = u64::from(self.sample_rate);
+        let seconds = self.samples / rate;
+        let nanos = (self.samples % rate) * 1_000_000_000 / rate;
+        Duration::new(seconds, nanos as u32)
+    }
+}
+
+/// The fields of an Ogg page header that are needed to find the length of
+/// the stream.
+struct PageHeader {
+    granule_position: u64,
+    serial: u32,
+    body_len: u64,
+}
+
+/// Reads the header of the next Ogg page, or returns `None` at the end of the
+/// file.
+fn read_page_header<R: Read>(reader: &mut R) -> Result<Option<PageHeader>> {
+    let mut header = [0; 27];
+    let mut read = 0;
+    while read < header.len() {
+        match reader.read(&mut header[read..]) {
+            Ok(0) if read == 0 => return Ok(None),
+            Ok(0) => return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into()),
+            Ok(n) => read += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    if &header[0..4] != b"OggS" || header[4] != 0 {
+        return Err(invalid("bad Ogg page header"));
+    }
+
+    let granule_position = u64::from_le_bytes(header[6..14].try_into().unwrap());
+    let serial = u32::from_le_bytes(header[14..18].try_into().unwrap());
+
+    let mut segments = vec![0; header[26] as usize];
+    reader.read_exact(&mut segments)?;
+    let body_len = segments.iter().map(|&len| u64::from(len)).sum();
+
+    Ok(Some(PageHeader {
+        granule_position,
+        serial,
+        body_len,
+    }))
+}
+
+/// Parses the Vorbis identification header, which is the first packet of
+/// the stream.
+fn parse_identification_header(packet: &[u8]) -> Result<SoundInfo> {
+    if packet.len() < 30 || packet[0] != 1 || &packet[1..7] != b"vorbis" {
+        return Err(invalid("missing Vorbis identification header"));
+    }
+
+    let version = u32::from_le_bytes(packet[7..11].try_into().unwrap());
+    let channels = packet[11];
+    let sample_rate = u32::from_le_bytes(packet[12..16].try_into().unwrap());
+    let nominal_bitrate = i32::from_le_bytes(packet[20..24].try_into().unwrap());
+
+    if version != 0 || channels == 0 || sample_rate == 0 {
+        return Err(invalid("bad Vorbis identification header"));
+    }
+
+    Ok(SoundInfo {
+        channels,
+        sample_rate,
+        samples: 0,
+        nominal_bitrate: u32::try_from(nominal_bitrate).ok().filter(|&b| b > 0),
+    })
+}
+
+fn invalid(message: &str) -> crate::api::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message).into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Returns an Ogg page with a single packet.
+    fn page(serial: u32, granule_position: u64, packet: &[u8]) -> Vec<u8> {
+        let mut page = b"OggS\0\0".to_vec();
+        page.extend(granule_position.to_le_bytes());
+        page.extend(serial.to_le_bytes());
+        page.extend([0; 8]);
+
+        let mut segments = vec![255; packet.len() / 255];
+        segments.push((packet.len() % 255) as u8);
+        page.push(segments.len() as u8);
+        page.extend(segments);
+        page.extend(packet);
+        page
+    }
+
+    fn identification_header(channels: u8, sample_rate: u32) -> Vec<u8> {
+        let mut packet = b"\x01vorbis".to_vec();
+        packet.extend(0u32.to_le_bytes());
+        packet.push(channels);
+        packet.extend(sample_rate.to_le_bytes());
+        packet.extend(0i32.to_le_bytes());
+        packet.extend(128_000i32.to_le_bytes());
+        packet.extend(0i32.to_le_bytes());
+        packet.extend([0xb8, 0x01]);
+        packet
+    }
+
+    #[test]
+    fn reads_the_length_of_the_first_stream() {
+        let mut file = page(7, 0, &identification_header(2, 48000));
+        file.extend(page(7, u64::MAX, &[0; 300]));
+        file.extend(page(7, 72000, &[0; 10]));
+        file.extend(page(8, 960000, &[0; 10]));
+
+        let info = SoundInfo::from_bytes(&file).unwrap();
+        assert_eq!(
+            info,
+            SoundInfo {
+                channels: 2,
+                sample_rate: 48000,
+                samples: 72000,
+                nominal_bitrate: Some(128_000),
+            }
+        );
+        assert_eq!(info.duration(), Duration::from_millis(1500));
+    }
+
+    #[test]
+    fn rejects_other_files() {
+        assert!(SoundInfo::from_bytes(b"").is_err());
+        assert!(SoundInfo::from_bytes(b"RIFF\0\0\0\0WAVEfmt ").is_err());
+
+        let opus = page(1, 0, b"OpusHead\x01\x02\x38\x01\x80\xbb\0\0\0\0\0");
+        assert!(SoundInfo::from_bytes(&opus).is_err());
+
+        let mut truncated = page(1, 0, &identification_header(1, 44100));
+        truncated.extend(&page(1, 100, &[0; 100])[..50]);
+        assert!(SoundInfo::from_bytes(&truncated).is_err());
+    }
+}
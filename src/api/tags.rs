@@ -0,0 +1,411 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::{
+    api::{
+        Error, LayeredResourceProvider, ResourceCategory, ResourceIdentifier, ResourceKind,
+        ResourceProvider, Result, MINECRAFT_NAMESPACE,
+    },
+    schemas::tags::{Tag, TagEntry},
+};
+
+use super::{parse_error::parse_resource, provider::is_missing};
+
+/// Merges the tag files of stacked data packs and expands them into the final
+/// sets of IDs, like the game does when loading tags.
+///
+/// Packs are added from the bottom up, like the layers of a
+/// [`LayeredResourceProvider`]. Each pack's tag file is appended to the
+/// entries of the tag from the lower packs, or replaces them if its
+/// [`replace`][Tag::replace] field is `true`.
+///
+/// Resolving a tag recursively expands the `#`-prefixed references to other
+/// tags of the same kind. A missing tag or ID fails the resolution, unless its
+/// entry is not [required][TagEntry::is_required], in which case it is
+/// skipped. IDs can only be checked after giving the resolver the IDs of the
+/// registry with [`with_registry()`][Self::with_registry]; otherwise, every ID
+/// is assumed to exist.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::api::*;
+/// let mut vanilla = MemoryResourceProvider::new();
+/// vanilla.insert(
+///     "data/minecraft/tags/blocks/logs.json",
+///     br##"{ "values": ["#minecraft:oak_logs", "minecraft:birch_log"] }"##.to_vec(),
+/// );
+/// vanilla.insert(
+///     "data/minecraft/tags/blocks/oak_logs.json",
+///     br##"{ "values": ["oak_log", "oak_wood"] }"##.to_vec(),
+/// );
+///
+/// let mut pack = MemoryResourceProvider::new();
+/// pack.insert(
+///     "data/minecraft/tags/blocks/logs.json",
+///     br##"{ "values": [{ "id": "#othermod:logs", "required": false }, "mypack:ruby_log"] }"##.to_vec(),
+/// );
+///
+/// let mut tags = TagResolver::new(ResourceKind::BlockTag);
+/// tags.add_pack(&vanilla).unwrap();
+/// tags.add_pack(&pack).unwrap();
+///
+/// assert_eq!(
+///     tags.resolve("logs").unwrap().unwrap(),
+///     [
+///         "minecraft:oak_log",
+///         "minecraft:oak_wood",
+///         "minecraft:birch_log",
+///         "mypack:ruby_log",
+///     ]
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct TagResolver {
+    kind: ResourceKind,
+    tags: BTreeMap<String, Vec<TagEntry>>,
+    registry: Option<HashSet<String>>,
+}
+
+impl TagResolver {
+    /// Returns a new resolver for tags of the given kind (e.g.,
+    /// [`ResourceKind::BlockTag`]), without any tags.
+    pub fn new(kind: ResourceKind) -> Self {
+        Self {
+            kind,
+            tags: BTreeMap::new(),
+            registry: None,
+        }
+    }
+
+    /// Sets the IDs that exist in the registry of the tags and returns `self`.
+    ///
+    /// IDs without a namespace are in the `minecraft` namespace.
+    pub fn with_registry<I, S>(mut self, ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let ids = ids.into_iter().map(|id| canonical(id.as_ref())).collect();
+        self.registry = Some(ids);
+        self
+    }
+
+    /// Returns the kind of the tags.
+    pub fn kind(&self) -> ResourceKind {
+        self.kind
+    }
+
+    /// Adds every tag of the resolver's kind in the given pack, on top of the
+    /// packs that were added before.
+    ///
+    /// An [`AssetPack`] can be passed with `&*assets`.
+    ///
+    /// [`AssetPack`]: crate::api::AssetPack
+    pub fn add_pack(&mut self, pack: &dyn ResourceProvider) -> Result<()> {
+        self.add_pack_where(pack, |_| true)
+    }
+
+    /// Adds the tag files of the given pack for which `keep` returns `true`.
+    fn add_pack_where<F>(&mut self, pack: &dyn ResourceProvider, mut keep: F) -> Result<()>
+    where
+        F: FnMut(&ResourceIdentifier) -> bool,
+    {
+        let namespaces = match pack.enumerate_namespaces(ResourceCategory::Data) {
+            Ok(namespaces) => namespaces,
+            Err(e) if is_missing(&e) => return Ok(()),
+            Err(e) => return Err(e.into()),
+        };
+
+        for namespace in namespaces {
+            let ids = match pack.enumerate_resources(&namespace, self.kind) {
+                Ok(ids) => ids,
+                Err(e) if is_missing(&e) => continue,
+                Err(e) => return Err(e.into()),
+            };
+
+            for id in ids.into_iter().filter(|id| keep(id)) {
+                let bytes = pack.load_resource(&id)?;
+                let tag: Tag = parse_resource(&id, None, &bytes)?;
+                self.add_tag(&format!("{}:{}", id.namespace(), id.path()), tag);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adds every layer of the given provider as a pack, from the bottom up.
+    ///
+    /// See [`add_pack()`][Self::add_pack]. The tag files hidden by the
+    /// `filter` of a layer above them are skipped.
+    pub fn add_layers(&mut self, layers: &LayeredResourceProvider) -> Result<()> {
+        for (i, layer) in layers.layers().enumerate() {
+            self.add_pack_where(layer, |id| !layers.is_filtered(i, id))?;
+        }
+
+        Ok(())
+    }
+
+    /// Adds the tag file with the given ID, on top of the tag files that were
+    /// added before.
+    pub fn add_tag(&mut self, id: &str, tag: Tag) {
+        let entries = self.tags.entry(canonical(id)).or_default();
+        if tag.replace {
+            entries.clear();
+        }
+        entries.extend(tag.values);
+    }
+
+    /// Returns the IDs of every tag, in order.
+    pub fn tags(&self) -> impl Iterator<Item = &str> {
+        self.tags.keys().map(String::as_str)
+    }
+
+    /// Returns the merged entries of the tag with the given ID, before they
+    /// are expanded.
+    pub fn entries(&self, tag: &str) -> Option<&[TagEntry]> {
+        self.tags.get(&canonical(tag)).map(Vec::as_slice)
+    }
+
+    /// Returns the IDs in the tag with the given ID (with or without the `#`),
+    /// or `None` if there is no such tag.
+    ///
+    /// The IDs are namespaced, and in the order of the tag's entries with
+    /// duplicates removed.
+    ///
+    /// Returns [`Error::TagCycle`] if the tag references itself, and
+    /// [`Error::MissingTagEntry`] if a required entry does not exist.
+    pub fn resolve(&self, tag: &str) -> Result<Option<Vec<String>>> {
+        let tag = canonical(tag.strip_prefix('#').unwrap_or(tag));
+        if !self.tags.contains_key(&tag) {
+            return Ok(None);
+        }
+
+        let mut resolved = HashMap::new();
+        self.expand(&tag, &mut Vec::new(), &mut resolved)?;
+        Ok(resolved.remove(&tag))
+    }
+
+    /// Returns the IDs in every tag, by tag ID, and the errors of the tags that
+    /// could not be resolved.
+    ///
+    /// Like the game, a broken tag (e.g., with a missing required entry or a
+    /// cycle) is left out, along with the tags that reference it, but does not
+    /// prevent resolving the other tags. See [`resolve()`][Self::resolve].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let mut pack = MemoryResourceProvider::new();
+    /// pack.insert("data/minecraft/tags/items/planks.json", br#"{ "values": ["oak_planks"] }"#.to_vec());
+    /// pack.insert("data/minecraft/tags/items/broken.json", br##"{ "values": ["#minecraft:missing"] }"##.to_vec());
+    ///
+    /// let mut tags = TagResolver::new(ResourceKind::ItemTag);
+    /// tags.add_pack(&pack).unwrap();
+    ///
+    /// let resolved = tags.resolve_all();
+    /// assert_eq!(resolved.tags["minecraft:planks"], ["minecraft:oak_planks"]);
+    /// assert!(!resolved.tags.contains_key("minecraft:broken"));
+    ///
+    /// let (tag, error) = &resolved.errors[0];
+    /// assert_eq!(tag, "minecraft:broken");
+    /// assert!(matches!(error, Error::MissingTagEntry { .. }));
+    /// ```
+    pub fn resolve_all(&self) -> ResolvedTags {
+        let mut resolved = HashMap::new();
+        let mut errors = Vec::new();
+        for tag in self.tags.keys() {
+            if let Err(e) = self.expand(tag, &mut Vec::new(), &mut resolved) {
+                errors.push((tag.clone(), e));
+            }
+        }
+
+        ResolvedTags {
+            tags: resolved.into_iter().collect(),
+            errors,
+        }
+    }
+
+    /// Expands the given tag into `resolved`, after the tags it references.
+    fn expand(
+        &self,
+        tag: &str,
+        stack: &mut Vec<String>,
+        resolved: &mut HashMap<String, Vec<String>>,
+    ) -> Result<()> {
+        if resolved.contains_key(tag) {
+            return Ok(());
+        }
+        if stack.iter().any(|t| t == tag) {
+            return Err(Error::TagCycle {
+                tag: tag.to_string(),
+            });
+        }
+
+        stack.push(tag.to_string());
+
+        let mut ids = Vec::new();
+        let mut seen = HashSet::new();
+        for entry in &self.tags[tag] {
+            let missing = || Error::MissingTagEntry {
+                tag: tag.to_string(),
+                entry: entry.id().to_string(),
+            };
+
+            match entry.tag() {
+                Some(referenced) => {
+                    let referenced = canonical(referenced);
+                    if !self.tags.contains_key(&referenced) {
+                        if entry.is_required() {
+                            return Err(missing());
+                        }
+                        continue;
+                    }
+
+                    self.expand(&referenced, stack, resolved)?;
+                    for id in &resolved[&referenced] {
+                        if seen.insert(id.clone()) {
+                            ids.push(id.clone());
+                        }
+                    }
+                }
+                None => {
+                    let id = canonical(entry.id());
+                    if let Some(registry) = &self.registry {
+                        if !registry.contains(&id) {
+                            if entry.is_required() {
+                                return Err(missing());
+                            }
+                            continue;
+                        }
+                    }
+
+                    if seen.insert(id.clone()) {
+                        ids.push(id);
+                    }
+                }
+            }
+        }
+
+        stack.pop();
+        resolved.insert(tag.to_string(), ids);
+        Ok(())
+    }
+}
+
+/// The tags resolved by [`TagResolver::resolve_all()`], and the errors of
+/// those that could not be resolved.
+#[derive(Debug)]
+pub struct ResolvedTags {
+    /// The IDs in each tag that was resolved, by tag ID.
+    pub tags: BTreeMap<String, Vec<String>>,
+
+    /// The tags that could not be resolved and their errors, ordered by tag
+    /// ID.
+    pub errors: Vec<(String, Error)>,
+}
+
+impl ResolvedTags {
+    /// Returns `true` if every tag was resolved.
+    pub fn is_complete(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Returns the ID with the default namespace if it has none.
+pub(crate) fn canonical(id: &str) -> String {
+    if id.contains(':') {
+        id.to_string()
+    } else {
+        format!("{}:{}", MINECRAFT_NAMESPACE, id)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn tag(replace: bool, values: &[&str]) -> Tag {
+        Tag {
+            replace,
+            values: values
+                .iter()
+                .map(|&value| value.try_into().unwrap())
+                .collect(),
+            #[cfg(feature = "preserve-unknown")]
+            extra: Default::default(),
+        }
+    }
+
+    #[test]
+    fn replace_discards_lower_packs() {
+        let mut tags = TagResolver::new(ResourceKind::ItemTag);
+        tags.add_tag("minecraft:planks", tag(false, &["oak_planks"]));
+        tags.add_tag("planks", tag(true, &["spruce_planks"]));
+        tags.add_tag("planks", tag(false, &["birch_planks", "spruce_planks"]));
+
+        assert_eq!(
+            tags.resolve("#minecraft:planks").unwrap().unwrap(),
+            ["minecraft:spruce_planks", "minecraft:birch_planks"]
+        );
+        assert_eq!(tags.resolve("logs").unwrap(), None);
+    }
+
+    #[test]
+    fn cycles_and_missing_entries_fail() {
+        let mut tags = TagResolver::new(ResourceKind::ItemTag);
+        tags.add_tag("a", tag(false, &["#b"]));
+        tags.add_tag("b", tag(false, &["stick", "#a"]));
+        tags.add_tag("c", tag(false, &["#d"]));
+
+        assert!(matches!(tags.resolve("a"), Err(Error::TagCycle { .. })));
+        assert!(matches!(
+            tags.resolve("c"),
+            Err(Error::MissingTagEntry { entry, .. }) if entry == "#d"
+        ));
+
+        let mut tags = TagResolver::new(ResourceKind::ItemTag).with_registry(["stick"]);
+        tags.add_tag("sticks", tag(false, &["stick", "minecraft:bamboo"]));
+        assert!(matches!(
+            tags.resolve("sticks"),
+            Err(Error::MissingTagEntry { entry, .. }) if entry == "minecraft:bamboo"
+        ));
+    }
+
+    #[test]
+    fn resolve_all_drops_only_broken_tags() {
+        let mut tags = TagResolver::new(ResourceKind::ItemTag);
+        tags.add_tag("a", tag(false, &["#b"]));
+        tags.add_tag("b", tag(false, &["stick", "#a"]));
+        tags.add_tag("c", tag(false, &["#d"]));
+        tags.add_tag("e", tag(false, &["#c"]));
+        tags.add_tag("f", tag(false, &["stick", "#g"]));
+        tags.add_tag("g", tag(false, &["bamboo"]));
+
+        let resolved = tags.resolve_all();
+        assert_eq!(
+            resolved.tags.keys().collect::<Vec<_>>(),
+            ["minecraft:f", "minecraft:g"]
+        );
+        assert_eq!(
+            resolved.tags["minecraft:f"],
+            ["minecraft:stick", "minecraft:bamboo"]
+        );
+
+        let errors: Vec<_> = resolved
+            .errors
+            .iter()
+            .map(|(tag, _)| tag.as_str())
+            .collect();
+        assert_eq!(
+            errors,
+            ["minecraft:a", "minecraft:b", "minecraft:c", "minecraft:e"]
+        );
+        assert!(matches!(resolved.errors[0].1, Error::TagCycle { .. }));
+        assert!(matches!(
+            resolved.errors[2].1,
+            Error::MissingTagEntry { .. }
+        ));
+        assert!(!resolved.is_complete());
+    }
+}
@@ -0,0 +1,85 @@
+use std::io;
+
+use image::RgbaImage;
+use png::{ColorType, Decoder, Transformations};
+
+use crate::api::Result;
+
+/// Methods for decoding texture `.png` files into [`RgbaImage`]s.
+///
+/// This type requires the `image` feature.
+///
+/// Every texture is converted to 8-bit RGBA, like the game does when loading
+/// it:
+///
+/// * Grayscale pixels are copied into the red, green, and blue channels.
+/// * Palettes, bit depths below 8, and `tRNS` transparency are expanded.
+/// * 16-bit channels are truncated to 8 bits.
+/// * Chunk checksums are not verified, since the game ignores bad checksums
+///   that some image editors produce.
+pub struct TextureDecoder;
+
+impl TextureDecoder {
+    /// Decodes the given contents of a `.png` file into an [`RgbaImage`].
+    ///
+    /// Animated textures are returned as-is, i.e., as one tall image with
+    /// every frame stacked vertically.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// // A 2x1 grayscale PNG with a mid-gray pixel and a pixel that is made
+    /// // transparent by its `tRNS` chunk.
+    /// let bytes = [
+    ///     0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48,
+    ///     0x44, 0x52, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x00, 0x00,
+    ///     0x00, 0xd1, 0x49, 0x20, 0x56, 0x00, 0x00, 0x00, 0x02, 0x74, 0x52, 0x4e, 0x53, 0x00,
+    ///     0x00, 0x76, 0x93, 0xcd, 0x38, 0x00, 0x00, 0x00, 0x0b, 0x49, 0x44, 0x41, 0x54, 0x78,
+    ///     0x9c, 0x63, 0x68, 0x60, 0x00, 0x00, 0x01, 0x03, 0x00, 0x81, 0x3e, 0x4c, 0xc5, 0x93,
+    ///     0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4e, 0x44, 0xae, 0x42, 0x60, 0x82,
+    /// ];
+    ///
+    /// let image = TextureDecoder::decode(&bytes).unwrap();
+    ///
+    /// assert_eq!(image.dimensions(), (2, 1));
+    /// assert_eq!(image.get_pixel(0, 0).0, [0x80, 0x80, 0x80, 0xff]);
+    /// assert_eq!(image.get_pixel(1, 0).0, [0x00, 0x00, 0x00, 0x00]);
+    /// ```
+    pub fn decode(bytes: &[u8]) -> Result<RgbaImage> {
+        let mut decoder = Decoder::new(io::Cursor::new(bytes));
+        decoder.set_transformations(Transformations::EXPAND | Transformations::STRIP_16);
+        decoder.ignore_checksums(true);
+
+        let mut reader = decoder.read_info()?;
+        let buffer_size = reader
+            .output_buffer_size()
+            .ok_or_else(|| invalid_data("texture is too large"))?;
+
+        let mut buffer = vec![0; buffer_size];
+        let info = reader.next_frame(&mut buffer)?;
+        let data = &buffer[..info.buffer_size()];
+
+        let rgba = match info.color_type {
+            ColorType::Rgba => data.to_vec(),
+            ColorType::Rgb => data
+                .chunks_exact(3)
+                .flat_map(|p| [p[0], p[1], p[2], 0xff])
+                .collect(),
+            ColorType::GrayscaleAlpha => data
+                .chunks_exact(2)
+                .flat_map(|p| [p[0], p[0], p[0], p[1]])
+                .collect(),
+            ColorType::Grayscale => data.iter().flat_map(|&l| [l, l, l, 0xff]).collect(),
+            // Palettes are expanded by `Transformations::EXPAND`.
+            ColorType::Indexed => return Err(invalid_data("unexpanded palette").into()),
+        };
+
+        RgbaImage::from_raw(info.width, info.height, rgba)
+            .ok_or_else(|| invalid_data("texture data is truncated").into())
+    }
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
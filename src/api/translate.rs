@@ -0,0 +1,315 @@
+use std::collections::HashMap;
+
+use crate::{
+    api::{AssetPack, Result},
+    schemas::{
+        text::{TextComponent, TextContent},
+        LanguageFile,
+    },
+};
+
+/// The language used as a fallback for keys missing from other languages.
+pub const DEFAULT_LANGUAGE: &str = "en_us";
+
+/// Looks up and formats translated strings from one or more
+/// [`LanguageFile`]s.
+///
+/// Language files are merged in the order they are added, so that files added
+/// later (i.e., from higher priority resource packs) override keys from files
+/// added earlier. Keys that are missing from the selected language are looked
+/// up in the fallback language (usually [`DEFAULT_LANGUAGE`]).
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::api::*;
+/// # use minecraft_assets::schemas::LanguageFile;
+/// use maplit::hashmap;
+///
+/// let vanilla = LanguageFile::from(hashmap! {
+///     "item.minecraft.diamond".into() => "Diamant".into(),
+///     "chat.type.text".into() => "<%s> %s".into(),
+/// });
+/// let pack = LanguageFile::from(hashmap! {
+///     "item.minecraft.diamond".into() => "Glitzerstein".into(),
+/// });
+/// let fallback = LanguageFile::from(hashmap! {
+///     "item.minecraft.emerald".into() => "Emerald".into(),
+/// });
+///
+/// let mut translator = Translator::new();
+/// translator.add_language_file(vanilla);
+/// translator.add_language_file(pack);
+/// translator.add_fallback_file(fallback);
+///
+/// assert_eq!(translator.translate("item.minecraft.diamond", &[]), "Glitzerstein");
+/// assert_eq!(translator.translate("item.minecraft.emerald", &[]), "Emerald");
+/// assert_eq!(translator.translate("chat.type.text", &["Steve", "hi"]), "<Steve> hi");
+///
+/// // Unknown keys are returned as-is.
+/// assert_eq!(translator.translate("item.minecraft.unknown", &[]), "item.minecraft.unknown");
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Translator {
+    translations: HashMap<String, String>,
+    fallback: HashMap<String, String>,
+}
+
+impl Translator {
+    /// Returns a new [`Translator`] with no translations.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a new [`Translator`] with the given language of the
+    /// `minecraft` namespace loaded from each of the given [`AssetPack`]s, in
+    /// order of increasing priority.
+    ///
+    /// Unless `language` is [`DEFAULT_LANGUAGE`], the default language is
+    /// loaded as the fallback. Asset packs that do not contain a language file
+    /// are skipped, but an error is returned if none of them contain the
+    /// selected language.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// let vanilla = AssetPack::at_path("~/.minecraft/");
+    /// let pack = AssetPack::at_path("~/.minecraft/resourcepacks/my_pack/");
+    ///
+    /// let translator = Translator::load(&[&vanilla, &pack], "de_de").unwrap();
+    /// println!("{}", translator.translate("item.minecraft.diamond", &[]));
+    /// ```
+    pub fn load(packs: &[&AssetPack], language: &str) -> Result<Self> {
+        let mut translator = Self::new();
+
+        let mut error = None;
+        let mut found = false;
+        for pack in packs {
+            match pack.load_language(language) {
+                Ok(file) => {
+                    translator.add_language_file(file);
+                    found = true;
+                }
+                Err(e) => error = Some(e),
+            }
+
+            if language != DEFAULT_LANGUAGE {
+                if let Ok(file) = pack.load_language(DEFAULT_LANGUAGE) {
+                    translator.add_fallback_file(file);
+                }
+            }
+        }
+
+        match error {
+            Some(e) if !found => Err(e),
+            _ => Ok(translator),
+        }
+    }
+
+    /// Merges the given language file into the selected language, overriding
+    /// any existing keys.
+    pub fn add_language_file(&mut self, file: LanguageFile) {
+        self.translations.extend(file.translations);
+    }
+
+    /// Merges the given language file into the fallback language, overriding
+    /// any existing keys.
+    pub fn add_fallback_file(&mut self, file: LanguageFile) {
+        self.fallback.extend(file.translations);
+    }
+
+    /// Returns the unformatted translated string for the given key, looking in
+    /// the fallback language if the key is missing from the selected language.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.translations
+            .get(key)
+            .or_else(|| self.fallback.get(key))
+            .map(String::as_str)
+    }
+
+    /// Returns `true` if the given key has a translation in either the
+    /// selected or the fallback language.
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Translates the given key and substitutes the given format arguments.
+    ///
+    /// If the key has no translation, the key itself is formatted instead.
+    /// See [`format`][Self::format] for the supported format arguments.
+    pub fn translate(&self, key: &str, args: &[&str]) -> String {
+        Self::format(self.get(key).unwrap_or(key), args)
+    }
+
+    /// Returns the plain text of the given [`TextComponent`], with its
+    /// translatable parts translated and its styles dropped.
+    ///
+    /// Translation keys without a translation use the component's
+    /// `fallback`, or the key itself. Contents that the game resolves from
+    /// the world are shown as follows: keybinds as the name of their control,
+    /// selectors as the selector, and scores and NBT values as nothing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// # use minecraft_assets::schemas::{text::TextComponent, LanguageFile};
+    /// use maplit::hashmap;
+    ///
+    /// let mut translator = Translator::new();
+    /// translator.add_language_file(LanguageFile::from(hashmap! {
+    ///     "chat.type.advancement.task".into() => "%s has made the advancement %s".into(),
+    /// }));
+    ///
+    /// let component: TextComponent = serde_json::from_str(r#"
+    ///     [
+    ///         { "translate": "chat.type.advancement.task", "with": ["Steve", ["[", { "text": "Stone Age" }, "]"]] },
+    ///         { "text": "!", "color": "green" }
+    ///     ]
+    /// "#).unwrap();
+    ///
+    /// assert_eq!(translator.render(&component), "Steve has made the advancement [Stone Age]!");
+    /// ```
+    pub fn render(&self, component: &TextComponent) -> String {
+        let mut output = String::new();
+        self.render_into(component, &mut output);
+        output
+    }
+
+    fn render_into(&self, component: &TextComponent, output: &mut String) {
+        let object = match component {
+            TextComponent::String(text) => return output.push_str(text),
+            TextComponent::Number(number) => return output.push_str(&number.to_string()),
+            TextComponent::Boolean(boolean) => return output.push_str(&boolean.to_string()),
+            TextComponent::List(components) => {
+                for component in components {
+                    self.render_into(component, output);
+                }
+                return;
+            }
+            TextComponent::Object(object) => object,
+        };
+
+        match &object.content {
+            TextContent::Text { text } => output.push_str(text),
+            TextContent::Translatable {
+                translate,
+                fallback,
+                with,
+            } => {
+                let template = self
+                    .get(translate)
+                    .or(fallback.as_deref())
+                    .unwrap_or(translate);
+                let args: Vec<String> = with.iter().flatten().map(|arg| self.render(arg)).collect();
+                let args: Vec<&str> = args.iter().map(String::as_str).collect();
+                output.push_str(&Self::format(template, &args));
+            }
+            TextContent::Keybind { keybind } => output.push_str(keybind),
+            TextContent::Selector { selector, .. } => output.push_str(selector),
+            TextContent::Score { .. } | TextContent::Nbt { .. } => {}
+        }
+
+        for child in object.extra.iter().flatten() {
+            self.render_into(child, output);
+        }
+    }
+
+    /// Substitutes format arguments in a translated string.
+    ///
+    /// The same subset of Java format specifiers as Minecraft is supported:
+    /// * `%s` is replaced with the next argument.
+    /// * `%1$s`, `%2$s`, ... are replaced with the argument at that (1-based)
+    ///   position.
+    /// * `%%` is replaced with `%`.
+    ///
+    /// Specifiers that refer to missing arguments are replaced with nothing,
+    /// and any other use of `%` is left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// assert_eq!(Translator::format("%s and %s", &["A", "B"]), "A and B");
+    /// assert_eq!(Translator::format("%2$s before %1$s", &["A", "B"]), "B before A");
+    /// assert_eq!(Translator::format("100%% %s", &["done"]), "100% done");
+    /// assert_eq!(Translator::format("%s, %s", &["A"]), "A, ");
+    /// ```
+    pub fn format(template: &str, args: &[&str]) -> String {
+        let mut output = String::with_capacity(template.len());
+        let mut next_arg = 0;
+        let mut rest = template;
+
+        while let Some(index) = rest.find('%') {
+            output.push_str(&rest[..index]);
+            rest = &rest[index + 1..];
+
+            if let Some(stripped) = rest.strip_prefix('%') {
+                output.push('%');
+                rest = stripped;
+            } else if let Some(stripped) = rest.strip_prefix('s') {
+                output.push_str(args.get(next_arg).copied().unwrap_or(""));
+                next_arg += 1;
+                rest = stripped;
+            } else if let Some((position, stripped)) = Self::parse_positional(rest) {
+                output.push_str(args.get(position - 1).copied().unwrap_or(""));
+                rest = stripped;
+            } else {
+                output.push('%');
+            }
+        }
+
+        output.push_str(rest);
+        output
+    }
+
+    /// Parses the `1$s` part of a `%1$s` specifier, returning the position and
+    /// the remainder of the string.
+    fn parse_positional(s: &str) -> Option<(usize, &str)> {
+        let digits = s.find(|c: char| !c.is_ascii_digit())?;
+        let stripped = s[digits..].strip_prefix("$s")?;
+        let position: usize = s[..digits].parse().ok()?;
+
+        (position > 0).then_some((position, stripped))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::api::{MemoryResourceProvider, TargetVersion};
+
+    #[test]
+    fn loads_legacy_language_files_with_upper_case_regions() {
+        let mut provider = MemoryResourceProvider::new();
+        provider.insert(
+            "assets/minecraft/lang/en_US.lang",
+            b"tile.stone.stone.name=Stone\nitem.diamond.name=Diamond".to_vec(),
+        );
+        provider.insert(
+            "assets/minecraft/lang/de_DE.lang",
+            b"tile.stone.stone.name=Stein".to_vec(),
+        );
+        let pack = AssetPack::new(provider).with_target_version(TargetVersion::V1_12_2);
+
+        let translator = Translator::load(&[&pack], "de_de").unwrap();
+        assert_eq!(translator.translate("tile.stone.stone.name", &[]), "Stein");
+        assert_eq!(translator.translate("item.diamond.name", &[]), "Diamond");
+
+        let translator = Translator::load(&[&pack], DEFAULT_LANGUAGE).unwrap();
+        assert_eq!(translator.translate("tile.stone.stone.name", &[]), "Stone");
+    }
+
+    #[test]
+    fn renders_numbers_and_booleans_as_text() {
+        let mut translator = Translator::new();
+        translator.add_language_file(LanguageFile::from_legacy("chat.square_brackets=[%s, %s]"));
+
+        let component: TextComponent =
+            serde_json::from_str(r#"{ "translate": "chat.square_brackets", "with": [1, true] }"#)
+                .unwrap();
+        assert_eq!(translator.render(&component), "[1, true]");
+    }
+}
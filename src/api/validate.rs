@@ -0,0 +1,1041 @@
+//! Checking packs for broken references between resources.
+//!
+//! Start here: [`Validator`].
+//!
+//! The validator walks every resource of a pack that references other
+//! resources and reports each reference that cannot be resolved as a
+//! [`Diagnostic`]:
+//!
+//! * Block and item models whose parents, textures, or override models are
+//!   missing.
+//! * Blockstates that use missing models.
+//! * Biomes that use unknown placed features or configured carvers.
+//! * `sounds.json` entries whose `.ogg` files are missing.
+//!
+//! Given the [`Registries`] of a version of the game with
+//! [`Validator::with_registries()`], the validator also checks that:
+//!
+//! * Blockstates files are named after blocks, and only use the properties
+//!   and values of their block.
+//! * Item definitions are named after items.
+//! * Biomes only play sound events that exist.
+//! * The required entries of tags exist, for tags of blocks, items, biomes,
+//!   etc.
+//!
+//! With [`Validator::with_schema_checks()`], every other JSON resource of the
+//! pack is parsed as well, and reported if it does not match its schema. With
+//! [`Validator::with_strict()`], resources are parsed in [strict mode], which
+//! also reports unknown fields and out-of-range values.
+//!
+//! References to tags (e.g., `#minecraft:cave`), inline definitions, and
+//! built-in models like `builtin/generated` are not checked.
+//!
+//! Only the pack itself is searched for the referenced resources. A resource
+//! pack that builds upon the vanilla assets should therefore be validated on
+//! top of them, using a [`LayeredResourceProvider`].
+//!
+//! [`LayeredResourceProvider`]: crate::api::LayeredResourceProvider
+//! [`Registries`]: crate::api::Registries
+//! [strict mode]: crate::schemas::strict
+
+use std::{collections::HashMap, fmt, io};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    api::{
+        ModelIdentifier, ModelResolver, Registries, ResourceIdentifier, ResourceKind,
+        ResourceProvider, Result,
+    },
+    schemas::{
+        blockstates::{
+            multipart::{StateValue, WhenClause},
+            Variant,
+        },
+        models::{BlockFace, Model, Texture},
+        reference::{MaybeReference, MaybeReferenceList},
+        shader::{PostChain, PostEffect, ShaderProgram},
+        sounds::{Sound, SoundKind},
+        strict::{self, CheckRanges, Violation},
+        tags::TagEntry,
+        texture,
+        worldgen::{
+            biome::CustomeBiome,
+            multi_noise_biome_source_parameter_list::MultiNoiseBiomeSourceParameterList,
+        },
+        Advancement, Atlas, BannerPattern, BlockStates, ChatType, DamageType, Enchantment,
+        Equipment, Font, GpuWarnlist, ItemDefinition, ItemModifier, JukeboxSong, LanguageFile,
+        LootTable, PaintingVariant, Particle, Predicate, Recipe, RegionalCompliancies, SoundsJson,
+        Tag, TrimMaterial, TrimPattern, WolfVariant,
+    },
+};
+
+use super::registries::tag_registry;
+
+/// The kinds of resources that are checked by [`Validator::validate()`], in
+/// the order in which they are checked.
+const VALIDATED_KINDS: [ResourceKind; 5] = [
+    ResourceKind::BlockStates,
+    ResourceKind::BlockModel,
+    ResourceKind::ItemModel,
+    ResourceKind::WorldGen_Biome,
+    ResourceKind::Sounds,
+];
+
+/// The kinds of resources that are only checked by [`Validator::validate()`]
+/// when it is given [`Registries`], in the order in which they are checked.
+const REGISTRY_VALIDATED_KINDS: [ResourceKind; 14] = [
+    ResourceKind::ItemDefinition,
+    ResourceKind::BlockTag,
+    ResourceKind::ItemTag,
+    ResourceKind::EntityTypeTag,
+    ResourceKind::FluidTag,
+    ResourceKind::GameEventTag,
+    ResourceKind::WorldGen_BiomeTag,
+    ResourceKind::WorldGen_StructureTag,
+    ResourceKind::WorldGen_WorldPresetTag,
+    ResourceKind::WorldGen_FlatLevelGeneratorPresetTag,
+    ResourceKind::DamageTypeTag,
+    ResourceKind::EnchantmentTag,
+    ResourceKind::BannerPatternTag,
+    ResourceKind::PaintingVariantTag,
+];
+
+/// A broken reference found by a [`Validator`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// The resource that contains the broken reference.
+    pub resource: ResourceIdentifier<'static>,
+
+    /// The location of the broken reference within the resource, as a [JSON
+    /// pointer] (e.g., `/textures/all`).
+    ///
+    /// This is empty if the problem concerns the resource as a whole.
+    ///
+    /// [JSON pointer]: <https://datatracker.ietf.org/doc/html/rfc6901>
+    pub json_path: String,
+
+    /// What is wrong with the reference.
+    pub problem: Problem,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.json_path.is_empty() {
+            write!(f, "{}: {}", self.resource, self.problem)
+        } else {
+            write!(
+                f,
+                "{} at {}: {}",
+                self.resource, self.json_path, self.problem
+            )
+        }
+    }
+}
+
+/// The problem reported by a [`Diagnostic`].
+///
+/// Every variant holds the reference exactly as it is written in the
+/// resource.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Problem {
+    /// The resource could not be parsed. Holds the error message.
+    Invalid(String),
+
+    /// The resource could not be checked, e.g., because it could not be read,
+    /// when [collecting errors](Validator::with_collected_errors). Holds the
+    /// error message.
+    Unchecked(String),
+
+    /// The parent of a model does not exist.
+    MissingModelParent(String),
+
+    /// A model used by a blockstates file or an item override does not exist.
+    MissingModel(String),
+
+    /// A texture used by a model does not exist.
+    MissingTexture(String),
+
+    /// A placed feature used by a biome does not exist.
+    UnknownPlacedFeature(String),
+
+    /// A configured carver used by a biome does not exist.
+    UnknownConfiguredCarver(String),
+
+    /// A sound file used by a sound event does not exist.
+    MissingSoundFile(String),
+
+    /// A blockstates file is named after a block that is not registered.
+    UnknownBlock(String),
+
+    /// A blockstates file uses a property or value (e.g., `facing=up`) that its
+    /// block does not have.
+    UnknownBlockState(String),
+
+    /// An item definition is named after an item that is not registered.
+    UnknownItem(String),
+
+    /// A sound event used by a biome is not registered.
+    UnknownSoundEvent(String),
+
+    /// A required entry of a tag is not registered.
+    UnknownTagEntry(String),
+
+    /// A field is not part of the resource's schema, in [strict
+    /// mode](Validator::with_strict). Holds the name of the field.
+    UnknownField(String),
+
+    /// An id is not a valid resource location, in [strict
+    /// mode](Validator::with_strict). Holds the error message.
+    InvalidIdentifier(String),
+
+    /// A value is outside of the range accepted by the game, in [strict
+    /// mode](Validator::with_strict). Holds a description of the range.
+    OutOfRange(String),
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid(message) => write!(f, "invalid resource: {}", message),
+            Self::Unchecked(message) => write!(f, "cannot check resource: {}", message),
+            Self::MissingModelParent(parent) => write!(f, "missing parent model {}", parent),
+            Self::MissingModel(model) => write!(f, "missing model {}", model),
+            Self::MissingTexture(texture) => write!(f, "missing texture {}", texture),
+            Self::UnknownPlacedFeature(feature) => write!(f, "unknown placed feature {}", feature),
+            Self::UnknownConfiguredCarver(carver) => {
+                write!(f, "unknown configured carver {}", carver)
+            }
+            Self::MissingSoundFile(sound) => write!(f, "missing sound file {}", sound),
+            Self::UnknownBlock(block) => write!(f, "unknown block {}", block),
+            Self::UnknownBlockState(state) => write!(f, "unknown block state {}", state),
+            Self::UnknownItem(item) => write!(f, "unknown item {}", item),
+            Self::UnknownSoundEvent(event) => write!(f, "unknown sound event {}", event),
+            Self::UnknownTagEntry(entry) => write!(f, "unknown tag entry {}", entry),
+            Self::UnknownField(field) => write!(f, "unknown field {}", field),
+            Self::InvalidIdentifier(message) => write!(f, "invalid id: {}", message),
+            Self::OutOfRange(message) => write!(f, "value out of range: {}", message),
+        }
+    }
+}
+
+/// Checks the resources of a pack for references to resources that do not
+/// exist.
+///
+/// The validator remembers which resources exist, so it can be reused to
+/// check several resources of the same pack efficiently.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::api::*;
+/// use minecraft_assets::api::validate::{Problem, Validator};
+/// use minecraft_assets::schemas::Model;
+///
+/// let root = std::env::temp_dir().join("minecraft-assets-validate-example");
+/// let mut writer = ResourceWriter::at_path(&root);
+///
+/// let model: Model = serde_json::from_str(r#"{
+///     "parent": "block/cube_all",
+///     "textures": { "all": "block/my_block" }
+/// }"#).unwrap();
+/// writer.write_block_model("my_block", &model).unwrap();
+///
+/// let assets = AssetPack::at_path(&root);
+/// let diagnostics = Validator::new(&*assets).validate().unwrap();
+///
+/// // Neither the parent nor the texture are part of the pack.
+/// assert_eq!(diagnostics.len(), 2);
+/// assert_eq!(diagnostics[0].json_path, "/parent");
+/// assert_eq!(diagnostics[0].problem, Problem::MissingModelParent("block/cube_all".into()));
+/// assert_eq!(diagnostics[1].json_path, "/textures/all");
+/// assert_eq!(diagnostics[1].problem, Problem::MissingTexture("block/my_block".into()));
+/// # std::fs::remove_dir_all(&root).unwrap();
+/// ```
+pub struct Validator<'a> {
+    provider: &'a dyn ResourceProvider,
+    registries: Option<&'a Registries>,
+    schema_checks: bool,
+    strict: bool,
+    collect_errors: bool,
+    exists: HashMap<ResourceIdentifier<'static>, bool>,
+}
+
+impl<'a> Validator<'a> {
+    /// Returns a new [`Validator`] that checks the resources of the given
+    /// provider, such as the provider of an [`AssetPack`] (i.e.,
+    /// `&*asset_pack`).
+    ///
+    /// [`AssetPack`]: crate::api::AssetPack
+    pub fn new(provider: &'a dyn ResourceProvider) -> Self {
+        Self {
+            provider,
+            registries: None,
+            schema_checks: false,
+            strict: false,
+            collect_errors: false,
+            exists: HashMap::new(),
+        }
+    }
+
+    /// Sets the registries against which the IDs used by the pack are checked
+    /// and returns `self`.
+    ///
+    /// Only the registries that are known are checked. For example, the
+    /// properties of blockstates files are only checked if the registries
+    /// were loaded with a `blocks.json` report.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// use minecraft_assets::api::validate::{Problem, Validator};
+    ///
+    /// let mut registries = Registries::new();
+    /// registries.add_entries("block", ["stone"]);
+    /// registries.add_entries("item", ["stone", "stick"]);
+    ///
+    /// let mut pack = MemoryResourceProvider::new();
+    /// pack.insert(
+    ///     "data/minecraft/tags/items/rods.json",
+    ///     br#"{ "values": ["stick", "minecraft:blaze_rod"] }"#.to_vec(),
+    /// );
+    ///
+    /// let diagnostics = Validator::new(&pack)
+    ///     .with_registries(&registries)
+    ///     .validate()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(diagnostics.len(), 1);
+    /// assert_eq!(diagnostics[0].json_path, "/values/1");
+    /// assert_eq!(diagnostics[0].problem, Problem::UnknownTagEntry("minecraft:blaze_rod".into()));
+    /// ```
+    pub fn with_registries(mut self, registries: &'a Registries) -> Self {
+        self.registries = Some(registries);
+        self
+    }
+
+    /// Enables parsing every JSON resource of the pack, including those without
+    /// references to check, and returns `self`.
+    ///
+    /// Resources that do not match their schema are reported as
+    /// [`Problem::Invalid`]. Kinds of resources without a schema in this crate
+    /// only have to be valid JSON.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// use minecraft_assets::api::validate::{Problem, Validator};
+    ///
+    /// let mut pack = MemoryResourceProvider::new();
+    /// pack.insert("data/minecraft/recipes/stick.json", br#"{ "type": 42 }"#.to_vec());
+    ///
+    /// let diagnostics = Validator::new(&pack).validate().unwrap();
+    /// assert!(diagnostics.is_empty());
+    ///
+    /// let diagnostics = Validator::new(&pack).with_schema_checks().validate().unwrap();
+    /// assert_eq!(diagnostics.len(), 1);
+    /// assert!(matches!(diagnostics[0].problem, Problem::Invalid(_)));
+    /// ```
+    pub fn with_schema_checks(mut self) -> Self {
+        self.schema_checks = true;
+        self
+    }
+
+    /// Enables parsing resources in [strict mode], and enables [schema
+    /// checks](Self::with_schema_checks), and returns `self`.
+    ///
+    /// Fields that are not part of a resource's schema are reported as
+    /// [`Problem::UnknownField`], and values outside of the range accepted by
+    /// the game as [`Problem::OutOfRange`].
+    ///
+    /// [strict mode]: crate::schemas::strict
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// use minecraft_assets::api::validate::{Problem, Validator};
+    ///
+    /// let mut pack = MemoryResourceProvider::new();
+    /// pack.insert(
+    ///     "assets/minecraft/blockstates/stone.json",
+    ///     br#"{ "variants": { "": { "model": "block/stone", "y": 45, "uvLock": true } } }"#.to_vec(),
+    /// );
+    /// pack.insert("assets/minecraft/models/block/stone.json", b"{}".to_vec());
+    ///
+    /// let diagnostics = Validator::new(&pack).validate().unwrap();
+    /// assert!(diagnostics.is_empty());
+    ///
+    /// let diagnostics = Validator::new(&pack).with_strict().validate().unwrap();
+    /// let problems: Vec<_> = diagnostics
+    ///     .iter()
+    ///     .map(|d| (d.json_path.as_str(), &d.problem))
+    ///     .collect();
+    /// assert_eq!(
+    ///     problems,
+    ///     [
+    ///         ("/variants//uvLock", &Problem::UnknownField("uvLock".into())),
+    ///         ("/variants//y", &Problem::OutOfRange("45 is not 0, 90, 180, or 270".into())),
+    ///     ]
+    /// );
+    /// ```
+    pub fn with_strict(mut self) -> Self {
+        self.strict = true;
+        self.schema_checks = true;
+        self
+    }
+
+    /// Enables reporting the errors of resources that cannot be checked as
+    /// [`Problem::Unchecked`] instead of stopping at the first one, and
+    /// returns `self`.
+    ///
+    /// Resources that cannot be parsed are always reported as
+    /// [`Problem::Invalid`]. Other errors, like files that cannot be read
+    /// from a damaged archive, stop [`validate()`][Self::validate] unless
+    /// errors are collected. Errors that concern the whole pack, like failing
+    /// to list its resources, are still returned.
+    pub fn with_collected_errors(mut self) -> Self {
+        self.collect_errors = true;
+        self
+    }
+
+    /// Checks every blockstates file, model, biome, and `sounds.json` file in
+    /// every namespace of the pack, as well as every item definition and tag if
+    /// the validator was given [`Registries`], and every other JSON resource
+    /// with [schema checks](Self::with_schema_checks).
+    ///
+    /// The diagnostics are grouped by the kind of resource and sorted by the
+    /// resources' ids.
+    pub fn validate(&mut self) -> Result<Vec<Diagnostic>> {
+        let mut diagnostics = Vec::new();
+
+        let registry_kinds: &[ResourceKind] = match self.registries {
+            Some(_) => &REGISTRY_VALIDATED_KINDS,
+            None => &[],
+        };
+
+        let mut kinds: Vec<ResourceKind> = VALIDATED_KINDS
+            .iter()
+            .chain(registry_kinds)
+            .copied()
+            .collect();
+        if self.schema_checks {
+            let remaining: Vec<_> = ResourceKind::ALL
+                .iter()
+                .filter(|kind| kind.extension() == "json" && !kinds.contains(kind))
+                .copied()
+                .collect();
+            kinds.extend(remaining);
+        }
+
+        for kind in kinds {
+            let mut ids = self.provider.list(kind)?;
+            ids.sort_by(|a, b| (a.namespace(), a.path()).cmp(&(b.namespace(), b.path())));
+
+            for id in ids {
+                match self.validate_resource(&id) {
+                    Ok(resource_diagnostics) => diagnostics.extend(resource_diagnostics),
+                    Err(e) if self.collect_errors => diagnostics.push(Diagnostic {
+                        resource: id,
+                        json_path: String::new(),
+                        problem: Problem::Unchecked(e.to_string()),
+                    }),
+                    Err(e) => return Err(e),
+                }
+            }
+        }
+
+        Ok(diagnostics)
+    }
+
+    /// Checks the references of the resource with the given id.
+    ///
+    /// Resources of kinds other than those listed in the [module
+    /// documentation](self) have no references to check.
+    pub fn validate_resource(&mut self, id: &ResourceIdentifier) -> Result<Vec<Diagnostic>> {
+        let mut report = Report {
+            resource: id.to_owned(),
+            diagnostics: Vec::new(),
+        };
+
+        match id.kind() {
+            ResourceKind::BlockModel | ResourceKind::ItemModel => {
+                self.validate_model(id, &mut report)?
+            }
+            ResourceKind::BlockStates => self.validate_blockstates(id, &mut report)?,
+            ResourceKind::WorldGen_Biome => self.validate_biome(id, &mut report)?,
+            ResourceKind::Sounds => self.validate_sounds(id, &mut report)?,
+            ResourceKind::ItemDefinition => self.validate_item_definition(id, &mut report)?,
+            kind if kind.is_tag() => self.validate_tag(id, &mut report)?,
+            _ if self.schema_checks => self.check_schema(id, &mut report)?,
+            _ => {}
+        }
+
+        Ok(report.diagnostics)
+    }
+
+    fn validate_model(&mut self, id: &ResourceIdentifier, report: &mut Report) -> Result<()> {
+        let model: Model = match self.parse(id, report)? {
+            Some(model) => model,
+            None => return Ok(()),
+        };
+
+        if let Some(parent) = &model.parent {
+            let parent_id = ModelResolver::model_id(parent, id.kind());
+            if !ModelIdentifier::is_builtin(parent.as_str()) && !self.exists(parent_id)? {
+                report.push(
+                    pointer(&[&"parent"]),
+                    Problem::MissingModelParent(parent.as_str().to_string()),
+                );
+            }
+        }
+
+        if let Some(textures) = &model.textures {
+            let mut names: Vec<_> = textures.keys().collect();
+            names.sort();
+
+            for name in names {
+                let path = pointer(&[&"textures", name]);
+                self.check_texture(&textures[name], path, report)?;
+            }
+        }
+
+        for (i, element) in model.elements.iter().flatten().enumerate() {
+            let mut faces: Vec<_> = element.faces.iter().collect();
+            faces.sort_by_key(|(face, _)| **face as u8);
+
+            for (face, element_face) in faces {
+                let path = pointer(&[&"elements", &i, &"faces", &face_name(*face), &"texture"]);
+                self.check_texture(&element_face.texture, path, report)?;
+            }
+        }
+
+        for (i, case) in model.overrides.iter().flatten().enumerate() {
+            let model_id = ModelResolver::model_id(&case.model, id.kind());
+            if !self.exists(model_id)? {
+                let path = pointer(&[&"overrides", &i, &"model"]);
+                report.push(path, Problem::MissingModel(case.model.as_str().to_string()));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate_blockstates(&mut self, id: &ResourceIdentifier, report: &mut Report) -> Result<()> {
+        let states: BlockStates = match self.parse(id, report)? {
+            Some(states) => states,
+            None => return Ok(()),
+        };
+
+        let block = format!("{}:{}", id.namespace(), id.path());
+        if self.is_unknown("minecraft:block", &block) {
+            report.push(String::new(), Problem::UnknownBlock(block.clone()));
+        }
+        let properties = self
+            .registries
+            .and_then(|registries| registries.block_properties(&block));
+
+        match &states {
+            BlockStates::Variants { variants } => {
+                let mut names: Vec<_> = variants.keys().collect();
+                names.sort();
+
+                for name in names {
+                    let path = pointer(&[&"variants", name]);
+                    if let Some(properties) = properties {
+                        let values = name
+                            .split(',')
+                            .filter(|state| !state.is_empty())
+                            .map(|state| state.split_once('=').unwrap_or((state, "")));
+                        check_states(properties, values, &path, report);
+                    }
+                    self.check_variant(&variants[name], path, report)?;
+                }
+            }
+            BlockStates::Multipart { cases } => {
+                for (i, case) in cases.iter().enumerate() {
+                    if let (Some(properties), Some(when)) = (properties, &case.when) {
+                        for (j, condition) in when.conditions().iter().enumerate() {
+                            let path = match when {
+                                WhenClause::Single(_) => pointer(&[&"multipart", &i, &"when"]),
+                                WhenClause::Or { .. } => {
+                                    pointer(&[&"multipart", &i, &"when", &"OR", &j])
+                                }
+                                WhenClause::And { .. } => {
+                                    pointer(&[&"multipart", &i, &"when", &"AND", &j])
+                                }
+                            };
+
+                            let mut names: Vec<_> = condition.and.keys().collect();
+                            names.sort();
+                            for name in names {
+                                let path = format!("{}{}", path, pointer(&[name]));
+                                let values = match &condition.and[name] {
+                                    StateValue::Bool(value) => vec![value.to_string()],
+                                    StateValue::String(value) => value
+                                        .strip_prefix('!')
+                                        .unwrap_or(value)
+                                        .split('|')
+                                        .map(String::from)
+                                        .collect(),
+                                };
+                                let values =
+                                    values.iter().map(|value| (name.as_str(), value.as_str()));
+                                check_states(properties, values, &path, report);
+                            }
+                        }
+                    }
+
+                    let path = pointer(&[&"multipart", &i, &"apply"]);
+                    self.check_variant(&case.apply, path, report)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate_biome(&mut self, id: &ResourceIdentifier, report: &mut Report) -> Result<()> {
+        let biome: CustomeBiome = match self.parse(id, report)? {
+            Some(biome) => biome,
+            None => return Ok(()),
+        };
+
+        for (step, features) in biome.features.iter().enumerate() {
+            let path = pointer(&[&"features", &step]);
+            for (path, feature) in references(features, path) {
+                let feature_id = ResourceIdentifier::placed_feature(feature).to_owned();
+                if !self.exists(feature_id)? {
+                    report.push(path, Problem::UnknownPlacedFeature(String::from(feature)));
+                }
+            }
+        }
+
+        let steps = [
+            ("air", &biome.carvers.air),
+            ("liquid", &biome.carvers.liquid),
+        ];
+        for (step, carvers) in steps {
+            let path = pointer(&[&"carvers", &step]);
+            for (path, carver) in references(carvers, path) {
+                let carver_id = ResourceIdentifier::configured_carver(carver).to_owned();
+                if !self.exists(carver_id)? {
+                    report.push(path, Problem::UnknownConfiguredCarver(String::from(carver)));
+                }
+            }
+        }
+
+        let effects = &biome.effects;
+        let sounds = [
+            (
+                pointer(&[&"effects", &"ambient_sound"]),
+                effects.ambient_sound.as_ref(),
+            ),
+            (
+                pointer(&[&"effects", &"mood_sound", &"sound"]),
+                effects.mood_sound.as_ref().map(|mood| &mood.sound),
+            ),
+            (
+                pointer(&[&"effects", &"additions_sound", &"sound"]),
+                effects
+                    .additions_sound
+                    .as_ref()
+                    .map(|additions| &additions.sound),
+            ),
+            (
+                pointer(&[&"effects", &"music", &"sound"]),
+                effects.music.as_ref().map(|music| &music.sound),
+            ),
+        ];
+        for (path, sound) in sounds {
+            if let Some(sound) = sound {
+                if self.is_unknown("minecraft:sound_event", sound.as_str()) {
+                    report.push(path, Problem::UnknownSoundEvent(sound.as_str().to_string()));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate_sounds(&mut self, id: &ResourceIdentifier, report: &mut Report) -> Result<()> {
+        let sounds: SoundsJson = match self.parse(id, report)? {
+            Some(sounds) => sounds,
+            None => return Ok(()),
+        };
+
+        let mut events: Vec<_> = sounds.events.iter().collect();
+        events.sort_by_key(|(name, _)| *name);
+
+        for (event, definition) in events {
+            for (i, sound) in definition.sounds.iter().enumerate() {
+                if sound.kind() != SoundKind::File {
+                    continue;
+                }
+
+                let sound_id = ResourceIdentifier::sound(sound.name()).to_owned();
+                if !self.exists(sound_id)? {
+                    let path = match sound {
+                        Sound::Name(_) => pointer(&[event, &"sounds", &i]),
+                        Sound::Object(_) => pointer(&[event, &"sounds", &i, &"name"]),
+                    };
+                    report.push(path, Problem::MissingSoundFile(String::from(sound.name())));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate_item_definition(&self, id: &ResourceIdentifier, report: &mut Report) -> Result<()> {
+        if self.schema_checks {
+            self.check_schema(id, report)?;
+        }
+
+        let item = format!("{}:{}", id.namespace(), id.path());
+        if self.is_unknown("minecraft:item", &item) {
+            report.push(String::new(), Problem::UnknownItem(item));
+        }
+
+        Ok(())
+    }
+
+    fn validate_tag(&mut self, id: &ResourceIdentifier, report: &mut Report) -> Result<()> {
+        let registry = tag_registry(id.kind())
+            .filter(|(registry, _)| self.registries.is_some_and(|r| r.has_registry(registry)));
+        if registry.is_none() && !self.schema_checks {
+            return Ok(());
+        }
+
+        let tag: Tag = match self.parse(id, report)? {
+            Some(tag) => tag,
+            None => return Ok(()),
+        };
+        let (registry, element_kind) = match registry {
+            Some(registry) => registry,
+            None => return Ok(()),
+        };
+
+        for (i, entry) in tag.values.iter().enumerate() {
+            if entry.is_tag() || !entry.is_required() || !self.is_unknown(registry, entry.id()) {
+                continue;
+            }
+
+            // Data-driven registries also contain the pack's own resources.
+            if let Some(kind) = element_kind {
+                let element_id = ResourceIdentifier::new_owned(kind, entry.id().to_string());
+                if self.exists(element_id)? {
+                    continue;
+                }
+            }
+
+            let path = match entry {
+                TagEntry::Id(_) => pointer(&[&"values", &i]),
+                TagEntry::Object { .. } => pointer(&[&"values", &i, &"id"]),
+            };
+            report.push(path, Problem::UnknownTagEntry(entry.id().to_string()));
+        }
+
+        Ok(())
+    }
+
+    fn check_texture(
+        &mut self,
+        texture: &Texture,
+        path: String,
+        report: &mut Report,
+    ) -> Result<()> {
+        // References to other texture variables are not files.
+        if let Some(location) = texture.location() {
+            if !self.exists(ResourceIdentifier::texture(location).to_owned())? {
+                report.push(path, Problem::MissingTexture(String::from(location)));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_variant(
+        &mut self,
+        variant: &Variant,
+        path: String,
+        report: &mut Report,
+    ) -> Result<()> {
+        let models = variant.models();
+        for (i, properties) in models.iter().enumerate() {
+            let path = match variant {
+                Variant::Single(_) => format!("{}/model", path),
+                Variant::Multiple(_) => format!("{}/{}/model", path, i),
+            };
+
+            let model = &properties.model;
+            let model_id = ModelResolver::model_id(model, ResourceKind::BlockModel);
+            if !self.exists(model_id)? {
+                report.push(path, Problem::MissingModel(model.as_str().to_string()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Parses the given resource with the schema of its kind, or as any JSON
+    /// value if it has none.
+    fn check_schema(&self, id: &ResourceIdentifier, report: &mut Report) -> Result<()> {
+        macro_rules! check {
+            ($($kind:ident => $schema:ty,)*) => {
+                match id.kind() {
+                    $(ResourceKind::$kind => {
+                        self.parse::<$schema>(id, report)?;
+                    })*
+                    _ => {
+                        self.parse::<serde_json::Value>(id, report)?;
+                    }
+                }
+            };
+        }
+
+        check! {
+            TextureMeta => texture::Texture,
+            WorldGen_MultiNoiseBiomeSourceParameterList => MultiNoiseBiomeSourceParameterList,
+            LootTable => LootTable,
+            Recipe => Recipe,
+            Advancement => Advancement,
+            Predicate => Predicate,
+            ItemModifier => ItemModifier,
+            DamageType => DamageType,
+            ChatType => ChatType,
+            TrimMaterial => TrimMaterial,
+            TrimPattern => TrimPattern,
+            Enchantment => Enchantment,
+            PaintingVariant => PaintingVariant,
+            WolfVariant => WolfVariant,
+            BannerPattern => BannerPattern,
+            JukeboxSong => JukeboxSong,
+            Language => LanguageFile,
+            Font => Font,
+            Particle => Particle,
+            Atlas => Atlas,
+            CoreShader => ShaderProgram,
+            ProgramShader => ShaderProgram,
+            PostChain => PostChain,
+            PostEffect => PostEffect,
+            GpuWarnlist => GpuWarnlist,
+            RegionalCompliancies => RegionalCompliancies,
+            Equipment => Equipment,
+            ItemDefinition => ItemDefinition,
+        }
+
+        Ok(())
+    }
+
+    /// Parses the given resource, reporting it as invalid if it cannot be
+    /// parsed.
+    ///
+    /// In strict mode, the violations of the strict mode are reported as well.
+    fn parse<T>(&self, id: &ResourceIdentifier, report: &mut Report) -> Result<Option<T>>
+    where
+        T: DeserializeOwned + Serialize + CheckRanges,
+    {
+        let bytes = self.provider.load_resource(id)?;
+        let parsed = if self.strict {
+            strict::check(&bytes)
+        } else {
+            serde_json::from_slice(&bytes).map(|value| (value, Vec::new()))
+        };
+
+        match parsed {
+            Ok((value, violations)) => {
+                for violation in violations {
+                    let problem = match &violation {
+                        Violation::UnknownField { json_path } => {
+                            let field = json_path.rsplit('/').next().unwrap_or_default();
+                            Problem::UnknownField(field.replace("~1", "/").replace("~0", "~"))
+                        }
+                        Violation::InvalidIdentifier { error, .. } => {
+                            Problem::InvalidIdentifier(error.to_string())
+                        }
+                        Violation::OutOfRange { message, .. } => {
+                            Problem::OutOfRange(message.clone())
+                        }
+                    };
+                    report.push(violation.json_path().to_string(), problem);
+                }
+                Ok(Some(value))
+            }
+            Err(e) => {
+                report.push(String::new(), Problem::Invalid(e.to_string()));
+                Ok(None)
+            }
+        }
+    }
+
+    /// Returns `true` if the registry with the given name is known and does
+    /// not contain the given ID.
+    fn is_unknown(&self, registry: &str, id: &str) -> bool {
+        self.registries.is_some_and(|registries| {
+            registries.has_registry(registry) && !registries.contains(registry, id)
+        })
+    }
+
+    fn exists(&mut self, id: ResourceIdentifier<'static>) -> Result<bool> {
+        if let Some(exists) = self.exists.get(&id) {
+            return Ok(*exists);
+        }
+
+        let exists = match self.provider.load_resource(&id) {
+            Ok(_) => true,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => false,
+            Err(e) => return Err(e.into()),
+        };
+
+        self.exists.insert(id, exists);
+        Ok(exists)
+    }
+}
+
+/// The diagnostics of a single resource.
+struct Report {
+    resource: ResourceIdentifier<'static>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Report {
+    fn push(&mut self, json_path: String, problem: Problem) {
+        self.diagnostics.push(Diagnostic {
+            resource: self.resource.clone(),
+            json_path,
+            problem,
+        });
+    }
+}
+
+/// Reports the given property names and values that are not states of a block
+/// with the given properties.
+fn check_states<'s>(
+    properties: &HashMap<String, Vec<String>>,
+    states: impl Iterator<Item = (&'s str, &'s str)>,
+    path: &str,
+    report: &mut Report,
+) {
+    for (name, value) in states {
+        let valid = properties
+            .get(name)
+            .is_some_and(|values| values.iter().any(|v| v == value));
+        if !valid {
+            let state = format!("{}={}", name, value);
+            report.push(path.to_string(), Problem::UnknownBlockState(state));
+        }
+    }
+}
+
+/// Returns the [JSON pointer] made up of the given reference tokens.
+///
+/// [JSON pointer]: <https://datatracker.ietf.org/doc/html/rfc6901>
+fn pointer(tokens: &[&dyn fmt::Display]) -> String {
+    let mut path = String::new();
+    for token in tokens {
+        path.push('/');
+        path.push_str(&token.to_string().replace('~', "~0").replace('/', "~1"));
+    }
+    path
+}
+
+/// Returns the ids referenced by the given list, excluding tags and inline
+/// definitions, along with their JSON pointers.
+fn references<T>(
+    list: &MaybeReferenceList<T>,
+    path: String,
+) -> impl Iterator<Item = (String, &str)> + '_ {
+    list.iter().enumerate().filter_map(move |(i, value)| {
+        let path = match list {
+            MaybeReferenceList::Single(_) => path.clone(),
+            MaybeReferenceList::Multiple(_) => format!("{}/{}", path, i),
+        };
+
+        match value {
+            MaybeReference::Reference(id) if !value.is_tag() => Some((path, id.as_str())),
+            _ => None,
+        }
+    })
+}
+
+fn face_name(face: BlockFace) -> &'static str {
+    match face {
+        BlockFace::Down => "down",
+        BlockFace::Up => "up",
+        BlockFace::North => "north",
+        BlockFace::South => "south",
+        BlockFace::West => "west",
+        BlockFace::East => "east",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::api::{EnumerateResources, LoadResource, MemoryResourceProvider, ResourceCategory};
+
+    /// A provider that fails to read the resources whose path contains
+    /// `damaged`.
+    struct DamagedProvider(MemoryResourceProvider);
+
+    impl EnumerateResources for DamagedProvider {
+        fn enumerate_resources(
+            &self,
+            namespace: &str,
+            kind: ResourceKind,
+        ) -> Result<Vec<ResourceIdentifier<'static>>, io::Error> {
+            self.0.enumerate_resources(namespace, kind)
+        }
+
+        fn enumerate_namespaces(
+            &self,
+            category: ResourceCategory,
+        ) -> Result<Vec<String>, io::Error> {
+            self.0.enumerate_namespaces(category)
+        }
+    }
+
+    impl LoadResource for DamagedProvider {
+        fn load_resource(&self, id: &ResourceIdentifier) -> Result<Vec<u8>, io::Error> {
+            if id.path().contains("damaged") {
+                return Err(io::Error::other("corrupt entry"));
+            }
+            self.0.load_resource(id)
+        }
+    }
+
+    #[test]
+    fn collects_errors_of_unreadable_resources() {
+        let mut pack = MemoryResourceProvider::new();
+        pack.insert("assets/minecraft/models/block/damaged.json", b"{}".to_vec());
+        pack.insert(
+            "assets/minecraft/models/block/stone.json",
+            br#"{ "parent": "block/missing" }"#.to_vec(),
+        );
+        let pack = DamagedProvider(pack);
+
+        assert!(Validator::new(&pack).validate().is_err());
+
+        let diagnostics = Validator::new(&pack)
+            .with_collected_errors()
+            .validate()
+            .unwrap();
+        let problems: Vec<_> = diagnostics
+            .iter()
+            .map(|d| (d.resource.path(), &d.problem))
+            .collect();
+        assert_eq!(
+            problems,
+            [
+                ("damaged", &Problem::Unchecked("corrupt entry".into())),
+                (
+                    "stone",
+                    &Problem::MissingModelParent("block/missing".into())
+                ),
+            ]
+        );
+    }
+}
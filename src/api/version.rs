@@ -0,0 +1,200 @@
+use std::fmt;
+
+use crate::api::{ResourceCategory, ResourceKind};
+
+/// The version of the game that a pack targets, given by the pack formats of
+/// its resource pack and data pack halves.
+///
+/// The layout of packs changes between versions of the game. For example,
+/// 1.21 renamed the plural directories of data packs (e.g., `loot_tables/`) to
+/// singular ones (e.g., `loot_table/`), and some kinds of resources were only
+/// added or removed in certain versions. A [`TargetVersion`] answers in which
+/// directory a kind of resource is stored, and whether it exists at all.
+///
+/// Providers like the [`FileSystemResourceProvider`] use the layout of a
+/// target version when given one through their `with_target_version()`
+/// method, and the [`AssetPack`] refuses to load kinds of resources that do
+/// not exist in its target version. Without a target version, the directories
+/// returned by [`ResourceKind::directory()`] are used.
+///
+/// [`FileSystemResourceProvider`]: crate::api::FileSystemResourceProvider
+/// [`AssetPack`]: crate::api::AssetPack
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::api::*;
+/// let old = TargetVersion::V1_20_4;
+/// let new = TargetVersion::V1_21_4;
+///
+/// assert_eq!(old.directory(ResourceKind::LootTable), "loot_tables");
+/// assert_eq!(new.directory(ResourceKind::LootTable), "loot_table");
+/// assert_eq!(new.directory(ResourceKind::BlockTag), "tags/block");
+///
+/// assert!(old.supports(ResourceKind::PostChain));
+/// assert!(!new.supports(ResourceKind::PostChain));
+/// assert!(new.supports(ResourceKind::ItemDefinition));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TargetVersion {
+    resource_pack_format: u32,
+    data_pack_format: u32,
+}
+
+impl TargetVersion {
+    /// Minecraft 1.12.2, with resource pack format 3.
+    ///
+    /// Data packs were only added in 1.13, so the data pack format is given as
+    /// 3 too, which orders it before every version that has them.
+    pub const V1_12_2: Self = Self::new(3, 3);
+
+    /// Minecraft 1.13, with resource pack format 4 and data pack format 4.
+    pub const V1_13: Self = Self::new(4, 4);
+
+    /// Minecraft 1.18, with resource pack format 8 and data pack format 8.
+    pub const V1_18: Self = Self::new(8, 8);
+
+    /// Minecraft 1.19, with resource pack format 9 and data pack format 10.
+    pub const V1_19: Self = Self::new(9, 10);
+
+    /// Minecraft 1.19.4, with resource pack format 13 and data pack format 12.
+    pub const V1_19_4: Self = Self::new(13, 12);
+
+    /// Minecraft 1.20, with resource pack format 15 and data pack format 15.
+    pub const V1_20: Self = Self::new(15, 15);
+
+    /// Minecraft 1.20.4, with resource pack format 22 and data pack format 26.
+    pub const V1_20_4: Self = Self::new(22, 26);
+
+    /// Minecraft 1.20.5, with resource pack format 32 and data pack format 41.
+    pub const V1_20_5: Self = Self::new(32, 41);
+
+    /// Minecraft 1.21, with resource pack format 34 and data pack format 48.
+    pub const V1_21: Self = Self::new(34, 48);
+
+    /// Minecraft 1.21.2, with resource pack format 42 and data pack format 57.
+    pub const V1_21_2: Self = Self::new(42, 57);
+
+    /// Minecraft 1.21.4, with resource pack format 46 and data pack format 61.
+    pub const V1_21_4: Self = Self::new(46, 61);
+
+    /// The most recent version known to this crate.
+    pub const LATEST: Self = Self::V1_21_4;
+
+    /// Returns a new [`TargetVersion`] with the given pack formats.
+    pub const fn new(resource_pack_format: u32, data_pack_format: u32) -> Self {
+        Self {
+            resource_pack_format,
+            data_pack_format,
+        }
+    }
+
+    /// Returns the pack format of resource packs (i.e., `assets/`).
+    pub fn resource_pack_format(&self) -> u32 {
+        self.resource_pack_format
+    }
+
+    /// Returns the pack format of data packs (i.e., `data/`).
+    pub fn data_pack_format(&self) -> u32 {
+        self.data_pack_format
+    }
+
+    /// Returns the pack format used by resources of the given category.
+    pub fn pack_format(&self, category: ResourceCategory) -> u32 {
+        match category {
+            ResourceCategory::Assets => self.resource_pack_format,
+            ResourceCategory::Data => self.data_pack_format,
+        }
+    }
+
+    /// Returns the path relative to `assets/<namespace>/` or
+    /// `data/<namespace>/` in which resources of the given kind reside in this
+    /// version.
+    ///
+    /// See [`ResourceKind::directory()`].
+    pub fn directory(&self, kind: ResourceKind) -> &'static str {
+        // Data pack format 45 (during 1.21) switched to singular names.
+        if self.data_pack_format >= 45 {
+            match kind {
+                ResourceKind::LootTable => return "loot_table",
+                ResourceKind::Recipe => return "recipe",
+                ResourceKind::Advancement => return "advancement",
+                ResourceKind::Predicate => return "predicate",
+                ResourceKind::ItemModifier => return "item_modifier",
+                ResourceKind::Structure => return "structure",
+                ResourceKind::Function => return "function",
+                ResourceKind::BlockTag => return "tags/block",
+                ResourceKind::ItemTag => return "tags/item",
+                ResourceKind::EntityTypeTag => return "tags/entity_type",
+                ResourceKind::FluidTag => return "tags/fluid",
+                ResourceKind::FunctionTag => return "tags/function",
+                ResourceKind::GameEventTag => return "tags/game_event",
+                _ => {}
+            }
+        }
+
+        match kind {
+            ResourceKind::Equipment if self.resource_pack_format < 46 => "models/equipment",
+            _ => kind.directory(),
+        }
+    }
+
+    /// Returns `true` if resources of the given kind exist in this version.
+    ///
+    /// Kinds that were added before 1.19 are assumed to always exist, except
+    /// for language files, whose format changed in 1.13.
+    pub fn supports(&self, kind: ResourceKind) -> bool {
+        let added = match kind {
+            ResourceKind::ChatType
+            | ResourceKind::PaintingVariantTag
+            | ResourceKind::BannerPatternTag => Self::V1_19,
+            ResourceKind::DamageType
+            | ResourceKind::DamageTypeTag
+            | ResourceKind::TrimMaterial
+            | ResourceKind::TrimPattern => Self::V1_19_4,
+            ResourceKind::UnihexArchive => Self::V1_20,
+            ResourceKind::Language => Self::V1_13,
+            ResourceKind::LegacyLanguage => return self.resource_pack_format < 4,
+            ResourceKind::GlyphSizes => return self.resource_pack_format < 15,
+            ResourceKind::WolfVariant | ResourceKind::BannerPattern => Self::V1_20_5,
+            ResourceKind::Enchantment
+            | ResourceKind::EnchantmentTag
+            | ResourceKind::PaintingVariant
+            | ResourceKind::JukeboxSong => Self::V1_21,
+            ResourceKind::PostEffect | ResourceKind::Equipment => Self::V1_21_2,
+            ResourceKind::ProgramShader | ResourceKind::PostChain => {
+                return self.resource_pack_format < 42
+            }
+            ResourceKind::ItemDefinition => Self::V1_21_4,
+            _ => return true,
+        };
+
+        let category = kind.category();
+        self.pack_format(category) >= added.pack_format(category)
+    }
+}
+
+impl Default for TargetVersion {
+    fn default() -> Self {
+        Self::LATEST
+    }
+}
+
+impl fmt::Display for TargetVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "resource pack format {} / data pack format {}",
+            self.resource_pack_format, self.data_pack_format
+        )
+    }
+}
+
+/// Returns the directory of the given kind in the given version, or the
+/// default directory without a target version.
+pub(crate) fn kind_directory(kind: ResourceKind, version: Option<TargetVersion>) -> &'static str {
+    match version {
+        Some(version) => version.directory(kind),
+        None => kind.directory(),
+    }
+}
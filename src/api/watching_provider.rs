@@ -0,0 +1,264 @@
+use std::{
+    io::{self, Read},
+    path::{Path, PathBuf},
+    sync::{
+        mpsc::{self, Receiver},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use notify::{
+    event::{ModifyKind, RenameMode},
+    Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher,
+};
+
+use crate::api::{
+    EnumerateResources, FileSystemResourceProvider, LoadResource, ResourceCategory,
+    ResourceIdentifier, ResourceKind,
+};
+
+/// A [`FileSystemResourceProvider`] that watches the pack's directory for
+/// changes, so that tools can live-reload textures, models, and other
+/// resources while they are being edited.
+///
+/// Changes are reported as [`ResourceChange`]s through the [`ResourceChanges`]
+/// handle returned by [`changes()`][Self::changes]. The handle can be kept
+/// after the provider is moved into an [`AssetPack`], and the reported ids can
+/// be passed to [`CachedAssetPack::invalidate()`].
+///
+/// This type requires the `notify` feature.
+///
+/// [`AssetPack`]: crate::api::AssetPack
+/// [`CachedAssetPack::invalidate()`]: crate::api::CachedAssetPack::invalidate
+///
+/// # Example
+///
+/// ```no_run
+/// # use minecraft_assets::api::*;
+/// let provider = WatchingResourceProvider::new("~/.minecraft/resourcepacks/my_pack/").unwrap();
+/// let changes = provider.changes();
+///
+/// let assets = CachedAssetPack::new(AssetPack::new(provider), 256);
+///
+/// loop {
+///     // Render a frame with `assets`...
+///
+///     for change in changes.poll() {
+///         if let Some(id) = &change.id {
+///             assets.invalidate(id);
+///         }
+///     }
+/// }
+/// ```
+pub struct WatchingResourceProvider {
+    provider: FileSystemResourceProvider,
+    changes: ResourceChanges,
+    // Stops watching when dropped.
+    _watcher: RecommendedWatcher,
+}
+
+impl WatchingResourceProvider {
+    /// Returns a new provider that reads data from the given directory and
+    /// starts watching it for changes.
+    ///
+    /// The provided `root_dir` should be the directory that contains the
+    /// `assets/` and/or `data/` directories.
+    pub fn new(root_dir: impl AsRef<Path>) -> Result<Self, notify::Error> {
+        let provider = FileSystemResourceProvider::new(root_dir);
+        let root = provider.root().to_path_buf();
+
+        let (sender, receiver) = mpsc::channel();
+        let mut watcher = {
+            let root = root.clone();
+            notify::recommended_watcher(move |event: notify::Result<Event>| {
+                // Errors of the watcher itself are not actionable.
+                if let Ok(event) = event {
+                    for change in changes_from_event(&root, event) {
+                        let _ = sender.send(change);
+                    }
+                }
+            })?
+        };
+        watcher.watch(&root, RecursiveMode::Recursive)?;
+
+        Ok(Self {
+            provider,
+            changes: ResourceChanges {
+                receiver: Arc::new(Mutex::new(receiver)),
+            },
+            _watcher: watcher,
+        })
+    }
+
+    /// Returns the directory that resources are read from.
+    pub fn root(&self) -> &Path {
+        self.provider.root()
+    }
+
+    /// Returns a handle that receives the changes to the pack.
+    ///
+    /// All handles share the same queue of changes, so each change is
+    /// received by only one of them.
+    pub fn changes(&self) -> ResourceChanges {
+        self.changes.clone()
+    }
+}
+
+impl EnumerateResources for WatchingResourceProvider {
+    fn enumerate_resources(
+        &self,
+        namespace: &str,
+        kind: ResourceKind,
+    ) -> Result<Vec<ResourceIdentifier<'static>>, io::Error> {
+        self.provider.enumerate_resources(namespace, kind)
+    }
+
+    fn enumerate_namespaces(&self, category: ResourceCategory) -> Result<Vec<String>, io::Error> {
+        self.provider.enumerate_namespaces(category)
+    }
+}
+
+impl LoadResource for WatchingResourceProvider {
+    fn load_resource(&self, id: &ResourceIdentifier) -> Result<Vec<u8>, io::Error> {
+        self.provider.load_resource(id)
+    }
+
+    fn load_root_file(&self, name: &str) -> Result<Vec<u8>, io::Error> {
+        self.provider.load_root_file(name)
+    }
+
+    fn open_resource(&self, id: &ResourceIdentifier) -> Result<Box<dyn Read + '_>, io::Error> {
+        self.provider.open_resource(id)
+    }
+}
+
+/// A handle to the changes reported by a [`WatchingResourceProvider`].
+///
+/// This type requires the `notify` feature.
+#[derive(Clone)]
+pub struct ResourceChanges {
+    receiver: Arc<Mutex<Receiver<ResourceChange>>>,
+}
+
+impl ResourceChanges {
+    /// Returns every change that has been reported since the last call,
+    /// without blocking.
+    pub fn poll(&self) -> Vec<ResourceChange> {
+        self.lock().try_iter().collect()
+    }
+
+    /// Blocks until a change is reported or the timeout expires.
+    ///
+    /// Returns `None` on timeout, or if the provider was dropped.
+    pub fn wait(&self, timeout: Duration) -> Option<ResourceChange> {
+        self.lock().recv_timeout(timeout).ok()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Receiver<ResourceChange>> {
+        self.receiver.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// A change to a file in a pack watched by a [`WatchingResourceProvider`].
+///
+/// This type requires the `notify` feature.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceChange {
+    /// What happened to the file.
+    pub kind: ChangeKind,
+
+    /// The path of the file, relative to the root of the pack.
+    pub path: PathBuf,
+
+    /// The resource stored in the file, or `None` if it is not a resource
+    /// (e.g., `pack.mcmeta` or a directory).
+    pub id: Option<ResourceIdentifier<'static>>,
+}
+
+/// What happened to a file in a [`ResourceChange`].
+///
+/// This type requires the `notify` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChangeKind {
+    /// The file was created or moved into the pack.
+    Created,
+
+    /// The contents of the file changed.
+    Modified,
+
+    /// The file was removed or moved out of the pack.
+    Removed,
+}
+
+fn changes_from_event(root: &Path, event: Event) -> Vec<ResourceChange> {
+    let change = |kind: ChangeKind, path: &Path| {
+        let path = path.strip_prefix(root).ok()?.to_path_buf();
+        let id = path
+            .to_str()
+            .map(|path| path.replace(std::path::MAIN_SEPARATOR, "/"))
+            .and_then(|path| ResourceIdentifier::from_pack_path(&path));
+
+        Some(ResourceChange { kind, path, id })
+    };
+
+    let kinds: &[ChangeKind] = match event.kind {
+        EventKind::Create(_) => &[ChangeKind::Created],
+        EventKind::Remove(_) => &[ChangeKind::Removed],
+        EventKind::Modify(ModifyKind::Name(RenameMode::From)) => &[ChangeKind::Removed],
+        EventKind::Modify(ModifyKind::Name(RenameMode::To)) => &[ChangeKind::Created],
+        EventKind::Modify(ModifyKind::Name(RenameMode::Both)) => {
+            // The paths are the old path followed by the new path.
+            &[ChangeKind::Removed, ChangeKind::Created]
+        }
+        EventKind::Modify(_) | EventKind::Any | EventKind::Other => &[ChangeKind::Modified],
+        EventKind::Access(_) => &[],
+    };
+
+    event
+        .paths
+        .iter()
+        .enumerate()
+        .filter_map(|(i, path)| change(kinds.get(i).or(kinds.last()).copied()?, path))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rename_is_removal_and_creation() {
+        let root = Path::new("pack");
+        let event = Event::new(EventKind::Modify(ModifyKind::Name(RenameMode::Both)))
+            .add_path(root.join("assets/minecraft/textures/block/old.png"))
+            .add_path(root.join("assets/minecraft/textures/block/new.png"));
+
+        let changes = changes_from_event(root, event);
+
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].kind, ChangeKind::Removed);
+        assert_eq!(
+            changes[0].id,
+            Some(ResourceIdentifier::texture("block/old").to_owned())
+        );
+        assert_eq!(changes[1].kind, ChangeKind::Created);
+        assert_eq!(
+            changes[1].id,
+            Some(ResourceIdentifier::texture("block/new").to_owned())
+        );
+    }
+
+    #[test]
+    fn non_resource_files_have_no_id() {
+        let root = Path::new("pack");
+        let event =
+            Event::new(EventKind::Modify(ModifyKind::Any)).add_path(root.join("pack.mcmeta"));
+
+        let changes = changes_from_event(root, event);
+
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].path, Path::new("pack.mcmeta"));
+        assert_eq!(changes[0].id, None);
+    }
+}
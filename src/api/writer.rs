@@ -0,0 +1,213 @@
+use std::{
+    ops::{Deref, DerefMut},
+    path::Path,
+};
+
+use serde::Serialize;
+
+use crate::{
+    api::{FileSystemResourceProvider, ResourceIdentifier, ResourceKind, Result, WriteResource},
+    schemas::{
+        canonical, texture::Texture, Advancement, BlockStates, LanguageFile, LootTable, Model,
+        PackMeta, Recipe, Tag,
+    },
+};
+
+/// Top-level API for writing Minecraft assets and data, i.e., for generating
+/// resource packs and data packs.
+///
+/// This is the counterpart of [`AssetPack`][crate::api::AssetPack]: every
+/// value is serialized and written to the path that the corresponding loader
+/// reads it from.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::api::*;
+/// use minecraft_assets::schemas::{Model, PackMeta};
+///
+/// let root = std::env::temp_dir().join("minecraft-assets-writer-example");
+/// let mut writer = ResourceWriter::at_path(&root);
+///
+/// let meta: PackMeta = serde_json::from_str(r#"
+///     { "pack": { "pack_format": 8, "description": "My pack" } }
+/// "#).unwrap();
+/// writer.write_pack_meta(&meta).unwrap();
+///
+/// let model = Model {
+///     parent: Some("block/cube_all".parse().unwrap()),
+///     ..Default::default()
+/// };
+/// writer.write_block_model("my_block", &model).unwrap();
+///
+/// // The written pack can be read back with an `AssetPack`.
+/// let assets = AssetPack::at_path(&root);
+/// assert_eq!(assets.load_pack_meta().unwrap(), meta);
+/// assert_eq!(assets.load_block_model("my_block").unwrap(), model);
+/// # std::fs::remove_dir_all(&root).unwrap();
+/// ```
+pub struct ResourceWriter<'a> {
+    writer: Box<dyn WriteResource + 'a>,
+    canonical: bool,
+}
+
+impl<'a> ResourceWriter<'a> {
+    /// Returns a new [`ResourceWriter`] that writes data into the given
+    /// directory, creating it and any subdirectories as needed.
+    ///
+    /// The provided `root_dir` will be the directory that contains the
+    /// `assets/` and/or `data/` directories.
+    pub fn at_path(root_dir: impl AsRef<Path>) -> Self {
+        Self::new(FileSystemResourceProvider::new(root_dir))
+    }
+
+    /// Returns a new [`ResourceWriter`] that uses the given [`WriteResource`].
+    ///
+    /// The writer may also be a mutable reference, so that the resources can
+    /// be read back from it afterwards.
+    pub fn new<W>(writer: W) -> Self
+    where
+        W: WriteResource + 'a,
+    {
+        Self {
+            writer: Box::new(writer),
+            canonical: false,
+        }
+    }
+
+    /// Makes the writer serialize values in the [canonical] format of the
+    /// game's data generator, instead of `serde_json`'s pretty-printed format,
+    /// and returns `self`.
+    ///
+    /// [canonical]: crate::schemas::canonical
+    pub fn with_canonical_json(mut self) -> Self {
+        self.canonical = true;
+        self
+    }
+
+    /// Writes the given [`PackMeta`] to the `pack.mcmeta` file at the root of
+    /// the pack.
+    pub fn write_pack_meta(&mut self, meta: &PackMeta) -> Result<()> {
+        let data = self.to_json(meta)?;
+        Ok(self.writer.write_root_file("pack.mcmeta", &data)?)
+    }
+
+    /// Writes the [`BlockStates`] of the block with the provided id.
+    pub fn write_blockstates(&mut self, block_id: &str, states: &BlockStates) -> Result<()> {
+        self.write_json(&ResourceIdentifier::blockstates(block_id), states)
+    }
+
+    /// Writes the block [`Model`] identified by the given name or path.
+    pub fn write_block_model(&mut self, model: &str, value: &Model) -> Result<()> {
+        self.write_json(&ResourceIdentifier::block_model(model), value)
+    }
+
+    /// Writes the item [`Model`] identified by the given name or path.
+    pub fn write_item_model(&mut self, model: &str, value: &Model) -> Result<()> {
+        self.write_json(&ResourceIdentifier::item_model(model), value)
+    }
+
+    /// Writes the [`Texture`] metadata (`.png.mcmeta`) of the texture located
+    /// at the given path.
+    pub fn write_texture_meta(&mut self, path: &str, meta: &Texture) -> Result<()> {
+        self.write_json(&ResourceIdentifier::texture_meta(path), meta)
+    }
+
+    /// Writes the given [`LanguageFile`] for the language with the given code.
+    pub fn write_language(&mut self, code: &str, language: &LanguageFile) -> Result<()> {
+        self.write_json(&ResourceIdentifier::language(code), language)
+    }
+
+    /// Writes the given [`LanguageFile`] for the language with the given code
+    /// as a legacy `.lang` file, used before 1.13.
+    ///
+    /// See [`LanguageFile::to_legacy()`].
+    pub fn write_legacy_language(&mut self, code: &str, language: &LanguageFile) -> Result<()> {
+        self.write_text(
+            &ResourceIdentifier::legacy_language(code),
+            &language.to_legacy(),
+        )
+    }
+
+    /// Writes the [`LootTable`] located at the given path.
+    pub fn write_loot_table(&mut self, path: &str, loot_table: &LootTable) -> Result<()> {
+        self.write_json(&ResourceIdentifier::loot_table(path), loot_table)
+    }
+
+    /// Writes the [`Recipe`] located at the given path.
+    pub fn write_recipe(&mut self, path: &str, recipe: &Recipe) -> Result<()> {
+        self.write_json(&ResourceIdentifier::recipe(path), recipe)
+    }
+
+    /// Writes the [`Advancement`] located at the given path.
+    pub fn write_advancement(&mut self, path: &str, advancement: &Advancement) -> Result<()> {
+        self.write_json(&ResourceIdentifier::advancement(path), advancement)
+    }
+
+    /// Writes the [`Tag`] of the given kind and id.
+    pub fn write_tag(&mut self, kind: ResourceKind, tag_id: &str, tag: &Tag) -> Result<()> {
+        self.write_json(&ResourceIdentifier::tag(kind, tag_id), tag)
+    }
+
+    /// Serializes the given value as pretty-printed (or canonical) JSON and
+    /// writes it as the resource referenced by the given
+    /// [`ResourceIdentifier`].
+    ///
+    /// This can be used to write any JSON resource, including those without a
+    /// dedicated method.
+    pub fn write_json<T>(&mut self, id: &ResourceIdentifier, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        let data = self.to_json(value)?;
+        self.write_bytes(id, &data)
+    }
+
+    /// Writes the given text as the resource referenced by the given
+    /// [`ResourceIdentifier`], such as a function or a shader source.
+    pub fn write_text(&mut self, id: &ResourceIdentifier, text: &str) -> Result<()> {
+        self.write_bytes(id, text.as_bytes())
+    }
+
+    /// Writes the given raw bytes as the resource referenced by the given
+    /// [`ResourceIdentifier`], such as a texture.
+    pub fn write_bytes(&mut self, id: &ResourceIdentifier, data: &[u8]) -> Result<()> {
+        Ok(self.writer.write_resource(id, data)?)
+    }
+
+    fn to_json<T>(&self, value: &T) -> Result<Vec<u8>>
+    where
+        T: Serialize + ?Sized,
+    {
+        to_json(value, self.canonical)
+    }
+}
+
+/// Serializes the given value as pretty-printed JSON with a trailing newline,
+/// or as canonical JSON.
+pub(crate) fn to_json<T>(value: &T, canonical: bool) -> Result<Vec<u8>>
+where
+    T: Serialize + ?Sized,
+{
+    if canonical {
+        return Ok(canonical::to_vec(value)?);
+    }
+
+    let mut data = serde_json::to_vec_pretty(value)?;
+    data.push(b'\n');
+    Ok(data)
+}
+
+impl<'a> Deref for ResourceWriter<'a> {
+    type Target = dyn WriteResource + 'a;
+
+    fn deref(&self) -> &Self::Target {
+        &*self.writer
+    }
+}
+
+impl<'a> DerefMut for ResourceWriter<'a> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut *self.writer
+    }
+}
@@ -0,0 +1,407 @@
+use std::{
+    collections::BTreeSet,
+    fs,
+    io::{self, Read, Seek},
+    path::Path,
+    sync::Mutex,
+};
+
+use zip::{result::ZipError, ZipArchive};
+
+use crate::api::{
+    version::kind_directory, EnumerateResources, LoadResource, ResourceCategory,
+    ResourceIdentifier, ResourceKind, TargetVersion,
+};
+
+/// A [`ResourceProvider`] that provides resources straight out of a `.zip`
+/// archive, such as a zipped resource pack or the vanilla client `.jar`.
+///
+/// The archive should contain the `assets/` and/or `data/` directories at its
+/// root.
+///
+/// This type requires the `zip` feature.
+///
+/// [`ResourceProvider`]: crate::api::ResourceProvider
+///
+/// # Example
+///
+/// ```no_run
+/// # use minecraft_assets::api::*;
+/// let provider = ZipResourceProvider::open("~/.minecraft/versions/1.18.2/1.18.2.jar").unwrap();
+/// let assets = AssetPack::new(provider);
+///
+/// let states = assets.load_blockstates("oak_planks").unwrap();
+/// ```
+pub struct ZipResourceProvider<R = fs::File> {
+    archive: Mutex<ZipArchive<R>>,
+    version: Option<TargetVersion>,
+    /// The bytes of the archive, if it was opened by `open_mapped()`.
+    #[cfg(feature = "mmap")]
+    mapped: Option<MappedFile>,
+}
+
+impl ZipResourceProvider {
+    /// Returns a new provider that provides resources from the `.zip` or
+    /// `.jar` file at the given path.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, io::Error> {
+        Self::new(fs::File::open(path)?)
+    }
+}
+
+/// The memory-mapped bytes of a `.zip` or `.jar` file, read by a
+/// [`ZipResourceProvider`] returned by
+/// [`open_mapped()`][ZipResourceProvider::open_mapped].
+///
+/// This type requires the `mmap` feature.
+#[cfg(feature = "mmap")]
+#[derive(Clone)]
+pub struct MappedFile(std::sync::Arc<memmap2::Mmap>);
+
+#[cfg(feature = "mmap")]
+impl AsRef<[u8]> for MappedFile {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl ZipResourceProvider<io::Cursor<MappedFile>> {
+    /// Returns a new provider that provides resources from the `.zip` or
+    /// `.jar` file at the given path, which is memory-mapped instead of read.
+    ///
+    /// This method requires the `mmap` feature.
+    ///
+    /// The entries of the archive are decoded straight out of the mapped
+    /// memory when they are loaded or opened, without first copying their
+    /// compressed bytes into a buffer, which keeps the memory usage low when
+    /// loading many resources (e.g., every texture) of a large archive. Only
+    /// stored and Deflate-compressed entries are decoded this way, and their
+    /// checksums are not verified; other entries are read as usual.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated, by this process or another
+    /// one, while the provider (or a reader returned by it) is alive, since
+    /// this would change memory that the provider reads as immutable. This is
+    /// the case for the jars of the game and of mods while they are in use.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// // SAFETY: The game is not running, so its jar is not modified.
+    /// let provider = unsafe {
+    ///     ZipResourceProvider::open_mapped("~/.minecraft/versions/1.21/1.21.jar").unwrap()
+    /// };
+    /// let assets = AssetPack::new(provider);
+    ///
+    /// for id in assets.enumerate(ResourceKind::Texture, "minecraft").unwrap() {
+    ///     let mut reader = assets.open_resource(&id).unwrap();
+    ///     // ...
+    /// }
+    /// ```
+    pub unsafe fn open_mapped(path: impl AsRef<Path>) -> Result<Self, io::Error> {
+        let file = fs::File::open(path)?;
+
+        // SAFETY: The mapping is only read, and the caller guarantees that the
+        // file is not modified while it is mapped.
+        let mmap = unsafe { memmap2::Mmap::map(&file)? };
+        let mapped = MappedFile(std::sync::Arc::new(mmap));
+
+        let mut provider = Self::new(io::Cursor::new(mapped.clone()))?;
+        provider.mapped = Some(mapped);
+        Ok(provider)
+    }
+}
+
+impl ZipResourceProvider<io::Cursor<Vec<u8>>> {
+    /// Returns a new provider that provides resources from the `.zip` archive
+    /// held in the given bytes, e.g., one that was fetched over the network.
+    ///
+    /// This does not need a file system, so it also works in WebAssembly.
+    pub fn from_bytes(bytes: impl Into<Vec<u8>>) -> Result<Self, io::Error> {
+        Self::new(io::Cursor::new(bytes.into()))
+    }
+}
+
+impl<R: Read + Seek> ZipResourceProvider<R> {
+    /// Returns a new provider that provides resources from the zip archive
+    /// read by the given reader.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// use std::io::{Cursor, Write};
+    /// use zip::{write::SimpleFileOptions, ZipWriter};
+    ///
+    /// let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+    /// writer.start_file("assets/minecraft/blockstates/stone.json", SimpleFileOptions::default()).unwrap();
+    /// writer.write_all(br#"{ "variants": { "": { "model": "block/stone" } } }"#).unwrap();
+    /// let bytes = writer.finish().unwrap().into_inner();
+    ///
+    /// let provider = ZipResourceProvider::new(Cursor::new(bytes.clone())).unwrap();
+    ///
+    /// let namespaces = provider.enumerate_namespaces(ResourceCategory::Assets).unwrap();
+    /// assert_eq!(namespaces, vec!["minecraft"]);
+    ///
+    /// let ids = provider.enumerate_resources("minecraft", ResourceKind::BlockStates).unwrap();
+    /// assert_eq!(ids, vec![ResourceIdentifier::blockstates("stone")]);
+    ///
+    /// // Equivalently, for an archive held in memory:
+    /// let provider = ZipResourceProvider::from_bytes(bytes).unwrap();
+    ///
+    /// let assets = AssetPack::new(provider);
+    /// let states = assets.load_blockstates("stone").unwrap();
+    /// assert_eq!(states.variants().unwrap()[""].models()[0].model, "block/stone");
+    /// ```
+    pub fn new(reader: R) -> Result<Self, io::Error> {
+        let archive = ZipArchive::new(reader).map_err(zip_to_io_error)?;
+
+        Ok(Self {
+            archive: Mutex::new(archive),
+            version: None,
+            #[cfg(feature = "mmap")]
+            mapped: None,
+        })
+    }
+
+    /// Uses the directory layout of the given [`TargetVersion`] and returns
+    /// `self`.
+    pub fn with_target_version(mut self, version: TargetVersion) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Returns the path of the given resource within the archive.
+    fn resource_path(&self, id: &ResourceIdentifier) -> String {
+        let mut path = self.kind_path(id.namespace(), id.kind());
+        path.push_str(id.path());
+        path.push('.');
+        path.push_str(id.kind().extension());
+        path
+    }
+
+    /// Returns the path of the directory (with a trailing `/`) that contains
+    /// resources of the given kind within the archive.
+    fn kind_path(&self, namespace: &str, kind: ResourceKind) -> String {
+        let mut path = format!("{}/{}/", kind.category().directory(), namespace);
+        let directory = kind_directory(kind, self.version);
+        if !directory.is_empty() {
+            path.push_str(directory);
+            path.push('/');
+        }
+        path
+    }
+
+    fn read_file(&self, name: &str) -> Result<Vec<u8>, io::Error> {
+        #[cfg(feature = "mmap")]
+        if let Some(mut entry) = self.open_mapped_file(name)? {
+            let mut bytes = with_capacity_for(entry.size as u64);
+            entry.reader.read_to_end(&mut bytes)?;
+            return Ok(bytes);
+        }
+
+        let mut archive = self.archive.lock().unwrap_or_else(|e| e.into_inner());
+        let mut file = archive.by_name(name).map_err(zip_to_io_error)?;
+
+        let mut bytes = with_capacity_for(file.size());
+        file.read_to_end(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Returns a reader that decodes the given file straight out of the
+    /// mapped archive, and the size of the decoded file, or `None` if the
+    /// archive is not memory-mapped or the file cannot be decoded this way.
+    #[cfg(feature = "mmap")]
+    fn open_mapped_file(&self, name: &str) -> Result<Option<MappedEntry<'_>>, io::Error> {
+        use flate2::read::DeflateDecoder;
+        use zip::CompressionMethod;
+
+        let Some(mapped) = &self.mapped else {
+            return Ok(None);
+        };
+
+        let (compression, start, len, size) = {
+            let mut archive = self.archive.lock().unwrap_or_else(|e| e.into_inner());
+            let index = archive
+                .index_for_name(name)
+                .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))?;
+            let file = archive.by_index_raw(index).map_err(zip_to_io_error)?;
+            if file.encrypted() {
+                return Ok(None);
+            }
+            (
+                file.compression(),
+                file.data_start(),
+                file.compressed_size(),
+                file.size(),
+            )
+        };
+
+        let data = usize::try_from(start)
+            .ok()
+            .zip(usize::try_from(len).ok())
+            .and_then(|(start, len)| mapped.as_ref().get(start..start.checked_add(len)?))
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "truncated zip entry"))?;
+        let reader: Box<dyn Read + '_> = match compression {
+            CompressionMethod::Stored => Box::new(data),
+            CompressionMethod::Deflated => Box::new(DeflateDecoder::new(data)),
+            _ => return Ok(None),
+        };
+
+        Ok(Some(MappedEntry {
+            reader,
+            size: size as usize,
+        }))
+    }
+}
+
+impl<R: Read + Seek> EnumerateResources for ZipResourceProvider<R> {
+    fn enumerate_resources(
+        &self,
+        namespace: &str,
+        kind: ResourceKind,
+    ) -> Result<Vec<ResourceIdentifier<'static>>, io::Error> {
+        let archive = self.archive.lock().unwrap_or_else(|e| e.into_inner());
+
+        if let Some(name) = kind.single_file() {
+            let id = ResourceIdentifier::new_owned(kind, format!("{}:{}", namespace, name));
+            let exists = archive.index_for_name(&self.resource_path(&id)).is_some();
+
+            return Ok(if exists { vec![id] } else { vec![] });
+        }
+
+        let directory = self.kind_path(namespace, kind);
+        let suffix = format!(".{}", kind.extension());
+
+        let ids = archive
+            .file_names()
+            .filter_map(|name| {
+                let path = name.strip_prefix(&directory)?.strip_suffix(&suffix)?;
+
+                // Skip over files starting with '_', like the file system
+                // provider does.
+                let file_name = path.rsplit('/').next().unwrap_or(path);
+                if file_name.is_empty() || file_name.starts_with('_') {
+                    return None;
+                }
+
+                Some(ResourceIdentifier::in_namespace(kind, namespace, path))
+            })
+            .collect();
+
+        Ok(ids)
+    }
+
+    fn enumerate_namespaces(&self, category: ResourceCategory) -> Result<Vec<String>, io::Error> {
+        let archive = self.archive.lock().unwrap_or_else(|e| e.into_inner());
+        let prefix = format!("{}/", category.directory());
+
+        let namespaces: BTreeSet<&str> = archive
+            .file_names()
+            .filter_map(|name| {
+                let (namespace, _) = name.strip_prefix(&prefix)?.split_once('/')?;
+                (!namespace.is_empty()).then_some(namespace)
+            })
+            .collect();
+
+        Ok(namespaces.into_iter().map(String::from).collect())
+    }
+}
+
+impl<R: Read + Seek> LoadResource for ZipResourceProvider<R> {
+    fn load_resource(&self, id: &ResourceIdentifier) -> Result<Vec<u8>, io::Error> {
+        self.read_file(&self.resource_path(id))
+    }
+
+    fn load_root_file(&self, name: &str) -> Result<Vec<u8>, io::Error> {
+        self.read_file(name)
+    }
+
+    fn open_resource(&self, id: &ResourceIdentifier) -> Result<Box<dyn Read + '_>, io::Error> {
+        let path = self.resource_path(id);
+
+        #[cfg(feature = "mmap")]
+        if let Some(entry) = self.open_mapped_file(&path)? {
+            return Ok(entry.reader);
+        }
+
+        Ok(Box::new(io::Cursor::new(self.read_file(&path)?)))
+    }
+}
+
+/// A file of a memory-mapped archive, decoded on demand.
+#[cfg(feature = "mmap")]
+struct MappedEntry<'a> {
+    reader: Box<dyn Read + 'a>,
+    /// The size of the decoded file.
+    size: usize,
+}
+
+/// Returns a buffer for a file of the given decoded size.
+///
+/// The size comes from the headers of the archive, which may lie (e.g., in a
+/// zip bomb), so the buffer is only allocated up front for files of a
+/// reasonable size, and grows as it is read otherwise.
+fn with_capacity_for(size: u64) -> Vec<u8> {
+    const MAX_PREALLOCATION: u64 = 16 * 1024 * 1024;
+
+    Vec::with_capacity(size.min(MAX_PREALLOCATION) as usize)
+}
+
+fn zip_to_io_error(error: ZipError) -> io::Error {
+    match error {
+        ZipError::Io(e) => e,
+        ZipError::FileNotFound => io::Error::new(io::ErrorKind::NotFound, error),
+        e => io::Error::new(io::ErrorKind::InvalidData, e),
+    }
+}
+
+#[cfg(all(test, feature = "mmap"))]
+mod test {
+    use super::*;
+    use crate::api::ZipPackWriter;
+
+    #[test]
+    fn mapped_archive_decodes_stored_and_deflated_entries() {
+        let json = format!(
+            "{{ \"values\": [{}] }}",
+            "\"stone\", ".repeat(100) + "\"dirt\""
+        );
+        let png = b"\x89PNG (not compressed again)".to_vec();
+
+        let mut pack = ZipPackWriter::new();
+        pack.insert(
+            "assets/minecraft/lang/en_us.json",
+            json.clone().into_bytes(),
+        );
+        pack.insert("assets/minecraft/textures/block/stone.png", png.clone());
+
+        let path = std::env::temp_dir().join("minecraft-assets-mapped-test.zip");
+        pack.write_to_path(&path).unwrap();
+
+        // SAFETY: The file is only written by this test, before it is mapped.
+        let provider = unsafe { ZipResourceProvider::open_mapped(&path).unwrap() };
+        let lang = ResourceIdentifier::language("en_us");
+        let texture = ResourceIdentifier::texture("block/stone");
+
+        assert_eq!(provider.load_resource(&lang).unwrap(), json.as_bytes());
+        assert_eq!(provider.load_resource(&texture).unwrap(), png);
+
+        let mut streamed = Vec::new();
+        provider
+            .open_resource(&lang)
+            .unwrap()
+            .read_to_end(&mut streamed)
+            .unwrap();
+        assert_eq!(streamed, json.as_bytes());
+
+        let missing = ResourceIdentifier::texture("block/missing");
+        let error = provider.load_resource(&missing).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+
+        drop(provider);
+        fs::remove_file(&path).unwrap();
+    }
+}
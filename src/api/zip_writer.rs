@@ -0,0 +1,256 @@
+use std::{
+    borrow::Cow,
+    fs,
+    io::{self, Seek, Write},
+    path::Path,
+};
+
+use zip::{write::SimpleFileOptions, CompressionMethod, DateTime, ZipWriter};
+
+use crate::api::{
+    provider::is_missing, MemoryResourceProvider, ResourceIdentifier, ResourceKind,
+    ResourceProvider, Result, TargetVersion, WriteResource,
+};
+
+/// The files at the root of a pack that are copied by
+/// [`ZipPackWriter::add_provider()`].
+const ROOT_FILES: &[&str] = &["pack.mcmeta", "pack.png"];
+
+/// Writes a pack as a `.zip` archive that can be distributed, e.g., uploaded
+/// for the players of a server.
+///
+/// This type requires the `zip` feature.
+///
+/// Files are collected in memory, from schema values through a
+/// [`ResourceWriter`] or from another provider through
+/// [`add_provider()`][Self::add_provider], and then written out by
+/// [`finish()`][Self::finish]. The `assets/` and `data/` directories and the
+/// root files like `pack.mcmeta` are placed at the root of the archive, where
+/// the game expects them.
+///
+/// The archive is reproducible: files are written in sorted order, with a
+/// fixed modification time and permissions, so the same files always give the
+/// same bytes, and the same [hash] for the `resource-pack-sha1` server
+/// property. Files that are already compressed (`.png` and `.ogg` files,
+/// including `pack.png`) are stored as is; the others use the configured
+/// compression, which defaults to Deflate.
+///
+/// [`ResourceWriter`]: crate::api::ResourceWriter
+/// [hash]: crate::api::pack_hash
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::api::*;
+/// use minecraft_assets::schemas::{Model, PackMeta};
+///
+/// let mut zip = ZipPackWriter::new();
+///
+/// let mut writer = ResourceWriter::new(&mut zip);
+/// writer.write_pack_meta(&PackMeta::new(34, "My pack")).unwrap();
+/// writer.write_block_model("my_block", &Model {
+///     parent: Some("block/cube_all".parse().unwrap()),
+///     ..Default::default()
+/// }).unwrap();
+/// drop(writer);
+/// zip.insert("pack.png", b"(PNG data)".to_vec());
+///
+/// let bytes = zip.finish(std::io::Cursor::new(Vec::new())).unwrap().into_inner();
+///
+/// // Writing the same files again gives the same archive.
+/// let again = zip.finish(std::io::Cursor::new(Vec::new())).unwrap().into_inner();
+/// assert_eq!(bytes, again);
+///
+/// let assets = AssetPack::new(ZipResourceProvider::from_bytes(bytes).unwrap());
+/// assert_eq!(assets.load_pack_meta().unwrap().pack.pack_format, 34);
+/// assert!(assets.load_block_model("my_block").is_ok());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ZipPackWriter {
+    files: MemoryResourceProvider,
+    compression: CompressionMethod,
+    compression_level: Option<i64>,
+}
+
+impl Default for ZipPackWriter {
+    fn default() -> Self {
+        Self {
+            files: MemoryResourceProvider::new(),
+            compression: CompressionMethod::Deflated,
+            compression_level: None,
+        }
+    }
+}
+
+impl ZipPackWriter {
+    /// Returns a new writer without any files.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Uses the directory layout of the given [`TargetVersion`] for the
+    /// resources added afterwards, and returns `self`.
+    pub fn with_target_version(mut self, version: TargetVersion) -> Self {
+        self.files = self.files.with_target_version(version);
+        self
+    }
+
+    /// Uses the given compression method for the files that are not already
+    /// compressed, and returns `self`.
+    ///
+    /// Only [`CompressionMethod::Stored`] and [`CompressionMethod::Deflated`]
+    /// are supported by the game.
+    pub fn with_compression(mut self, compression: CompressionMethod) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Uses the given compression level (e.g., `0` to `9` for Deflate), or
+    /// the default level of the compression method if `None`, and returns
+    /// `self`.
+    pub fn with_compression_level(mut self, level: Option<i64>) -> Self {
+        self.compression_level = level;
+        self
+    }
+
+    /// Adds a file with the given path relative to the root of the pack,
+    /// replacing any previous file with the same path.
+    pub fn insert(&mut self, path: impl Into<String>, data: impl Into<Cow<'static, [u8]>>) {
+        self.files.insert(path, data);
+    }
+
+    /// Adds every resource of the given provider, and its `pack.mcmeta` and
+    /// `pack.png` files, replacing any previous files with the same paths.
+    ///
+    /// The resources are written with the directory layout of this writer,
+    /// so a pack can be converted to the layout of another version (e.g., the
+    /// singular directory names of 1.21) with
+    /// [`with_target_version()`][Self::with_target_version].
+    pub fn add_provider(&mut self, provider: &dyn ResourceProvider) -> Result<()> {
+        for &name in ROOT_FILES {
+            match provider.load_root_file(name) {
+                Ok(data) => self.insert(name, data),
+                Err(e) if is_missing(&e) => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        for &kind in ResourceKind::ALL {
+            let ids = match provider.list(kind) {
+                Ok(ids) => ids,
+                Err(e) if is_missing(&e) => continue,
+                Err(e) => return Err(e.into()),
+            };
+
+            for id in ids {
+                let data = provider.load_resource(&id)?;
+                self.files.insert_resource(&id, data);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the paths of the files of the pack relative to its root, in the
+    /// order in which they are written.
+    pub fn paths(&self) -> impl Iterator<Item = &str> {
+        self.files.paths()
+    }
+
+    /// Writes the pack as a `.zip` archive into the given writer, and returns
+    /// the writer.
+    pub fn finish<W: Write + Seek>(&self, writer: W) -> Result<W> {
+        let mut zip = ZipWriter::new(writer);
+
+        for path in self.files.paths() {
+            let data = self.files.get(path).unwrap_or_default();
+            zip.start_file(path, self.options(path))?;
+            zip.write_all(data)?;
+        }
+
+        Ok(zip.finish()?)
+    }
+
+    /// Writes the pack as a `.zip` file at the given path, replacing any
+    /// existing file.
+    pub fn write_to_path(&self, path: impl AsRef<Path>) -> Result<()> {
+        let file = fs::File::create(path)?;
+        let mut file = self.finish(io::BufWriter::new(file))?;
+        file.flush()?;
+        Ok(())
+    }
+
+    fn options(&self, path: &str) -> SimpleFileOptions {
+        let options = SimpleFileOptions::default()
+            .last_modified_time(DateTime::default())
+            .unix_permissions(0o644);
+
+        let compressed = path.ends_with(".png") || path.ends_with(".ogg");
+        if compressed {
+            options.compression_method(CompressionMethod::Stored)
+        } else {
+            options
+                .compression_method(self.compression)
+                .compression_level(self.compression_level)
+        }
+    }
+}
+
+impl WriteResource for ZipPackWriter {
+    fn write_resource(&mut self, id: &ResourceIdentifier, data: &[u8]) -> Result<(), io::Error> {
+        self.files.write_resource(id, data)
+    }
+
+    fn write_root_file(&mut self, name: &str, data: &[u8]) -> Result<(), io::Error> {
+        self.files.write_root_file(name, data)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use zip::ZipArchive;
+
+    use super::*;
+
+    #[test]
+    fn writes_sorted_reproducible_entries() {
+        let mut pack = MemoryResourceProvider::new();
+        pack.insert("pack.mcmeta", b"{}".to_vec());
+        pack.insert("pack.png", b"png".to_vec());
+        pack.insert("data/minecraft/recipes/stick.json", b"{}".to_vec());
+        pack.insert("assets/minecraft/lang/en_us.json", b"{}".to_vec());
+        pack.insert("assets/minecraft/textures/block/stone.png", b"png".to_vec());
+        pack.insert("unrelated.txt", b"".to_vec());
+
+        let mut writer = ZipPackWriter::new().with_target_version(TargetVersion::V1_21);
+        writer.add_provider(&pack).unwrap();
+
+        let bytes = writer.finish(Cursor::new(Vec::new())).unwrap().into_inner();
+        let mut archive = ZipArchive::new(Cursor::new(bytes)).unwrap();
+
+        let names: Vec<_> = archive.file_names().collect();
+        assert_eq!(
+            names,
+            [
+                "assets/minecraft/lang/en_us.json",
+                "assets/minecraft/textures/block/stone.png",
+                "data/minecraft/recipe/stick.json",
+                "pack.mcmeta",
+                "pack.png",
+            ]
+        );
+
+        for i in 0..archive.len() {
+            let file = archive.by_index(i).unwrap();
+            assert_eq!(file.last_modified(), Some(DateTime::default()));
+            let expected = if file.name().ends_with(".png") {
+                CompressionMethod::Stored
+            } else {
+                CompressionMethod::Deflated
+            };
+            assert_eq!(file.compression(), expected, "{}", file.name());
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use std::process::ExitCode;
+
+use minecraft_assets::api::diff::{ChangeKind, PackDiff, ResourceChange};
+
+use crate::{error, open_pack, usage, CommandResult};
+
+/// Runs `minecraft-assets diff`.
+pub fn run(args: &[String]) -> CommandResult {
+    let mut json = false;
+    let mut summary = false;
+    let mut positional = Vec::new();
+
+    for arg in args {
+        match arg.as_str() {
+            "--json" => json = true,
+            "--summary" => summary = true,
+            option if option.starts_with('-') => {
+                return Err(usage(format!("unknown option `{}`", option)))
+            }
+            arg => positional.push(arg),
+        }
+    }
+
+    let (old, new) = match positional[..] {
+        [old, new] => (old, new),
+        [] | [_] => return Err(usage("missing pack paths")),
+        [_, _, extra, ..] => return Err(usage(format!("unexpected argument `{}`", extra))),
+    };
+    let old_assets = open_pack(&[old])?;
+    let new_assets = open_pack(&[new])?;
+
+    let diff = PackDiff::compare(&*old_assets, &*new_assets)
+        .map_err(|e| error(format!("cannot compare {} with {}", old, new), e))?;
+
+    if json {
+        let changes: Vec<_> = diff.changes.iter().map(to_json).collect();
+        println!("{}", serde_json::Value::Array(changes));
+    } else if summary {
+        for kind in diff.summary() {
+            println!(
+                "{:?}: {} added, {} removed, {} changed",
+                kind.kind, kind.added, kind.removed, kind.changed
+            );
+        }
+    } else {
+        for change in &diff.changes {
+            println!("{}", change);
+            if let ChangeKind::Changed(json_changes) = &change.change {
+                for json_change in json_changes {
+                    println!("    {}", json_change);
+                }
+            }
+        }
+    }
+
+    if diff.is_empty() {
+        eprintln!("no differences found between {} and {}", old, new);
+        Ok(ExitCode::SUCCESS)
+    } else {
+        Ok(ExitCode::FAILURE)
+    }
+}
+
+fn to_json(change: &ResourceChange) -> serde_json::Value {
+    let (status, json_changes) = match &change.change {
+        ChangeKind::Added => ("added", Vec::new()),
+        ChangeKind::Removed => ("removed", Vec::new()),
+        ChangeKind::Changed(json_changes) => ("changed", json_changes.iter().collect()),
+    };
+    let json_changes: Vec<_> = json_changes
+        .into_iter()
+        .map(|json_change| {
+            serde_json::json!({
+                "json_path": json_change.json_path,
+                "old": json_change.old,
+                "new": json_change.new,
+            })
+        })
+        .collect();
+
+    serde_json::json!({
+        "file": change.resource.pack_path(),
+        "resource": change.resource.to_string(),
+        "kind": format!("{:?}", change.resource.kind()),
+        "status": status,
+        "changes": json_changes,
+    })
+}
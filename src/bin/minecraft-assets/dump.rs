@@ -0,0 +1,164 @@
+use std::process::ExitCode;
+
+use minecraft_assets::{
+    api::AssetPack,
+    schemas::{
+        blockstates::{
+            multipart::{StateValue, WhenClause},
+            Variant,
+        },
+        BlockStates,
+    },
+};
+
+use crate::{error, open_pack, usage, CommandError, CommandResult};
+
+/// What `minecraft-assets dump` prints.
+enum Target {
+    BlockModel,
+    ItemModel,
+    BlockStates,
+}
+
+/// Runs `minecraft-assets dump`.
+pub fn run(args: &[String]) -> CommandResult {
+    let mut target = Target::BlockModel;
+    let mut packs = Vec::new();
+    let mut positional = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--item" => target = Target::ItemModel,
+            "--states" => target = Target::BlockStates,
+            "--base" => match args.next() {
+                Some(pack) => packs.push(pack.as_str()),
+                None => return Err(usage("`--base` expects a pack")),
+            },
+            option if option.starts_with('-') => {
+                return Err(usage(format!("unknown option `{}`", option)))
+            }
+            arg => positional.push(arg),
+        }
+    }
+
+    let (pack, id) = match positional[..] {
+        [pack, id] => (pack, id),
+        [] | [_] => return Err(usage("missing pack path or id")),
+        [_, _, extra, ..] => return Err(usage(format!("unexpected argument `{}`", extra))),
+    };
+    packs.push(pack);
+    let assets = open_pack(&packs)?;
+
+    match target {
+        Target::BlockModel => {
+            let model = assets
+                .load_block_model_resolved(id)
+                .map_err(|e| error(format!("cannot resolve block model {}", id), e))?;
+            print_json(&model);
+        }
+        Target::ItemModel => {
+            let model = assets
+                .load_item_model_resolved(id)
+                .map_err(|e| error(format!("cannot resolve item model {}", id), e))?;
+            print_json(&model);
+        }
+        Target::BlockStates => print_states(&assets, id)?,
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn print_json(value: &impl serde::Serialize) {
+    // Serializing the schemas to a string cannot fail.
+    println!("{}", serde_json::to_string_pretty(value).unwrap());
+}
+
+/// Prints a table of the cases of the blockstates file of the given block and
+/// the models that they apply.
+fn print_states(assets: &AssetPack, block: &str) -> Result<(), CommandError> {
+    let states = assets
+        .load_blockstates(block)
+        .map_err(|e| error(format!("cannot load blockstates of {}", block), e))?;
+
+    let mut rows = Vec::new();
+    let header = match &states {
+        BlockStates::Variants { variants } => {
+            let mut names: Vec<_> = variants.keys().collect();
+            names.sort();
+
+            for name in names {
+                let case = if name.is_empty() { "(any)" } else { name };
+                push_rows(&mut rows, case.to_string(), &variants[name]);
+            }
+            "variant"
+        }
+        BlockStates::Multipart { cases } => {
+            for case in cases {
+                let when = match &case.when {
+                    Some(when) => when_to_string(when),
+                    None => String::from("(always)"),
+                };
+                push_rows(&mut rows, when, &case.apply);
+            }
+            "when"
+        }
+    };
+
+    let header = [header, "model", "x", "y", "uvlock", "weight"].map(String::from);
+    let mut widths = header.clone().map(|column| column.len());
+    for row in &rows {
+        for (width, column) in widths.iter_mut().zip(row) {
+            *width = (*width).max(column.len());
+        }
+    }
+
+    for row in std::iter::once(&header).chain(&rows) {
+        let columns: Vec<_> = row
+            .iter()
+            .zip(widths)
+            .map(|(column, width)| format!("{:width$}", column, width = width))
+            .collect();
+        println!("{}", columns.join("  ").trim_end());
+    }
+
+    Ok(())
+}
+
+/// Adds a row for each model of the given variant. Only the first row of a
+/// case names the case.
+fn push_rows(rows: &mut Vec<[String; 6]>, case: String, variant: &Variant) {
+    let mut case = Some(case);
+    for model in variant.models() {
+        rows.push([
+            case.take().unwrap_or_default(),
+            model.model.as_str().to_string(),
+            model.x.to_string(),
+            model.y.to_string(),
+            model.uv_lock.to_string(),
+            model.weight.to_string(),
+        ]);
+    }
+}
+
+/// Formats a `when` clause as, e.g., `north=side|up,up=true OR east=up`.
+fn when_to_string(when: &WhenClause) -> String {
+    let conditions: Vec<_> = when
+        .conditions()
+        .iter()
+        .map(|condition| {
+            let mut states: Vec<_> = condition
+                .and
+                .iter()
+                .map(|(name, value)| match value {
+                    StateValue::Bool(value) => format!("{}={}", name, value),
+                    StateValue::String(value) => format!("{}={}", name, value),
+                })
+                .collect();
+            states.sort();
+            states.join(",")
+        })
+        .collect();
+
+    conditions.join(" OR ")
+}
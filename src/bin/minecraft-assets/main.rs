@@ -0,0 +1,122 @@
+//! The `minecraft-assets` command-line tool.
+//!
+//! Requires the `cli` feature:
+//!
+//! ```text
+//! cargo install minecraft-assets --features cli
+//! minecraft-assets validate path/to/pack.zip
+//! minecraft-assets dump --states path/to/pack.zip oak_stairs
+//! minecraft-assets diff 1.20.jar 1.21.jar
+//! ```
+
+use std::{env, path::Path, process::ExitCode};
+
+use minecraft_assets::api::{
+    self, AssetPack, FileSystemResourceProvider, LayeredResourceProvider, ZipResourceProvider,
+};
+
+mod diff;
+mod dump;
+mod validate;
+
+const USAGE: &str = "\
+Usage: minecraft-assets <command> [options]
+
+Commands:
+  validate [--json] [--strict] [--reports <dir>] <pack>
+      Checks every resource of a pack directory, .zip file, or .jar file for
+      schema errors and broken references.
+
+      --json           Prints the diagnostics as a JSON array.
+      --strict         Also reports unknown fields and out-of-range values.
+      --reports <dir>  Also checks block, item, sound event, and tag ids
+                       against the data generator reports in <dir>.
+
+  dump [--item | --states] [--base <pack>]... <pack> <id>
+      Prints the block model with the given id (e.g., `oak_stairs` or
+      `block/oak_stairs`) as JSON, with its parents and texture variables
+      resolved.
+
+      --item           Prints the item model with the given id instead.
+      --states         Prints the variants of the blockstates file of the
+                       block with the given id and the models they use.
+      --base <pack>    Loads missing resources (e.g., vanilla parent models)
+                       from another pack. Can be given several times, from
+                       the bottom up.
+
+  diff [--json | --summary] <old pack> <new pack>
+      Lists the resources that were added, removed, or changed between two
+      packs, and the values that changed within JSON resources.
+
+      --json           Prints the changes as a JSON array.
+      --summary        Prints the number of changes of each kind of resource.
+";
+
+/// The result of a command: its exit code, or why it failed.
+type CommandResult = Result<ExitCode, CommandError>;
+
+/// An error that stops a command.
+enum CommandError {
+    /// The command was not invoked correctly.
+    Usage(String),
+
+    /// The command could not complete.
+    Failed(String),
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let result = match args.first().map(String::as_str) {
+        Some("validate") => validate::run(&args[1..]),
+        Some("dump") => dump::run(&args[1..]),
+        Some("diff") => diff::run(&args[1..]),
+        Some("-h" | "--help" | "help") => {
+            print!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Some(command) => Err(usage(format!("unknown command `{}`", command))),
+        None => Err(usage("missing command")),
+    };
+
+    match result {
+        Ok(code) => code,
+        Err(CommandError::Usage(message)) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            ExitCode::from(2)
+        }
+        Err(CommandError::Failed(message)) => {
+            eprintln!("error: {}", message);
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Opens the given pack directories, or `.zip` or `.jar` files, on top of each
+/// other, from the bottom up.
+fn open_pack(paths: &[&str]) -> Result<AssetPack, CommandError> {
+    let mut layers = LayeredResourceProvider::new();
+    for &path in paths {
+        if Path::new(path).is_file() {
+            let provider = ZipResourceProvider::open(path)
+                .map_err(|e| error(format!("cannot open {}", path), e.into()))?;
+            layers.push_layer(provider);
+        } else if Path::new(path).is_dir() {
+            layers.push_layer(FileSystemResourceProvider::new(path));
+        } else {
+            return Err(CommandError::Failed(format!("no pack found at {}", path)));
+        }
+    }
+
+    Ok(AssetPack::new(layers))
+}
+
+/// Returns a usage error with the given message.
+fn usage(message: impl Into<String>) -> CommandError {
+    CommandError::Usage(message.into())
+}
+
+/// Returns the error of a command that failed with the given API error.
+fn error(context: String, e: api::Error) -> CommandError {
+    CommandError::Failed(format!("{}: {}", context, e))
+}
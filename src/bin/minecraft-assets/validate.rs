@@ -0,0 +1,97 @@
+use std::process::ExitCode;
+
+use minecraft_assets::api::{
+    validate::{Diagnostic, Validator},
+    Registries,
+};
+
+use crate::{error, open_pack, usage, CommandResult};
+
+/// Runs `minecraft-assets validate`.
+pub fn run(args: &[String]) -> CommandResult {
+    let mut json = false;
+    let mut strict = false;
+    let mut reports = None;
+    let mut pack = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--json" => json = true,
+            "--strict" => strict = true,
+            "--reports" => match args.next() {
+                Some(dir) => reports = Some(dir),
+                None => return Err(usage("`--reports` expects a directory")),
+            },
+            option if option.starts_with('-') => {
+                return Err(usage(format!("unknown option `{}`", option)))
+            }
+            path if pack.is_none() => pack = Some(path),
+            path => return Err(usage(format!("unexpected argument `{}`", path))),
+        }
+    }
+
+    let pack = pack.ok_or_else(|| usage("missing pack path"))?;
+    let assets = open_pack(&[pack])?;
+
+    let registries = match reports {
+        Some(dir) => {
+            let registries = Registries::load(dir)
+                .map_err(|e| error(format!("cannot load reports from {}", dir), e))?;
+            Some(registries)
+        }
+        None => None,
+    };
+
+    let mut validator = Validator::new(&*assets)
+        .with_schema_checks()
+        .with_collected_errors();
+    if strict {
+        validator = validator.with_strict();
+    }
+    if let Some(registries) = &registries {
+        validator = validator.with_registries(registries);
+    }
+    let diagnostics = validator
+        .validate()
+        .map_err(|e| error(format!("cannot validate {}", pack), e))?;
+
+    if json {
+        let diagnostics: Vec<_> = diagnostics.iter().map(to_json).collect();
+        println!("{}", serde_json::Value::Array(diagnostics));
+    } else {
+        for diagnostic in &diagnostics {
+            let file = diagnostic.resource.pack_path();
+            if diagnostic.json_path.is_empty() {
+                println!("{}: {}", file, diagnostic.problem);
+            } else {
+                println!(
+                    "{} at {}: {}",
+                    file, diagnostic.json_path, diagnostic.problem
+                );
+            }
+        }
+
+        match diagnostics.len() {
+            0 => eprintln!("no problems found in {}", pack),
+            1 => eprintln!("1 problem found in {}", pack),
+            n => eprintln!("{} problems found in {}", n, pack),
+        }
+    }
+
+    if diagnostics.is_empty() {
+        Ok(ExitCode::SUCCESS)
+    } else {
+        Ok(ExitCode::FAILURE)
+    }
+}
+
+fn to_json(diagnostic: &Diagnostic) -> serde_json::Value {
+    serde_json::json!({
+        "file": diagnostic.resource.pack_path(),
+        "resource": diagnostic.resource.to_string(),
+        "kind": format!("{:?}", diagnostic.resource.kind()),
+        "json_path": diagnostic.json_path,
+        "message": diagnostic.problem.to_string(),
+    })
+}
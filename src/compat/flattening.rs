@@ -0,0 +1,1771 @@
+use std::fmt;
+
+/// Expands to the names of the 16 colors in the order of wool data values.
+macro_rules! colored {
+    ($suffix:literal) => {
+        [
+            concat!("minecraft:white", $suffix),
+            concat!("minecraft:orange", $suffix),
+            concat!("minecraft:magenta", $suffix),
+            concat!("minecraft:light_blue", $suffix),
+            concat!("minecraft:yellow", $suffix),
+            concat!("minecraft:lime", $suffix),
+            concat!("minecraft:pink", $suffix),
+            concat!("minecraft:gray", $suffix),
+            concat!("minecraft:light_gray", $suffix),
+            concat!("minecraft:cyan", $suffix),
+            concat!("minecraft:purple", $suffix),
+            concat!("minecraft:blue", $suffix),
+            concat!("minecraft:brown", $suffix),
+            concat!("minecraft:green", $suffix),
+            concat!("minecraft:red", $suffix),
+            concat!("minecraft:black", $suffix),
+        ]
+    };
+}
+
+/// Expands to the names of the 6 kinds of wood in the order of planks data
+/// values.
+macro_rules! woods {
+    ($suffix:literal) => {
+        [
+            concat!("minecraft:oak", $suffix),
+            concat!("minecraft:spruce", $suffix),
+            concat!("minecraft:birch", $suffix),
+            concat!("minecraft:jungle", $suffix),
+            concat!("minecraft:acacia", $suffix),
+            concat!("minecraft:dark_oak", $suffix),
+        ]
+    };
+}
+
+/// A block state that a 1.12 block id and data value were flattened into.
+///
+/// Only the properties that were stored in the data value are included.
+/// Properties that the game derived from neighboring blocks (e.g., the shape
+/// of stairs or the connections of fences) are left out.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FlattenedBlock {
+    /// The namespaced id of the block, e.g., `minecraft:oak_stairs`.
+    pub name: &'static str,
+
+    /// The block state properties, sorted by name.
+    pub properties: Vec<(&'static str, &'static str)>,
+}
+
+impl FlattenedBlock {
+    fn new(name: &'static str, mut properties: Vec<(&'static str, &'static str)>) -> Self {
+        properties.sort_unstable();
+        Self { name, properties }
+    }
+
+    /// Returns the value of the given property, if the block has it.
+    pub fn property(&self, name: &str) -> Option<&'static str> {
+        self.properties
+            .iter()
+            .find(|(property, _)| *property == name)
+            .map(|(_, value)| *value)
+    }
+
+    /// Returns the properties in the format of the keys of
+    /// [`Variants`][crate::schemas::blockstates::Variants], e.g.,
+    /// `facing=north,half=top`.
+    pub fn variant_key(&self) -> String {
+        self.properties
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+impl fmt::Display for FlattenedBlock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if !self.properties.is_empty() {
+            write!(f, "[{}]", self.variant_key())?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the 1.12 name of the block with the given numeric id, e.g.,
+/// `minecraft:wool` for `35`.
+pub fn legacy_block_name(id: u16) -> Option<&'static str> {
+    LEGACY_BLOCKS
+        .get(id as usize)
+        .copied()
+        .filter(|name| !name.is_empty())
+}
+
+/// Returns the 1.12 name of the item with the given numeric id, e.g.,
+/// `minecraft:dye` for `351`.
+///
+/// Ids below 256 are the items of blocks, and have the name of their block.
+pub fn legacy_item_name(id: u16) -> Option<&'static str> {
+    if id < 256 {
+        return legacy_block_name(id).filter(|_| !BLOCKS_WITHOUT_ITEMS.contains(&id));
+    }
+
+    LEGACY_ITEMS
+        .iter()
+        .find(|(item_id, _, _)| *item_id == id)
+        .map(|(_, name, _)| *name)
+}
+
+/// Flattens the block with the given 1.12 numeric id and data value into a
+/// block state.
+///
+/// Data values that 1.12 did not use for the block are treated like `0`, as
+/// the game does. Returns `None` if the id is not a 1.12 block.
+pub fn flatten_block(id: u16, data: u8) -> Option<FlattenedBlock> {
+    flatten_block_name(legacy_block_name(id)?, data)
+}
+
+/// Flattens the block with the given 1.12 name (e.g., `minecraft:wool`) and
+/// data value into a block state.
+///
+/// The namespace may be omitted. See [`flatten_block()`].
+pub fn flatten_block_name(name: &str, data: u8) -> Option<FlattenedBlock> {
+    let name = name.strip_prefix("minecraft:").unwrap_or(name);
+    block(name, data & 0xf).or_else(|| block(name, 0))
+}
+
+/// Flattens the item with the given 1.12 numeric id and damage value into a
+/// namespaced item id.
+///
+/// Damage values that do not select a variant of the item (e.g., the
+/// durability of tools) are ignored. Returns `None` if the id is not a 1.12
+/// item, or for spawn eggs, whose entity was stored in their NBT data.
+pub fn flatten_item(id: u16, damage: u16) -> Option<&'static str> {
+    flatten_item_name(legacy_item_name(id)?, damage)
+}
+
+/// Flattens the item with the given 1.12 name (e.g., `minecraft:dye`) and
+/// damage value into a namespaced item id.
+///
+/// The namespace may be omitted. See [`flatten_item()`].
+pub fn flatten_item_name(name: &str, damage: u16) -> Option<&'static str> {
+    let name = name.strip_prefix("minecraft:").unwrap_or(name);
+    let variant = |names: &[&'static str]| {
+        names
+            .get(damage as usize)
+            .or_else(|| names.first())
+            .copied()
+    };
+
+    match name {
+        "coal" => variant(&["minecraft:coal", "minecraft:charcoal"]),
+        "golden_apple" => variant(&["minecraft:golden_apple", "minecraft:enchanted_golden_apple"]),
+        "fish" => variant(&[
+            "minecraft:cod",
+            "minecraft:salmon",
+            "minecraft:tropical_fish",
+            "minecraft:pufferfish",
+        ]),
+        "cooked_fish" => variant(&["minecraft:cooked_cod", "minecraft:cooked_salmon"]),
+        "dye" => variant(&DYES),
+        "bed" => variant(&colored!("_bed")),
+        // The damage of banners is a dye color, in the opposite order of wool.
+        "banner" => {
+            let mut banners = colored!("_banner");
+            banners.reverse();
+            variant(&banners)
+        }
+        "skull" => variant(&[
+            "minecraft:skeleton_skull",
+            "minecraft:wither_skeleton_skull",
+            "minecraft:zombie_head",
+            "minecraft:player_head",
+            "minecraft:creeper_head",
+            "minecraft:dragon_head",
+        ]),
+        "spawn_egg" => None,
+        // Unlike other blocks, the damage of anvils is stored in the upper bits.
+        "anvil" => flatten_block_name(name, (damage.min(2) as u8) << 2).map(|block| block.name),
+        _ => {
+            if let Some((_, _, modern)) = LEGACY_ITEMS
+                .iter()
+                .find(|(_, item, _)| item.strip_prefix("minecraft:").unwrap_or(item) == name)
+            {
+                return Some(*modern);
+            }
+
+            let id = LEGACY_BLOCKS
+                .iter()
+                .position(|block| block.strip_prefix("minecraft:") == Some(name))?
+                as u16;
+            if BLOCKS_WITHOUT_ITEMS.contains(&id) {
+                return None;
+            }
+
+            let data = if BLOCKS_WITH_ITEM_VARIANTS.contains(&id) && damage < 16 {
+                damage as u8
+            } else {
+                0
+            };
+            flatten_block_name(name, data).map(|block| block.name)
+        }
+    }
+}
+
+fn block(name: &str, data: u8) -> Option<FlattenedBlock> {
+    let variant = |names: &[&'static str], index: u8| names.get(index as usize).copied();
+    let simple = |name: &'static str| Some(FlattenedBlock::new(name, Vec::new()));
+    let with = |name: &'static str, properties: Vec<(&'static str, &'static str)>| {
+        Some(FlattenedBlock::new(name, properties))
+    };
+
+    match name {
+        "air" => simple("minecraft:air"),
+        "stone" => simple(variant(&STONES, data)?),
+        "grass" => simple("minecraft:grass_block"),
+        "dirt" => simple(variant(
+            &[
+                "minecraft:dirt",
+                "minecraft:coarse_dirt",
+                "minecraft:podzol",
+            ],
+            data,
+        )?),
+        "cobblestone" => simple("minecraft:cobblestone"),
+        "planks" => simple(variant(&woods!("_planks"), data)?),
+        "sapling" => with(
+            variant(&woods!("_sapling"), data & 7)?,
+            vec![("stage", number(data >> 3))],
+        ),
+        "bedrock" => simple("minecraft:bedrock"),
+        "flowing_water" | "water" => with("minecraft:water", vec![("level", number(data))]),
+        "flowing_lava" | "lava" => with("minecraft:lava", vec![("level", number(data))]),
+        "sand" => simple(variant(&["minecraft:sand", "minecraft:red_sand"], data)?),
+        "gravel" => simple("minecraft:gravel"),
+        "gold_ore" => simple("minecraft:gold_ore"),
+        "iron_ore" => simple("minecraft:iron_ore"),
+        "coal_ore" => simple("minecraft:coal_ore"),
+        "log" => log(&woods!("_log")[..4], &woods!("_wood")[..4], data),
+        "log2" => log(&woods!("_log")[4..], &woods!("_wood")[4..], data),
+        "leaves" => with(
+            variant(&woods!("_leaves")[..4], data & 3)?,
+            vec![("persistent", boolean(data & 4 != 0))],
+        ),
+        "leaves2" => with(
+            variant(&woods!("_leaves")[4..], data & 3)?,
+            vec![("persistent", boolean(data & 4 != 0))],
+        ),
+        "sponge" => simple(variant(
+            &["minecraft:sponge", "minecraft:wet_sponge"],
+            data,
+        )?),
+        "glass" => simple("minecraft:glass"),
+        "lapis_ore" => simple("minecraft:lapis_ore"),
+        "lapis_block" => simple("minecraft:lapis_block"),
+        "dispenser" | "dropper" => with(
+            if name == "dispenser" {
+                "minecraft:dispenser"
+            } else {
+                "minecraft:dropper"
+            },
+            vec![
+                ("facing", facing(data & 7)),
+                ("triggered", boolean(data & 8 != 0)),
+            ],
+        ),
+        "sandstone" => simple(variant(
+            &[
+                "minecraft:sandstone",
+                "minecraft:chiseled_sandstone",
+                "minecraft:cut_sandstone",
+            ],
+            data,
+        )?),
+        "noteblock" => simple("minecraft:note_block"),
+        // The color of beds was stored in their block entity.
+        "bed" => with(
+            "minecraft:red_bed",
+            vec![
+                ("facing", horizontal(data & 3)),
+                ("occupied", boolean(data & 4 != 0)),
+                ("part", if data & 8 != 0 { "head" } else { "foot" }),
+            ],
+        ),
+        "golden_rail" | "detector_rail" | "activator_rail" => with(
+            match name {
+                "golden_rail" => "minecraft:powered_rail",
+                "detector_rail" => "minecraft:detector_rail",
+                _ => "minecraft:activator_rail",
+            },
+            vec![
+                ("powered", boolean(data & 8 != 0)),
+                ("shape", variant(&RAIL_SHAPES[..6], data & 7)?),
+            ],
+        ),
+        "rail" => with(
+            "minecraft:rail",
+            vec![("shape", variant(&RAIL_SHAPES, data)?)],
+        ),
+        "sticky_piston" | "piston" => with(
+            if name == "piston" {
+                "minecraft:piston"
+            } else {
+                "minecraft:sticky_piston"
+            },
+            vec![
+                ("extended", boolean(data & 8 != 0)),
+                ("facing", facing(data & 7)),
+            ],
+        ),
+        "piston_head" | "piston_extension" => with(
+            if name == "piston_head" {
+                "minecraft:piston_head"
+            } else {
+                "minecraft:moving_piston"
+            },
+            vec![
+                ("facing", facing(data & 7)),
+                ("type", if data & 8 != 0 { "sticky" } else { "normal" }),
+            ],
+        ),
+        "web" => simple("minecraft:cobweb"),
+        "tallgrass" => simple(variant(
+            &[
+                "minecraft:dead_bush",
+                "minecraft:short_grass",
+                "minecraft:fern",
+            ],
+            data,
+        )?),
+        "deadbush" => simple("minecraft:dead_bush"),
+        "wool" => simple(colored!("_wool")[data as usize]),
+        "yellow_flower" => simple("minecraft:dandelion"),
+        "red_flower" => simple(variant(
+            &[
+                "minecraft:poppy",
+                "minecraft:blue_orchid",
+                "minecraft:allium",
+                "minecraft:azure_bluet",
+                "minecraft:red_tulip",
+                "minecraft:orange_tulip",
+                "minecraft:white_tulip",
+                "minecraft:pink_tulip",
+                "minecraft:oxeye_daisy",
+            ],
+            data,
+        )?),
+        "brown_mushroom" => simple("minecraft:brown_mushroom"),
+        "red_mushroom" => simple("minecraft:red_mushroom"),
+        "gold_block" => simple("minecraft:gold_block"),
+        "iron_block" => simple("minecraft:iron_block"),
+        // The upper bit selected the seamless variants of double slabs.
+        "double_stone_slab" => match data {
+            8 => simple("minecraft:smooth_stone"),
+            9 => simple("minecraft:smooth_sandstone"),
+            15 => simple("minecraft:smooth_quartz"),
+            _ => with(STONE_SLABS[(data & 7) as usize], vec![("type", "double")]),
+        },
+        "stone_slab" => with(STONE_SLABS[(data & 7) as usize], vec![slab_half(data)]),
+        "double_stone_slab2" => match data {
+            8 => simple("minecraft:smooth_red_sandstone"),
+            _ => with("minecraft:red_sandstone_slab", vec![("type", "double")]),
+        },
+        "stone_slab2" => with("minecraft:red_sandstone_slab", vec![slab_half(data)]),
+        "double_wooden_slab" => with(
+            variant(&woods!("_slab"), data & 7)?,
+            vec![("type", "double")],
+        ),
+        "wooden_slab" => with(variant(&woods!("_slab"), data & 7)?, vec![slab_half(data)]),
+        "purpur_double_slab" => with("minecraft:purpur_slab", vec![("type", "double")]),
+        "purpur_slab" => with("minecraft:purpur_slab", vec![slab_half(data)]),
+        "brick_block" => simple("minecraft:bricks"),
+        "tnt" => with("minecraft:tnt", vec![("unstable", boolean(data & 1 != 0))]),
+        "bookshelf" => simple("minecraft:bookshelf"),
+        "mossy_cobblestone" => simple("minecraft:mossy_cobblestone"),
+        "obsidian" => simple("minecraft:obsidian"),
+        "torch" => torch("minecraft:torch", "minecraft:wall_torch", data, Vec::new()),
+        "unlit_redstone_torch" | "redstone_torch" => torch(
+            "minecraft:redstone_torch",
+            "minecraft:redstone_wall_torch",
+            data,
+            vec![("lit", boolean(name == "redstone_torch"))],
+        ),
+        "fire" => with("minecraft:fire", vec![("age", number(data))]),
+        "mob_spawner" => simple("minecraft:spawner"),
+        "oak_stairs"
+        | "stone_stairs"
+        | "brick_stairs"
+        | "stone_brick_stairs"
+        | "nether_brick_stairs"
+        | "sandstone_stairs"
+        | "spruce_stairs"
+        | "birch_stairs"
+        | "jungle_stairs"
+        | "quartz_stairs"
+        | "acacia_stairs"
+        | "dark_oak_stairs"
+        | "red_sandstone_stairs"
+        | "purpur_stairs" => with(
+            match name {
+                "oak_stairs" => "minecraft:oak_stairs",
+                "stone_stairs" => "minecraft:cobblestone_stairs",
+                "brick_stairs" => "minecraft:brick_stairs",
+                "stone_brick_stairs" => "minecraft:stone_brick_stairs",
+                "nether_brick_stairs" => "minecraft:nether_brick_stairs",
+                "sandstone_stairs" => "minecraft:sandstone_stairs",
+                "spruce_stairs" => "minecraft:spruce_stairs",
+                "birch_stairs" => "minecraft:birch_stairs",
+                "jungle_stairs" => "minecraft:jungle_stairs",
+                "quartz_stairs" => "minecraft:quartz_stairs",
+                "acacia_stairs" => "minecraft:acacia_stairs",
+                "dark_oak_stairs" => "minecraft:dark_oak_stairs",
+                "red_sandstone_stairs" => "minecraft:red_sandstone_stairs",
+                _ => "minecraft:purpur_stairs",
+            },
+            vec![
+                (
+                    "facing",
+                    ["east", "west", "south", "north"][(data & 3) as usize],
+                ),
+                ("half", if data & 4 != 0 { "top" } else { "bottom" }),
+            ],
+        ),
+        "chest" => with("minecraft:chest", vec![("facing", wall_facing(data & 7))]),
+        "trapped_chest" => with(
+            "minecraft:trapped_chest",
+            vec![("facing", wall_facing(data & 7))],
+        ),
+        "ender_chest" => with(
+            "minecraft:ender_chest",
+            vec![("facing", wall_facing(data & 7))],
+        ),
+        "redstone_wire" => with("minecraft:redstone_wire", vec![("power", number(data))]),
+        "diamond_ore" => simple("minecraft:diamond_ore"),
+        "diamond_block" => simple("minecraft:diamond_block"),
+        "crafting_table" => simple("minecraft:crafting_table"),
+        "wheat" => with("minecraft:wheat", vec![("age", number(data & 7))]),
+        "carrots" => with("minecraft:carrots", vec![("age", number(data & 7))]),
+        "potatoes" => with("minecraft:potatoes", vec![("age", number(data & 7))]),
+        "beetroots" => with("minecraft:beetroots", vec![("age", number(data & 3))]),
+        "nether_wart" => with("minecraft:nether_wart", vec![("age", number(data & 3))]),
+        "frosted_ice" => with("minecraft:frosted_ice", vec![("age", number(data & 3))]),
+        "farmland" => with("minecraft:farmland", vec![("moisture", number(data & 7))]),
+        "furnace" | "lit_furnace" => with(
+            "minecraft:furnace",
+            vec![
+                ("facing", wall_facing(data & 7)),
+                ("lit", boolean(name == "lit_furnace")),
+            ],
+        ),
+        "standing_sign" => with("minecraft:oak_sign", vec![("rotation", number(data))]),
+        "wall_sign" => with(
+            "minecraft:oak_wall_sign",
+            vec![("facing", wall_facing(data & 7))],
+        ),
+        "wooden_door" | "iron_door" | "spruce_door" | "birch_door" | "jungle_door"
+        | "acacia_door" | "dark_oak_door" => door(
+            match name {
+                "wooden_door" => "minecraft:oak_door",
+                "iron_door" => "minecraft:iron_door",
+                "spruce_door" => "minecraft:spruce_door",
+                "birch_door" => "minecraft:birch_door",
+                "jungle_door" => "minecraft:jungle_door",
+                "acacia_door" => "minecraft:acacia_door",
+                _ => "minecraft:dark_oak_door",
+            },
+            data,
+        ),
+        "ladder" => with("minecraft:ladder", vec![("facing", wall_facing(data & 7))]),
+        "lever" => {
+            let (face, facing) = [
+                ("ceiling", "west"),
+                ("wall", "east"),
+                ("wall", "west"),
+                ("wall", "south"),
+                ("wall", "north"),
+                ("floor", "north"),
+                ("floor", "west"),
+                ("ceiling", "north"),
+            ][(data & 7) as usize];
+            with(
+                "minecraft:lever",
+                vec![
+                    ("face", face),
+                    ("facing", facing),
+                    ("powered", boolean(data & 8 != 0)),
+                ],
+            )
+        }
+        "stone_pressure_plate" | "wooden_pressure_plate" => with(
+            if name == "stone_pressure_plate" {
+                "minecraft:stone_pressure_plate"
+            } else {
+                "minecraft:oak_pressure_plate"
+            },
+            vec![("powered", boolean(data & 1 != 0))],
+        ),
+        "light_weighted_pressure_plate" | "heavy_weighted_pressure_plate" => with(
+            if name == "light_weighted_pressure_plate" {
+                "minecraft:light_weighted_pressure_plate"
+            } else {
+                "minecraft:heavy_weighted_pressure_plate"
+            },
+            vec![("power", number(data))],
+        ),
+        "redstone_ore" | "lit_redstone_ore" => with(
+            "minecraft:redstone_ore",
+            vec![("lit", boolean(name == "lit_redstone_ore"))],
+        ),
+        "stone_button" | "wooden_button" => {
+            let (face, facing) = *[
+                ("ceiling", "north"),
+                ("wall", "east"),
+                ("wall", "west"),
+                ("wall", "south"),
+                ("wall", "north"),
+                ("floor", "north"),
+            ]
+            .get((data & 7) as usize)?;
+            with(
+                if name == "stone_button" {
+                    "minecraft:stone_button"
+                } else {
+                    "minecraft:oak_button"
+                },
+                vec![
+                    ("face", face),
+                    ("facing", facing),
+                    ("powered", boolean(data & 8 != 0)),
+                ],
+            )
+        }
+        "snow_layer" => with("minecraft:snow", vec![("layers", number((data & 7) + 1))]),
+        "ice" => simple("minecraft:ice"),
+        "snow" => simple("minecraft:snow_block"),
+        "cactus" => with("minecraft:cactus", vec![("age", number(data))]),
+        "clay" => simple("minecraft:clay"),
+        "reeds" => with("minecraft:sugar_cane", vec![("age", number(data))]),
+        "jukebox" => with(
+            "minecraft:jukebox",
+            vec![("has_record", boolean(data & 1 != 0))],
+        ),
+        "fence" => simple("minecraft:oak_fence"),
+        "nether_brick_fence" => simple("minecraft:nether_brick_fence"),
+        "spruce_fence" => simple("minecraft:spruce_fence"),
+        "birch_fence" => simple("minecraft:birch_fence"),
+        "jungle_fence" => simple("minecraft:jungle_fence"),
+        "dark_oak_fence" => simple("minecraft:dark_oak_fence"),
+        "acacia_fence" => simple("minecraft:acacia_fence"),
+        // Pumpkins had a face until 1.13 split them into carved pumpkins.
+        "pumpkin" => with(
+            "minecraft:carved_pumpkin",
+            vec![("facing", horizontal(data & 3))],
+        ),
+        "lit_pumpkin" => with(
+            "minecraft:jack_o_lantern",
+            vec![("facing", horizontal(data & 3))],
+        ),
+        "netherrack" => simple("minecraft:netherrack"),
+        "soul_sand" => simple("minecraft:soul_sand"),
+        "glowstone" => simple("minecraft:glowstone"),
+        "portal" => with(
+            "minecraft:nether_portal",
+            vec![("axis", if data == 2 { "z" } else { "x" })],
+        ),
+        "cake" => with(
+            "minecraft:cake",
+            vec![("bites", variant(&NUMBERS[..7], data)?)],
+        ),
+        "unpowered_repeater" | "powered_repeater" => with(
+            "minecraft:repeater",
+            vec![
+                ("delay", number((data >> 2) + 1)),
+                ("facing", horizontal(data & 3)),
+                ("powered", boolean(name == "powered_repeater")),
+            ],
+        ),
+        "stained_glass" => simple(colored!("_stained_glass")[data as usize]),
+        "stained_glass_pane" => simple(colored!("_stained_glass_pane")[data as usize]),
+        "carpet" => simple(colored!("_carpet")[data as usize]),
+        "stained_hardened_clay" => simple(colored!("_terracotta")[data as usize]),
+        "concrete" => simple(colored!("_concrete")[data as usize]),
+        "concrete_powder" => simple(colored!("_concrete_powder")[data as usize]),
+        "trapdoor" | "iron_trapdoor" => with(
+            if name == "trapdoor" {
+                "minecraft:oak_trapdoor"
+            } else {
+                "minecraft:iron_trapdoor"
+            },
+            vec![
+                (
+                    "facing",
+                    ["north", "south", "west", "east"][(data & 3) as usize],
+                ),
+                ("half", if data & 8 != 0 { "top" } else { "bottom" }),
+                ("open", boolean(data & 4 != 0)),
+            ],
+        ),
+        "monster_egg" => simple(variant(
+            &[
+                "minecraft:infested_stone",
+                "minecraft:infested_cobblestone",
+                "minecraft:infested_stone_bricks",
+                "minecraft:infested_mossy_stone_bricks",
+                "minecraft:infested_cracked_stone_bricks",
+                "minecraft:infested_chiseled_stone_bricks",
+            ],
+            data,
+        )?),
+        "stonebrick" => simple(variant(
+            &[
+                "minecraft:stone_bricks",
+                "minecraft:mossy_stone_bricks",
+                "minecraft:cracked_stone_bricks",
+                "minecraft:chiseled_stone_bricks",
+            ],
+            data,
+        )?),
+        "brown_mushroom_block" | "red_mushroom_block" => mushroom_block(
+            if name == "brown_mushroom_block" {
+                "minecraft:brown_mushroom_block"
+            } else {
+                "minecraft:red_mushroom_block"
+            },
+            data,
+        ),
+        "iron_bars" => simple("minecraft:iron_bars"),
+        "glass_pane" => simple("minecraft:glass_pane"),
+        "melon_block" => simple("minecraft:melon"),
+        "pumpkin_stem" => with("minecraft:pumpkin_stem", vec![("age", number(data & 7))]),
+        "melon_stem" => with("minecraft:melon_stem", vec![("age", number(data & 7))]),
+        "vine" => with(
+            "minecraft:vine",
+            vec![
+                ("east", boolean(data & 8 != 0)),
+                ("north", boolean(data & 4 != 0)),
+                ("south", boolean(data & 1 != 0)),
+                ("west", boolean(data & 2 != 0)),
+            ],
+        ),
+        "fence_gate"
+        | "spruce_fence_gate"
+        | "birch_fence_gate"
+        | "jungle_fence_gate"
+        | "dark_oak_fence_gate"
+        | "acacia_fence_gate" => with(
+            match name {
+                "fence_gate" => "minecraft:oak_fence_gate",
+                "spruce_fence_gate" => "minecraft:spruce_fence_gate",
+                "birch_fence_gate" => "minecraft:birch_fence_gate",
+                "jungle_fence_gate" => "minecraft:jungle_fence_gate",
+                "dark_oak_fence_gate" => "minecraft:dark_oak_fence_gate",
+                _ => "minecraft:acacia_fence_gate",
+            },
+            vec![
+                ("facing", horizontal(data & 3)),
+                ("open", boolean(data & 4 != 0)),
+                ("powered", boolean(data & 8 != 0)),
+            ],
+        ),
+        "mycelium" => simple("minecraft:mycelium"),
+        "waterlily" => simple("minecraft:lily_pad"),
+        "nether_brick" => simple("minecraft:nether_bricks"),
+        "enchanting_table" => simple("minecraft:enchanting_table"),
+        "brewing_stand" => with(
+            "minecraft:brewing_stand",
+            vec![
+                ("has_bottle_0", boolean(data & 1 != 0)),
+                ("has_bottle_1", boolean(data & 2 != 0)),
+                ("has_bottle_2", boolean(data & 4 != 0)),
+            ],
+        ),
+        // Cauldrons with water were split into their own block in 1.17.
+        "cauldron" => match data & 3 {
+            0 => simple("minecraft:cauldron"),
+            level => with("minecraft:water_cauldron", vec![("level", number(level))]),
+        },
+        "end_portal" => simple("minecraft:end_portal"),
+        "end_portal_frame" => with(
+            "minecraft:end_portal_frame",
+            vec![
+                ("eye", boolean(data & 4 != 0)),
+                ("facing", horizontal(data & 3)),
+            ],
+        ),
+        "end_stone" => simple("minecraft:end_stone"),
+        "dragon_egg" => simple("minecraft:dragon_egg"),
+        "redstone_lamp" | "lit_redstone_lamp" => with(
+            "minecraft:redstone_lamp",
+            vec![("lit", boolean(name == "lit_redstone_lamp"))],
+        ),
+        "cocoa" => with(
+            "minecraft:cocoa",
+            vec![
+                ("age", variant(&NUMBERS[..3], data >> 2)?),
+                ("facing", horizontal(data & 3)),
+            ],
+        ),
+        "emerald_ore" => simple("minecraft:emerald_ore"),
+        "tripwire_hook" => with(
+            "minecraft:tripwire_hook",
+            vec![
+                ("attached", boolean(data & 4 != 0)),
+                ("facing", horizontal(data & 3)),
+                ("powered", boolean(data & 8 != 0)),
+            ],
+        ),
+        "tripwire" => with(
+            "minecraft:tripwire",
+            vec![
+                ("attached", boolean(data & 4 != 0)),
+                ("disarmed", boolean(data & 8 != 0)),
+                ("powered", boolean(data & 1 != 0)),
+            ],
+        ),
+        "emerald_block" => simple("minecraft:emerald_block"),
+        "command_block" | "repeating_command_block" | "chain_command_block" => with(
+            match name {
+                "command_block" => "minecraft:command_block",
+                "repeating_command_block" => "minecraft:repeating_command_block",
+                _ => "minecraft:chain_command_block",
+            },
+            vec![
+                ("conditional", boolean(data & 8 != 0)),
+                ("facing", facing(data & 7)),
+            ],
+        ),
+        "beacon" => simple("minecraft:beacon"),
+        "cobblestone_wall" => simple(variant(
+            &[
+                "minecraft:cobblestone_wall",
+                "minecraft:mossy_cobblestone_wall",
+            ],
+            data,
+        )?),
+        // The plant in flower pots was stored in their block entity.
+        "flower_pot" => simple("minecraft:flower_pot"),
+        // The type and rotation of skulls were stored in their block entity.
+        "skull" => match data & 7 {
+            0 | 1 => simple("minecraft:skeleton_skull"),
+            facing => with(
+                "minecraft:skeleton_wall_skull",
+                vec![("facing", wall_facing(facing))],
+            ),
+        },
+        "anvil" => with(
+            variant(
+                &[
+                    "minecraft:anvil",
+                    "minecraft:chipped_anvil",
+                    "minecraft:damaged_anvil",
+                ],
+                data >> 2,
+            )?,
+            vec![("facing", horizontal(data & 3))],
+        ),
+        "unpowered_comparator" | "powered_comparator" => with(
+            "minecraft:comparator",
+            vec![
+                ("facing", horizontal(data & 3)),
+                ("mode", if data & 4 != 0 { "subtract" } else { "compare" }),
+                ("powered", boolean(data & 8 != 0)),
+            ],
+        ),
+        "daylight_detector" | "daylight_detector_inverted" => with(
+            "minecraft:daylight_detector",
+            vec![
+                ("inverted", boolean(name == "daylight_detector_inverted")),
+                ("power", number(data)),
+            ],
+        ),
+        "redstone_block" => simple("minecraft:redstone_block"),
+        "quartz_ore" => simple("minecraft:nether_quartz_ore"),
+        "hopper" => with(
+            "minecraft:hopper",
+            vec![
+                ("enabled", boolean(data & 8 == 0)),
+                (
+                    "facing",
+                    match data & 7 {
+                        2..=5 => facing(data & 7),
+                        _ => "down",
+                    },
+                ),
+            ],
+        ),
+        "quartz_block" => match data {
+            0 => simple("minecraft:quartz_block"),
+            1 => simple("minecraft:chiseled_quartz_block"),
+            2..=4 => with(
+                "minecraft:quartz_pillar",
+                vec![("axis", ["y", "x", "z"][(data - 2) as usize])],
+            ),
+            _ => None,
+        },
+        "slime" => simple("minecraft:slime_block"),
+        "barrier" => simple("minecraft:barrier"),
+        "prismarine" => simple(variant(
+            &[
+                "minecraft:prismarine",
+                "minecraft:prismarine_bricks",
+                "minecraft:dark_prismarine",
+            ],
+            data,
+        )?),
+        "sea_lantern" => simple("minecraft:sea_lantern"),
+        "hay_block" => with("minecraft:hay_block", vec![("axis", axis(data))]),
+        "bone_block" => with("minecraft:bone_block", vec![("axis", axis(data))]),
+        "purpur_pillar" => with("minecraft:purpur_pillar", vec![("axis", axis(data))]),
+        "hardened_clay" => simple("minecraft:terracotta"),
+        "coal_block" => simple("minecraft:coal_block"),
+        "packed_ice" => simple("minecraft:packed_ice"),
+        "double_plant" => {
+            // The upper half did not know its variant, which was taken from
+            // the lower half.
+            let name = if data & 8 != 0 {
+                "minecraft:sunflower"
+            } else {
+                variant(
+                    &[
+                        "minecraft:sunflower",
+                        "minecraft:lilac",
+                        "minecraft:tall_grass",
+                        "minecraft:large_fern",
+                        "minecraft:rose_bush",
+                        "minecraft:peony",
+                    ],
+                    data,
+                )?
+            };
+            with(
+                name,
+                vec![("half", if data & 8 != 0 { "upper" } else { "lower" })],
+            )
+        }
+        // The color of banners was stored in their block entity.
+        "standing_banner" => with("minecraft:white_banner", vec![("rotation", number(data))]),
+        "wall_banner" => with(
+            "minecraft:white_wall_banner",
+            vec![("facing", wall_facing(data & 7))],
+        ),
+        "red_sandstone" => simple(variant(
+            &[
+                "minecraft:red_sandstone",
+                "minecraft:chiseled_red_sandstone",
+                "minecraft:cut_red_sandstone",
+            ],
+            data,
+        )?),
+        "end_rod" => with("minecraft:end_rod", vec![("facing", facing(data & 7))]),
+        "chorus_plant" => simple("minecraft:chorus_plant"),
+        "chorus_flower" => with(
+            "minecraft:chorus_flower",
+            vec![("age", variant(&NUMBERS[..6], data)?)],
+        ),
+        "purpur_block" => simple("minecraft:purpur_block"),
+        "end_bricks" => simple("minecraft:end_stone_bricks"),
+        "grass_path" => simple("minecraft:dirt_path"),
+        "end_gateway" => simple("minecraft:end_gateway"),
+        "magma" => simple("minecraft:magma_block"),
+        "nether_wart_block" => simple("minecraft:nether_wart_block"),
+        "red_nether_brick" => simple("minecraft:red_nether_bricks"),
+        "structure_void" => simple("minecraft:structure_void"),
+        "observer" => with(
+            "minecraft:observer",
+            vec![
+                ("facing", facing(data & 7)),
+                ("powered", boolean(data & 8 != 0)),
+            ],
+        ),
+        "structure_block" => with(
+            "minecraft:structure_block",
+            vec![(
+                "mode",
+                ["save", "load", "corner", "data"][(data & 3) as usize],
+            )],
+        ),
+        _ => {
+            if let Some(color) = name.strip_suffix("_shulker_box").and_then(color) {
+                with(
+                    colored!("_shulker_box")[color],
+                    vec![("facing", facing(data & 7))],
+                )
+            } else if let Some(color) = name.strip_suffix("_glazed_terracotta").and_then(color) {
+                with(
+                    colored!("_glazed_terracotta")[color],
+                    vec![("facing", horizontal(data & 3))],
+                )
+            } else {
+                None
+            }
+        }
+    }
+}
+
+fn log(logs: &[&'static str], woods: &[&'static str], data: u8) -> Option<FlattenedBlock> {
+    let index = (data & 3) as usize;
+    // Logs with bark on all sides had no axis.
+    let name = if data >> 2 == 3 {
+        woods.get(index)?
+    } else {
+        logs.get(index)?
+    };
+    Some(FlattenedBlock::new(name, vec![("axis", axis(data))]))
+}
+
+fn torch(
+    standing: &'static str,
+    wall: &'static str,
+    data: u8,
+    mut properties: Vec<(&'static str, &'static str)>,
+) -> Option<FlattenedBlock> {
+    let name = match data {
+        1..=4 => {
+            properties.push((
+                "facing",
+                ["east", "west", "south", "north"][data as usize - 1],
+            ));
+            wall
+        }
+        _ => standing,
+    };
+    Some(FlattenedBlock::new(name, properties))
+}
+
+fn door(name: &'static str, data: u8) -> Option<FlattenedBlock> {
+    // The lower half stored the facing and whether the door is open, and the
+    // upper half stored the hinge and whether the door is powered.
+    let properties = if data & 8 == 0 {
+        vec![
+            (
+                "facing",
+                ["east", "south", "west", "north"][(data & 3) as usize],
+            ),
+            ("half", "lower"),
+            ("open", boolean(data & 4 != 0)),
+        ]
+    } else {
+        vec![
+            ("half", "upper"),
+            ("hinge", if data & 1 != 0 { "right" } else { "left" }),
+            ("powered", boolean(data & 2 != 0)),
+        ]
+    };
+    Some(FlattenedBlock::new(name, properties))
+}
+
+fn mushroom_block(name: &'static str, data: u8) -> Option<FlattenedBlock> {
+    let (name, outside): (_, &[&str]) = match data {
+        0 => (name, &[]),
+        1 => (name, &["north", "up", "west"]),
+        2 => (name, &["north", "up"]),
+        3 => (name, &["east", "north", "up"]),
+        4 => (name, &["up", "west"]),
+        5 => (name, &["up"]),
+        6 => (name, &["east", "up"]),
+        7 => (name, &["south", "up", "west"]),
+        8 => (name, &["south", "up"]),
+        9 => (name, &["east", "south", "up"]),
+        10 => (
+            "minecraft:mushroom_stem",
+            &["east", "north", "south", "west"],
+        ),
+        14 => (name, &["down", "east", "north", "south", "up", "west"]),
+        15 => (
+            "minecraft:mushroom_stem",
+            &["down", "east", "north", "south", "up", "west"],
+        ),
+        _ => return None,
+    };
+    let properties = ["down", "east", "north", "south", "up", "west"]
+        .iter()
+        .map(|side| (*side, boolean(outside.contains(side))))
+        .collect();
+    Some(FlattenedBlock::new(name, properties))
+}
+
+fn slab_half(data: u8) -> (&'static str, &'static str) {
+    ("type", if data & 8 != 0 { "top" } else { "bottom" })
+}
+
+fn axis(data: u8) -> &'static str {
+    match (data >> 2) & 3 {
+        1 => "x",
+        2 => "z",
+        _ => "y",
+    }
+}
+
+/// A facing in any direction, like from `EnumFacing.getFront()`.
+fn facing(data: u8) -> &'static str {
+    ["down", "up", "north", "south", "west", "east"][(data % 6) as usize]
+}
+
+/// A facing on the walls, where the vertical directions meant north.
+fn wall_facing(data: u8) -> &'static str {
+    match data {
+        2..=5 => facing(data),
+        _ => "north",
+    }
+}
+
+/// A horizontal facing, like from `EnumFacing.getHorizontal()`.
+fn horizontal(data: u8) -> &'static str {
+    ["south", "west", "north", "east"][(data & 3) as usize]
+}
+
+fn number(n: u8) -> &'static str {
+    NUMBERS[n as usize]
+}
+
+fn boolean(b: bool) -> &'static str {
+    if b {
+        "true"
+    } else {
+        "false"
+    }
+}
+
+/// Returns the index of a 1.12 color name, in the order of wool data values.
+fn color(name: &str) -> Option<usize> {
+    [
+        "white",
+        "orange",
+        "magenta",
+        "light_blue",
+        "yellow",
+        "lime",
+        "pink",
+        "gray",
+        "silver",
+        "cyan",
+        "purple",
+        "blue",
+        "brown",
+        "green",
+        "red",
+        "black",
+    ]
+    .iter()
+    .position(|color| *color == name)
+}
+
+const NUMBERS: [&str; 16] = [
+    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15",
+];
+
+const STONES: [&str; 7] = [
+    "minecraft:stone",
+    "minecraft:granite",
+    "minecraft:polished_granite",
+    "minecraft:diorite",
+    "minecraft:polished_diorite",
+    "minecraft:andesite",
+    "minecraft:polished_andesite",
+];
+
+const STONE_SLABS: [&str; 8] = [
+    "minecraft:smooth_stone_slab",
+    "minecraft:sandstone_slab",
+    "minecraft:petrified_oak_slab",
+    "minecraft:cobblestone_slab",
+    "minecraft:brick_slab",
+    "minecraft:stone_brick_slab",
+    "minecraft:nether_brick_slab",
+    "minecraft:quartz_slab",
+];
+
+const RAIL_SHAPES: [&str; 10] = [
+    "north_south",
+    "east_west",
+    "ascending_east",
+    "ascending_west",
+    "ascending_north",
+    "ascending_south",
+    "south_east",
+    "south_west",
+    "north_west",
+    "north_east",
+];
+
+/// The dye items, in the order of their damage values.
+const DYES: [&str; 16] = [
+    "minecraft:ink_sac",
+    "minecraft:red_dye",
+    "minecraft:green_dye",
+    "minecraft:cocoa_beans",
+    "minecraft:lapis_lazuli",
+    "minecraft:purple_dye",
+    "minecraft:cyan_dye",
+    "minecraft:light_gray_dye",
+    "minecraft:gray_dye",
+    "minecraft:pink_dye",
+    "minecraft:lime_dye",
+    "minecraft:yellow_dye",
+    "minecraft:light_blue_dye",
+    "minecraft:magenta_dye",
+    "minecraft:orange_dye",
+    "minecraft:bone_meal",
+];
+
+/// The ids of blocks that had no item of their own.
+const BLOCKS_WITHOUT_ITEMS: [u16; 52] = [
+    8, 9, 10, 11, 26, 34, 36, 43, 51, 55, 59, 62, 63, 64, 68, 71, 74, 75, 83, 90, 92, 93, 94, 104,
+    105, 115, 117, 118, 119, 124, 125, 127, 132, 140, 141, 142, 144, 149, 150, 176, 177, 178, 181,
+    193, 194, 195, 196, 197, 204, 207, 209, 212,
+];
+
+/// The ids of blocks whose items used the damage value as the data value of
+/// the placed block.
+const BLOCKS_WITH_ITEM_VARIANTS: [u16; 29] = [
+    1, 3, 5, 6, 12, 17, 18, 19, 24, 31, 35, 38, 44, 95, 97, 98, 126, 139, 155, 159, 160, 161, 162,
+    168, 171, 175, 179, 251, 252,
+];
+
+/// The 1.12 names of blocks, indexed by their numeric id.
+const LEGACY_BLOCKS: [&str; 256] = [
+    "minecraft:air",
+    "minecraft:stone",
+    "minecraft:grass",
+    "minecraft:dirt",
+    "minecraft:cobblestone",
+    "minecraft:planks",
+    "minecraft:sapling",
+    "minecraft:bedrock",
+    "minecraft:flowing_water",
+    "minecraft:water",
+    "minecraft:flowing_lava",
+    "minecraft:lava",
+    "minecraft:sand",
+    "minecraft:gravel",
+    "minecraft:gold_ore",
+    "minecraft:iron_ore",
+    "minecraft:coal_ore",
+    "minecraft:log",
+    "minecraft:leaves",
+    "minecraft:sponge",
+    "minecraft:glass",
+    "minecraft:lapis_ore",
+    "minecraft:lapis_block",
+    "minecraft:dispenser",
+    "minecraft:sandstone",
+    "minecraft:noteblock",
+    "minecraft:bed",
+    "minecraft:golden_rail",
+    "minecraft:detector_rail",
+    "minecraft:sticky_piston",
+    "minecraft:web",
+    "minecraft:tallgrass",
+    "minecraft:deadbush",
+    "minecraft:piston",
+    "minecraft:piston_head",
+    "minecraft:wool",
+    "minecraft:piston_extension",
+    "minecraft:yellow_flower",
+    "minecraft:red_flower",
+    "minecraft:brown_mushroom",
+    "minecraft:red_mushroom",
+    "minecraft:gold_block",
+    "minecraft:iron_block",
+    "minecraft:double_stone_slab",
+    "minecraft:stone_slab",
+    "minecraft:brick_block",
+    "minecraft:tnt",
+    "minecraft:bookshelf",
+    "minecraft:mossy_cobblestone",
+    "minecraft:obsidian",
+    "minecraft:torch",
+    "minecraft:fire",
+    "minecraft:mob_spawner",
+    "minecraft:oak_stairs",
+    "minecraft:chest",
+    "minecraft:redstone_wire",
+    "minecraft:diamond_ore",
+    "minecraft:diamond_block",
+    "minecraft:crafting_table",
+    "minecraft:wheat",
+    "minecraft:farmland",
+    "minecraft:furnace",
+    "minecraft:lit_furnace",
+    "minecraft:standing_sign",
+    "minecraft:wooden_door",
+    "minecraft:ladder",
+    "minecraft:rail",
+    "minecraft:stone_stairs",
+    "minecraft:wall_sign",
+    "minecraft:lever",
+    "minecraft:stone_pressure_plate",
+    "minecraft:iron_door",
+    "minecraft:wooden_pressure_plate",
+    "minecraft:redstone_ore",
+    "minecraft:lit_redstone_ore",
+    "minecraft:unlit_redstone_torch",
+    "minecraft:redstone_torch",
+    "minecraft:stone_button",
+    "minecraft:snow_layer",
+    "minecraft:ice",
+    "minecraft:snow",
+    "minecraft:cactus",
+    "minecraft:clay",
+    "minecraft:reeds",
+    "minecraft:jukebox",
+    "minecraft:fence",
+    "minecraft:pumpkin",
+    "minecraft:netherrack",
+    "minecraft:soul_sand",
+    "minecraft:glowstone",
+    "minecraft:portal",
+    "minecraft:lit_pumpkin",
+    "minecraft:cake",
+    "minecraft:unpowered_repeater",
+    "minecraft:powered_repeater",
+    "minecraft:stained_glass",
+    "minecraft:trapdoor",
+    "minecraft:monster_egg",
+    "minecraft:stonebrick",
+    "minecraft:brown_mushroom_block",
+    "minecraft:red_mushroom_block",
+    "minecraft:iron_bars",
+    "minecraft:glass_pane",
+    "minecraft:melon_block",
+    "minecraft:pumpkin_stem",
+    "minecraft:melon_stem",
+    "minecraft:vine",
+    "minecraft:fence_gate",
+    "minecraft:brick_stairs",
+    "minecraft:stone_brick_stairs",
+    "minecraft:mycelium",
+    "minecraft:waterlily",
+    "minecraft:nether_brick",
+    "minecraft:nether_brick_fence",
+    "minecraft:nether_brick_stairs",
+    "minecraft:nether_wart",
+    "minecraft:enchanting_table",
+    "minecraft:brewing_stand",
+    "minecraft:cauldron",
+    "minecraft:end_portal",
+    "minecraft:end_portal_frame",
+    "minecraft:end_stone",
+    "minecraft:dragon_egg",
+    "minecraft:redstone_lamp",
+    "minecraft:lit_redstone_lamp",
+    "minecraft:double_wooden_slab",
+    "minecraft:wooden_slab",
+    "minecraft:cocoa",
+    "minecraft:sandstone_stairs",
+    "minecraft:emerald_ore",
+    "minecraft:ender_chest",
+    "minecraft:tripwire_hook",
+    "minecraft:tripwire",
+    "minecraft:emerald_block",
+    "minecraft:spruce_stairs",
+    "minecraft:birch_stairs",
+    "minecraft:jungle_stairs",
+    "minecraft:command_block",
+    "minecraft:beacon",
+    "minecraft:cobblestone_wall",
+    "minecraft:flower_pot",
+    "minecraft:carrots",
+    "minecraft:potatoes",
+    "minecraft:wooden_button",
+    "minecraft:skull",
+    "minecraft:anvil",
+    "minecraft:trapped_chest",
+    "minecraft:light_weighted_pressure_plate",
+    "minecraft:heavy_weighted_pressure_plate",
+    "minecraft:unpowered_comparator",
+    "minecraft:powered_comparator",
+    "minecraft:daylight_detector",
+    "minecraft:redstone_block",
+    "minecraft:quartz_ore",
+    "minecraft:hopper",
+    "minecraft:quartz_block",
+    "minecraft:quartz_stairs",
+    "minecraft:activator_rail",
+    "minecraft:dropper",
+    "minecraft:stained_hardened_clay",
+    "minecraft:stained_glass_pane",
+    "minecraft:leaves2",
+    "minecraft:log2",
+    "minecraft:acacia_stairs",
+    "minecraft:dark_oak_stairs",
+    "minecraft:slime",
+    "minecraft:barrier",
+    "minecraft:iron_trapdoor",
+    "minecraft:prismarine",
+    "minecraft:sea_lantern",
+    "minecraft:hay_block",
+    "minecraft:carpet",
+    "minecraft:hardened_clay",
+    "minecraft:coal_block",
+    "minecraft:packed_ice",
+    "minecraft:double_plant",
+    "minecraft:standing_banner",
+    "minecraft:wall_banner",
+    "minecraft:daylight_detector_inverted",
+    "minecraft:red_sandstone",
+    "minecraft:red_sandstone_stairs",
+    "minecraft:double_stone_slab2",
+    "minecraft:stone_slab2",
+    "minecraft:spruce_fence_gate",
+    "minecraft:birch_fence_gate",
+    "minecraft:jungle_fence_gate",
+    "minecraft:dark_oak_fence_gate",
+    "minecraft:acacia_fence_gate",
+    "minecraft:spruce_fence",
+    "minecraft:birch_fence",
+    "minecraft:jungle_fence",
+    "minecraft:dark_oak_fence",
+    "minecraft:acacia_fence",
+    "minecraft:spruce_door",
+    "minecraft:birch_door",
+    "minecraft:jungle_door",
+    "minecraft:acacia_door",
+    "minecraft:dark_oak_door",
+    "minecraft:end_rod",
+    "minecraft:chorus_plant",
+    "minecraft:chorus_flower",
+    "minecraft:purpur_block",
+    "minecraft:purpur_pillar",
+    "minecraft:purpur_stairs",
+    "minecraft:purpur_double_slab",
+    "minecraft:purpur_slab",
+    "minecraft:end_bricks",
+    "minecraft:beetroots",
+    "minecraft:grass_path",
+    "minecraft:end_gateway",
+    "minecraft:repeating_command_block",
+    "minecraft:chain_command_block",
+    "minecraft:frosted_ice",
+    "minecraft:magma",
+    "minecraft:nether_wart_block",
+    "minecraft:red_nether_brick",
+    "minecraft:bone_block",
+    "minecraft:structure_void",
+    "minecraft:observer",
+    "minecraft:white_shulker_box",
+    "minecraft:orange_shulker_box",
+    "minecraft:magenta_shulker_box",
+    "minecraft:light_blue_shulker_box",
+    "minecraft:yellow_shulker_box",
+    "minecraft:lime_shulker_box",
+    "minecraft:pink_shulker_box",
+    "minecraft:gray_shulker_box",
+    "minecraft:silver_shulker_box",
+    "minecraft:cyan_shulker_box",
+    "minecraft:purple_shulker_box",
+    "minecraft:blue_shulker_box",
+    "minecraft:brown_shulker_box",
+    "minecraft:green_shulker_box",
+    "minecraft:red_shulker_box",
+    "minecraft:black_shulker_box",
+    "minecraft:white_glazed_terracotta",
+    "minecraft:orange_glazed_terracotta",
+    "minecraft:magenta_glazed_terracotta",
+    "minecraft:light_blue_glazed_terracotta",
+    "minecraft:yellow_glazed_terracotta",
+    "minecraft:lime_glazed_terracotta",
+    "minecraft:pink_glazed_terracotta",
+    "minecraft:gray_glazed_terracotta",
+    "minecraft:silver_glazed_terracotta",
+    "minecraft:cyan_glazed_terracotta",
+    "minecraft:purple_glazed_terracotta",
+    "minecraft:blue_glazed_terracotta",
+    "minecraft:brown_glazed_terracotta",
+    "minecraft:green_glazed_terracotta",
+    "minecraft:red_glazed_terracotta",
+    "minecraft:black_glazed_terracotta",
+    "minecraft:concrete",
+    "minecraft:concrete_powder",
+    "",
+    "",
+    "minecraft:structure_block",
+];
+
+/// The 1.12 items that were not blocks, with their numeric ids and the ids of
+/// their variant with damage `0` today.
+const LEGACY_ITEMS: [(u16, &str, &str); 209] = [
+    (256, "minecraft:iron_shovel", "minecraft:iron_shovel"),
+    (257, "minecraft:iron_pickaxe", "minecraft:iron_pickaxe"),
+    (258, "minecraft:iron_axe", "minecraft:iron_axe"),
+    (
+        259,
+        "minecraft:flint_and_steel",
+        "minecraft:flint_and_steel",
+    ),
+    (260, "minecraft:apple", "minecraft:apple"),
+    (261, "minecraft:bow", "minecraft:bow"),
+    (262, "minecraft:arrow", "minecraft:arrow"),
+    (263, "minecraft:coal", "minecraft:coal"),
+    (264, "minecraft:diamond", "minecraft:diamond"),
+    (265, "minecraft:iron_ingot", "minecraft:iron_ingot"),
+    (266, "minecraft:gold_ingot", "minecraft:gold_ingot"),
+    (267, "minecraft:iron_sword", "minecraft:iron_sword"),
+    (268, "minecraft:wooden_sword", "minecraft:wooden_sword"),
+    (269, "minecraft:wooden_shovel", "minecraft:wooden_shovel"),
+    (270, "minecraft:wooden_pickaxe", "minecraft:wooden_pickaxe"),
+    (271, "minecraft:wooden_axe", "minecraft:wooden_axe"),
+    (272, "minecraft:stone_sword", "minecraft:stone_sword"),
+    (273, "minecraft:stone_shovel", "minecraft:stone_shovel"),
+    (274, "minecraft:stone_pickaxe", "minecraft:stone_pickaxe"),
+    (275, "minecraft:stone_axe", "minecraft:stone_axe"),
+    (276, "minecraft:diamond_sword", "minecraft:diamond_sword"),
+    (277, "minecraft:diamond_shovel", "minecraft:diamond_shovel"),
+    (
+        278,
+        "minecraft:diamond_pickaxe",
+        "minecraft:diamond_pickaxe",
+    ),
+    (279, "minecraft:diamond_axe", "minecraft:diamond_axe"),
+    (280, "minecraft:stick", "minecraft:stick"),
+    (281, "minecraft:bowl", "minecraft:bowl"),
+    (282, "minecraft:mushroom_stew", "minecraft:mushroom_stew"),
+    (283, "minecraft:golden_sword", "minecraft:golden_sword"),
+    (284, "minecraft:golden_shovel", "minecraft:golden_shovel"),
+    (285, "minecraft:golden_pickaxe", "minecraft:golden_pickaxe"),
+    (286, "minecraft:golden_axe", "minecraft:golden_axe"),
+    (287, "minecraft:string", "minecraft:string"),
+    (288, "minecraft:feather", "minecraft:feather"),
+    (289, "minecraft:gunpowder", "minecraft:gunpowder"),
+    (290, "minecraft:wooden_hoe", "minecraft:wooden_hoe"),
+    (291, "minecraft:stone_hoe", "minecraft:stone_hoe"),
+    (292, "minecraft:iron_hoe", "minecraft:iron_hoe"),
+    (293, "minecraft:diamond_hoe", "minecraft:diamond_hoe"),
+    (294, "minecraft:golden_hoe", "minecraft:golden_hoe"),
+    (295, "minecraft:wheat_seeds", "minecraft:wheat_seeds"),
+    (296, "minecraft:wheat", "minecraft:wheat"),
+    (297, "minecraft:bread", "minecraft:bread"),
+    (298, "minecraft:leather_helmet", "minecraft:leather_helmet"),
+    (
+        299,
+        "minecraft:leather_chestplate",
+        "minecraft:leather_chestplate",
+    ),
+    (
+        300,
+        "minecraft:leather_leggings",
+        "minecraft:leather_leggings",
+    ),
+    (301, "minecraft:leather_boots", "minecraft:leather_boots"),
+    (
+        302,
+        "minecraft:chainmail_helmet",
+        "minecraft:chainmail_helmet",
+    ),
+    (
+        303,
+        "minecraft:chainmail_chestplate",
+        "minecraft:chainmail_chestplate",
+    ),
+    (
+        304,
+        "minecraft:chainmail_leggings",
+        "minecraft:chainmail_leggings",
+    ),
+    (
+        305,
+        "minecraft:chainmail_boots",
+        "minecraft:chainmail_boots",
+    ),
+    (306, "minecraft:iron_helmet", "minecraft:iron_helmet"),
+    (
+        307,
+        "minecraft:iron_chestplate",
+        "minecraft:iron_chestplate",
+    ),
+    (308, "minecraft:iron_leggings", "minecraft:iron_leggings"),
+    (309, "minecraft:iron_boots", "minecraft:iron_boots"),
+    (310, "minecraft:diamond_helmet", "minecraft:diamond_helmet"),
+    (
+        311,
+        "minecraft:diamond_chestplate",
+        "minecraft:diamond_chestplate",
+    ),
+    (
+        312,
+        "minecraft:diamond_leggings",
+        "minecraft:diamond_leggings",
+    ),
+    (313, "minecraft:diamond_boots", "minecraft:diamond_boots"),
+    (314, "minecraft:golden_helmet", "minecraft:golden_helmet"),
+    (
+        315,
+        "minecraft:golden_chestplate",
+        "minecraft:golden_chestplate",
+    ),
+    (
+        316,
+        "minecraft:golden_leggings",
+        "minecraft:golden_leggings",
+    ),
+    (317, "minecraft:golden_boots", "minecraft:golden_boots"),
+    (318, "minecraft:flint", "minecraft:flint"),
+    (319, "minecraft:porkchop", "minecraft:porkchop"),
+    (
+        320,
+        "minecraft:cooked_porkchop",
+        "minecraft:cooked_porkchop",
+    ),
+    (321, "minecraft:painting", "minecraft:painting"),
+    (322, "minecraft:golden_apple", "minecraft:golden_apple"),
+    (323, "minecraft:sign", "minecraft:oak_sign"),
+    (324, "minecraft:wooden_door", "minecraft:oak_door"),
+    (325, "minecraft:bucket", "minecraft:bucket"),
+    (326, "minecraft:water_bucket", "minecraft:water_bucket"),
+    (327, "minecraft:lava_bucket", "minecraft:lava_bucket"),
+    (328, "minecraft:minecart", "minecraft:minecart"),
+    (329, "minecraft:saddle", "minecraft:saddle"),
+    (330, "minecraft:iron_door", "minecraft:iron_door"),
+    (331, "minecraft:redstone", "minecraft:redstone"),
+    (332, "minecraft:snowball", "minecraft:snowball"),
+    (333, "minecraft:boat", "minecraft:oak_boat"),
+    (334, "minecraft:leather", "minecraft:leather"),
+    (335, "minecraft:milk_bucket", "minecraft:milk_bucket"),
+    (336, "minecraft:brick", "minecraft:brick"),
+    (337, "minecraft:clay_ball", "minecraft:clay_ball"),
+    (338, "minecraft:reeds", "minecraft:sugar_cane"),
+    (339, "minecraft:paper", "minecraft:paper"),
+    (340, "minecraft:book", "minecraft:book"),
+    (341, "minecraft:slime_ball", "minecraft:slime_ball"),
+    (342, "minecraft:chest_minecart", "minecraft:chest_minecart"),
+    (
+        343,
+        "minecraft:furnace_minecart",
+        "minecraft:furnace_minecart",
+    ),
+    (344, "minecraft:egg", "minecraft:egg"),
+    (345, "minecraft:compass", "minecraft:compass"),
+    (346, "minecraft:fishing_rod", "minecraft:fishing_rod"),
+    (347, "minecraft:clock", "minecraft:clock"),
+    (348, "minecraft:glowstone_dust", "minecraft:glowstone_dust"),
+    (349, "minecraft:fish", "minecraft:cod"),
+    (350, "minecraft:cooked_fish", "minecraft:cooked_cod"),
+    (351, "minecraft:dye", "minecraft:ink_sac"),
+    (352, "minecraft:bone", "minecraft:bone"),
+    (353, "minecraft:sugar", "minecraft:sugar"),
+    (354, "minecraft:cake", "minecraft:cake"),
+    (355, "minecraft:bed", "minecraft:white_bed"),
+    (356, "minecraft:repeater", "minecraft:repeater"),
+    (357, "minecraft:cookie", "minecraft:cookie"),
+    (358, "minecraft:filled_map", "minecraft:filled_map"),
+    (359, "minecraft:shears", "minecraft:shears"),
+    (360, "minecraft:melon", "minecraft:melon_slice"),
+    (361, "minecraft:pumpkin_seeds", "minecraft:pumpkin_seeds"),
+    (362, "minecraft:melon_seeds", "minecraft:melon_seeds"),
+    (363, "minecraft:beef", "minecraft:beef"),
+    (364, "minecraft:cooked_beef", "minecraft:cooked_beef"),
+    (365, "minecraft:chicken", "minecraft:chicken"),
+    (366, "minecraft:cooked_chicken", "minecraft:cooked_chicken"),
+    (367, "minecraft:rotten_flesh", "minecraft:rotten_flesh"),
+    (368, "minecraft:ender_pearl", "minecraft:ender_pearl"),
+    (369, "minecraft:blaze_rod", "minecraft:blaze_rod"),
+    (370, "minecraft:ghast_tear", "minecraft:ghast_tear"),
+    (371, "minecraft:gold_nugget", "minecraft:gold_nugget"),
+    (372, "minecraft:nether_wart", "minecraft:nether_wart"),
+    (373, "minecraft:potion", "minecraft:potion"),
+    (374, "minecraft:glass_bottle", "minecraft:glass_bottle"),
+    (375, "minecraft:spider_eye", "minecraft:spider_eye"),
+    (
+        376,
+        "minecraft:fermented_spider_eye",
+        "minecraft:fermented_spider_eye",
+    ),
+    (377, "minecraft:blaze_powder", "minecraft:blaze_powder"),
+    (378, "minecraft:magma_cream", "minecraft:magma_cream"),
+    (379, "minecraft:brewing_stand", "minecraft:brewing_stand"),
+    (380, "minecraft:cauldron", "minecraft:cauldron"),
+    (381, "minecraft:ender_eye", "minecraft:ender_eye"),
+    (
+        382,
+        "minecraft:speckled_melon",
+        "minecraft:glistering_melon_slice",
+    ),
+    (383, "minecraft:spawn_egg", "minecraft:pig_spawn_egg"),
+    (
+        384,
+        "minecraft:experience_bottle",
+        "minecraft:experience_bottle",
+    ),
+    (385, "minecraft:fire_charge", "minecraft:fire_charge"),
+    (386, "minecraft:writable_book", "minecraft:writable_book"),
+    (387, "minecraft:written_book", "minecraft:written_book"),
+    (388, "minecraft:emerald", "minecraft:emerald"),
+    (389, "minecraft:item_frame", "minecraft:item_frame"),
+    (390, "minecraft:flower_pot", "minecraft:flower_pot"),
+    (391, "minecraft:carrot", "minecraft:carrot"),
+    (392, "minecraft:potato", "minecraft:potato"),
+    (393, "minecraft:baked_potato", "minecraft:baked_potato"),
+    (
+        394,
+        "minecraft:poisonous_potato",
+        "minecraft:poisonous_potato",
+    ),
+    (395, "minecraft:map", "minecraft:map"),
+    (396, "minecraft:golden_carrot", "minecraft:golden_carrot"),
+    (397, "minecraft:skull", "minecraft:skeleton_skull"),
+    (
+        398,
+        "minecraft:carrot_on_a_stick",
+        "minecraft:carrot_on_a_stick",
+    ),
+    (399, "minecraft:nether_star", "minecraft:nether_star"),
+    (400, "minecraft:pumpkin_pie", "minecraft:pumpkin_pie"),
+    (401, "minecraft:fireworks", "minecraft:firework_rocket"),
+    (402, "minecraft:firework_charge", "minecraft:firework_star"),
+    (403, "minecraft:enchanted_book", "minecraft:enchanted_book"),
+    (404, "minecraft:comparator", "minecraft:comparator"),
+    (405, "minecraft:netherbrick", "minecraft:nether_brick"),
+    (406, "minecraft:quartz", "minecraft:quartz"),
+    (407, "minecraft:tnt_minecart", "minecraft:tnt_minecart"),
+    (
+        408,
+        "minecraft:hopper_minecart",
+        "minecraft:hopper_minecart",
+    ),
+    (
+        409,
+        "minecraft:prismarine_shard",
+        "minecraft:prismarine_shard",
+    ),
+    (
+        410,
+        "minecraft:prismarine_crystals",
+        "minecraft:prismarine_crystals",
+    ),
+    (411, "minecraft:rabbit", "minecraft:rabbit"),
+    (412, "minecraft:cooked_rabbit", "minecraft:cooked_rabbit"),
+    (413, "minecraft:rabbit_stew", "minecraft:rabbit_stew"),
+    (414, "minecraft:rabbit_foot", "minecraft:rabbit_foot"),
+    (415, "minecraft:rabbit_hide", "minecraft:rabbit_hide"),
+    (416, "minecraft:armor_stand", "minecraft:armor_stand"),
+    (
+        417,
+        "minecraft:iron_horse_armor",
+        "minecraft:iron_horse_armor",
+    ),
+    (
+        418,
+        "minecraft:golden_horse_armor",
+        "minecraft:golden_horse_armor",
+    ),
+    (
+        419,
+        "minecraft:diamond_horse_armor",
+        "minecraft:diamond_horse_armor",
+    ),
+    (420, "minecraft:lead", "minecraft:lead"),
+    (421, "minecraft:name_tag", "minecraft:name_tag"),
+    (
+        422,
+        "minecraft:command_block_minecart",
+        "minecraft:command_block_minecart",
+    ),
+    (423, "minecraft:mutton", "minecraft:mutton"),
+    (424, "minecraft:cooked_mutton", "minecraft:cooked_mutton"),
+    (425, "minecraft:banner", "minecraft:black_banner"),
+    (426, "minecraft:end_crystal", "minecraft:end_crystal"),
+    (427, "minecraft:spruce_door", "minecraft:spruce_door"),
+    (428, "minecraft:birch_door", "minecraft:birch_door"),
+    (429, "minecraft:jungle_door", "minecraft:jungle_door"),
+    (430, "minecraft:acacia_door", "minecraft:acacia_door"),
+    (431, "minecraft:dark_oak_door", "minecraft:dark_oak_door"),
+    (432, "minecraft:chorus_fruit", "minecraft:chorus_fruit"),
+    (
+        433,
+        "minecraft:chorus_fruit_popped",
+        "minecraft:popped_chorus_fruit",
+    ),
+    (434, "minecraft:beetroot", "minecraft:beetroot"),
+    (435, "minecraft:beetroot_seeds", "minecraft:beetroot_seeds"),
+    (436, "minecraft:beetroot_soup", "minecraft:beetroot_soup"),
+    (437, "minecraft:dragon_breath", "minecraft:dragon_breath"),
+    (438, "minecraft:splash_potion", "minecraft:splash_potion"),
+    (439, "minecraft:spectral_arrow", "minecraft:spectral_arrow"),
+    (440, "minecraft:tipped_arrow", "minecraft:tipped_arrow"),
+    (
+        441,
+        "minecraft:lingering_potion",
+        "minecraft:lingering_potion",
+    ),
+    (442, "minecraft:shield", "minecraft:shield"),
+    (443, "minecraft:elytra", "minecraft:elytra"),
+    (444, "minecraft:spruce_boat", "minecraft:spruce_boat"),
+    (445, "minecraft:birch_boat", "minecraft:birch_boat"),
+    (446, "minecraft:jungle_boat", "minecraft:jungle_boat"),
+    (447, "minecraft:acacia_boat", "minecraft:acacia_boat"),
+    (448, "minecraft:dark_oak_boat", "minecraft:dark_oak_boat"),
+    (
+        449,
+        "minecraft:totem_of_undying",
+        "minecraft:totem_of_undying",
+    ),
+    (450, "minecraft:shulker_shell", "minecraft:shulker_shell"),
+    (452, "minecraft:iron_nugget", "minecraft:iron_nugget"),
+    (453, "minecraft:knowledge_book", "minecraft:knowledge_book"),
+    (2256, "minecraft:record_13", "minecraft:music_disc_13"),
+    (2257, "minecraft:record_cat", "minecraft:music_disc_cat"),
+    (
+        2258,
+        "minecraft:record_blocks",
+        "minecraft:music_disc_blocks",
+    ),
+    (2259, "minecraft:record_chirp", "minecraft:music_disc_chirp"),
+    (2260, "minecraft:record_far", "minecraft:music_disc_far"),
+    (2261, "minecraft:record_mall", "minecraft:music_disc_mall"),
+    (
+        2262,
+        "minecraft:record_mellohi",
+        "minecraft:music_disc_mellohi",
+    ),
+    (2263, "minecraft:record_stal", "minecraft:music_disc_stal"),
+    (2264, "minecraft:record_strad", "minecraft:music_disc_strad"),
+    (2265, "minecraft:record_ward", "minecraft:music_disc_ward"),
+    (2266, "minecraft:record_11", "minecraft:music_disc_11"),
+    (2267, "minecraft:record_wait", "minecraft:music_disc_wait"),
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn every_block_flattens_with_data_zero() {
+        for id in 0..256 {
+            if let Some(name) = legacy_block_name(id) {
+                let block = flatten_block(id, 0);
+                assert!(block.is_some(), "{} did not flatten", name);
+            }
+        }
+    }
+
+    #[test]
+    fn block_data_selects_state() {
+        let block = flatten_block(17, 0b0110).unwrap();
+        assert_eq!(block.to_string(), "minecraft:birch_log[axis=x]");
+
+        let block = flatten_block(17, 0b1101).unwrap();
+        assert_eq!(block.to_string(), "minecraft:spruce_wood[axis=y]");
+
+        let block = flatten_block(64, 0b1001).unwrap();
+        assert_eq!(block.property("hinge"), Some("right"));
+        assert_eq!(block.property("half"), Some("upper"));
+
+        // Invalid data values fall back to the default state.
+        assert_eq!(flatten_block(1, 9).unwrap().name, "minecraft:stone");
+
+        let block = flatten_block_name("silver_glazed_terracotta", 2).unwrap();
+        assert_eq!(
+            block.to_string(),
+            "minecraft:light_gray_glazed_terracotta[facing=north]"
+        );
+    }
+
+    #[test]
+    fn items_flatten_by_damage() {
+        assert_eq!(flatten_item(35, 8), Some("minecraft:light_gray_wool"));
+        assert_eq!(flatten_item(145, 2), Some("minecraft:damaged_anvil"));
+        assert_eq!(flatten_item(425, 0), Some("minecraft:black_banner"));
+        assert_eq!(flatten_item(355, 14), Some("minecraft:red_bed"));
+        assert_eq!(flatten_item(276, 100), Some("minecraft:diamond_sword"));
+        assert_eq!(flatten_item(2263, 0), Some("minecraft:music_disc_stal"));
+        assert_eq!(flatten_item(26, 0), None);
+        assert_eq!(flatten_item_name("minecraft:spawn_egg", 0), None);
+    }
+}
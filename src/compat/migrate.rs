@@ -0,0 +1,236 @@
+use crate::{
+    api::{ResourceLocation, TargetVersion},
+    schemas::{
+        blockstates::{ModelProperties, Variant},
+        models::Texture,
+        worldgen::biome::{CustomeBiome, Precipitation},
+        BlockStates, Model,
+    },
+};
+
+/// Conversions of a schema between the representations used by different
+/// versions of the game.
+///
+/// The schemas of this crate parse the files of every supported version, so
+/// a value knows which representation it is in. Upgrading rewrites the parts
+/// that the given newer version changed, and downgrading rewrites the parts
+/// that versions after the given older one changed. Parts that are already in
+/// the representation of the target version are left as they are.
+///
+/// Downgrading cannot restore information that newer versions do not store,
+/// so fields that only older versions have are left unset. See the
+/// implementations for the changes that each schema knows about.
+pub trait Migrate: Sized {
+    /// Converts `self` into the representation used by the given newer
+    /// version.
+    fn upgrade(self, to: TargetVersion) -> Self;
+
+    /// Converts `self` into the representation used by the given older
+    /// version.
+    fn downgrade(self, to: TargetVersion) -> Self;
+}
+
+/// Biomes know about the following changes:
+///
+/// * 1.18 removed `depth` and `scale`.
+/// * 1.19 removed `category`.
+/// * 1.19.4 replaced `precipitation` with `has_precipitation`.
+///
+/// # Example
+///
+/// ```
+/// use minecraft_assets::{api::TargetVersion, compat::Migrate, schemas::worldgen::biome::*};
+///
+/// let biome = CustomeBiome {
+///     precipitation: Some(Precipitation::Snow),
+///     category: Some(String::from("icy")),
+///     ..Default::default()
+/// };
+///
+/// let biome = biome.upgrade(TargetVersion::V1_21);
+/// assert_eq!(biome.has_precipitation, Some(true));
+/// assert_eq!(biome.precipitation, None);
+/// assert_eq!(biome.category, None);
+///
+/// let biome = biome.downgrade(TargetVersion::V1_18);
+/// assert_eq!(biome.has_precipitation, None);
+/// assert_eq!(biome.precipitation, Some(Precipitation::Snow));
+/// ```
+impl Migrate for CustomeBiome {
+    fn upgrade(mut self, to: TargetVersion) -> Self {
+        let format = to.data_pack_format();
+
+        if format >= TargetVersion::V1_18.data_pack_format() {
+            self.depth = None;
+            self.scale = None;
+        }
+        if format >= TargetVersion::V1_19.data_pack_format() {
+            self.category = None;
+        }
+        if format >= TargetVersion::V1_19_4.data_pack_format() {
+            if let Some(precipitation) = self.precipitation.take() {
+                self.has_precipitation = Some(precipitation != Precipitation::None);
+            }
+        }
+
+        self
+    }
+
+    fn downgrade(mut self, to: TargetVersion) -> Self {
+        if to.data_pack_format() < TargetVersion::V1_19_4.data_pack_format()
+            && self.has_precipitation.is_some()
+        {
+            self.precipitation = Some(self.normalized_precipitation());
+            self.has_precipitation = None;
+        }
+
+        self
+    }
+}
+
+/// Models know about the following changes:
+///
+/// * 1.13 renamed the `blocks/` and `items/` texture directories to `block/`
+///   and `item/`.
+///
+/// # Example
+///
+/// ```
+/// use minecraft_assets::{api::TargetVersion, compat::Migrate, schemas::models::*};
+/// use maplit::hashmap;
+///
+/// let model = Model {
+///     parent: Some("block/cube_all".parse().unwrap()),
+///     textures: Some(Textures::from(hashmap! { "all" => "blocks/stone" })),
+///     ..Default::default()
+/// };
+///
+/// let model = model.upgrade(TargetVersion::V1_13);
+/// assert_eq!(model.textures.as_ref().unwrap()["all"].0, "block/stone");
+///
+/// let model = model.downgrade(TargetVersion::V1_12_2);
+/// assert_eq!(model.textures.as_ref().unwrap()["all"].0, "blocks/stone");
+/// ```
+impl Migrate for Model {
+    fn upgrade(mut self, to: TargetVersion) -> Self {
+        if to.resource_pack_format() >= TargetVersion::V1_13.resource_pack_format() {
+            self.map_texture_locations(|path| {
+                rename_directory(path, "blocks/", "block/")
+                    .or_else(|| rename_directory(path, "items/", "item/"))
+            });
+        }
+
+        self
+    }
+
+    fn downgrade(mut self, to: TargetVersion) -> Self {
+        if to.resource_pack_format() < TargetVersion::V1_13.resource_pack_format() {
+            self.map_texture_locations(|path| {
+                rename_directory(path, "block/", "blocks/")
+                    .or_else(|| rename_directory(path, "item/", "items/"))
+            });
+        }
+
+        self
+    }
+}
+
+impl Model {
+    fn map_texture_locations(&mut self, f: impl Fn(&str) -> Option<String>) {
+        for texture in self.textures.iter_mut().flat_map(|t| t.values_mut()) {
+            if let Some(renamed) = texture.location().and_then(&f) {
+                *texture = Texture::from(renamed);
+            }
+        }
+    }
+}
+
+/// Block states know about the following changes:
+///
+/// * 1.13 made the paths of models relative to `models/` instead of
+///   `models/block/`, so that they start with `block/`. See the
+///   [`ModelIdentifier`][crate::api::ModelIdentifier] docs.
+///
+/// # Example
+///
+/// ```
+/// use minecraft_assets::{api::TargetVersion, compat::Migrate, schemas::BlockStates};
+///
+/// let states: BlockStates = serde_json::from_str(r#"{
+///     "variants": { "": { "model": "stone" } }
+/// }"#).unwrap();
+///
+/// let states = states.upgrade(TargetVersion::V1_13);
+/// assert_eq!(states.variants().unwrap()[""].models()[0].model, "block/stone");
+///
+/// let states = states.downgrade(TargetVersion::V1_12_2);
+/// assert_eq!(states.variants().unwrap()[""].models()[0].model, "stone");
+/// ```
+impl Migrate for BlockStates {
+    fn upgrade(mut self, to: TargetVersion) -> Self {
+        if to.resource_pack_format() >= TargetVersion::V1_13.resource_pack_format() {
+            self.map_models(|model| {
+                let (namespace, path) = split_namespace(model);
+                if path.contains('/') {
+                    None
+                } else {
+                    Some(format!("{}block/{}", namespace, path))
+                }
+            });
+        }
+
+        self
+    }
+
+    fn downgrade(mut self, to: TargetVersion) -> Self {
+        if to.resource_pack_format() < TargetVersion::V1_13.resource_pack_format() {
+            self.map_models(|model| {
+                let (namespace, path) = split_namespace(model);
+                path.strip_prefix("block/")
+                    .map(|name| format!("{}{}", namespace, name))
+            });
+        }
+
+        self
+    }
+}
+
+impl BlockStates {
+    fn map_models(&mut self, f: impl Fn(&str) -> Option<String>) {
+        let variants: Vec<&mut Variant> = match self {
+            Self::Variants { variants } => variants.values_mut().collect(),
+            Self::Multipart { cases } => cases.iter_mut().map(|case| &mut case.apply).collect(),
+        };
+
+        for models in variants {
+            let models: &mut [ModelProperties] = match models {
+                Variant::Single(model) => std::slice::from_mut(model),
+                Variant::Multiple(models) => models,
+            };
+            for model in models {
+                let renamed = f(model.model.as_str())
+                    .and_then(|renamed| ResourceLocation::new_owned(renamed).ok());
+                if let Some(renamed) = renamed {
+                    model.model = renamed;
+                }
+            }
+        }
+    }
+}
+
+/// Splits a resource location into its namespace (including the `:`, if any)
+/// and its path.
+fn split_namespace(location: &str) -> (&str, &str) {
+    match location.find(':') {
+        Some(index) => location.split_at(index + 1),
+        None => ("", location),
+    }
+}
+
+/// Replaces the leading directory of the path in the given resource location,
+/// or returns `None` if the path does not start with it.
+fn rename_directory(location: &str, from: &str, to: &str) -> Option<String> {
+    let (namespace, path) = split_namespace(location);
+    path.strip_prefix(from)
+        .map(|rest| format!("{}{}{}", namespace, to, rest))
+}
@@ -0,0 +1,60 @@
+//! Compatibility with older versions of the game.
+//!
+//! # The Flattening
+//!
+//! Until 1.12, blocks and items were stored as numeric ids together with a
+//! 4-bit block data value or a 16-bit item damage value, which selected the
+//! variant (e.g., the color of wool) and the state (e.g., the facing of stairs).
+//! 1.13 ([The Flattening]) replaced them with namespaced ids and block state
+//! properties. The functions in this module translate the old identifiers of
+//! schematics, maps, and packs into the ids used by the game today, like the
+//! game's own data fixers do when upgrading a world.
+//!
+//! # Example
+//!
+//! ```
+//! use minecraft_assets::compat;
+//!
+//! assert_eq!(compat::legacy_block_name(35), Some("minecraft:wool"));
+//!
+//! // Red wool.
+//! let block = compat::flatten_block(35, 14).unwrap();
+//! assert_eq!(block.name, "minecraft:red_wool");
+//!
+//! // Upside-down oak stairs facing north.
+//! let block = compat::flatten_block_name("minecraft:oak_stairs", 7).unwrap();
+//! assert_eq!(block.to_string(), "minecraft:oak_stairs[facing=north,half=top]");
+//! assert_eq!(block.variant_key(), "facing=north,half=top");
+//!
+//! // Bone meal.
+//! assert_eq!(compat::flatten_item(351, 15), Some("minecraft:bone_meal"));
+//! assert_eq!(compat::flatten_item_name("minecraft:dye", 4), Some("minecraft:lapis_lazuli"));
+//! ```
+//!
+//! # Migrations
+//!
+//! The [`Migrate`] trait converts schemas between the representations used by
+//! different versions, e.g., to port a pack to a newer version of the game.
+//!
+//! ```
+//! use minecraft_assets::{api::TargetVersion, compat::Migrate, schemas::BlockStates};
+//!
+//! let states: BlockStates = serde_json::from_str(r#"{
+//!     "variants": { "facing=north": { "model": "minecraft:furnace" } }
+//! }"#).unwrap();
+//!
+//! let states = states.upgrade(TargetVersion::LATEST);
+//! let variant = &states.variants().unwrap()["facing=north"];
+//! assert_eq!(variant.models()[0].model, "minecraft:block/furnace");
+//! ```
+//!
+//! [The Flattening]: <https://minecraft.wiki/w/Java_Edition_1.13/Flattening>
+
+mod flattening;
+mod migrate;
+
+pub use flattening::{
+    flatten_block, flatten_block_name, flatten_item, flatten_item_name, legacy_block_name,
+    legacy_item_name, FlattenedBlock,
+};
+pub use migrate::Migrate;
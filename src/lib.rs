@@ -2,5 +2,6 @@
 #![warn(missing_docs)]
 
 pub mod api;
+pub mod compat;
 pub mod schemas;
 pub mod versions;
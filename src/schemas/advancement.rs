@@ -0,0 +1,268 @@
+//! Serde-(de)serializable data types for
+//! `data/<namespace>/advancements/**/*.json`.
+//!
+//! Start here: [`Advancement`].
+//!
+//! See <https://minecraft.fandom.com/wiki/Advancement/JSON_format>.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::ResourceLocation,
+    schemas::{components::DataComponents, text::TextComponent},
+};
+
+/// An advancement as stored in the `data/<namespace>/advancements/`
+/// directory.
+///
+/// Advancements form a tree through their [`parent`] fields. Advancements
+/// without a parent are the roots of a tab in the advancements screen.
+///
+/// See also the corresponding section of the [wiki page].
+///
+/// [`parent`]: Self::parent
+/// [wiki page]: <https://minecraft.fandom.com/wiki/Advancement/JSON_format>
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::advancement::*;
+/// let advancement: Advancement = serde_json::from_str(r#"
+///     {
+///         "display": {
+///             "icon": { "item": "minecraft:wooden_pickaxe" },
+///             "title": { "translate": "advancements.story.mine_stone.title" },
+///             "description": { "translate": "advancements.story.mine_stone.description" },
+///             "frame": "task"
+///         },
+///         "parent": "minecraft:story/root",
+///         "criteria": {
+///             "get_stone": {
+///                 "trigger": "minecraft:inventory_changed",
+///                 "conditions": {
+///                     "items": [{ "tag": "minecraft:stone_tool_materials" }]
+///                 }
+///             }
+///         },
+///         "requirements": [["get_stone"]]
+///     }
+/// "#).unwrap();
+///
+/// assert!(!advancement.is_root());
+/// assert_eq!(advancement.parent.unwrap(), "minecraft:story/root");
+///
+/// let display = advancement.display.unwrap();
+/// assert_eq!(display.icon.unwrap().id, "minecraft:wooden_pickaxe");
+/// assert_eq!(display.frame, Frame::Task);
+///
+/// assert_eq!(
+///     advancement.criteria["get_stone"].trigger,
+///     "minecraft:inventory_changed"
+/// );
+/// ```
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Advancement {
+    /// The display settings of the advancement.
+    ///
+    /// If not present, the advancement is hidden from the advancements screen
+    /// (e.g., recipe unlocking advancements).
+    pub display: Option<AdvancementDisplay>,
+
+    /// The namespaced ID of the parent advancement.
+    ///
+    /// If not present, this advancement is the root of a new tab.
+    pub parent: Option<ResourceLocation<'static>>,
+
+    /// The required criteria that have to be met, by name.
+    #[serde(default)]
+    pub criteria: HashMap<String, Criterion>,
+
+    /// A list of requirements (all the lists have to be met), where each
+    /// requirement is a list of criteria names (any of which have to be met).
+    ///
+    /// If not present, all criteria are required.
+    pub requirements: Option<Vec<Vec<String>>>,
+
+    /// The rewards given when the advancement is completed.
+    pub rewards: Option<AdvancementRewards>,
+
+    /// Whether a telemetry event should be sent when the advancement is
+    /// completed.
+    ///
+    /// Added in 1.20.
+    pub sends_telemetry_event: Option<bool>,
+
+    /// The fields that this crate does not model (e.g., fields added by mods
+    /// or by newer versions), kept so that they survive a round trip.
+    ///
+    /// Requires the `preserve-unknown` feature.
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten, serialize_with = "crate::schemas::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Advancement {
+    /// Returns `true` if this advancement is the root of a tab in the
+    /// advancements screen (i.e., it has no parent).
+    pub fn is_root(&self) -> bool {
+        self.parent.is_none()
+    }
+
+    /// Returns the requirements of the advancement, where each (outer) list
+    /// has to be met by completing any of the criteria in the inner list.
+    ///
+    /// If [`requirements`][Self::requirements] is not specified, every
+    /// criterion is required.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::advancement::*;
+    /// let advancement: Advancement = serde_json::from_str(r#"
+    ///     {
+    ///         "criteria": {
+    ///             "a": { "trigger": "minecraft:tick" }
+    ///         }
+    ///     }
+    /// "#).unwrap();
+    ///
+    /// assert_eq!(advancement.effective_requirements(), vec![vec!["a"]]);
+    /// ```
+    pub fn effective_requirements(&self) -> Vec<Vec<&str>> {
+        match self.requirements {
+            Some(ref requirements) => requirements
+                .iter()
+                .map(|any_of| any_of.iter().map(String::as_str).collect())
+                .collect(),
+            None => {
+                let mut names: Vec<&str> = self.criteria.keys().map(String::as_str).collect();
+                names.sort_unstable();
+                names.into_iter().map(|name| vec![name]).collect()
+            }
+        }
+    }
+}
+
+/// Specifies how an [`Advancement`] is displayed in the advancements screen.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct AdvancementDisplay {
+    /// The item used as the icon of the advancement.
+    pub icon: Option<AdvancementIcon>,
+
+    /// The title of the advancement, as a JSON text component.
+    pub title: TextComponent,
+
+    /// The description of the advancement, as a JSON text component.
+    pub description: TextComponent,
+
+    /// The type of frame for the icon. Defaults to [`Frame::Task`].
+    #[serde(default)]
+    pub frame: Frame,
+
+    /// The resource location of the background texture of the tab.
+    ///
+    /// **Only used by root advancements.**
+    pub background: Option<ResourceLocation<'static>>,
+
+    /// Whether to show a toast when the advancement is completed. Defaults to
+    /// `true`.
+    #[serde(default = "AdvancementDisplay::default_true")]
+    pub show_toast: bool,
+
+    /// Whether to announce the completion in the chat. Defaults to `true`.
+    #[serde(default = "AdvancementDisplay::default_true")]
+    pub announce_to_chat: bool,
+
+    /// Whether to hide the advancement and its children until it is
+    /// completed. Defaults to `false`.
+    #[serde(default)]
+    pub hidden: bool,
+}
+
+impl AdvancementDisplay {
+    pub(crate) const fn default_true() -> bool {
+        true
+    }
+}
+
+/// The item used as the icon of an [`Advancement`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct AdvancementIcon {
+    /// The namespaced ID of the item.
+    ///
+    /// Prior to 1.20.5 this field is called `item`. Both names are accepted,
+    /// but it is always serialized as `id`.
+    #[serde(alias = "item")]
+    pub id: ResourceLocation<'static>,
+
+    /// The NBT data of the item, as an SNBT string, which can be parsed with
+    /// [`SnbtValue::parse()`][crate::schemas::snbt::SnbtValue::parse].
+    ///
+    /// Removed in 1.20.5 in favor of `components`.
+    pub nbt: Option<String>,
+
+    /// The number of items in the stack.
+    ///
+    /// Added in 1.20.5.
+    pub count: Option<u32>,
+
+    /// Data components to apply to the item.
+    ///
+    /// Added in 1.20.5.
+    pub components: Option<DataComponents>,
+}
+
+/// The type of frame for the icon of an [`Advancement`].
+#[derive(Deserialize, Serialize, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum Frame {
+    /// A frame with a plain border.
+    #[default]
+    Task,
+
+    /// A frame with a rounded border.
+    Goal,
+
+    /// A frame with a spiked border.
+    Challenge,
+}
+
+/// A criterion of an [`Advancement`].
+///
+/// *only the trigger name is typed; the trigger conditions are kept as raw
+/// JSON*
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Criterion {
+    /// The namespaced ID of the trigger, e.g., `minecraft:inventory_changed`.
+    pub trigger: ResourceLocation<'static>,
+
+    /// The conditions that have to be met for the trigger to count.
+    pub conditions: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+/// The rewards given when an [`Advancement`] is completed.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct AdvancementRewards {
+    /// The namespaced IDs of the recipes to unlock.
+    #[serde(default)]
+    pub recipes: Vec<ResourceLocation<'static>>,
+
+    /// The namespaced IDs of the loot tables to give to the player.
+    #[serde(default)]
+    pub loot: Vec<ResourceLocation<'static>>,
+
+    /// The amount of experience to give.
+    #[serde(default)]
+    pub experience: i32,
+
+    /// The namespaced ID of a function to run.
+    pub function: Option<ResourceLocation<'static>>,
+}
@@ -0,0 +1,87 @@
+//! Serde-(de)serializable data types for the launcher's
+//! `assets/indexes/<id>.json` files.
+//!
+//! Start here: [`AssetIndex`].
+//!
+//! See <https://minecraft.fandom.com/wiki/.minecraft#Assets>.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// An asset index, which maps the virtual paths of assets that are not in the
+/// client jar (e.g., sounds and most languages) to the hashed files in the
+/// launcher's `assets/objects/` directory.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::asset_index::*;
+/// let index: AssetIndex = serde_json::from_str(r#"
+///     {
+///         "objects": {
+///             "minecraft/sounds/ambient/cave/cave1.ogg": {
+///                 "hash": "4532ac40da9bf3e7f3d5ab0a1bd8a4e3c5fa1d3d",
+///                 "size": 31304
+///             },
+///             "minecraft/lang/de_de.json": {
+///                 "hash": "0fb3e7a1d40a3b3a5d9b4ff0e5a5c7a9b3dbd1e0",
+///                 "size": 415678
+///             }
+///         }
+///     }
+/// "#).unwrap();
+///
+/// let object = &index.objects["minecraft/sounds/ambient/cave/cave1.ogg"];
+/// assert_eq!(object.object_path(), "45/4532ac40da9bf3e7f3d5ab0a1bd8a4e3c5fa1d3d");
+/// assert!(!index.is_virtual);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct AssetIndex {
+    /// Map of virtual paths (e.g., `minecraft/sounds.json`) to the objects
+    /// that store them.
+    pub objects: HashMap<String, AssetObject>,
+
+    /// If true, the launcher also copies the objects to
+    /// `assets/virtual/<id>/` under their virtual paths.
+    ///
+    /// Only used by the `legacy` index of versions before 1.7.
+    #[serde(default, rename = "virtual")]
+    pub is_virtual: bool,
+
+    /// If true, the launcher also copies the objects to the `resources/`
+    /// directory of the game directory under their virtual paths.
+    ///
+    /// Only used by the `pre-1.6` index.
+    #[serde(default)]
+    pub map_to_resources: bool,
+
+    /// The fields that this crate does not model (e.g., fields added by mods
+    /// or by newer versions), kept so that they survive a round trip.
+    ///
+    /// Requires the `preserve-unknown` feature.
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten, serialize_with = "crate::schemas::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// An object in an [`AssetIndex`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct AssetObject {
+    /// The SHA-1 hash of the object, which is also its file name.
+    pub hash: String,
+
+    /// The size of the object, in bytes.
+    pub size: u64,
+}
+
+impl AssetObject {
+    /// Returns the path of the object relative to the `assets/objects/`
+    /// directory, i.e., `<first two hash digits>/<hash>`.
+    pub fn object_path(&self) -> String {
+        let prefix = self.hash.get(..2).unwrap_or(&self.hash);
+        format!("{}/{}", prefix, self.hash)
+    }
+}
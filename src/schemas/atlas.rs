@@ -0,0 +1,178 @@
+//! Serde-(de)serializable data types for `assets/<namespace>/atlases/*.json`.
+//!
+//! Start here: [`Atlas`].
+//!
+//! See <https://minecraft.fandom.com/wiki/Resource_pack#Atlases>.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{api::ResourceLocation, schemas::pack_meta::ResourcePattern};
+
+/// An atlas configuration as stored in the `assets/<namespace>/atlases/`
+/// directory, e.g., `blocks.json`.
+///
+/// An atlas configuration lists the sources of the sprites that are stitched
+/// together into a texture atlas. The sources are applied in order, and later
+/// sources may remove sprites added by earlier ones.
+///
+/// Added in 1.19.3.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::atlas::*;
+/// let atlas: Atlas = serde_json::from_str(r#"
+///     {
+///         "sources": [
+///             { "type": "directory", "source": "block", "prefix": "block/" },
+///             { "type": "single", "resource": "minecraft:entity/bell/bell_body" },
+///             { "type": "filter", "pattern": { "path": "block/debug.*" } },
+///             {
+///                 "type": "paletted_permutations",
+///                 "textures": ["trims/models/armor/coast"],
+///                 "palette_key": "trims/color_palettes/trim_palette",
+///                 "permutations": { "gold": "trims/color_palettes/gold" }
+///             }
+///         ]
+///     }
+/// "#).unwrap();
+///
+/// assert_eq!(atlas.sources.len(), 4);
+///
+/// match &atlas.sources[0] {
+///     AtlasSource::Directory { source, prefix } => {
+///         assert_eq!(source, "block");
+///         assert_eq!(prefix, "block/");
+///     }
+///     _ => unreachable!(),
+/// }
+///
+/// match &atlas.sources[1] {
+///     AtlasSource::Single { sprite, .. } => assert!(sprite.is_none()),
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Atlas {
+    /// The sprite sources of the atlas, in order.
+    pub sources: Vec<AtlasSource>,
+
+    /// The fields that this crate does not model (e.g., fields added by mods
+    /// or by newer versions), kept so that they survive a round trip.
+    ///
+    /// Requires the `preserve-unknown` feature.
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten, serialize_with = "crate::schemas::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// A sprite source in an [`Atlas`].
+///
+/// The source's `type` field determines which variant is used.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(tag = "type")]
+pub enum AtlasSource {
+    /// Adds all textures in a directory and its subdirectories.
+    #[serde(rename = "directory", alias = "minecraft:directory")]
+    Directory {
+        /// The directory to search, relative to `textures/`.
+        source: String,
+
+        /// The prefix prepended to the names of the added sprites, which are
+        /// relative to [`source`][AtlasSource::Directory::source].
+        prefix: String,
+    },
+
+    /// Adds a single texture.
+    #[serde(rename = "single", alias = "minecraft:single")]
+    Single {
+        /// The resource location of the texture, relative to `textures/` and
+        /// without the `.png` extension.
+        resource: ResourceLocation<'static>,
+
+        /// The name of the added sprite. Defaults to
+        /// [`resource`][AtlasSource::Single::resource].
+        sprite: Option<String>,
+    },
+
+    /// Removes the sprites added by earlier sources whose names match the
+    /// given pattern.
+    #[serde(rename = "filter", alias = "minecraft:filter")]
+    Filter {
+        /// The pattern of the sprite names to remove.
+        pattern: ResourcePattern,
+    },
+
+    /// Adds sprites cut out of a larger texture.
+    #[serde(rename = "unstitch", alias = "minecraft:unstitch")]
+    Unstitch {
+        /// The resource location of the texture, relative to `textures/` and
+        /// without the `.png` extension.
+        resource: ResourceLocation<'static>,
+
+        /// The number of units the texture is divided into horizontally.
+        /// Defaults to `1.0`.
+        #[serde(default = "AtlasSource::default_divisor")]
+        divisor_x: f64,
+
+        /// The number of units the texture is divided into vertically.
+        /// Defaults to `1.0`.
+        #[serde(default = "AtlasSource::default_divisor")]
+        divisor_y: f64,
+
+        /// The regions to cut out of the texture.
+        regions: Vec<UnstitchRegion>,
+    },
+
+    /// Adds recolored versions of textures, where the colors of a palette key
+    /// texture are replaced with those of other palette textures.
+    #[serde(
+        rename = "paletted_permutations",
+        alias = "minecraft:paletted_permutations"
+    )]
+    PalettedPermutations {
+        /// The resource locations of the textures to recolor, relative to
+        /// `textures/` and without the `.png` extension.
+        textures: Vec<ResourceLocation<'static>>,
+
+        /// The resource location of the palette key texture, whose pixels
+        /// list the colors to replace.
+        palette_key: ResourceLocation<'static>,
+
+        /// Map of sprite name suffixes to the resource locations of the
+        /// palette textures to recolor with.
+        permutations: HashMap<String, String>,
+    },
+}
+
+impl AtlasSource {
+    pub(crate) const fn default_divisor() -> f64 {
+        1.0
+    }
+}
+
+/// A region of an [`AtlasSource::Unstitch`] source.
+///
+/// All coordinates are in units of the divisors of the source.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct UnstitchRegion {
+    /// The name of the added sprite.
+    pub sprite: String,
+
+    /// The x coordinate of the top-left corner of the region.
+    pub x: f64,
+
+    /// The y coordinate of the top-left corner of the region.
+    pub y: f64,
+
+    /// The width of the region.
+    pub width: f64,
+
+    /// The height of the region.
+    pub height: f64,
+}
@@ -0,0 +1,48 @@
+//! Serde-(de)serializable data types for
+//! `data/<namespace>/banner_pattern/*.json`.
+//!
+//! Start here: [`BannerPattern`].
+//!
+//! Banner patterns became data-driven in 1.20.5.
+//!
+//! See <https://minecraft.fandom.com/wiki/Banner_pattern_definition>.
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::ResourceLocation;
+
+/// A banner pattern as stored in the `data/<namespace>/banner_pattern/`
+/// directory.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::banner_pattern::*;
+/// let pattern: BannerPattern = serde_json::from_str(r#"
+///     {
+///         "asset_id": "minecraft:creeper",
+///         "translation_key": "block.minecraft.banner.creeper"
+///     }
+/// "#).unwrap();
+///
+/// assert_eq!(pattern.asset_id, "minecraft:creeper");
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BannerPattern {
+    /// The namespaced ID of the pattern texture, relative to
+    /// `textures/entity/banner/` and `textures/entity/shield/`.
+    pub asset_id: ResourceLocation<'static>,
+
+    /// The translation key of the pattern name, to which the dye color is
+    /// appended (e.g., `block.minecraft.banner.creeper.red`).
+    pub translation_key: String,
+
+    /// The fields that this crate does not model (e.g., fields added by mods
+    /// or by newer versions), kept so that they survive a round trip.
+    ///
+    /// Requires the `preserve-unknown` feature.
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten, serialize_with = "crate::schemas::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
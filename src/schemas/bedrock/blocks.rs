@@ -0,0 +1,168 @@
+//! Serde-(de)serializable data types for the `blocks.json` of Bedrock
+//! resource packs.
+//!
+//! Start here: [`BlocksJson`].
+//!
+//! See <https://wiki.bedrock.dev/blocks/blocks-json.html>.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::schemas::models::BlockFace;
+
+/// The textures and sounds of the blocks of a Bedrock resource pack, as stored
+/// in the `blocks.json` file at the root of the pack.
+///
+/// The textures are short names, which are looked up in the `texture_data` of
+/// the [`TextureAtlas`] in `textures/terrain_texture.json`.
+///
+/// [`TextureAtlas`]: crate::schemas::bedrock::TextureAtlas
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::bedrock::blocks::*;
+/// use minecraft_assets::schemas::models::BlockFace;
+///
+/// let blocks: BlocksJson = serde_json::from_str(r#"
+///     {
+///         "format_version": [1, 1, 0],
+///         "stone": { "textures": "stone", "sound": "stone" },
+///         "grass": {
+///             "isotropic": { "up": true, "down": true },
+///             "textures": { "up": "grass_top", "down": "grass_bottom", "side": "grass_side" },
+///             "carried_textures": { "up": "grass_carried_top", "side": "grass_carried" },
+///             "sound": "grass"
+///         }
+///     }
+/// "#).unwrap();
+///
+/// assert_eq!(blocks.blocks.len(), 2);
+///
+/// let grass = &blocks.blocks["grass"];
+/// let textures = grass.textures.as_ref().unwrap();
+/// assert_eq!(textures.face(BlockFace::Up), Some("grass_top"));
+/// assert_eq!(textures.face(BlockFace::North), Some("grass_side"));
+///
+/// let stone = &blocks.blocks["stone"];
+/// assert_eq!(stone.textures.as_ref().unwrap().face(BlockFace::East), Some("stone"));
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BlocksJson {
+    /// The version of the file format, e.g., `[1, 1, 0]`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format_version: Option<serde_json::Value>,
+
+    /// Map of block names (e.g., `stone` or `mymod:ruby_block`) to their
+    /// textures and sounds.
+    #[serde(flatten)]
+    pub blocks: HashMap<String, BlockEntry>,
+}
+
+/// The textures and sounds of one of the blocks of a [`BlocksJson`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BlockEntry {
+    /// The textures of the faces of the block in the world.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub textures: Option<BlockTextures>,
+
+    /// The textures of the faces of the block in the inventory, if different.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub carried_textures: Option<BlockTextures>,
+
+    /// Whether the textures of the faces are randomly rotated, like grass
+    /// tops.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub isotropic: Option<Isotropic>,
+
+    /// The name of the sound set of the block, e.g., `"stone"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sound: Option<String>,
+
+    /// The brightness of the faces of the block.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brightness_gamma: Option<f32>,
+}
+
+/// The textures of the faces of a [`BlockEntry`], which are either the same
+/// for every face or given face by face.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum BlockTextures {
+    /// The texture of every face.
+    All(String),
+
+    /// The textures of each face.
+    Faces(BlockFaceTextures),
+}
+
+impl BlockTextures {
+    /// Returns the texture of the given face.
+    ///
+    /// The faces that have no texture of their own use the `side` texture for
+    /// the horizontal faces.
+    pub fn face(&self, face: BlockFace) -> Option<&str> {
+        match self {
+            Self::All(texture) => Some(texture),
+            Self::Faces(faces) => faces.face(face),
+        }
+    }
+}
+
+/// The textures of each face of a [`BlockEntry`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[allow(missing_docs)]
+pub struct BlockFaceTextures {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub up: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub down: Option<String>,
+    /// The texture of the horizontal faces that have none of their own.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub side: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub north: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub south: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub east: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub west: Option<String>,
+}
+
+impl BlockFaceTextures {
+    /// Returns the texture of the given face.
+    ///
+    /// See [`BlockTextures::face()`].
+    pub fn face(&self, face: BlockFace) -> Option<&str> {
+        let texture = match face {
+            BlockFace::Up => &self.up,
+            BlockFace::Down => &self.down,
+            BlockFace::North => &self.north,
+            BlockFace::South => &self.south,
+            BlockFace::East => &self.east,
+            BlockFace::West => &self.west,
+        };
+        match face {
+            BlockFace::Up | BlockFace::Down => texture.as_deref(),
+            _ => texture.as_deref().or(self.side.as_deref()),
+        }
+    }
+}
+
+/// Whether the textures of a [`BlockEntry`] are randomly rotated.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum Isotropic {
+    /// Whether the textures of every face are rotated.
+    All(bool),
+
+    /// Map of face names (e.g., `up`) to whether their texture is rotated.
+    Faces(HashMap<String, bool>),
+}
@@ -0,0 +1,289 @@
+//! Serde-(de)serializable data types for the `.geo.json` models of Bedrock
+//! resource packs.
+//!
+//! Start here: [`GeometryFile`].
+//!
+//! See <https://learn.microsoft.com/en-us/minecraft/creator/reference/content/schemasreference/schemas/minecraftschema_geometry_1.12.0>.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// A file of Bedrock geometries (entity and block models), as stored in the
+/// `models/` directory of a resource pack, e.g.,
+/// `models/entity/cow.geo.json`.
+///
+/// Files in the current format (1.12.0 and later) list their geometries in
+/// `minecraft:geometry`. Files in the legacy format (1.8.0 and 1.10.0) have a
+/// field for each geometry instead, named after its identifier.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::bedrock::geometry::*;
+/// let file: GeometryFile = serde_json::from_str(r#"
+///     {
+///         "format_version": "1.12.0",
+///         "minecraft:geometry": [
+///             {
+///                 "description": {
+///                     "identifier": "geometry.cow",
+///                     "texture_width": 64,
+///                     "texture_height": 32
+///                 },
+///                 "bones": [
+///                     {
+///                         "name": "body",
+///                         "pivot": [0, 19, 2],
+///                         "rotation": [90, 0, 0],
+///                         "cubes": [
+///                             { "origin": [-6, 11, -5], "size": [12, 18, 10], "uv": [18, 4] }
+///                         ]
+///                     },
+///                     {
+///                         "name": "head",
+///                         "parent": "body",
+///                         "cubes": [{
+///                             "origin": [-4, 16, -14],
+///                             "size": [8, 8, 6],
+///                             "uv": { "north": { "uv": [6, 6], "uv_size": [8, 8] } }
+///                         }]
+///                     }
+///                 ]
+///             }
+///         ]
+///     }
+/// "#).unwrap();
+///
+/// let geometries = file.into_geometries();
+/// let cow = &geometries[0];
+/// assert_eq!(cow.description.identifier, "geometry.cow");
+/// assert_eq!(cow.description.texture_width, Some(64));
+/// assert_eq!(cow.bones[1].parent.as_deref(), Some("body"));
+/// assert_eq!(cow.bones[0].cubes[0].uv, Some(CubeUv::Box([18.0, 4.0])));
+/// ```
+///
+/// Legacy files are converted into the current format:
+///
+/// ```
+/// # use minecraft_assets::schemas::bedrock::geometry::*;
+/// let file: GeometryFile = serde_json::from_str(r#"
+///     {
+///         "format_version": "1.8.0",
+///         "geometry.pig:geometry.quadruped": {
+///             "texturewidth": 64,
+///             "textureheight": 32,
+///             "bones": [{ "name": "body", "cubes": [] }]
+///         }
+///     }
+/// "#).unwrap();
+///
+/// let geometries = file.into_geometries();
+/// assert_eq!(geometries[0].description.identifier, "geometry.pig");
+/// assert_eq!(geometries[0].description.texture_height, Some(32));
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct GeometryFile {
+    /// The version of the file format, e.g., `"1.12.0"`.
+    pub format_version: String,
+
+    /// The geometries of a file in the current format.
+    #[serde(
+        rename = "minecraft:geometry",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub geometry: Vec<Geometry>,
+
+    /// The geometries of a file in the legacy format, by identifier.
+    ///
+    /// The identifier may name the geometry that this one inherits from after
+    /// a colon, e.g., `geometry.pig:geometry.quadruped`.
+    #[serde(flatten)]
+    pub legacy: HashMap<String, LegacyGeometry>,
+}
+
+impl GeometryFile {
+    /// Returns the geometries of the file, converting the ones in the legacy
+    /// format into the current format.
+    ///
+    /// Legacy geometries keep only their own bones, without the bones of the
+    /// geometry that they inherit from.
+    pub fn into_geometries(self) -> Vec<Geometry> {
+        let mut geometries = self.geometry;
+
+        let mut legacy: Vec<_> = self.legacy.into_iter().collect();
+        legacy.sort_by(|(a, _), (b, _)| a.cmp(b));
+        geometries.extend(legacy.into_iter().map(|(key, geometry)| {
+            let identifier = key.split(':').next().unwrap_or_default().to_string();
+            Geometry {
+                description: GeometryDescription {
+                    identifier,
+                    texture_width: geometry.texturewidth,
+                    texture_height: geometry.textureheight,
+                    visible_bounds_width: geometry.visible_bounds_width,
+                    visible_bounds_height: geometry.visible_bounds_height,
+                    visible_bounds_offset: geometry.visible_bounds_offset,
+                },
+                bones: geometry.bones,
+            }
+        }));
+
+        geometries
+    }
+}
+
+/// A geometry of a [`GeometryFile`] in the current format.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Geometry {
+    /// The identifier and texture size of the geometry.
+    pub description: GeometryDescription,
+
+    /// The bones of the geometry, which hold its cubes.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bones: Vec<Bone>,
+}
+
+/// The `description` of a [`Geometry`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct GeometryDescription {
+    /// The identifier of the geometry, e.g., `"geometry.cow"`.
+    pub identifier: String,
+
+    /// The width of the texture in the units of the UV coordinates.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub texture_width: Option<u32>,
+
+    /// The height of the texture in the units of the UV coordinates.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub texture_height: Option<u32>,
+
+    /// The width of the box that the geometry is rendered within, in blocks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visible_bounds_width: Option<f32>,
+
+    /// The height of the box that the geometry is rendered within, in blocks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visible_bounds_height: Option<f32>,
+
+    /// The offset of the box that the geometry is rendered within.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visible_bounds_offset: Option<[f32; 3]>,
+}
+
+/// A geometry of a [`GeometryFile`] in the legacy format.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[allow(missing_docs)]
+pub struct LegacyGeometry {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub texturewidth: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub textureheight: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visible_bounds_width: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visible_bounds_height: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visible_bounds_offset: Option<[f32; 3]>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bones: Vec<Bone>,
+}
+
+/// A bone of a [`Geometry`], which is a group of cubes that are animated
+/// together.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Bone {
+    /// The name of the bone, which animations refer to.
+    pub name: String,
+
+    /// The name of the bone that this bone is attached to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+
+    /// The point that the bone rotates around.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pivot: Option<[f32; 3]>,
+
+    /// The rotation of the bone around the X, Y, and Z axes, in degrees.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rotation: Option<[f32; 3]>,
+
+    /// Whether the box UVs of the cubes of the bone are mirrored. Defaults to
+    /// `false`.
+    #[serde(default)]
+    pub mirror: bool,
+
+    /// The amount that the cubes of the bone are grown by on every side.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inflate: Option<f32>,
+
+    /// The cubes of the bone.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cubes: Vec<Cube>,
+}
+
+/// A cube of a [`Bone`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Cube {
+    /// The corner of the cube with the lowest coordinates.
+    pub origin: [f32; 3],
+
+    /// The size of the cube along the X, Y, and Z axes.
+    pub size: [f32; 3],
+
+    /// The UV coordinates of the faces of the cube.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uv: Option<CubeUv>,
+
+    /// The amount that the cube is grown by on every side, overriding the
+    /// one of its bone.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inflate: Option<f32>,
+
+    /// Whether the box UV of the cube is mirrored, overriding the bone.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mirror: Option<bool>,
+
+    /// The point that the cube rotates around.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pivot: Option<[f32; 3]>,
+
+    /// The rotation of the cube around the X, Y, and Z axes, in degrees.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rotation: Option<[f32; 3]>,
+}
+
+/// The UV coordinates of a [`Cube`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum CubeUv {
+    /// The corner of a box UV layout, from which the UVs of every face are
+    /// derived from the size of the cube.
+    Box([f32; 2]),
+
+    /// Map of face names (e.g., `north`) to their UVs.
+    PerFace(HashMap<String, FaceUv>),
+}
+
+/// The UV coordinates of one face of a [`Cube`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct FaceUv {
+    /// The corner of the face in the texture.
+    pub uv: [f32; 2],
+
+    /// The size of the face in the texture, which may be negative to flip it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uv_size: Option<[f32; 2]>,
+
+    /// The material instance of the face, for block geometries.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub material_instance: Option<String>,
+}
@@ -0,0 +1,190 @@
+//! Serde-(de)serializable data types for the `manifest.json` of Bedrock packs.
+//!
+//! Start here: [`Manifest`].
+//!
+//! See <https://learn.microsoft.com/en-us/minecraft/creator/reference/content/addonsreference/packmanifest>.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// The manifest of a Bedrock resource pack or behavior pack, as stored in the
+/// `manifest.json` file at the root of the pack.
+///
+/// This is the Bedrock counterpart of [`PackMeta`].
+///
+/// [`PackMeta`]: crate::schemas::PackMeta
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::bedrock::manifest::*;
+/// let manifest: Manifest = serde_json::from_str(r#"
+///     {
+///         "format_version": 2,
+///         "header": {
+///             "name": "My Pack",
+///             "description": "Converted from Java",
+///             "uuid": "66c6e9a8-3093-462a-9c36-dbb052165822",
+///             "version": [1, 0, 0],
+///             "min_engine_version": [1, 20, 0]
+///         },
+///         "modules": [
+///             {
+///                 "type": "resources",
+///                 "uuid": "743f6949-53be-44b6-b326-398005028819",
+///                 "version": [1, 0, 0]
+///             }
+///         ],
+///         "dependencies": [
+///             { "module_name": "@minecraft/server", "version": "1.8.0" }
+///         ]
+///     }
+/// "#).unwrap();
+///
+/// assert_eq!(manifest.header.name, "My Pack");
+/// assert_eq!(manifest.header.version, PackVersion::Triple([1, 0, 0]));
+/// assert!(manifest.is_resource_pack());
+/// assert_eq!(manifest.dependencies[0].version.to_string(), "1.8.0");
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Manifest {
+    /// The version of the manifest format, `2` for current packs.
+    pub format_version: u32,
+
+    /// The `header` section, describing the pack itself.
+    pub header: ManifestHeader,
+
+    /// The modules of the pack, which tell its type.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub modules: Vec<ManifestModule>,
+
+    /// The packs and script modules that this pack requires.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dependencies: Vec<ManifestDependency>,
+
+    /// Optional features of the game that the pack uses, e.g.,
+    /// `"raytraced"`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub capabilities: Vec<String>,
+
+    /// Information about the authors of the pack.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ManifestMetadata>,
+
+    /// The fields that this crate does not model (e.g., fields added by newer
+    /// versions), kept so that they survive a round trip.
+    ///
+    /// Requires the `preserve-unknown` feature.
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten, serialize_with = "crate::schemas::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Manifest {
+    /// Returns `true` if the pack has a `resources` module, i.e., it is a
+    /// resource pack.
+    pub fn is_resource_pack(&self) -> bool {
+        self.modules.iter().any(|module| module.kind == "resources")
+    }
+}
+
+/// The `header` section of a [`Manifest`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ManifestHeader {
+    /// The name of the pack shown in the game.
+    pub name: String,
+
+    /// The description of the pack shown in the game.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub description: String,
+
+    /// The UUID that identifies the pack.
+    pub uuid: String,
+
+    /// The version of the pack.
+    pub version: PackVersion,
+
+    /// The oldest version of the game that the pack is made for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_engine_version: Option<PackVersion>,
+}
+
+/// One of the `modules` of a [`Manifest`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ManifestModule {
+    /// The type of the module, e.g., `"resources"`, `"data"`, or
+    /// `"script"`.
+    #[serde(rename = "type")]
+    pub kind: String,
+
+    /// The UUID that identifies the module.
+    pub uuid: String,
+
+    /// The version of the module.
+    pub version: PackVersion,
+
+    /// The description of the module.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// One of the `dependencies` of a [`Manifest`], either another pack (by its
+/// UUID) or a script module (by its name).
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ManifestDependency {
+    /// The UUID of the pack that is required.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+
+    /// The name of the script module that is required, e.g.,
+    /// `"@minecraft/server"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub module_name: Option<String>,
+
+    /// The version that is required.
+    pub version: PackVersion,
+}
+
+/// The `metadata` section of a [`Manifest`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ManifestMetadata {
+    /// The names of the authors of the pack.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub authors: Vec<String>,
+
+    /// The license of the pack.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license: Option<String>,
+
+    /// The website of the pack.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// A version in a [`Manifest`], which is either an array of three numbers or,
+/// for script modules, a semantic version string.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum PackVersion {
+    /// A version like `[1, 20, 0]`.
+    Triple([u32; 3]),
+
+    /// A version like `"1.8.0-beta"`.
+    String(String),
+}
+
+impl fmt::Display for PackVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Triple([major, minor, patch]) => write!(f, "{}.{}.{}", major, minor, patch),
+            Self::String(version) => f.write_str(version),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+//! Serde-(de)serializable data types for the files of Bedrock Edition
+//! resource packs.
+//!
+//! See <https://learn.microsoft.com/en-us/minecraft/creator/>.
+//!
+//! Bedrock packs have no namespaces, and their files are found at fixed paths
+//! from the root of the pack instead:
+//!
+//! * `manifest.json`: [`Manifest`]
+//! * `blocks.json`: [`BlocksJson`]
+//! * `textures/terrain_texture.json` and `textures/item_texture.json`:
+//!   [`TextureAtlas`]
+//! * `models/**/*.geo.json`: [`GeometryFile`]
+//!
+//! They can be loaded through the [`BedrockPack`] API, which also maps the
+//! paths of textures between the Java and Bedrock editions.
+//!
+//! [`BedrockPack`]: crate::api::bedrock::BedrockPack
+//!
+//! *currently only the files needed to convert blocks, items, and entity
+//! models are implemented*
+
+pub mod blocks;
+pub mod geometry;
+pub mod manifest;
+pub mod texture_atlas;
+
+pub use blocks::BlocksJson;
+pub use geometry::GeometryFile;
+pub use manifest::Manifest;
+pub use texture_atlas::TextureAtlas;
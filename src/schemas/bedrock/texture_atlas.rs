@@ -0,0 +1,190 @@
+//! Serde-(de)serializable data types for the `terrain_texture.json` and
+//! `item_texture.json` of Bedrock resource packs.
+//!
+//! Start here: [`TextureAtlas`].
+//!
+//! See <https://wiki.bedrock.dev/concepts/texture-atlases.html>.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// The short names of the textures of a Bedrock atlas, as stored in
+/// `textures/terrain_texture.json` (for blocks) or
+/// `textures/item_texture.json` (for items).
+///
+/// The `blocks.json` file and item definitions refer to textures by these
+/// short names, which map to the paths of the texture files, without their
+/// extension, from the root of the pack.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::bedrock::texture_atlas::*;
+/// let atlas: TextureAtlas = serde_json::from_str(r##"
+///     {
+///         "resource_pack_name": "vanilla",
+///         "texture_name": "atlas.terrain",
+///         "padding": 8,
+///         "num_mip_levels": 4,
+///         "texture_data": {
+///             "stone": { "textures": "textures/blocks/stone" },
+///             "grass_top": {
+///                 "textures": [
+///                     "textures/blocks/grass_top",
+///                     { "path": "textures/blocks/grass_top", "overlay_color": "#79c05a" }
+///                 ]
+///             },
+///             "dirt": {
+///                 "textures": {
+///                     "variations": [
+///                         { "path": "textures/blocks/dirt", "weight": 3 },
+///                         { "path": "textures/blocks/dirt_alt" }
+///                     ]
+///                 }
+///             }
+///         }
+///     }
+/// "##).unwrap();
+///
+/// assert_eq!(atlas.texture_name, "atlas.terrain");
+/// assert_eq!(atlas.path("stone"), Some("textures/blocks/stone"));
+/// assert_eq!(atlas.path("dirt"), Some("textures/blocks/dirt"));
+/// assert_eq!(atlas.texture_data["grass_top"].textures.paths().count(), 2);
+/// assert_eq!(atlas.path("missing"), None);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TextureAtlas {
+    /// The name of the pack, e.g., `"vanilla"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_pack_name: Option<String>,
+
+    /// The name of the atlas, `"atlas.terrain"` or `"atlas.items"`.
+    pub texture_name: String,
+
+    /// The number of pixels of padding around each texture.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub padding: Option<u32>,
+
+    /// The number of mipmap levels of the atlas.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_mip_levels: Option<u32>,
+
+    /// Map of short names to the textures they refer to.
+    #[serde(default)]
+    pub texture_data: HashMap<String, TextureEntry>,
+
+    /// The fields that this crate does not model (e.g., fields added by newer
+    /// versions), kept so that they survive a round trip.
+    ///
+    /// Requires the `preserve-unknown` feature.
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten, serialize_with = "crate::schemas::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl TextureAtlas {
+    /// Returns the path of the first texture of the given short name, or
+    /// `None` if the atlas has no such texture.
+    pub fn path(&self, name: &str) -> Option<&str> {
+        self.texture_data.get(name)?.textures.paths().next()
+    }
+}
+
+/// One of the `texture_data` entries of a [`TextureAtlas`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TextureEntry {
+    /// The texture, or the list of textures that blocks select with their
+    /// data value (e.g., the colors of wool before the flattening).
+    pub textures: TextureSources,
+}
+
+/// The textures of a [`TextureEntry`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum TextureSources {
+    /// A single texture.
+    One(TextureSource),
+
+    /// A list of textures.
+    Many(Vec<TextureSource>),
+}
+
+impl TextureSources {
+    /// Returns the list of textures.
+    pub fn as_slice(&self) -> &[TextureSource] {
+        match self {
+            Self::One(source) => std::slice::from_ref(source),
+            Self::Many(sources) => sources,
+        }
+    }
+
+    /// Returns an iterator over the path of each texture (the first variation
+    /// of textures that have variations).
+    pub fn paths(&self) -> impl Iterator<Item = &str> + '_ {
+        self.as_slice().iter().filter_map(TextureSource::path)
+    }
+}
+
+/// A texture of a [`TextureEntry`], either a plain path or a path with tints
+/// or random variations.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum TextureSource {
+    /// The path of the texture file, without its extension.
+    Path(String),
+
+    /// A texture with more settings.
+    Detailed(DetailedTexture),
+}
+
+impl TextureSource {
+    /// Returns the path of the texture file, or of its first variation.
+    pub fn path(&self) -> Option<&str> {
+        match self {
+            Self::Path(path) => Some(path),
+            Self::Detailed(texture) => texture
+                .path
+                .as_deref()
+                .or_else(|| Some(texture.variations.first()?.path.as_str())),
+        }
+    }
+}
+
+/// A [`TextureSource`] with more settings.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DetailedTexture {
+    /// The path of the texture file, without its extension.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
+    /// The color (e.g., `"#79c05a"`) that the transparent pixels of the
+    /// texture are tinted with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overlay_color: Option<String>,
+
+    /// The color that the whole texture is tinted with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tint_color: Option<String>,
+
+    /// The textures that are randomly picked for each block.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub variations: Vec<TextureVariation>,
+}
+
+/// One of the `variations` of a [`DetailedTexture`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TextureVariation {
+    /// The path of the texture file, without its extension.
+    pub path: String,
+
+    /// The relative chance of the variation to be picked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight: Option<u32>,
+}
@@ -0,0 +1,321 @@
+//! Serde-(de)serializable data types for blocks, shared by the schemas that
+//! embed them (e.g., configured features, processors, carvers, and
+//! structures).
+//!
+//! Start here: [`BlockStateValue`], [`BlockPredicate`], and [`RuleTest`].
+
+use std::{collections::HashMap, fmt};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::{InvalidIdentifier, ResourceLocation},
+    schemas::reference::IdList,
+};
+
+/// A block together with the values of its block state properties, e.g., the
+/// block that a feature places.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::block::*;
+/// let state: BlockStateValue = serde_json::from_str(r#"{
+///     "Name": "minecraft:oak_log",
+///     "Properties": { "axis": "y" }
+/// }"#).unwrap();
+///
+/// assert_eq!(state, BlockStateValue::new("minecraft:oak_log")?.with_property("axis", "y"));
+/// assert_eq!(state.to_string(), "minecraft:oak_log[axis=y]");
+/// # Ok::<(), minecraft_assets::api::InvalidIdentifier>(())
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BlockStateValue {
+    /// The namespaced ID of the block.
+    #[serde(rename = "Name")]
+    pub name: ResourceLocation<'static>,
+
+    /// The block state properties of the block.
+    #[serde(
+        rename = "Properties",
+        default,
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    pub properties: HashMap<String, String>,
+}
+
+impl BlockStateValue {
+    /// Constructs a new block state for the given block with no properties.
+    ///
+    /// Returns an error if the name is not a valid [`ResourceLocation`].
+    pub fn new(name: impl AsRef<str>) -> Result<Self, InvalidIdentifier> {
+        Ok(Self {
+            name: name.as_ref().parse()?,
+            properties: HashMap::new(),
+        })
+    }
+
+    /// Returns this block state with the given property set.
+    pub fn with_property(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.properties.insert(name.into(), value.into());
+        self
+    }
+
+    /// Returns the value of the given property, if the block state has it.
+    pub fn property(&self, name: &str) -> Option<&str> {
+        self.properties.get(name).map(String::as_str)
+    }
+}
+
+/// Formats the block state like the game does in commands, e.g.,
+/// `minecraft:oak_stairs[facing=north,half=top]`, with the properties sorted
+/// by name.
+impl fmt::Display for BlockStateValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if !self.properties.is_empty() {
+            let mut properties: Vec<_> = self.properties.iter().collect();
+            properties.sort_unstable();
+
+            let properties: Vec<_> = properties
+                .into_iter()
+                .map(|(name, value)| format!("{}={}", name, value))
+                .collect();
+            write!(f, "[{}]", properties.join(","))?;
+        }
+        Ok(())
+    }
+}
+
+/// A predicate that tests the block at (an offset from) a position, as used
+/// by placement modifiers and configured features since 1.18.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::block::*;
+/// let predicate: BlockPredicate = serde_json::from_str(r#"{
+///     "type": "minecraft:all_of",
+///     "predicates": [
+///         { "type": "minecraft:matching_blocks", "blocks": "minecraft:air" },
+///         { "type": "minecraft:solid", "offset": [0, -1, 0] }
+///     ]
+/// }"#).unwrap();
+///
+/// let BlockPredicate::AllOf { predicates } = predicate else { panic!() };
+/// assert_eq!(predicates[1], BlockPredicate::Solid { offset: Some([0, -1, 0]) });
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(tag = "type")]
+pub enum BlockPredicate {
+    /// Passes if the block is one of the given blocks.
+    #[serde(rename = "minecraft:matching_blocks", alias = "matching_blocks")]
+    MatchingBlocks {
+        /// The namespaced IDs of the blocks (or a `#`-prefixed tag).
+        blocks: IdList,
+
+        /// (optional) The offset of the tested block from the position.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        offset: Option<[i32; 3]>,
+    },
+
+    /// Passes if the block is in the given tag.
+    ///
+    /// Removed in 1.20.5 in favor of [`MatchingBlocks`][Self::MatchingBlocks]
+    /// with a tag.
+    #[serde(rename = "minecraft:matching_block_tag", alias = "matching_block_tag")]
+    MatchingBlockTag {
+        /// The namespaced ID of the block tag, without the leading `#`.
+        tag: ResourceLocation<'static>,
+
+        /// (optional) The offset of the tested block from the position.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        offset: Option<[i32; 3]>,
+    },
+
+    /// Passes if the fluid at the position is one of the given fluids.
+    #[serde(rename = "minecraft:matching_fluids", alias = "matching_fluids")]
+    MatchingFluids {
+        /// The namespaced IDs of the fluids (or a `#`-prefixed tag).
+        fluids: IdList,
+
+        /// (optional) The offset of the tested block from the position.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        offset: Option<[i32; 3]>,
+    },
+
+    /// Passes if the given face of the block is sturdy.
+    #[serde(rename = "minecraft:has_sturdy_face", alias = "has_sturdy_face")]
+    HasSturdyFace {
+        /// The face to test.
+        direction: Direction,
+
+        /// (optional) The offset of the tested block from the position.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        offset: Option<[i32; 3]>,
+    },
+
+    /// Passes if the block is solid.
+    #[serde(rename = "minecraft:solid", alias = "solid")]
+    Solid {
+        /// (optional) The offset of the tested block from the position.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        offset: Option<[i32; 3]>,
+    },
+
+    /// Passes if the block can be replaced, like air or grass.
+    #[serde(rename = "minecraft:replaceable", alias = "replaceable")]
+    Replaceable {
+        /// (optional) The offset of the tested block from the position.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        offset: Option<[i32; 3]>,
+    },
+
+    /// Passes if the given block state could survive at the position.
+    #[serde(rename = "minecraft:would_survive", alias = "would_survive")]
+    WouldSurvive {
+        /// The block state to test.
+        state: BlockStateValue,
+
+        /// (optional) The offset of the tested block from the position.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        offset: Option<[i32; 3]>,
+    },
+
+    /// Passes if no entity collides with the block.
+    #[serde(rename = "minecraft:unobstructed", alias = "unobstructed")]
+    Unobstructed {
+        /// (optional) The offset of the tested block from the position.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        offset: Option<[i32; 3]>,
+    },
+
+    /// Passes if the block is inside the build limits of the world.
+    #[serde(
+        rename = "minecraft:inside_world_bounds",
+        alias = "inside_world_bounds"
+    )]
+    InsideWorldBounds {
+        /// (optional) The offset of the tested block from the position.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        offset: Option<[i32; 3]>,
+    },
+
+    /// Passes if the inner predicate fails.
+    #[serde(rename = "minecraft:not", alias = "not")]
+    Not {
+        /// The predicate to invert.
+        predicate: Box<BlockPredicate>,
+    },
+
+    /// Passes if any of the inner predicates passes.
+    #[serde(rename = "minecraft:any_of", alias = "any_of")]
+    AnyOf {
+        /// The predicates to test.
+        predicates: Vec<BlockPredicate>,
+    },
+
+    /// Passes if all of the inner predicates pass.
+    #[serde(rename = "minecraft:all_of", alias = "all_of")]
+    AllOf {
+        /// The predicates to test.
+        predicates: Vec<BlockPredicate>,
+    },
+
+    /// Always passes.
+    #[serde(rename = "minecraft:true", alias = "true")]
+    True,
+}
+
+/// One of the six directions that the faces of a block point in.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum Direction {
+    /// Towards negative y.
+    Down,
+
+    /// Towards positive y.
+    Up,
+
+    /// Towards negative z.
+    North,
+
+    /// Towards positive z.
+    South,
+
+    /// Towards negative x.
+    West,
+
+    /// Towards positive x.
+    East,
+}
+
+/// A test of a block state, as used by ore features and by the rule
+/// processors of structures.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::block::*;
+/// let test: RuleTest = serde_json::from_str(r#"{
+///     "predicate_type": "minecraft:tag_match",
+///     "tag": "minecraft:stone_ore_replaceables"
+/// }"#).unwrap();
+///
+/// assert_eq!(test, RuleTest::TagMatch { tag: "minecraft:stone_ore_replaceables".parse().unwrap() });
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(tag = "predicate_type")]
+pub enum RuleTest {
+    /// Always passes.
+    #[serde(rename = "minecraft:always_true", alias = "always_true")]
+    AlwaysTrue,
+
+    /// Passes for any state of the given block.
+    #[serde(rename = "minecraft:block_match", alias = "block_match")]
+    BlockMatch {
+        /// The namespaced ID of the block.
+        block: ResourceLocation<'static>,
+    },
+
+    /// Passes for exactly the given block state.
+    #[serde(rename = "minecraft:blockstate_match", alias = "blockstate_match")]
+    BlockStateMatch {
+        /// The block state to match.
+        block_state: BlockStateValue,
+    },
+
+    /// Passes for blocks in the given tag.
+    #[serde(rename = "minecraft:tag_match", alias = "tag_match")]
+    TagMatch {
+        /// The namespaced ID of the block tag, without the leading `#`.
+        tag: ResourceLocation<'static>,
+    },
+
+    /// Passes with the given probability for any state of the given block.
+    #[serde(rename = "minecraft:random_block_match", alias = "random_block_match")]
+    RandomBlockMatch {
+        /// The namespaced ID of the block.
+        block: ResourceLocation<'static>,
+
+        /// The probability of passing, between 0 and 1.
+        probability: f32,
+    },
+
+    /// Passes with the given probability for exactly the given block state.
+    #[serde(
+        rename = "minecraft:random_blockstate_match",
+        alias = "random_blockstate_match"
+    )]
+    RandomBlockStateMatch {
+        /// The block state to match.
+        block_state: BlockStateValue,
+
+        /// The probability of passing, between 0 and 1.
+        probability: f32,
+    },
+}
@@ -5,10 +5,19 @@
 //!
 //! See <https://minecraft.fandom.com/wiki/Model#Block_states>.
 
-use std::collections::HashMap;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+    str::FromStr,
+};
 
 use serde::{Deserialize, Serialize};
 
+use crate::{
+    api::{random::LegacyRandom, InvalidIdentifier, ResourceLocation},
+    schemas::strict,
+};
+
 /// Block states as stored in the `assets/<namespace>/blockstates` directory.
 ///
 /// There are several different variants of some blocks (like [doors], which can
@@ -26,6 +35,8 @@ use serde::{Deserialize, Serialize};
 /// [block state]: https://minecraft.fandom.com/wiki/Block_state
 /// [wiki page]: <https://minecraft.fandom.com/wiki/Model#Block_states>
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "bevy", derive(bevy::asset::Asset, bevy::reflect::TypePath))]
 #[serde(untagged)]
 pub enum BlockStates {
     /// One way of representing the different states of a block.
@@ -76,6 +87,182 @@ impl BlockStates {
         }
     }
 
+    /// Returns the [`Variant`]s that apply to a block with the given state
+    /// values (e.g., `facing=east` and `half=top`), each of which holds the
+    /// model(s) to render along with their rotations and `uvlock`.
+    ///
+    /// For [`Variants`], this is the one variant whose name is matched by the
+    /// state values. Every state in a variant name must have a matching value,
+    /// but states that are not in the name are ignored; if several names
+    /// match, the one with the most states wins. For [`Multipart`], this is
+    /// the variant of every [`Case`] that [applies][multipart::Case::applies].
+    ///
+    /// [`Variants`]: Self::Variants
+    /// [`Multipart`]: Self::Multipart
+    /// [`Case`]: multipart::Case
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::blockstates::*;
+    /// let states: BlockStates = serde_json::from_str(r#"
+    ///     {
+    ///         "variants": {
+    ///             "facing=east,half=bottom": { "model": "block/oak_stairs" },
+    ///             "facing=east,half=top": { "model": "block/oak_stairs", "x": 180, "uvlock": true }
+    ///         }
+    ///     }
+    /// "#).unwrap();
+    ///
+    /// let variants = states.applied_variants([("facing", "east"), ("half", "top"), ("waterlogged", "false")]);
+    /// assert_eq!(variants.len(), 1);
+    ///
+    /// let model = &variants[0].models()[0];
+    /// assert_eq!(model.model, "block/oak_stairs");
+    /// assert_eq!(model.x, 180);
+    /// assert!(model.uv_lock);
+    ///
+    /// let states: BlockStates = serde_json::from_str(r#"
+    ///     {
+    ///         "multipart": [
+    ///             { "apply": { "model": "block/oak_fence_post" } },
+    ///             { "when": { "north": "true" }, "apply": { "model": "block/oak_fence_side", "uvlock": true } },
+    ///             { "when": { "east": "true" }, "apply": { "model": "block/oak_fence_side", "y": 90, "uvlock": true } }
+    ///         ]
+    ///     }
+    /// "#).unwrap();
+    ///
+    /// let variants = states.applied_variants([("north", "false"), ("east", "true")]);
+    /// assert_eq!(variants.len(), 2);
+    /// assert_eq!(variants[1].models()[0].y, 90);
+    /// ```
+    pub fn applied_variants<'a, I>(&self, state_values: I) -> Vec<&Variant>
+    where
+        I: IntoIterator<Item = (&'a str, &'a str)>,
+    {
+        let state_values: Vec<(&str, multipart::StateValue)> = state_values
+            .into_iter()
+            .map(|(state, value)| (state, multipart::StateValue::from(value)))
+            .collect();
+        let state_values = || state_values.iter().map(|(state, value)| (*state, value));
+
+        match self {
+            Self::Variants { variants } => variants
+                .iter()
+                .filter_map(|(name, variant)| {
+                    let condition = Self::parse_variant_name(name)?;
+                    condition.applies(state_values()).then_some((
+                        condition.and.len(),
+                        name,
+                        variant,
+                    ))
+                })
+                // Prefer the most specific name, then the first name in order.
+                .max_by(|(len_a, name_a, _), (len_b, name_b, _)| {
+                    len_a.cmp(len_b).then_with(|| name_b.cmp(name_a))
+                })
+                .map(|(_, _, variant)| variant)
+                .into_iter()
+                .collect(),
+
+            Self::Multipart { cases } => cases
+                .iter()
+                .filter(|case| case.applies(state_values()))
+                .map(|case| &case.apply)
+                .collect(),
+        }
+    }
+
+    /// Like [`applied_variants()`][Self::applied_variants], but with typed
+    /// [`BlockProperties`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::blockstates::*;
+    /// let states = BlockStatesBuilder::new()
+    ///     .variant("lit=false", ModelProperties::new("block/furnace")?)
+    ///     .variant("lit=true", ModelProperties::new("block/furnace_on")?)
+    ///     .build();
+    ///
+    /// let properties: BlockProperties = "facing=north,lit=true".parse().unwrap();
+    /// let variants = states.applied_variants_for(&properties);
+    /// assert_eq!(variants[0].models()[0].model, "block/furnace_on");
+    /// # Ok::<(), minecraft_assets::api::InvalidIdentifier>(())
+    /// ```
+    pub fn applied_variants_for(&self, properties: &BlockProperties) -> Vec<&Variant> {
+        let values: Vec<(&str, String)> = properties
+            .iter()
+            .map(|(name, value)| (name, value.to_string()))
+            .collect();
+        self.applied_variants(values.iter().map(|(name, value)| (*name, value.as_str())))
+    }
+
+    /// Returns the models that the game renders for the block with the given
+    /// state values at the given position, i.e., one model of each of the
+    /// [`applied_variants()`][Self::applied_variants], picked like the game
+    /// does up to 1.21.4.
+    ///
+    /// See [`Variant::choose_seeded()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::blockstates::*;
+    /// let grass_block = ModelProperties::new("block/grass_block")?;
+    /// let states = BlockStatesBuilder::single(vec![
+    ///     grass_block.clone(),
+    ///     grass_block.clone().with_y(90),
+    ///     grass_block.clone().with_y(180),
+    ///     grass_block.with_y(270),
+    /// ])
+    /// .build();
+    ///
+    /// let models = states.models_at([("snowy", "false")], [0, 64, 0]);
+    /// assert_eq!(models.len(), 1);
+    /// assert_eq!(models, states.models_at([("snowy", "false")], [0, 64, 0]));
+    /// # Ok::<(), minecraft_assets::api::InvalidIdentifier>(())
+    /// ```
+    pub fn models_at<'a, I>(&self, state_values: I, pos: [i32; 3]) -> Vec<&ModelProperties>
+    where
+        I: IntoIterator<Item = (&'a str, &'a str)>,
+    {
+        let seed = position_seed(pos);
+        // Every part of a multipart block is picked with the same seed, drawn
+        // from the seed of the position.
+        let seed = match self {
+            Self::Variants { .. } => seed,
+            Self::Multipart { .. } => LegacyRandom::new(seed).next_long(),
+        };
+
+        self.applied_variants(state_values)
+            .into_iter()
+            .map(|variant| variant.choose_seeded(seed))
+            .collect()
+    }
+
+    /// Parses a variant name like `"facing=east,half=top"` into the
+    /// [`Condition`] that it represents.
+    ///
+    /// The empty name and the pre-1.13 `"normal"` name match every state.
+    /// Returns `None` for other names that are not state values, like the
+    /// pre-1.13 `"inventory"`.
+    ///
+    /// [`Condition`]: multipart::Condition
+    fn parse_variant_name(name: &str) -> Option<multipart::Condition> {
+        if name == "normal" {
+            return Some(multipart::Condition::default());
+        }
+
+        let properties: BlockProperties = name.parse().ok()?;
+        Some(
+            properties
+                .iter()
+                .map(|(name, value)| (name, value.to_string()))
+                .collect(),
+        )
+    }
+
     /// Consumes `self` and returns a new [`BlockStates::Multipart`] where all
     /// of the [`Variants`] have been converted to an equivalent [`Case`]
     ///
@@ -87,11 +274,7 @@ impl BlockStates {
 
             Self::Variants { variants } => {
                 if variants.len() == 1 {
-                    let variant = variants
-                        .into_iter()
-                        .map(|(_, variant)| variant)
-                        .next()
-                        .unwrap();
+                    let variant = variants.into_values().next().unwrap();
 
                     let case = multipart::Case {
                         when: None,
@@ -138,12 +321,350 @@ impl Default for BlockStates {
     }
 }
 
+/// The values of the properties of a block state, as written in the names of
+/// [`Variants`][BlockStates::Variants] (e.g., `"facing=north,half=top"`).
+///
+/// The properties are kept sorted by name, which is the order in which the
+/// game writes them, so that formatting them gives the canonical variant
+/// name.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::blockstates::*;
+/// let properties: BlockProperties = "open=false,half=top,facing=north,age=3".parse().unwrap();
+///
+/// assert_eq!(properties.get("facing"), Some(&PropertyValue::String("north".into())));
+/// assert_eq!(properties.get("open"), Some(&PropertyValue::Bool(false)));
+/// assert_eq!(properties.get("age").and_then(PropertyValue::as_int), Some(3));
+/// assert_eq!(properties.to_string(), "age=3,facing=north,half=top,open=false");
+///
+/// assert!("".parse::<BlockProperties>().unwrap().is_empty());
+/// assert!("inventory".parse::<BlockProperties>().is_err());
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct BlockProperties {
+    properties: BTreeMap<String, PropertyValue>,
+}
+
+impl BlockProperties {
+    /// Returns an empty set of properties, as used by blocks without
+    /// properties (whose single variant is named `""`).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the value of the given property, if it is set.
+    pub fn get(&self, name: &str) -> Option<&PropertyValue> {
+        self.properties.get(name)
+    }
+
+    /// Sets the value of the given property, returning its previous value.
+    pub fn insert(
+        &mut self,
+        name: impl Into<String>,
+        value: impl Into<PropertyValue>,
+    ) -> Option<PropertyValue> {
+        self.properties.insert(name.into(), value.into())
+    }
+
+    /// Returns these properties with the given property set.
+    pub fn with(mut self, name: impl Into<String>, value: impl Into<PropertyValue>) -> Self {
+        self.insert(name, value);
+        self
+    }
+
+    /// Unsets the given property, returning its value.
+    pub fn remove(&mut self, name: &str) -> Option<PropertyValue> {
+        self.properties.remove(name)
+    }
+
+    /// Returns the names and values of the properties, sorted by name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &PropertyValue)> {
+        self.properties
+            .iter()
+            .map(|(name, value)| (name.as_str(), value))
+    }
+
+    /// Returns the number of properties that are set.
+    pub fn len(&self) -> usize {
+        self.properties.len()
+    }
+
+    /// Returns `true` if no property is set.
+    pub fn is_empty(&self) -> bool {
+        self.properties.is_empty()
+    }
+}
+
+/// Formats the properties as a variant name, sorted by name.
+impl fmt::Display for BlockProperties {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (name, value)) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{}={}", name, value)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for BlockProperties {
+    type Err = ParseBlockPropertiesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut properties = Self::new();
+        if s.is_empty() {
+            return Ok(properties);
+        }
+
+        for property in s.split(',') {
+            match property.split_once('=') {
+                Some((name, value)) if !name.is_empty() => {
+                    properties.insert(name, PropertyValue::parse(value));
+                }
+                _ => return Err(ParseBlockPropertiesError(property.to_string())),
+            }
+        }
+
+        Ok(properties)
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for BlockProperties
+where
+    K: Into<String>,
+    V: Into<PropertyValue>,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let properties = iter
+            .into_iter()
+            .map(|(name, value)| (name.into(), value.into()))
+            .collect();
+        Self { properties }
+    }
+}
+
+/// The value of a property in [`BlockProperties`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PropertyValue {
+    /// The value of a boolean property, like `open` or `lit`.
+    Bool(bool),
+
+    /// The value of an integer property, like `age` or `power`.
+    Int(i32),
+
+    /// The value of any other property, like `facing` or `half`.
+    String(String),
+}
+
+impl PropertyValue {
+    /// Parses the value of a property, as a bool or an integer if possible,
+    /// and as a string otherwise.
+    pub fn parse(value: &str) -> Self {
+        match value {
+            "true" => Self::Bool(true),
+            "false" => Self::Bool(false),
+            // Values like `01` stay strings, so that they are written back
+            // unchanged.
+            _ => match value.parse::<i32>() {
+                Ok(int) if int.to_string() == value => Self::Int(int),
+                _ => Self::String(value.to_string()),
+            },
+        }
+    }
+
+    /// Returns the value as a bool, if it is one.
+    pub fn as_bool(&self) -> Option<bool> {
+        match *self {
+            Self::Bool(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as an integer, if it is one.
+    pub fn as_int(&self) -> Option<i32> {
+        match *self {
+            Self::Int(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a string, if it is neither a bool nor an integer.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for PropertyValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bool(value) => write!(f, "{}", value),
+            Self::Int(value) => write!(f, "{}", value),
+            Self::String(value) => f.write_str(value),
+        }
+    }
+}
+
+impl From<bool> for PropertyValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<i32> for PropertyValue {
+    fn from(value: i32) -> Self {
+        Self::Int(value)
+    }
+}
+
+impl From<&str> for PropertyValue {
+    fn from(value: &str) -> Self {
+        Self::parse(value)
+    }
+}
+
+impl From<String> for PropertyValue {
+    fn from(value: String) -> Self {
+        Self::parse(&value)
+    }
+}
+
+/// An error from parsing [`BlockProperties`] that are not of the form
+/// `name=value,...`. Holds the invalid property.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseBlockPropertiesError(pub String);
+
+impl fmt::Display for ParseBlockPropertiesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid block property `{}`, expected `name=value`",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for ParseBlockPropertiesError {}
+
+/// A builder for [`BlockStates`], e.g., for tools that generate packs.
+///
+/// The builder starts out with [`Variants`][BlockStates::Variants]. Adding a
+/// part turns the variants added so far into equivalent cases of a
+/// [`Multipart`][BlockStates::Multipart], and variants added after that are
+/// added as cases too.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::blockstates::{multipart::Condition, *};
+/// let furnace = ModelProperties::new("block/furnace")?;
+/// let states = BlockStatesBuilder::new()
+///     .variant("facing=north", furnace.clone())
+///     .variant("facing=east", furnace.with_y(90))
+///     .build();
+///
+/// let variants = states.applied_variants([("facing", "east")]);
+/// assert_eq!(variants[0].models()[0].y, 90);
+///
+/// let states = BlockStatesBuilder::new()
+///     .part(Variant::try_from("block/oak_fence_post")?)
+///     .part_when(
+///         Condition::from_iter([("north", "true")]),
+///         ModelProperties::new("block/oak_fence_side")?.with_uv_lock(),
+///     )
+///     .build();
+///
+/// assert_eq!(states.applied_variants([("north", "true")]).len(), 2);
+/// assert_eq!(states.applied_variants([("north", "false")]).len(), 1);
+/// # Ok::<(), minecraft_assets::api::InvalidIdentifier>(())
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct BlockStatesBuilder {
+    states: BlockStates,
+}
+
+impl BlockStatesBuilder {
+    /// Returns a builder with no variants.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a builder for a block with a single variant, used for every
+    /// state.
+    pub fn single(variant: impl Into<Variant>) -> Self {
+        Self::new().variant("", variant)
+    }
+
+    /// Adds a variant for the states matching the given name, e.g.,
+    /// `"facing=north,half=top"`.
+    ///
+    /// If the builder already has parts, names that are not state values
+    /// (like the pre-1.13 `"inventory"`) are left out.
+    pub fn variant(mut self, name: impl Into<String>, variant: impl Into<Variant>) -> Self {
+        let name = name.into();
+        match &mut self.states {
+            BlockStates::Variants { variants } => {
+                variants.insert(name, variant.into());
+            }
+            BlockStates::Multipart { cases } => {
+                cases.extend(Self::variant_case(&name, variant.into()));
+            }
+        }
+        self
+    }
+
+    /// Adds a part that applies in every state.
+    pub fn part(self, apply: impl Into<Variant>) -> Self {
+        self.push_case(None, apply.into())
+    }
+
+    /// Adds a part that applies in the states matching the given clause.
+    pub fn part_when(
+        self,
+        when: impl Into<multipart::WhenClause>,
+        apply: impl Into<Variant>,
+    ) -> Self {
+        self.push_case(Some(when.into()), apply.into())
+    }
+
+    fn push_case(mut self, when: Option<multipart::WhenClause>, apply: Variant) -> Self {
+        let mut cases = match self.states {
+            BlockStates::Variants { variants } => variants
+                .into_iter()
+                .filter_map(|(name, variant)| Self::variant_case(&name, variant))
+                .collect(),
+            BlockStates::Multipart { cases } => cases,
+        };
+        cases.push(multipart::Case { when, apply });
+
+        self.states = BlockStates::Multipart { cases };
+        self
+    }
+
+    fn variant_case(name: &str, apply: Variant) -> Option<multipart::Case> {
+        let condition = BlockStates::parse_variant_name(name)?;
+        let when = (!condition.and.is_empty()).then(|| condition.into());
+        Some(multipart::Case { when, apply })
+    }
+
+    /// Returns the block states.
+    pub fn build(self) -> BlockStates {
+        self.states
+    }
+}
+
 /// A block variant.
 ///
 /// Each variant can have **one model** or an **array of models** and contains
 /// their properties. If set to an array, the model is chosen randomly from the
 /// models contained in the array based on the `Model::weight` field.
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 pub enum Variant {
     /// A variant with only a single model to choose from.
@@ -153,9 +674,10 @@ pub enum Variant {
     Multiple(Vec<ModelProperties>),
 }
 
+/// Returns a variant with no models.
 impl Default for Variant {
     fn default() -> Self {
-        Self::Single(Default::default())
+        Self::Multiple(Vec::new())
     }
 }
 
@@ -171,11 +693,124 @@ impl Variant {
             Self::Multiple(models) => &models[..],
         }
     }
+
+    /// Returns the sum of the [`weight`][ModelProperties::weight]s of the
+    /// models of this variant.
+    pub fn total_weight(&self) -> u32 {
+        self.models()
+            .iter()
+            .fold(0u32, |total, model| total.saturating_add(model.weight))
+    }
+
+    /// Returns the model at the given weighted index, from `0` to the
+    /// [`total_weight()`][Self::total_weight] (excluded), i.e., each model
+    /// covers as many indices as its weight.
+    ///
+    /// Indices past the total weight wrap around, so that a uniformly random
+    /// number picks each model with a probability proportional to its weight.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::blockstates::*;
+    /// let variant = Variant::from(vec![
+    ///     ModelProperties::new("block/stone")?,
+    ///     ModelProperties::new("block/stone_mirrored")?.with_weight(2),
+    /// ]);
+    ///
+    /// assert_eq!(variant.total_weight(), 3);
+    /// assert_eq!(variant.choose(0).model, "block/stone");
+    /// assert_eq!(variant.choose(2).model, "block/stone_mirrored");
+    /// assert_eq!(variant.choose(3).model, "block/stone");
+    /// # Ok::<(), minecraft_assets::api::InvalidIdentifier>(())
+    /// ```
+    pub fn choose(&self, index: u32) -> &ModelProperties {
+        let models = self.models();
+        let total = self.total_weight();
+        if total == 0 {
+            return &models[0];
+        }
+
+        let mut index = index % total;
+        for model in models {
+            if index < model.weight {
+                return model;
+            }
+            index -= model.weight;
+        }
+
+        unreachable!("the index is less than the total weight")
+    }
+
+    /// Returns the model that the game picks for a block whose random
+    /// generator is seeded with the given seed, like the game does up to
+    /// 1.21.4.
+    ///
+    /// The seed of a [`Variants`][BlockStates::Variants] block is the
+    /// [`position_seed()`] of its position. Use
+    /// [`BlockStates::models_at()`] to also pick the models of the parts of a
+    /// [`Multipart`][BlockStates::Multipart] block.
+    pub fn choose_seeded(&self, seed: i64) -> &ModelProperties {
+        let models = self.models();
+        let total = self.total_weight() as i32;
+        if models.len() == 1 || total <= 0 {
+            return &models[0];
+        }
+
+        // `Math.abs((int) random.nextLong()) % totalWeight`, which is negative
+        // when the random int is `i32::MIN`. Like `WeightedRandom.getWeightedItem`,
+        // the weight of a model is subtracted before the index is compared, so
+        // a negative index picks the first model, and the loop always picks a
+        // model since the index is less than the total weight.
+        let mut index = (LegacyRandom::new(seed).next_long() as i32).wrapping_abs() % total;
+        for model in models {
+            index -= model.weight as i32;
+            if index < 0 {
+                return model;
+            }
+        }
+
+        &models[0]
+    }
+}
+
+/// Returns the seed of the random generator that the game uses to pick the
+/// models of the block at the given position.
+///
+/// A few blocks use the seed of another position, e.g., the upper halves of
+/// tall flowers use the seed of their lower halves.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::blockstates::*;
+/// let stone = ModelProperties::new("block/stone")?;
+/// let mirrored = ModelProperties::new("block/stone_mirrored")?;
+/// let variant = Variant::from(vec![
+///     stone.clone(),
+///     mirrored.clone(),
+///     stone.with_y(180),
+///     mirrored.with_y(180),
+/// ]);
+///
+/// let model = variant.choose_seeded(position_seed([12, 64, -7]));
+/// assert_eq!(model, variant.choose_seeded(position_seed([12, 64, -7])));
+/// # Ok::<(), minecraft_assets::api::InvalidIdentifier>(())
+/// ```
+pub fn position_seed([x, y, z]: [i32; 3]) -> i64 {
+    let mut seed =
+        i64::from(x.wrapping_mul(3129871)) ^ i64::from(z).wrapping_mul(116129781) ^ i64::from(y);
+    seed = seed
+        .wrapping_mul(seed)
+        .wrapping_mul(42317861)
+        .wrapping_add(seed.wrapping_mul(11));
+    seed >> 16
 }
 
 /// Contains the properties of a model that is used to render all or part of a
 /// block in a particular state.
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ModelProperties {
     /// Specifies the path to the model file of the block, in the form of a
     /// [resource location].
@@ -190,14 +825,20 @@ pub struct ModelProperties {
     ///
     /// [resource location]: <https://minecraft.fandom.com/wiki/Model#File_path>
     /// [`ModelIdentifier`]: crate::api::ModelIdentifier
-    pub model: String,
+    pub model: ResourceLocation<'static>,
 
     /// Rotation of the model on the x-axis in increments of 90 degrees.
-    #[serde(default = "ModelProperties::default_rotation")]
+    #[serde(
+        default = "ModelProperties::default_rotation",
+        skip_serializing_if = "ModelProperties::is_default_rotation"
+    )]
     pub x: i32,
 
     /// Rotation of the model on the y-axis in increments of 90 degrees.
-    #[serde(default = "ModelProperties::default_rotation")]
+    #[serde(
+        default = "ModelProperties::default_rotation",
+        skip_serializing_if = "ModelProperties::is_default_rotation"
+    )]
     pub y: i32,
 
     /// Can be `true` or `false` (default). Locks the rotation of the texture of
@@ -207,7 +848,11 @@ pub struct ModelProperties {
     /// See the example on the [wiki page].
     ///
     /// [wiki page]: <https://minecraft.fandom.com/wiki/Model#Block_states>
-    #[serde(rename = "uvlock", default = "ModelProperties::default_uv_lock")]
+    #[serde(
+        rename = "uvlock",
+        default = "ModelProperties::default_uv_lock",
+        skip_serializing_if = "ModelProperties::is_default_uv_lock"
+    )]
     pub uv_lock: bool,
 
     /// Sets the probability of the model for being used in the game.
@@ -219,7 +864,10 @@ pub struct ModelProperties {
     /// weight would be 4 (1+1+2). The probability of each model being used
     /// would then be determined by dividing each weight by 4: 1/4, 1/4 and 2/4,
     /// or 25%, 25% and 50%, respectively.)
-    #[serde(default = "ModelProperties::default_weight")]
+    #[serde(
+        default = "ModelProperties::default_weight",
+        skip_serializing_if = "ModelProperties::is_default_weight"
+    )]
     pub weight: u32,
 }
 
@@ -235,12 +883,59 @@ impl ModelProperties {
     pub(crate) const fn default_weight() -> u32 {
         1
     }
+
+    fn is_default_rotation(rotation: &i32) -> bool {
+        strict::is_default(rotation, Self::default_rotation())
+    }
+
+    fn is_default_uv_lock(uv_lock: &bool) -> bool {
+        strict::is_default(uv_lock, Self::default_uv_lock())
+    }
+
+    fn is_default_weight(weight: &u32) -> bool {
+        strict::is_default(weight, Self::default_weight())
+    }
 }
 
-impl Default for ModelProperties {
-    fn default() -> Self {
+impl ModelProperties {
+    /// Returns the properties of the given model with no rotation.
+    ///
+    /// Returns an error if the model is not a valid [`ResourceLocation`], like
+    /// the [`TryFrom`] conversions of strings into [`ModelProperties`] and
+    /// [`Variant`].
+    pub fn new(model: impl AsRef<str>) -> Result<Self, InvalidIdentifier> {
+        Ok(Self::from(model.as_ref().parse::<ResourceLocation>()?))
+    }
+
+    /// Returns these properties with the given rotation around the x-axis.
+    pub fn with_x(mut self, x: i32) -> Self {
+        self.x = x;
+        self
+    }
+
+    /// Returns these properties with the given rotation around the y-axis.
+    pub fn with_y(mut self, y: i32) -> Self {
+        self.y = y;
+        self
+    }
+
+    /// Returns these properties with the texture rotation locked.
+    pub fn with_uv_lock(mut self) -> Self {
+        self.uv_lock = true;
+        self
+    }
+
+    /// Returns these properties with the given weight.
+    pub fn with_weight(mut self, weight: u32) -> Self {
+        self.weight = weight;
+        self
+    }
+}
+
+impl From<ResourceLocation<'static>> for ModelProperties {
+    fn from(model: ResourceLocation<'static>) -> Self {
         Self {
-            model: Default::default(),
+            model,
             x: Self::default_rotation(),
             y: Self::default_rotation(),
             uv_lock: Self::default_uv_lock(),
@@ -249,12 +944,55 @@ impl Default for ModelProperties {
     }
 }
 
+impl TryFrom<&str> for ModelProperties {
+    type Error = InvalidIdentifier;
+
+    fn try_from(model: &str) -> Result<Self, Self::Error> {
+        Self::new(model)
+    }
+}
+
+impl TryFrom<String> for ModelProperties {
+    type Error = InvalidIdentifier;
+
+    fn try_from(model: String) -> Result<Self, Self::Error> {
+        Ok(Self::from(ResourceLocation::new_owned(model)?))
+    }
+}
+
+impl From<ModelProperties> for Variant {
+    fn from(model: ModelProperties) -> Self {
+        Self::Single(model)
+    }
+}
+
+impl From<Vec<ModelProperties>> for Variant {
+    fn from(models: Vec<ModelProperties>) -> Self {
+        Self::Multiple(models)
+    }
+}
+
+impl From<ResourceLocation<'static>> for Variant {
+    fn from(model: ResourceLocation<'static>) -> Self {
+        Self::Single(model.into())
+    }
+}
+
+impl TryFrom<&str> for Variant {
+    type Error = InvalidIdentifier;
+
+    fn try_from(model: &str) -> Result<Self, Self::Error> {
+        ModelProperties::new(model).map(Self::Single)
+    }
+}
+
 /// Types used to compose [`BlockStates::Multipart`].
 pub mod multipart {
     use super::*;
 
     /// Specifies a case and the model that should apply in that case.
     #[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     pub struct Case {
         /// A list of cases that have to be met for the model to be applied.
         ///
@@ -280,10 +1018,18 @@ pub mod multipart {
                 true
             }
         }
+
+        /// Like [`applies()`][Self::applies], but with typed
+        /// [`BlockProperties`].
+        pub fn applies_to(&self, properties: &BlockProperties) -> bool {
+            let values = state_values(properties);
+            self.applies(values.iter().map(|(name, value)| (*name, value)))
+        }
     }
 
     /// A list of conditions that have to be met for a model to be applied.
     #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[serde(untagged)]
     pub enum WhenClause {
         /// A `when` clause that is true when the given condition is true.
@@ -295,31 +1041,86 @@ pub mod multipart {
             #[serde(rename = "OR")]
             or: Vec<Condition>,
         },
+
+        /// A `when` clause that is true when all of the given conditions are
+        /// true.
+        ///
+        /// Added in 1.20.3.
+        And {
+            /// The conditions in the `AND` clause.
+            #[serde(rename = "AND")]
+            and: Vec<Condition>,
+        },
     }
 
     impl WhenClause {
         /// Returns all of the [`Condition`]s of this when clause as a slice.
         ///
         /// The slice will contain one element for a [`Single`][Self::Single]
-        /// variant, and multiple for an [`Or`][Self::Or] variant.
+        /// variant, and multiple for an [`Or`][Self::Or] or an
+        /// [`And`][Self::And] variant.
         pub fn conditions(&self) -> &[Condition] {
             match self {
                 Self::Single(condition) => std::slice::from_ref(condition),
                 Self::Or { or } => &or[..],
+                Self::And { and } => &and[..],
             }
         }
 
-        /// Returns `true` if any of the conditions specified by this `when`
-        /// clause are satisfied by the provided state values.
+        /// Returns `true` if the conditions specified by this `when` clause
+        /// are satisfied by the provided state values: any of them for an
+        /// [`Or`][Self::Or] clause, and all of them otherwise.
         ///
         /// See [`Condition::applies`].
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// # use minecraft_assets::schemas::blockstates::multipart::*;
+        /// let when: WhenClause = serde_json::from_str(r#"
+        ///     { "AND": [{ "north": "low|tall" }, { "up": "false" }] }
+        /// "#).unwrap();
+        ///
+        /// let tall = StateValue::from("tall");
+        /// let no = StateValue::from(false);
+        /// let yes = StateValue::from(true);
+        /// assert!(when.applies([("north", &tall), ("up", &no)]));
+        /// assert!(!when.applies([("north", &tall), ("up", &yes)]));
+        /// ```
         pub fn applies<'a, I>(&self, state_values: I) -> bool
         where
             I: IntoIterator<Item = (&'a str, &'a StateValue)> + Clone,
         {
-            self.conditions()
-                .iter()
-                .any(|condition| condition.applies(state_values.clone()))
+            let mut conditions = self.conditions().iter();
+            match self {
+                Self::Or { .. } => {
+                    conditions.any(|condition| condition.applies(state_values.clone()))
+                }
+                Self::Single(_) | Self::And { .. } => {
+                    conditions.all(|condition| condition.applies(state_values.clone()))
+                }
+            }
+        }
+
+        /// Like [`applies()`][Self::applies], but with typed
+        /// [`BlockProperties`].
+        pub fn applies_to(&self, properties: &BlockProperties) -> bool {
+            let values = state_values(properties);
+            self.applies(values.iter().map(|(name, value)| (*name, value)))
+        }
+    }
+
+    /// Returns the given properties as state values.
+    fn state_values(properties: &BlockProperties) -> Vec<(&str, StateValue)> {
+        properties
+            .iter()
+            .map(|(name, value)| (name, StateValue::from(value.to_string())))
+            .collect()
+    }
+
+    impl From<Condition> for WhenClause {
+        fn from(condition: Condition) -> Self {
+            Self::Single(condition)
         }
     }
 
@@ -330,7 +1131,11 @@ pub mod multipart {
     /// ```json
     /// "when": {"north": "side|up", "east": "side|up" }
     /// ```
+    ///
+    /// A value can list several alternatives separated by `|`, and a value
+    /// starting with `!` matches every value except the listed ones.
     #[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[serde(rename_all = "lowercase")]
     pub struct Condition {
         /// Map from state name to state value that forms the list of conditions.
@@ -339,7 +1144,8 @@ pub mod multipart {
     }
 
     impl Condition {
-        /// Returns `true` if
+        /// Returns `true` if every state of the condition has a matching value
+        /// in the provided state values.
         ///
         /// # Example
         ///
@@ -384,14 +1190,36 @@ pub mod multipart {
             let state_values: HashMap<&'a str, &'a StateValue> = state_values.into_iter().collect();
 
             self.and.iter().all(|(state, required_value)| {
-                state_values
-                    .get(state.as_str())
-                    .map(|value| *required_value == **value)
-                    .unwrap_or(false)
+                let value = match state_values.get(state.as_str()) {
+                    Some(value) => *value,
+                    None => return false,
+                };
+
+                // Values starting with `!` match every other value.
+                match required_value {
+                    StateValue::String(required) if required.starts_with('!') => {
+                        StateValue::from(&required[1..]) != *value
+                    }
+                    _ => required_value == value,
+                }
             })
         }
     }
 
+    impl<K, V> FromIterator<(K, V)> for Condition
+    where
+        K: Into<String>,
+        V: Into<StateValue>,
+    {
+        fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+            let and = iter
+                .into_iter()
+                .map(|(state, value)| (state.into(), value.into()))
+                .collect();
+            Self { and }
+        }
+    }
+
     /// The right-hand side of a [`Condition`] requirement.
     ///
     /// ```txt
@@ -399,6 +1227,7 @@ pub mod multipart {
     ///                   ^^^^^^^^^          ^^^^^
     /// ```
     #[derive(Deserialize, Serialize, Debug, Clone)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[serde(untagged)]
     pub enum StateValue {
         /// Unquoted bool value.
@@ -564,10 +1393,7 @@ mod test {
     use maplit::hashmap;
 
     fn make_single_variant(model_name: &str) -> Variant {
-        Variant::Single(ModelProperties {
-            model: String::from(model_name),
-            ..Default::default()
-        })
+        Variant::Single(ModelProperties::new(model_name).unwrap())
     }
 
     fn do_test(
@@ -623,6 +1449,43 @@ mod test {
         do_test(blockstates, &state_values, &["model2"]);
     }
 
+    #[test]
+    fn test_applied_variants_most_specific() {
+        let blockstates = BlockStates::Variants {
+            variants: hashmap! {
+                String::from("var1=foo") => make_single_variant("model1"),
+                String::from("var1=foo,var2=true") => make_single_variant("model2"),
+                String::from("inventory") => make_single_variant("model3"),
+            },
+        };
+
+        let models = |state_values: &[(&'static str, &'static str)]| {
+            blockstates
+                .applied_variants(state_values.iter().copied())
+                .iter()
+                .flat_map(|variant| variant.models())
+                .map(|model_properties| model_properties.model.as_str())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(models(&[("var1", "foo"), ("var2", "true")]), ["model2"]);
+        assert_eq!(models(&[("var1", "foo"), ("var2", "false")]), ["model1"]);
+        assert!(models(&[("var1", "bar")]).is_empty());
+    }
+
+    #[test]
+    fn test_applied_variants_legacy_normal() {
+        let blockstates = BlockStates::Variants {
+            variants: hashmap! {
+                String::from("normal") => make_single_variant("model1"),
+                String::from("inventory") => make_single_variant("model2"),
+            },
+        };
+
+        let variants = blockstates.applied_variants([]);
+        assert_eq!(variants, [&make_single_variant("model1")]);
+    }
+
     #[test]
     fn test_multipart() {
         let blockstates = BlockStates::Multipart {
@@ -650,4 +1513,30 @@ mod test {
 
         do_test(blockstates, &state_values, &["model1", "model2"]);
     }
+
+    #[test]
+    fn test_multipart_and_negation() {
+        let blockstates: BlockStates = serde_json::from_str(
+            r#"{
+                "multipart": [
+                    { "when": { "AND": [{ "up": "true" }, { "north": "!none" }] }, "apply": { "model": "model1" } },
+                    { "when": { "OR": [{ "north": "none" }, { "east": "low|tall" }] }, "apply": { "model": "model2" } }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let properties: BlockProperties = "east=none,north=low,up=true".parse().unwrap();
+        let models: Vec<_> = blockstates
+            .applied_variants_for(&properties)
+            .into_iter()
+            .map(|variant| variant.models()[0].model.as_str())
+            .collect();
+        assert_eq!(models, ["model1"]);
+
+        let cases = blockstates.cases().unwrap();
+        let properties = properties.with("north", "none").with("east", "tall");
+        assert!(!cases[0].applies_to(&properties));
+        assert!(cases[1].applies_to(&properties));
+    }
 }
@@ -0,0 +1,632 @@
+//! Borrowed variants of the [`Model`] and [`BlockStates`] schemas, which
+//! deserialize without allocating their strings.
+//!
+//! Start here: [`ModelRef`] and [`BlockStatesRef`].
+//!
+//! The strings of these types (texture variables, state values, etc.) are
+//! [`CowStr`]s, and their model locations are [`ResourceLocation`]s, that
+//! borrow from the JSON text whenever it does not need unescaping, which is
+//! nearly always the case. Bulk loading
+//! thousands of models or block states this way avoids most of the small
+//! allocations of the owned schemas. The JSON text must outlive the values,
+//! e.g., by loading it as a [`RawResource`] first.
+//!
+//! Fields that the types do not model are ignored, even with the
+//! `preserve-unknown` feature. Every type converts into its owned
+//! counterpart with [`From`].
+//!
+//! [`RawResource`]: crate::api::RawResource
+//! [`ResourceLocation`]: crate::api::ResourceLocation
+//!
+//! # Example
+//!
+//! ```
+//! use minecraft_assets::schemas::{borrowed::*, Model};
+//!
+//! let json = r#"{
+//!     "parent": "block/cube_all",
+//!     "textures": { "all": "block/stone" }
+//! }"#;
+//!
+//! let model: ModelRef = serde_json::from_str(json).unwrap();
+//! let parent = model.parent.as_ref().unwrap();
+//! assert_eq!(parent, "block/cube_all");
+//! assert!(parent.is_borrowed());
+//! assert_eq!(model.textures.as_ref().unwrap()["all"], "block/stone");
+//!
+//! let owned = Model::from(model);
+//! assert_eq!(owned, serde_json::from_str::<Model>(json).unwrap());
+//! ```
+
+use std::{
+    borrow::{Borrow, Cow},
+    collections::HashMap,
+    fmt,
+    ops::Deref,
+};
+
+use serde::{de::Visitor, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    api::ResourceLocation,
+    schemas::{
+        blockstates::{
+            multipart::{Case, Condition, StateValue, WhenClause},
+            ModelProperties, Variant,
+        },
+        models::{
+            BlockFace, Display, Element, ElementFace, ElementRotation, GuiLightMode, OverrideCase,
+            PredicateValue, Texture, Textures,
+        },
+        BlockStates, Model,
+    },
+};
+
+/// A string that borrows from the deserialized text if it can, or owns an
+/// unescaped copy otherwise.
+///
+/// Unlike a plain [`Cow<str>`][Cow], this also borrows when nested in an
+/// [`Option`] or a collection.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CowStr<'a>(pub Cow<'a, str>);
+
+impl CowStr<'_> {
+    /// Returns the string, borrowed from the text.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the string as an owned [`String`], copying it if it is
+    /// borrowed.
+    pub fn into_owned(self) -> String {
+        self.0.into_owned()
+    }
+}
+
+impl Deref for CowStr<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for CowStr<'_> {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq<str> for CowStr<'_> {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for CowStr<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl fmt::Display for CowStr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl<'a> From<&'a str> for CowStr<'a> {
+    fn from(s: &'a str) -> Self {
+        Self(Cow::Borrowed(s))
+    }
+}
+
+impl From<String> for CowStr<'_> {
+    fn from(s: String) -> Self {
+        Self(Cow::Owned(s))
+    }
+}
+
+impl Serialize for CowStr<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de: 'a, 'a> Deserialize<'de> for CowStr<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(CowStrVisitor)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for CowStr<'_> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        String::schema_name()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        String::json_schema(generator)
+    }
+}
+
+struct CowStrVisitor;
+
+impl<'de> Visitor<'de> for CowStrVisitor {
+    type Value = CowStr<'de>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a string")
+    }
+
+    fn visit_borrowed_str<E>(self, s: &'de str) -> Result<Self::Value, E> {
+        Ok(CowStr(Cow::Borrowed(s)))
+    }
+
+    fn visit_str<E>(self, s: &str) -> Result<Self::Value, E> {
+        Ok(CowStr(Cow::Owned(s.to_string())))
+    }
+
+    fn visit_string<E>(self, s: String) -> Result<Self::Value, E> {
+        Ok(CowStr(Cow::Owned(s)))
+    }
+}
+
+fn deserialize_parent<'de: 'a, 'a, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<ResourceLocation<'a>>, D::Error> {
+    ResourceLocation::deserialize_borrowed(deserializer).map(Some)
+}
+
+/*
+    dMMMMMMMMb  .aMMMb  dMMMMb  dMMMMMP dMP
+   dMP"dMP"dMP dMP"dMP dMP VMP dMP     dMP
+  dMP dMP dMP dMP dMP dMP dMP dMMMP   dMP
+ dMP dMP dMP dMP.aMP dMP.aMP dMP     dMP
+dMP dMP dMP  VMMMP" dMMMMP" dMMMMMP dMMMMMP
+
+*/
+
+/// A borrowed [`Model`].
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ModelRef<'a> {
+    /// See [`Model::parent`].
+    #[serde(borrow, default, deserialize_with = "deserialize_parent")]
+    pub parent: Option<ResourceLocation<'a>>,
+
+    /// See [`Model::display`].
+    pub display: Option<Display>,
+
+    /// The values of the texture variables by name. See [`Model::textures`].
+    #[serde(borrow)]
+    pub textures: Option<HashMap<CowStr<'a>, CowStr<'a>>>,
+
+    /// See [`Model::elements`].
+    #[serde(borrow)]
+    pub elements: Option<Vec<ElementRef<'a>>>,
+
+    /// See [`Model::ambient_occlusion`].
+    #[serde(rename = "ambientocclusion")]
+    pub ambient_occlusion: Option<bool>,
+
+    /// See [`Model::gui_light_mode`].
+    #[serde(rename = "gui_light")]
+    pub gui_light_mode: Option<GuiLightMode>,
+
+    /// See [`Model::overrides`].
+    #[serde(borrow)]
+    pub overrides: Option<Vec<OverrideCaseRef<'a>>>,
+}
+
+impl From<ModelRef<'_>> for Model {
+    fn from(model: ModelRef<'_>) -> Self {
+        Self {
+            parent: model.parent.map(|parent| parent.to_owned()),
+            display: model.display,
+            textures: model.textures.map(|textures| Textures {
+                variables: textures
+                    .into_iter()
+                    .map(|(name, value)| (name.into_owned(), Texture(value.into_owned())))
+                    .collect(),
+            }),
+            elements: model
+                .elements
+                .map(|elements| elements.into_iter().map(Element::from).collect()),
+            ambient_occlusion: model.ambient_occlusion,
+            gui_light_mode: model.gui_light_mode,
+            overrides: model
+                .overrides
+                .map(|overrides| overrides.into_iter().map(OverrideCase::from).collect()),
+            #[cfg(feature = "preserve-unknown")]
+            extra: Default::default(),
+        }
+    }
+}
+
+/// A borrowed [`Element`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ElementRef<'a> {
+    /// See [`Element::from`].
+    pub from: [f32; 3],
+
+    /// See [`Element::to`].
+    pub to: [f32; 3],
+
+    /// See [`Element::faces`].
+    #[serde(borrow)]
+    pub faces: HashMap<BlockFace, ElementFaceRef<'a>>,
+
+    /// See [`Element::rotation`].
+    #[serde(default)]
+    pub rotation: ElementRotation,
+
+    /// See [`Element::shade`].
+    #[serde(default = "Element::default_shade")]
+    pub shade: bool,
+}
+
+impl From<ElementRef<'_>> for Element {
+    fn from(element: ElementRef<'_>) -> Self {
+        Self {
+            from: element.from,
+            to: element.to,
+            faces: element
+                .faces
+                .into_iter()
+                .map(|(face, value)| (face, ElementFace::from(value)))
+                .collect(),
+            rotation: element.rotation,
+            shade: element.shade,
+            #[cfg(feature = "preserve-unknown")]
+            extra: Default::default(),
+        }
+    }
+}
+
+/// A borrowed [`ElementFace`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ElementFaceRef<'a> {
+    /// See [`ElementFace::uv`].
+    pub uv: Option<[f32; 4]>,
+
+    /// See [`ElementFace::texture`].
+    #[serde(borrow)]
+    pub texture: CowStr<'a>,
+
+    /// See [`ElementFace::cull_face`].
+    #[serde(rename = "cullface")]
+    pub cull_face: Option<BlockFace>,
+
+    /// See [`ElementFace::rotation`].
+    #[serde(default = "ElementFace::default_rotation")]
+    pub rotation: u32,
+
+    /// See [`ElementFace::tint_index`].
+    #[serde(rename = "tintindex", default = "ElementFace::default_tint_index")]
+    pub tint_index: i32,
+}
+
+impl From<ElementFaceRef<'_>> for ElementFace {
+    fn from(face: ElementFaceRef<'_>) -> Self {
+        Self {
+            uv: face.uv,
+            texture: Texture(face.texture.into_owned()),
+            cull_face: face.cull_face,
+            rotation: face.rotation,
+            tint_index: face.tint_index,
+            #[cfg(feature = "preserve-unknown")]
+            extra: Default::default(),
+        }
+    }
+}
+
+/// A borrowed [`OverrideCase`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct OverrideCaseRef<'a> {
+    /// See [`OverrideCase::predicate`].
+    #[serde(borrow)]
+    pub predicate: HashMap<CowStr<'a>, PredicateValue>,
+
+    /// See [`OverrideCase::model`].
+    #[serde(borrow, deserialize_with = "ResourceLocation::deserialize_borrowed")]
+    pub model: ResourceLocation<'a>,
+}
+
+impl From<OverrideCaseRef<'_>> for OverrideCase {
+    fn from(case: OverrideCaseRef<'_>) -> Self {
+        Self {
+            predicate: case
+                .predicate
+                .into_iter()
+                .map(|(name, value)| (name.into_owned(), value))
+                .collect(),
+            model: case.model.to_owned(),
+        }
+    }
+}
+
+/*
+    dMMMMb  dMP        .aMMMb  .aMMMb  dMP dMP .dMMMb  dMMMMMMP .aMMMb dMMMMMMP dMMMMMP .dMMMb
+   dMP"dMP dMP       dMP"dMP dMP"VMP dMP.dMP dMP" VP    dMP   dMP"dMP   dMP   dMP     dMP" VP
+  dMMMMK" dMP       dMP dMP dMP     dMMMMK"  VMMMb     dMP   dMMMMMP   dMP   dMMMP    VMMMb
+ dMP.aMF dMP       dMP.aMP dMP.aMP dMP"AMF dP .dMP    dMP   dMP dMP   dMP   dMP     dP .dMP
+dMMMMP" dMMMMMP    VMMMP"  VMMMP" dMP dMP  VMMMP"    dMP   dMP dMP   dMP   dMMMMMP  VMMMP"
+
+*/
+
+/// Borrowed [`BlockStates`].
+///
+/// # Example
+///
+/// ```
+/// use minecraft_assets::schemas::{borrowed::*, BlockStates};
+///
+/// let json = r#"{
+///     "variants": {
+///         "snowy=false": [{ "model": "block/grass_block" }, { "model": "block/grass_block", "y": 90 }],
+///         "snowy=true": { "model": "block/grass_block_snow" }
+///     }
+/// }"#;
+///
+/// let states: BlockStatesRef = serde_json::from_str(json).unwrap();
+/// let BlockStatesRef::Variants { variants } = &states else { panic!() };
+/// assert_eq!(variants["snowy=true"].models()[0].model, "block/grass_block_snow");
+///
+/// assert_eq!(BlockStates::from(states), serde_json::from_str::<BlockStates>(json).unwrap());
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum BlockStatesRef<'a> {
+    /// See [`BlockStates::Variants`].
+    Variants {
+        /// The variants of the block by name.
+        #[serde(borrow)]
+        variants: HashMap<CowStr<'a>, VariantRef<'a>>,
+    },
+
+    /// See [`BlockStates::Multipart`].
+    Multipart {
+        /// The cases and the models that apply in each case.
+        #[serde(borrow, rename = "multipart")]
+        cases: Vec<CaseRef<'a>>,
+    },
+}
+
+impl From<BlockStatesRef<'_>> for BlockStates {
+    fn from(states: BlockStatesRef<'_>) -> Self {
+        match states {
+            BlockStatesRef::Variants { variants } => Self::Variants {
+                variants: variants
+                    .into_iter()
+                    .map(|(name, variant)| (name.into_owned(), Variant::from(variant)))
+                    .collect(),
+            },
+            BlockStatesRef::Multipart { cases } => Self::Multipart {
+                cases: cases.into_iter().map(Case::from).collect(),
+            },
+        }
+    }
+}
+
+/// A borrowed [`Variant`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum VariantRef<'a> {
+    /// A variant with only a single model to choose from.
+    Single(#[serde(borrow)] ModelPropertiesRef<'a>),
+
+    /// A variant with multiple models to choose from.
+    Multiple(#[serde(borrow)] Vec<ModelPropertiesRef<'a>>),
+}
+
+impl<'a> VariantRef<'a> {
+    /// Returns the models of the variant as a slice. See [`Variant::models`].
+    pub fn models(&self) -> &[ModelPropertiesRef<'a>] {
+        match self {
+            Self::Single(model) => std::slice::from_ref(model),
+            Self::Multiple(models) => models,
+        }
+    }
+}
+
+impl From<VariantRef<'_>> for Variant {
+    fn from(variant: VariantRef<'_>) -> Self {
+        match variant {
+            VariantRef::Single(model) => Self::Single(model.into()),
+            VariantRef::Multiple(models) => {
+                Self::Multiple(models.into_iter().map(ModelProperties::from).collect())
+            }
+        }
+    }
+}
+
+/// Borrowed [`ModelProperties`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ModelPropertiesRef<'a> {
+    /// See [`ModelProperties::model`].
+    #[serde(borrow, deserialize_with = "ResourceLocation::deserialize_borrowed")]
+    pub model: ResourceLocation<'a>,
+
+    /// See [`ModelProperties::x`].
+    #[serde(default = "ModelProperties::default_rotation")]
+    pub x: i32,
+
+    /// See [`ModelProperties::y`].
+    #[serde(default = "ModelProperties::default_rotation")]
+    pub y: i32,
+
+    /// See [`ModelProperties::uv_lock`].
+    #[serde(rename = "uvlock", default = "ModelProperties::default_uv_lock")]
+    pub uv_lock: bool,
+
+    /// See [`ModelProperties::weight`].
+    #[serde(default = "ModelProperties::default_weight")]
+    pub weight: u32,
+}
+
+impl From<ModelPropertiesRef<'_>> for ModelProperties {
+    fn from(model: ModelPropertiesRef<'_>) -> Self {
+        Self {
+            model: model.model.to_owned(),
+            x: model.x,
+            y: model.y,
+            uv_lock: model.uv_lock,
+            weight: model.weight,
+        }
+    }
+}
+
+/// A borrowed multipart [`Case`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CaseRef<'a> {
+    /// See [`Case::when`].
+    #[serde(borrow)]
+    pub when: Option<WhenClauseRef<'a>>,
+
+    /// See [`Case::apply`].
+    #[serde(borrow)]
+    pub apply: VariantRef<'a>,
+}
+
+impl From<CaseRef<'_>> for Case {
+    fn from(case: CaseRef<'_>) -> Self {
+        Self {
+            when: case.when.map(WhenClause::from),
+            apply: case.apply.into(),
+        }
+    }
+}
+
+/// A borrowed multipart [`WhenClause`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum WhenClauseRef<'a> {
+    /// See [`WhenClause::Single`].
+    Single(#[serde(borrow)] ConditionRef<'a>),
+
+    /// See [`WhenClause::Or`].
+    Or {
+        /// The conditions in the `OR` clause.
+        #[serde(borrow, rename = "OR")]
+        or: Vec<ConditionRef<'a>>,
+    },
+
+    /// See [`WhenClause::And`].
+    And {
+        /// The conditions in the `AND` clause.
+        #[serde(borrow, rename = "AND")]
+        and: Vec<ConditionRef<'a>>,
+    },
+}
+
+impl From<WhenClauseRef<'_>> for WhenClause {
+    fn from(clause: WhenClauseRef<'_>) -> Self {
+        let convert = |conditions: Vec<ConditionRef<'_>>| {
+            conditions.into_iter().map(Condition::from).collect()
+        };
+
+        match clause {
+            WhenClauseRef::Single(condition) => Self::Single(condition.into()),
+            WhenClauseRef::Or { or } => Self::Or { or: convert(or) },
+            WhenClauseRef::And { and } => Self::And { and: convert(and) },
+        }
+    }
+}
+
+/// A borrowed multipart [`Condition`].
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ConditionRef<'a> {
+    /// See [`Condition::and`].
+    #[serde(borrow, flatten)]
+    pub and: HashMap<CowStr<'a>, StateValueRef<'a>>,
+}
+
+impl From<ConditionRef<'_>> for Condition {
+    fn from(condition: ConditionRef<'_>) -> Self {
+        Self {
+            and: condition
+                .and
+                .into_iter()
+                .map(|(name, value)| (name.into_owned(), value.into()))
+                .collect(),
+        }
+    }
+}
+
+/// A borrowed multipart [`StateValue`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum StateValueRef<'a> {
+    /// Unquoted bool value.
+    Bool(bool),
+
+    /// String value (possibly boolean-like, i.e., `"true"` or `"false"`).
+    String(#[serde(borrow)] CowStr<'a>),
+}
+
+impl From<StateValueRef<'_>> for StateValue {
+    fn from(value: StateValueRef<'_>) -> Self {
+        match value {
+            StateValueRef::Bool(value) => Self::Bool(value),
+            StateValueRef::String(value) => Self::String(value.into_owned()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn borrows_unescaped_strings() {
+        let json = r#"{
+            "multipart": [
+                { "apply": { "model": "block/fence_post" } },
+                {
+                    "when": { "OR": [{ "north": "true" }, { "east": "low|tall" }] },
+                    "apply": { "model": "block\/fence_side", "uvlock": true }
+                }
+            ]
+        }"#;
+
+        let states: BlockStatesRef = serde_json::from_str(json).unwrap();
+        let BlockStatesRef::Multipart { cases } = &states else {
+            panic!("expected multipart block states");
+        };
+
+        let model = &cases[0].apply.models()[0].model;
+        assert!(model.is_borrowed());
+        assert_eq!(model, "block/fence_post");
+        let model = &cases[1].apply.models()[0].model;
+        assert!(!model.is_borrowed());
+        assert_eq!(model, "block/fence_side");
+
+        let Some(WhenClauseRef::Or { or }) = &cases[1].when else {
+            panic!("expected an OR clause");
+        };
+        let (name, value) = or[1].and.iter().next().unwrap();
+        assert!(matches!(name.0, Cow::Borrowed("east")));
+        assert!(matches!(
+            value,
+            StateValueRef::String(CowStr(Cow::Borrowed("low|tall")))
+        ));
+
+        let owned: BlockStates = serde_json::from_str(json).unwrap();
+        assert_eq!(BlockStates::from(states), owned);
+    }
+}
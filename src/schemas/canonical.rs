@@ -0,0 +1,249 @@
+//! Serializing schemas to JSON with the same conventions as the files written
+//! by the game's data generator, so that they diff cleanly against them.
+//!
+//! Start here: [`to_string()`].
+//!
+//! The canonical format differs from `serde_json`'s pretty printer in that:
+//!
+//! * The fields of objects are sorted, except that `type` and `parent` come
+//!   first, like the data generator's key comparator orders them.
+//! * Fields whose value is `null` are left out, like absent optional fields.
+//!   The schemas also leave out the fields that hold their default value
+//!   (e.g., `"shade": true` or `"tintindex": -1`).
+//! * Numbers without a fractional part are written as integers (e.g., `16`
+//!   rather than `16.0`), like the game's files write them.
+//! * Arrays of numbers and booleans (e.g., the `from` and `to` corners of a
+//!   model element) are written on a single line.
+//! * The output does not end with a newline.
+//!
+//! Objects and other arrays are indented with two spaces, and empty ones are
+//! written as `{}` and `[]`.
+//!
+//! # Example
+//!
+//! ```
+//! use minecraft_assets::schemas::{canonical, Model};
+//!
+//! let model: Model = serde_json::from_str(r##"{
+//!     "textures": { "texture": "block/stone", "particle": "#texture" },
+//!     "elements": [{
+//!         "from": [0, 0, 0],
+//!         "to": [16, 8, 16],
+//!         "rotation": { "origin": [8, 8, 8], "axis": "y", "angle": 22.5 },
+//!         "faces": { "up": { "texture": "#texture", "tintindex": -1 } }
+//!     }],
+//!     "parent": "block/block"
+//! }"##).unwrap();
+//!
+//! assert_eq!(
+//!     canonical::to_string(&model).unwrap(),
+//!     r##"{
+//!   "parent": "block/block",
+//!   "elements": [
+//!     {
+//!       "faces": {
+//!         "up": {
+//!           "texture": "#texture"
+//!         }
+//!       },
+//!       "from": [0, 0, 0],
+//!       "rotation": {
+//!         "angle": 22.5,
+//!         "axis": "y",
+//!         "origin": [8, 8, 8]
+//!       },
+//!       "to": [16, 8, 16]
+//!     }
+//!   ],
+//!   "textures": {
+//!     "particle": "#texture",
+//!     "texture": "block/stone"
+//!   }
+//! }"##
+//! );
+//! ```
+
+use std::{cmp::Ordering, io};
+
+use serde::Serialize;
+use serde_json::{Map, Number, Value};
+
+/// Serializes the given value as canonical JSON.
+pub fn to_string<T>(value: &T) -> serde_json::Result<String>
+where
+    T: Serialize + ?Sized,
+{
+    let value = serde_json::to_value(value)?;
+    let mut out = String::new();
+    write_value(&mut out, &value, 0);
+    Ok(out)
+}
+
+/// Serializes the given value as canonical JSON bytes.
+pub fn to_vec<T>(value: &T) -> serde_json::Result<Vec<u8>>
+where
+    T: Serialize + ?Sized,
+{
+    to_string(value).map(String::into_bytes)
+}
+
+/// Serializes the given value as canonical JSON into the given writer.
+pub fn to_writer<W, T>(mut writer: W, value: &T) -> serde_json::Result<()>
+where
+    W: io::Write,
+    T: Serialize + ?Sized,
+{
+    let out = to_string(value)?;
+    writer
+        .write_all(out.as_bytes())
+        .map_err(serde_json::Error::io)
+}
+
+/// Compares field names like the data generator's `DataProvider.KEY_COMPARATOR`.
+fn compare_keys(a: &str, b: &str) -> Ordering {
+    fn rank(key: &str) -> u8 {
+        match key {
+            "type" => 0,
+            "parent" => 1,
+            _ => 2,
+        }
+    }
+
+    rank(a).cmp(&rank(b)).then_with(|| a.cmp(b))
+}
+
+fn write_value(out: &mut String, value: &Value, indent: usize) {
+    match value {
+        Value::Object(object) => write_object(out, object, indent),
+        Value::Array(array) if array.is_empty() => out.push_str("[]"),
+        Value::Array(array) if array.iter().all(|v| v.is_number() || v.is_boolean()) => {
+            out.push('[');
+            for (i, value) in array.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                write_value(out, value, indent);
+            }
+            out.push(']');
+        }
+        Value::Array(array) => {
+            out.push('[');
+            for (i, value) in array.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                newline(out, indent + 1);
+                write_value(out, value, indent + 1);
+            }
+            newline(out, indent);
+            out.push(']');
+        }
+        Value::String(s) => write_string(out, s),
+        Value::Number(number) => write_number(out, number),
+        // Booleans, and nulls in arrays.
+        value => out.push_str(&value.to_string()),
+    }
+}
+
+fn write_number(out: &mut String, number: &Number) {
+    match number.as_f64() {
+        // The schemas store most numbers as `f32`, but the game writes the
+        // whole ones without a fractional part.
+        Some(float) if number.is_f64() && float.fract() == 0.0 && float.abs() < 1e15 => {
+            out.push_str(&(float as i64).to_string())
+        }
+        // Print the shortest representation of the `f32` (e.g., `0.1` rather
+        // than `0.10000000149011612`).
+        Some(float) if number.is_f64() && f64::from(float as f32) == float => {
+            out.push_str(&(float as f32).to_string())
+        }
+        _ => out.push_str(&number.to_string()),
+    }
+}
+
+fn write_object(out: &mut String, object: &Map<String, Value>, indent: usize) {
+    let mut fields: Vec<_> = object.iter().filter(|(_, v)| !v.is_null()).collect();
+    if fields.is_empty() {
+        out.push_str("{}");
+        return;
+    }
+    fields.sort_by(|a, b| compare_keys(a.0, b.0));
+
+    out.push('{');
+    for (i, (key, value)) in fields.into_iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        newline(out, indent + 1);
+        write_string(out, key);
+        out.push_str(": ");
+        write_value(out, value, indent + 1);
+    }
+    newline(out, indent);
+    out.push('}');
+}
+
+fn write_string(out: &mut String, s: &str) {
+    // The game's JSON writer also escapes the line and paragraph separators.
+    let escaped = Value::String(s.to_string()).to_string();
+    out.push_str(
+        &escaped
+            .replace('\u{2028}', "\\u2028")
+            .replace('\u{2029}', "\\u2029"),
+    );
+}
+
+fn newline(out: &mut String, indent: usize) {
+    out.push('\n');
+    for _ in 0..indent {
+        out.push_str("  ");
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn output_is_stable() {
+        let value: Value = serde_json::from_str(
+            r#"{
+                "values": ["b", "a"],
+                "type": "minecraft:crafting_shaped",
+                "optional": null,
+                "nested": [[1, 2.0, 0.5], { "z": true, "a": null }],
+                "text": "line\u2028break"
+            }"#,
+        )
+        .unwrap();
+
+        let canonical = to_string(&value).unwrap();
+        assert_eq!(
+            canonical,
+            r#"{
+  "type": "minecraft:crafting_shaped",
+  "nested": [
+    [1, 2, 0.5],
+    {
+      "z": true
+    }
+  ],
+  "text": "line\u2028break",
+  "values": [
+    "b",
+    "a"
+  ]
+}"#
+        );
+
+        let reparsed: Value = serde_json::from_str(&canonical).unwrap();
+        assert_eq!(to_string(&reparsed).unwrap(), canonical);
+    }
+
+    #[test]
+    fn numbers_keep_their_shortest_form() {
+        let numbers: [f32; 4] = [16.0, 0.1, -22.5, 0.0];
+        assert_eq!(to_string(&numbers).unwrap(), "[16, 0.1, -22.5, 0]");
+        assert_eq!(to_string(&[1u8, 2]).unwrap(), "[1, 2]");
+    }
+}
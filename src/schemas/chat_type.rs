@@ -0,0 +1,101 @@
+//! Serde-(de)serializable data types for
+//! `data/<namespace>/chat_type/*.json`.
+//!
+//! Start here: [`ChatType`].
+//!
+//! Chat types were added in 1.19. Only the format used since 1.19.1 is
+//! supported.
+//!
+//! See <https://minecraft.fandom.com/wiki/Chat_type>.
+
+use serde::{Deserialize, Serialize};
+
+use crate::schemas::text::Style;
+
+/// A chat type as stored in the `data/<namespace>/chat_type/` directory.
+///
+/// A chat type determines how a chat message is formatted when it is
+/// displayed in the chat and when it is read by the narrator.
+///
+/// See also the corresponding section of the [wiki page].
+///
+/// [wiki page]: <https://minecraft.fandom.com/wiki/Chat_type#JSON_format>
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::chat_type::*;
+/// let chat_type: ChatType = serde_json::from_str(r#"
+///     {
+///         "chat": {
+///             "translation_key": "commands.message.display.incoming",
+///             "parameters": ["sender", "content"],
+///             "style": { "color": "gray", "italic": true }
+///         },
+///         "narration": {
+///             "translation_key": "chat.type.text.narrate",
+///             "parameters": ["sender", "content"]
+///         }
+///     }
+/// "#).unwrap();
+///
+/// assert_eq!(chat_type.chat.translation_key, "commands.message.display.incoming");
+/// assert_eq!(
+///     chat_type.chat.parameters,
+///     vec![ChatParameter::Sender, ChatParameter::Content]
+/// );
+/// assert!(chat_type.chat.style.is_some());
+/// assert!(chat_type.narration.style.is_none());
+/// ```
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ChatType {
+    /// How the message is displayed in the chat.
+    pub chat: ChatDecoration,
+
+    /// How the message is read by the narrator.
+    pub narration: ChatDecoration,
+
+    /// The fields that this crate does not model (e.g., fields added by mods
+    /// or by newer versions), kept so that they survive a round trip.
+    ///
+    /// Requires the `preserve-unknown` feature.
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten, serialize_with = "crate::schemas::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Specifies how a message of a [`ChatType`] is decorated.
+///
+/// The decorated message is the translation of
+/// [`translation_key`][Self::translation_key], with the
+/// [`parameters`][Self::parameters] as its arguments.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ChatDecoration {
+    /// The translation key of the decorated message.
+    pub translation_key: String,
+
+    /// The arguments passed to the translation, in order.
+    pub parameters: Vec<ChatParameter>,
+
+    /// The text style applied to the decorated message (e.g., `color`,
+    /// `bold`, `italic`).
+    pub style: Option<Style>,
+}
+
+/// An argument passed to the translation of a [`ChatDecoration`].
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ChatParameter {
+    /// The display name of the sender of the message.
+    Sender,
+
+    /// The display name of the target of the message (e.g., the receiver of
+    /// a `/msg` or the team of a `/teammsg`).
+    Target,
+
+    /// The content of the message.
+    Content,
+}
@@ -0,0 +1,179 @@
+//! A packed RGB color, as used by many JSON files.
+//!
+//! Start here: [`Rgb`].
+
+use std::{fmt, str::FromStr};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// A color packed into an integer as `0xRRGGBB`, e.g., the colors of a biome's
+/// [`Effects`].
+///
+/// [`Rgb`] serializes with Serde as a decimal integer, like the game writes
+/// it, but also deserializes from hex strings like `"#91bd59"`.
+///
+/// [`Effects`]: crate::schemas::worldgen::biome::Effects
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::color::*;
+/// let color: Rgb = serde_json::from_str("9551193").unwrap();
+/// assert_eq!((color.r(), color.g(), color.b()), (0x91, 0xbd, 0x59));
+/// assert_eq!(color.to_string(), "#91bd59");
+///
+/// let color: Rgb = serde_json::from_str(r##""#91BD59""##).unwrap();
+/// assert_eq!(color, Rgb::new(0x91, 0xbd, 0x59));
+/// assert_eq!(serde_json::to_string(&color).unwrap(), "9551193");
+///
+/// assert_eq!("6a7039".parse(), Ok(Rgb(0x6a7039)));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u32);
+
+impl Rgb {
+    /// Returns the color with the given red, green, and blue components.
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self(u32::from_be_bytes([0, r, g, b]))
+    }
+
+    /// Returns the red component.
+    pub const fn r(self) -> u8 {
+        (self.0 >> 16) as u8
+    }
+
+    /// Returns the green component.
+    pub const fn g(self) -> u8 {
+        (self.0 >> 8) as u8
+    }
+
+    /// Returns the blue component.
+    pub const fn b(self) -> u8 {
+        self.0 as u8
+    }
+
+    /// Returns the red, green, and blue components.
+    pub const fn to_array(self) -> [u8; 3] {
+        [self.r(), self.g(), self.b()]
+    }
+
+    /// Returns the color as a hex string, e.g., `#91bd59`.
+    ///
+    /// This is the same as the [`Display`][fmt::Display] implementation.
+    pub fn to_hex(self) -> String {
+        self.to_string()
+    }
+
+    /// Parses a hex string with six digits, with or without a leading `#`.
+    pub fn from_hex(hex: &str) -> Result<Self, ParseRgbError> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        if digits.len() != 6 || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(ParseRgbError);
+        }
+
+        u32::from_str_radix(digits, 16)
+            .map(Self)
+            .map_err(|_| ParseRgbError)
+    }
+}
+
+impl From<u32> for Rgb {
+    fn from(packed: u32) -> Self {
+        Self(packed)
+    }
+}
+
+impl From<Rgb> for u32 {
+    fn from(color: Rgb) -> Self {
+        color.0
+    }
+}
+
+impl From<[u8; 3]> for Rgb {
+    fn from([r, g, b]: [u8; 3]) -> Self {
+        Self::new(r, g, b)
+    }
+}
+
+impl fmt::Display for Rgb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:06x}", self.0)
+    }
+}
+
+impl FromStr for Rgb {
+    type Err = ParseRgbError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_hex(s)
+    }
+}
+
+impl Serialize for Rgb {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Rgb {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Rgb;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a packed RGB integer or a hex color string")
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Rgb, E> {
+                u32::try_from(v)
+                    .map(Rgb)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Unsigned(v), &self))
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<Rgb, E> {
+                // Colors with an alpha channel may be written as negative
+                // signed integers.
+                i32::try_from(v)
+                    .map(|v| Rgb(v as u32))
+                    .map_err(|_| E::invalid_value(de::Unexpected::Signed(v), &self))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Rgb, E> {
+                Rgb::from_hex(v).map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Rgb {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "Rgb".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "A packed RGB integer or a hex color string.",
+            "anyOf": [
+                { "type": "integer", "minimum": i32::MIN, "maximum": u32::MAX },
+                { "type": "string", "pattern": "^#?[0-9a-fA-F]{6}$" }
+            ]
+        })
+    }
+}
+
+/// An error from parsing an invalid hex color string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseRgbError;
+
+impl fmt::Display for ParseRgbError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid hex color, expected 6 hex digits")
+    }
+}
+
+impl std::error::Error for ParseRgbError {}
@@ -0,0 +1,526 @@
+//! Serde-(de)serializable data types for the data components of items, as
+//! found in recipes, loot tables, advancements, and item predicates.
+//!
+//! Start here: [`DataComponents`].
+//!
+//! Data components replaced the NBT of items in 1.20.5. Only the formats used
+//! up to 1.21.4 are supported.
+//!
+//! See <https://minecraft.wiki/w/Data_component_format>.
+
+use std::collections::{btree_map, BTreeMap};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::schemas::{color::Rgb, text::TextComponent};
+
+/// A map of data components, e.g., the `components` of a recipe result.
+///
+/// The components that this crate knows are parsed into their typed
+/// [`DataComponent`] variant, and the others (or known components whose value
+/// has an unexpected format) are kept as [`DataComponent::Other`] raw JSON.
+///
+/// Components are looked up by their ID, with or without the `minecraft:`
+/// namespace. A component whose ID is prefixed with `!` removes the
+/// component from the item, and is kept as [`DataComponent::Removed`].
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::components::*;
+/// # use minecraft_assets::schemas::text::TextComponent;
+/// let components: DataComponents = serde_json::from_str(r#"
+///     {
+///         "minecraft:custom_name": { "text": "Excalibur", "italic": false },
+///         "minecraft:enchantments": { "levels": { "minecraft:sharpness": 5 } },
+///         "max_stack_size": 1,
+///         "!minecraft:food": {},
+///         "mymod:charge": 3
+///     }
+/// "#).unwrap();
+///
+/// assert!(matches!(components.get("custom_name"), Some(DataComponent::CustomName(_))));
+/// match components.get("minecraft:enchantments") {
+///     Some(DataComponent::Enchantments(enchantments)) => {
+///         assert_eq!(enchantments.levels()["minecraft:sharpness"], 5);
+///     }
+///     _ => unreachable!(),
+/// }
+/// assert_eq!(components.get("max_stack_size"), Some(&DataComponent::MaxStackSize(1)));
+/// assert_eq!(components.get("food"), Some(&DataComponent::Removed));
+/// assert_eq!(components.get("mymod:charge"), Some(&DataComponent::Other(3.into())));
+///
+/// // The components are written back as they were read.
+/// let json = serde_json::to_value(&components).unwrap();
+/// assert_eq!(json["!minecraft:food"], serde_json::json!({}));
+/// assert_eq!(json["max_stack_size"], 1);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct DataComponents {
+    components: BTreeMap<String, DataComponent>,
+}
+
+impl DataComponents {
+    /// Returns an empty map of components.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the component with the given ID, with or without the
+    /// `minecraft:` namespace.
+    pub fn get(&self, id: &str) -> Option<&DataComponent> {
+        let id = canonical_id(id);
+        self.components
+            .iter()
+            .find(|(key, _)| canonical_id(key) == id)
+            .map(|(_, component)| component)
+    }
+
+    /// Inserts a component with the given ID, replacing the component with
+    /// the same ID if any, and returns the replaced component.
+    pub fn insert(
+        &mut self,
+        id: impl Into<String>,
+        component: DataComponent,
+    ) -> Option<DataComponent> {
+        let id = id.into();
+        let replaced = self.remove(&id);
+        self.components.insert(id, component);
+        replaced
+    }
+
+    /// Removes the component with the given ID, with or without the
+    /// `minecraft:` namespace, and returns it.
+    pub fn remove(&mut self, id: &str) -> Option<DataComponent> {
+        let id = canonical_id(id);
+        let key = self
+            .components
+            .keys()
+            .find(|key| canonical_id(key) == id)?
+            .clone();
+        self.components.remove(&key)
+    }
+
+    /// Returns an iterator over the IDs of the components (as written, without
+    /// the `!` of removed components) and the components.
+    pub fn iter(&self) -> btree_map::Iter<'_, String, DataComponent> {
+        self.components.iter()
+    }
+
+    /// Returns the number of components.
+    pub fn len(&self) -> usize {
+        self.components.len()
+    }
+
+    /// Returns `true` if there are no components.
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+}
+
+impl FromIterator<(String, DataComponent)> for DataComponents {
+    fn from_iter<T: IntoIterator<Item = (String, DataComponent)>>(iter: T) -> Self {
+        let mut components = Self::new();
+        for (id, component) in iter {
+            components.insert(id, component);
+        }
+        components
+    }
+}
+
+impl<'a> IntoIterator for &'a DataComponents {
+    type Item = (&'a String, &'a DataComponent);
+    type IntoIter = btree_map::Iter<'a, String, DataComponent>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'de> Deserialize<'de> for DataComponents {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = serde_json::Map::<String, serde_json::Value>::deserialize(deserializer)?;
+        let components = raw
+            .into_iter()
+            .map(|(id, value)| match id.strip_prefix('!') {
+                Some(id) => (String::from(id), DataComponent::Removed),
+                None => {
+                    let component = DataComponent::parse(&id, value);
+                    (id, component)
+                }
+            })
+            .collect();
+
+        Ok(Self { components })
+    }
+}
+
+impl Serialize for DataComponents {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::{Error, SerializeMap};
+
+        let mut map = serializer.serialize_map(Some(self.components.len()))?;
+        for (id, component) in &self.components {
+            let value = component.to_value().map_err(S::Error::custom)?;
+            match component {
+                DataComponent::Removed => map.serialize_entry(&format!("!{}", id), &value)?,
+                _ => map.serialize_entry(id, &value)?,
+            }
+        }
+        map.end()
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for DataComponents {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "DataComponents".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "A map from data component IDs to their values. IDs prefixed with `!` remove the component.",
+            "type": "object"
+        })
+    }
+}
+
+fn canonical_id(id: &str) -> &str {
+    id.strip_prefix("minecraft:").unwrap_or(id)
+}
+
+/// A data component of a [`DataComponents`] map.
+///
+/// Each typed variant corresponds to the component whose ID is its name in
+/// snake case, e.g., [`CustomName`][Self::CustomName] for
+/// `minecraft:custom_name`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DataComponent {
+    /// The name of the item, as renamed in an anvil.
+    CustomName(TextComponent),
+
+    /// The default name of the item.
+    ItemName(TextComponent),
+
+    /// The lines of lore shown in the tooltip of the item.
+    Lore(Vec<TextComponent>),
+
+    /// The rarity of the item, which colors its name.
+    Rarity(Rarity),
+
+    /// The maximum number of items in a stack.
+    MaxStackSize(u32),
+
+    /// The durability of the item.
+    MaxDamage(u32),
+
+    /// The damage taken by the item.
+    Damage(u32),
+
+    /// Makes the item unbreakable.
+    Unbreakable(Unbreakable),
+
+    /// The enchantments of the item.
+    Enchantments(ItemEnchantments),
+
+    /// The enchantments stored in an enchanted book.
+    StoredEnchantments(ItemEnchantments),
+
+    /// Makes the item edible.
+    Food(Food),
+
+    /// The values used to select the model of the item.
+    CustomModelData(CustomModelData),
+
+    /// The number of levels added to the cost of repairing the item.
+    RepairCost(i32),
+
+    /// Overrides whether the item has the enchantment glint.
+    EnchantmentGlintOverride(bool),
+
+    /// The color of dyed leather armor.
+    DyedColor(DyedColor),
+
+    /// The ID of the item model definition of the item.
+    ///
+    /// Added in 1.21.4.
+    ItemModel(String),
+
+    /// The potion and effects of a potion or tipped arrow.
+    PotionContents(PotionContents),
+
+    /// Removes the component from the item, written as an empty object with
+    /// a `!` prefixed to the ID of the component.
+    Removed,
+
+    /// A component that this crate does not know, or whose value has an
+    /// unexpected format, kept as raw JSON.
+    Other(serde_json::Value),
+}
+
+impl DataComponent {
+    /// Parses the value of the component with the given ID, falling back to
+    /// [`Other`][Self::Other] if the component is unknown or its value has
+    /// an unexpected format.
+    pub fn parse(id: &str, value: serde_json::Value) -> Self {
+        fn typed<T, F>(value: &serde_json::Value, variant: F) -> Option<DataComponent>
+        where
+            T: serde::de::DeserializeOwned,
+            F: FnOnce(T) -> DataComponent,
+        {
+            T::deserialize(value).ok().map(variant)
+        }
+
+        let component = match canonical_id(id) {
+            "custom_name" => typed(&value, Self::CustomName),
+            "item_name" => typed(&value, Self::ItemName),
+            "lore" => typed(&value, Self::Lore),
+            "rarity" => typed(&value, Self::Rarity),
+            "max_stack_size" => typed(&value, Self::MaxStackSize),
+            "max_damage" => typed(&value, Self::MaxDamage),
+            "damage" => typed(&value, Self::Damage),
+            "unbreakable" => typed(&value, Self::Unbreakable),
+            "enchantments" => typed(&value, Self::Enchantments),
+            "stored_enchantments" => typed(&value, Self::StoredEnchantments),
+            "food" => typed(&value, Self::Food),
+            "custom_model_data" => typed(&value, Self::CustomModelData),
+            "repair_cost" => typed(&value, Self::RepairCost),
+            "enchantment_glint_override" => typed(&value, Self::EnchantmentGlintOverride),
+            "dyed_color" => typed(&value, Self::DyedColor),
+            "item_model" => typed(&value, Self::ItemModel),
+            "potion_contents" => typed(&value, Self::PotionContents),
+            _ => None,
+        };
+
+        component.unwrap_or(Self::Other(value))
+    }
+
+    /// Returns the JSON value of the component.
+    pub fn to_value(&self) -> serde_json::Result<serde_json::Value> {
+        use serde_json::to_value;
+
+        match self {
+            Self::CustomName(value) | Self::ItemName(value) => to_value(value),
+            Self::Lore(value) => to_value(value),
+            Self::Rarity(value) => to_value(value),
+            Self::MaxStackSize(value) | Self::MaxDamage(value) | Self::Damage(value) => {
+                to_value(value)
+            }
+            Self::Unbreakable(value) => to_value(value),
+            Self::Enchantments(value) | Self::StoredEnchantments(value) => to_value(value),
+            Self::Food(value) => to_value(value),
+            Self::CustomModelData(value) => to_value(value),
+            Self::RepairCost(value) => to_value(value),
+            Self::EnchantmentGlintOverride(value) => to_value(value),
+            Self::DyedColor(value) => to_value(value),
+            Self::ItemModel(value) => to_value(value),
+            Self::PotionContents(value) => to_value(value),
+            Self::Removed => Ok(serde_json::Value::Object(Default::default())),
+            Self::Other(value) => Ok(value.clone()),
+        }
+    }
+}
+
+/// The value of the `minecraft:rarity` component.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum Rarity {
+    /// White name.
+    Common,
+
+    /// Yellow name.
+    Uncommon,
+
+    /// Aqua name.
+    Rare,
+
+    /// Light purple name.
+    Epic,
+}
+
+/// The value of the `minecraft:unbreakable` component.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Unbreakable {
+    /// Whether the tooltip shows that the item is unbreakable. Defaults to
+    /// `true`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub show_in_tooltip: Option<bool>,
+}
+
+/// The value of the `minecraft:enchantments` and
+/// `minecraft:stored_enchantments` components.
+///
+/// This is either a map of enchantment IDs to levels, or an object with such
+/// a map and other fields.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum ItemEnchantments {
+    /// An object with the levels of the enchantments.
+    Full {
+        /// The level of each enchantment, by ID.
+        levels: BTreeMap<String, u32>,
+
+        /// Whether the enchantments are shown in the tooltip. Defaults to
+        /// `true`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        show_in_tooltip: Option<bool>,
+    },
+
+    /// The level of each enchantment, by ID.
+    Levels(BTreeMap<String, u32>),
+}
+
+impl ItemEnchantments {
+    /// Returns the level of each enchantment, by ID.
+    pub fn levels(&self) -> &BTreeMap<String, u32> {
+        match self {
+            Self::Full { levels, .. } | Self::Levels(levels) => levels,
+        }
+    }
+}
+
+/// The value of the `minecraft:food` component.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Food {
+    /// The number of food points restored.
+    pub nutrition: i32,
+
+    /// The amount of saturation restored.
+    pub saturation: f32,
+
+    /// Whether the item can be eaten when the hunger bar is full. Defaults to
+    /// `false`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub can_always_eat: Option<bool>,
+
+    /// The number of seconds it takes to eat the item.
+    ///
+    /// Moved to the `minecraft:consumable` component in 1.21.2.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eat_seconds: Option<f32>,
+
+    /// The item left after eating the item.
+    ///
+    /// Moved to the `minecraft:use_remainder` component in 1.21.2.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub using_converts_to: Option<serde_json::Value>,
+
+    /// The effects applied when eating the item, kept as raw JSON.
+    ///
+    /// Moved to the `minecraft:consumable` component in 1.21.2.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effects: Option<Vec<serde_json::Value>>,
+}
+
+/// The value of the `minecraft:custom_model_data` component.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum CustomModelData {
+    /// A single integer, used by the `custom_model_data` predicate of item
+    /// model overrides.
+    ///
+    /// Replaced by [`Lists`][Self::Lists] in 1.21.4.
+    Legacy(i32),
+
+    /// Lists of values, used by the item model definitions.
+    ///
+    /// Added in 1.21.4.
+    Lists {
+        /// The values used by `range_dispatch` models.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        floats: Vec<f32>,
+
+        /// The values used by `condition` models.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        flags: Vec<bool>,
+
+        /// The values used by `select` models.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        strings: Vec<String>,
+
+        /// The values used by `custom_model_data` tints, as RGB integers or
+        /// lists of floats.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        colors: Vec<serde_json::Value>,
+    },
+}
+
+/// The value of the `minecraft:dyed_color` component.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum DyedColor {
+    /// The color.
+    Rgb(Rgb),
+
+    /// An object with the color.
+    Full {
+        /// The color.
+        rgb: Rgb,
+
+        /// Whether the color is shown in the tooltip. Defaults to `true`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        show_in_tooltip: Option<bool>,
+    },
+}
+
+impl DyedColor {
+    /// Returns the color.
+    pub fn rgb(&self) -> Rgb {
+        match self {
+            Self::Rgb(rgb) | Self::Full { rgb, .. } => *rgb,
+        }
+    }
+}
+
+/// The value of the `minecraft:potion_contents` component.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum PotionContents {
+    /// The ID of a potion.
+    Potion(String),
+
+    /// An object with a potion, custom effects, or both.
+    Full {
+        /// The ID of the potion.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        potion: Option<String>,
+
+        /// The color of the potion, overriding the color of its effects.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        custom_color: Option<Rgb>,
+
+        /// Additional effects, kept as raw JSON.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        custom_effects: Vec<serde_json::Value>,
+
+        /// The name of the potion, used for the translation key of its name
+        /// instead of the ID of the potion.
+        ///
+        /// Added in 1.21.2.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        custom_name: Option<String>,
+    },
+}
+
+impl PotionContents {
+    /// Returns the ID of the potion, if any.
+    pub fn potion(&self) -> Option<&str> {
+        match self {
+            Self::Potion(potion) => Some(potion),
+            Self::Full { potion, .. } => potion.as_deref(),
+        }
+    }
+}
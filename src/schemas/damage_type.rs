@@ -0,0 +1,125 @@
+//! Serde-(de)serializable data types for
+//! `data/<namespace>/damage_type/*.json`.
+//!
+//! Start here: [`DamageType`].
+//!
+//! Damage types were added in 1.19.4.
+//!
+//! See <https://minecraft.fandom.com/wiki/Damage_type>.
+
+use serde::{Deserialize, Serialize};
+
+/// A damage type as stored in the `data/<namespace>/damage_type/` directory.
+///
+/// See also the corresponding section of the [wiki page].
+///
+/// [wiki page]: <https://minecraft.fandom.com/wiki/Damage_type#JSON_format>
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::damage_type::*;
+/// let damage_type: DamageType = serde_json::from_str(r#"
+///     {
+///         "message_id": "inFire",
+///         "scaling": "when_caused_by_living_non_player",
+///         "exhaustion": 0.1,
+///         "effects": "burning"
+///     }
+/// "#).unwrap();
+///
+/// assert_eq!(damage_type.message_id, "inFire");
+/// assert_eq!(damage_type.scaling, DamageScaling::WhenCausedByLivingNonPlayer);
+/// assert_eq!(damage_type.effects, DamageEffects::Burning);
+/// assert_eq!(damage_type.death_message_type, DeathMessageType::Default);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DamageType {
+    /// The ID of the death message, used to build the translation key
+    /// `death.attack.<message_id>`.
+    pub message_id: String,
+
+    /// Whether the damage scales with the difficulty.
+    pub scaling: DamageScaling,
+
+    /// The amount of hunger exhaustion caused by this damage type.
+    pub exhaustion: f32,
+
+    /// The effects (sound) played when the damage is taken. Defaults to
+    /// [`DamageEffects::Hurt`].
+    #[serde(default)]
+    pub effects: DamageEffects,
+
+    /// How the death message is built. Defaults to
+    /// [`DeathMessageType::Default`].
+    #[serde(default)]
+    pub death_message_type: DeathMessageType,
+
+    /// The fields that this crate does not model (e.g., fields added by mods
+    /// or by newer versions), kept so that they survive a round trip.
+    ///
+    /// Requires the `preserve-unknown` feature.
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten, serialize_with = "crate::schemas::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Whether a [`DamageType`] scales with the difficulty.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum DamageScaling {
+    /// The damage is never scaled.
+    Never,
+
+    /// The damage is scaled if it was caused by a living entity that is not a
+    /// player.
+    WhenCausedByLivingNonPlayer,
+
+    /// The damage is always scaled.
+    Always,
+}
+
+/// The effects played when a [`DamageType`] is taken.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum DamageEffects {
+    /// The default hurt sound.
+    #[default]
+    Hurt,
+
+    /// The thorns hurt sound.
+    Thorns,
+
+    /// The drowning sound.
+    Drowning,
+
+    /// A single tick of the burning sound.
+    Burning,
+
+    /// The sweet berry bush poking sound.
+    Poking,
+
+    /// The freezing tick sound.
+    Freezing,
+}
+
+/// How the death message of a [`DamageType`] is built.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum DeathMessageType {
+    /// The message is built from the [`message_id`][DamageType::message_id].
+    #[default]
+    Default,
+
+    /// The message is built from the fall variants (e.g., fell off a ladder),
+    /// if the player fell before dying.
+    FallVariants,
+
+    /// The message `death.attack.<message_id>.message`, with a link to
+    /// [MCPE-28723](https://bugs.mojang.com/browse/MCPE-28723).
+    IntentionalGameDesign,
+}
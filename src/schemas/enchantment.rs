@@ -0,0 +1,543 @@
+//! Serde-(de)serializable data types for
+//! `data/<namespace>/enchantment/*.json`.
+//!
+//! Start here: [`Enchantment`].
+//!
+//! Enchantments became data-driven in 1.21.
+//!
+//! See <https://minecraft.fandom.com/wiki/Enchantment_definition>.
+
+use serde::{Deserialize, Serialize};
+
+use crate::schemas::{predicate::Predicate, reference::IdList, text::TextComponent};
+
+/// An enchantment as stored in the `data/<namespace>/enchantment/` directory.
+///
+/// See also the corresponding section of the [wiki page].
+///
+/// [wiki page]: <https://minecraft.fandom.com/wiki/Enchantment_definition#JSON_format>
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::enchantment::*;
+/// let enchantment: Enchantment = serde_json::from_str(r##"
+///     {
+///         "description": { "translate": "enchantment.minecraft.sharpness" },
+///         "exclusive_set": "#minecraft:exclusive_set/damage",
+///         "supported_items": "#minecraft:enchantable/sharp_weapon",
+///         "primary_items": "#minecraft:enchantable/sword",
+///         "weight": 10,
+///         "max_level": 5,
+///         "min_cost": { "base": 1, "per_level_above_first": 11 },
+///         "max_cost": { "base": 21, "per_level_above_first": 11 },
+///         "anvil_cost": 1,
+///         "slots": ["mainhand"],
+///         "effects": {
+///             "minecraft:damage": [
+///                 {
+///                     "effect": {
+///                         "type": "minecraft:add",
+///                         "value": {
+///                             "type": "minecraft:linear",
+///                             "base": 1.0,
+///                             "per_level_above_first": 0.5
+///                         }
+///                     }
+///                 }
+///             ]
+///         }
+///     }
+/// "##).unwrap();
+///
+/// assert_eq!(enchantment.max_level, 5);
+/// assert_eq!(enchantment.min_cost.at_level(2), 12);
+/// assert_eq!(enchantment.slots, vec![EquipmentSlotGroup::MainHand]);
+///
+/// let damage = &enchantment.effects.damage[0];
+/// assert!(damage.requirements.is_none());
+/// assert_eq!(damage.effect.apply(3, 2.0), 4.0);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Enchantment {
+    /// The name of the enchantment, as a JSON text component.
+    pub description: TextComponent,
+
+    /// The enchantment(s) (or `#`-prefixed enchantment tag) that are
+    /// incompatible with this enchantment.
+    pub exclusive_set: Option<IdList>,
+
+    /// The item(s) (or `#`-prefixed item tag) that this enchantment can be
+    /// applied to, using an anvil or commands.
+    pub supported_items: IdList,
+
+    /// The item(s) (or `#`-prefixed item tag) that this enchantment can be
+    /// applied to in an enchanting table. Defaults to
+    /// [`supported_items`][Self::supported_items].
+    pub primary_items: Option<IdList>,
+
+    /// The weight of the enchantment when choosing a random enchantment.
+    pub weight: u32,
+
+    /// The maximum level of the enchantment.
+    pub max_level: u32,
+
+    /// The minimum cost, in enchanting levels, of the enchantment.
+    pub min_cost: EnchantmentCost,
+
+    /// The maximum cost, in enchanting levels, of the enchantment.
+    pub max_cost: EnchantmentCost,
+
+    /// The base cost, in levels, of applying the enchantment in an anvil.
+    pub anvil_cost: u32,
+
+    /// The equipment slots in which the enchanted item has to be for the
+    /// effects to apply.
+    pub slots: Vec<EquipmentSlotGroup>,
+
+    /// The effect components of the enchantment.
+    #[serde(default)]
+    pub effects: EnchantmentEffects,
+
+    /// The fields that this crate does not model (e.g., fields added by mods
+    /// or by newer versions), kept so that they survive a round trip.
+    ///
+    /// Requires the `preserve-unknown` feature.
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten, serialize_with = "crate::schemas::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// A cost of an [`Enchantment`] that scales linearly with its level.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct EnchantmentCost {
+    /// The cost at level 1.
+    pub base: i32,
+
+    /// The amount added to the cost for every level above 1.
+    pub per_level_above_first: i32,
+}
+
+impl EnchantmentCost {
+    /// Returns the cost at the given (1-based) level.
+    pub fn at_level(&self, level: u32) -> i32 {
+        self.base + self.per_level_above_first * (level as i32 - 1)
+    }
+}
+
+/// A group of equipment slots in which an enchanted item applies its effects.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
+#[allow(missing_docs)]
+pub enum EquipmentSlotGroup {
+    Any,
+    Hand,
+    MainHand,
+    OffHand,
+    Armor,
+    Feet,
+    Legs,
+    Chest,
+    Head,
+    Body,
+
+    /// Added in 1.21.5.
+    Saddle,
+}
+
+/// The effect components of an [`Enchantment`].
+///
+/// *only the components holding value effects are typed; the remaining
+/// components are kept as raw JSON*
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct EnchantmentEffects {
+    /// Modifies the amount of ammunition used by a ranged weapon.
+    #[serde(
+        rename = "minecraft:ammo_use",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub ammo_use: Vec<ConditionalEffect<ValueEffect>>,
+
+    /// Modifies the effectiveness of the armor of the attacked entity.
+    #[serde(
+        rename = "minecraft:armor_effectiveness",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub armor_effectiveness: Vec<ConditionalEffect<ValueEffect>>,
+
+    /// Modifies the experience dropped when breaking a block.
+    #[serde(
+        rename = "minecraft:block_experience",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub block_experience: Vec<ConditionalEffect<ValueEffect>>,
+
+    /// Modifies the time it takes to charge a crossbow, in seconds.
+    #[serde(
+        rename = "minecraft:crossbow_charge_time",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub crossbow_charge_time: Option<ValueEffect>,
+
+    /// Modifies the damage dealt in an attack.
+    #[serde(
+        rename = "minecraft:damage",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub damage: Vec<ConditionalEffect<ValueEffect>>,
+
+    /// Modifies the damage protection against an attack.
+    #[serde(
+        rename = "minecraft:damage_protection",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub damage_protection: Vec<ConditionalEffect<ValueEffect>>,
+
+    /// Modifies the luck bonus when fishing.
+    #[serde(
+        rename = "minecraft:fishing_luck_bonus",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub fishing_luck_bonus: Vec<ConditionalEffect<ValueEffect>>,
+
+    /// Modifies the time it takes to catch something when fishing, in
+    /// seconds.
+    #[serde(
+        rename = "minecraft:fishing_time_reduction",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub fishing_time_reduction: Vec<ConditionalEffect<ValueEffect>>,
+
+    /// Modifies the durability damage taken by the item.
+    #[serde(
+        rename = "minecraft:item_damage",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub item_damage: Vec<ConditionalEffect<ValueEffect>>,
+
+    /// Modifies the knockback dealt in an attack.
+    #[serde(
+        rename = "minecraft:knockback",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub knockback: Vec<ConditionalEffect<ValueEffect>>,
+
+    /// Modifies the experience dropped when killing a mob.
+    #[serde(
+        rename = "minecraft:mob_experience",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub mob_experience: Vec<ConditionalEffect<ValueEffect>>,
+
+    /// Modifies the number of projectiles shot by a ranged weapon.
+    #[serde(
+        rename = "minecraft:projectile_count",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub projectile_count: Vec<ConditionalEffect<ValueEffect>>,
+
+    /// Modifies the number of entities a projectile can pierce.
+    #[serde(
+        rename = "minecraft:projectile_piercing",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub projectile_piercing: Vec<ConditionalEffect<ValueEffect>>,
+
+    /// Modifies the spread of projectiles, in degrees.
+    #[serde(
+        rename = "minecraft:projectile_spread",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub projectile_spread: Vec<ConditionalEffect<ValueEffect>>,
+
+    /// Modifies the durability repaired per experience orb.
+    #[serde(
+        rename = "minecraft:repair_with_xp",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub repair_with_xp: Vec<ConditionalEffect<ValueEffect>>,
+
+    /// Modifies the extra damage per block fallen dealt by a mace.
+    #[serde(
+        rename = "minecraft:smash_damage_per_fallen_block",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub smash_damage_per_fallen_block: Vec<ConditionalEffect<ValueEffect>>,
+
+    /// Modifies the return speed of a thrown trident.
+    #[serde(
+        rename = "minecraft:trident_return_acceleration",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub trident_return_acceleration: Vec<ConditionalEffect<ValueEffect>>,
+
+    /// Modifies the strength of the riptide spin attack of a trident.
+    #[serde(
+        rename = "minecraft:trident_spin_attack_strength",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub trident_spin_attack_strength: Option<ValueEffect>,
+
+    /// The remaining effect components (e.g., `minecraft:post_attack`,
+    /// `minecraft:tick`, or `minecraft:attributes`), kept as raw JSON.
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+/// An effect that only applies if its requirements pass.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ConditionalEffect<T> {
+    /// The effect to apply.
+    pub effect: T,
+
+    /// The conditions that have to pass for the effect to apply.
+    pub requirements: Option<Predicate>,
+}
+
+/// An effect that modifies a numeric value.
+///
+/// The effect's `type` field determines which variant is used. Both the
+/// namespaced (`"minecraft:add"`) and bare (`"add"`) forms are accepted.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(tag = "type")]
+pub enum ValueEffect {
+    /// Adds a value.
+    #[serde(rename = "minecraft:add", alias = "add")]
+    Add {
+        /// The value to add.
+        value: LevelBasedValue,
+    },
+
+    /// Applies multiple effects in order.
+    #[serde(rename = "minecraft:all_of", alias = "all_of")]
+    AllOf {
+        /// The effects to apply.
+        effects: Vec<ValueEffect>,
+    },
+
+    /// Multiplies by a factor.
+    #[serde(rename = "minecraft:multiply", alias = "multiply")]
+    Multiply {
+        /// The factor to multiply by.
+        factor: LevelBasedValue,
+    },
+
+    /// Subtracts a random value following a binomial distribution, where the
+    /// number of trials is the original value.
+    #[serde(rename = "minecraft:remove_binomial", alias = "remove_binomial")]
+    RemoveBinomial {
+        /// The probability of success on an individual trial.
+        chance: LevelBasedValue,
+    },
+
+    /// Replaces the value.
+    #[serde(rename = "minecraft:set", alias = "set")]
+    Set {
+        /// The new value.
+        value: LevelBasedValue,
+    },
+}
+
+impl Default for ValueEffect {
+    fn default() -> Self {
+        Self::Add {
+            value: Default::default(),
+        }
+    }
+}
+
+impl ValueEffect {
+    /// Applies the effect to the given value at the given enchantment level.
+    ///
+    /// Returns `None` for [`RemoveBinomial`][Self::RemoveBinomial], which
+    /// requires a random number generator, and for any effect containing a
+    /// level-based value that cannot be computed by [`LevelBasedValue::at_level`].
+    pub fn try_apply(&self, level: u32, value: f32) -> Option<f32> {
+        Some(match self {
+            Self::Add { value: add } => value + add.at_level(level)?,
+            Self::AllOf { effects } => {
+                let mut value = value;
+                for effect in effects {
+                    value = effect.try_apply(level, value)?;
+                }
+                value
+            }
+            Self::Multiply { factor } => value * factor.at_level(level)?,
+            Self::RemoveBinomial { .. } => return None,
+            Self::Set { value: set } => set.at_level(level)?,
+        })
+    }
+
+    /// Applies the effect to the given value at the given enchantment level.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the effect cannot be computed deterministically (see
+    /// [`try_apply`][Self::try_apply]).
+    pub fn apply(&self, level: u32, value: f32) -> f32 {
+        self.try_apply(level, value)
+            .expect("value effect cannot be computed deterministically")
+    }
+}
+
+/// A value that is computed from an enchantment level.
+///
+/// A bare number is shorthand for a constant value.
+///
+/// See <https://minecraft.fandom.com/wiki/Enchantment_definition#Level-based_value>.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum LevelBasedValue {
+    /// A constant value.
+    Constant(f32),
+
+    /// A value with an explicit `type` field.
+    Typed(Box<TypedLevelBasedValue>),
+}
+
+impl Default for LevelBasedValue {
+    fn default() -> Self {
+        Self::Constant(0.0)
+    }
+}
+
+impl From<f32> for LevelBasedValue {
+    fn from(source: f32) -> Self {
+        Self::Constant(source)
+    }
+}
+
+impl LevelBasedValue {
+    /// Computes the value at the given (1-based) enchantment level.
+    ///
+    /// Returns `None` if a [`Lookup`][TypedLevelBasedValue::Lookup] has no
+    /// entry for the level and no fallback.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::enchantment::*;
+    /// let value: LevelBasedValue = serde_json::from_str(r#"
+    ///     { "type": "minecraft:levels_squared", "added": 1.0 }
+    /// "#).unwrap();
+    /// assert_eq!(value.at_level(3), Some(10.0));
+    /// ```
+    pub fn at_level(&self, level: u32) -> Option<f32> {
+        match self {
+            Self::Constant(value) => Some(*value),
+            Self::Typed(typed) => typed.at_level(level),
+        }
+    }
+}
+
+/// A [`LevelBasedValue`] with an explicit `type` field.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(tag = "type")]
+pub enum TypedLevelBasedValue {
+    /// A value clamped between a minimum and a maximum.
+    #[serde(rename = "minecraft:clamped", alias = "clamped")]
+    Clamped {
+        /// The value to clamp.
+        value: LevelBasedValue,
+
+        /// The minimum value.
+        min: f32,
+
+        /// The maximum value.
+        max: f32,
+    },
+
+    /// The result of dividing two values.
+    #[serde(rename = "minecraft:fraction", alias = "fraction")]
+    Fraction {
+        /// The numerator.
+        numerator: LevelBasedValue,
+
+        /// The denominator.
+        denominator: LevelBasedValue,
+    },
+
+    /// The square of the level, plus a constant.
+    #[serde(rename = "minecraft:levels_squared", alias = "levels_squared")]
+    LevelsSquared {
+        /// The constant to add.
+        added: f32,
+    },
+
+    /// A value that increases linearly with the level.
+    #[serde(rename = "minecraft:linear", alias = "linear")]
+    Linear {
+        /// The value at level 1.
+        base: f32,
+
+        /// The amount added for every level above 1.
+        per_level_above_first: f32,
+    },
+
+    /// A value looked up by level from a list.
+    #[serde(rename = "minecraft:lookup", alias = "lookup")]
+    Lookup {
+        /// The values, indexed by level starting at level 1.
+        values: Vec<LevelBasedValue>,
+
+        /// The value used for levels beyond the end of the list.
+        fallback: Option<LevelBasedValue>,
+    },
+}
+
+impl TypedLevelBasedValue {
+    /// Computes the value at the given (1-based) enchantment level.
+    ///
+    /// See [`LevelBasedValue::at_level`].
+    pub fn at_level(&self, level: u32) -> Option<f32> {
+        match self {
+            Self::Clamped { value, min, max } => Some(value.at_level(level)?.clamp(*min, *max)),
+            Self::Fraction {
+                numerator,
+                denominator,
+            } => {
+                let denominator = denominator.at_level(level)?;
+                if denominator == 0.0 {
+                    Some(0.0)
+                } else {
+                    Some(numerator.at_level(level)? / denominator)
+                }
+            }
+            Self::LevelsSquared { added } => Some((level * level) as f32 + added),
+            Self::Linear {
+                base,
+                per_level_above_first,
+            } => Some(base + per_level_above_first * (level as f32 - 1.0)),
+            Self::Lookup { values, fallback } => {
+                match level.checked_sub(1).and_then(|i| values.get(i as usize)) {
+                    Some(value) => value.at_level(level),
+                    None => fallback.as_ref()?.at_level(level),
+                }
+            }
+        }
+    }
+}
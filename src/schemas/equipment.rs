@@ -0,0 +1,161 @@
+//! Serde-(de)serializable data types for `assets/<namespace>/equipment/*.json`.
+//!
+//! Start here: [`Equipment`].
+//!
+//! See <https://minecraft.fandom.com/wiki/Equipment>.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::ResourceLocation;
+
+/// An equipment asset as stored in the `assets/<namespace>/equipment/`
+/// directory, e.g., `diamond.json`.
+///
+/// An equipment asset describes the textures rendered on an entity wearing a
+/// piece of equipment (armor, elytra, horse armor, saddles, ...), grouped by
+/// the kind of layer they are rendered on.
+///
+/// Added in 1.21.2 (in `models/equipment/` until 1.21.4).
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::equipment::*;
+/// let equipment: Equipment = serde_json::from_str(r#"
+///     {
+///         "layers": {
+///             "humanoid": [
+///                 { "texture": "minecraft:leather", "dyeable": { "color_when_undyed": -6265536 } },
+///                 { "texture": "minecraft:leather_overlay" }
+///             ],
+///             "wings": [
+///                 { "texture": "minecraft:elytra", "use_player_texture": true }
+///             ]
+///         }
+///     }
+/// "#).unwrap();
+///
+/// let humanoid = &equipment.layers[&EquipmentLayerType::Humanoid];
+/// assert_eq!(humanoid.len(), 2);
+/// assert_eq!(humanoid[0].dyeable.unwrap().color_when_undyed, Some(-6265536));
+/// assert!(humanoid[1].dyeable.is_none());
+///
+/// assert!(equipment.layers[&EquipmentLayerType::Wings][0].use_player_texture);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Equipment {
+    /// Map of layer types to the layers rendered on them, from bottom to top.
+    #[serde(default)]
+    pub layers: HashMap<EquipmentLayerType, Vec<EquipmentLayer>>,
+
+    /// The fields that this crate does not model (e.g., fields added by mods
+    /// or by newer versions), kept so that they survive a round trip.
+    ///
+    /// Requires the `preserve-unknown` feature.
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten, serialize_with = "crate::schemas::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// The kind of layer an [`EquipmentLayer`] is rendered on.
+///
+/// Each layer type reads its textures from
+/// `textures/entity/equipment/<layer_type>/`.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum EquipmentLayerType {
+    /// The body of a humanoid (helmet, chestplate, and boots).
+    Humanoid,
+
+    /// The legs of a humanoid (leggings).
+    HumanoidLeggings,
+
+    /// Elytra wings.
+    Wings,
+
+    /// Wolf armor.
+    WolfBody,
+
+    /// Horse armor.
+    HorseBody,
+
+    /// Llama carpets.
+    LlamaBody,
+
+    /// Pig saddles.
+    ///
+    /// Added in 1.21.5.
+    PigSaddle,
+
+    /// Strider saddles.
+    ///
+    /// Added in 1.21.5.
+    StriderSaddle,
+
+    /// Camel saddles.
+    ///
+    /// Added in 1.21.5.
+    CamelSaddle,
+
+    /// Horse saddles.
+    ///
+    /// Added in 1.21.5.
+    HorseSaddle,
+
+    /// Donkey saddles.
+    ///
+    /// Added in 1.21.5.
+    DonkeySaddle,
+
+    /// Mule saddles.
+    ///
+    /// Added in 1.21.5.
+    MuleSaddle,
+
+    /// Skeleton horse saddles.
+    ///
+    /// Added in 1.21.5.
+    SkeletonHorseSaddle,
+
+    /// Zombie horse saddles.
+    ///
+    /// Added in 1.21.5.
+    ZombieHorseSaddle,
+
+    /// Happy ghast harnesses.
+    ///
+    /// Added in 1.21.6.
+    HappyGhastBody,
+}
+
+/// A single textured layer of an [`Equipment`] asset.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct EquipmentLayer {
+    /// The resource location of the texture, relative to
+    /// `textures/entity/equipment/<layer_type>/` and without the `.png`
+    /// extension.
+    pub texture: ResourceLocation<'static>,
+
+    /// If present, the layer is tinted with the dyed color of the item.
+    pub dyeable: Option<Dyeable>,
+
+    /// Whether the texture is replaced by the player's texture (e.g., their
+    /// cape for elytra), if they have one. Defaults to `false`.
+    #[serde(default)]
+    pub use_player_texture: bool,
+}
+
+/// The dye settings of an [`EquipmentLayer`].
+#[derive(Deserialize, Serialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Dyeable {
+    /// The color (as a signed ARGB integer) the layer is tinted with if the
+    /// item is not dyed. If absent, the layer is not rendered for undyed
+    /// items.
+    pub color_when_undyed: Option<i32>,
+}
@@ -0,0 +1,239 @@
+//! Serde-(de)serializable data types for `assets/<namespace>/font/*.json`.
+//!
+//! Start here: [`Font`].
+//!
+//! See <https://minecraft.fandom.com/wiki/Resource_pack#Fonts>.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::ResourceLocation;
+
+/// A font definition as stored in the `assets/<namespace>/font/` directory,
+/// e.g., `default.json`.
+///
+/// A font is made up of a list of glyph providers. When rendering a
+/// character, the providers are searched in order and the first one that
+/// provides the character is used.
+///
+/// See also the corresponding section of the [wiki page].
+///
+/// [wiki page]: <https://minecraft.fandom.com/wiki/Font#Providers>
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::font::*;
+/// let font: Font = serde_json::from_str(r##"
+///     {
+///         "providers": [
+///             { "type": "space", "advances": { " ": 4, "\u200c": 0 } },
+///             {
+///                 "type": "bitmap",
+///                 "file": "minecraft:font/ascii.png",
+///                 "ascent": 7,
+///                 "chars": ["\u0000 !\"#$%&'()*+,-./", "0123456789:;<=>?"]
+///             },
+///             { "type": "reference", "id": "minecraft:include/unifont" }
+///         ]
+///     }
+/// "##).unwrap();
+///
+/// assert_eq!(font.providers.len(), 3);
+///
+/// match &font.providers[0] {
+///     FontProvider::Space { advances } => assert_eq!(advances[&' '], 4.0),
+///     _ => unreachable!(),
+/// }
+///
+/// match &font.providers[1] {
+///     FontProvider::Bitmap { height, ascent, chars, .. } => {
+///         assert_eq!(*height, 8);
+///         assert_eq!(*ascent, 7);
+///         assert_eq!(chars[1].chars().next(), Some('0'));
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Font {
+    /// The glyph providers of the font, in order of priority.
+    pub providers: Vec<FontProvider>,
+
+    /// The fields that this crate does not model (e.g., fields added by mods
+    /// or by newer versions), kept so that they survive a round trip.
+    ///
+    /// Requires the `preserve-unknown` feature.
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten, serialize_with = "crate::schemas::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// A glyph provider of a [`Font`].
+///
+/// The provider's `type` field determines which variant is used.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(tag = "type")]
+pub enum FontProvider {
+    /// Provides glyphs from a bitmap texture, arranged in a grid.
+    #[serde(rename = "bitmap", alias = "minecraft:bitmap")]
+    Bitmap {
+        /// The resource location of the texture, relative to `textures/`
+        /// and including the `.png` extension.
+        file: ResourceLocation<'static>,
+
+        /// The height to render the glyphs at. Defaults to `8`.
+        #[serde(default = "FontProvider::default_height")]
+        height: i32,
+
+        /// The vertical shift of the glyphs, which must not be greater than
+        /// [`height`][FontProvider::Bitmap::height].
+        ascent: i32,
+
+        /// The rows of characters in the texture grid. Every row must have
+        /// the same number of characters; `\u0000` marks an unused cell.
+        chars: Vec<String>,
+    },
+
+    /// Provides invisible glyphs with a given width.
+    ///
+    /// Added in 1.19.
+    #[serde(rename = "space", alias = "minecraft:space")]
+    Space {
+        /// Map of characters to their advance (width), in pixels.
+        advances: HashMap<char, f32>,
+    },
+
+    /// Provides glyphs from a TrueType or OpenType font file.
+    #[serde(rename = "ttf", alias = "minecraft:ttf")]
+    Ttf {
+        /// The resource location of the font file, relative to `font/` and
+        /// including the extension.
+        file: ResourceLocation<'static>,
+
+        /// The horizontal and vertical shift of the glyphs. Defaults to
+        /// `[0.0, 0.0]`.
+        #[serde(default)]
+        shift: [f32; 2],
+
+        /// The font size to render at. Defaults to `11.0`.
+        #[serde(default = "FontProvider::default_ttf_size")]
+        size: f32,
+
+        /// The resolution multiplier to render at. Defaults to `1.0`.
+        #[serde(default = "FontProvider::default_oversample")]
+        oversample: f32,
+
+        /// Characters that this provider does not provide.
+        #[serde(default)]
+        skip: TtfSkip,
+    },
+
+    /// Provides glyphs from a zipped set of `.hex` files in the [GNU Unifont]
+    /// format.
+    ///
+    /// Added in 1.20.
+    ///
+    /// [GNU Unifont]: <https://unifoundry.com/unifont/index.html>
+    #[serde(rename = "unihex", alias = "minecraft:unihex")]
+    Unihex {
+        /// The resource location of the `.zip` archive containing the `.hex`
+        /// files, relative to `assets/<namespace>/`.
+        hex_file: ResourceLocation<'static>,
+
+        /// Ranges of characters with overridden glyph widths.
+        #[serde(default)]
+        size_overrides: Vec<UnihexSizeOverride>,
+    },
+
+    /// Provides glyphs from the legacy unicode pages
+    /// (`textures/font/unicode_page_XX.png`).
+    ///
+    /// Removed in 1.20.
+    #[serde(rename = "legacy_unicode", alias = "minecraft:legacy_unicode")]
+    LegacyUnicode {
+        /// The resource location of the glyph sizes file (e.g.,
+        /// `minecraft:font/glyph_sizes.bin`).
+        sizes: String,
+
+        /// The resource location of the page textures, with `%s` in place of
+        /// the page number (e.g., `minecraft:font/unicode_page_%s.png`).
+        template: String,
+    },
+
+    /// Includes the providers of another font.
+    ///
+    /// Added in 1.20.
+    #[serde(rename = "reference", alias = "minecraft:reference")]
+    Reference {
+        /// The namespaced ID of the font to include.
+        id: ResourceLocation<'static>,
+    },
+}
+
+impl FontProvider {
+    pub(crate) const fn default_height() -> i32 {
+        8
+    }
+
+    pub(crate) const fn default_ttf_size() -> f32 {
+        11.0
+    }
+
+    pub(crate) const fn default_oversample() -> f32 {
+        1.0
+    }
+}
+
+/// The characters skipped by a [`FontProvider::Ttf`].
+///
+/// This is either a single string of characters, or a list of strings.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum TtfSkip {
+    /// A single string of characters.
+    String(String),
+
+    /// A list of strings of characters.
+    List(Vec<String>),
+}
+
+impl TtfSkip {
+    /// Returns an iterator over all of the skipped characters.
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        let strings: &[String] = match self {
+            Self::String(s) => std::slice::from_ref(s),
+            Self::List(list) => &list[..],
+        };
+
+        strings.iter().flat_map(|s| s.chars())
+    }
+}
+
+impl Default for TtfSkip {
+    fn default() -> Self {
+        Self::String(String::new())
+    }
+}
+
+/// A range of characters with an overridden glyph width in a
+/// [`FontProvider::Unihex`].
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct UnihexSizeOverride {
+    /// The first character of the range (inclusive).
+    pub from: char,
+
+    /// The last character of the range (inclusive).
+    pub to: char,
+
+    /// The column of the leftmost pixel of the glyphs.
+    pub left: i32,
+
+    /// The column of the rightmost pixel of the glyphs.
+    pub right: i32,
+}
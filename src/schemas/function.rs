@@ -0,0 +1,144 @@
+//! Data types for `data/<namespace>/functions/**/*.mcfunction`.
+//!
+//! Start here: [`Function`].
+//!
+//! Function files are plain text rather than JSON, so [`Function`] is parsed
+//! with [`Function::parse`] (or [`str::parse`]) instead of Serde.
+//!
+//! See <https://minecraft.fandom.com/wiki/Function_(Java_Edition)>.
+
+use std::{convert::Infallible, str::FromStr};
+
+/// A function as stored in the `data/<namespace>/functions/` directory.
+///
+/// A function is a list of commands, one per line. Empty lines and comment
+/// lines (starting with `#`) are ignored, a line ending with `\` is continued
+/// on the next line, and a line starting with `$` is a macro line whose
+/// `$(name)` placeholders are substituted when the function is run with
+/// arguments.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::function::*;
+/// let function = Function::parse(r#"
+/// ## Give the player a reward.
+/// say Hello!
+/// give @s minecraft:diamond 1
+///
+/// $tp @s $(x) \
+///     $(y) $(z)
+/// "#);
+///
+/// assert_eq!(function.commands.len(), 3);
+/// assert_eq!(function.commands[0].text, "say Hello!");
+/// assert_eq!(function.commands[0].line, 3);
+/// assert_eq!(function.commands[1].name(), "give");
+///
+/// let tp = &function.commands[2];
+/// assert!(tp.is_macro);
+/// assert_eq!(tp.text, "tp @s $(x) $(y) $(z)");
+/// assert_eq!(tp.macro_variables().collect::<Vec<_>>(), vec!["x", "y", "z"]);
+///
+/// assert!(function.is_macro());
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct Function {
+    /// The commands of the function, in order.
+    pub commands: Vec<Command>,
+}
+
+impl Function {
+    /// Parses a function from the contents of a `.mcfunction` file.
+    pub fn parse(source: &str) -> Self {
+        let mut commands = Vec::new();
+        let mut lines = source.lines().enumerate();
+
+        while let Some((index, line)) = lines.next() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut text = String::from(line);
+            while let Some(stripped) = text.strip_suffix('\\') {
+                text.truncate(stripped.len());
+                match lines.next() {
+                    Some((_, next)) => text.push_str(next.trim()),
+                    None => break,
+                }
+            }
+
+            let (is_macro, text) = match text.strip_prefix('$') {
+                Some(stripped) => (true, String::from(stripped)),
+                None => (false, text),
+            };
+
+            commands.push(Command {
+                line: index + 1,
+                text,
+                is_macro,
+            });
+        }
+
+        Self { commands }
+    }
+
+    /// Returns `true` if this function contains any macro lines, i.e., it
+    /// must be run with arguments.
+    pub fn is_macro(&self) -> bool {
+        self.commands.iter().any(|command| command.is_macro)
+    }
+
+    /// Returns an iterator over the macro lines of this function.
+    pub fn macro_commands(&self) -> impl Iterator<Item = &Command> {
+        self.commands.iter().filter(|command| command.is_macro)
+    }
+}
+
+impl FromStr for Function {
+    type Err = Infallible;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        Ok(Self::parse(source))
+    }
+}
+
+/// A single command in a [`Function`].
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct Command {
+    /// The (1-based) line number on which the command starts.
+    pub line: usize,
+
+    /// The text of the command, without the leading `$` of a macro line, and
+    /// with any continuation lines joined.
+    pub text: String,
+
+    /// Whether the command is a macro line (starting with `$`).
+    ///
+    /// Added in 1.20.2.
+    pub is_macro: bool,
+}
+
+impl Command {
+    /// Returns the name of the command, i.e., its first word.
+    pub fn name(&self) -> &str {
+        self.text.split_whitespace().next().unwrap_or("")
+    }
+
+    /// Returns an iterator over the names of the `$(name)` placeholders in a
+    /// macro line.
+    ///
+    /// Returns an empty iterator for non-macro lines.
+    pub fn macro_variables(&self) -> impl Iterator<Item = &str> {
+        let mut rest = if self.is_macro { &self.text[..] } else { "" };
+
+        std::iter::from_fn(move || {
+            let start = rest.find("$(")? + 2;
+            let len = rest[start..].find(')')?;
+            let name = &rest[start..start + len];
+            rest = &rest[start + len + 1..];
+            Some(name)
+        })
+    }
+}
@@ -0,0 +1,261 @@
+//! Data types for the binary glyph assets used by font providers:
+//! `assets/<namespace>/font/glyph_sizes.bin` and the `.hex` files of
+//! `unihex` providers.
+//!
+//! Start here: [`GlyphSizes`] and [`UnihexGlyphs`].
+//!
+//! These files are not JSON, so they are parsed with [`GlyphSizes::from_bytes`]
+//! and [`UnihexGlyphs::parse_hex`] (or [`UnihexGlyphs::from_zip`], with the
+//! `zip` feature) instead of Serde.
+//!
+//! See <https://minecraft.fandom.com/wiki/Font>.
+
+use std::{collections::HashMap, io};
+
+/// The glyph sizes of a
+/// [`FontProvider::LegacyUnicode`][crate::schemas::font::FontProvider::LegacyUnicode],
+/// as stored in `assets/<namespace>/font/glyph_sizes.bin`.
+///
+/// The file contains one byte for every character in the Basic Multilingual
+/// Plane (`U+0000` to `U+FFFF`). The high nibble of each byte is the first
+/// column of the glyph in its 16x16 cell, and the low nibble is the last
+/// column. A byte of `0` means the glyph is absent.
+///
+/// Removed in 1.20.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::glyphs::*;
+/// let mut bytes = vec![0u8; 0x10000];
+/// bytes['A' as usize] = 0x0E;
+/// bytes['i' as usize] = 0x46;
+///
+/// let sizes = GlyphSizes::from_bytes(&bytes);
+///
+/// assert_eq!(sizes.get('A'), Some(GlyphSize { left: 0, right: 14 }));
+/// assert_eq!(sizes.get('i').unwrap().width(), 3);
+/// assert_eq!(sizes.get('B'), None);
+/// assert_eq!(sizes.get('😀'), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GlyphSizes {
+    /// The raw glyph size bytes, indexed by code point.
+    pub sizes: Vec<u8>,
+}
+
+impl GlyphSizes {
+    /// The number of characters covered by a `glyph_sizes.bin` file.
+    pub const LEN: usize = 0x10000;
+
+    /// Reads the glyph sizes from the contents of a `glyph_sizes.bin` file.
+    ///
+    /// Files shorter than [`LEN`][Self::LEN] bytes are padded with absent
+    /// glyphs, and any extra bytes are ignored.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let mut sizes = bytes[..bytes.len().min(Self::LEN)].to_vec();
+        sizes.resize(Self::LEN, 0);
+
+        Self { sizes }
+    }
+
+    /// Returns the size of the glyph for the given character, or `None` if
+    /// the glyph is absent or the character is outside of the Basic
+    /// Multilingual Plane.
+    pub fn get(&self, c: char) -> Option<GlyphSize> {
+        let byte = *self.sizes.get(c as usize)?;
+
+        (byte != 0).then_some(GlyphSize {
+            left: byte >> 4,
+            right: byte & 0x0F,
+        })
+    }
+}
+
+impl Default for GlyphSizes {
+    fn default() -> Self {
+        Self {
+            sizes: vec![0; Self::LEN],
+        }
+    }
+}
+
+/// The size of a glyph in a [`GlyphSizes`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GlyphSize {
+    /// The first (leftmost) column of the glyph in its 16x16 cell.
+    pub left: u8,
+
+    /// The last (rightmost) column of the glyph in its 16x16 cell.
+    pub right: u8,
+}
+
+impl GlyphSize {
+    /// Returns the width of the glyph, in pixels of its 16x16 cell.
+    pub fn width(&self) -> u32 {
+        (self.right as u32 + 1).saturating_sub(self.left as u32)
+    }
+}
+
+/// The glyphs of a
+/// [`FontProvider::Unihex`][crate::schemas::font::FontProvider::Unihex], as
+/// stored in the `.hex` files of its zip archive.
+///
+/// Each line of a `.hex` file is a code point and a bitmap, both in
+/// hexadecimal and separated by a colon, e.g., `0041:0000000018242442427E424242420000`.
+/// Every glyph is 16 pixels high and 8, 16, 24, or 32 pixels wide.
+///
+/// Added in 1.20.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::glyphs::*;
+/// let glyphs = UnihexGlyphs::parse_hex(concat!(
+///     "0041:0000000018242442427E424242420000\n",
+///     "4E00:00000000000000000000FFFE0000000000000000000000000000000000000000\n",
+/// ))
+/// .unwrap();
+///
+/// let a = &glyphs.glyphs[&'A'];
+/// assert_eq!(a.width, 8);
+/// assert!(a.pixel(3, 4));
+/// assert!(!a.pixel(0, 4));
+/// assert_eq!(a.bounds(), Some((1, 6)));
+///
+/// assert_eq!(glyphs.glyphs[&'一'].width, 16);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct UnihexGlyphs {
+    /// Map of characters to their glyphs.
+    pub glyphs: HashMap<char, UnihexGlyph>,
+}
+
+impl UnihexGlyphs {
+    /// Parses the glyphs from the contents of a `.hex` file.
+    ///
+    /// Empty lines are ignored. Returns an error of kind
+    /// [`io::ErrorKind::InvalidData`] if any other line is malformed.
+    pub fn parse_hex(source: &str) -> Result<Self, io::Error> {
+        let mut glyphs = Self::default();
+        glyphs.add_hex(source)?;
+        Ok(glyphs)
+    }
+
+    /// Parses the glyphs from the contents of a `.hex` file and adds them,
+    /// replacing any existing glyphs for the same characters.
+    pub fn add_hex(&mut self, source: &str) -> Result<(), io::Error> {
+        for (index, line) in source.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let (c, glyph) = UnihexGlyph::parse_line(line).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid unihex glyph on line {}", index + 1),
+                )
+            })?;
+
+            self.glyphs.insert(c, glyph);
+        }
+
+        Ok(())
+    }
+
+    /// Reads the glyphs from every `.hex` file in the given zip archive, as
+    /// referenced by the [`hex_file`] of a `unihex` provider.
+    ///
+    /// This method requires the `zip` feature.
+    ///
+    /// [`hex_file`]: crate::schemas::font::FontProvider::Unihex::hex_file
+    #[cfg(feature = "zip")]
+    pub fn from_zip(bytes: &[u8]) -> crate::api::Result<Self> {
+        use std::io::Read;
+
+        let mut archive = zip::ZipArchive::new(io::Cursor::new(bytes))?;
+        let mut glyphs = Self::default();
+
+        for index in 0..archive.len() {
+            let mut file = archive.by_index(index)?;
+            if !file.is_file() || !file.name().ends_with(".hex") {
+                continue;
+            }
+
+            let mut source = String::new();
+            file.read_to_string(&mut source)?;
+            glyphs.add_hex(&source)?;
+        }
+
+        Ok(glyphs)
+    }
+}
+
+/// A single glyph of a [`UnihexGlyphs`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnihexGlyph {
+    /// The width of the glyph, in pixels: 8, 16, 24, or 32.
+    pub width: u32,
+
+    /// The rows of the glyph from top to bottom. The most significant of the
+    /// [`width`][Self::width] lowest bits of each row is its leftmost pixel.
+    pub rows: [u32; 16],
+}
+
+impl UnihexGlyph {
+    /// The height of every glyph, in pixels.
+    pub const HEIGHT: u32 = 16;
+
+    /// Returns whether the pixel at the given column and row is set.
+    ///
+    /// Pixels outside of the glyph are never set.
+    pub fn pixel(&self, x: u32, y: u32) -> bool {
+        if x >= self.width || y >= Self::HEIGHT {
+            return false;
+        }
+
+        self.rows[y as usize] >> (self.width - 1 - x) & 1 == 1
+    }
+
+    /// Returns the first and last columns that contain set pixels, or `None`
+    /// if the glyph is empty.
+    pub fn bounds(&self) -> Option<(u32, u32)> {
+        let mut columns = (0..self.width).filter(|&x| (0..Self::HEIGHT).any(|y| self.pixel(x, y)));
+
+        let left = columns.next()?;
+        let right = columns.next_back().unwrap_or(left);
+
+        Some((left, right))
+    }
+
+    fn parse_line(line: &str) -> Option<(char, Self)> {
+        let (code_point, bitmap) = line.split_once(':')?;
+
+        let c = char::from_u32(u32::from_str_radix(code_point, 16).ok()?)?;
+
+        // Each row is `width / 4` hex digits.
+        let digits_per_row = match bitmap.len() {
+            32 => 2,
+            64 => 4,
+            96 => 6,
+            128 => 8,
+            _ => return None,
+        };
+
+        let mut rows = [0; 16];
+        for (row, digits) in rows
+            .iter_mut()
+            .zip(bitmap.as_bytes().chunks(digits_per_row))
+        {
+            *row = u32::from_str_radix(std::str::from_utf8(digits).ok()?, 16).ok()?;
+        }
+
+        let glyph = Self {
+            width: digits_per_row as u32 * 4,
+            rows,
+        };
+
+        Some((c, glyph))
+    }
+}
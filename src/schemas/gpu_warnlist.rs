@@ -0,0 +1,52 @@
+//! Serde-(de)serializable data types for `assets/minecraft/gpu_warnlist.json`.
+//!
+//! Start here: [`GpuWarnlist`].
+//!
+//! See <https://minecraft.fandom.com/wiki/Resource_pack#Contents>.
+
+use serde::{Deserialize, Serialize};
+
+/// The list of graphics drivers that Minecraft warns about when enabling
+/// "Fabulous!" graphics, as stored in `assets/minecraft/gpu_warnlist.json`.
+///
+/// A driver is on the list if its renderer, version, or vendor string matches
+/// any of the corresponding regular expressions.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::gpu_warnlist::*;
+/// let warnlist: GpuWarnlist = serde_json::from_str(r#"
+///     {
+///         "renderer": ["Radeon HD 2\\d{3}.*"],
+///         "version": [],
+///         "vendor": ["Intel.*"]
+///     }
+/// "#).unwrap();
+///
+/// assert_eq!(warnlist.renderer, vec![r"Radeon HD 2\d{3}.*"]);
+/// assert!(warnlist.version.is_empty());
+/// ```
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct GpuWarnlist {
+    /// Regular expressions matched against the GL renderer string.
+    #[serde(default)]
+    pub renderer: Vec<String>,
+
+    /// Regular expressions matched against the GL version string.
+    #[serde(default)]
+    pub version: Vec<String>,
+
+    /// Regular expressions matched against the GL vendor string.
+    #[serde(default)]
+    pub vendor: Vec<String>,
+
+    /// The fields that this crate does not model (e.g., fields added by mods
+    /// or by newer versions), kept so that they survive a round trip.
+    ///
+    /// Requires the `preserve-unknown` feature.
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten, serialize_with = "crate::schemas::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
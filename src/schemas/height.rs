@@ -0,0 +1,89 @@
+//! Serde-(de)serializable data types for heights in the world, shared by
+//! placed features, carvers, and structure placement.
+//!
+//! Start here: [`VerticalAnchor`] and [`Heightmap`].
+
+use serde::{Deserialize, Serialize};
+
+/// A y level given relative to the bottom or top of the world, or as an
+/// absolute value.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::height::*;
+/// let anchor: VerticalAnchor = serde_json::from_str(r#"{ "below_top": 8 }"#).unwrap();
+/// assert_eq!(anchor, VerticalAnchor::BelowTop(8));
+///
+/// // The overworld spans from y = -64 to y = 319.
+/// assert_eq!(anchor.resolve(-64, 384), 311);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum VerticalAnchor {
+    /// An absolute y level.
+    Absolute(i32),
+
+    /// A number of levels above the bottom of the world.
+    AboveBottom(i32),
+
+    /// A number of levels below the top of the world.
+    BelowTop(i32),
+}
+
+impl VerticalAnchor {
+    /// Returns the y level of the anchor in a world with the given minimum y
+    /// level and height.
+    pub fn resolve(&self, min_y: i32, height: i32) -> i32 {
+        match *self {
+            Self::Absolute(y) => y,
+            Self::AboveBottom(offset) => min_y + offset,
+            Self::BelowTop(offset) => min_y + height - 1 - offset,
+        }
+    }
+}
+
+/// A heightmap, which stores the highest block of each column that matches a
+/// certain condition.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::height::*;
+/// let heightmap: Heightmap = serde_json::from_str(r#""OCEAN_FLOOR_WG""#).unwrap();
+/// assert_eq!(heightmap, Heightmap::OceanFloorWg);
+/// assert!(heightmap.is_worldgen());
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Heightmap {
+    /// The highest block that is not air, while the world is generated.
+    WorldSurfaceWg,
+
+    /// The highest block that is not air.
+    WorldSurface,
+
+    /// The highest block that blocks motion, while the world is generated.
+    OceanFloorWg,
+
+    /// The highest block that blocks motion.
+    OceanFloor,
+
+    /// The highest block that blocks motion or contains a fluid.
+    MotionBlocking,
+
+    /// The highest block that blocks motion or contains a fluid, and is not
+    /// leaves.
+    MotionBlockingNoLeaves,
+}
+
+impl Heightmap {
+    /// Returns `true` if the heightmap only exists while the world is
+    /// generated, i.e., for [`WorldSurfaceWg`][Self::WorldSurfaceWg] and
+    /// [`OceanFloorWg`][Self::OceanFloorWg].
+    pub fn is_worldgen(&self) -> bool {
+        matches!(self, Self::WorldSurfaceWg | Self::OceanFloorWg)
+    }
+}
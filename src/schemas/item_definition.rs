@@ -0,0 +1,765 @@
+//! Serde-(de)serializable data types for `assets/<namespace>/items/*.json`.
+//!
+//! Start here: [`ItemDefinition`].
+//!
+//! See <https://minecraft.fandom.com/wiki/Items_model_definition>.
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::ResourceLocation;
+
+/// An item model definition as stored in the `assets/<namespace>/items/`
+/// directory, e.g., `compass.json`.
+///
+/// An item model definition selects the [`Model`][crate::schemas::Model]s
+/// used to render an item, depending on the item's components and the context
+/// it is rendered in.
+///
+/// Added in 1.21.4.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::item_definition::*;
+/// let definition: ItemDefinition = serde_json::from_str(r#"
+///     {
+///         "model": {
+///             "type": "minecraft:condition",
+///             "property": "minecraft:using_item",
+///             "on_false": { "type": "minecraft:model", "model": "minecraft:item/bow" },
+///             "on_true": {
+///                 "type": "minecraft:range_dispatch",
+///                 "property": "minecraft:use_duration",
+///                 "scale": 0.05,
+///                 "entries": [
+///                     { "threshold": 0.65, "model": { "type": "minecraft:model", "model": "minecraft:item/bow_pulling_1" } },
+///                     { "threshold": 0.9, "model": { "type": "minecraft:model", "model": "minecraft:item/bow_pulling_2" } }
+///                 ],
+///                 "fallback": { "type": "minecraft:model", "model": "minecraft:item/bow_pulling_0" }
+///             }
+///         }
+///     }
+/// "#).unwrap();
+///
+/// assert!(definition.hand_animation_on_swap);
+///
+/// match &definition.model {
+///     ItemModel::Condition { property, on_true, .. } => {
+///         assert_eq!(*property, ConditionProperty::UsingItem);
+///
+///         match &**on_true {
+///             ItemModel::RangeDispatch { property, scale, entries, .. } => {
+///                 assert_eq!(*property, RangeProperty::UseDuration { remaining: false });
+///                 assert_eq!(*scale, 0.05);
+///                 assert_eq!(entries.len(), 2);
+///             }
+///             _ => unreachable!(),
+///         }
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ItemDefinition {
+    /// The model of the item.
+    pub model: ItemModel,
+
+    /// Whether the item is animated moving down and back up when it is
+    /// swapped or its components change. Defaults to `true`.
+    #[serde(default = "ItemDefinition::default_true")]
+    pub hand_animation_on_swap: bool,
+
+    /// Whether the model may be rendered larger than its slot in GUIs.
+    /// Defaults to `false`.
+    ///
+    /// Added in 1.21.6.
+    #[serde(default)]
+    pub oversized_in_gui: bool,
+
+    /// The fields that this crate does not model (e.g., fields added by mods
+    /// or by newer versions), kept so that they survive a round trip.
+    ///
+    /// Requires the `preserve-unknown` feature.
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten, serialize_with = "crate::schemas::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl ItemDefinition {
+    pub(crate) const fn default_true() -> bool {
+        true
+    }
+
+    pub(crate) const fn default_scale() -> f32 {
+        1.0
+    }
+}
+
+/// An item model in an [`ItemDefinition`].
+///
+/// The model's `type` field determines which variant is used.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::item_definition::*;
+/// let model: ItemModel = serde_json::from_str(r#"
+///     {
+///         "type": "minecraft:select",
+///         "property": "minecraft:display_context",
+///         "cases": [
+///             {
+///                 "when": ["gui", "ground"],
+///                 "model": {
+///                     "type": "minecraft:model",
+///                     "model": "minecraft:item/leather_helmet",
+///                     "tints": [{ "type": "minecraft:dye", "default": -6265536 }]
+///                 }
+///             }
+///         ],
+///         "fallback": {
+///             "type": "minecraft:composite",
+///             "models": [
+///                 { "type": "minecraft:special", "base": "minecraft:item/chest", "model": { "type": "minecraft:chest", "texture": "minecraft:normal" } },
+///                 { "type": "minecraft:empty" }
+///             ]
+///         }
+///     }
+/// "#).unwrap();
+///
+/// match &model {
+///     ItemModel::Select { property, cases, fallback } => {
+///         assert_eq!(*property, SelectProperty::DisplayContext);
+///         assert_eq!(cases[0].when.values().len(), 2);
+///
+///         match fallback.as_deref() {
+///             Some(ItemModel::Composite { models }) => assert_eq!(models[1], ItemModel::Empty),
+///             _ => unreachable!(),
+///         }
+///     }
+///     _ => unreachable!(),
+/// }
+///
+/// // The model survives a round trip.
+/// let json = serde_json::to_string(&model).unwrap();
+/// assert_eq!(serde_json::from_str::<ItemModel>(&json).unwrap(), model);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(tag = "type")]
+pub enum ItemModel {
+    /// Renders a plain [`Model`][crate::schemas::Model].
+    #[serde(rename = "minecraft:model", alias = "model")]
+    Model {
+        /// The resource location of the model, e.g., `minecraft:item/stick`.
+        model: ResourceLocation<'static>,
+
+        /// The tints applied to the model's faces, indexed by their
+        /// `tintindex`.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        tints: Vec<TintSource>,
+    },
+
+    /// Renders several models on top of each other.
+    #[serde(rename = "minecraft:composite", alias = "composite")]
+    Composite {
+        /// The models to render.
+        models: Vec<ItemModel>,
+    },
+
+    /// Renders one of two models, depending on a boolean property.
+    #[serde(rename = "minecraft:condition", alias = "condition")]
+    Condition {
+        /// The property to check.
+        #[serde(flatten)]
+        property: ConditionProperty,
+
+        /// The model rendered if the property is `true`.
+        on_true: Box<ItemModel>,
+
+        /// The model rendered if the property is `false`.
+        on_false: Box<ItemModel>,
+    },
+
+    /// Renders the model of the case matching the value of a property.
+    #[serde(rename = "minecraft:select", alias = "select")]
+    Select {
+        /// The property to check.
+        #[serde(flatten)]
+        property: SelectProperty,
+
+        /// The cases to match, in order.
+        cases: Vec<SelectCase>,
+
+        /// The model rendered if no case matches.
+        fallback: Option<Box<ItemModel>>,
+    },
+
+    /// Renders the model of the entry with the highest threshold that is at
+    /// most the value of a numeric property.
+    #[serde(rename = "minecraft:range_dispatch", alias = "range_dispatch")]
+    RangeDispatch {
+        /// The property to check.
+        #[serde(flatten)]
+        property: RangeProperty,
+
+        /// The factor the value of the property is multiplied by. Defaults to
+        /// `1.0`.
+        #[serde(default = "ItemDefinition::default_scale")]
+        scale: f32,
+
+        /// The entries to choose from.
+        entries: Vec<RangeDispatchEntry>,
+
+        /// The model rendered if the value is below every threshold.
+        fallback: Option<Box<ItemModel>>,
+    },
+
+    /// Renders nothing.
+    #[serde(rename = "minecraft:empty", alias = "empty")]
+    Empty,
+
+    /// Renders the selected item of a bundle.
+    #[serde(
+        rename = "minecraft:bundle/selected_item",
+        alias = "bundle/selected_item"
+    )]
+    BundleSelectedItem,
+
+    /// Renders a hardcoded model, combined with the particle texture and
+    /// transformations of a base model.
+    #[serde(rename = "minecraft:special", alias = "special")]
+    Special {
+        /// The hardcoded model to render.
+        model: SpecialModel,
+
+        /// The resource location of the base model.
+        base: ResourceLocation<'static>,
+    },
+}
+
+/// A boolean property checked by an [`ItemModel::Condition`].
+///
+/// The property's `property` field determines which variant is used.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(tag = "property")]
+pub enum ConditionProperty {
+    /// Whether the item is being used (e.g., a bow being drawn).
+    #[serde(rename = "minecraft:using_item", alias = "using_item")]
+    UsingItem,
+
+    /// Whether the item is damageable and has only one use remaining.
+    #[serde(rename = "minecraft:broken", alias = "broken")]
+    Broken,
+
+    /// Whether the item is damageable and has been damaged.
+    #[serde(rename = "minecraft:damaged", alias = "damaged")]
+    Damaged,
+
+    /// Whether the item has the given component.
+    #[serde(rename = "minecraft:has_component", alias = "has_component")]
+    HasComponent {
+        /// The ID of the component.
+        component: ResourceLocation<'static>,
+
+        /// Whether components that are present by default are treated as
+        /// absent. Defaults to `false`.
+        #[serde(default)]
+        ignore_default: bool,
+    },
+
+    /// Whether the fishing rod's bobber is cast.
+    #[serde(rename = "minecraft:fishing_rod/cast", alias = "fishing_rod/cast")]
+    FishingRodCast,
+
+    /// Whether the bundle has a selected item.
+    #[serde(
+        rename = "minecraft:bundle/has_selected_item",
+        alias = "bundle/has_selected_item"
+    )]
+    BundleHasSelectedItem,
+
+    /// Whether the item is in the selected hotbar slot.
+    #[serde(rename = "minecraft:selected", alias = "selected")]
+    Selected,
+
+    /// Whether the item is carried by the mouse cursor in an inventory.
+    #[serde(rename = "minecraft:carried", alias = "carried")]
+    Carried,
+
+    /// Whether the player has requested an extended view (by holding shift).
+    #[serde(rename = "minecraft:extended_view", alias = "extended_view")]
+    ExtendedView,
+
+    /// Whether the given key binding is pressed.
+    #[serde(rename = "minecraft:keybind_down", alias = "keybind_down")]
+    KeybindDown {
+        /// The ID of the key binding, e.g., `key.use`.
+        keybind: String,
+    },
+
+    /// Whether the camera is viewing from the entity holding the item.
+    #[serde(rename = "minecraft:view_entity", alias = "view_entity")]
+    ViewEntity,
+
+    /// A flag of the `minecraft:custom_model_data` component.
+    #[serde(rename = "minecraft:custom_model_data", alias = "custom_model_data")]
+    CustomModelData {
+        /// The index of the flag. Defaults to `0`.
+        #[serde(default)]
+        index: u32,
+    },
+
+    /// Whether a component of the item matches a predicate.
+    ///
+    /// Added in 1.21.5.
+    #[serde(rename = "minecraft:component", alias = "component")]
+    Component {
+        /// The ID of the component predicate.
+        predicate: ResourceLocation<'static>,
+
+        /// The value to match.
+        ///
+        /// *Predicate values are kept as raw JSON.*
+        value: serde_json::Value,
+    },
+}
+
+/// A property whose value is matched by an [`ItemModel::Select`].
+///
+/// The property's `property` field determines which variant is used.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(tag = "property")]
+pub enum SelectProperty {
+    /// The main hand of the holding player (`left` or `right`).
+    #[serde(rename = "minecraft:main_hand", alias = "main_hand")]
+    MainHand,
+
+    /// The kind of projectile loaded in a crossbow (`none`, `arrow`, or
+    /// `rocket`).
+    #[serde(rename = "minecraft:charge_type", alias = "charge_type")]
+    ChargeType,
+
+    /// The ID of the trim material of armor.
+    #[serde(rename = "minecraft:trim_material", alias = "trim_material")]
+    TrimMaterial,
+
+    /// The value of a block state property in the
+    /// `minecraft:block_state` component.
+    #[serde(rename = "minecraft:block_state", alias = "block_state")]
+    BlockState {
+        /// The name of the block state property.
+        block_state_property: String,
+    },
+
+    /// The context the item is rendered in, e.g., `gui` or `head`.
+    #[serde(rename = "minecraft:display_context", alias = "display_context")]
+    DisplayContext,
+
+    /// The current time, formatted with a pattern.
+    #[serde(rename = "minecraft:local_time", alias = "local_time")]
+    LocalTime {
+        /// The locale used for formatting. Defaults to the root locale.
+        locale: Option<String>,
+
+        /// The time zone used for formatting. Defaults to the time zone of
+        /// the device.
+        time_zone: Option<String>,
+
+        /// The `SimpleDateFormat` pattern used for formatting.
+        pattern: String,
+    },
+
+    /// The ID of the dimension the holding entity is in.
+    #[serde(rename = "minecraft:context_dimension", alias = "context_dimension")]
+    ContextDimension,
+
+    /// The ID of the type of the holding entity.
+    #[serde(
+        rename = "minecraft:context_entity_type",
+        alias = "context_entity_type"
+    )]
+    ContextEntityType,
+
+    /// A string of the `minecraft:custom_model_data` component.
+    #[serde(rename = "minecraft:custom_model_data", alias = "custom_model_data")]
+    CustomModelData {
+        /// The index of the string. Defaults to `0`.
+        #[serde(default)]
+        index: u32,
+    },
+
+    /// The value of a component of the item.
+    ///
+    /// Added in 1.21.5.
+    #[serde(rename = "minecraft:component", alias = "component")]
+    Component {
+        /// The ID of the component.
+        component: ResourceLocation<'static>,
+    },
+}
+
+/// A case of an [`ItemModel::Select`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SelectCase {
+    /// The value or values of the property that this case matches.
+    ///
+    /// *Values are kept as raw JSON, since their type depends on the
+    /// property.*
+    pub when: SelectWhen,
+
+    /// The model rendered if this case matches.
+    pub model: ItemModel,
+}
+
+/// The value or values matched by a [`SelectCase`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum SelectWhen {
+    /// A list of values, any of which is matched.
+    Multiple(Vec<serde_json::Value>),
+
+    /// A single value.
+    Single(serde_json::Value),
+}
+
+impl SelectWhen {
+    /// Returns the matched values as a slice.
+    pub fn values(&self) -> &[serde_json::Value] {
+        match self {
+            Self::Multiple(values) => &values[..],
+            Self::Single(value) => std::slice::from_ref(value),
+        }
+    }
+}
+
+/// A numeric property checked by an [`ItemModel::RangeDispatch`].
+///
+/// The property's `property` field determines which variant is used.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(tag = "property")]
+pub enum RangeProperty {
+    /// The fullness of a bundle, from `0.0` to `1.0`.
+    #[serde(rename = "minecraft:bundle/fullness", alias = "bundle/fullness")]
+    BundleFullness,
+
+    /// The damage of the item.
+    #[serde(rename = "minecraft:damage", alias = "damage")]
+    Damage {
+        /// Whether the damage is divided by the maximum damage. Defaults to
+        /// `true`.
+        #[serde(default = "ItemDefinition::default_true")]
+        normalize: bool,
+    },
+
+    /// The stack size of the item.
+    #[serde(rename = "minecraft:count", alias = "count")]
+    Count {
+        /// Whether the stack size is divided by the maximum stack size.
+        /// Defaults to `true`.
+        #[serde(default = "ItemDefinition::default_true")]
+        normalize: bool,
+    },
+
+    /// The remaining cooldown of the item, from `0.0` to `1.0`.
+    #[serde(rename = "minecraft:cooldown", alias = "cooldown")]
+    Cooldown,
+
+    /// The in-game time, from `0.0` to `1.0`.
+    #[serde(rename = "minecraft:time", alias = "time")]
+    Time {
+        /// Whether the value oscillates towards the actual value. Defaults to
+        /// `true`.
+        #[serde(default = "ItemDefinition::default_true")]
+        wobble: bool,
+
+        /// The source of the time.
+        source: TimeSource,
+    },
+
+    /// The angle between the holder and a target, from `0.0` to `1.0`.
+    #[serde(rename = "minecraft:compass", alias = "compass")]
+    Compass {
+        /// The target of the compass.
+        target: CompassTarget,
+
+        /// Whether the value oscillates towards the actual value. Defaults to
+        /// `true`.
+        #[serde(default = "ItemDefinition::default_true")]
+        wobble: bool,
+    },
+
+    /// The draw progress of a crossbow, from `0.0` to `1.0`.
+    #[serde(rename = "minecraft:crossbow/pull", alias = "crossbow/pull")]
+    CrossbowPull,
+
+    /// The time, in ticks, the item has been used for.
+    #[serde(rename = "minecraft:use_duration", alias = "use_duration")]
+    UseDuration {
+        /// Whether the remaining use time is returned instead. Defaults to
+        /// `false`.
+        #[serde(default)]
+        remaining: bool,
+    },
+
+    /// The remaining use time, in ticks, modulo a period.
+    #[serde(rename = "minecraft:use_cycle", alias = "use_cycle")]
+    UseCycle {
+        /// The period. Defaults to `1.0`.
+        #[serde(default = "ItemDefinition::default_scale")]
+        period: f32,
+    },
+
+    /// A float of the `minecraft:custom_model_data` component.
+    #[serde(rename = "minecraft:custom_model_data", alias = "custom_model_data")]
+    CustomModelData {
+        /// The index of the float. Defaults to `0`.
+        #[serde(default)]
+        index: u32,
+    },
+}
+
+/// The source of a [`RangeProperty::Time`].
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum TimeSource {
+    /// The time of day.
+    Daytime,
+
+    /// The moon phase.
+    MoonPhase,
+
+    /// A random value.
+    Random,
+}
+
+/// The target of a [`RangeProperty::Compass`].
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum CompassTarget {
+    /// The world spawn point.
+    Spawn,
+
+    /// The lodestone in the `minecraft:lodestone_tracker` component.
+    Lodestone,
+
+    /// The last death location of the holder.
+    Recovery,
+
+    /// No target; the compass spins randomly.
+    None,
+}
+
+/// An entry of an [`ItemModel::RangeDispatch`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RangeDispatchEntry {
+    /// The lowest (scaled) value for which this entry is chosen.
+    pub threshold: f32,
+
+    /// The model rendered if this entry is chosen.
+    pub model: ItemModel,
+}
+
+/// A tint applied to the faces of an [`ItemModel::Model`].
+///
+/// The tint's `type` field determines which variant is used.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(tag = "type")]
+pub enum TintSource {
+    /// A constant color.
+    #[serde(rename = "minecraft:constant", alias = "constant")]
+    Constant {
+        /// The color.
+        value: TintColor,
+    },
+
+    /// The color of the `minecraft:dyed_color` component.
+    #[serde(rename = "minecraft:dye", alias = "dye")]
+    Dye {
+        /// The color used if the component is absent.
+        default: TintColor,
+    },
+
+    /// The grass color at the given climate.
+    #[serde(rename = "minecraft:grass", alias = "grass")]
+    Grass {
+        /// The temperature, from `0.0` to `1.0`.
+        temperature: f32,
+
+        /// The downfall, from `0.0` to `1.0`.
+        downfall: f32,
+    },
+
+    /// The average color of the `minecraft:firework_explosion` component.
+    #[serde(rename = "minecraft:firework", alias = "firework")]
+    Firework {
+        /// The color used if the component is absent or has no colors.
+        default: TintColor,
+    },
+
+    /// The color of the `minecraft:potion_contents` component.
+    #[serde(rename = "minecraft:potion", alias = "potion")]
+    Potion {
+        /// The color used if the component is absent or has no effects.
+        default: TintColor,
+    },
+
+    /// The color of the `minecraft:map_color` component.
+    #[serde(rename = "minecraft:map_color", alias = "map_color")]
+    MapColor {
+        /// The color used if the component is absent.
+        default: TintColor,
+    },
+
+    /// The team color of the holding entity.
+    #[serde(rename = "minecraft:team", alias = "team")]
+    Team {
+        /// The color used if the entity has no team color.
+        default: TintColor,
+    },
+
+    /// A color of the `minecraft:custom_model_data` component.
+    #[serde(rename = "minecraft:custom_model_data", alias = "custom_model_data")]
+    CustomModelData {
+        /// The index of the color. Defaults to `0`.
+        #[serde(default)]
+        index: u32,
+
+        /// The color used if the component has no color at the index.
+        default: TintColor,
+    },
+}
+
+/// A color of a [`TintSource`].
+///
+/// This is either a packed RGB integer, or a list of red, green, and blue
+/// components from `0.0` to `1.0`.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum TintColor {
+    /// A packed RGB integer, e.g., `0xFF0000` for red.
+    Packed(i32),
+
+    /// Red, green, and blue components from `0.0` to `1.0`.
+    Components([f32; 3]),
+}
+
+/// A hardcoded model used by an [`ItemModel::Special`].
+///
+/// The model's `type` field determines which variant is used.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(tag = "type")]
+pub enum SpecialModel {
+    /// A banner, with the patterns of the `minecraft:banner_patterns`
+    /// component.
+    #[serde(rename = "minecraft:banner", alias = "banner")]
+    Banner {
+        /// The base color of the banner.
+        color: String,
+    },
+
+    /// A bed.
+    #[serde(rename = "minecraft:bed", alias = "bed")]
+    Bed {
+        /// The resource location of the texture, relative to
+        /// `textures/entity/bed/`.
+        texture: ResourceLocation<'static>,
+    },
+
+    /// A chest.
+    #[serde(rename = "minecraft:chest", alias = "chest")]
+    Chest {
+        /// The resource location of the texture, relative to
+        /// `textures/entity/chest/`.
+        texture: ResourceLocation<'static>,
+
+        /// How far the lid is opened, from `0.0` to `1.0`. Defaults to
+        /// `0.0`.
+        #[serde(default)]
+        openness: f32,
+    },
+
+    /// A conduit.
+    #[serde(rename = "minecraft:conduit", alias = "conduit")]
+    Conduit,
+
+    /// A decorated pot, with the sherds of the `minecraft:pot_decorations`
+    /// component.
+    #[serde(rename = "minecraft:decorated_pot", alias = "decorated_pot")]
+    DecoratedPot,
+
+    /// A mob head or skull.
+    #[serde(rename = "minecraft:head", alias = "head")]
+    Head {
+        /// The kind of head, e.g., `skeleton` or `dragon`.
+        kind: String,
+
+        /// The resource location of the texture, relative to
+        /// `textures/entity/`. Defaults to the texture of the kind of head.
+        texture: Option<ResourceLocation<'static>>,
+
+        /// The progress of the head's animation. Defaults to `0.0`.
+        #[serde(default)]
+        animation: f32,
+    },
+
+    /// A shield, with the patterns of the `minecraft:banner_patterns`
+    /// component.
+    #[serde(rename = "minecraft:shield", alias = "shield")]
+    Shield,
+
+    /// A shulker box.
+    #[serde(rename = "minecraft:shulker_box", alias = "shulker_box")]
+    ShulkerBox {
+        /// The resource location of the texture, relative to
+        /// `textures/entity/shulker/`.
+        texture: ResourceLocation<'static>,
+
+        /// How far the lid is opened, from `0.0` to `1.0`. Defaults to
+        /// `0.0`.
+        #[serde(default)]
+        openness: f32,
+
+        /// The direction the box faces. Defaults to `up`.
+        orientation: Option<String>,
+    },
+
+    /// A standing sign.
+    #[serde(rename = "minecraft:standing_sign", alias = "standing_sign")]
+    StandingSign {
+        /// The wood type of the sign, e.g., `oak`.
+        wood_type: String,
+
+        /// The resource location of the texture, relative to
+        /// `textures/entity/signs/`. Defaults to the texture of the wood type.
+        texture: Option<ResourceLocation<'static>>,
+    },
+
+    /// A hanging sign.
+    #[serde(rename = "minecraft:hanging_sign", alias = "hanging_sign")]
+    HangingSign {
+        /// The wood type of the sign, e.g., `oak`.
+        wood_type: String,
+
+        /// The resource location of the texture, relative to
+        /// `textures/entity/signs/hanging/`. Defaults to the texture of the
+        /// wood type.
+        texture: Option<ResourceLocation<'static>>,
+    },
+
+    /// A trident.
+    #[serde(rename = "minecraft:trident", alias = "trident")]
+    Trident,
+}
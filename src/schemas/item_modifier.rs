@@ -0,0 +1,674 @@
+//! Serde-(de)serializable data types for
+//! `data/<namespace>/item_modifiers/*.json`.
+//!
+//! Start here: [`ItemModifier`] and [`LootFunction`].
+//!
+//! The same functions are also used by loot tables.
+//!
+//! See <https://minecraft.fandom.com/wiki/Item_modifier>.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::ResourceLocation,
+    schemas::{
+        components::DataComponents,
+        loot_table::{LootEntry, NumberProvider},
+        predicate::{ItemPredicate, LootCondition, NumberRange},
+        reference::IdList,
+        text::TextComponent,
+    },
+};
+
+/// An item modifier as stored in the `data/<namespace>/item_modifiers/`
+/// directory.
+///
+/// An item modifier file contains either a single [`LootFunction`], or a list
+/// of functions that are applied in order. They are used by the `/item modify`
+/// command.
+///
+/// See also the corresponding section of the [wiki page].
+///
+/// [wiki page]: <https://minecraft.fandom.com/wiki/Item_modifier#JSON_format>
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::item_modifier::*;
+/// let modifier: ItemModifier = serde_json::from_str(r#"
+///     [
+///         { "function": "minecraft:set_count", "count": 3, "add": true },
+///         {
+///             "function": "minecraft:enchant_with_levels",
+///             "levels": { "min": 5, "max": 30 },
+///             "conditions": [
+///                 { "condition": "minecraft:random_chance", "chance": 0.5 }
+///             ]
+///         }
+///     ]
+/// "#).unwrap();
+///
+/// let functions = modifier.functions();
+/// assert_eq!(functions.len(), 2);
+/// assert_matches::assert_matches!(
+///     &functions[0].function,
+///     LootFunctionKind::SetCount { add: Some(true), .. }
+/// );
+/// assert_eq!(functions[1].conditions.len(), 1);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum ItemModifier {
+    /// An item modifier consisting of one function.
+    Single(LootFunction),
+
+    /// An item modifier consisting of a list of functions.
+    Multiple(Vec<LootFunction>),
+}
+
+impl ItemModifier {
+    /// Returns all of the functions of this item modifier as a slice.
+    ///
+    /// The slice will contain one element for a [`Single`][Self::Single]
+    /// modifier, and multiple for a [`Multiple`][Self::Multiple] modifier.
+    pub fn functions(&self) -> &[LootFunction] {
+        match self {
+            Self::Single(function) => std::slice::from_ref(function),
+            Self::Multiple(functions) => &functions[..],
+        }
+    }
+}
+
+impl Default for ItemModifier {
+    fn default() -> Self {
+        Self::Multiple(Vec::new())
+    }
+}
+
+/// A loot function that modifies the items produced by a loot table, pool, or
+/// entry, or by the `/item modify` command.
+///
+/// See also the corresponding section of the [wiki page].
+///
+/// [wiki page]: <https://minecraft.fandom.com/wiki/Item_modifier#JSON_format>
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct LootFunction {
+    /// Conditions that all have to pass for the function to be applied.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub conditions: Vec<LootCondition>,
+
+    /// The function and its parameters.
+    #[serde(flatten)]
+    pub function: LootFunctionKind,
+}
+
+impl From<LootFunctionKind> for LootFunction {
+    fn from(function: LootFunctionKind) -> Self {
+        Self {
+            conditions: Vec::new(),
+            function,
+        }
+    }
+}
+
+/// The kind of a [`LootFunction`], along with its parameters.
+///
+/// The function's `function` field determines which variant is used. Both the
+/// namespaced (`"minecraft:set_count"`) and bare (`"set_count"`) forms are
+/// accepted.
+///
+/// Fields containing NBT or data components are kept as raw JSON.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(tag = "function")]
+pub enum LootFunctionKind {
+    /// Applies a predefined bonus formula to the count of the item stack.
+    #[serde(rename = "minecraft:apply_bonus", alias = "apply_bonus")]
+    ApplyBonus {
+        /// The namespaced ID of the enchantment whose level is used.
+        enchantment: ResourceLocation<'static>,
+
+        /// The formula: `minecraft:binomial_with_bonus_count`,
+        /// `minecraft:uniform_bonus_count`, or `minecraft:ore_drops`.
+        formula: String,
+
+        /// The parameters of the formula.
+        parameters: Option<serde_json::Value>,
+    },
+
+    /// Copies data components from a block entity to the item.
+    ///
+    /// Added in 1.20.5.
+    #[serde(rename = "minecraft:copy_components", alias = "copy_components")]
+    CopyComponents {
+        /// The source to copy from, e.g., `block_entity`.
+        source: String,
+
+        /// The components to copy. If not present, all are copied.
+        include: Option<Vec<String>>,
+
+        /// The components not to copy.
+        exclude: Option<Vec<String>>,
+    },
+
+    /// Copies NBT into the `minecraft:custom_data` component of the item.
+    ///
+    /// Replaced `copy_nbt` in 1.20.5.
+    #[serde(rename = "minecraft:copy_custom_data", alias = "copy_custom_data")]
+    CopyCustomData {
+        /// The NBT provider to copy from.
+        source: serde_json::Value,
+
+        /// The copy operations.
+        ops: Vec<CopyOperation>,
+    },
+
+    /// Copies an entity's or block entity's name to the item.
+    #[serde(rename = "minecraft:copy_name", alias = "copy_name")]
+    CopyName {
+        /// The source to copy from: `this`, `killer`, `killer_player`, or
+        /// `block_entity`.
+        source: String,
+    },
+
+    /// Copies NBT into the item's NBT.
+    ///
+    /// Replaced by `copy_custom_data` in 1.20.5.
+    #[serde(rename = "minecraft:copy_nbt", alias = "copy_nbt")]
+    CopyNbt {
+        /// The NBT provider to copy from.
+        source: serde_json::Value,
+
+        /// The copy operations.
+        ops: Vec<CopyOperation>,
+    },
+
+    /// Copies block state properties to the item.
+    #[serde(rename = "minecraft:copy_state", alias = "copy_state")]
+    CopyState {
+        /// The namespaced ID of the block whose properties are copied.
+        block: ResourceLocation<'static>,
+
+        /// The names of the properties to copy.
+        properties: Vec<String>,
+    },
+
+    /// Removes the item.
+    ///
+    /// Added in 1.21.2.
+    #[serde(rename = "minecraft:discard", alias = "discard")]
+    Discard,
+
+    /// Adjusts the count of the item stack by a number of items per level of
+    /// an enchantment.
+    ///
+    /// Replaced `looting_enchant` in 1.21.
+    #[serde(
+        rename = "minecraft:enchanted_count_increase",
+        alias = "enchanted_count_increase"
+    )]
+    EnchantedCountIncrease {
+        /// The namespaced ID of the enchantment.
+        enchantment: ResourceLocation<'static>,
+
+        /// The number of additional items per level.
+        count: NumberProvider,
+
+        /// The maximum number of items in the stack after the increase. No
+        /// limit if `0` or not present.
+        limit: Option<i32>,
+    },
+
+    /// Enchants the item with one randomly-selected enchantment.
+    #[serde(rename = "minecraft:enchant_randomly", alias = "enchant_randomly")]
+    EnchantRandomly {
+        /// The enchantments to choose from. If not present, all enchantments
+        /// applicable to the item are possible.
+        ///
+        /// Replaced by `options` in 1.20.5.
+        enchantments: Option<Vec<String>>,
+
+        /// The enchantment(s) (or `#`-prefixed enchantment tag) to choose
+        /// from.
+        ///
+        /// Added in 1.20.5.
+        options: Option<IdList>,
+
+        /// Whether only enchantments compatible with the item are chosen.
+        ///
+        /// Added in 1.20.5.
+        only_compatible: Option<bool>,
+    },
+
+    /// Enchants the item, with the levels as with an enchanting table.
+    #[serde(
+        rename = "minecraft:enchant_with_levels",
+        alias = "enchant_with_levels"
+    )]
+    EnchantWithLevels {
+        /// The number of levels to enchant with.
+        levels: NumberProvider,
+
+        /// Whether treasure enchantments may be chosen.
+        ///
+        /// Replaced by `options` in 1.20.5.
+        treasure: Option<bool>,
+
+        /// The enchantment(s) (or `#`-prefixed enchantment tag) to choose
+        /// from.
+        ///
+        /// Added in 1.20.5.
+        options: Option<IdList>,
+    },
+
+    /// Converts an empty map into an explorer map leading to a nearby
+    /// structure.
+    #[serde(rename = "minecraft:exploration_map", alias = "exploration_map")]
+    ExplorationMap {
+        /// The `#`-prefixed structure tag to locate.
+        destination: Option<String>,
+
+        /// The map icon used to mark the structure.
+        decoration: Option<String>,
+
+        /// The zoom level of the map.
+        zoom: Option<u8>,
+
+        /// The size, in chunks, of the area to search for structures.
+        search_radius: Option<i32>,
+
+        /// Whether to skip existing chunks when searching.
+        skip_existing_chunks: Option<bool>,
+    },
+
+    /// Removes some items from the stack if it was produced by an explosion.
+    #[serde(rename = "minecraft:explosion_decay", alias = "explosion_decay")]
+    ExplosionDecay,
+
+    /// Sets the owner of a player head to the specified entity.
+    #[serde(rename = "minecraft:fill_player_head", alias = "fill_player_head")]
+    FillPlayerHead {
+        /// The entity to use as the owner.
+        entity: String,
+    },
+
+    /// Applies another function only to items that match a predicate.
+    ///
+    /// Added in 1.20.5.
+    #[serde(rename = "minecraft:filtered", alias = "filtered")]
+    Filtered {
+        /// The item predicate to test.
+        item_filter: Box<ItemPredicate>,
+
+        /// The functions to apply to matching items.
+        modifier: Box<ItemModifier>,
+    },
+
+    /// Smelts the item as it would be in a furnace.
+    #[serde(rename = "minecraft:furnace_smelt", alias = "furnace_smelt")]
+    FurnaceSmelt,
+
+    /// Limits the count of the item stack.
+    #[serde(rename = "minecraft:limit_count", alias = "limit_count")]
+    LimitCount {
+        /// The count limit (or range of limits).
+        limit: NumberRange,
+    },
+
+    /// Adjusts the count of the item stack based on the looting level of the
+    /// killer.
+    ///
+    /// Replaced by `enchanted_count_increase` in 1.21.
+    #[serde(rename = "minecraft:looting_enchant", alias = "looting_enchant")]
+    LootingEnchant {
+        /// The number of additional items per level.
+        count: NumberProvider,
+
+        /// The maximum number of items in the stack after the increase. No
+        /// limit if `0` or not present.
+        limit: Option<i32>,
+    },
+
+    /// Applies functions to every item inside a container component.
+    ///
+    /// Added in 1.20.5.
+    #[serde(rename = "minecraft:modify_contents", alias = "modify_contents")]
+    ModifyContents {
+        /// The container component to modify, e.g., `minecraft:bundle_contents`.
+        component: String,
+
+        /// The functions to apply to the contained items.
+        modifier: Box<ItemModifier>,
+    },
+
+    /// Applies the functions of another item modifier file.
+    #[serde(rename = "minecraft:reference", alias = "reference")]
+    Reference {
+        /// The namespaced ID of the item modifier.
+        name: ResourceLocation<'static>,
+    },
+
+    /// Applies a list of functions in order.
+    ///
+    /// Added in 1.20.5.
+    #[serde(rename = "minecraft:sequence", alias = "sequence")]
+    Sequence {
+        /// The functions to apply.
+        functions: Vec<LootFunction>,
+    },
+
+    /// Adds attribute modifiers to the item.
+    #[serde(rename = "minecraft:set_attributes", alias = "set_attributes")]
+    SetAttributes {
+        /// The attribute modifiers to add.
+        modifiers: Vec<serde_json::Value>,
+
+        /// Whether to replace the existing modifiers.
+        ///
+        /// Added in 1.20.5.
+        replace: Option<bool>,
+    },
+
+    /// Sets the banner patterns of a banner or shield.
+    #[serde(rename = "minecraft:set_banner_pattern", alias = "set_banner_pattern")]
+    SetBannerPattern {
+        /// The patterns to set.
+        patterns: Vec<serde_json::Value>,
+
+        /// Whether to append the patterns to the existing ones.
+        append: bool,
+    },
+
+    /// Sets the cover details of a written book.
+    ///
+    /// Added in 1.20.5.
+    #[serde(rename = "minecraft:set_book_cover", alias = "set_book_cover")]
+    SetBookCover {
+        /// The title of the book.
+        title: Option<serde_json::Value>,
+
+        /// The author of the book.
+        author: Option<String>,
+
+        /// The generation of the book (0 to 3).
+        generation: Option<u8>,
+    },
+
+    /// Sets data components of the item.
+    ///
+    /// Added in 1.20.5.
+    #[serde(rename = "minecraft:set_components", alias = "set_components")]
+    SetComponents {
+        /// The components to set.
+        components: DataComponents,
+    },
+
+    /// Sets the contents of a container block item.
+    #[serde(rename = "minecraft:set_contents", alias = "set_contents")]
+    SetContents {
+        /// The entries used to generate the contents.
+        entries: Vec<LootEntry>,
+
+        /// The block entity type of the container.
+        ///
+        /// Replaced by `component` in 1.20.5.
+        #[serde(rename = "type")]
+        kind: Option<String>,
+
+        /// The container component to set.
+        ///
+        /// Added in 1.20.5.
+        component: Option<String>,
+    },
+
+    /// Sets the count of the item stack.
+    #[serde(rename = "minecraft:set_count", alias = "set_count")]
+    SetCount {
+        /// The count to set (or add).
+        count: NumberProvider,
+
+        /// If `true`, the count is added to the current count.
+        add: Option<bool>,
+    },
+
+    /// Sets the `minecraft:custom_data` component of the item.
+    ///
+    /// Replaced `set_nbt` in 1.20.5.
+    #[serde(rename = "minecraft:set_custom_data", alias = "set_custom_data")]
+    SetCustomData {
+        /// The custom data, as an SNBT string or NBT object.
+        tag: serde_json::Value,
+    },
+
+    /// Sets the custom model data of the item.
+    #[serde(
+        rename = "minecraft:set_custom_model_data",
+        alias = "set_custom_model_data"
+    )]
+    SetCustomModelData {
+        /// The custom model data value.
+        ///
+        /// Replaced by `floats`, `flags`, `strings`, and `colors` in 1.21.4.
+        value: Option<NumberProvider>,
+
+        /// The remaining fields (1.21.4+), kept as raw JSON.
+        #[serde(flatten)]
+        fields: serde_json::Map<String, serde_json::Value>,
+    },
+
+    /// Sets the durability of the item, as a fraction of its maximum.
+    #[serde(rename = "minecraft:set_damage", alias = "set_damage")]
+    SetDamage {
+        /// The damage fraction to set (or add).
+        damage: NumberProvider,
+
+        /// If `true`, the damage is added to the current damage.
+        add: Option<bool>,
+    },
+
+    /// Sets the levels of enchantments on the item.
+    #[serde(rename = "minecraft:set_enchantments", alias = "set_enchantments")]
+    SetEnchantments {
+        /// Map of enchantment IDs to the level to set (or add).
+        enchantments: HashMap<String, NumberProvider>,
+
+        /// If `true`, the levels are added to the current levels.
+        add: Option<bool>,
+    },
+
+    /// Sets the firework explosion of a firework star.
+    ///
+    /// Added in 1.20.5.
+    #[serde(
+        rename = "minecraft:set_firework_explosion",
+        alias = "set_firework_explosion"
+    )]
+    SetFireworkExplosion {
+        /// The fields of the function, kept as raw JSON.
+        #[serde(flatten)]
+        fields: serde_json::Map<String, serde_json::Value>,
+    },
+
+    /// Sets the explosions and flight duration of a firework rocket.
+    ///
+    /// Added in 1.20.5.
+    #[serde(rename = "minecraft:set_fireworks", alias = "set_fireworks")]
+    SetFireworks {
+        /// The fields of the function, kept as raw JSON.
+        #[serde(flatten)]
+        fields: serde_json::Map<String, serde_json::Value>,
+    },
+
+    /// Sets the instrument of a goat horn.
+    #[serde(rename = "minecraft:set_instrument", alias = "set_instrument")]
+    SetInstrument {
+        /// The `#`-prefixed instrument tag to choose from.
+        options: String,
+    },
+
+    /// Replaces the item type, keeping count and components.
+    ///
+    /// Added in 1.21.
+    #[serde(rename = "minecraft:set_item", alias = "set_item")]
+    SetItem {
+        /// The namespaced ID of the new item.
+        item: ResourceLocation<'static>,
+    },
+
+    /// Sets the loot table of a container block item.
+    #[serde(rename = "minecraft:set_loot_table", alias = "set_loot_table")]
+    SetLootTable {
+        /// The namespaced ID of the loot table.
+        name: ResourceLocation<'static>,
+
+        /// The seed of the loot table. `0` or not present means random.
+        seed: Option<i64>,
+
+        /// The block entity type of the container.
+        #[serde(rename = "type")]
+        kind: Option<String>,
+    },
+
+    /// Adds lore to the item.
+    #[serde(rename = "minecraft:set_lore", alias = "set_lore")]
+    SetLore {
+        /// The lines of lore, as JSON text components.
+        lore: Vec<TextComponent>,
+
+        /// The entity used to resolve the text components.
+        entity: Option<String>,
+
+        /// If `true`, the existing lore is replaced.
+        ///
+        /// Replaced by `mode` in 1.20.5.
+        replace: Option<bool>,
+
+        /// How the lore is merged with the existing lore, e.g.,
+        /// `replace_all` or `append`.
+        ///
+        /// Added in 1.20.5.
+        mode: Option<String>,
+
+        /// The line to start at for `insert` and `replace_section`.
+        offset: Option<i32>,
+
+        /// The number of lines to replace for `replace_section`.
+        size: Option<i32>,
+    },
+
+    /// Sets the name of the item.
+    #[serde(rename = "minecraft:set_name", alias = "set_name")]
+    SetName {
+        /// The name, as a JSON text component.
+        name: Option<TextComponent>,
+
+        /// The entity used to resolve the text component.
+        entity: Option<String>,
+
+        /// The name component to set: `custom_name` or `item_name`.
+        ///
+        /// Added in 1.20.5.
+        target: Option<String>,
+    },
+
+    /// Adds NBT data to the item.
+    ///
+    /// Replaced by `set_custom_data` in 1.20.5.
+    #[serde(rename = "minecraft:set_nbt", alias = "set_nbt")]
+    SetNbt {
+        /// The NBT data, as an SNBT string, which can be parsed with
+        /// [`SnbtValue::parse()`][crate::schemas::snbt::SnbtValue::parse].
+        tag: String,
+    },
+
+    /// Sets the amplifier of an ominous bottle.
+    ///
+    /// Added in 1.20.5.
+    #[serde(
+        rename = "minecraft:set_ominous_bottle_amplifier",
+        alias = "set_ominous_bottle_amplifier"
+    )]
+    SetOminousBottleAmplifier {
+        /// The amplifier to set.
+        amplifier: NumberProvider,
+    },
+
+    /// Sets the potion of the item.
+    #[serde(rename = "minecraft:set_potion", alias = "set_potion")]
+    SetPotion {
+        /// The namespaced ID of the potion.
+        id: ResourceLocation<'static>,
+    },
+
+    /// Sets the status effects of a suspicious stew.
+    #[serde(rename = "minecraft:set_stew_effect", alias = "set_stew_effect")]
+    SetStewEffect {
+        /// The effects to choose from.
+        effects: Vec<StewEffect>,
+    },
+
+    /// Sets the pages of a book and quill.
+    ///
+    /// Added in 1.20.5.
+    #[serde(
+        rename = "minecraft:set_writable_book_pages",
+        alias = "set_writable_book_pages"
+    )]
+    SetWritableBookPages {
+        /// The fields of the function, kept as raw JSON.
+        #[serde(flatten)]
+        fields: serde_json::Map<String, serde_json::Value>,
+    },
+
+    /// Sets the pages of a written book.
+    ///
+    /// Added in 1.20.5.
+    #[serde(
+        rename = "minecraft:set_written_book_pages",
+        alias = "set_written_book_pages"
+    )]
+    SetWrittenBookPages {
+        /// The fields of the function, kept as raw JSON.
+        #[serde(flatten)]
+        fields: serde_json::Map<String, serde_json::Value>,
+    },
+
+    /// Toggles the visibility of parts of the item's tooltip.
+    ///
+    /// Added in 1.20.5.
+    #[serde(rename = "minecraft:toggle_tooltips", alias = "toggle_tooltips")]
+    ToggleTooltips {
+        /// Map of component IDs to whether their tooltip is shown.
+        toggles: HashMap<String, bool>,
+    },
+}
+
+/// A copy operation of the `copy_nbt` and `copy_custom_data` functions.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CopyOperation {
+    /// The NBT path to copy from.
+    pub source: String,
+
+    /// The NBT path to copy to.
+    pub target: String,
+
+    /// The merge strategy: `replace`, `append`, or `merge`.
+    pub op: String,
+}
+
+/// A status effect of the `set_stew_effect` function.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct StewEffect {
+    /// The namespaced ID of the effect.
+    #[serde(rename = "type")]
+    pub kind: ResourceLocation<'static>,
+
+    /// The duration of the effect, in seconds.
+    pub duration: NumberProvider,
+}
@@ -0,0 +1,83 @@
+//! Serde-(de)serializable data types for
+//! `data/<namespace>/jukebox_song/*.json`.
+//!
+//! Start here: [`JukeboxSong`].
+//!
+//! Jukebox songs became data-driven in 1.21.
+//!
+//! See <https://minecraft.fandom.com/wiki/Jukebox_song_definition>.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::ResourceLocation,
+    schemas::{text::TextComponent, MaybeReference},
+};
+
+/// A jukebox song as stored in the `data/<namespace>/jukebox_song/`
+/// directory.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::jukebox_song::*;
+/// let song: JukeboxSong = serde_json::from_str(r#"
+///     {
+///         "sound_event": "minecraft:music_disc.cat",
+///         "description": { "translate": "jukebox_song.minecraft.cat" },
+///         "length_in_seconds": 185.0,
+///         "comparator_output": 2
+///     }
+/// "#).unwrap();
+///
+/// assert_eq!(song.sound_event.reference(), Some("minecraft:music_disc.cat"));
+/// assert_eq!(song.comparator_output, 2);
+///
+/// let song: JukeboxSong = serde_json::from_str(r#"
+///     {
+///         "sound_event": { "sound_id": "mypack:my_song", "range": 32.0 },
+///         "description": "My Song",
+///         "length_in_seconds": 60.0,
+///         "comparator_output": 15
+///     }
+/// "#).unwrap();
+///
+/// assert_eq!(song.sound_event.inline().unwrap().range, Some(32.0));
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct JukeboxSong {
+    /// The sound event played, either as a namespaced ID or inline.
+    pub sound_event: MaybeReference<SoundEvent>,
+
+    /// The description of the song shown in the item tooltip and the "Now
+    /// Playing" message, as a JSON text component.
+    pub description: TextComponent,
+
+    /// The length of the song, in seconds.
+    pub length_in_seconds: f32,
+
+    /// The redstone signal strength output by a comparator reading a jukebox
+    /// playing this song (0 to 15).
+    pub comparator_output: u8,
+
+    /// The fields that this crate does not model (e.g., fields added by mods
+    /// or by newer versions), kept so that they survive a round trip.
+    ///
+    /// Requires the `preserve-unknown` feature.
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten, serialize_with = "crate::schemas::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// An inline sound event definition.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SoundEvent {
+    /// The resource location of the sound, as defined in `sounds.json`.
+    pub sound_id: ResourceLocation<'static>,
+
+    /// The fixed range of the sound, in blocks. If not present, the range
+    /// depends on the volume.
+    pub range: Option<f32>,
+}
@@ -0,0 +1,177 @@
+//! Serde-(de)serializable data types for `assets/<namespace>/lang/*.json`.
+//!
+//! Start here: [`LanguageFile`].
+//!
+//! Before 1.13, language files were `key=value` text files with the `.lang`
+//! extension (e.g., `en_US.lang`), which are parsed with
+//! [`LanguageFile::from_legacy()`].
+//!
+//! See <https://minecraft.fandom.com/wiki/Resource_pack#Language>.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// A language file as stored in the `assets/<namespace>/lang/` directory,
+/// e.g., `en_us.json`.
+///
+/// A language file maps translation keys to translated strings. Translated
+/// strings may contain `%s` and `%1$s` format arguments; see
+/// [`Translator`][crate::api::Translator] for formatting them.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::lang::*;
+/// let lang: LanguageFile = serde_json::from_str(r#"
+///     {
+///         "block.minecraft.stone": "Stone",
+///         "death.attack.fall": "%1$s hit the ground too hard"
+///     }
+/// "#).unwrap();
+///
+/// assert_eq!(lang.get("block.minecraft.stone"), Some("Stone"));
+/// assert_eq!(lang.get("block.minecraft.dirt"), None);
+/// assert_eq!(lang.len(), 2);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+pub struct LanguageFile {
+    /// Map of translation keys to translated strings.
+    pub translations: HashMap<String, String>,
+}
+
+impl LanguageFile {
+    /// Returns the translated string for the given key, if present.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.translations.get(key).map(String::as_str)
+    }
+
+    /// Returns the number of translations in the file.
+    pub fn len(&self) -> usize {
+        self.translations.len()
+    }
+
+    /// Returns `true` if the file contains no translations.
+    pub fn is_empty(&self) -> bool {
+        self.translations.is_empty()
+    }
+
+    /// Parses the contents of a legacy `.lang` file, used before 1.13.
+    ///
+    /// Like in the game, each line is split at its first `=`, and empty lines,
+    /// lines that start with `#`, and lines without a `=` are ignored. The
+    /// `%d` and `%.2f` format arguments of the old format are converted into
+    /// `%s` arguments, keeping their index (e.g., `%2$d` becomes `%2$s`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::lang::*;
+    /// let lang = LanguageFile::from_legacy("
+    /// ## Blocks
+    /// tile.stone.stone.name=Stone
+    /// potion.effectDuration=%1$s (%2$d:%3$.2f)
+    /// gui.progress=100% of %d
+    /// ");
+    ///
+    /// assert_eq!(lang.get("tile.stone.stone.name"), Some("Stone"));
+    /// assert_eq!(lang.get("potion.effectDuration"), Some("%1$s (%2$s:%3$s)"));
+    /// assert_eq!(lang.get("gui.progress"), Some("100% of %s"));
+    /// assert_eq!(lang.len(), 3);
+    /// ```
+    pub fn from_legacy(text: &str) -> Self {
+        let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+        let translations = text
+            .lines()
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| line.split_once('='))
+            .map(|(key, value)| (String::from(key), legacy_format_to_s(value)))
+            .collect();
+
+        Self { translations }
+    }
+
+    /// Returns the contents of a legacy `.lang` file with the translations of
+    /// this file, sorted by key.
+    ///
+    /// Since each translation is a single line, line breaks in translated
+    /// strings are written as `\n` escapes, which the game does not unescape.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::lang::*;
+    /// let mut lang = LanguageFile::default();
+    /// lang.translations.insert("tile.dirt.name".into(), "Dirt".into());
+    /// lang.translations.insert("tile.stone.stone.name".into(), "Stone".into());
+    ///
+    /// assert_eq!(lang.to_legacy(), "tile.dirt.name=Dirt\ntile.stone.stone.name=Stone\n");
+    /// assert_eq!(LanguageFile::from_legacy(&lang.to_legacy()), lang);
+    /// ```
+    pub fn to_legacy(&self) -> String {
+        let mut keys: Vec<_> = self.translations.keys().collect();
+        keys.sort();
+
+        let mut text = String::new();
+        for key in keys {
+            text.push_str(key);
+            text.push('=');
+            text.push_str(&self.translations[key].replace('\n', "\\n"));
+            text.push('\n');
+        }
+        text
+    }
+}
+
+/// Replaces the `%d` and `%f` format arguments (with an optional index,
+/// width, and precision) of a legacy translated string with `%s` arguments,
+/// like the `%(\d+\$)?[\d.]*[df]` pattern of the game.
+fn legacy_format_to_s(value: &str) -> String {
+    let mut converted = String::with_capacity(value.len());
+    let mut rest = value;
+
+    while let Some(start) = rest.find('%') {
+        converted.push_str(&rest[..start]);
+        let argument = &rest[start + 1..];
+
+        // An optional `<index>$` followed by a width and precision.
+        let digits = argument.len()
+            - argument
+                .trim_start_matches(|c: char| c.is_ascii_digit())
+                .len();
+        let index = if digits > 0 && argument[digits..].starts_with('$') {
+            digits + 1
+        } else {
+            0
+        };
+        let after_index = &argument[index..];
+        let spec = after_index.len()
+            - after_index
+                .trim_start_matches(|c: char| c.is_ascii_digit() || c == '.')
+                .len();
+
+        match after_index[spec..].chars().next() {
+            Some('d') | Some('f') => {
+                converted.push('%');
+                converted.push_str(&argument[..index]);
+                converted.push('s');
+                rest = &after_index[spec + 1..];
+            }
+            _ => {
+                converted.push('%');
+                rest = argument;
+            }
+        }
+    }
+
+    converted.push_str(rest);
+    converted
+}
+
+impl From<HashMap<String, String>> for LanguageFile {
+    fn from(translations: HashMap<String, String>) -> Self {
+        Self { translations }
+    }
+}
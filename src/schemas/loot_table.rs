@@ -0,0 +1,309 @@
+//! Serde-(de)serializable data types for
+//! `data/<namespace>/loot_tables/**/*.json`.
+//!
+//! Start here: [`LootTable`].
+//!
+//! See <https://minecraft.fandom.com/wiki/Loot_table>.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{api::ResourceLocation, schemas::MaybeReference};
+
+pub use crate::schemas::{
+    item_modifier::LootFunction,
+    predicate::LootCondition,
+    providers::{NumberProvider, TypedNumberProvider},
+};
+
+/// A loot table as stored in the `data/<namespace>/loot_tables/` directory.
+///
+/// Loot tables are organized in subdirectories by what they are used for,
+/// e.g., `blocks/stone.json`, `chests/simple_dungeon.json`, or
+/// `entities/zombie.json`.
+///
+/// See also the corresponding section of the [wiki page].
+///
+/// [wiki page]: <https://minecraft.fandom.com/wiki/Loot_table#JSON_format>
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::loot_table::*;
+/// let table: LootTable = serde_json::from_str(r#"
+///     {
+///         "type": "minecraft:block",
+///         "pools": [
+///             {
+///                 "rolls": 1.0,
+///                 "bonus_rolls": 0.0,
+///                 "entries": [
+///                     { "type": "minecraft:item", "name": "minecraft:cobblestone" }
+///                 ],
+///                 "conditions": [
+///                     { "condition": "minecraft:survives_explosion" }
+///                 ]
+///             }
+///         ]
+///     }
+/// "#).unwrap();
+///
+/// assert_eq!(table.kind.unwrap(), "minecraft:block");
+///
+/// let pool = &table.pools[0];
+/// assert_eq!(pool.rolls, NumberProvider::Constant(1.0));
+/// assert_matches::assert_matches!(
+///     &pool.entries[0],
+///     LootEntry::Item { name, .. } if name == "minecraft:cobblestone"
+/// );
+/// ```
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct LootTable {
+    /// The loot context type (e.g., `minecraft:block`, `minecraft:chest`)
+    /// that the loot table should be invoked in.
+    ///
+    /// Used to validate which conditions and functions are allowed.
+    #[serde(rename = "type")]
+    pub kind: Option<ResourceLocation<'static>>,
+
+    /// Functions that are applied to all items produced by this table.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub functions: Vec<LootFunction>,
+
+    /// The pools of the loot table.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pools: Vec<LootPool>,
+
+    /// The ID of the random sequence used to generate loot from this table.
+    ///
+    /// Added in 1.20.
+    pub random_sequence: Option<ResourceLocation<'static>>,
+
+    /// The fields that this crate does not model (e.g., fields added by mods
+    /// or by newer versions), kept so that they survive a round trip.
+    ///
+    /// Requires the `preserve-unknown` feature.
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten, serialize_with = "crate::schemas::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// A single pool of a [`LootTable`].
+///
+/// Each pool rolls a number of times, and each time picks one entry from its
+/// list of [`LootEntry`]s according to their weights.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct LootPool {
+    /// Conditions that all have to pass for the pool to be used.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub conditions: Vec<LootCondition>,
+
+    /// Functions that are applied to all items produced by this pool.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub functions: Vec<LootFunction>,
+
+    /// The number of rolls on the pool.
+    pub rolls: NumberProvider,
+
+    /// The number of bonus rolls on the pool per point of luck.
+    ///
+    /// Rounded down after multiplying.
+    #[serde(default = "LootPool::default_bonus_rolls")]
+    pub bonus_rolls: NumberProvider,
+
+    /// The list of entries to choose from.
+    #[serde(default)]
+    pub entries: Vec<LootEntry>,
+}
+
+impl LootPool {
+    pub(crate) const fn default_bonus_rolls() -> NumberProvider {
+        NumberProvider::Constant(0.0)
+    }
+}
+
+impl Default for LootPool {
+    fn default() -> Self {
+        Self {
+            conditions: Default::default(),
+            functions: Default::default(),
+            rolls: NumberProvider::Constant(1.0),
+            bonus_rolls: Self::default_bonus_rolls(),
+            entries: Default::default(),
+        }
+    }
+}
+
+/// A single entry in a [`LootPool`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(tag = "type")]
+pub enum LootEntry {
+    /// Produces a single item.
+    #[serde(rename = "minecraft:item", alias = "item")]
+    Item {
+        /// The namespaced ID of the item to produce.
+        name: ResourceLocation<'static>,
+
+        /// Properties common to all single-producing entries.
+        #[serde(flatten)]
+        properties: SingletonProperties,
+    },
+
+    /// Produces items from an item tag.
+    #[serde(rename = "minecraft:tag", alias = "tag")]
+    Tag {
+        /// The namespaced ID of the item tag (without the `#`).
+        name: ResourceLocation<'static>,
+
+        /// If `true`, chooses one item of the tag, each with the same weight
+        /// and quality. If `false`, generates one of each of the items in the
+        /// tag.
+        expand: bool,
+
+        /// Properties common to all single-producing entries.
+        #[serde(flatten)]
+        properties: SingletonProperties,
+    },
+
+    /// Produces items from another loot table.
+    #[serde(rename = "minecraft:loot_table", alias = "loot_table")]
+    LootTable {
+        /// The namespaced ID of the loot table to use.
+        ///
+        /// Since 1.20.5 this field is called `value` and can also be an inline
+        /// loot table.
+        #[serde(alias = "value")]
+        name: MaybeReference<Box<LootTable>>,
+
+        /// Properties common to all single-producing entries.
+        #[serde(flatten)]
+        properties: SingletonProperties,
+    },
+
+    /// Produces block-specific drops (e.g., the contents of a shulker box).
+    #[serde(rename = "minecraft:dynamic", alias = "dynamic")]
+    Dynamic {
+        /// Can be `contents` for block entity contents, or `self` for
+        /// decorated pots.
+        name: String,
+
+        /// Properties common to all single-producing entries.
+        #[serde(flatten)]
+        properties: SingletonProperties,
+    },
+
+    /// Produces nothing.
+    #[serde(rename = "minecraft:empty", alias = "empty")]
+    Empty {
+        /// Properties common to all single-producing entries.
+        #[serde(flatten)]
+        properties: SingletonProperties,
+    },
+
+    /// Tests the children in order, picking the first one that can be used.
+    #[serde(rename = "minecraft:alternatives", alias = "alternatives")]
+    Alternatives {
+        /// The entries to test.
+        children: Vec<LootEntry>,
+
+        /// Conditions that all have to pass for the entry to be used.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        conditions: Vec<LootCondition>,
+    },
+
+    /// Uses each of the children in order until one cannot be used.
+    #[serde(rename = "minecraft:sequence", alias = "sequence")]
+    Sequence {
+        /// The entries to use.
+        children: Vec<LootEntry>,
+
+        /// Conditions that all have to pass for the entry to be used.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        conditions: Vec<LootCondition>,
+    },
+
+    /// Uses all of the children that can be used.
+    #[serde(rename = "minecraft:group", alias = "group")]
+    Group {
+        /// The entries to use.
+        children: Vec<LootEntry>,
+
+        /// Conditions that all have to pass for the entry to be used.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        conditions: Vec<LootCondition>,
+    },
+}
+
+impl LootEntry {
+    /// Returns the conditions of this entry.
+    pub fn conditions(&self) -> &[LootCondition] {
+        match self {
+            Self::Item { properties, .. }
+            | Self::Tag { properties, .. }
+            | Self::LootTable { properties, .. }
+            | Self::Dynamic { properties, .. }
+            | Self::Empty { properties } => &properties.conditions[..],
+            Self::Alternatives { conditions, .. }
+            | Self::Sequence { conditions, .. }
+            | Self::Group { conditions, .. } => &conditions[..],
+        }
+    }
+
+    /// Returns the child entries of a composite entry, or `None` if this entry
+    /// produces items itself.
+    pub fn children(&self) -> Option<&[LootEntry]> {
+        match self {
+            Self::Alternatives { children, .. }
+            | Self::Sequence { children, .. }
+            | Self::Group { children, .. } => Some(&children[..]),
+            _ => None,
+        }
+    }
+}
+
+/// Properties shared by all [`LootEntry`] types that produce items
+/// themselves (as opposed to composite entries).
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SingletonProperties {
+    /// Conditions that all have to pass for the entry to be used.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub conditions: Vec<LootCondition>,
+
+    /// Functions applied to the items produced by this entry.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub functions: Vec<LootFunction>,
+
+    /// Determines how often the entry is chosen out of all entries in the
+    /// pool.
+    ///
+    /// Entries with higher weights are used more often. Defaults to `1`.
+    #[serde(default = "SingletonProperties::default_weight")]
+    pub weight: u32,
+
+    /// Modifies the entry's weight based on the luck attribute.
+    ///
+    /// The formula is `floor(weight + (quality * generic.luck))`. Defaults to
+    /// `0`.
+    #[serde(default)]
+    pub quality: i32,
+}
+
+impl SingletonProperties {
+    pub(crate) const fn default_weight() -> u32 {
+        1
+    }
+}
+
+impl Default for SingletonProperties {
+    fn default() -> Self {
+        Self {
+            conditions: Default::default(),
+            functions: Default::default(),
+            weight: Self::default_weight(),
+            quality: 0,
+        }
+    }
+}
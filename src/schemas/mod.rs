@@ -1,10 +1,192 @@
 //! Serde-(de)serializable data types for files in the Minecraft `assets/`
-//! directory.
+//! and `data/` directories.
+//!
+//! ## Unknown fields
+//!
+//! By default, fields that the schemas do not model are ignored when
+//! deserializing, and are lost when the data is serialized again. With the
+//! `preserve-unknown` feature, the top-level types (and the elements and faces
+//! of [`Model`]s, and the [`Effects`][worldgen::biome::Effects] of biomes) keep
+//! them in an `extra` map instead.
+//!
+//! ```
+//! # #[cfg(feature = "preserve-unknown")]
+//! # {
+//! use minecraft_assets::schemas::Tag;
+//!
+//! let json = r#"{"values":["minecraft:stone"],"replace":false,"mymod:priority":3}"#;
+//! let tag: Tag = serde_json::from_str(json).unwrap();
+//! assert_eq!(tag.extra["mymod:priority"], 3);
+//!
+//! let round_trip = serde_json::to_value(&tag).unwrap();
+//! assert_eq!(round_trip, serde_json::from_str::<serde_json::Value>(json).unwrap());
+//! # }
+//! ```
+//!
+//! ## JSON Schemas
+//!
+//! With the `schemars` feature, the schemas implement
+//! `schemars::JsonSchema`, so that JSON Schemas of the files of resource packs
+//! and data packs can be generated, e.g., for editors or for validating packs
+//! in CI. Structure templates are NBT files, and the OptiFine properties are
+//! `.properties` files, so they have none.
+//!
+//! ```
+//! # #[cfg(feature = "schemars")]
+//! # {
+//! use minecraft_assets::schemas::Model;
+//!
+//! let schema = schemars::schema_for!(Model);
+//! let schema = serde_json::to_value(&schema).unwrap();
+//! assert_eq!(schema["title"], "Model");
+//! assert!(schema["properties"]["ambientocclusion"].is_object());
+//! # }
+//! ```
 
+pub mod advancement;
+pub mod asset_index;
+pub mod atlas;
+pub mod banner_pattern;
+pub mod bedrock;
+pub mod block;
 pub mod blockstates;
-pub mod texture;
+pub mod borrowed;
+pub mod canonical;
+pub mod chat_type;
+pub mod color;
+pub mod components;
+pub mod damage_type;
+pub mod enchantment;
+pub mod equipment;
+pub mod font;
+pub mod function;
+pub mod glyphs;
+pub mod gpu_warnlist;
+pub mod height;
+pub mod item_definition;
+pub mod item_modifier;
+pub mod jukebox_song;
+pub mod lang;
+pub mod loot_table;
 pub mod models;
+pub mod optifine;
+pub mod pack_meta;
+pub mod painting_variant;
+pub mod particle;
+pub mod predicate;
+pub mod providers;
+pub mod recipe;
+pub mod reference;
+pub mod regional_compliancies;
+pub mod reports;
+pub mod shader;
+pub mod snbt;
+pub mod sounds;
+pub mod strict;
+#[cfg(feature = "nbt")]
+pub mod structure;
+pub mod tags;
+pub mod text;
+pub mod texture;
+pub mod trim_material;
+pub mod trim_pattern;
+pub mod wolf_variant;
 pub mod worldgen;
 
+pub use advancement::Advancement;
+pub use asset_index::AssetIndex;
+pub use atlas::Atlas;
+pub use banner_pattern::BannerPattern;
 pub use blockstates::BlockStates;
+pub use chat_type::ChatType;
+pub use color::Rgb;
+pub use damage_type::DamageType;
+pub use enchantment::Enchantment;
+pub use equipment::Equipment;
+pub use font::Font;
+pub use function::Function;
+pub use gpu_warnlist::GpuWarnlist;
+pub use item_definition::ItemDefinition;
+pub use item_modifier::ItemModifier;
+pub use jukebox_song::JukeboxSong;
+pub use lang::LanguageFile;
+pub use loot_table::LootTable;
 pub use models::Model;
+pub use pack_meta::PackMeta;
+pub use painting_variant::PaintingVariant;
+pub use particle::Particle;
+pub use predicate::Predicate;
+pub use recipe::Recipe;
+pub use reference::{MaybeReference, MaybeReferenceList};
+pub use regional_compliancies::RegionalCompliancies;
+pub use reports::{BlocksReport, RegistriesReport};
+pub use snbt::SnbtValue;
+pub use sounds::SoundsJson;
+#[cfg(feature = "nbt")]
+pub use structure::StructureTemplate;
+pub use tags::Tag;
+pub use trim_material::TrimMaterial;
+pub use trim_pattern::TrimPattern;
+pub use wolf_variant::WolfVariant;
+
+#[cfg(all(test, feature = "schemars"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn generates_json_schemas_of_top_level_types() {
+        macro_rules! check {
+            ($($schema:ty,)*) => {
+                $(
+                    let schema = serde_json::to_value(schemars::schema_for!($schema)).unwrap();
+                    assert!(schema.is_object(), "{}", stringify!($schema));
+                )*
+            };
+        }
+
+        check! {
+            Advancement,
+            AssetIndex,
+            Atlas,
+            BannerPattern,
+            BlockStates,
+            ChatType,
+            DamageType,
+            Enchantment,
+            Equipment,
+            Font,
+            GpuWarnlist,
+            ItemDefinition,
+            ItemModifier,
+            JukeboxSong,
+            LanguageFile,
+            LootTable,
+            Model,
+            PackMeta,
+            PaintingVariant,
+            Particle,
+            Predicate,
+            Recipe,
+            RegionalCompliancies,
+            BlocksReport,
+            RegistriesReport,
+            SoundsJson,
+            Tag,
+            TrimMaterial,
+            TrimPattern,
+            WolfVariant,
+            shader::ShaderProgram,
+            shader::PostChain,
+            shader::PostEffect,
+            texture::Texture,
+            worldgen::biome::CustomeBiome,
+            worldgen::multi_noise_biome_source_parameter_list::MultiNoiseBiomeSourceParameterList,
+            borrowed::ModelRef<'static>,
+            borrowed::BlockStatesRef<'static>,
+            bedrock::Manifest,
+            bedrock::BlocksJson,
+            bedrock::TextureAtlas,
+            bedrock::GeometryFile,
+        }
+    }
+}
@@ -13,6 +13,11 @@ use std::{
 
 use serde::{Deserialize, Serialize};
 
+use crate::{
+    api::{InvalidIdentifier, ResourceLocation},
+    schemas::strict,
+};
+
 /// A block or item model as stored in the
 /// `assets/<namespace>/models/{block,item}/` directories.
 ///
@@ -20,6 +25,8 @@ use serde::{Deserialize, Serialize};
 ///
 /// [wiki page]: <https://minecraft.fandom.com/wiki/Model#Block_models>
 #[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "bevy", derive(bevy::asset::Asset, bevy::reflect::TypePath))]
 pub struct Model {
     /// Specifies that this model should inherit fields from the model at the
     /// given [resource location]. If both `parent` and `elements` are set, the
@@ -36,7 +43,7 @@ pub struct Model {
     ///     tridents).
     ///
     /// [resource location]: <https://minecraft.fandom.com/wiki/Model#File_path>
-    pub parent: Option<String>,
+    pub parent: Option<ResourceLocation<'static>>,
 
     /// Contains the different places where item models are displayed in
     /// different views.
@@ -80,10 +87,62 @@ pub struct Model {
     ///
     /// **Applies only to item models.**
     pub overrides: Option<Vec<OverrideCase>>,
+
+    /// The fields that this crate does not model (e.g., fields added by mods
+    /// or by newer versions), kept so that they survive a round trip.
+    ///
+    /// Requires the `preserve-unknown` feature.
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten, serialize_with = "crate::schemas::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Model {
+    /// Returns the model that the game uses instead of this item model, given
+    /// the values of the item's predicates (e.g., `damage`, `pull`, or
+    /// `custom_model_data`), or `None` if no [override](Self::overrides)
+    /// matches.
+    ///
+    /// Like in the game, the last matching override wins, and the overrides
+    /// of the returned model are not applied in turn. See
+    /// [`OverrideCase::matches()`] for how the predicates are matched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::models::*;
+    /// use maplit::hashmap;
+    ///
+    /// let bow: Model = serde_json::from_str(r#"{
+    ///     "parent": "item/generated",
+    ///     "overrides": [
+    ///         { "predicate": { "pulling": 1 }, "model": "item/bow_pulling_0" },
+    ///         { "predicate": { "pulling": 1, "pull": 0.65 }, "model": "item/bow_pulling_1" },
+    ///         { "predicate": { "pulling": 1, "pull": 0.9 }, "model": "item/bow_pulling_2" }
+    ///     ]
+    /// }"#).unwrap();
+    ///
+    /// assert_eq!(bow.resolve_override(&hashmap! {}), None);
+    ///
+    /// let values = hashmap! { "pulling".to_string() => 1.0, "pull".to_string() => 0.7 };
+    /// assert_eq!(bow.resolve_override(&values), Some("item/bow_pulling_1"));
+    ///
+    /// let values = hashmap! { "minecraft:pulling".to_string() => 1.0, "pull".to_string() => 1.0 };
+    /// assert_eq!(bow.resolve_override(&values), Some("item/bow_pulling_2"));
+    /// ```
+    pub fn resolve_override(&self, values: &HashMap<String, f32>) -> Option<&str> {
+        self.overrides
+            .iter()
+            .flatten()
+            .rev()
+            .find(|case| case.matches(values))
+            .map(|case| case.model.as_str())
+    }
 }
 
 /// Specifies how a [`Model`] is displayed in different views.
 #[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Display {
     /// How the model is displayed when held in the right hand in third-person
     /// view.
@@ -114,14 +173,97 @@ pub struct Display {
     pub fixed: Option<Transform>,
 }
 
+impl Display {
+    /// Returns the transform of the given context, if the model has one.
+    ///
+    /// Like in the game, the left hand contexts fall back to the transforms of
+    /// the right hand.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::models::*;
+    /// let display: Display = serde_json::from_str(r#"{
+    ///     "thirdperson_righthand": { "rotation": [0, 90, 0] }
+    /// }"#).unwrap();
+    ///
+    /// let transform = display.get(DisplayContext::ThirdPersonLeftHand).unwrap();
+    /// assert_eq!(transform.rotation, [0.0, 90.0, 0.0]);
+    /// assert_eq!(display.get(DisplayContext::Gui), None);
+    /// ```
+    pub fn get(&self, context: DisplayContext) -> Option<&Transform> {
+        match context {
+            DisplayContext::ThirdPersonRightHand => self.thirdperson_righthand.as_ref(),
+            DisplayContext::ThirdPersonLeftHand => self
+                .thirdperson_lefthand
+                .as_ref()
+                .or(self.thirdperson_righthand.as_ref()),
+            DisplayContext::FirstPersonRightHand => self.firstperson_righthand.as_ref(),
+            DisplayContext::FirstPersonLeftHand => self
+                .firstperson_lefthand
+                .as_ref()
+                .or(self.firstperson_righthand.as_ref()),
+            DisplayContext::Gui => self.gui.as_ref(),
+            DisplayContext::Head => self.head.as_ref(),
+            DisplayContext::Ground => self.ground.as_ref(),
+            DisplayContext::Fixed => self.fixed.as_ref(),
+        }
+    }
+}
+
+/// One of the places where a model is displayed, each with its own
+/// [`Transform`] in a model's [`Display`].
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+#[allow(missing_docs)]
+pub enum DisplayContext {
+    #[serde(rename = "thirdperson_righthand")]
+    ThirdPersonRightHand,
+    #[serde(rename = "thirdperson_lefthand")]
+    ThirdPersonLeftHand,
+    #[serde(rename = "firstperson_righthand")]
+    FirstPersonRightHand,
+    #[serde(rename = "firstperson_lefthand")]
+    FirstPersonLeftHand,
+    Gui,
+    Head,
+    Ground,
+    Fixed,
+}
+
+impl DisplayContext {
+    /// Every display context, in the order of the fields of [`Display`].
+    pub const ALL: [DisplayContext; 8] = [
+        Self::ThirdPersonRightHand,
+        Self::ThirdPersonLeftHand,
+        Self::FirstPersonRightHand,
+        Self::FirstPersonLeftHand,
+        Self::Gui,
+        Self::Head,
+        Self::Ground,
+        Self::Fixed,
+    ];
+
+    /// Returns `true` if the model is held in the left hand, in which case
+    /// the game mirrors its transform.
+    pub fn is_left_hand(&self) -> bool {
+        matches!(self, Self::ThirdPersonLeftHand | Self::FirstPersonLeftHand)
+    }
+}
+
 /// Specifies the position, rotation, and scale at which a model is displayed.
 ///
 /// Note that translations are applied to the model before rotations.
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Transform {
     /// Specifies the rotation of the model in degrees according to the scheme
     /// `[x, y, z]`.
-    #[serde(default = "Transform::zeros")]
+    #[serde(
+        default = "Transform::zeros",
+        skip_serializing_if = "Transform::is_zeros"
+    )]
     pub rotation: [f32; 3],
 
     /// Specifies the position of the model according to the scheme `[x, y, z]`.
@@ -129,13 +271,19 @@ pub struct Transform {
     /// The unit of distance is **1/16th of a block** (0.0625 meters).
     ///
     /// The values should be clamped between -80 and 80.
-    #[serde(default = "Transform::zeros")]
+    #[serde(
+        default = "Transform::zeros",
+        skip_serializing_if = "Transform::is_zeros"
+    )]
     pub translation: [f32; 3],
 
     /// Specifies the scale of the model according to the scheme `[x, y, z]`.
     ///
     /// If the value is greater than 4, it is displayed as 4.
-    #[serde(default = "Transform::ones")]
+    #[serde(
+        default = "Transform::ones",
+        skip_serializing_if = "Transform::is_ones"
+    )]
     pub scale: [f32; 3],
 }
 
@@ -147,6 +295,14 @@ impl Transform {
     pub(crate) const fn ones() -> [f32; 3] {
         [1.0; 3]
     }
+
+    fn is_zeros(value: &[f32; 3]) -> bool {
+        strict::is_default(value, Self::zeros())
+    }
+
+    fn is_ones(value: &[f32; 3]) -> bool {
+        strict::is_default(value, Self::ones())
+    }
 }
 
 impl Default for Transform {
@@ -211,6 +367,7 @@ impl Default for Transform {
 ///
 /// [resource location]: <https://minecraft.fandom.com/wiki/Model#File_path>
 #[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Textures {
     /// The values of all texture variables by name.
     #[serde(flatten)]
@@ -322,6 +479,7 @@ impl DerefMut for Textures {
 /// [`location`]: Self::location
 /// [`reference`]: Self::reference
 #[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Texture(pub String);
 
 impl Texture {
@@ -421,6 +579,7 @@ impl<'a> From<&'a str> for Texture {
 
 /// A single, cube-shaped element of a [`Model`]'s geometry.
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Element {
     /// Start point of a cuboid according to the scheme `[x, y, z]`.
     ///
@@ -437,18 +596,33 @@ pub struct Element {
     pub faces: HashMap<BlockFace, ElementFace>,
 
     /// The rotation of the element
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "ElementRotation::is_default")]
     pub rotation: ElementRotation,
 
     /// Specifies if shadows are rendered (`true` - default), or not (`false`).
-    #[serde(default = "Element::default_shade")]
+    #[serde(
+        default = "Element::default_shade",
+        skip_serializing_if = "Element::is_default_shade"
+    )]
     pub shade: bool,
+
+    /// The fields that this crate does not model (e.g., fields added by mods
+    /// or by newer versions), kept so that they survive a round trip.
+    ///
+    /// Requires the `preserve-unknown` feature.
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten, serialize_with = "crate::schemas::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 impl Element {
     pub(crate) const fn default_shade() -> bool {
         true
     }
+
+    fn is_default_shade(shade: &bool) -> bool {
+        strict::is_default(shade, Self::default_shade())
+    }
 }
 
 impl Default for Element {
@@ -459,12 +633,15 @@ impl Default for Element {
             faces: Default::default(),
             rotation: Default::default(),
             shade: Self::default_shade(),
+            #[cfg(feature = "preserve-unknown")]
+            extra: Default::default(),
         }
     }
 }
 
 /// Specifies the rotation of an [`Element`].
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ElementRotation {
     /// Sets the center of the rotation according to the scheme `[x, y, z]`.
     pub origin: [f32; 3],
@@ -480,7 +657,10 @@ pub struct ElementRotation {
     /// Specifies whether or not to scale the faces across the whole block.
     ///
     /// Defaults to `false`.
-    #[serde(default = "ElementRotation::default_rescale")]
+    #[serde(
+        default = "ElementRotation::default_rescale",
+        skip_serializing_if = "ElementRotation::is_default_rescale"
+    )]
     pub rescale: bool,
 }
 
@@ -488,6 +668,14 @@ impl ElementRotation {
     pub(crate) const fn default_rescale() -> bool {
         false
     }
+
+    fn is_default_rescale(rescale: &bool) -> bool {
+        strict::is_default(rescale, Self::default_rescale())
+    }
+
+    fn is_default(&self) -> bool {
+        strict::is_default(self, Self::default())
+    }
 }
 
 impl Default for ElementRotation {
@@ -503,6 +691,7 @@ impl Default for ElementRotation {
 
 /// Specifies the details of a single face in a cuboid [`Element`].
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ElementFace {
     /// Defines the area of the image that should be sampled for this texture.
     ///
@@ -540,7 +729,10 @@ pub struct ElementFace {
     /// affect which part of the texture is used. Instead, it amounts to a
     /// permutation of the selected texture vertexes (selected implicitly, or
     /// explicitly though `uv`).
-    #[serde(default = "ElementFace::default_rotation")]
+    #[serde(
+        default = "ElementFace::default_rotation",
+        skip_serializing_if = "ElementFace::is_default_rotation"
+    )]
     pub rotation: u32,
 
     /// Determines whether to tint the texture using a hardcoded tint index.
@@ -552,8 +744,20 @@ pub struct ElementFace {
     /// multiple tint values, and thus the tint index value is ignored (as long
     /// as it is set to something other than `-1`); it could be used for modded
     /// blocks that need multiple distinct tint values in the same block though.
-    #[serde(rename = "tintindex", default = "ElementFace::default_tint_index")]
+    #[serde(
+        rename = "tintindex",
+        default = "ElementFace::default_tint_index",
+        skip_serializing_if = "ElementFace::is_default_tint_index"
+    )]
     pub tint_index: i32,
+
+    /// The fields that this crate does not model (e.g., fields added by mods
+    /// or by newer versions), kept so that they survive a round trip.
+    ///
+    /// Requires the `preserve-unknown` feature.
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten, serialize_with = "crate::schemas::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 impl ElementFace {
@@ -564,6 +768,14 @@ impl ElementFace {
     pub(crate) const fn default_tint_index() -> i32 {
         -1
     }
+
+    fn is_default_rotation(rotation: &u32) -> bool {
+        strict::is_default(rotation, Self::default_rotation())
+    }
+
+    fn is_default_tint_index(tint_index: &i32) -> bool {
+        strict::is_default(tint_index, Self::default_tint_index())
+    }
 }
 
 impl Default for ElementFace {
@@ -574,12 +786,15 @@ impl Default for ElementFace {
             cull_face: Default::default(),
             rotation: Self::default_rotation(),
             tint_index: Self::default_tint_index(),
+            #[cfg(feature = "preserve-unknown")]
+            extra: Default::default(),
         }
     }
 }
 
 /// One possible case in which an item's [`Model`] should be overridden.
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct OverrideCase {
     /// Specifies when this override should be active.
     ///
@@ -592,11 +807,37 @@ pub struct OverrideCase {
     /// location].
     ///
     /// [resource location]: <https://minecraft.fandom.com/wiki/Model#File_path>
-    pub model: String,
+    pub model: ResourceLocation<'static>,
+}
+
+impl OverrideCase {
+    /// Returns `true` if every predicate of this case is matched by the given
+    /// values of the item's predicates, i.e., if each value is at least the
+    /// value of its predicate.
+    ///
+    /// Predicate names with and without the `minecraft:` namespace are
+    /// equivalent. Predicates without a given value are considered to be
+    /// `0.0`, which is the value of most predicates for an item in its
+    /// default state.
+    pub fn matches(&self, values: &HashMap<String, f32>) -> bool {
+        self.predicate.iter().all(|(name, threshold)| {
+            let value = values
+                .iter()
+                .find(|(other, _)| predicate_name(other) == predicate_name(name))
+                .map_or(0.0, |(_, value)| *value);
+            value >= threshold.as_f32()
+        })
+    }
+}
+
+/// Returns the name of a predicate without the `minecraft:` namespace.
+fn predicate_name(name: &str) -> &str {
+    name.strip_prefix("minecraft:").unwrap_or(name)
 }
 
 /// The value for an item tag specified in a predicate in an [`OverrideCase`].
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 #[allow(missing_docs)]
 pub enum PredicateValue {
@@ -604,8 +845,19 @@ pub enum PredicateValue {
     Float(f32),
 }
 
+impl PredicateValue {
+    /// Returns the value as a float, like the game compares it.
+    pub fn as_f32(&self) -> f32 {
+        match *self {
+            Self::Int(value) => value as f32,
+            Self::Float(value) => value,
+        }
+    }
+}
+
 /// The two possible ways to shade a model in the UI.
 #[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum GuiLightMode {
     /// Shade the model like a block.
@@ -617,6 +869,7 @@ pub enum GuiLightMode {
 
 /// The three possible axes in 3D space.
 #[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "lowercase")]
 #[allow(missing_docs)]
 pub enum Axis {
@@ -627,6 +880,7 @@ pub enum Axis {
 
 /// The six possible faces of a cuboid.
 #[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "lowercase")]
 #[allow(missing_docs)]
 #[repr(u8)]
@@ -640,3 +894,213 @@ pub enum BlockFace {
     West,
     East,
 }
+
+/// A builder for [`Model`]s, e.g., for tools that generate packs.
+///
+/// The constructors start from the vanilla parent models that most block and
+/// item models inherit from.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::models::*;
+/// let model = ModelBuilder::cube_all("block/stone").build();
+/// assert_eq!(model.parent.unwrap(), "block/cube_all");
+/// assert_eq!(model.textures.unwrap()["all"].location(), Some("block/stone"));
+///
+/// let slab = ModelBuilder::new()
+///     .texture("particle", "#side")
+///     .texture("side", "block/stone")
+///     .cuboid([0.0, 0.0, 0.0], [16.0, 8.0, 16.0], "#side")
+///     .build();
+///
+/// let element = &slab.elements.unwrap()[0];
+/// assert_eq!(element.faces.len(), 6);
+/// assert_eq!(element.faces[&BlockFace::Down].cull_face, Some(BlockFace::Down));
+/// assert_eq!(element.faces[&BlockFace::Up].cull_face, None);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct ModelBuilder {
+    model: Model,
+}
+
+impl ModelBuilder {
+    /// Returns a builder for a model with no parent.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a builder for a model that inherits from the given parent.
+    ///
+    /// Returns an error if the parent is not a valid [`ResourceLocation`].
+    pub fn with_parent(parent: impl AsRef<str>) -> Result<Self, InvalidIdentifier> {
+        Self::new().parent(parent)
+    }
+
+    /// Returns a builder for a model that inherits from a vanilla model.
+    fn with_vanilla_parent(parent: &'static str) -> Self {
+        let mut builder = Self::new();
+        builder.model.parent = Some(ResourceLocation::from_static(parent));
+        builder
+    }
+
+    /// Returns a builder for a block with the same texture on all sides.
+    pub fn cube_all(texture: impl Into<String>) -> Self {
+        Self::with_vanilla_parent("block/cube_all").texture("all", texture)
+    }
+
+    /// Returns a builder for a block like a log, with one texture on the top
+    /// and bottom and another on the sides.
+    pub fn cube_column(end: impl Into<String>, side: impl Into<String>) -> Self {
+        Self::with_vanilla_parent("block/cube_column")
+            .texture("end", end)
+            .texture("side", side)
+    }
+
+    /// Returns a builder for a block with different textures on the top,
+    /// bottom, and sides.
+    pub fn cube_bottom_top(
+        top: impl Into<String>,
+        bottom: impl Into<String>,
+        side: impl Into<String>,
+    ) -> Self {
+        Self::with_vanilla_parent("block/cube_bottom_top")
+            .texture("top", top)
+            .texture("bottom", bottom)
+            .texture("side", side)
+    }
+
+    /// Returns a builder for a plant like a sapling, made of two crossed
+    /// planes.
+    pub fn cross(texture: impl Into<String>) -> Self {
+        Self::with_vanilla_parent("block/cross").texture("cross", texture)
+    }
+
+    /// Returns a builder for a flat item made out of the given icon.
+    pub fn generated_item(layer0: impl Into<String>) -> Self {
+        Self::with_vanilla_parent("item/generated").texture("layer0", layer0)
+    }
+
+    /// Returns a builder for a flat item that is held like a tool.
+    pub fn handheld_item(layer0: impl Into<String>) -> Self {
+        Self::with_vanilla_parent("item/handheld").texture("layer0", layer0)
+    }
+
+    /// Sets the parent model.
+    ///
+    /// Returns an error if the parent is not a valid [`ResourceLocation`].
+    pub fn parent(mut self, parent: impl AsRef<str>) -> Result<Self, InvalidIdentifier> {
+        self.model.parent = Some(parent.as_ref().parse()?);
+        Ok(self)
+    }
+
+    /// Sets the value of a texture variable to a location or to a `#`-prefixed
+    /// reference to another variable.
+    pub fn texture(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.model
+            .textures
+            .get_or_insert_with(Default::default)
+            .insert(name.into(), Texture(value.into()));
+        self
+    }
+
+    /// Adds an element.
+    pub fn element(mut self, element: Element) -> Self {
+        self.model
+            .elements
+            .get_or_insert_with(Default::default)
+            .push(element);
+        self
+    }
+
+    /// Adds a cuboid element between the given corners, with all six faces
+    /// using the given texture.
+    ///
+    /// Faces that lie on the boundary of the block are culled when a block
+    /// touches them, like in the vanilla models.
+    pub fn cuboid(self, from: [f32; 3], to: [f32; 3], texture: impl Into<String>) -> Self {
+        let texture = Texture(texture.into());
+        let on_boundary = |face| match face {
+            BlockFace::Down => from[1] <= 0.0,
+            BlockFace::Up => to[1] >= 16.0,
+            BlockFace::North => from[2] <= 0.0,
+            BlockFace::South => to[2] >= 16.0,
+            BlockFace::West => from[0] <= 0.0,
+            BlockFace::East => to[0] >= 16.0,
+        };
+
+        let faces = [
+            BlockFace::Down,
+            BlockFace::Up,
+            BlockFace::North,
+            BlockFace::South,
+            BlockFace::West,
+            BlockFace::East,
+        ]
+        .into_iter()
+        .map(|face| {
+            let element_face = ElementFace {
+                texture: texture.clone(),
+                cull_face: on_boundary(face).then_some(face),
+                ..Default::default()
+            };
+            (face, element_face)
+        })
+        .collect();
+
+        self.element(Element {
+            from,
+            to,
+            faces,
+            ..Default::default()
+        })
+    }
+
+    /// Sets whether to use ambient occlusion.
+    pub fn ambient_occlusion(mut self, ambient_occlusion: bool) -> Self {
+        self.model.ambient_occlusion = Some(ambient_occlusion);
+        self
+    }
+
+    /// Sets how to shade the model in the GUI.
+    pub fn gui_light(mut self, mode: GuiLightMode) -> Self {
+        self.model.gui_light_mode = Some(mode);
+        self
+    }
+
+    /// Sets how the model is displayed in different views.
+    pub fn display(mut self, display: Display) -> Self {
+        self.model.display = Some(display);
+        self
+    }
+
+    /// Adds an override that uses the given model when all of the given item
+    /// predicates match.
+    ///
+    /// Returns an error if the model is not a valid [`ResourceLocation`].
+    pub fn override_model<'a, I>(
+        mut self,
+        predicate: I,
+        model: impl AsRef<str>,
+    ) -> Result<Self, InvalidIdentifier>
+    where
+        I: IntoIterator<Item = (&'a str, PredicateValue)>,
+    {
+        let model = model.as_ref().parse()?;
+        let predicate = predicate
+            .into_iter()
+            .map(|(name, value)| (String::from(name), value))
+            .collect();
+
+        self.model
+            .overrides
+            .get_or_insert_with(Default::default)
+            .push(OverrideCase { predicate, model });
+        Ok(self)
+    }
+
+    /// Returns the model.
+    pub fn build(self) -> Model {
+        self.model
+    }
+}
@@ -0,0 +1,189 @@
+//! Data types for the custom item textures (CIT) properties of OptiFine and
+//! CIT Resewn, in `assets/<namespace>/optifine/cit/**/*.properties`.
+//!
+//! Start here: [`CitProperties`].
+//!
+//! See <https://optifine.readthedocs.io/cit.html>.
+
+use std::collections::BTreeMap;
+
+use crate::schemas::optifine::properties::{parse_list, IntRange, Properties};
+
+/// A custom item texture rule, as stored in a `.properties` file in the
+/// `optifine/cit/` directory of a resource pack.
+///
+/// The keys that this type does not model (e.g., the `blend` and `speed` of
+/// enchantment rules) are kept in [`other`][Self::other].
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::optifine::{cit::*, properties::IntRange};
+/// let cit = CitProperties::parse(r"
+/// type=item
+/// items=diamond_sword minecraft:netherite_sword
+/// texture=fire_sword
+/// texture.bow_pulling_0=fire_bow_0
+/// damage=0-50%
+/// enchantments=minecraft:fire_aspect
+/// enchantmentLevels=2-
+/// nbt.display.Name=ipattern:*fire*
+/// weight=5
+/// ");
+///
+/// assert_eq!(cit.kind, CitType::Item);
+/// assert_eq!(cit.items, ["diamond_sword", "minecraft:netherite_sword"]);
+/// assert_eq!(cit.texture.as_deref(), Some("fire_sword"));
+/// assert_eq!(cit.textures["bow_pulling_0"], "fire_bow_0");
+/// assert_eq!(cit.damage, [IntRange { min: 0, max: Some(50) }]);
+/// assert!(cit.damage_percent);
+/// assert!(cit.enchantment_levels[0].contains(3));
+/// assert_eq!(cit.nbt["display.Name"], "ipattern:*fire*");
+/// assert_eq!(cit.weight, Some(5));
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CitProperties {
+    /// What the rule replaces, from `type`.
+    pub kind: CitType,
+
+    /// The items that the rule applies to, from `items` (or the legacy
+    /// `matchItems`).
+    pub items: Vec<String>,
+
+    /// The replacement texture, from `texture`.
+    ///
+    /// If the rule has neither a texture nor a model, the texture with the
+    /// name of the `.properties` file is used.
+    pub texture: Option<String>,
+
+    /// Map of the textures of the item model (e.g., `bow_pulling_0`) to
+    /// their replacements, from the `texture.<name>` keys.
+    pub textures: BTreeMap<String, String>,
+
+    /// The replacement model, from `model`.
+    pub model: Option<String>,
+
+    /// Map of the models of the item (e.g., `bow_pulling_0`) to their
+    /// replacements, from the `model.<name>` keys.
+    pub models: BTreeMap<String, String>,
+
+    /// The damage values that the rule applies to, from `damage`.
+    pub damage: Vec<IntRange>,
+
+    /// Whether the [`damage`][Self::damage] ranges are percentages of the
+    /// maximum durability of the item.
+    pub damage_percent: bool,
+
+    /// The bits of the damage value that are compared, from `damageMask`.
+    pub damage_mask: Option<i32>,
+
+    /// The stack sizes that the rule applies to, from `stackSize`.
+    pub stack_size: Vec<IntRange>,
+
+    /// The enchantments that the item must have one of, from
+    /// `enchantments` (or the legacy `enchantmentIDs`).
+    pub enchantments: Vec<String>,
+
+    /// The levels of the enchantments, from `enchantmentLevels`.
+    pub enchantment_levels: Vec<IntRange>,
+
+    /// The hand that the item must be held in, from `hand`: `any`, `main`,
+    /// or `off`.
+    pub hand: Option<String>,
+
+    /// Map of NBT paths (e.g., `display.Name`) to the values that they must
+    /// match, from the `nbt.<path>` keys.
+    pub nbt: BTreeMap<String, String>,
+
+    /// The priority of the rule over other rules for the same items, from
+    /// `weight`.
+    pub weight: Option<i32>,
+
+    /// The keys that this type does not model, with their values.
+    pub other: BTreeMap<String, String>,
+}
+
+impl CitProperties {
+    /// Parses the contents of a CIT `.properties` file.
+    ///
+    /// Like in OptiFine, values that are not valid are ignored.
+    pub fn parse(text: &str) -> Self {
+        Self::from_properties(Properties::parse(text))
+    }
+
+    /// Reads a rule from the given parsed `.properties` file.
+    pub fn from_properties(properties: Properties) -> Self {
+        let mut cit = Self::default();
+
+        for (key, value) in properties.entries {
+            if let Some(name) = key.strip_prefix("texture.") {
+                cit.textures.insert(name.to_string(), value);
+                continue;
+            }
+            if let Some(name) = key.strip_prefix("model.") {
+                cit.models.insert(name.to_string(), value);
+                continue;
+            }
+            if let Some(path) = key.strip_prefix("nbt.") {
+                cit.nbt.insert(path.to_string(), value);
+                continue;
+            }
+
+            match key.as_str() {
+                "type" => cit.kind = CitType::from_name(value.trim()),
+                "items" | "matchItems" => cit.items = parse_list(&value),
+                "texture" | "tile" => cit.texture = Some(value.trim().to_string()),
+                "model" => cit.model = Some(value.trim().to_string()),
+                "damage" => {
+                    let value = value.trim();
+                    cit.damage_percent = value.contains('%');
+                    cit.damage = IntRange::parse_list(&value.replace('%', ""));
+                }
+                "damageMask" => cit.damage_mask = value.trim().parse().ok(),
+                "stackSize" => cit.stack_size = IntRange::parse_list(&value),
+                "enchantments" | "enchantmentIDs" => cit.enchantments = parse_list(&value),
+                "enchantmentLevels" => cit.enchantment_levels = IntRange::parse_list(&value),
+                "hand" => cit.hand = Some(value.trim().to_string()),
+                "weight" => cit.weight = value.trim().parse().ok(),
+                _ => {
+                    cit.other.insert(key, value);
+                }
+            }
+        }
+
+        cit
+    }
+}
+
+/// The `type` of a [`CitProperties`] rule.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub enum CitType {
+    /// Replaces the texture or model of the item. The default.
+    #[default]
+    Item,
+
+    /// Replaces the enchantment glint of the item.
+    Enchantment,
+
+    /// Replaces the texture of the armor when it is worn.
+    Armor,
+
+    /// Replaces the texture of the elytra when it is worn.
+    Elytra,
+
+    /// A type that this crate does not know, e.g., added by a mod.
+    Other(String),
+}
+
+impl CitType {
+    /// Returns the type with the given name.
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "item" => Self::Item,
+            "enchantment" => Self::Enchantment,
+            "armor" => Self::Armor,
+            "elytra" => Self::Elytra,
+            _ => Self::Other(name.to_string()),
+        }
+    }
+}
@@ -0,0 +1,295 @@
+//! Data types for the connected textures (CTM) properties of OptiFine and
+//! Continuity, in `assets/<namespace>/optifine/ctm/**/*.properties`.
+//!
+//! Start here: [`CtmProperties`].
+//!
+//! See <https://optifine.readthedocs.io/ctm.html>.
+
+use std::collections::BTreeMap;
+
+use crate::schemas::optifine::properties::{parse_bool, parse_list, IntRange, Properties};
+
+/// A connected textures rule, as stored in a `.properties` file in the
+/// `optifine/ctm/` directory of a resource pack.
+///
+/// The keys that this type does not model are kept in
+/// [`other`][Self::other].
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::optifine::ctm::*;
+/// let ctm = CtmProperties::parse("
+/// method=ctm_compact
+/// tiles=0-4 <skip> glass_overlay
+/// matchBlocks=minecraft:glass minecraft:oak_log:axis=x,z
+/// faces=sides
+/// heights=(-64)-0
+/// connect=block
+/// innerSeams=true
+/// ");
+///
+/// assert_eq!(ctm.method, Some(CtmMethod::CtmCompact));
+/// assert_eq!(ctm.tiles.len(), 7);
+/// assert_eq!(ctm.tiles[4], CtmTile::Texture("4".into()));
+/// assert_eq!(ctm.tiles[5], CtmTile::Skip);
+///
+/// let log = &ctm.match_blocks[1];
+/// assert_eq!(log.block, "minecraft:oak_log");
+/// assert_eq!(log.states["axis"], ["x", "z"]);
+///
+/// assert_eq!(ctm.faces, ["sides"]);
+/// assert!(ctm.heights[0].contains(-10));
+/// assert_eq!(ctm.inner_seams, Some(true));
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CtmProperties {
+    /// The method that is used to pick the tile of each face, from `method`.
+    pub method: Option<CtmMethod>,
+
+    /// The tiles of the rule, from `tiles`, with ranges like `0-46` expanded
+    /// into one tile each.
+    pub tiles: Vec<CtmTile>,
+
+    /// The blocks that the rule applies to, from `matchBlocks`.
+    pub match_blocks: Vec<BlockMatch>,
+
+    /// The textures that the rule applies to, from `matchTiles`, e.g.,
+    /// `stone` or `optifine/ctm/glass/0`.
+    pub match_tiles: Vec<String>,
+
+    /// The priority of the rule over other rules for the same blocks or
+    /// tiles, from `weight`.
+    pub weight: Option<i32>,
+
+    /// The faces that the rule applies to, from `faces`, e.g., `top`,
+    /// `sides`, or `all`.
+    pub faces: Vec<String>,
+
+    /// The biomes that the rule applies to, from `biomes`.
+    pub biomes: Vec<String>,
+
+    /// The heights that the rule applies at, from `heights`.
+    pub heights: Vec<IntRange>,
+
+    /// What a block connects to, from `connect`: `block`, `tile`, or `state`.
+    pub connect: Option<String>,
+
+    /// Whether the seams between inner corners are drawn, from
+    /// `innerSeams`.
+    pub inner_seams: Option<bool>,
+
+    /// The width of the pattern of the `repeat` method, from `width`.
+    pub width: Option<u32>,
+
+    /// The height of the pattern of the `repeat` method, from `height`.
+    pub height: Option<u32>,
+
+    /// The weights of the tiles of the `random` method, from `weights`.
+    pub weights: Vec<u32>,
+
+    /// The keys that this type does not model, with their values.
+    pub other: BTreeMap<String, String>,
+}
+
+impl CtmProperties {
+    /// Parses the contents of a CTM `.properties` file.
+    ///
+    /// Like in OptiFine, values that are not valid are ignored.
+    pub fn parse(text: &str) -> Self {
+        Self::from_properties(Properties::parse(text))
+    }
+
+    /// Reads a rule from the given parsed `.properties` file.
+    pub fn from_properties(properties: Properties) -> Self {
+        let mut ctm = Self::default();
+
+        for (key, value) in properties.entries {
+            match key.as_str() {
+                "method" => ctm.method = Some(CtmMethod::from_name(value.trim())),
+                "tiles" => ctm.tiles = CtmTile::parse_list(&value),
+                "matchBlocks" => {
+                    ctm.match_blocks = value.split_whitespace().map(BlockMatch::parse).collect()
+                }
+                "matchTiles" => ctm.match_tiles = parse_list(&value),
+                "weight" => ctm.weight = value.trim().parse().ok(),
+                "faces" => ctm.faces = parse_list(&value),
+                "biomes" => ctm.biomes = parse_list(&value),
+                "heights" => ctm.heights = IntRange::parse_list(&value),
+                "connect" => ctm.connect = Some(value.trim().to_string()),
+                "innerSeams" => ctm.inner_seams = parse_bool(&value),
+                "width" => ctm.width = value.trim().parse().ok(),
+                "height" => ctm.height = value.trim().parse().ok(),
+                "weights" => {
+                    ctm.weights = value
+                        .split_whitespace()
+                        .filter_map(|weight| weight.parse().ok())
+                        .collect()
+                }
+                _ => {
+                    ctm.other.insert(key, value);
+                }
+            }
+        }
+
+        ctm
+    }
+
+    /// Fills [`match_blocks`][Self::match_blocks] or
+    /// [`match_tiles`][Self::match_tiles] from the name of the file (without
+    /// its extension) if the rule has neither, like OptiFine.
+    ///
+    /// A file named `block_<name>` matches the block `<name>`, and any other
+    /// file matches the tile of its name.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::optifine::ctm::*;
+    /// let mut ctm = CtmProperties::parse("method=ctm\ntiles=0-46");
+    /// ctm.match_file_name("block_bookshelf");
+    /// assert_eq!(ctm.match_blocks[0].block, "bookshelf");
+    ///
+    /// let mut ctm = CtmProperties::parse("method=ctm\ntiles=0-46");
+    /// ctm.match_file_name("glass");
+    /// assert_eq!(ctm.match_tiles, ["glass"]);
+    /// ```
+    pub fn match_file_name(&mut self, name: &str) {
+        if !self.match_blocks.is_empty() || !self.match_tiles.is_empty() {
+            return;
+        }
+
+        match name.strip_prefix("block_") {
+            Some(block) => self.match_blocks.push(BlockMatch::parse(block)),
+            None => self.match_tiles.push(name.to_string()),
+        }
+    }
+}
+
+/// The method of a [`CtmProperties`] rule.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum CtmMethod {
+    /// Connects to the neighbors in every direction, with 47 tiles. Also
+    /// named `glass`.
+    Ctm,
+    /// Like [`Ctm`][Self::Ctm], but with 5 tiles that are split into
+    /// quarters.
+    CtmCompact,
+    /// Connects to the left and right neighbors. Also named `bookshelf`.
+    Horizontal,
+    /// Connects to the neighbors above and below.
+    Vertical,
+    /// Connects horizontally, then vertically. Also named `h+v`.
+    HorizontalVertical,
+    /// Connects vertically, then horizontally. Also named `v+h`.
+    VerticalHorizontal,
+    /// Connects to the same block above. Also named `sandstone`.
+    Top,
+    Random,
+    Repeat,
+    Fixed,
+    Overlay,
+    OverlayCtm,
+    OverlayRandom,
+    OverlayRepeat,
+    OverlayFixed,
+    /// A method that this crate does not know, e.g., added by a mod.
+    Other(String),
+}
+
+impl CtmMethod {
+    /// Returns the method with the given name, including the legacy aliases.
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "ctm" | "glass" => Self::Ctm,
+            "ctm_compact" => Self::CtmCompact,
+            "horizontal" | "bookshelf" => Self::Horizontal,
+            "vertical" => Self::Vertical,
+            "horizontal+vertical" | "h+v" => Self::HorizontalVertical,
+            "vertical+horizontal" | "v+h" => Self::VerticalHorizontal,
+            "top" | "sandstone" => Self::Top,
+            "random" => Self::Random,
+            "repeat" | "pattern" => Self::Repeat,
+            "fixed" | "static" => Self::Fixed,
+            "overlay" => Self::Overlay,
+            "overlay_ctm" => Self::OverlayCtm,
+            "overlay_random" => Self::OverlayRandom,
+            "overlay_repeat" => Self::OverlayRepeat,
+            "overlay_fixed" => Self::OverlayFixed,
+            _ => Self::Other(name.to_string()),
+        }
+    }
+}
+
+/// One of the tiles of a [`CtmProperties`] rule.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CtmTile {
+    /// A texture, as written in the file: a name (e.g., `0` or `glass`) in
+    /// the directory of the `.properties` file, or a path (e.g.,
+    /// `textures/block/stone` or `mymod:block/ruby`).
+    Texture(String),
+
+    /// `<skip>`: the face is not changed by this rule, and other rules are
+    /// tried.
+    Skip,
+
+    /// `<default>`: the face keeps the texture of its model.
+    Default,
+}
+
+impl CtmTile {
+    /// Parses a whitespace-separated list of tiles, expanding ranges like
+    /// `0-46` into one tile each.
+    pub fn parse_list(text: &str) -> Vec<Self> {
+        let mut tiles = Vec::new();
+        for tile in text.split_whitespace() {
+            match tile {
+                "<skip>" => tiles.push(Self::Skip),
+                "<default>" => tiles.push(Self::Default),
+                _ => match tile.split_once('-').and_then(|(start, end)| {
+                    Some((start.parse::<u32>().ok()?, end.parse::<u32>().ok()?))
+                }) {
+                    Some((start, end)) => {
+                        tiles.extend((start..=end).map(|i| Self::Texture(i.to_string())))
+                    }
+                    None => tiles.push(Self::Texture(tile.to_string())),
+                },
+            }
+        }
+        tiles
+    }
+}
+
+/// One of the blocks that a [`CtmProperties`] rule matches, with optional
+/// block states, like `minecraft:oak_log:axis=x,z`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BlockMatch {
+    /// The ID of the block, with its namespace if it was given.
+    pub block: String,
+
+    /// Map of block state properties to the values that they may have.
+    pub states: BTreeMap<String, Vec<String>>,
+}
+
+impl BlockMatch {
+    /// Parses a block with optional states.
+    pub fn parse(text: &str) -> Self {
+        let mut parts = text.split(':').peekable();
+        let mut block = parts.next().unwrap_or_default().to_string();
+        if let Some(path) = parts.next_if(|part| !part.contains('=')) {
+            block.push(':');
+            block.push_str(path);
+        }
+
+        let states = parts
+            .filter_map(|state| state.split_once('='))
+            .map(|(name, values)| {
+                let values = values.split(',').map(String::from).collect();
+                (name.to_string(), values)
+            })
+            .collect();
+
+        Self { block, states }
+    }
+}
@@ -0,0 +1,21 @@
+//! Data types for the `.properties` files of OptiFine resource packs, in
+//! `assets/<namespace>/optifine/`, which Continuity, CIT Resewn, and other
+//! mods read too.
+//!
+//! See <https://optifine.readthedocs.io/>.
+//!
+//! The files are Java `.properties` files rather than JSON, so these types are
+//! parsed with their `parse()` methods instead of serde. The textures that
+//! they reference can be resolved into resources through the
+//! [`optifine`][crate::api::optifine] API module.
+//!
+//! *currently only connected textures (CTM) and custom item textures (CIT)
+//! are implemented*
+
+pub mod cit;
+pub mod ctm;
+pub mod properties;
+
+pub use cit::CitProperties;
+pub use ctm::CtmProperties;
+pub use properties::Properties;
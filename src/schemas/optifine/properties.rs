@@ -0,0 +1,255 @@
+//! Parsing of Java `.properties` files, and of the values that OptiFine
+//! stores in them.
+//!
+//! Start here: [`Properties`].
+
+use std::collections::BTreeMap;
+
+/// The `key=value` pairs of a Java `.properties` file.
+///
+/// Like `java.util.Properties`, keys and values may be separated by `=`, `:`,
+/// or whitespace, lines that start with `#` or `!` are comments, a `\` at the
+/// end of a line continues it on the next line, and `\t`, `\n`, `\uXXXX`,
+/// etc. are unescaped. If a key appears several times, the last value wins.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::optifine::properties::*;
+/// let properties = Properties::parse(r"
+/// ## Connected glass
+/// method=ctm
+/// tiles: 0-46
+/// matchBlocks = minecraft:glass \
+///               minecraft:tinted_glass
+/// name=café
+/// ");
+///
+/// assert_eq!(properties.get("method"), Some("ctm"));
+/// assert_eq!(properties.get("tiles"), Some("0-46"));
+/// assert_eq!(properties.get("matchBlocks"), Some("minecraft:glass minecraft:tinted_glass"));
+/// assert_eq!(properties.get("name"), Some("café"));
+/// assert_eq!(properties.entries.len(), 4);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Properties {
+    /// Map of keys to their values.
+    pub entries: BTreeMap<String, String>,
+}
+
+impl Properties {
+    /// Parses the contents of a `.properties` file.
+    pub fn parse(text: &str) -> Self {
+        let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+
+        let mut entries = BTreeMap::new();
+        let mut lines = text.lines();
+        while let Some(line) = lines.next() {
+            let line = line.trim_start();
+            if line.is_empty() || line.starts_with('#') || line.starts_with('!') {
+                continue;
+            }
+
+            // Joins the lines that end with an odd number of backslashes.
+            let mut logical = String::from(line);
+            while ends_with_continuation(&logical) {
+                logical.pop();
+                match lines.next() {
+                    Some(next) => logical.push_str(next.trim_start()),
+                    None => break,
+                }
+            }
+
+            let (key, value) = split_entry(&logical);
+            entries.insert(unescape(key), unescape(value));
+        }
+
+        Self { entries }
+    }
+
+    /// Returns the value of the given key.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries.get(key).map(String::as_str)
+    }
+}
+
+fn ends_with_continuation(line: &str) -> bool {
+    let backslashes = line.len() - line.trim_end_matches('\\').len();
+    backslashes % 2 == 1
+}
+
+/// Splits a logical line at the first unescaped `=`, `:`, or whitespace.
+fn split_entry(line: &str) -> (&str, &str) {
+    let mut escaped = false;
+    let mut end = line.len();
+    for (i, c) in line.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == '=' || c == ':' || c.is_whitespace() {
+            end = i;
+            break;
+        }
+    }
+
+    let key = &line[..end];
+    let mut rest = line[end..].trim_start();
+    if let Some(after) = rest.strip_prefix(['=', ':']) {
+        rest = after.trim_start();
+    }
+    (key, rest)
+}
+
+fn unescape(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => unescaped.push('\t'),
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some('f') => unescaped.push('\u{c}'),
+            Some('u') => {
+                let hex: String = chars.by_ref().take(4).collect();
+                match u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+                    Some(c) => unescaped.push(c),
+                    None => unescaped.push_str(&hex),
+                }
+            }
+            Some(c) => unescaped.push(c),
+            None => {}
+        }
+    }
+    unescaped
+}
+
+/// An inclusive range of integers in an OptiFine property, like `5`, `0-15`,
+/// or `(-64)-0`, which may be open at its end, like `10-`.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::optifine::properties::*;
+/// let ranges = IntRange::parse_list("3 10-20 (-64)-(-1) 100-");
+/// assert_eq!(
+///     ranges,
+///     [
+///         IntRange { min: 3, max: Some(3) },
+///         IntRange { min: 10, max: Some(20) },
+///         IntRange { min: -64, max: Some(-1) },
+///         IntRange { min: 100, max: None },
+///     ]
+/// );
+/// assert!(ranges[1].contains(15));
+/// assert!(ranges[3].contains(1000));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IntRange {
+    /// The lowest value of the range.
+    pub min: i32,
+
+    /// The highest value of the range, or `None` if it has no upper bound.
+    pub max: Option<i32>,
+}
+
+impl IntRange {
+    /// Parses a range, or returns `None` if it is invalid.
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+        if let Ok(value) = parse_int(text) {
+            return Some(Self {
+                min: value,
+                max: Some(value),
+            });
+        }
+
+        // The separator is the first `-` that does not start a number.
+        let mut depth = 0;
+        let separator = text
+            .char_indices()
+            .find(|&(i, c)| {
+                match c {
+                    '(' => depth += 1,
+                    ')' => depth -= 1,
+                    '-' => return i > 0 && depth == 0,
+                    _ => {}
+                }
+                false
+            })
+            .map(|(i, _)| i)?;
+        let min = parse_int(&text[..separator]).ok()?;
+        let max = &text[separator + 1..];
+        let max = if max.is_empty() {
+            None
+        } else {
+            Some(parse_int(max).ok()?)
+        };
+        Some(Self { min, max })
+    }
+
+    /// Parses a whitespace-separated list of ranges, skipping the invalid
+    /// ones.
+    pub fn parse_list(text: &str) -> Vec<Self> {
+        text.split_whitespace().filter_map(Self::parse).collect()
+    }
+
+    /// Returns `true` if the range contains the given value.
+    pub fn contains(&self, value: i32) -> bool {
+        value >= self.min && self.max.is_none_or(|max| value <= max)
+    }
+}
+
+/// Parses an integer, which may be wrapped in parentheses, like `(-64)`.
+fn parse_int(text: &str) -> Result<i32, std::num::ParseIntError> {
+    let text = text
+        .strip_prefix('(')
+        .and_then(|text| text.strip_suffix(')'))
+        .unwrap_or(text);
+    text.parse()
+}
+
+/// Splits a whitespace-separated list of values.
+pub(crate) fn parse_list(text: &str) -> Vec<String> {
+    text.split_whitespace().map(String::from).collect()
+}
+
+/// Parses a boolean value like OptiFine, which only accepts `true` and
+/// `false`.
+pub(crate) fn parse_bool(text: &str) -> Option<bool> {
+    match text.trim() {
+        "true" => Some(true),
+        "false" => Some(false),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_escapes_and_separators_like_java() {
+        let properties = Properties::parse(
+            "! comment\n\
+             a\\=b=c\\:d\n\
+             spaced   value with spaces  \n\
+             unicode=\\u00e9t\\u00E9\n\
+             empty\n\
+             continued=one\\\n   two\\\\\n\
+             tabs\\tin\\tvalue=\\t\n",
+        );
+
+        assert_eq!(properties.get("a=b"), Some("c:d"));
+        assert_eq!(properties.get("spaced"), Some("value with spaces  "));
+        assert_eq!(properties.get("unicode"), Some("été"));
+        assert_eq!(properties.get("empty"), Some(""));
+        assert_eq!(properties.get("continued"), Some("onetwo\\"));
+        assert_eq!(properties.get("tabs\tin\tvalue"), Some("\t"));
+        assert_eq!(properties.entries.len(), 6);
+    }
+}
@@ -0,0 +1,327 @@
+//! Serde-(de)serializable data types for `pack.mcmeta`.
+//!
+//! Start here: [`PackMeta`].
+//!
+//! See <https://minecraft.fandom.com/wiki/Pack.mcmeta>.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::schemas::text::TextComponent;
+
+/// The metadata of a resource pack or data pack, as stored in the
+/// `pack.mcmeta` file at the root of the pack.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::pack_meta::*;
+/// let meta: PackMeta = serde_json::from_str(r#"
+///     {
+///         "pack": {
+///             "pack_format": 18,
+///             "supported_formats": [15, 18],
+///             "description": "My resource pack"
+///         },
+///         "filter": {
+///             "block": [
+///                 { "namespace": "minecraft", "path": "textures/block/.*" }
+///             ]
+///         },
+///         "overlays": {
+///             "entries": [
+///                 { "formats": { "min_inclusive": 16, "max_inclusive": 18 }, "directory": "new" }
+///             ]
+///         },
+///         "language": {
+///             "tlh_aa": { "name": "tlhIngan Hol", "region": "Qo'noS" }
+///         }
+///     }
+/// "#).unwrap();
+///
+/// assert_eq!(meta.pack.pack_format, 18);
+/// assert_eq!(meta.pack.description.as_str(), Some("My resource pack"));
+/// assert!(meta.pack.supports_format(15));
+/// assert!(!meta.pack.supports_format(19));
+///
+/// let filter = meta.filter.unwrap();
+/// assert_eq!(filter.block[0].namespace.as_deref(), Some("minecraft"));
+///
+/// let overlays = meta.overlays.unwrap();
+/// assert!(overlays.entries[0].formats.contains(17));
+/// assert_eq!(overlays.applicable(15).count(), 0);
+/// assert_eq!(overlays.entries[0].directory, "new");
+///
+/// assert!(!meta.language["tlh_aa"].bidirectional);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PackMeta {
+    /// The `pack` section, describing the pack itself.
+    pub pack: PackSection,
+
+    /// Resources of lower priority packs that this pack hides.
+    ///
+    /// Added in 1.19.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<PackFilter>,
+
+    /// Sub-packs that are applied on top of this pack for certain pack
+    /// formats.
+    ///
+    /// Added in 1.20.2.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overlays: Option<PackOverlays>,
+
+    /// Map of language codes (e.g., `en_us`) to language definitions that
+    /// this pack adds.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub language: HashMap<String, LanguageDefinition>,
+
+    /// The fields that this crate does not model (e.g., fields added by mods
+    /// or by newer versions), kept so that they survive a round trip.
+    ///
+    /// Requires the `preserve-unknown` feature.
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten, serialize_with = "crate::schemas::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// The `pack` section of a [`PackMeta`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PackSection {
+    /// The pack format version the pack was made for.
+    pub pack_format: u32,
+
+    /// The range of pack format versions the pack supports, in addition to
+    /// [`pack_format`][Self::pack_format].
+    ///
+    /// Added in 1.20.2.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supported_formats: Option<SupportedFormats>,
+
+    /// The description of the pack shown in the pack selection screen.
+    pub description: PackDescription,
+
+    /// The fields that this crate does not model (e.g., fields added by mods
+    /// or by newer versions), kept so that they survive a round trip.
+    ///
+    /// Requires the `preserve-unknown` feature.
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten, serialize_with = "crate::schemas::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl PackMeta {
+    /// Returns the metadata of a pack with the given pack format and
+    /// description.
+    pub fn new(pack_format: u32, description: impl Into<String>) -> Self {
+        Self {
+            pack: PackSection {
+                pack_format,
+                supported_formats: None,
+                description: PackDescription::String(description.into()),
+                #[cfg(feature = "preserve-unknown")]
+                extra: Default::default(),
+            },
+            filter: None,
+            overlays: None,
+            language: HashMap::new(),
+            #[cfg(feature = "preserve-unknown")]
+            extra: Default::default(),
+        }
+    }
+}
+
+impl PackSection {
+    /// Returns `true` if the pack declares support for the given pack format,
+    /// either through [`pack_format`][Self::pack_format] or
+    /// [`supported_formats`][Self::supported_formats].
+    pub fn supports_format(&self, format: u32) -> bool {
+        self.pack_format == format
+            || self
+                .supported_formats
+                .as_ref()
+                .is_some_and(|formats| formats.contains(format))
+    }
+}
+
+/// The description of a pack.
+///
+/// This is either a plain string, or a text component.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum PackDescription {
+    /// A plain string.
+    String(String),
+
+    /// A text component.
+    Component(TextComponent),
+}
+
+impl PackDescription {
+    /// Returns the description if it is a plain string.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(s) => Some(s),
+            Self::Component(TextComponent::String(s)) => Some(s),
+            Self::Component(_) => None,
+        }
+    }
+}
+
+impl Default for PackDescription {
+    fn default() -> Self {
+        Self::String(String::new())
+    }
+}
+
+/// A range of pack format versions.
+///
+/// This is either a single version, a `[min, max]` list, or an object with
+/// `min_inclusive` and `max_inclusive` fields.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum SupportedFormats {
+    /// A single pack format version.
+    Exact(u32),
+
+    /// An inclusive `[min, max]` range of pack format versions.
+    List([u32; 2]),
+
+    /// An inclusive range of pack format versions.
+    Range {
+        /// The lowest supported pack format version.
+        min_inclusive: u32,
+
+        /// The highest supported pack format version.
+        max_inclusive: u32,
+    },
+}
+
+impl SupportedFormats {
+    /// Returns the lowest and highest pack format versions in the range.
+    pub fn bounds(&self) -> (u32, u32) {
+        match *self {
+            Self::Exact(format) => (format, format),
+            Self::List([min, max]) => (min, max),
+            Self::Range {
+                min_inclusive,
+                max_inclusive,
+            } => (min_inclusive, max_inclusive),
+        }
+    }
+
+    /// Returns `true` if the given pack format version is in the range.
+    pub fn contains(&self, format: u32) -> bool {
+        let (min, max) = self.bounds();
+        (min..=max).contains(&format)
+    }
+}
+
+/// The `filter` section of a [`PackMeta`].
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PackFilter {
+    /// Patterns of files from lower priority packs that are hidden.
+    #[serde(default)]
+    pub block: Vec<ResourcePattern>,
+}
+
+/// A pattern matching resources in a [`PackFilter`].
+///
+/// A resource matches if both its namespace and its path match the given
+/// regular expressions.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ResourcePattern {
+    /// A regular expression for the namespace. Matches any namespace if
+    /// absent.
+    pub namespace: Option<String>,
+
+    /// A regular expression for the path within the namespace (e.g.,
+    /// `textures/block/.*`). Matches any path if absent.
+    pub path: Option<String>,
+}
+
+impl ResourcePattern {
+    /// Returns `true` if the given namespace and path match the pattern.
+    ///
+    /// Like in the game, the regular expressions have to match the whole
+    /// namespace or path. Invalid regular expressions match nothing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::pack_meta::*;
+    /// let pattern = ResourcePattern {
+    ///     namespace: Some(String::from("minecraft")),
+    ///     path: Some(String::from("block/debug.*")),
+    /// };
+    ///
+    /// assert!(pattern.matches("minecraft", "block/debug2"));
+    /// assert!(!pattern.matches("minecraft", "item/block/debug"));
+    /// assert!(!pattern.matches("mypack", "block/debug"));
+    /// ```
+    pub fn matches(&self, namespace: &str, path: &str) -> bool {
+        fn full_match(pattern: &Option<String>, text: &str) -> bool {
+            match pattern {
+                Some(pattern) => regex::Regex::new(&format!("^(?:{})$", pattern))
+                    .is_ok_and(|regex| regex.is_match(text)),
+                None => true,
+            }
+        }
+
+        full_match(&self.namespace, namespace) && full_match(&self.path, path)
+    }
+}
+
+/// The `overlays` section of a [`PackMeta`].
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PackOverlays {
+    /// The overlays of the pack, in order of increasing priority.
+    #[serde(default)]
+    pub entries: Vec<OverlayEntry>,
+}
+
+impl PackOverlays {
+    /// Returns the overlays that are applied for the given pack format, in
+    /// order of increasing priority.
+    pub fn applicable(&self, format: u32) -> impl Iterator<Item = &OverlayEntry> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.formats.contains(format))
+    }
+}
+
+/// An overlay in the [`PackOverlays`] of a [`PackMeta`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct OverlayEntry {
+    /// The pack format versions for which the overlay is applied.
+    pub formats: SupportedFormats,
+
+    /// The directory, relative to the pack root, containing the overlay's
+    /// `assets/` and/or `data/` directories.
+    pub directory: String,
+}
+
+/// A language added by a pack, in the `language` section of a [`PackMeta`].
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct LanguageDefinition {
+    /// The full name of the language, shown in the language menu.
+    pub name: String,
+
+    /// The country or region name, shown in the language menu.
+    pub region: String,
+
+    /// Whether the language is written right-to-left. Defaults to `false`.
+    #[serde(default)]
+    pub bidirectional: bool,
+}
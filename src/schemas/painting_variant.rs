@@ -0,0 +1,64 @@
+//! Serde-(de)serializable data types for
+//! `data/<namespace>/painting_variant/*.json`.
+//!
+//! Start here: [`PaintingVariant`].
+//!
+//! Painting variants became data-driven in 1.21.
+//!
+//! See <https://minecraft.fandom.com/wiki/Painting_variant_definition>.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{api::ResourceLocation, schemas::text::TextComponent};
+
+/// A painting variant as stored in the `data/<namespace>/painting_variant/`
+/// directory.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::painting_variant::*;
+/// let variant: PaintingVariant = serde_json::from_str(r#"
+///     {
+///         "asset_id": "minecraft:kebab",
+///         "width": 1,
+///         "height": 1,
+///         "title": { "translate": "painting.minecraft.kebab.title", "color": "yellow" },
+///         "author": { "translate": "painting.minecraft.kebab.author", "color": "gray" }
+///     }
+/// "#).unwrap();
+///
+/// assert_eq!(variant.asset_id, "minecraft:kebab");
+/// assert_eq!((variant.width, variant.height), (1, 1));
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PaintingVariant {
+    /// The namespaced ID of the painting texture, relative to
+    /// `textures/painting/`.
+    pub asset_id: ResourceLocation<'static>,
+
+    /// The width of the painting, in blocks.
+    pub width: u32,
+
+    /// The height of the painting, in blocks.
+    pub height: u32,
+
+    /// The title of the painting, as a JSON text component.
+    ///
+    /// Added in 1.21.2.
+    pub title: Option<TextComponent>,
+
+    /// The author of the painting, as a JSON text component.
+    ///
+    /// Added in 1.21.2.
+    pub author: Option<TextComponent>,
+
+    /// The fields that this crate does not model (e.g., fields added by mods
+    /// or by newer versions), kept so that they survive a round trip.
+    ///
+    /// Requires the `preserve-unknown` feature.
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten, serialize_with = "crate::schemas::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
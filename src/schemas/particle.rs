@@ -0,0 +1,55 @@
+//! Serde-(de)serializable data types for
+//! `assets/<namespace>/particles/*.json`.
+//!
+//! Start here: [`Particle`].
+//!
+//! See <https://minecraft.fandom.com/wiki/Resource_pack#Particles>.
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::ResourceLocation;
+
+/// A particle definition as stored in the `assets/<namespace>/particles/`
+/// directory.
+///
+/// The file name is the particle type's ID (e.g., `flame.json` for
+/// `minecraft:flame`).
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::particle::*;
+/// let particle: Particle = serde_json::from_str(r#"
+///     {
+///         "textures": [
+///             "minecraft:generic_0",
+///             "minecraft:generic_1",
+///             "minecraft:generic_2"
+///         ]
+///     }
+/// "#).unwrap();
+///
+/// assert_eq!(particle.textures.len(), 3);
+/// assert_eq!(particle.textures[0], "minecraft:generic_0");
+/// ```
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Particle {
+    /// The textures of the particle, relative to `textures/particle/` and
+    /// without the `.png` extension.
+    ///
+    /// Particles with multiple textures pick one at random or play through
+    /// them as an animation, depending on the particle type. Particles that
+    /// are rendered without a sprite (e.g., `minecraft:block`) have no
+    /// textures.
+    #[serde(default)]
+    pub textures: Vec<ResourceLocation<'static>>,
+
+    /// The fields that this crate does not model (e.g., fields added by mods
+    /// or by newer versions), kept so that they survive a round trip.
+    ///
+    /// Requires the `preserve-unknown` feature.
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten, serialize_with = "crate::schemas::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
@@ -0,0 +1,724 @@
+//! Serde-(de)serializable data types for
+//! `data/<namespace>/predicates/*.json`.
+//!
+//! Start here: [`Predicate`] and [`LootCondition`].
+//!
+//! The same conditions are also used by loot tables and item modifiers, and
+//! the entity, location, item, and damage source sub-predicates are shared
+//! with advancement triggers.
+//!
+//! See <https://minecraft.fandom.com/wiki/Predicate>.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::ResourceLocation,
+    schemas::{components::DataComponents, loot_table::NumberProvider, reference::IdList},
+};
+
+/// A predicate as stored in the `data/<namespace>/predicates/` directory.
+///
+/// A predicate file contains either a single [`LootCondition`], or a list of
+/// conditions that all have to pass.
+///
+/// See also the corresponding section of the [wiki page].
+///
+/// [wiki page]: <https://minecraft.fandom.com/wiki/Predicate#JSON_structure>
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::predicate::*;
+/// let predicate: Predicate = serde_json::from_str(r#"
+///     {
+///         "condition": "minecraft:entity_properties",
+///         "entity": "this",
+///         "predicate": {
+///             "flags": { "is_sneaking": true },
+///             "equipment": {
+///                 "mainhand": { "items": ["minecraft:stick"] }
+///             }
+///         }
+///     }
+/// "#).unwrap();
+///
+/// let condition = &predicate.conditions()[0];
+/// let (entity, predicate) = match condition {
+///     LootCondition::EntityProperties { entity, predicate } => (entity, predicate),
+///     _ => unreachable!(),
+/// };
+///
+/// assert_eq!(entity, "this");
+/// assert_eq!(predicate.flags.as_ref().unwrap().is_sneaking, Some(true));
+///
+/// let predicate: Predicate = serde_json::from_str(r#"
+///     [
+///         { "condition": "minecraft:random_chance", "chance": 0.5 },
+///         { "condition": "minecraft:weather_check", "raining": true }
+///     ]
+/// "#).unwrap();
+///
+/// assert_eq!(predicate.conditions().len(), 2);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum Predicate {
+    /// A predicate consisting of one condition.
+    Single(LootCondition),
+
+    /// A predicate consisting of a list of conditions that all have to pass.
+    Multiple(Vec<LootCondition>),
+}
+
+impl Predicate {
+    /// Returns all of the conditions of this predicate as a slice.
+    ///
+    /// The slice will contain one element for a [`Single`][Self::Single]
+    /// predicate, and multiple for a [`Multiple`][Self::Multiple] predicate.
+    pub fn conditions(&self) -> &[LootCondition] {
+        match self {
+            Self::Single(condition) => std::slice::from_ref(condition),
+            Self::Multiple(conditions) => &conditions[..],
+        }
+    }
+}
+
+impl Default for Predicate {
+    fn default() -> Self {
+        Self::Multiple(Vec::new())
+    }
+}
+
+/// A loot condition (predicate) that determines whether a loot table, pool,
+/// entry, or function is used, or whether a predicate passes.
+///
+/// The condition's `condition` field determines which variant is used. Both
+/// the namespaced (`"minecraft:random_chance"`) and bare (`"random_chance"`)
+/// forms are accepted.
+///
+/// See also the corresponding section of the [wiki page].
+///
+/// [wiki page]: <https://minecraft.fandom.com/wiki/Predicate#JSON_structure>
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(tag = "condition")]
+pub enum LootCondition {
+    /// Passes if all of the terms pass.
+    ///
+    /// Added in 1.20.
+    #[serde(rename = "minecraft:all_of", alias = "all_of")]
+    AllOf {
+        /// The conditions to test.
+        terms: Vec<LootCondition>,
+    },
+
+    /// Passes if any of the terms pass.
+    ///
+    /// Prior to 1.20 this condition was called `alternative`. Both names are
+    /// accepted, but it is always serialized as `any_of`.
+    #[serde(
+        rename = "minecraft:any_of",
+        alias = "any_of",
+        alias = "minecraft:alternative",
+        alias = "alternative"
+    )]
+    AnyOf {
+        /// The conditions to test.
+        terms: Vec<LootCondition>,
+    },
+
+    /// Passes if the term does not pass.
+    #[serde(rename = "minecraft:inverted", alias = "inverted")]
+    Inverted {
+        /// The condition to invert.
+        term: Box<LootCondition>,
+    },
+
+    /// Checks the block and its block state properties.
+    #[serde(
+        rename = "minecraft:block_state_property",
+        alias = "block_state_property"
+    )]
+    BlockStateProperty {
+        /// The namespaced ID of the block.
+        block: ResourceLocation<'static>,
+
+        /// Map of block state names to the values (or `{min, max}` ranges)
+        /// they must have.
+        #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+        properties: HashMap<String, serde_json::Value>,
+    },
+
+    /// Checks properties of the damage source.
+    #[serde(
+        rename = "minecraft:damage_source_properties",
+        alias = "damage_source_properties"
+    )]
+    DamageSourceProperties {
+        /// The damage source predicate to test.
+        predicate: Box<DamageSourcePredicate>,
+    },
+
+    /// Checks whether an enchantment is active.
+    ///
+    /// Added in 1.21.
+    #[serde(
+        rename = "minecraft:enchantment_active_check",
+        alias = "enchantment_active_check"
+    )]
+    EnchantmentActiveCheck {
+        /// Whether the enchantment should be active.
+        active: bool,
+    },
+
+    /// Checks properties of an entity.
+    #[serde(rename = "minecraft:entity_properties", alias = "entity_properties")]
+    EntityProperties {
+        /// The entity to check: `this`, `attacker`, `direct_attacker`,
+        /// `killer`, `direct_killer`, or `killer_player`.
+        entity: String,
+
+        /// The entity predicate to test.
+        #[serde(default)]
+        predicate: Box<EntityPredicate>,
+    },
+
+    /// Checks the scoreboard scores of an entity.
+    #[serde(rename = "minecraft:entity_scores", alias = "entity_scores")]
+    EntityScores {
+        /// The entity to check.
+        entity: String,
+
+        /// Map of scoreboard objectives to the required score (or range).
+        scores: HashMap<String, NumberRange>,
+    },
+
+    /// Checks if there is a `killer_player` in the loot context.
+    #[serde(rename = "minecraft:killed_by_player", alias = "killed_by_player")]
+    KilledByPlayer {
+        /// If `true`, the condition passes if there is **no** killer player.
+        ///
+        /// Removed in 1.20.5.
+        inverse: Option<bool>,
+    },
+
+    /// Checks the current location against a location predicate.
+    #[serde(rename = "minecraft:location_check", alias = "location_check")]
+    LocationCheck {
+        /// An optional x offset to the location.
+        #[serde(rename = "offsetX")]
+        offset_x: Option<i32>,
+
+        /// An optional y offset to the location.
+        #[serde(rename = "offsetY")]
+        offset_y: Option<i32>,
+
+        /// An optional z offset to the location.
+        #[serde(rename = "offsetZ")]
+        offset_z: Option<i32>,
+
+        /// The location predicate to test.
+        #[serde(default)]
+        predicate: Box<LocationPredicate>,
+    },
+
+    /// Checks the tool used to mine the block.
+    #[serde(rename = "minecraft:match_tool", alias = "match_tool")]
+    MatchTool {
+        /// The item predicate to test.
+        #[serde(default)]
+        predicate: Box<ItemPredicate>,
+    },
+
+    /// Generates a random number between 0.0 and 1.0, and checks if it is
+    /// less than a specified value.
+    #[serde(rename = "minecraft:random_chance", alias = "random_chance")]
+    RandomChance {
+        /// Success rate as a number between 0.0 and 1.0.
+        ///
+        /// Since 1.21 this can be any number provider.
+        chance: NumberProvider,
+    },
+
+    /// Like [`RandomChance`][Self::RandomChance], but with the looting level
+    /// taken into account.
+    ///
+    /// Replaced by `random_chance_with_enchanted_bonus` in 1.21.
+    #[serde(
+        rename = "minecraft:random_chance_with_looting",
+        alias = "random_chance_with_looting"
+    )]
+    RandomChanceWithLooting {
+        /// Base success rate.
+        chance: f32,
+
+        /// Looting adjustment to the base success rate. The formula is
+        /// `chance + (looting_level * looting_multiplier)`.
+        looting_multiplier: f32,
+    },
+
+    /// Like [`RandomChance`][Self::RandomChance], but with the level of an
+    /// enchantment taken into account.
+    ///
+    /// Added in 1.21.
+    #[serde(
+        rename = "minecraft:random_chance_with_enchanted_bonus",
+        alias = "random_chance_with_enchanted_bonus"
+    )]
+    RandomChanceWithEnchantedBonus {
+        /// The success rate if the enchantment is not present.
+        unenchanted_chance: f32,
+
+        /// The level-based success rate if the enchantment is present.
+        enchanted_chance: serde_json::Value,
+
+        /// The namespaced ID of the enchantment.
+        enchantment: ResourceLocation<'static>,
+    },
+
+    /// Invokes another predicate file and returns its result.
+    #[serde(rename = "minecraft:reference", alias = "reference")]
+    Reference {
+        /// The namespaced ID of the predicate to invoke.
+        name: ResourceLocation<'static>,
+    },
+
+    /// Returns success with `1 ÷ explosion radius` probability.
+    #[serde(rename = "minecraft:survives_explosion", alias = "survives_explosion")]
+    SurvivesExplosion,
+
+    /// Passes with a probability picked from a list, indexed by enchantment
+    /// level.
+    #[serde(rename = "minecraft:table_bonus", alias = "table_bonus")]
+    TableBonus {
+        /// The namespaced ID of the enchantment.
+        enchantment: ResourceLocation<'static>,
+
+        /// List of probabilities for enchantment level, indexed from 0.
+        chances: Vec<f32>,
+    },
+
+    /// Compares the current day time (or rather, `24000 * day count + day
+    /// time`) against given values.
+    #[serde(rename = "minecraft:time_check", alias = "time_check")]
+    TimeCheck {
+        /// The time value in ticks (or range of values).
+        value: NumberRange,
+
+        /// If present, the game time is first reduced modulo the given number
+        /// before being checked against `value`.
+        period: Option<u64>,
+    },
+
+    /// Compares a number against another number or range of numbers.
+    #[serde(rename = "minecraft:value_check", alias = "value_check")]
+    ValueCheck {
+        /// The number to test.
+        value: NumberProvider,
+
+        /// The value (or range of values) to test against.
+        range: NumberRange,
+    },
+
+    /// Checks the current game weather.
+    #[serde(rename = "minecraft:weather_check", alias = "weather_check")]
+    WeatherCheck {
+        /// If `true`, the condition passes only if it is raining or
+        /// thundering.
+        raining: Option<bool>,
+
+        /// If `true`, the condition passes only if it is thundering.
+        thundering: Option<bool>,
+    },
+}
+
+/// A number (or range of numbers) that a value is compared against.
+///
+/// The bounds of a range can be any [`NumberProvider`].
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::predicate::*;
+/// let range: NumberRange = serde_json::from_str("5").unwrap();
+/// assert_eq!(range, NumberRange::Exact(5));
+///
+/// let range: NumberRange = serde_json::from_str(r#"{ "min": 1 }"#).unwrap();
+/// assert_matches::assert_matches!(range, NumberRange::Bounds { min: Some(_), max: None });
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum NumberRange {
+    /// An exact value.
+    Exact(i64),
+
+    /// An inclusive range with optional bounds.
+    Bounds {
+        /// The minimum value.
+        min: Option<NumberProvider>,
+
+        /// The maximum value.
+        max: Option<NumberProvider>,
+    },
+}
+
+/// A number (or range of numbers) used in entity, location, and item
+/// predicates.
+///
+/// Unlike [`NumberRange`], the bounds are plain numbers.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::predicate::*;
+/// let bounds: MinMaxBounds<u32> = serde_json::from_str("3").unwrap();
+/// assert!(bounds.matches(3));
+/// assert!(!bounds.matches(4));
+///
+/// let bounds: MinMaxBounds<f32> = serde_json::from_str(r#"{ "max": 10.5 }"#).unwrap();
+/// assert!(bounds.matches(-3.0));
+/// assert!(!bounds.matches(11.0));
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum MinMaxBounds<T> {
+    /// An exact value.
+    Exact(T),
+
+    /// An inclusive range with optional bounds.
+    Bounds {
+        /// The minimum value.
+        min: Option<T>,
+
+        /// The maximum value.
+        max: Option<T>,
+    },
+}
+
+impl<T: PartialOrd + Copy> MinMaxBounds<T> {
+    /// Returns `true` if the given value lies within the bounds.
+    pub fn matches(&self, value: T) -> bool {
+        match *self {
+            Self::Exact(exact) => value == exact,
+            Self::Bounds { min, max } => {
+                min.map(|min| min <= value).unwrap_or(true)
+                    && max.map(|max| value <= max).unwrap_or(true)
+            }
+        }
+    }
+}
+
+/// Tests properties of an entity.
+///
+/// All fields are optional; a missing field always passes.
+///
+/// See <https://minecraft.fandom.com/wiki/Template:Nbt_inherit/conditions/entity>.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct EntityPredicate {
+    /// The namespaced ID of the entity type, or a `#`-prefixed entity type
+    /// tag.
+    #[serde(rename = "type")]
+    pub kind: Option<ResourceLocation<'static>>,
+
+    /// The distance from the origin of the loot context to the entity.
+    pub distance: Option<DistancePredicate>,
+
+    /// The location of the entity.
+    pub location: Option<LocationPredicate>,
+
+    /// The location of the block the entity is standing on.
+    pub stepping_on: Option<LocationPredicate>,
+
+    /// The status effects of the entity, by effect ID.
+    pub effects: Option<HashMap<String, EffectPredicate>>,
+
+    /// An SNBT string that the entity's NBT data must match, which can be
+    /// parsed with [`SnbtValue::parse()`][crate::schemas::snbt::SnbtValue::parse].
+    pub nbt: Option<String>,
+
+    /// Boolean flags of the entity.
+    pub flags: Option<EntityFlagsPredicate>,
+
+    /// The items in the equipment slots of the entity.
+    pub equipment: Option<EquipmentPredicate>,
+
+    /// The entity that this entity is riding.
+    pub vehicle: Option<Box<EntityPredicate>>,
+
+    /// The entity directly riding this entity.
+    pub passenger: Option<Box<EntityPredicate>>,
+
+    /// The entity that this entity is targeting for attacks.
+    pub targeted_entity: Option<Box<EntityPredicate>>,
+
+    /// The team the entity belongs to.
+    pub team: Option<String>,
+
+    /// Remaining fields (e.g., `player`, `type_specific`, `movement`,
+    /// `slots`), kept as raw JSON.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Tests the distance between two points.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DistancePredicate {
+    /// The 3D distance.
+    pub absolute: Option<MinMaxBounds<f64>>,
+
+    /// The distance ignoring the y coordinate.
+    pub horizontal: Option<MinMaxBounds<f64>>,
+
+    /// The absolute difference of the x coordinates.
+    pub x: Option<MinMaxBounds<f64>>,
+
+    /// The absolute difference of the y coordinates.
+    pub y: Option<MinMaxBounds<f64>>,
+
+    /// The absolute difference of the z coordinates.
+    pub z: Option<MinMaxBounds<f64>>,
+}
+
+/// Tests a status effect of an entity.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct EffectPredicate {
+    /// The effect amplifier.
+    pub amplifier: Option<MinMaxBounds<i32>>,
+
+    /// The effect duration in ticks.
+    pub duration: Option<MinMaxBounds<i32>>,
+
+    /// Whether the effect is from a beacon.
+    pub ambient: Option<bool>,
+
+    /// Whether the effect has visible particles.
+    pub visible: Option<bool>,
+}
+
+/// Tests boolean flags of an entity.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[allow(missing_docs)]
+pub struct EntityFlagsPredicate {
+    pub is_on_fire: Option<bool>,
+    pub is_sneaking: Option<bool>,
+    pub is_sprinting: Option<bool>,
+    pub is_swimming: Option<bool>,
+    pub is_baby: Option<bool>,
+
+    /// Added in 1.21.
+    pub is_on_ground: Option<bool>,
+
+    /// Added in 1.21.
+    pub is_flying: Option<bool>,
+}
+
+/// Tests the items in the equipment slots of an entity.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[allow(missing_docs)]
+pub struct EquipmentPredicate {
+    pub mainhand: Option<ItemPredicate>,
+    pub offhand: Option<ItemPredicate>,
+    pub head: Option<ItemPredicate>,
+    pub chest: Option<ItemPredicate>,
+    pub legs: Option<ItemPredicate>,
+    pub feet: Option<ItemPredicate>,
+
+    /// Added in 1.20.5.
+    pub body: Option<ItemPredicate>,
+}
+
+/// Tests properties of a location.
+///
+/// All fields are optional; a missing field always passes.
+///
+/// See <https://minecraft.fandom.com/wiki/Template:Nbt_inherit/conditions/location>.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct LocationPredicate {
+    /// The coordinates of the location.
+    pub position: Option<PositionPredicate>,
+
+    /// The namespaced ID of the biome the location is in.
+    ///
+    /// Replaced by `biomes` in 1.20.5.
+    pub biome: Option<ResourceLocation<'static>>,
+
+    /// The biome(s) (or `#`-prefixed biome tag) the location is in.
+    ///
+    /// Added in 1.20.5.
+    pub biomes: Option<IdList>,
+
+    /// The namespaced ID of the structure the location is in.
+    ///
+    /// Replaced by `structures` in 1.20.5.
+    pub structure: Option<ResourceLocation<'static>>,
+
+    /// The structure(s) (or `#`-prefixed structure tag) the location is in.
+    ///
+    /// Added in 1.20.5.
+    pub structures: Option<IdList>,
+
+    /// The namespaced ID of the dimension the location is in.
+    pub dimension: Option<ResourceLocation<'static>>,
+
+    /// Whether the location is the position of a campfire's smoke.
+    pub smokey: Option<bool>,
+
+    /// The light level of the location.
+    pub light: Option<LightPredicate>,
+
+    /// Whether the location has visibility of the sky.
+    ///
+    /// Added in 1.20.5.
+    pub can_see_sky: Option<bool>,
+
+    /// The block at the location, kept as raw JSON.
+    pub block: Option<serde_json::Value>,
+
+    /// The fluid at the location, kept as raw JSON.
+    pub fluid: Option<serde_json::Value>,
+
+    /// Remaining fields (e.g., the pre-1.19 `feature`), kept as raw JSON.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Tests the coordinates of a location.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[allow(missing_docs)]
+pub struct PositionPredicate {
+    pub x: Option<MinMaxBounds<f64>>,
+    pub y: Option<MinMaxBounds<f64>>,
+    pub z: Option<MinMaxBounds<f64>>,
+}
+
+/// Tests the light level of a location.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct LightPredicate {
+    /// The visible light level (max of sky and block light).
+    pub light: Option<MinMaxBounds<u8>>,
+}
+
+/// Tests properties of an item stack.
+///
+/// All fields are optional; a missing field always passes.
+///
+/// See <https://minecraft.fandom.com/wiki/Template:Nbt_inherit/conditions/item>.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ItemPredicate {
+    /// The item ID(s) (or, since 1.20.5, a `#`-prefixed item tag) the item
+    /// must be.
+    pub items: Option<IdList>,
+
+    /// The item tag the item must be in (without the `#`).
+    ///
+    /// Removed in 1.20.5.
+    pub tag: Option<String>,
+
+    /// The amount of items in the stack.
+    pub count: Option<MinMaxBounds<u32>>,
+
+    /// The remaining durability of the item.
+    ///
+    /// Moved to `predicates` in 1.20.5.
+    pub durability: Option<MinMaxBounds<u32>>,
+
+    /// The namespaced ID of the potion of the item.
+    ///
+    /// Moved to `predicates` in 1.20.5.
+    pub potion: Option<ResourceLocation<'static>>,
+
+    /// An SNBT string that the item's NBT data must match, which can be
+    /// parsed with [`SnbtValue::parse()`][crate::schemas::snbt::SnbtValue::parse].
+    ///
+    /// Removed in 1.20.5.
+    pub nbt: Option<String>,
+
+    /// The enchantments the item must have.
+    ///
+    /// Moved to `predicates` in 1.20.5.
+    pub enchantments: Option<Vec<EnchantmentPredicate>>,
+
+    /// The stored enchantments (of an enchanted book) the item must have.
+    ///
+    /// Moved to `predicates` in 1.20.5.
+    pub stored_enchantments: Option<Vec<EnchantmentPredicate>>,
+
+    /// Exact data components the item must have.
+    ///
+    /// Added in 1.20.5.
+    pub components: Option<DataComponents>,
+
+    /// Item sub-predicates, kept as raw JSON.
+    ///
+    /// Added in 1.20.5.
+    pub predicates: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+/// Tests an enchantment of an item.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct EnchantmentPredicate {
+    /// The namespaced ID of the enchantment.
+    ///
+    /// Since 1.20.5 this field is called `enchantments` and accepts a list or
+    /// tag.
+    pub enchantment: Option<ResourceLocation<'static>>,
+
+    /// The level of the enchantment.
+    pub levels: Option<MinMaxBounds<u32>>,
+}
+
+/// Tests properties of a damage source.
+///
+/// All fields are optional; a missing field always passes.
+///
+/// See <https://minecraft.fandom.com/wiki/Template:Nbt_inherit/conditions/damage_type>.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DamageSourcePredicate {
+    /// Damage type tags that the damage source must (or must not) be in.
+    ///
+    /// Added in 1.19.4, replacing the `is_*` and `bypasses_*` flags.
+    pub tags: Option<Vec<DamageTagPredicate>>,
+
+    /// The entity that was the ultimate source of the damage.
+    pub source_entity: Option<EntityPredicate>,
+
+    /// The entity that was the direct cause of the damage.
+    pub direct_entity: Option<EntityPredicate>,
+
+    /// Whether the damage was dealt directly.
+    ///
+    /// Added in 1.20.5.
+    pub is_direct: Option<bool>,
+
+    /// Remaining fields (e.g., the pre-1.19.4 `is_projectile` or
+    /// `bypasses_armor` flags), kept as raw JSON.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Tests whether a damage source is in a damage type tag.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DamageTagPredicate {
+    /// The namespaced ID of the damage type tag.
+    pub id: ResourceLocation<'static>,
+
+    /// Whether the damage source is expected to be in the tag.
+    pub expected: bool,
+}
@@ -0,0 +1,545 @@
+//! Serde-(de)serializable number providers shared by many schemas.
+//!
+//! Data-driven fields that hold a random number accept either a bare value,
+//! which is shorthand for a constant, or an object whose `type` field selects
+//! a distribution:
+//!
+//! * [`IntProvider`] and [`FloatProvider`] are used by placed features,
+//!   configured features, and carvers.
+//! * [`HeightProvider`] picks a y level between two [`VerticalAnchor`]s, e.g.,
+//!   for ores and carvers. The anchors are defined in the
+//!   [`height`][crate::schemas::height] module, and re-exported here.
+//! * [`NumberProvider`] is used by loot tables, predicates, and item
+//!   modifiers.
+//!
+//! # Example
+//!
+//! ```
+//! # use minecraft_assets::schemas::providers::*;
+//! let count: IntProvider = serde_json::from_str("4").unwrap();
+//! assert_eq!(count, IntProvider::Constant(4));
+//!
+//! let count: IntProvider = serde_json::from_str(r#"{
+//!     "type": "minecraft:uniform",
+//!     "min_inclusive": 0,
+//!     "max_inclusive": 2
+//! }"#).unwrap();
+//! assert_eq!((count.min_value(), count.max_value()), (0, 2));
+//!
+//! let height: HeightProvider = serde_json::from_str(r#"{
+//!     "type": "minecraft:trapezoid",
+//!     "min_inclusive": { "above_bottom": 0 },
+//!     "max_inclusive": { "absolute": 16 }
+//! }"#).unwrap();
+//! assert!(matches!(height, HeightProvider::Typed(TypedHeightProvider::Trapezoid { .. })));
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+pub use crate::schemas::height::VerticalAnchor;
+
+/// An integer provider.
+///
+/// A bare integer is shorthand for a [`Constant`][Self::Constant].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum IntProvider {
+    /// A constant value.
+    Constant(i32),
+
+    /// A provider with an explicit `type` field.
+    Typed(TypedIntProvider),
+}
+
+impl IntProvider {
+    /// Returns the smallest value that the provider can return.
+    pub fn min_value(&self) -> i32 {
+        match self {
+            Self::Constant(value) => *value,
+            Self::Typed(typed) => typed.min_value(),
+        }
+    }
+
+    /// Returns the largest value that the provider can return.
+    pub fn max_value(&self) -> i32 {
+        match self {
+            Self::Constant(value) => *value,
+            Self::Typed(typed) => typed.max_value(),
+        }
+    }
+}
+
+impl Default for IntProvider {
+    fn default() -> Self {
+        Self::Constant(0)
+    }
+}
+
+impl From<i32> for IntProvider {
+    fn from(source: i32) -> Self {
+        Self::Constant(source)
+    }
+}
+
+/// An [`IntProvider`] with an explicit `type` field.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(tag = "type")]
+pub enum TypedIntProvider {
+    /// A constant value.
+    #[serde(rename = "minecraft:constant", alias = "constant")]
+    Constant {
+        /// The exact value.
+        value: i32,
+    },
+
+    /// A random number following a uniform distribution between two values.
+    #[serde(rename = "minecraft:uniform", alias = "uniform")]
+    Uniform {
+        /// The minimum value (inclusive).
+        min_inclusive: i32,
+
+        /// The maximum value (inclusive).
+        max_inclusive: i32,
+    },
+
+    /// A random number between two values that is more likely to be small.
+    #[serde(rename = "minecraft:biased_to_bottom", alias = "biased_to_bottom")]
+    BiasedToBottom {
+        /// The minimum value (inclusive).
+        min_inclusive: i32,
+
+        /// The maximum value (inclusive).
+        max_inclusive: i32,
+    },
+
+    /// The value of another provider, clamped between two values.
+    #[serde(rename = "minecraft:clamped", alias = "clamped")]
+    Clamped {
+        /// The provider to clamp.
+        source: Box<IntProvider>,
+
+        /// The minimum value (inclusive).
+        min_inclusive: i32,
+
+        /// The maximum value (inclusive).
+        max_inclusive: i32,
+    },
+
+    /// A random number following a normal distribution, clamped between two
+    /// values.
+    #[serde(rename = "minecraft:clamped_normal", alias = "clamped_normal")]
+    ClampedNormal {
+        /// The mean of the distribution.
+        mean: f32,
+
+        /// The standard deviation of the distribution.
+        deviation: f32,
+
+        /// The minimum value (inclusive).
+        min_inclusive: i32,
+
+        /// The maximum value (inclusive).
+        max_inclusive: i32,
+    },
+
+    /// The value of a provider chosen randomly from a weighted list.
+    #[serde(rename = "minecraft:weighted_list", alias = "weighted_list")]
+    WeightedList {
+        /// The providers to choose from.
+        distribution: Vec<Weighted<IntProvider>>,
+    },
+}
+
+impl TypedIntProvider {
+    fn min_value(&self) -> i32 {
+        match self {
+            Self::Constant { value } => *value,
+            Self::Uniform { min_inclusive, .. }
+            | Self::BiasedToBottom { min_inclusive, .. }
+            | Self::ClampedNormal { min_inclusive, .. } => *min_inclusive,
+            Self::Clamped {
+                source,
+                min_inclusive,
+                ..
+            } => source.min_value().max(*min_inclusive),
+            Self::WeightedList { distribution } => distribution
+                .iter()
+                .map(|entry| entry.data.min_value())
+                .min()
+                .unwrap_or(0),
+        }
+    }
+
+    fn max_value(&self) -> i32 {
+        match self {
+            Self::Constant { value } => *value,
+            Self::Uniform { max_inclusive, .. }
+            | Self::BiasedToBottom { max_inclusive, .. }
+            | Self::ClampedNormal { max_inclusive, .. } => *max_inclusive,
+            Self::Clamped {
+                source,
+                max_inclusive,
+                ..
+            } => source.max_value().min(*max_inclusive),
+            Self::WeightedList { distribution } => distribution
+                .iter()
+                .map(|entry| entry.data.max_value())
+                .max()
+                .unwrap_or(0),
+        }
+    }
+}
+
+/// A floating-point number provider.
+///
+/// A bare number is shorthand for a [`Constant`][Self::Constant].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum FloatProvider {
+    /// A constant value.
+    Constant(f32),
+
+    /// A provider with an explicit `type` field.
+    Typed(TypedFloatProvider),
+}
+
+impl FloatProvider {
+    /// Returns the smallest value that the provider can return.
+    pub fn min_value(&self) -> f32 {
+        match self {
+            Self::Constant(value) => *value,
+            Self::Typed(TypedFloatProvider::Constant { value }) => *value,
+            Self::Typed(TypedFloatProvider::Uniform { min_inclusive, .. }) => *min_inclusive,
+            Self::Typed(TypedFloatProvider::ClampedNormal { min, .. })
+            | Self::Typed(TypedFloatProvider::Trapezoid { min, .. }) => *min,
+        }
+    }
+
+    /// Returns the largest value that the provider can return.
+    ///
+    /// For a [`Uniform`][TypedFloatProvider::Uniform] provider, this is the
+    /// exclusive maximum.
+    pub fn max_value(&self) -> f32 {
+        match self {
+            Self::Constant(value) => *value,
+            Self::Typed(TypedFloatProvider::Constant { value }) => *value,
+            Self::Typed(TypedFloatProvider::Uniform { max_exclusive, .. }) => *max_exclusive,
+            Self::Typed(TypedFloatProvider::ClampedNormal { max, .. })
+            | Self::Typed(TypedFloatProvider::Trapezoid { max, .. }) => *max,
+        }
+    }
+}
+
+impl Default for FloatProvider {
+    fn default() -> Self {
+        Self::Constant(0.0)
+    }
+}
+
+impl From<f32> for FloatProvider {
+    fn from(source: f32) -> Self {
+        Self::Constant(source)
+    }
+}
+
+/// A [`FloatProvider`] with an explicit `type` field.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(tag = "type")]
+pub enum TypedFloatProvider {
+    /// A constant value.
+    #[serde(rename = "minecraft:constant", alias = "constant")]
+    Constant {
+        /// The exact value.
+        value: f32,
+    },
+
+    /// A random number following a uniform distribution between two values.
+    #[serde(rename = "minecraft:uniform", alias = "uniform")]
+    Uniform {
+        /// The minimum value (inclusive).
+        min_inclusive: f32,
+
+        /// The maximum value (exclusive).
+        max_exclusive: f32,
+    },
+
+    /// A random number following a normal distribution, clamped between two
+    /// values.
+    #[serde(rename = "minecraft:clamped_normal", alias = "clamped_normal")]
+    ClampedNormal {
+        /// The mean of the distribution.
+        mean: f32,
+
+        /// The standard deviation of the distribution.
+        deviation: f32,
+
+        /// The minimum value.
+        min: f32,
+
+        /// The maximum value.
+        max: f32,
+    },
+
+    /// A random number following a trapezoidal distribution between two
+    /// values.
+    #[serde(rename = "minecraft:trapezoid", alias = "trapezoid")]
+    Trapezoid {
+        /// The minimum value.
+        min: f32,
+
+        /// The maximum value.
+        max: f32,
+
+        /// The length of the range in the middle of the distribution that has
+        /// a uniform probability.
+        plateau: f32,
+    },
+}
+
+/// A provider of a y level, e.g., where to place ores.
+///
+/// A bare [`VerticalAnchor`] is shorthand for a [`Constant`][Self::Constant].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum HeightProvider {
+    /// A constant y level.
+    Constant(VerticalAnchor),
+
+    /// A provider with an explicit `type` field.
+    Typed(TypedHeightProvider),
+}
+
+impl Default for HeightProvider {
+    fn default() -> Self {
+        Self::Constant(VerticalAnchor::Absolute(0))
+    }
+}
+
+impl From<VerticalAnchor> for HeightProvider {
+    fn from(source: VerticalAnchor) -> Self {
+        Self::Constant(source)
+    }
+}
+
+/// A [`HeightProvider`] with an explicit `type` field.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(tag = "type")]
+pub enum TypedHeightProvider {
+    /// A constant y level.
+    #[serde(rename = "minecraft:constant", alias = "constant")]
+    Constant {
+        /// The exact y level.
+        value: VerticalAnchor,
+    },
+
+    /// A random y level following a uniform distribution between two anchors.
+    #[serde(rename = "minecraft:uniform", alias = "uniform")]
+    Uniform {
+        /// The lowest y level (inclusive).
+        min_inclusive: VerticalAnchor,
+
+        /// The highest y level (inclusive).
+        max_inclusive: VerticalAnchor,
+    },
+
+    /// A random y level between two anchors that is more likely to be low.
+    #[serde(rename = "minecraft:biased_to_bottom", alias = "biased_to_bottom")]
+    BiasedToBottom {
+        /// The lowest y level (inclusive).
+        min_inclusive: VerticalAnchor,
+
+        /// The highest y level (inclusive).
+        max_inclusive: VerticalAnchor,
+
+        /// (optional, defaults to 1) How many levels at the bottom are
+        /// excluded from the bias.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        inner: Option<u32>,
+    },
+
+    /// A random y level between two anchors that is even more likely to be
+    /// low than with [`BiasedToBottom`][Self::BiasedToBottom].
+    #[serde(
+        rename = "minecraft:very_biased_to_bottom",
+        alias = "very_biased_to_bottom"
+    )]
+    VeryBiasedToBottom {
+        /// The lowest y level (inclusive).
+        min_inclusive: VerticalAnchor,
+
+        /// The highest y level (inclusive).
+        max_inclusive: VerticalAnchor,
+
+        /// (optional, defaults to 1) How many levels at the bottom are
+        /// excluded from the bias.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        inner: Option<u32>,
+    },
+
+    /// A random y level following a trapezoidal distribution between two
+    /// anchors.
+    #[serde(rename = "minecraft:trapezoid", alias = "trapezoid")]
+    Trapezoid {
+        /// The lowest y level (inclusive).
+        min_inclusive: VerticalAnchor,
+
+        /// The highest y level (inclusive).
+        max_inclusive: VerticalAnchor,
+
+        /// (optional, defaults to 0) The length of the range in the middle of
+        /// the distribution that has a uniform probability.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        plateau: Option<i32>,
+    },
+
+    /// The y level of a provider chosen randomly from a weighted list.
+    #[serde(rename = "minecraft:weighted_list", alias = "weighted_list")]
+    WeightedList {
+        /// The providers to choose from.
+        distribution: Vec<Weighted<HeightProvider>>,
+    },
+}
+
+/// An entry of a weighted list.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Weighted<T> {
+    /// The value of the entry.
+    pub data: T,
+
+    /// The weight of the entry, relative to the other entries of the list.
+    pub weight: u32,
+}
+
+/// A number provider, used to compute a number (e.g., the number of rolls of a
+/// [`LootPool`][crate::schemas::loot_table::LootPool]) when a loot table is invoked.
+///
+/// A bare number is shorthand for a [`Constant`][Self::Constant], and an
+/// object with only `min` and `max` is shorthand for a `minecraft:uniform`
+/// provider.
+///
+/// See <https://minecraft.fandom.com/wiki/Loot_table#Number_providers>.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::providers::*;
+/// let rolls: NumberProvider = serde_json::from_str("2").unwrap();
+/// assert_eq!(rolls, NumberProvider::Constant(2.0));
+///
+/// let rolls: NumberProvider = serde_json::from_str(r#"{ "min": 1, "max": 3 }"#).unwrap();
+/// assert_eq!(rolls, NumberProvider::Uniform {
+///     min: Box::new(NumberProvider::Constant(1.0)),
+///     max: Box::new(NumberProvider::Constant(3.0)),
+/// });
+///
+/// let rolls: NumberProvider = serde_json::from_str(r#"
+///     { "type": "minecraft:binomial", "n": 3, "p": 0.5 }
+/// "#).unwrap();
+/// assert_matches::assert_matches!(rolls, NumberProvider::Typed(TypedNumberProvider::Binomial { .. }));
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum NumberProvider {
+    /// A constant value.
+    Constant(f32),
+
+    /// A provider with an explicit `type` field.
+    Typed(TypedNumberProvider),
+
+    /// A `minecraft:uniform` provider with the `type` field omitted.
+    Uniform {
+        /// The minimum value (inclusive).
+        min: Box<NumberProvider>,
+
+        /// The maximum value (inclusive).
+        max: Box<NumberProvider>,
+    },
+}
+
+impl Default for NumberProvider {
+    fn default() -> Self {
+        Self::Constant(0.0)
+    }
+}
+
+impl From<f32> for NumberProvider {
+    fn from(source: f32) -> Self {
+        Self::Constant(source)
+    }
+}
+
+/// A [`NumberProvider`] with an explicit `type` field.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(tag = "type")]
+pub enum TypedNumberProvider {
+    /// A constant value.
+    #[serde(rename = "minecraft:constant", alias = "constant")]
+    Constant {
+        /// The exact value.
+        value: f32,
+    },
+
+    /// A random number following a uniform distribution between two values
+    /// (inclusive).
+    #[serde(rename = "minecraft:uniform", alias = "uniform")]
+    Uniform {
+        /// The minimum value.
+        min: Box<NumberProvider>,
+
+        /// The maximum value.
+        max: Box<NumberProvider>,
+    },
+
+    /// A random number following a binomial distribution.
+    #[serde(rename = "minecraft:binomial", alias = "binomial")]
+    Binomial {
+        /// The number of trials.
+        n: Box<NumberProvider>,
+
+        /// The probability of success on an individual trial.
+        p: Box<NumberProvider>,
+    },
+
+    /// A scoreboard value.
+    #[serde(rename = "minecraft:score", alias = "score")]
+    Score {
+        /// The scoreboard name provider (target).
+        target: serde_json::Value,
+
+        /// The scoreboard objective.
+        score: String,
+
+        /// Scale to multiply the score before returning it.
+        scale: Option<f32>,
+    },
+
+    /// A value read from command storage.
+    ///
+    /// Added in 1.20.5.
+    #[serde(rename = "minecraft:storage", alias = "storage")]
+    Storage {
+        /// The namespaced ID of the command storage.
+        storage: String,
+
+        /// The NBT path to the value.
+        path: String,
+    },
+
+    /// A value computed from the level of an enchantment.
+    ///
+    /// Added in 1.21.
+    #[serde(rename = "minecraft:enchantment_level", alias = "enchantment_level")]
+    EnchantmentLevel {
+        /// The level-based value to compute.
+        amount: serde_json::Value,
+    },
+}
@@ -0,0 +1,561 @@
+//! Serde-(de)serializable data types for
+//! `data/<namespace>/recipes/*.json`.
+//!
+//! Start here: [`Recipe`].
+//!
+//! See <https://minecraft.fandom.com/wiki/Recipe>.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{api::ResourceLocation, schemas::components::DataComponents};
+
+/// A recipe as stored in the `data/<namespace>/recipes/` directory.
+///
+/// The recipe's `type` field determines which variant is used. Both the
+/// namespaced (`"minecraft:smelting"`) and bare (`"smelting"`) forms of the
+/// type are accepted.
+///
+/// See also the corresponding section of the [wiki page].
+///
+/// [wiki page]: <https://minecraft.fandom.com/wiki/Recipe#JSON_format>
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::recipe::*;
+/// let recipe: Recipe = serde_json::from_str(r##"
+///     {
+///         "type": "minecraft:crafting_shaped",
+///         "pattern": [
+///             "#",
+///             "#"
+///         ],
+///         "key": {
+///             "#": { "tag": "minecraft:planks" }
+///         },
+///         "result": { "item": "minecraft:stick", "count": 4 }
+///     }
+/// "##).unwrap();
+///
+/// let shaped = match recipe {
+///     Recipe::CraftingShaped(shaped) => shaped,
+///     _ => unreachable!(),
+/// };
+///
+/// assert_eq!(shaped.pattern, vec!["#", "#"]);
+/// assert_eq!(shaped.key[&'#'], Ingredient::Tag { tag: "minecraft:planks".parse().unwrap() });
+/// assert_eq!(shaped.result.id(), "minecraft:stick");
+/// assert_eq!(shaped.result.count(), 4);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(tag = "type")]
+#[allow(missing_docs)]
+pub enum Recipe {
+    #[serde(rename = "minecraft:crafting_shaped", alias = "crafting_shaped")]
+    CraftingShaped(ShapedRecipe),
+
+    #[serde(rename = "minecraft:crafting_shapeless", alias = "crafting_shapeless")]
+    CraftingShapeless(ShapelessRecipe),
+
+    /// Added in 1.21.2.
+    #[serde(rename = "minecraft:crafting_transmute", alias = "crafting_transmute")]
+    CraftingTransmute(TransmuteRecipe),
+
+    #[serde(rename = "minecraft:smelting", alias = "smelting")]
+    Smelting(CookingRecipe),
+
+    #[serde(rename = "minecraft:blasting", alias = "blasting")]
+    Blasting(CookingRecipe),
+
+    #[serde(rename = "minecraft:smoking", alias = "smoking")]
+    Smoking(CookingRecipe),
+
+    #[serde(rename = "minecraft:campfire_cooking", alias = "campfire_cooking")]
+    CampfireCooking(CookingRecipe),
+
+    #[serde(rename = "minecraft:stonecutting", alias = "stonecutting")]
+    Stonecutting(StonecuttingRecipe),
+
+    /// The pre-1.20 smithing table recipe (e.g., netherite upgrades).
+    #[serde(rename = "minecraft:smithing", alias = "smithing")]
+    Smithing(LegacySmithingRecipe),
+
+    /// Added in 1.20.
+    #[serde(rename = "minecraft:smithing_transform", alias = "smithing_transform")]
+    SmithingTransform(SmithingTransformRecipe),
+
+    /// Added in 1.20.
+    #[serde(rename = "minecraft:smithing_trim", alias = "smithing_trim")]
+    SmithingTrim(SmithingTrimRecipe),
+
+    /// Added in 1.20.
+    #[serde(
+        rename = "minecraft:crafting_decorated_pot",
+        alias = "crafting_decorated_pot"
+    )]
+    CraftingDecoratedPot(SpecialRecipe),
+
+    #[serde(
+        rename = "minecraft:crafting_special_armordye",
+        alias = "crafting_special_armordye"
+    )]
+    SpecialArmorDye(SpecialRecipe),
+
+    #[serde(
+        rename = "minecraft:crafting_special_bannerduplicate",
+        alias = "crafting_special_bannerduplicate"
+    )]
+    SpecialBannerDuplicate(SpecialRecipe),
+
+    #[serde(
+        rename = "minecraft:crafting_special_bookcloning",
+        alias = "crafting_special_bookcloning"
+    )]
+    SpecialBookCloning(SpecialRecipe),
+
+    #[serde(
+        rename = "minecraft:crafting_special_firework_rocket",
+        alias = "crafting_special_firework_rocket"
+    )]
+    SpecialFireworkRocket(SpecialRecipe),
+
+    #[serde(
+        rename = "minecraft:crafting_special_firework_star",
+        alias = "crafting_special_firework_star"
+    )]
+    SpecialFireworkStar(SpecialRecipe),
+
+    #[serde(
+        rename = "minecraft:crafting_special_firework_star_fade",
+        alias = "crafting_special_firework_star_fade"
+    )]
+    SpecialFireworkStarFade(SpecialRecipe),
+
+    #[serde(
+        rename = "minecraft:crafting_special_mapcloning",
+        alias = "crafting_special_mapcloning"
+    )]
+    SpecialMapCloning(SpecialRecipe),
+
+    #[serde(
+        rename = "minecraft:crafting_special_mapextending",
+        alias = "crafting_special_mapextending"
+    )]
+    SpecialMapExtending(SpecialRecipe),
+
+    #[serde(
+        rename = "minecraft:crafting_special_repairitem",
+        alias = "crafting_special_repairitem"
+    )]
+    SpecialRepairItem(SpecialRecipe),
+
+    #[serde(
+        rename = "minecraft:crafting_special_shielddecoration",
+        alias = "crafting_special_shielddecoration"
+    )]
+    SpecialShieldDecoration(SpecialRecipe),
+
+    #[serde(
+        rename = "minecraft:crafting_special_shulkerboxcoloring",
+        alias = "crafting_special_shulkerboxcoloring"
+    )]
+    SpecialShulkerBoxColoring(SpecialRecipe),
+
+    #[serde(
+        rename = "minecraft:crafting_special_suspiciousstew",
+        alias = "crafting_special_suspiciousstew"
+    )]
+    SpecialSuspiciousStew(SpecialRecipe),
+
+    #[serde(
+        rename = "minecraft:crafting_special_tippedarrow",
+        alias = "crafting_special_tippedarrow"
+    )]
+    SpecialTippedArrow(SpecialRecipe),
+}
+
+impl Recipe {
+    /// Returns `true` if this is one of the `crafting_special_*` (or
+    /// `crafting_decorated_pot`) recipes whose behavior is hardcoded in the
+    /// game.
+    pub fn is_special(&self) -> bool {
+        self.as_special().is_some()
+    }
+
+    /// Returns the [`SpecialRecipe`] contents of a hardcoded recipe, or `None`
+    /// if this is a regular recipe.
+    pub fn as_special(&self) -> Option<&SpecialRecipe> {
+        match self {
+            Self::CraftingDecoratedPot(recipe)
+            | Self::SpecialArmorDye(recipe)
+            | Self::SpecialBannerDuplicate(recipe)
+            | Self::SpecialBookCloning(recipe)
+            | Self::SpecialFireworkRocket(recipe)
+            | Self::SpecialFireworkStar(recipe)
+            | Self::SpecialFireworkStarFade(recipe)
+            | Self::SpecialMapCloning(recipe)
+            | Self::SpecialMapExtending(recipe)
+            | Self::SpecialRepairItem(recipe)
+            | Self::SpecialShieldDecoration(recipe)
+            | Self::SpecialShulkerBoxColoring(recipe)
+            | Self::SpecialSuspiciousStew(recipe)
+            | Self::SpecialTippedArrow(recipe) => Some(recipe),
+            _ => None,
+        }
+    }
+
+    /// Returns the result of the recipe, or `None` for recipes whose result is
+    /// computed by the game (special and trim recipes).
+    pub fn result(&self) -> Option<&RecipeResult> {
+        match self {
+            Self::CraftingShaped(recipe) => Some(&recipe.result),
+            Self::CraftingShapeless(recipe) => Some(&recipe.result),
+            Self::CraftingTransmute(recipe) => Some(&recipe.result),
+            Self::Smelting(recipe)
+            | Self::Blasting(recipe)
+            | Self::Smoking(recipe)
+            | Self::CampfireCooking(recipe) => Some(&recipe.result),
+            Self::Stonecutting(recipe) => Some(&recipe.result),
+            Self::Smithing(recipe) => Some(&recipe.result),
+            Self::SmithingTransform(recipe) => Some(&recipe.result),
+            _ => None,
+        }
+    }
+}
+
+/// A recipe for the crafting table with a fixed shape.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ShapedRecipe {
+    /// A string identifier used to group multiple recipes together in the
+    /// recipe book.
+    pub group: Option<String>,
+
+    /// The recipe book category (`building`, `redstone`, `equipment`, or
+    /// `misc`).
+    ///
+    /// Added in 1.19.3.
+    pub category: Option<String>,
+
+    /// A list of single-character keys used to describe the pattern.
+    ///
+    /// Each row in the crafting grid is one string in this list, containing
+    /// no more than 3 characters. A space means the slot is empty.
+    pub pattern: Vec<String>,
+
+    /// The ingredient corresponding to each key in the pattern.
+    pub key: HashMap<char, Ingredient>,
+
+    /// The output item of the recipe.
+    pub result: RecipeResult,
+
+    /// Whether a notification is shown when the recipe is unlocked.
+    ///
+    /// Added in 1.19.4.
+    pub show_notification: Option<bool>,
+}
+
+/// A recipe for the crafting table without a fixed shape.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ShapelessRecipe {
+    /// A string identifier used to group multiple recipes together in the
+    /// recipe book.
+    pub group: Option<String>,
+
+    /// The recipe book category (`building`, `redstone`, `equipment`, or
+    /// `misc`).
+    ///
+    /// Added in 1.19.3.
+    pub category: Option<String>,
+
+    /// A list of between 1 and 9 ingredients.
+    pub ingredients: Vec<Ingredient>,
+
+    /// The output item of the recipe.
+    pub result: RecipeResult,
+}
+
+/// A crafting recipe that copies the components of the input item onto the
+/// result item (e.g., dyeing a shulker box).
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TransmuteRecipe {
+    /// A string identifier used to group multiple recipes together in the
+    /// recipe book.
+    pub group: Option<String>,
+
+    /// The recipe book category.
+    pub category: Option<String>,
+
+    /// The item whose components are copied.
+    pub input: Ingredient,
+
+    /// The additional ingredient.
+    pub material: Ingredient,
+
+    /// The output item of the recipe.
+    pub result: RecipeResult,
+}
+
+/// A recipe for a furnace, blast furnace, smoker, or campfire.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CookingRecipe {
+    /// A string identifier used to group multiple recipes together in the
+    /// recipe book.
+    pub group: Option<String>,
+
+    /// The recipe book category (`food`, `blocks`, or `misc`).
+    ///
+    /// Added in 1.19.3.
+    pub category: Option<String>,
+
+    /// The ingredient to cook.
+    pub ingredient: Ingredient,
+
+    /// The output item of the recipe.
+    pub result: RecipeResult,
+
+    /// The amount of experience this recipe yields.
+    #[serde(default)]
+    pub experience: f32,
+
+    /// The cook time of the recipe in ticks.
+    ///
+    /// Defaults to 200 for smelting, 100 for blasting and smoking, and 100
+    /// for campfire cooking.
+    #[serde(rename = "cookingtime")]
+    pub cooking_time: Option<u32>,
+}
+
+/// A recipe for the stonecutter.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct StonecuttingRecipe {
+    /// A string identifier used to group multiple recipes together in the
+    /// recipe book.
+    pub group: Option<String>,
+
+    /// The ingredient to cut.
+    pub ingredient: Ingredient,
+
+    /// The output item of the recipe.
+    pub result: RecipeResult,
+
+    /// The amount of the output item.
+    ///
+    /// Only used prior to 1.20.5, where `result` is a bare item ID. Later
+    /// versions specify the count in `result` instead.
+    pub count: Option<u32>,
+}
+
+/// A recipe for the smithing table prior to 1.20.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct LegacySmithingRecipe {
+    /// The item to upgrade.
+    pub base: Ingredient,
+
+    /// The upgrade material.
+    pub addition: Ingredient,
+
+    /// The output item of the recipe.
+    pub result: RecipeResult,
+}
+
+/// A recipe for the smithing table that transforms one item into another
+/// (e.g., netherite upgrades).
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SmithingTransformRecipe {
+    /// The smithing template.
+    pub template: Ingredient,
+
+    /// The item to upgrade.
+    pub base: Ingredient,
+
+    /// The upgrade material.
+    pub addition: Ingredient,
+
+    /// The output item of the recipe. Components of the base item are copied
+    /// onto it.
+    pub result: RecipeResult,
+}
+
+/// A recipe for the smithing table that applies an armor trim.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SmithingTrimRecipe {
+    /// The trim template.
+    pub template: Ingredient,
+
+    /// The armor to trim.
+    pub base: Ingredient,
+
+    /// The trim material.
+    pub addition: Ingredient,
+
+    /// The ID of the trim pattern to apply.
+    ///
+    /// Added in 1.21.5.
+    pub pattern: Option<ResourceLocation<'static>>,
+}
+
+/// A crafting recipe whose behavior is hardcoded in the game.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SpecialRecipe {
+    /// The recipe book category.
+    ///
+    /// Added in 1.19.3.
+    pub category: Option<String>,
+}
+
+/// An item (or set of items) accepted by a recipe slot.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::recipe::*;
+/// let ingredient: Ingredient = serde_json::from_str(r#"{ "item": "minecraft:stick" }"#).unwrap();
+/// assert_eq!(ingredient.items().collect::<Vec<_>>(), vec!["minecraft:stick"]);
+///
+/// // Since 1.21.2, ingredients are written as bare IDs or tags.
+/// let ingredient: Ingredient = serde_json::from_str(r##""#minecraft:planks""##).unwrap();
+/// assert_eq!(ingredient.tags().collect::<Vec<_>>(), vec!["minecraft:planks"]);
+///
+/// let ingredient: Ingredient = serde_json::from_str(r#"
+///     [{ "item": "minecraft:coal" }, { "item": "minecraft:charcoal" }]
+/// "#).unwrap();
+/// assert_eq!(
+///     ingredient.items().collect::<Vec<_>>(),
+///     vec!["minecraft:coal", "minecraft:charcoal"],
+/// );
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum Ingredient {
+    /// A single item.
+    Item {
+        /// The namespaced ID of the item.
+        item: ResourceLocation<'static>,
+    },
+
+    /// Any item in an item tag.
+    Tag {
+        /// The namespaced ID of the item tag (without the `#`).
+        tag: ResourceLocation<'static>,
+    },
+
+    /// An item ID, or a `#`-prefixed item tag.
+    ///
+    /// Used since 1.21.2.
+    Id(ResourceLocation<'static>),
+
+    /// Any one of the given ingredients.
+    Alternatives(Vec<Ingredient>),
+}
+
+impl Ingredient {
+    /// Returns an iterator over all item IDs directly referenced by this
+    /// ingredient (not including tags).
+    pub fn items(&self) -> impl Iterator<Item = &str> + '_ {
+        self.leaves().filter_map(|ingredient| match ingredient {
+            Self::Item { item } => Some(item.as_str()),
+            Self::Id(id) if !id.is_tag() => Some(id.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Returns an iterator over all item tags (without the `#`) referenced by
+    /// this ingredient.
+    pub fn tags(&self) -> impl Iterator<Item = &str> + '_ {
+        self.leaves().filter_map(|ingredient| match ingredient {
+            Self::Tag { tag } => Some(tag.as_str()),
+            Self::Id(id) => id.as_str().strip_prefix('#'),
+            _ => None,
+        })
+    }
+
+    fn leaves(&self) -> Box<dyn Iterator<Item = &Ingredient> + '_> {
+        match self {
+            Self::Alternatives(alternatives) => Box::new(
+                alternatives
+                    .iter()
+                    .flat_map(|ingredient| ingredient.leaves()),
+            ),
+            _ => Box::new(std::iter::once(self)),
+        }
+    }
+}
+
+impl Default for Ingredient {
+    fn default() -> Self {
+        Self::Alternatives(Vec::new())
+    }
+}
+
+/// The output of a [`Recipe`].
+///
+/// Prior to 1.20.5, cooking and stonecutting recipes specify their result as a
+/// bare item ID.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum RecipeResult {
+    /// A bare item ID.
+    Id(ResourceLocation<'static>),
+
+    /// An item stack.
+    Stack(ItemStack),
+}
+
+impl RecipeResult {
+    /// Returns the item ID of the result.
+    pub fn id(&self) -> &str {
+        match self {
+            Self::Id(id) => id.as_str(),
+            Self::Stack(stack) => stack.id.as_str(),
+        }
+    }
+
+    /// Returns the number of items produced, or `1` if not specified.
+    pub fn count(&self) -> u32 {
+        match self {
+            Self::Id(_) => 1,
+            Self::Stack(stack) => stack.count,
+        }
+    }
+}
+
+/// A stack of items produced by a [`Recipe`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ItemStack {
+    /// The namespaced ID of the item.
+    ///
+    /// Prior to 1.20.5 this field is called `item`. Both names are accepted,
+    /// but it is always serialized as `id`.
+    #[serde(alias = "item")]
+    pub id: ResourceLocation<'static>,
+
+    /// The number of items in the stack. Defaults to `1`.
+    #[serde(default = "ItemStack::default_count")]
+    pub count: u32,
+
+    /// Data components to apply to the item.
+    ///
+    /// Added in 1.20.5.
+    pub components: Option<DataComponents>,
+}
+
+impl ItemStack {
+    pub(crate) const fn default_count() -> u32 {
+        1
+    }
+}
@@ -0,0 +1,196 @@
+//! A reusable wrapper for fields that accept either a namespaced ID or an
+//! inline definition.
+//!
+//! Start here: [`MaybeReference`] and [`MaybeReferenceList`].
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::{InvalidIdentifier, ResourceLocation};
+
+/// A value that is either a reference to another resource (by namespaced ID
+/// or `#`-prefixed tag) or an inline definition of that resource.
+///
+/// Many data-driven fields (carvers, placed features, density functions,
+/// noise settings, ...) accept both forms, for example:
+///
+/// ```json
+/// "features": [
+///     ["minecraft:ore_dirt", { "feature": "...", "placement": [] }]
+/// ]
+/// ```
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::MaybeReference;
+/// let value: MaybeReference<serde_json::Value> =
+///     serde_json::from_str(r#""minecraft:ore_dirt""#).unwrap();
+/// assert_eq!(value.reference(), Some("minecraft:ore_dirt"));
+///
+/// let value: MaybeReference<serde_json::Value> =
+///     serde_json::from_str(r#"{ "feature": "foo" }"#).unwrap();
+/// assert!(value.inline().is_some());
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum MaybeReference<T> {
+    /// A namespaced ID (or `#`-prefixed tag) referencing another resource.
+    Reference(ResourceLocation<'static>),
+
+    /// An inline definition of the resource.
+    Inline(T),
+}
+
+impl<T> MaybeReference<T> {
+    /// Returns the referenced ID, or `None` if the value is inline.
+    pub fn reference(&self) -> Option<&str> {
+        match self {
+            Self::Reference(id) => Some(id.as_str()),
+            Self::Inline(_) => None,
+        }
+    }
+
+    /// Returns the inline value, or `None` if this is a reference.
+    pub fn inline(&self) -> Option<&T> {
+        match self {
+            Self::Reference(_) => None,
+            Self::Inline(value) => Some(value),
+        }
+    }
+
+    /// Returns `true` if this is a reference to a tag (i.e., starts with `#`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::MaybeReference;
+    /// let value = MaybeReference::<()>::try_from("#minecraft:logs").unwrap();
+    /// assert!(value.is_tag());
+    ///
+    /// let value = MaybeReference::<()>::try_from("minecraft:oak_log").unwrap();
+    /// assert!(!value.is_tag());
+    /// ```
+    pub fn is_tag(&self) -> bool {
+        matches!(self, Self::Reference(id) if id.is_tag())
+    }
+
+    /// Maps the inline value (if any) using the provided function.
+    pub fn map<U, F>(self, op: F) -> MaybeReference<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            Self::Reference(id) => MaybeReference::Reference(id),
+            Self::Inline(value) => MaybeReference::Inline(op(value)),
+        }
+    }
+}
+
+impl<T> From<ResourceLocation<'static>> for MaybeReference<T> {
+    fn from(source: ResourceLocation<'static>) -> Self {
+        Self::Reference(source)
+    }
+}
+
+impl<T> TryFrom<String> for MaybeReference<T> {
+    type Error = InvalidIdentifier;
+
+    fn try_from(source: String) -> Result<Self, Self::Error> {
+        ResourceLocation::new_owned(source).map(Self::Reference)
+    }
+}
+
+impl<'a, T> TryFrom<&'a str> for MaybeReference<T> {
+    type Error = InvalidIdentifier;
+
+    fn try_from(source: &'a str) -> Result<Self, Self::Error> {
+        source.parse().map(Self::Reference)
+    }
+}
+
+/// A list of [`MaybeReference`]s that may also be written as a single value.
+///
+/// Lists of carvers, placed features, biomes, etc. can be given either as a
+/// JSON array or as a single string (usually a `#`-prefixed tag).
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::reference::*;
+/// let list: MaybeReferenceList<serde_json::Value> =
+///     serde_json::from_str(r#""minecraft:nether_cave""#).unwrap();
+/// assert_eq!(list.len(), 1);
+///
+/// let list: MaybeReferenceList<serde_json::Value> =
+///     serde_json::from_str(r#"["minecraft:cave", "minecraft:canyon"]"#).unwrap();
+/// assert_eq!(list.len(), 2);
+/// assert_eq!(list.as_slice()[1].reference(), Some("minecraft:canyon"));
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum MaybeReferenceList<T> {
+    // NOTE: `Multiple` must come first, otherwise an array could be parsed as
+    // a single inline value.
+    /// A list of values.
+    Multiple(Vec<MaybeReference<T>>),
+
+    /// A single value.
+    Single(MaybeReference<T>),
+}
+
+impl<T> MaybeReferenceList<T> {
+    /// Returns all of the values in the list as a slice.
+    ///
+    /// The slice will contain one element for a [`Single`][Self::Single]
+    /// value, and multiple for a [`Multiple`][Self::Multiple] value.
+    pub fn as_slice(&self) -> &[MaybeReference<T>] {
+        match self {
+            Self::Single(value) => std::slice::from_ref(value),
+            Self::Multiple(values) => &values[..],
+        }
+    }
+
+    /// Returns the number of values in the list.
+    pub fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    /// Returns `true` if there are no values in the list.
+    pub fn is_empty(&self) -> bool {
+        self.as_slice().is_empty()
+    }
+
+    /// Returns an iterator over the values in the list.
+    pub fn iter(&self) -> std::slice::Iter<'_, MaybeReference<T>> {
+        self.as_slice().iter()
+    }
+}
+
+impl<T> Default for MaybeReferenceList<T> {
+    fn default() -> Self {
+        Self::Multiple(Vec::new())
+    }
+}
+
+impl<T> From<Vec<MaybeReference<T>>> for MaybeReferenceList<T> {
+    fn from(source: Vec<MaybeReference<T>>) -> Self {
+        Self::Multiple(source)
+    }
+}
+
+/// A list of namespaced IDs that may also be written as a single ID or
+/// `#`-prefixed tag.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::reference::*;
+/// let list: IdList = serde_json::from_str(r##""#minecraft:planks""##).unwrap();
+/// assert!(list.as_slice()[0].is_tag());
+///
+/// let list: IdList = serde_json::from_str(r#"["minecraft:stone", "minecraft:dirt"]"#).unwrap();
+/// assert_eq!(list.len(), 2);
+/// ```
+pub type IdList = MaybeReferenceList<()>;
@@ -0,0 +1,55 @@
+//! Serde-(de)serializable data types for
+//! `assets/minecraft/regional_compliancies.json`.
+//!
+//! Start here: [`RegionalCompliancies`].
+//!
+//! See <https://minecraft.fandom.com/wiki/Resource_pack#Contents>.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// The play-time notices required by law in certain regions, as stored in
+/// `assets/minecraft/regional_compliancies.json`.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::regional_compliancies::*;
+/// let compliancies: RegionalCompliancies = serde_json::from_str(r#"
+///     {
+///         "KOR": [
+///             { "delay": 3600000, "period": 3600000, "translation": "compliance.playtime.message" }
+///         ]
+///     }
+/// "#).unwrap();
+///
+/// let notice = &compliancies.regions["KOR"][0];
+/// assert_eq!(notice.period, 3_600_000);
+/// assert_eq!(notice.translation, "compliance.playtime.message");
+/// ```
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+pub struct RegionalCompliancies {
+    /// Map of ISO 3166-1 alpha-3 country codes (e.g., `KOR`) to the notices
+    /// shown in that country.
+    pub regions: HashMap<String, Vec<PlaytimeNotice>>,
+}
+
+/// A notice that is shown periodically while playing, in the
+/// [`RegionalCompliancies`].
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PlaytimeNotice {
+    /// The time in milliseconds after starting the game at which the notice
+    /// is first shown.
+    pub delay: u64,
+
+    /// The time in milliseconds between showings of the notice.
+    pub period: u64,
+
+    /// The translation key of the notice's message, whose format argument is
+    /// the number of hours played.
+    pub translation: String,
+}
@@ -0,0 +1,152 @@
+//! Serde-(de)serializable data types for the reports written to
+//! `generated/reports/` by the game's data generator.
+//!
+//! Start here: [`RegistriesReport`] and [`BlocksReport`].
+//!
+//! The reports list the contents of the game's built-in registries (e.g.,
+//! every block, item, and sound event) and the states of every block. They are
+//! generated by running the server jar with
+//! `java -DbundlerMainClass=net.minecraft.data.Main -jar server.jar --reports`.
+//!
+//! See <https://minecraft.fandom.com/wiki/Tutorials/Running_the_data_generator>.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// The contents of `registries.json`, by registry name (e.g.,
+/// `minecraft:block`).
+///
+/// Data-driven registries, like biomes since 1.19.3, are not part of this
+/// report.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::reports::*;
+/// let report: RegistriesReport = serde_json::from_str(r#"
+///     {
+///         "minecraft:block": {
+///             "default": "minecraft:air",
+///             "protocol_id": 4,
+///             "entries": {
+///                 "minecraft:air": { "protocol_id": 0 },
+///                 "minecraft:stone": { "protocol_id": 1 }
+///             }
+///         }
+///     }
+/// "#).unwrap();
+///
+/// let blocks = &report.registries["minecraft:block"];
+/// assert_eq!(blocks.default.as_deref(), Some("minecraft:air"));
+/// assert_eq!(blocks.entries["minecraft:stone"].protocol_id, 1);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+pub struct RegistriesReport {
+    /// Map of registry names to their contents.
+    pub registries: HashMap<String, RegistryReport>,
+}
+
+/// The contents of a single registry in a [`RegistriesReport`].
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RegistryReport {
+    /// The entry used in place of unknown IDs, if the registry has one.
+    #[serde(default)]
+    pub default: Option<String>,
+
+    /// The numeric ID of the registry in the network protocol.
+    pub protocol_id: u32,
+
+    /// Map of the registry's namespaced IDs to their entries.
+    pub entries: HashMap<String, RegistryEntryReport>,
+}
+
+/// An entry of a [`RegistryReport`].
+#[derive(Deserialize, Serialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RegistryEntryReport {
+    /// The numeric ID of the entry in the network protocol.
+    pub protocol_id: u32,
+}
+
+/// The contents of `blocks.json`, by namespaced block ID.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::reports::*;
+/// let report: BlocksReport = serde_json::from_str(r#"
+///     {
+///         "minecraft:lever": {
+///             "properties": {
+///                 "face": ["floor", "wall", "ceiling"],
+///                 "powered": ["true", "false"]
+///             },
+///             "states": [
+///                 { "id": 5000, "properties": { "face": "floor", "powered": "true" } },
+///                 { "default": true, "id": 5001, "properties": { "face": "floor", "powered": "false" } }
+///             ]
+///         },
+///         "minecraft:stone": {
+///             "states": [{ "default": true, "id": 1 }]
+///         }
+///     }
+/// "#).unwrap();
+///
+/// let lever = &report.blocks["minecraft:lever"];
+/// assert_eq!(lever.properties["powered"], ["true", "false"]);
+/// assert_eq!(lever.default_state().unwrap().id, 5001);
+///
+/// assert!(report.blocks["minecraft:stone"].properties.is_empty());
+/// ```
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+pub struct BlocksReport {
+    /// Map of block IDs to their states.
+    pub blocks: HashMap<String, BlockReport>,
+}
+
+/// The states of a single block in a [`BlocksReport`].
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BlockReport {
+    /// The definition of the block's type and settings, added in 1.20.3.
+    #[serde(default)]
+    pub definition: Option<serde_json::Value>,
+
+    /// Map of the block's state properties to their possible values, in order.
+    ///
+    /// This is empty for blocks with a single state.
+    #[serde(default)]
+    pub properties: HashMap<String, Vec<String>>,
+
+    /// Every state of the block.
+    pub states: Vec<BlockStateReport>,
+}
+
+impl BlockReport {
+    /// Returns the state that the block is placed in by default.
+    pub fn default_state(&self) -> Option<&BlockStateReport> {
+        self.states.iter().find(|state| state.default)
+    }
+}
+
+/// A single state of a block in a [`BlockReport`].
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BlockStateReport {
+    /// The numeric ID of the state in the network protocol.
+    pub id: u32,
+
+    /// Map of the block's state properties to their values in this state.
+    #[serde(default)]
+    pub properties: HashMap<String, String>,
+
+    /// Whether this is the state that the block is placed in by default.
+    #[serde(default)]
+    pub default: bool,
+}
@@ -0,0 +1,421 @@
+//! Serde-(de)serializable data types for `assets/<namespace>/shaders/**/*.json`
+//! and `assets/<namespace>/post_effect/*.json`.
+//!
+//! Start here: [`ShaderProgram`], [`PostChain`], and [`PostEffect`].
+//!
+//! The GLSL sources referenced by these files are plain text; their
+//! `#moj_import` directives can be resolved with
+//! [`ShaderImportResolver`][crate::api::ShaderImportResolver].
+//!
+//! See <https://minecraft.fandom.com/wiki/Shaders>.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// A shader program definition as stored in the
+/// `assets/<namespace>/shaders/core/` directory, or (for post-processing
+/// programs) in the `assets/<namespace>/shaders/program/` directory.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::shader::*;
+/// let program: ShaderProgram = serde_json::from_str(r#"
+///     {
+///         "blend": { "func": "add", "srcrgb": "srcalpha", "dstrgb": "1-srcalpha" },
+///         "vertex": "position_tex",
+///         "fragment": "position_tex",
+///         "attributes": ["Position", "UV0"],
+///         "samplers": [{ "name": "Sampler0" }],
+///         "uniforms": [
+///             { "name": "ColorModulator", "type": "float", "count": 4, "values": [1.0, 1.0, 1.0, 1.0] }
+///         ]
+///     }
+/// "#).unwrap();
+///
+/// assert_eq!(program.vertex, "position_tex");
+/// assert_eq!(program.blend.unwrap().func, "add");
+/// assert_eq!(program.samplers[0].name, "Sampler0");
+/// assert_eq!(program.uniforms[0].count, 4);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ShaderProgram {
+    /// The blend mode used when rendering with the program.
+    pub blend: Option<BlendMode>,
+
+    /// The resource location of the vertex shader.
+    ///
+    /// Before 1.21.2 this is relative to the directory of the program
+    /// definition and has no namespace (e.g., `position_tex`). Since 1.21.2 it
+    /// is relative to `shaders/` (e.g., `minecraft:core/position_tex`).
+    pub vertex: String,
+
+    /// The resource location of the fragment shader, in the same format as
+    /// [`vertex`][Self::vertex].
+    pub fragment: String,
+
+    /// The names of the vertex attributes.
+    ///
+    /// Removed in 1.21.2.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attributes: Vec<String>,
+
+    /// The samplers used by the program.
+    #[serde(default)]
+    pub samplers: Vec<ShaderSampler>,
+
+    /// The uniforms used by the program.
+    #[serde(default)]
+    pub uniforms: Vec<ShaderUniform>,
+
+    /// Preprocessor definitions prepended to the shader sources.
+    ///
+    /// Added in 1.21.2.
+    pub defines: Option<ShaderDefines>,
+}
+
+/// The blend mode of a [`ShaderProgram`].
+///
+/// The blend factors are written as, e.g., `srcalpha` or `1-srcalpha`.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BlendMode {
+    /// The blend function, e.g., `add` or `subtract`. Defaults to `add`.
+    #[serde(default = "BlendMode::default_func")]
+    pub func: String,
+
+    /// The source color blend factor.
+    pub srcrgb: Option<String>,
+
+    /// The destination color blend factor.
+    pub dstrgb: Option<String>,
+
+    /// The source alpha blend factor. Defaults to
+    /// [`srcrgb`][Self::srcrgb].
+    pub srcalpha: Option<String>,
+
+    /// The destination alpha blend factor. Defaults to
+    /// [`dstrgb`][Self::dstrgb].
+    pub dstalpha: Option<String>,
+}
+
+impl BlendMode {
+    pub(crate) fn default_func() -> String {
+        String::from("add")
+    }
+}
+
+/// A sampler of a [`ShaderProgram`].
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ShaderSampler {
+    /// The name of the sampler uniform in the shader sources.
+    pub name: String,
+}
+
+/// A uniform of a [`ShaderProgram`].
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ShaderUniform {
+    /// The name of the uniform in the shader sources.
+    pub name: String,
+
+    /// The type of the uniform, e.g., `int`, `float`, or `matrix4x4`.
+    #[serde(rename = "type")]
+    pub kind: String,
+
+    /// The number of components of the uniform.
+    pub count: u32,
+
+    /// The default values of the components.
+    pub values: Vec<f32>,
+}
+
+/// The preprocessor definitions of a [`ShaderProgram`].
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ShaderDefines {
+    /// Map of names to values, each written as `#define NAME VALUE`.
+    #[serde(default)]
+    pub values: HashMap<String, String>,
+
+    /// Names written as `#define NAME`.
+    #[serde(default)]
+    pub flags: Vec<String>,
+}
+
+/// A post-processing pipeline as stored in the
+/// `assets/<namespace>/shaders/post/` directory, e.g., `spider.json`.
+///
+/// Replaced by [`PostEffect`] in 1.21.2.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::shader::*;
+/// let chain: PostChain = serde_json::from_str(r#"
+///     {
+///         "targets": ["swap", { "name": "previous", "width": 64, "height": 64 }],
+///         "passes": [
+///             {
+///                 "name": "blur",
+///                 "intarget": "minecraft:main",
+///                 "outtarget": "swap",
+///                 "uniforms": [{ "name": "BlurDir", "values": [1.0, 0.0] }]
+///             },
+///             {
+///                 "name": "blit",
+///                 "intarget": "swap",
+///                 "outtarget": "minecraft:main",
+///                 "auxtargets": [{ "name": "PrevSampler", "id": "previous" }]
+///             }
+///         ]
+///     }
+/// "#).unwrap();
+///
+/// assert_eq!(chain.targets[0].name(), "swap");
+/// assert_eq!(chain.targets[1].name(), "previous");
+/// assert_eq!(chain.passes[0].uniforms[0].values, vec![1.0, 0.0]);
+/// assert_eq!(chain.passes[1].auxtargets[0].id, "previous");
+/// ```
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PostChain {
+    /// The render targets used by the passes, besides `minecraft:main`.
+    #[serde(default)]
+    pub targets: Vec<PostChainTarget>,
+
+    /// The passes of the pipeline, in order.
+    #[serde(default)]
+    pub passes: Vec<PostChainPass>,
+}
+
+/// A render target of a [`PostChain`].
+///
+/// This is either the name of a target the size of the screen, or an object
+/// with an explicit size.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum PostChainTarget {
+    /// The name of a target the size of the screen.
+    Name(String),
+
+    /// A target with an explicit size.
+    Sized {
+        /// The name of the target.
+        name: String,
+
+        /// The width of the target, in pixels.
+        width: Option<u32>,
+
+        /// The height of the target, in pixels.
+        height: Option<u32>,
+    },
+}
+
+impl PostChainTarget {
+    /// Returns the name of the target.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Name(name) => name,
+            Self::Sized { name, .. } => name,
+        }
+    }
+}
+
+/// A pass of a [`PostChain`].
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PostChainPass {
+    /// The name of the program used by the pass, relative to
+    /// `shaders/program/`.
+    pub name: String,
+
+    /// The name of the target read by the pass.
+    pub intarget: String,
+
+    /// The name of the target written by the pass.
+    pub outtarget: String,
+
+    /// Additional targets or textures read by the pass.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub auxtargets: Vec<PostChainAuxTarget>,
+
+    /// Overridden values of the program's uniforms.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub uniforms: Vec<PostChainUniform>,
+}
+
+/// An additional input of a [`PostChainPass`].
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PostChainAuxTarget {
+    /// The name of the sampler the input is bound to.
+    pub name: String,
+
+    /// The name of a target (optionally with a `:depth` suffix), or the
+    /// resource location of a texture relative to `textures/effect/`.
+    pub id: String,
+
+    /// The width of the texture, in pixels. Required for textures.
+    pub width: Option<u32>,
+
+    /// The height of the texture, in pixels. Required for textures.
+    pub height: Option<u32>,
+
+    /// Whether the texture is sampled with bilinear filtering.
+    pub bilinear: Option<bool>,
+}
+
+/// An overridden uniform value of a [`PostChainPass`].
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PostChainUniform {
+    /// The name of the uniform.
+    pub name: String,
+
+    /// The values of the uniform's components.
+    pub values: Vec<f32>,
+}
+
+/// A post-processing pipeline as stored in the
+/// `assets/<namespace>/post_effect/` directory, e.g., `spider.json`.
+///
+/// Added in 1.21.2, replacing [`PostChain`].
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::shader::*;
+/// let effect: PostEffect = serde_json::from_str(r#"
+///     {
+///         "targets": { "swap": {} },
+///         "passes": [
+///             {
+///                 "program": "minecraft:post/blur",
+///                 "inputs": [
+///                     { "sampler_name": "In", "target": "minecraft:main", "bilinear": true }
+///                 ],
+///                 "output": "swap"
+///             },
+///             {
+///                 "program": "minecraft:post/blit",
+///                 "inputs": [
+///                     { "sampler_name": "In", "target": "swap" },
+///                     { "sampler_name": "Noise", "location": "minecraft:noise", "width": 64, "height": 64 }
+///                 ],
+///                 "output": "minecraft:main"
+///             }
+///         ]
+///     }
+/// "#).unwrap();
+///
+/// assert!(effect.targets["swap"].width.is_none());
+/// assert_eq!(effect.passes[0].inputs[0].sampler_name(), "In");
+/// assert!(matches!(effect.passes[1].inputs[1], PostEffectInput::Texture { .. }));
+/// ```
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PostEffect {
+    /// Map of names to the render targets used by the passes, besides
+    /// `minecraft:main`.
+    #[serde(default)]
+    pub targets: HashMap<String, PostEffectTarget>,
+
+    /// The passes of the pipeline, in order.
+    #[serde(default)]
+    pub passes: Vec<PostEffectPass>,
+}
+
+/// A render target of a [`PostEffect`].
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PostEffectTarget {
+    /// The width of the target, in pixels. Defaults to the screen width.
+    pub width: Option<u32>,
+
+    /// The height of the target, in pixels. Defaults to the screen height.
+    pub height: Option<u32>,
+
+    /// Whether the contents of the target are kept between frames.
+    #[serde(default)]
+    pub persistent: bool,
+}
+
+/// A pass of a [`PostEffect`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PostEffectPass {
+    /// The resource location of the program used by the pass, relative to
+    /// `shaders/`.
+    pub program: String,
+
+    /// The targets or textures read by the pass.
+    #[serde(default)]
+    pub inputs: Vec<PostEffectInput>,
+
+    /// The name of the target written by the pass.
+    pub output: String,
+
+    /// Overridden values of the program's uniforms.
+    ///
+    /// *Uniforms are kept as raw JSON, since their format differs between
+    /// versions.*
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uniforms: Option<serde_json::Value>,
+}
+
+/// An input of a [`PostEffectPass`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum PostEffectInput {
+    /// Reads a render target.
+    Target {
+        /// The name of the sampler the input is bound to.
+        sampler_name: String,
+
+        /// The name of the target.
+        target: String,
+
+        /// Whether to read the depth buffer instead of the color buffer.
+        #[serde(default)]
+        use_depth_buffer: bool,
+
+        /// Whether the target is sampled with bilinear filtering.
+        #[serde(default)]
+        bilinear: bool,
+    },
+
+    /// Reads a texture.
+    Texture {
+        /// The name of the sampler the input is bound to.
+        sampler_name: String,
+
+        /// The resource location of the texture, relative to `textures/`.
+        location: String,
+
+        /// The width of the texture, in pixels.
+        width: u32,
+
+        /// The height of the texture, in pixels.
+        height: u32,
+
+        /// Whether the texture is sampled with bilinear filtering.
+        #[serde(default)]
+        bilinear: bool,
+    },
+}
+
+impl PostEffectInput {
+    /// Returns the name of the sampler the input is bound to.
+    pub fn sampler_name(&self) -> &str {
+        match self {
+            Self::Target { sampler_name, .. } => sampler_name,
+            Self::Texture { sampler_name, .. } => sampler_name,
+        }
+    }
+}
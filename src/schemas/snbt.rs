@@ -0,0 +1,600 @@
+//! A parser and serializer for SNBT, the stringified NBT format.
+//!
+//! Start here: [`SnbtValue`].
+//!
+//! SNBT strings are embedded in many JSON files, e.g., the `nbt` fields of
+//! predicates and advancement icons, and the `tag` of the `set_nbt` loot
+//! function. [`SnbtValue::parse()`] turns them into a tree of typed values that
+//! can be inspected, and [`SnbtValue`]'s [`Display`][std::fmt::Display]
+//! implementation turns the tree back into a string.
+//!
+//! See <https://minecraft.wiki/w/NBT_format#SNBT_format>.
+
+use std::{collections::BTreeMap, fmt, str::FromStr};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// A value of the SNBT format.
+///
+/// [`SnbtValue`] (de)serializes with Serde as an SNBT string.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::snbt::*;
+/// let value = SnbtValue::parse(r#"{display:{Name:'"Excalibur"'},Damage:3s,Unbreakable:1b}"#).unwrap();
+///
+/// assert_eq!(value.get("Damage"), Some(&SnbtValue::Short(3)));
+/// assert_eq!(value.get("Unbreakable").and_then(SnbtValue::as_bool), Some(true));
+///
+/// let name = value.get("display").and_then(|display| display.get("Name"));
+/// assert_eq!(name.and_then(SnbtValue::as_str), Some(r#""Excalibur""#));
+///
+/// // Compounds are serialized with their keys in order.
+/// assert_eq!(
+///     value.to_string(),
+///     r#"{Damage:3s,Unbreakable:1b,display:{Name:'"Excalibur"'}}"#,
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum SnbtValue {
+    /// A byte, e.g., `3b`, or a boolean, e.g., `true`.
+    Byte(i8),
+
+    /// A short, e.g., `3s`.
+    Short(i16),
+
+    /// An int, e.g., `3`.
+    Int(i32),
+
+    /// A long, e.g., `3L`.
+    Long(i64),
+
+    /// A float, e.g., `3.5f`.
+    Float(f32),
+
+    /// A double, e.g., `3.5d` or `3.5`.
+    Double(f64),
+
+    /// A quoted or unquoted string, e.g., `"Hello, world!"` or `hello`.
+    String(String),
+
+    /// A list of values, e.g., `[1, 2, 3]`.
+    List(Vec<SnbtValue>),
+
+    /// A compound of named values, e.g., `{a: 1, b: "2"}`.
+    Compound(BTreeMap<String, SnbtValue>),
+
+    /// An array of bytes, e.g., `[B; 1b, 2b]`.
+    ByteArray(Vec<i8>),
+
+    /// An array of ints, e.g., `[I; 1, 2]`.
+    IntArray(Vec<i32>),
+
+    /// An array of longs, e.g., `[L; 1L, 2L]`.
+    LongArray(Vec<i64>),
+}
+
+impl SnbtValue {
+    /// Parses an SNBT string.
+    ///
+    /// Like the game, unquoted tokens that are not numbers or booleans are
+    /// strings, and numbers that are out of range for their type are too.
+    pub fn parse(source: &str) -> Result<Self, SnbtError> {
+        let mut parser = Parser { source, pos: 0 };
+        let value = parser.value()?;
+
+        parser.skip_whitespace();
+        if parser.pos < source.len() {
+            return Err(parser.error("trailing characters after the value"));
+        }
+
+        Ok(value)
+    }
+
+    /// Returns the value of an integer type (`Byte`, `Short`, `Int`, or
+    /// `Long`) as an `i64`.
+    pub fn as_i64(&self) -> Option<i64> {
+        match *self {
+            Self::Byte(n) => Some(n as i64),
+            Self::Short(n) => Some(n as i64),
+            Self::Int(n) => Some(n as i64),
+            Self::Long(n) => Some(n),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a numeric type as an `f64`.
+    pub fn as_f64(&self) -> Option<f64> {
+        match *self {
+            Self::Float(n) => Some(n as f64),
+            Self::Double(n) => Some(n),
+            _ => self.as_i64().map(|n| n as f64),
+        }
+    }
+
+    /// Returns the value of a `Byte` as a boolean.
+    pub fn as_bool(&self) -> Option<bool> {
+        match *self {
+            Self::Byte(n) => Some(n != 0),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a `String`.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Returns the values of a `List`.
+    pub fn as_list(&self) -> Option<&[SnbtValue]> {
+        match self {
+            Self::List(list) => Some(list),
+            _ => None,
+        }
+    }
+
+    /// Returns the values of a `Compound`.
+    pub fn as_compound(&self) -> Option<&BTreeMap<String, SnbtValue>> {
+        match self {
+            Self::Compound(compound) => Some(compound),
+            _ => None,
+        }
+    }
+
+    /// Returns the value with the given name, if this is a `Compound` that
+    /// has it.
+    pub fn get(&self, name: &str) -> Option<&SnbtValue> {
+        self.as_compound()?.get(name)
+    }
+}
+
+impl FromStr for SnbtValue {
+    type Err = SnbtError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        Self::parse(source)
+    }
+}
+
+impl fmt::Display for SnbtValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Byte(n) => write!(f, "{}b", n),
+            Self::Short(n) => write!(f, "{}s", n),
+            Self::Int(n) => write!(f, "{}", n),
+            Self::Long(n) => write!(f, "{}L", n),
+            Self::Float(n) => write!(f, "{:?}f", n),
+            Self::Double(n) => write!(f, "{:?}d", n),
+            Self::String(s) => write_quoted(f, s),
+            Self::List(list) => write_list(f, "", list.iter()),
+            Self::Compound(compound) => {
+                write!(f, "{{")?;
+                for (i, (name, value)) in compound.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    if !name.is_empty() && name.chars().all(is_unquoted_char) {
+                        write!(f, "{}", name)?;
+                    } else {
+                        write_quoted(f, name)?;
+                    }
+                    write!(f, ":{}", value)?;
+                }
+                write!(f, "}}")
+            }
+            Self::ByteArray(array) => {
+                write_list(f, "B;", array.iter().map(|&n| SnbtValue::Byte(n)))
+            }
+            Self::IntArray(array) => write_list(f, "I;", array.iter().map(|&n| SnbtValue::Int(n))),
+            Self::LongArray(array) => {
+                write_list(f, "L;", array.iter().map(|&n| SnbtValue::Long(n)))
+            }
+        }
+    }
+}
+
+fn write_list<T: fmt::Display>(
+    f: &mut fmt::Formatter<'_>,
+    prefix: &str,
+    values: impl Iterator<Item = T>,
+) -> fmt::Result {
+    write!(f, "[{}", prefix)?;
+    for (i, value) in values.enumerate() {
+        if i > 0 {
+            write!(f, ",")?;
+        }
+        write!(f, "{}", value)?;
+    }
+    write!(f, "]")
+}
+
+/// Writes a quoted string, preferring double quotes like the game does.
+fn write_quoted(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    let quote = if s.contains('"') && !s.contains('\'') {
+        '\''
+    } else {
+        '"'
+    };
+
+    write!(f, "{}", quote)?;
+    for c in s.chars() {
+        if c == quote || c == '\\' {
+            write!(f, "\\")?;
+        }
+        write!(f, "{}", c)?;
+    }
+    write!(f, "{}", quote)
+}
+
+impl Serialize for SnbtValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SnbtValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let source = String::deserialize(deserializer)?;
+        Self::parse(&source).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for SnbtValue {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "SnbtValue".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "An NBT value written as SNBT text.",
+            "type": "string"
+        })
+    }
+}
+
+#[cfg(feature = "nbt")]
+impl From<SnbtValue> for fastnbt::Value {
+    fn from(value: SnbtValue) -> Self {
+        match value {
+            SnbtValue::Byte(n) => Self::Byte(n),
+            SnbtValue::Short(n) => Self::Short(n),
+            SnbtValue::Int(n) => Self::Int(n),
+            SnbtValue::Long(n) => Self::Long(n),
+            SnbtValue::Float(n) => Self::Float(n),
+            SnbtValue::Double(n) => Self::Double(n),
+            SnbtValue::String(s) => Self::String(s),
+            SnbtValue::List(list) => Self::List(list.into_iter().map(Self::from).collect()),
+            SnbtValue::Compound(compound) => Self::Compound(
+                compound
+                    .into_iter()
+                    .map(|(name, value)| (name, Self::from(value)))
+                    .collect(),
+            ),
+            SnbtValue::ByteArray(array) => Self::ByteArray(fastnbt::ByteArray::new(array)),
+            SnbtValue::IntArray(array) => Self::IntArray(fastnbt::IntArray::new(array)),
+            SnbtValue::LongArray(array) => Self::LongArray(fastnbt::LongArray::new(array)),
+        }
+    }
+}
+
+/// An error from parsing an invalid SNBT string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnbtError {
+    message: &'static str,
+    position: usize,
+}
+
+impl SnbtError {
+    /// Returns a description of the error.
+    pub fn message(&self) -> &'static str {
+        self.message
+    }
+
+    /// Returns the byte offset in the string at which the error occurred.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for SnbtError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
+
+impl std::error::Error for SnbtError {}
+
+struct Parser<'a> {
+    source: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, message: &'static str) -> SnbtError {
+        SnbtError {
+            message,
+            position: self.pos,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.pos..].chars().next()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.next();
+        }
+    }
+
+    fn expect(&mut self, expected: char, message: &'static str) -> Result<(), SnbtError> {
+        self.skip_whitespace();
+        if self.peek() == Some(expected) {
+            self.next();
+            Ok(())
+        } else {
+            Err(self.error(message))
+        }
+    }
+
+    fn value(&mut self) -> Result<SnbtValue, SnbtError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('{') => self.compound(),
+            Some('[') => self.list(),
+            Some('"' | '\'') => self.quoted().map(SnbtValue::String),
+            _ => Ok(scalar(self.unquoted()?)),
+        }
+    }
+
+    fn compound(&mut self) -> Result<SnbtValue, SnbtError> {
+        self.next();
+        let mut compound = BTreeMap::new();
+
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some('}') {
+                self.next();
+                return Ok(SnbtValue::Compound(compound));
+            }
+
+            let name = match self.peek() {
+                Some('"' | '\'') => self.quoted()?,
+                _ => self.unquoted()?.to_string(),
+            };
+            self.expect(':', "expected `:` after a name")?;
+            let value = self.value()?;
+            compound.insert(name, value);
+
+            if !self.separator('}')? {
+                return Err(self.error("expected `,` or `}`"));
+            }
+        }
+    }
+
+    fn list(&mut self) -> Result<SnbtValue, SnbtError> {
+        self.next();
+        self.skip_whitespace();
+
+        // Typed arrays start with their type, e.g., `[I;`.
+        let rest = &self.source[self.pos..];
+        let array_type = match rest.as_bytes() {
+            [t @ (b'B' | b'I' | b'L'), b';', ..] => Some(*t),
+            _ => None,
+        };
+        if array_type.is_some() {
+            self.pos += 2;
+        }
+
+        let mut values = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some(']') {
+                self.next();
+                break;
+            }
+
+            let start = self.pos;
+            let value = self.value()?;
+            if array_type.is_some() && value.as_i64().is_none() {
+                self.pos = start;
+                return Err(self.error("expected an integer in an array"));
+            }
+            values.push(value);
+
+            if !self.separator(']')? {
+                return Err(self.error("expected `,` or `]`"));
+            }
+        }
+
+        let integers = || values.iter().filter_map(SnbtValue::as_i64);
+        let out_of_range = || self.error("integer out of range for the array");
+        Ok(match array_type {
+            Some(b'B') => SnbtValue::ByteArray(
+                integers()
+                    .map(i8::try_from)
+                    .collect::<Result<_, _>>()
+                    .map_err(|_| out_of_range())?,
+            ),
+            Some(b'I') => SnbtValue::IntArray(
+                integers()
+                    .map(i32::try_from)
+                    .collect::<Result<_, _>>()
+                    .map_err(|_| out_of_range())?,
+            ),
+            Some(_) => SnbtValue::LongArray(integers().collect()),
+            None => SnbtValue::List(values),
+        })
+    }
+
+    /// Consumes a `,` and returns `true`, or consumes the closing character and
+    /// returns `true` after the last value, or returns `false`.
+    fn separator(&mut self, close: char) -> Result<bool, SnbtError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(',') => {
+                self.next();
+                Ok(true)
+            }
+            Some(c) if c == close => Ok(true),
+            _ => Ok(false),
+        }
+    }
+
+    fn quoted(&mut self) -> Result<String, SnbtError> {
+        let quote = self.next();
+        let mut s = String::new();
+
+        loop {
+            match self.next() {
+                None => return Err(self.error("unterminated string")),
+                Some('\\') => match self.next() {
+                    Some(c @ ('\\' | '"' | '\'')) => s.push(c),
+                    Some('n') => s.push('\n'),
+                    Some('t') => s.push('\t'),
+                    Some('r') => s.push('\r'),
+                    _ => return Err(self.error("invalid escape sequence")),
+                },
+                c if c == quote => return Ok(s),
+                Some(c) => s.push(c),
+            }
+        }
+    }
+
+    fn unquoted(&mut self) -> Result<&'a str, SnbtError> {
+        let start = self.pos;
+        while self.peek().is_some_and(is_unquoted_char) {
+            self.next();
+        }
+
+        if self.pos == start {
+            Err(self.error("expected a value"))
+        } else {
+            Ok(&self.source[start..self.pos])
+        }
+    }
+}
+
+fn is_unquoted_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '+')
+}
+
+/// Interprets an unquoted token as a number, a boolean, or a string.
+fn scalar(token: &str) -> SnbtValue {
+    let string = || SnbtValue::String(token.to_string());
+
+    match token {
+        "true" => return SnbtValue::Byte(1),
+        "false" => return SnbtValue::Byte(0),
+        _ => {}
+    }
+
+    let (number, suffix) = match token.char_indices().last() {
+        Some((i, c)) if c.is_ascii_alphabetic() => (&token[..i], Some(c.to_ascii_lowercase())),
+        _ => (token, None),
+    };
+
+    let value = match suffix {
+        Some('b') if is_integer(number) => number.parse().ok().map(SnbtValue::Byte),
+        Some('s') if is_integer(number) => number.parse().ok().map(SnbtValue::Short),
+        Some('l') if is_integer(number) => number.parse().ok().map(SnbtValue::Long),
+        Some('f') if is_float(number) => number.parse().ok().map(SnbtValue::Float),
+        Some('d') if is_float(number) => number.parse().ok().map(SnbtValue::Double),
+        None if is_integer(number) => number.parse().ok().map(SnbtValue::Int),
+        None if is_float(number) => number.parse().ok().map(SnbtValue::Double),
+        _ => None,
+    };
+
+    value.unwrap_or_else(string)
+}
+
+fn is_integer(s: &str) -> bool {
+    let digits = s.strip_prefix(['-', '+']).unwrap_or(s);
+    !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit())
+}
+
+fn is_float(s: &str) -> bool {
+    let s = s.strip_prefix(['-', '+']).unwrap_or(s);
+    let (mantissa, exponent) = match s.find(['e', 'E']) {
+        Some(i) => (&s[..i], Some(&s[i + 1..])),
+        None => (s, None),
+    };
+
+    let (whole, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let valid_mantissa = !(whole.is_empty() && fraction.is_empty())
+        && whole.bytes().all(|b| b.is_ascii_digit())
+        && fraction.bytes().all(|b| b.is_ascii_digit());
+
+    valid_mantissa && exponent.is_none_or(is_integer)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_every_type() {
+        let value = SnbtValue::parse(
+            r#"{ b: -3B, s: 4s, i: 5, l: 6l, f: 1.5F, d: .5, e: 1e3, t: true,
+                 u: stone, q: 'it\'s', list: [1, 2,], empty: [],
+                 bytes: [B; 1b, 2b], ints: [I; -1, 2], longs: [L; 3L],
+                 "quoted key": {}, big: 3000000000 }"#,
+        )
+        .unwrap();
+
+        let get = |name| value.get(name).unwrap().clone();
+        assert_eq!(get("b"), SnbtValue::Byte(-3));
+        assert_eq!(get("s"), SnbtValue::Short(4));
+        assert_eq!(get("i"), SnbtValue::Int(5));
+        assert_eq!(get("l"), SnbtValue::Long(6));
+        assert_eq!(get("f"), SnbtValue::Float(1.5));
+        assert_eq!(get("d"), SnbtValue::Double(0.5));
+        assert_eq!(get("e"), SnbtValue::Double(1000.0));
+        assert_eq!(get("t"), SnbtValue::Byte(1));
+        assert_eq!(get("u"), SnbtValue::String("stone".into()));
+        assert_eq!(get("q"), SnbtValue::String("it's".into()));
+        assert_eq!(
+            get("list"),
+            SnbtValue::List(vec![SnbtValue::Int(1), SnbtValue::Int(2)])
+        );
+        assert_eq!(get("empty"), SnbtValue::List(vec![]));
+        assert_eq!(get("bytes"), SnbtValue::ByteArray(vec![1, 2]));
+        assert_eq!(get("ints"), SnbtValue::IntArray(vec![-1, 2]));
+        assert_eq!(get("longs"), SnbtValue::LongArray(vec![3]));
+        assert_eq!(get("quoted key"), SnbtValue::Compound(BTreeMap::new()));
+        assert_eq!(get("big"), SnbtValue::String("3000000000".into()));
+    }
+
+    #[test]
+    fn round_trips_through_display() {
+        let source = r#"{"a b":[B;1b,-2b],c:[L;3L],d:"say \"hi\"",e:"x",f:[1.5f,2.0d],g:{}}"#;
+        let value = SnbtValue::parse(source).unwrap();
+
+        assert_eq!(
+            value.to_string(),
+            source.replace(r#""say \"hi\"""#, r#"'say "hi"'"#)
+        );
+        assert_eq!(SnbtValue::parse(&value.to_string()).unwrap(), value);
+    }
+
+    #[test]
+    fn reports_errors() {
+        let error = SnbtValue::parse("{a: 1 b: 2}").unwrap_err();
+        assert_eq!(error.position(), 6);
+
+        assert!(SnbtValue::parse("[I; 1, x]").is_err());
+        assert!(SnbtValue::parse("\"unterminated").is_err());
+        assert!(SnbtValue::parse("1 2").is_err());
+    }
+}
@@ -0,0 +1,230 @@
+//! Serde-(de)serializable data types for `assets/<namespace>/sounds.json`.
+//!
+//! Start here: [`SoundsJson`].
+//!
+//! See <https://minecraft.fandom.com/wiki/Sounds.json>.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// The contents of a `sounds.json` file.
+///
+/// Each namespace has at most one `sounds.json` file, which maps sound event
+/// names (without the namespace) to their definitions.
+///
+/// See also the corresponding section of the [wiki page].
+///
+/// [wiki page]: <https://minecraft.fandom.com/wiki/Sounds.json#Java_Edition_values>
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::sounds::*;
+/// let sounds: SoundsJson = serde_json::from_str(r#"
+///     {
+///         "block.stone.break": {
+///             "sounds": [
+///                 "dig/stone1",
+///                 { "name": "dig/stone2", "volume": 0.8, "weight": 2 }
+///             ],
+///             "subtitle": "subtitles.block.generic.break"
+///         },
+///         "music.game": {
+///             "replace": true,
+///             "sounds": [
+///                 { "name": "music.menu", "type": "event" }
+///             ]
+///         }
+///     }
+/// "#).unwrap();
+///
+/// let stone = &sounds.events["block.stone.break"];
+/// assert!(!stone.replace);
+/// assert_eq!(stone.subtitle.as_deref(), Some("subtitles.block.generic.break"));
+///
+/// assert_eq!(stone.sounds[0].name(), "dig/stone1");
+/// assert_eq!(stone.sounds[0].volume(), 1.0);
+/// assert_eq!(stone.sounds[1].volume(), 0.8);
+/// assert_eq!(stone.sounds[1].weight(), 2);
+///
+/// let music = &sounds.events["music.game"];
+/// assert!(music.replace);
+/// assert_eq!(music.sounds[0].kind(), SoundKind::Event);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+pub struct SoundsJson {
+    /// Map of sound event names to their definitions.
+    pub events: HashMap<String, SoundEventDefinition>,
+}
+
+/// The definition of a sound event in a [`SoundsJson`].
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SoundEventDefinition {
+    /// Whether the sounds listed should replace the sounds listed in the
+    /// definition of this sound event by lower priority resource packs.
+    ///
+    /// When `false` the sounds are added to the existing sounds, instead.
+    #[serde(default)]
+    pub replace: bool,
+
+    /// The sounds that may be played when the event is triggered. One of them
+    /// is chosen at random.
+    #[serde(default)]
+    pub sounds: Vec<Sound>,
+
+    /// The translation key of the subtitle shown when the event is triggered
+    /// and subtitles are enabled.
+    pub subtitle: Option<String>,
+}
+
+/// A sound in the [`sounds`][SoundEventDefinition::sounds] of a
+/// [`SoundEventDefinition`].
+///
+/// A sound is either the path to a sound file, or an object with additional
+/// properties.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum Sound {
+    /// The path to a sound file, relative to `assets/<namespace>/sounds/` and
+    /// without the `.ogg` extension.
+    Name(String),
+
+    /// A sound with additional properties.
+    Object(SoundProperties),
+}
+
+impl Sound {
+    /// Returns the path to the sound file, or the name of the sound event if
+    /// [`kind`][Self::kind] is [`SoundKind::Event`].
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Name(name) => name,
+            Self::Object(properties) => &properties.name,
+        }
+    }
+
+    /// Returns the properties of the sound, or `None` if it was written as a
+    /// plain string.
+    pub fn properties(&self) -> Option<&SoundProperties> {
+        match self {
+            Self::Name(_) => None,
+            Self::Object(properties) => Some(properties),
+        }
+    }
+
+    /// Returns the volume of the sound. Defaults to `1.0`.
+    pub fn volume(&self) -> f32 {
+        self.properties()
+            .map(|p| p.volume)
+            .unwrap_or_else(SoundProperties::default_one)
+    }
+
+    /// Returns the pitch of the sound. Defaults to `1.0`.
+    pub fn pitch(&self) -> f32 {
+        self.properties()
+            .map(|p| p.pitch)
+            .unwrap_or_else(SoundProperties::default_one)
+    }
+
+    /// Returns the weight of the sound when choosing a random sound. Defaults
+    /// to `1`.
+    pub fn weight(&self) -> u32 {
+        self.properties()
+            .map(|p| p.weight)
+            .unwrap_or_else(SoundProperties::default_weight)
+    }
+
+    /// Returns whether the sound is streamed from its file. Defaults to
+    /// `false`.
+    pub fn stream(&self) -> bool {
+        self.properties().map(|p| p.stream).unwrap_or_default()
+    }
+
+    /// Returns whether [`name`][Self::name] refers to a file or a sound
+    /// event. Defaults to [`SoundKind::File`].
+    pub fn kind(&self) -> SoundKind {
+        self.properties().map(|p| p.kind).unwrap_or_default()
+    }
+}
+
+impl From<String> for Sound {
+    fn from(source: String) -> Self {
+        Self::Name(source)
+    }
+}
+
+impl<'a> From<&'a str> for Sound {
+    fn from(source: &'a str) -> Self {
+        Self::Name(String::from(source))
+    }
+}
+
+/// The properties of a [`Sound`] written as an object.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SoundProperties {
+    /// The path to the sound file, relative to `assets/<namespace>/sounds/`
+    /// and without the `.ogg` extension, or the name of a sound event if
+    /// [`kind`][Self::kind] is [`SoundKind::Event`].
+    pub name: String,
+
+    /// The volume of the sound, from `0.0` to `1.0`. Defaults to `1.0`.
+    #[serde(default = "SoundProperties::default_one")]
+    pub volume: f32,
+
+    /// The pitch of the sound. Defaults to `1.0`.
+    #[serde(default = "SoundProperties::default_one")]
+    pub pitch: f32,
+
+    /// The weight of the sound when choosing a random sound. Defaults to `1`.
+    #[serde(default = "SoundProperties::default_weight")]
+    pub weight: u32,
+
+    /// Whether the sound should be streamed from its file, instead of being
+    /// loaded into memory. Used for long sounds like music. Defaults to
+    /// `false`.
+    #[serde(default)]
+    pub stream: bool,
+
+    /// The distance, in blocks, at which the sound stops being audible.
+    /// Defaults to `16`.
+    pub attenuation_distance: Option<u32>,
+
+    /// Whether the sound should be loaded when the resource pack is loaded,
+    /// instead of when it is first played. Defaults to `false`.
+    #[serde(default)]
+    pub preload: bool,
+
+    /// Whether [`name`][Self::name] refers to a file or a sound event.
+    /// Defaults to [`SoundKind::File`].
+    #[serde(rename = "type", default)]
+    pub kind: SoundKind,
+}
+
+impl SoundProperties {
+    pub(crate) const fn default_one() -> f32 {
+        1.0
+    }
+
+    pub(crate) const fn default_weight() -> u32 {
+        1
+    }
+}
+
+/// What the [`name`][SoundProperties::name] of a [`Sound`] refers to.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum SoundKind {
+    /// A sound file.
+    #[default]
+    File,
+
+    /// Another sound event, whose sounds are used.
+    Event,
+}
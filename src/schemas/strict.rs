@@ -0,0 +1,689 @@
+//! Parsing resources in a strict mode, which rejects unknown fields and
+//! out-of-range values.
+//!
+//! Start here: [`from_slice()`] and [`check()`].
+//!
+//! The schemas of this crate are lenient: like the game, they ignore the fields
+//! that they do not know, and accept any value of the right type. This is the
+//! right behavior for loading packs, but it hides typos (e.g., `"rescael"`)
+//! and mistakes that the game rejects or silently fixes (e.g., a model element
+//! rotated by 60 degrees) from pack linting tools.
+//!
+//! In strict mode, a resource is parsed with its lenient schema, and then
+//! compared with the result of serializing it again. For this round trip, the
+//! fields that hold their default value are serialized too, and the unknown
+//! fields kept by the `preserve-unknown` feature are not. The fields of the
+//! input that do not survive the round trip are reported as
+//! [`Violation::UnknownField`]s. Fields whose value is `null`, `[]`, or `{}`
+//! are not reported, since they may be known fields that are skipped when
+//! serialized. The ids that are not valid [`ResourceLocation`]s (e.g., with
+//! uppercase letters, which the game only accepted prior to 1.13) are reported
+//! as [`Violation::InvalidIdentifier`]s. Then, the values of the resource are
+//! checked by its [`CheckRanges`] implementation.
+//!
+//! # Example
+//!
+//! ```
+//! use minecraft_assets::schemas::{strict::{self, Violation}, Model};
+//!
+//! let json = br##"{
+//!     "parent": "block/cube_all",
+//!     "elements": [{
+//!         "from": [0, 0, 0],
+//!         "to": [16, 48, 16],
+//!         "rotation": { "origin": [8, 8, 8], "axis": "y", "angle": 0, "rescael": true },
+//!         "faces": { "up": { "texture": "#all", "rotation": 45 } }
+//!     }]
+//! }"##;
+//!
+//! // The lenient schema accepts the model.
+//! let _: Model = serde_json::from_slice(json).unwrap();
+//!
+//! let (_, violations) = strict::check::<Model>(json).unwrap();
+//! assert_eq!(
+//!     violations.iter().map(ToString::to_string).collect::<Vec<_>>(),
+//!     [
+//!         "unknown field at /elements/0/rotation/rescael",
+//!         "/elements/0/to/1: 48 is not between -16 and 32",
+//!         "/elements/0/faces/up/rotation: 45 is not 0, 90, 180, or 270",
+//!     ]
+//! );
+//!
+//! assert!(strict::from_slice::<Model>(json).is_err());
+//!
+//! // Uppercase names are only accepted by the lenient schema.
+//! let (_, violations) = strict::check::<Model>(br#"{ "parent": "block/MyCube" }"#).unwrap();
+//! assert_matches::assert_matches!(
+//!     &violations[..],
+//!     [Violation::InvalidIdentifier { json_path, .. }] if json_path == "/parent"
+//! );
+//! ```
+
+use std::{cell::Cell, collections::HashMap, fmt};
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+use crate::{
+    api::{InvalidIdentifier, ResourceLocation},
+    schemas::{
+        blockstates::{ModelProperties, Variant},
+        models::BlockFace,
+        shader::{PostChain, PostEffect, ShaderProgram},
+        sounds::Sound,
+        texture::Texture,
+        worldgen::{
+            biome::{CustomeBiome, GenerationStep},
+            multi_noise_biome_source_parameter_list::MultiNoiseBiomeSourceParameterList,
+        },
+        Advancement, AssetIndex, Atlas, BannerPattern, BlockStates, ChatType, DamageType,
+        Enchantment, Equipment, Font, GpuWarnlist, ItemDefinition, ItemModifier, JukeboxSong,
+        LanguageFile, LootTable, Model, PackMeta, PaintingVariant, Particle, Predicate, Recipe,
+        RegionalCompliancies, SoundsJson, Tag, TrimMaterial, TrimPattern, WolfVariant,
+    },
+};
+
+/// A problem found by the strict mode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Violation {
+    /// A field is not part of the schema.
+    UnknownField {
+        /// The location of the field, as a [JSON pointer] (e.g.,
+        /// `/elements/0/rotation/rescael`).
+        ///
+        /// [JSON pointer]: <https://datatracker.ietf.org/doc/html/rfc6901>
+        json_path: String,
+    },
+
+    /// An id is not a valid [`ResourceLocation`].
+    InvalidIdentifier {
+        /// The location of the id, as a [JSON pointer] (e.g., `/parent`).
+        ///
+        /// [JSON pointer]: <https://datatracker.ietf.org/doc/html/rfc6901>
+        json_path: String,
+
+        /// Why the id is not valid.
+        error: InvalidIdentifier,
+    },
+
+    /// A value is outside of the range accepted by the game.
+    OutOfRange {
+        /// The location of the value, as a [JSON pointer] (e.g.,
+        /// `/elements/0/from/1`).
+        ///
+        /// [JSON pointer]: <https://datatracker.ietf.org/doc/html/rfc6901>
+        json_path: String,
+
+        /// A description of the accepted range, e.g., `48 is not between -16
+        /// and 32`.
+        message: String,
+    },
+}
+
+impl Violation {
+    /// Returns the location of the problem, as a JSON pointer.
+    pub fn json_path(&self) -> &str {
+        match self {
+            Self::UnknownField { json_path }
+            | Self::InvalidIdentifier { json_path, .. }
+            | Self::OutOfRange { json_path, .. } => json_path,
+        }
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownField { json_path } => write!(f, "unknown field at {}", json_path),
+            Self::InvalidIdentifier { json_path, error } => write!(f, "{}: {}", json_path, error),
+            Self::OutOfRange { json_path, message } => write!(f, "{}: {}", json_path, message),
+        }
+    }
+}
+
+/// An error from parsing a resource in strict mode.
+#[derive(Debug)]
+pub enum StrictError {
+    /// The resource does not match its schema, even in lenient mode.
+    Json(serde_json::Error),
+
+    /// The resource matches its schema in lenient mode, but not in strict mode.
+    Violations(Vec<Violation>),
+}
+
+impl fmt::Display for StrictError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Json(e) => e.fmt(f),
+            Self::Violations(violations) => {
+                write!(f, "{} strict mode violation(s)", violations.len())?;
+                for violation in violations {
+                    write!(f, "\n  {}", violation)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for StrictError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Json(e) => Some(e),
+            Self::Violations(_) => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for StrictError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Json(e)
+    }
+}
+
+/// Checks the values of a schema against the ranges accepted by the game.
+///
+/// The default implementation accepts every value. It is implemented for the
+/// top-level schemas of this crate, and overridden for those whose values have
+/// a restricted range, like [`Model`], [`BlockStates`], [`SoundsJson`],
+/// [texture metadata](Texture), and [`CustomeBiome`].
+pub trait CheckRanges {
+    /// Pushes a [`Violation::OutOfRange`] for each value that is out of
+    /// range.
+    fn check_ranges(&self, violations: &mut Vec<Violation>) {
+        let _ = violations;
+    }
+}
+
+thread_local! {
+    /// Whether [`check()`] is serializing a resource to compare it with its
+    /// input.
+    static ROUND_TRIP: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Returns whether the given field holds its default value, and can be skipped
+/// when serialized.
+///
+/// The fields are never skipped during the round trip of [`check()`], so that
+/// they are not mistaken for unknown fields.
+pub(crate) fn is_default<T: PartialEq>(value: &T, default: T) -> bool {
+    !ROUND_TRIP.with(Cell::get) && *value == default
+}
+
+/// Serializes the unknown fields kept by the `preserve-unknown` feature, except
+/// during the round trip of [`check()`], so that they are reported.
+#[cfg(feature = "preserve-unknown")]
+pub(crate) fn serialize_extra<S>(
+    extra: &serde_json::Map<String, Value>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    if ROUND_TRIP.with(Cell::get) {
+        serde_json::Map::new().serialize(serializer)
+    } else {
+        extra.serialize(serializer)
+    }
+}
+
+/// Parses a resource in strict mode, and returns it along with the violations
+/// of the strict mode.
+///
+/// Returns an error if the resource does not match its schema in lenient mode.
+pub fn check<T>(bytes: &[u8]) -> serde_json::Result<(T, Vec<Violation>)>
+where
+    T: DeserializeOwned + Serialize + CheckRanges,
+{
+    let input: Value = serde_json::from_slice(bytes)?;
+    let (value, invalid) = ResourceLocation::collect_invalid(|| T::deserialize(&input));
+    let value = value?;
+    ROUND_TRIP.with(|round_trip| round_trip.set(true));
+    let output = serde_json::to_value(&value);
+    ROUND_TRIP.with(|round_trip| round_trip.set(false));
+    let output = output?;
+
+    let mut violations = Vec::new();
+    unknown_fields(&input, &output, &mut String::new(), &mut violations);
+    invalid_identifiers(&input, &invalid, &mut String::new(), &mut violations);
+    value.check_ranges(&mut violations);
+
+    Ok((value, violations))
+}
+
+/// Parses a resource in strict mode.
+///
+/// Returns [`StrictError::Violations`] if the resource is only valid in lenient
+/// mode.
+pub fn from_slice<T>(bytes: &[u8]) -> Result<T, StrictError>
+where
+    T: DeserializeOwned + Serialize + CheckRanges,
+{
+    let (value, violations) = check(bytes)?;
+    if violations.is_empty() {
+        Ok(value)
+    } else {
+        Err(StrictError::Violations(violations))
+    }
+}
+
+/// Parses a resource from a string in strict mode.
+///
+/// See [`from_slice()`].
+pub fn from_str<T>(s: &str) -> Result<T, StrictError>
+where
+    T: DeserializeOwned + Serialize + CheckRanges,
+{
+    from_slice(s.as_bytes())
+}
+
+/// Reports the fields of `input` that are missing from `output`.
+fn unknown_fields(
+    input: &Value,
+    output: &Value,
+    path: &mut String,
+    violations: &mut Vec<Violation>,
+) {
+    match (input, output) {
+        (Value::Object(input), Value::Object(output)) => {
+            let mut keys: Vec<_> = input.keys().collect();
+            keys.sort();
+
+            for key in keys {
+                let len = path.len();
+                push_token(path, key);
+
+                let value = &input[key];
+                match output.get(key) {
+                    Some(output) => unknown_fields(value, output, path, violations),
+                    None if is_empty(value) => {}
+                    // The field was written under an alias, e.g., `item`
+                    // instead of `id`.
+                    None if output
+                        .iter()
+                        .any(|(k, v)| !input.contains_key(k) && v == value) => {}
+                    None => violations.push(Violation::UnknownField {
+                        json_path: path.clone(),
+                    }),
+                }
+
+                path.truncate(len);
+            }
+        }
+        (Value::Array(input), Value::Array(output)) if input.len() == output.len() => {
+            for (i, (input, output)) in input.iter().zip(output).enumerate() {
+                let len = path.len();
+                push_token(path, &i.to_string());
+                unknown_fields(input, output, path, violations);
+                path.truncate(len);
+            }
+        }
+        // The value was written in another form, e.g., a string instead of an
+        // object.
+        _ => {}
+    }
+}
+
+/// Reports the strings of `input` that are one of the `invalid` ids.
+fn invalid_identifiers(
+    input: &Value,
+    invalid: &[InvalidIdentifier],
+    path: &mut String,
+    violations: &mut Vec<Violation>,
+) {
+    match input {
+        Value::String(id) => {
+            if let Some(error) = invalid.iter().find(|error| error.id() == id) {
+                violations.push(Violation::InvalidIdentifier {
+                    json_path: path.clone(),
+                    error: error.clone(),
+                });
+            }
+        }
+        Value::Object(object) => {
+            let mut keys: Vec<_> = object.keys().collect();
+            keys.sort();
+
+            for key in keys {
+                let len = path.len();
+                push_token(path, key);
+                invalid_identifiers(&object[key], invalid, path, violations);
+                path.truncate(len);
+            }
+        }
+        Value::Array(array) => {
+            for (i, value) in array.iter().enumerate() {
+                let len = path.len();
+                push_token(path, &i.to_string());
+                invalid_identifiers(value, invalid, path, violations);
+                path.truncate(len);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn is_empty(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::Array(array) => array.is_empty(),
+        Value::Object(object) => object.is_empty(),
+        _ => false,
+    }
+}
+
+/// Appends a token to a JSON pointer, escaping it.
+fn push_token(path: &mut String, token: &str) {
+    path.push('/');
+    path.push_str(&token.replace('~', "~0").replace('/', "~1"));
+}
+
+/// Builds the violations of a [`CheckRanges`] implementation.
+struct Checker<'v> {
+    path: String,
+    violations: &'v mut Vec<Violation>,
+}
+
+impl<'v> Checker<'v> {
+    fn new(violations: &'v mut Vec<Violation>) -> Self {
+        Self {
+            path: String::new(),
+            violations,
+        }
+    }
+
+    /// Runs `f` with the given tokens appended to the path.
+    fn at(&mut self, tokens: &[&dyn fmt::Display], f: impl FnOnce(&mut Self)) {
+        let len = self.path.len();
+        for token in tokens {
+            push_token(&mut self.path, &token.to_string());
+        }
+        f(self);
+        self.path.truncate(len);
+    }
+
+    fn between<T>(&mut self, token: &dyn fmt::Display, value: T, min: T, max: T)
+    where
+        T: PartialOrd + fmt::Display,
+    {
+        if value < min || value > max {
+            self.report(
+                token,
+                format!("{} is not between {} and {}", value, min, max),
+            );
+        }
+    }
+
+    fn at_least<T>(&mut self, token: &dyn fmt::Display, value: T, min: T)
+    where
+        T: PartialOrd + fmt::Display,
+    {
+        if value < min {
+            self.report(token, format!("{} is less than {}", value, min));
+        }
+    }
+
+    fn right_angle<T>(&mut self, token: &dyn fmt::Display, value: T)
+    where
+        T: Into<i64> + Copy + fmt::Display,
+    {
+        if ![0, 90, 180, 270].contains(&value.into()) {
+            self.report(token, format!("{} is not 0, 90, 180, or 270", value));
+        }
+    }
+
+    fn report(&mut self, token: &dyn fmt::Display, message: String) {
+        let mut json_path = self.path.clone();
+        push_token(&mut json_path, &token.to_string());
+        self.violations
+            .push(Violation::OutOfRange { json_path, message });
+    }
+}
+
+/// Returns the keys of the given map, sorted.
+fn sorted<K: Ord, V>(map: &HashMap<K, V>) -> Vec<(&K, &V)> {
+    let mut entries: Vec<_> = map.iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+    entries
+}
+
+impl CheckRanges for Model {
+    fn check_ranges(&self, violations: &mut Vec<Violation>) {
+        let mut checker = Checker::new(violations);
+
+        for (i, element) in self.elements.iter().flatten().enumerate() {
+            checker.at(&[&"elements", &i], |checker| {
+                for (name, corner) in [("from", element.from), ("to", element.to)] {
+                    checker.at(&[&name], |checker| {
+                        for (j, coordinate) in corner.into_iter().enumerate() {
+                            checker.between(&j, coordinate, -16.0, 32.0);
+                        }
+                    });
+                }
+
+                checker.at(&[&"rotation"], |checker| {
+                    checker.between(&"angle", element.rotation.angle, -45.0, 45.0);
+                });
+
+                let mut faces: Vec<_> = element.faces.iter().collect();
+                faces.sort_by_key(|(face, _)| **face as u8);
+                for (face, element_face) in faces {
+                    checker.at(&[&"faces", &face_name(*face)], |checker| {
+                        if let Some(uv) = element_face.uv {
+                            checker.at(&[&"uv"], |checker| {
+                                for (j, coordinate) in uv.into_iter().enumerate() {
+                                    checker.between(&j, coordinate, 0.0, 16.0);
+                                }
+                            });
+                        }
+                        checker.right_angle(&"rotation", element_face.rotation);
+                    });
+                }
+            });
+        }
+    }
+}
+
+impl CheckRanges for BlockStates {
+    fn check_ranges(&self, violations: &mut Vec<Violation>) {
+        let mut checker = Checker::new(violations);
+
+        match self {
+            Self::Variants { variants } => {
+                for (name, variant) in sorted(variants) {
+                    checker.at(&[&"variants", name], |checker| {
+                        check_variant(checker, variant);
+                    });
+                }
+            }
+            Self::Multipart { cases } => {
+                for (i, case) in cases.iter().enumerate() {
+                    checker.at(&[&"multipart", &i, &"apply"], |checker| {
+                        check_variant(checker, &case.apply);
+                    });
+                }
+            }
+        }
+    }
+}
+
+fn check_variant(checker: &mut Checker, variant: &Variant) {
+    match variant {
+        Variant::Single(model) => check_model_properties(checker, model),
+        Variant::Multiple(models) => {
+            for (i, model) in models.iter().enumerate() {
+                checker.at(&[&i], |checker| check_model_properties(checker, model));
+            }
+        }
+    }
+}
+
+fn check_model_properties(checker: &mut Checker, model: &ModelProperties) {
+    checker.right_angle(&"x", model.x);
+    checker.right_angle(&"y", model.y);
+    checker.at_least(&"weight", model.weight, 1);
+}
+
+impl CheckRanges for SoundsJson {
+    fn check_ranges(&self, violations: &mut Vec<Violation>) {
+        let mut checker = Checker::new(violations);
+
+        for (event, definition) in sorted(&self.events) {
+            for (i, sound) in definition.sounds.iter().enumerate() {
+                let properties = match sound {
+                    Sound::Object(properties) => properties,
+                    Sound::Name(_) => continue,
+                };
+
+                checker.at(&[event, &"sounds", &i], |checker| {
+                    checker.between(&"volume", properties.volume, 0.0, 1.0);
+                    if properties.pitch <= 0.0 {
+                        checker.report(&"pitch", format!("{} is not positive", properties.pitch));
+                    }
+                    checker.at_least(&"weight", properties.weight, 1);
+                });
+            }
+        }
+    }
+}
+
+impl CheckRanges for Texture {
+    fn check_ranges(&self, violations: &mut Vec<Violation>) {
+        let mut checker = Checker::new(violations);
+
+        if let Some(animation) = &self.animation {
+            checker.at(&[&"animation"], |checker| {
+                checker.at_least(&"frametime", animation.frametime, 1);
+            });
+        }
+    }
+}
+
+impl CheckRanges for CustomeBiome {
+    fn check_ranges(&self, violations: &mut Vec<Violation>) {
+        let mut checker = Checker::new(violations);
+
+        if let Some(probability) = self.creature_spawn_probability {
+            checker.between(&"creature_spawn_probability", probability, 0.0, 0.9999999);
+        }
+
+        let steps = GenerationStep::ALL.len();
+        if self.features.len() > steps {
+            checker.report(
+                &"features",
+                format!(
+                    "{} generation steps is more than {}",
+                    self.features.len(),
+                    steps
+                ),
+            );
+        }
+    }
+}
+
+fn face_name(face: BlockFace) -> &'static str {
+    match face {
+        BlockFace::Down => "down",
+        BlockFace::Up => "up",
+        BlockFace::North => "north",
+        BlockFace::South => "south",
+        BlockFace::West => "west",
+        BlockFace::East => "east",
+    }
+}
+
+macro_rules! impl_check_ranges {
+    ($($schema:ty,)*) => {
+        $(impl CheckRanges for $schema {})*
+    };
+}
+
+impl_check_ranges! {
+    Value,
+    Advancement,
+    AssetIndex,
+    Atlas,
+    BannerPattern,
+    ChatType,
+    DamageType,
+    Enchantment,
+    Equipment,
+    Font,
+    GpuWarnlist,
+    ItemDefinition,
+    ItemModifier,
+    JukeboxSong,
+    LanguageFile,
+    LootTable,
+    MultiNoiseBiomeSourceParameterList,
+    PackMeta,
+    PaintingVariant,
+    Particle,
+    PostChain,
+    PostEffect,
+    Predicate,
+    Recipe,
+    RegionalCompliancies,
+    ShaderProgram,
+    Tag,
+    TrimMaterial,
+    TrimPattern,
+    WolfVariant,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn aliases_and_skipped_fields_are_known() {
+        let json = br#"{
+            "display": {
+                "icon": { "item": "minecraft:stick" },
+                "title": "Stick",
+                "description": "Get a stick"
+            },
+            "criteria": {},
+            "requirements": [],
+            "rewards": null
+        }"#;
+
+        let (_, violations) = check::<Advancement>(json).unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn fields_with_default_values_are_known() {
+        let json = br##"{
+            "elements": [{
+                "from": [0, 0, 0],
+                "to": [16, 16, 16],
+                "shade": true,
+                "faces": { "up": { "texture": "#all", "rotation": 0, "tintindex": -1 } }
+            }]
+        }"##;
+
+        let (_, violations) = check::<Model>(json).unwrap();
+        assert_eq!(violations, []);
+    }
+
+    #[test]
+    fn preserved_fields_are_unknown() {
+        let json = br#"{ "parent": "block/cube_all", "render_type": "cutout" }"#;
+
+        let (model, violations) = check::<Model>(json).unwrap();
+        assert_eq!(
+            violations,
+            [Violation::UnknownField {
+                json_path: "/render_type".to_string()
+            }]
+        );
+
+        #[cfg(feature = "preserve-unknown")]
+        assert!(serde_json::to_string(&model)
+            .unwrap()
+            .contains("render_type"));
+        #[cfg(not(feature = "preserve-unknown"))]
+        let _ = model;
+    }
+}
@@ -0,0 +1,174 @@
+//! Serde-(de)serializable data types for
+//! `data/<namespace>/structures/**/*.nbt`.
+//!
+//! Start here: [`StructureTemplate`].
+//!
+//! Unlike every other resource, structure templates are stored as
+//! gzip-compressed [NBT] rather than JSON. This module is only available with
+//! the `nbt` feature enabled.
+//!
+//! See <https://minecraft.fandom.com/wiki/Structure_file>.
+//!
+//! [NBT]: <https://minecraft.fandom.com/wiki/NBT_format>
+
+use std::io::{Read, Write};
+
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use serde::{Deserialize, Serialize};
+
+use crate::{api::Result, schemas::block::BlockStateValue};
+
+/// Re-export of the NBT value type used for untyped NBT data.
+pub use fastnbt::Value as NbtValue;
+
+/// A structure template as stored in the `data/<namespace>/structures/`
+/// directory.
+///
+/// Structure templates are used by structure blocks, jigsaw structures, and
+/// the `/place template` command.
+///
+/// See also the corresponding section of the [wiki page].
+///
+/// [wiki page]: <https://minecraft.fandom.com/wiki/Structure_file#NBT_structure>
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::structure::*;
+/// let mut template = StructureTemplate {
+///     data_version: 2975,
+///     size: [1, 2, 1],
+///     palette: vec![
+///         PaletteEntry::new("minecraft:stone").unwrap(),
+///         PaletteEntry::new("minecraft:oak_log").unwrap().with_property("axis", "y"),
+///     ],
+///     ..Default::default()
+/// };
+/// template.blocks.push(StructureBlock { state: 0, pos: [0, 0, 0], nbt: None });
+/// template.blocks.push(StructureBlock { state: 1, pos: [0, 1, 0], nbt: None });
+///
+/// let bytes = template.to_bytes().unwrap();
+/// let parsed = StructureTemplate::from_bytes(&bytes).unwrap();
+/// assert_eq!(parsed, template);
+///
+/// let log = parsed.block_state(&parsed.blocks[1], 0).unwrap();
+/// assert_eq!(log.name, "minecraft:oak_log");
+/// assert_eq!(log.properties["axis"], "y");
+/// ```
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+pub struct StructureTemplate {
+    /// The data version of the game that saved the structure.
+    #[serde(rename = "DataVersion")]
+    pub data_version: i32,
+
+    /// The size of the structure, as `[x, y, z]`.
+    pub size: [i32; 3],
+
+    /// The block states used in the structure.
+    ///
+    /// Either this or [`palettes`][Self::palettes] is present.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub palette: Vec<PaletteEntry>,
+
+    /// A list of alternative palettes, one of which is chosen at random when
+    /// the structure is placed (e.g., for shipwrecks).
+    ///
+    /// Either this or [`palette`][Self::palette] is present.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub palettes: Vec<Vec<PaletteEntry>>,
+
+    /// The blocks in the structure. Air blocks that are not in the list are
+    /// left untouched when the structure is placed.
+    #[serde(default)]
+    pub blocks: Vec<StructureBlock>,
+
+    /// The entities in the structure.
+    #[serde(default)]
+    pub entities: Vec<StructureEntity>,
+}
+
+impl StructureTemplate {
+    /// Parses a structure template from gzip-compressed or uncompressed NBT
+    /// bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.starts_with(&[0x1f, 0x8b]) {
+            let mut decompressed = Vec::new();
+            GzDecoder::new(bytes).read_to_end(&mut decompressed)?;
+            Ok(fastnbt::from_bytes(&decompressed)?)
+        } else {
+            Ok(fastnbt::from_bytes(bytes)?)
+        }
+    }
+
+    /// Serializes the structure template to gzip-compressed NBT bytes, as
+    /// stored in `.nbt` files.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let nbt = fastnbt::to_bytes(self)?;
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&nbt)?;
+        Ok(encoder.finish()?)
+    }
+
+    /// Returns the number of palettes in the structure.
+    pub fn palette_count(&self) -> usize {
+        if self.palettes.is_empty() {
+            1
+        } else {
+            self.palettes.len()
+        }
+    }
+
+    /// Returns the palette at the given index, or `None` if out of range.
+    ///
+    /// Structures with a single [`palette`][Self::palette] only have a
+    /// palette at index `0`.
+    pub fn palette(&self, index: usize) -> Option<&[PaletteEntry]> {
+        if self.palettes.is_empty() {
+            (index == 0).then_some(&self.palette[..])
+        } else {
+            self.palettes.get(index).map(|palette| &palette[..])
+        }
+    }
+
+    /// Returns the block state of the given block, as found in the palette at
+    /// the given index.
+    pub fn block_state(&self, block: &StructureBlock, palette: usize) -> Option<&PaletteEntry> {
+        self.palette(palette)?.get(block.state as usize)
+    }
+}
+
+/// A block state in the palette of a [`StructureTemplate`].
+pub type PaletteEntry = BlockStateValue;
+
+/// A block in a [`StructureTemplate`].
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+pub struct StructureBlock {
+    /// The index of the block state in the palette.
+    pub state: i32,
+
+    /// The position of the block relative to the structure origin, as
+    /// `[x, y, z]`.
+    pub pos: [i32; 3],
+
+    /// The block entity data of the block, without the `x`, `y`, and `z`
+    /// tags.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nbt: Option<NbtValue>,
+}
+
+/// An entity in a [`StructureTemplate`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct StructureEntity {
+    /// The exact position of the entity relative to the structure origin, as
+    /// `[x, y, z]`.
+    pub pos: [f64; 3],
+
+    /// The block position of the entity relative to the structure origin, as
+    /// `[x, y, z]`.
+    #[serde(rename = "blockPos")]
+    pub block_pos: [i32; 3],
+
+    /// The entity data, without the `UUID` and `Pos` tags.
+    pub nbt: NbtValue,
+}
@@ -0,0 +1,156 @@
+//! Serde-(de)serializable data types for
+//! `data/<namespace>/tags/**/*.json`.
+//!
+//! Start here: [`Tag`].
+//!
+//! See <https://minecraft.fandom.com/wiki/Tag>.
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::{InvalidIdentifier, ResourceLocation};
+
+/// A tag as stored in the `data/<namespace>/tags/` directory.
+///
+/// Tags group together blocks, items, entity types, fluids, functions, biomes,
+/// etc. The registry that a tag refers to is determined by the directory it is
+/// stored in (e.g., `tags/blocks/` or `tags/worldgen/biome/`).
+///
+/// See also the corresponding section of the [wiki page].
+///
+/// [wiki page]: <https://minecraft.fandom.com/wiki/Tag#JSON_format>
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::tags::*;
+/// let tag: Tag = serde_json::from_str(r##"
+///     {
+///         "replace": false,
+///         "values": [
+///             "minecraft:oak_log",
+///             "#minecraft:birch_logs",
+///             { "id": "othermod:magic_log", "required": false }
+///         ]
+///     }
+/// "##).unwrap();
+///
+/// assert!(!tag.replace);
+///
+/// assert_eq!(tag.values[0].id(), "minecraft:oak_log");
+/// assert!(!tag.values[0].is_tag());
+///
+/// assert_eq!(tag.values[1].id(), "#minecraft:birch_logs");
+/// assert!(tag.values[1].is_tag());
+///
+/// assert_eq!(tag.values[2].id(), "othermod:magic_log");
+/// assert!(!tag.values[2].is_required());
+/// ```
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Tag {
+    /// Whether or not the contents of this tag should completely replace tag
+    /// contents from different lower priority data packs with the same
+    /// resource location.
+    ///
+    /// When `false` the tag's content is appended to the contents of the
+    /// higher priority data packs, instead.
+    #[serde(default)]
+    pub replace: bool,
+
+    /// The entries of the tag.
+    pub values: Vec<TagEntry>,
+
+    /// The fields that this crate does not model (e.g., fields added by mods
+    /// or by newer versions), kept so that they survive a round trip.
+    ///
+    /// Requires the `preserve-unknown` feature.
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten, serialize_with = "crate::schemas::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// A single entry in the [`values`][Tag::values] of a [`Tag`].
+///
+/// An entry is either a namespaced ID of an object in the tag's registry, or a
+/// `#`-prefixed reference to another tag of the same registry.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum TagEntry {
+    /// A required entry, written as a plain string.
+    Id(ResourceLocation<'static>),
+
+    /// An entry written as an object, which may be optional.
+    Object {
+        /// The namespaced ID (or `#`-prefixed tag) of the entry.
+        id: ResourceLocation<'static>,
+
+        /// Whether loading the tag should fail if this entry does not exist.
+        /// Defaults to `true`.
+        #[serde(default = "TagEntry::default_required")]
+        required: bool,
+    },
+}
+
+impl TagEntry {
+    pub(crate) const fn default_required() -> bool {
+        true
+    }
+
+    /// Returns the namespaced ID (or `#`-prefixed tag) of the entry, as
+    /// written in the file.
+    pub fn id(&self) -> &str {
+        self.location().as_str()
+    }
+
+    /// Returns the location of the entry.
+    pub fn location(&self) -> &ResourceLocation<'static> {
+        match self {
+            Self::Id(id) => id,
+            Self::Object { id, .. } => id,
+        }
+    }
+
+    /// Returns `true` if this entry references another tag (i.e., starts with
+    /// `#`).
+    pub fn is_tag(&self) -> bool {
+        self.location().is_tag()
+    }
+
+    /// Returns the ID of the referenced tag (without the `#`), or `None` if
+    /// this entry is not a tag reference.
+    pub fn tag(&self) -> Option<&str> {
+        self.id().strip_prefix('#')
+    }
+
+    /// Returns `false` if this entry may be silently skipped when it does not
+    /// exist.
+    pub fn is_required(&self) -> bool {
+        match self {
+            Self::Id(_) => true,
+            Self::Object { required, .. } => *required,
+        }
+    }
+}
+
+impl From<ResourceLocation<'static>> for TagEntry {
+    fn from(source: ResourceLocation<'static>) -> Self {
+        Self::Id(source)
+    }
+}
+
+impl TryFrom<String> for TagEntry {
+    type Error = InvalidIdentifier;
+
+    fn try_from(source: String) -> Result<Self, Self::Error> {
+        ResourceLocation::new_owned(source).map(Self::Id)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for TagEntry {
+    type Error = InvalidIdentifier;
+
+    fn try_from(source: &'a str) -> Result<Self, Self::Error> {
+        source.parse().map(Self::Id)
+    }
+}
@@ -0,0 +1,638 @@
+//! Data types for the files in `assets/minecraft/texts/`, and for the JSON
+//! text components embedded in other resources.
+//!
+//! Start here: [`Splashes`], [`EndPoem`], [`Credits`], and [`TextComponent`].
+//!
+//! `splashes.txt` and `end.txt` are plain text rather than JSON, so they are
+//! parsed with [`Splashes::parse`] and [`EndPoem::parse`] instead of Serde.
+//! `credits.json` is Serde-(de)serializable.
+//!
+//! See <https://minecraft.fandom.com/wiki/Resource_pack#Texts> and
+//! <https://minecraft.wiki/w/Text_component_format>.
+
+use std::{convert::Infallible, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::schemas::components::DataComponents;
+
+/// The splash texts shown on the title screen, as stored in
+/// `assets/minecraft/texts/splashes.txt`.
+///
+/// The file contains one splash per line. Empty lines are ignored.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::text::*;
+/// let splashes = Splashes::parse("As seen on TV!\nAwesome!\n\n100% pure!\n");
+///
+/// assert_eq!(splashes.splashes, vec!["As seen on TV!", "Awesome!", "100% pure!"]);
+/// assert_eq!(splashes.to_string(), "As seen on TV!\nAwesome!\n100% pure!\n");
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct Splashes {
+    /// The splash texts, in order.
+    pub splashes: Vec<String>,
+}
+
+impl Splashes {
+    /// Parses the splash texts from the contents of a `splashes.txt` file.
+    pub fn parse(source: &str) -> Self {
+        let splashes = source
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(String::from)
+            .collect();
+
+        Self { splashes }
+    }
+}
+
+impl FromStr for Splashes {
+    type Err = Infallible;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        Ok(Self::parse(source))
+    }
+}
+
+impl std::fmt::Display for Splashes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for splash in &self.splashes {
+            writeln!(f, "{}", splash)?;
+        }
+        Ok(())
+    }
+}
+
+/// The end poem shown after defeating the Ender Dragon, as stored in
+/// `assets/minecraft/texts/end.txt`.
+///
+/// The poem is kept line by line, including empty lines. The lines contain
+/// `§` formatting codes that distinguish the two speakers, and the
+/// [`PLAYER_NAME`][Self::PLAYER_NAME] placeholder.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::text::*;
+/// let poem = EndPoem::parse("§3I see the player you mean.\n\n§2PLAYERNAME?\n");
+///
+/// assert_eq!(poem.lines.len(), 3);
+/// assert_eq!(poem.with_player_name("Steve")[2], "§2Steve?");
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct EndPoem {
+    /// The lines of the poem, in order.
+    pub lines: Vec<String>,
+}
+
+impl EndPoem {
+    /// The placeholder that is replaced with the player's name.
+    pub const PLAYER_NAME: &'static str = "PLAYERNAME";
+
+    /// Parses the poem from the contents of an `end.txt` file.
+    pub fn parse(source: &str) -> Self {
+        let lines = source.lines().map(String::from).collect();
+
+        Self { lines }
+    }
+
+    /// Returns the lines of the poem with the
+    /// [`PLAYER_NAME`][Self::PLAYER_NAME] placeholder replaced by the given
+    /// name.
+    pub fn with_player_name(&self, name: &str) -> Vec<String> {
+        self.lines
+            .iter()
+            .map(|line| line.replace(Self::PLAYER_NAME, name))
+            .collect()
+    }
+}
+
+impl FromStr for EndPoem {
+    type Err = Infallible;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        Ok(Self::parse(source))
+    }
+}
+
+/// The credits shown after the end poem, as stored in
+/// `assets/minecraft/texts/credits.json`.
+///
+/// Added in 1.17, replacing `credits.txt`.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::text::*;
+/// let credits: Credits = serde_json::from_str(r#"
+///     [
+///         {
+///             "section": "Mojang Studios",
+///             "disciplines": [
+///                 {
+///                     "discipline": "Mojang Studios Leadership",
+///                     "titles": [
+///                         { "title": "Creative Officer", "names": ["Lydia Winters"] }
+///                     ]
+///                 }
+///             ]
+///         }
+///     ]
+/// "#).unwrap();
+///
+/// let section = &credits.sections[0];
+/// assert_eq!(section.section, "Mojang Studios");
+/// assert_eq!(section.disciplines[0].titles[0].names, vec!["Lydia Winters"]);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+pub struct Credits {
+    /// The sections of the credits, in order.
+    pub sections: Vec<CreditsSection>,
+}
+
+/// A section of the [`Credits`], e.g., a company.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CreditsSection {
+    /// The heading of the section.
+    pub section: String,
+
+    /// The disciplines listed in the section.
+    #[serde(default)]
+    pub disciplines: Vec<CreditsDiscipline>,
+}
+
+/// A discipline in a [`CreditsSection`], e.g., a department.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CreditsDiscipline {
+    /// The heading of the discipline. May be empty.
+    pub discipline: String,
+
+    /// The job titles listed in the discipline.
+    #[serde(default)]
+    pub titles: Vec<CreditsTitle>,
+}
+
+/// A job title in a [`CreditsDiscipline`].
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CreditsTitle {
+    /// The job title. May be empty.
+    pub title: String,
+
+    /// The names of the people with this job title.
+    #[serde(default)]
+    pub names: Vec<String>,
+}
+
+/// A JSON text component, as embedded in advancements, item modifiers, pack
+/// descriptions, etc.
+///
+/// A text component is either a plain string, a list of components (where the
+/// first one is the parent of the others, which inherit its style), or an
+/// object with some content, a style, and children.
+///
+/// Only the formats used up to 1.21.4 are supported, e.g., click events have
+/// an `action` and a `value`.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::text::*;
+/// let component: TextComponent = serde_json::from_str(r#"
+///     [
+///         { "translate": "chat.type.advancement.task", "with": ["Steve", { "text": "Stone Age" }] },
+///         { "text": "!", "color": "green", "bold": true }
+///     ]
+/// "#).unwrap();
+///
+/// let children = match &component {
+///     TextComponent::List(children) => children,
+///     _ => unreachable!(),
+/// };
+/// let object = children[0].as_object().unwrap();
+/// assert!(matches!(&object.content, TextContent::Translatable { translate, .. } if translate == "chat.type.advancement.task"));
+///
+/// let style = &children[1].as_object().unwrap().style;
+/// assert_eq!(style.color.as_deref(), Some("green"));
+/// assert_eq!(style.bold, Some(true));
+///
+/// assert_eq!(TextComponent::from("Stone Age").as_str(), Some("Stone Age"));
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum TextComponent {
+    /// A plain string, equivalent to `{ "text": "..." }`.
+    String(String),
+
+    /// A number, equivalent to a plain string of its digits, e.g., in the
+    /// arguments of a translated string.
+    Number(serde_json::Number),
+
+    /// A boolean, equivalent to the plain string `true` or `false`.
+    Boolean(bool),
+
+    /// A list of components, where the first component is the parent of the
+    /// other ones.
+    List(Vec<TextComponent>),
+
+    /// A component with some content, a style, and children.
+    Object(Box<TextComponentObject>),
+}
+
+impl TextComponent {
+    /// Returns a component with the given plain text.
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::String(text.into())
+    }
+
+    /// Returns a component that translates the given key, without arguments.
+    pub fn translatable(key: impl Into<String>) -> Self {
+        Self::Object(Box::new(TextComponentObject {
+            content: TextContent::Translatable {
+                translate: key.into(),
+                fallback: None,
+                with: None,
+            },
+            ..Default::default()
+        }))
+    }
+
+    /// Returns the text of this component if it is a plain string.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    /// Returns this component as an object, if it is one.
+    pub fn as_object(&self) -> Option<&TextComponentObject> {
+        match self {
+            Self::Object(object) => Some(object),
+            _ => None,
+        }
+    }
+
+    /// Returns the children of this component: the components after the
+    /// first one for lists, and the [`extra`][TextComponentObject::extra]
+    /// components for objects.
+    pub fn children(&self) -> &[TextComponent] {
+        match self {
+            Self::String(_) | Self::Number(_) | Self::Boolean(_) => &[],
+            Self::List(components) => components.get(1..).unwrap_or_default(),
+            Self::Object(object) => object.extra.as_deref().unwrap_or_default(),
+        }
+    }
+}
+
+impl Default for TextComponent {
+    fn default() -> Self {
+        Self::String(String::new())
+    }
+}
+
+impl From<String> for TextComponent {
+    fn from(text: String) -> Self {
+        Self::String(text)
+    }
+}
+
+impl<'a> From<&'a str> for TextComponent {
+    fn from(text: &'a str) -> Self {
+        Self::String(String::from(text))
+    }
+}
+
+/// A [`TextComponent`] in its object form.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TextComponentObject {
+    /// The type of the content, e.g., `text` or `translatable`.
+    ///
+    /// This is optional, since the type is inferred from the fields of the
+    /// content. It only speeds up parsing in the game.
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+
+    /// The content of the component.
+    #[serde(flatten)]
+    pub content: TextContent,
+
+    /// The style of the component, which its children inherit.
+    #[serde(flatten)]
+    pub style: Style,
+
+    /// The children of the component, which are appended to its content.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extra: Option<Vec<TextComponent>>,
+}
+
+/// The content of a [`TextComponentObject`].
+///
+/// The variants are tried in the same order as the game, so an object with
+/// both a `text` and a `translate` field is plain text.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum TextContent {
+    /// Plain text.
+    Text {
+        /// The text.
+        text: String,
+    },
+
+    /// A translated string.
+    Translatable {
+        /// The translation key.
+        translate: String,
+
+        /// The string used if the key has no translation.
+        ///
+        /// Added in 1.19.4.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        fallback: Option<String>,
+
+        /// The arguments of the translated string.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        with: Option<Vec<TextComponent>>,
+    },
+
+    /// The name of the key bound to a control, e.g., `key.jump`.
+    Keybind {
+        /// The name of the control.
+        keybind: String,
+    },
+
+    /// The score of an entity in an objective.
+    Score {
+        /// The entity and objective of the score.
+        score: ScoreContent,
+    },
+
+    /// The names of the entities found by a selector.
+    Selector {
+        /// The selector, e.g., `@p`.
+        selector: String,
+
+        /// The separator between the names. Defaults to `", "` in gray.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        separator: Option<Box<TextComponent>>,
+    },
+
+    /// NBT values from a block entity, an entity, or a command storage.
+    Nbt {
+        /// The NBT path of the values.
+        nbt: String,
+
+        /// Whether the values are parsed as text components.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        interpret: Option<bool>,
+
+        /// The separator between the values. Defaults to `", "`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        separator: Option<Box<TextComponent>>,
+
+        /// The coordinates of the block entity.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        block: Option<String>,
+
+        /// The selector of the entity.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        entity: Option<String>,
+
+        /// The ID of the command storage.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        storage: Option<String>,
+
+        /// The source of the values: `block`, `entity`, or `storage`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        source: Option<String>,
+    },
+}
+
+impl Default for TextContent {
+    fn default() -> Self {
+        Self::Text {
+            text: String::new(),
+        }
+    }
+}
+
+/// The entity and objective of a [`TextContent::Score`].
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ScoreContent {
+    /// The name of the score holder, or a selector, or `*` for the reader.
+    pub name: String,
+
+    /// The name of the objective.
+    pub objective: String,
+}
+
+/// The style of a [`TextComponent`].
+///
+/// Every field is optional, and is inherited from the parent if absent.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Style {
+    /// The color of the text, as a color name (e.g., `red`) or as a
+    /// `#RRGGBB` hex code.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+
+    /// The color of the shadow of the text, as an ARGB integer or a list of
+    /// RGBA floats.
+    ///
+    /// Added in 1.21.4.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shadow_color: Option<serde_json::Value>,
+
+    /// Whether the text is bold.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bold: Option<bool>,
+
+    /// Whether the text is italic.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub italic: Option<bool>,
+
+    /// Whether the text is underlined.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub underlined: Option<bool>,
+
+    /// Whether the text is struck through.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strikethrough: Option<bool>,
+
+    /// Whether the characters of the text are randomly replaced.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub obfuscated: Option<bool>,
+
+    /// The font of the text, e.g., `minecraft:uniform`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub font: Option<String>,
+
+    /// The text inserted into the chat input when the text is shift-clicked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub insertion: Option<String>,
+
+    /// What happens when the text is clicked.
+    #[serde(
+        rename = "clickEvent",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub click_event: Option<ClickEvent>,
+
+    /// What is shown when the text is hovered.
+    #[serde(
+        rename = "hoverEvent",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub hover_event: Option<HoverEvent>,
+}
+
+/// What happens when a [`TextComponent`] is clicked.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ClickEvent {
+    /// The action to perform.
+    pub action: ClickAction,
+
+    /// The value used by the action, e.g., the URL or the command.
+    pub value: String,
+}
+
+/// The action of a [`ClickEvent`].
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ClickAction {
+    /// Opens the URL in the value.
+    OpenUrl,
+
+    /// Opens the file in the value. Only used by the game's own messages.
+    OpenFile,
+
+    /// Runs the command in the value.
+    RunCommand,
+
+    /// Puts the command in the value into the chat input.
+    SuggestCommand,
+
+    /// Goes to the page in the value of a book.
+    ChangePage,
+
+    /// Copies the value to the clipboard.
+    CopyToClipboard,
+}
+
+/// What is shown when a [`TextComponent`] is hovered.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::text::*;
+/// let component: TextComponent = serde_json::from_str(r#"
+///     {
+///         "text": "Diamond",
+///         "hoverEvent": { "action": "show_item", "contents": { "id": "minecraft:diamond", "count": 2 } },
+///         "clickEvent": { "action": "copy_to_clipboard", "value": "diamond" }
+///     }
+/// "#).unwrap();
+///
+/// let style = &component.as_object().unwrap().style;
+/// assert!(matches!(
+///     &style.hover_event,
+///     Some(HoverEvent::ShowItem(HoverItem::Stack { id, count: Some(2), .. })) if id == "minecraft:diamond"
+/// ));
+/// assert_eq!(style.click_event.as_ref().unwrap().action, ClickAction::CopyToClipboard);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(tag = "action", content = "contents", rename_all = "snake_case")]
+pub enum HoverEvent {
+    /// Shows a text component.
+    ShowText(Box<TextComponent>),
+
+    /// Shows the tooltip of an item.
+    ShowItem(HoverItem),
+
+    /// Shows the name, type, and UUID of an entity.
+    ShowEntity(HoverEntity),
+}
+
+/// The item shown by a [`HoverEvent::ShowItem`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum HoverItem {
+    /// The ID of a single item.
+    Id(String),
+
+    /// A stack of items.
+    Stack {
+        /// The ID of the item.
+        id: String,
+
+        /// The number of items in the stack.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        count: Option<i32>,
+
+        /// The data components of the stack.
+        ///
+        /// Added in 1.20.5, replacing `tag`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        components: Option<DataComponents>,
+
+        /// The NBT of the stack, as an SNBT string.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        tag: Option<String>,
+    },
+}
+
+/// The entity shown by a [`HoverEvent::ShowEntity`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct HoverEntity {
+    /// The ID of the entity type.
+    #[serde(rename = "type")]
+    pub kind: String,
+
+    /// The UUID of the entity, as a string or as a list of four integers.
+    pub id: serde_json::Value,
+
+    /// The name of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<TextComponent>>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn numbers_and_booleans_are_components() {
+        let component: TextComponent =
+            serde_json::from_str(r#"{ "translate": "chat.square_brackets", "with": [1, true] }"#)
+                .unwrap();
+        let with = match &component.as_object().unwrap().content {
+            TextContent::Translatable { with, .. } => with.as_deref().unwrap(),
+            _ => unreachable!(),
+        };
+        assert_eq!(with[0], TextComponent::Number(1.into()));
+        assert_eq!(with[1], TextComponent::Boolean(true));
+
+        let component: TextComponent = serde_json::from_str("2.5").unwrap();
+        assert_eq!(serde_json::to_string(&component).unwrap(), "2.5");
+    }
+}
@@ -1,5 +1,5 @@
 //! Serde-(de)serializable data types for
-//! `assets/<namespace>/textures/{block}/*.mcmeta`
+//! `assets/<namespace>/textures/**/*.mcmeta`
 //!
 //! Start here: [`Texture`].
 //!
@@ -7,46 +7,486 @@
 
 use serde::{Deserialize, Serialize};
 
-///Describes the order of a single frame in a texture animation
+/// The struct representing an entire texture `.mcmeta` file.
+///
+/// Every section is optional; sections that are absent leave the texture
+/// unaffected.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::texture::*;
+/// let meta: Texture = serde_json::from_str(r#"
+///     {
+///         "animation": {
+///             "frametime": 2,
+///             "interpolate": true,
+///             "frames": [0, 1, { "index": 2, "time": 10 }, { "index": 1 }]
+///         },
+///         "texture": { "blur": true },
+///         "villager": { "hat": "partial" }
+///     }
+/// "#).unwrap();
+///
+/// let animation = meta.animation.unwrap();
+/// assert_eq!(animation.frametime, 2);
+///
+/// let frames = animation.frames.unwrap();
+/// assert_eq!(frames[1], Frame::Index(1));
+/// assert_eq!(frames[2].index(), 2);
+/// assert_eq!(frames[2].time(animation.frametime), 10);
+/// assert_eq!(frames[3].time(animation.frametime), 2);
+///
+/// let texture = meta.texture.unwrap();
+/// assert!(texture.blur);
+/// assert!(!texture.clamp);
+///
+/// assert_eq!(meta.villager.unwrap().hat, VillagerHat::Partial);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Texture {
+    /// Optional, describes the animation a texture will have in-game.
+    pub animation: Option<TextureAnimation>,
+
+    /// Optional, describes how the texture is sampled.
+    pub texture: Option<TextureProperties>,
+
+    /// Optional, describes how a villager or zombie villager profession or
+    /// biome texture in `textures/entity/(zombie_)villager/` interacts with
+    /// the hat layers of other textures.
+    pub villager: Option<VillagerMeta>,
+
+    /// Optional, describes how a GUI sprite in `textures/gui/sprites/` is
+    /// scaled to the size it is drawn at.
+    ///
+    /// Added in 1.20.2.
+    pub gui: Option<GuiMeta>,
+
+    /// The fields that this crate does not model (e.g., fields added by mods
+    /// or by newer versions), kept so that they survive a round trip.
+    ///
+    /// Requires the `preserve-unknown` feature.
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten, serialize_with = "crate::schemas::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// The `animation` section of a [`Texture`] `.mcmeta` file.
+///
+/// An animated texture is made up of frames stacked vertically (or laid out
+/// in a grid if [`width`][Self::width] or [`height`][Self::height] is given).
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TextureAnimation {
+    /// Whether the game should blend between frames. Defaults to `false`.
+    #[serde(default)]
+    pub interpolate: bool,
+
+    /// The width of a frame, in pixels. Defaults to the width of the texture,
+    /// or to its height if [`height`][Self::height] is also absent.
+    ///
+    /// Not used in vanilla's asset files.
+    pub width: Option<u32>,
+
+    /// The height of a frame, in pixels. Defaults to the width of the
+    /// texture, or to [`width`][Self::width] if that is given.
+    ///
+    /// Not used in vanilla's asset files.
+    pub height: Option<u32>,
+
+    /// How long each frame lasts, in ticks, unless overridden by a
+    /// [`Frame::Override`]. Defaults to `1`.
+    #[serde(default = "TextureAnimation::default_frametime")]
+    pub frametime: i32,
+
+    /// The order in which the frames are shown. Defaults to every frame of the
+    /// texture, top to bottom.
+    pub frames: Option<Vec<Frame>>,
+}
+
+impl TextureAnimation {
+    pub(crate) const fn default_frametime() -> i32 {
+        1
+    }
+
+    /// Returns the index and duration, in ticks, of every frame of the
+    /// animation, in the order in which they are shown, given the number of
+    /// frames in the texture.
+    ///
+    /// Like in the game, frames whose index is out of bounds or whose duration
+    /// is not positive are skipped, and every frame of the texture is shown
+    /// for the animation's [`frametime`][Self::frametime] if
+    /// [`frames`][Self::frames] is absent or empty.
+    pub fn frame_times(&self, frame_count: u32) -> Vec<(u32, u32)> {
+        match self.frames.as_deref() {
+            Some(frames) if !frames.is_empty() => frames
+                .iter()
+                .map(|frame| (frame.index(), frame.time(self.frametime)))
+                .filter(|&(index, time)| index < frame_count && time > 0)
+                .collect(),
+            _ => (0..frame_count)
+                .map(|index| (index, self.frametime.max(0) as u32))
+                .filter(|&(_, time)| time > 0)
+                .collect(),
+        }
+    }
+
+    /// Returns the number of ticks after which the animation repeats, given
+    /// the number of frames in the texture.
+    pub fn total_time(&self, frame_count: u32) -> u64 {
+        self.frame_times(frame_count)
+            .iter()
+            .map(|&(_, time)| u64::from(time))
+            .sum()
+    }
+
+    /// Returns the frame shown at the given tick, counted from the start of
+    /// the animation, given the number of frames in the texture.
+    ///
+    /// Like in the game, the animation is only played if it has at least two
+    /// valid frames (see [`frame_times()`][Self::frame_times]). Otherwise,
+    /// the first frame of the texture is always shown.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::texture::*;
+    /// let animation: TextureAnimation = serde_json::from_str(r#"
+    ///     { "frametime": 4, "interpolate": true, "frames": [2, { "index": 0, "time": 2 }] }
+    /// "#).unwrap();
+    ///
+    /// assert_eq!(animation.total_time(3), 6);
+    /// assert_eq!(
+    ///     animation.frame_at(3, 1),
+    ///     AnimationFrame { index: 2, next_index: 0, factor: 0.25 }
+    /// );
+    /// assert_eq!(
+    ///     animation.frame_at(3, 5),
+    ///     AnimationFrame { index: 0, next_index: 2, factor: 0.5 }
+    /// );
+    ///
+    /// // The animation repeats.
+    /// assert_eq!(animation.frame_at(3, 6), animation.frame_at(3, 0));
+    /// ```
+    pub fn frame_at(&self, frame_count: u32, tick: u64) -> AnimationFrame {
+        let frames = self.frame_times(frame_count);
+        if frames.len() < 2 {
+            return AnimationFrame {
+                index: 0,
+                next_index: 0,
+                factor: 0.0,
+            };
+        }
+
+        let total: u64 = frames.iter().map(|&(_, time)| u64::from(time)).sum();
+        let mut elapsed = tick % total;
+        for (i, &(index, time)) in frames.iter().enumerate() {
+            if elapsed < u64::from(time) {
+                let factor = if self.interpolate {
+                    elapsed as f32 / time as f32
+                } else {
+                    0.0
+                };
+                return AnimationFrame {
+                    index,
+                    next_index: frames[(i + 1) % frames.len()].0,
+                    factor,
+                };
+            }
+            elapsed -= u64::from(time);
+        }
+
+        unreachable!("the elapsed ticks are less than the total time")
+    }
+}
+
+impl Default for TextureAnimation {
+    fn default() -> Self {
+        Self {
+            interpolate: false,
+            width: None,
+            height: None,
+            frametime: Self::default_frametime(),
+            frames: None,
+        }
+    }
+}
+
+/// The frame of a [`TextureAnimation`] shown at a given tick, as returned by
+/// [`TextureAnimation::frame_at()`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnimationFrame {
+    /// The index of the frame in the texture.
+    pub index: u32,
+
+    /// The index in the texture of the frame shown after this one.
+    pub next_index: u32,
+
+    /// How far the animation has progressed from this frame towards the next
+    /// one, from `0.0` to `1.0`, which the game uses to blend them if the
+    /// animation [interpolates][TextureAnimation::interpolate].
+    ///
+    /// This is always `0.0` if the animation does not interpolate.
+    pub factor: f32,
+}
+
+/// A single frame in the [`frames`][TextureAnimation::frames] of a
+/// [`TextureAnimation`].
+///
+/// This is either the index of a frame, or an object with a custom duration.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 pub enum Frame {
-    ///Normal frame order
+    /// The index of a frame in the texture, shown for the animation's
+    /// [`frametime`][TextureAnimation::frametime].
     Index(u32),
-    ///Describes if a frame should display differently than in the default order. Unused in vanilla
+
+    /// A frame that may be shown for a different duration than the
+    /// animation's [`frametime`][TextureAnimation::frametime].
     Override {
-        ///Index of the frame in the texture
+        /// The index of the frame in the texture.
         index: u32,
-        ///How long this specific frame should last for
-        time: u32
+
+        /// How long this specific frame lasts, in ticks. Defaults to the
+        /// animation's [`frametime`][TextureAnimation::frametime].
+        time: Option<u32>,
+    },
+}
+
+impl Frame {
+    /// Returns the index of the frame in the texture.
+    pub fn index(&self) -> u32 {
+        match *self {
+            Self::Index(index) => index,
+            Self::Override { index, .. } => index,
+        }
+    }
+
+    /// Returns how long the frame lasts, in ticks, given the animation's
+    /// [`frametime`][TextureAnimation::frametime].
+    pub fn time(&self, frametime: i32) -> u32 {
+        match *self {
+            Self::Override {
+                time: Some(time), ..
+            } => time,
+            _ => frametime.max(0) as u32,
+        }
     }
 }
 
-fn one() -> i32 {
-    1
+impl From<u32> for Frame {
+    fn from(index: u32) -> Self {
+        Self::Index(index)
+    }
 }
 
-///A struct representing a textures animation within a texture .mcmeta file
-#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
-pub struct TextureAnimation {
-    ///Describes whether or not the game should "blend" between frames
+/// The `texture` section of a [`Texture`] `.mcmeta` file.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TextureProperties {
+    /// Whether the texture is blurred when magnified. Defaults to `false`.
     #[serde(default)]
-    pub interpolate: bool,
-    ///Not used in vanilla's asset files
-    pub width: Option<u32>,
-    ///Not used in vanilla's asset files
-    pub height: Option<u32>,
-    ///How quickly, in ticks, the animation should progress. Defaults to one.
-    #[serde(default = "one")]
-    pub frametime: i32,
-    ///Extra information about each frame
-    pub frames: Option<Vec<Frame>>
+    pub blur: bool,
+
+    /// Whether the texture is clamped instead of repeated when sampled
+    /// outside of its bounds. Defaults to `false`.
+    #[serde(default)]
+    pub clamp: bool,
 }
 
-///The struct representing an entire texture .mcmeta
-#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
-pub struct Texture {
-    ///Optional, describes the animation a texture will have in-game
-    pub animation: Option<TextureAnimation>
+/// The `villager` section of a [`Texture`] `.mcmeta` file.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct VillagerMeta {
+    /// How the texture's hat layer interacts with other textures' hat
+    /// layers. Defaults to [`VillagerHat::None`].
+    #[serde(default)]
+    pub hat: VillagerHat,
+}
+
+/// The [`hat`][VillagerMeta::hat] of a [`VillagerMeta`].
+#[derive(Deserialize, Serialize, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum VillagerHat {
+    /// The texture has no hat.
+    #[default]
+    None,
+
+    /// The texture has a hat that covers only the top of the head, so the
+    /// hat of the biome texture is still shown.
+    Partial,
+
+    /// The texture has a hat that covers the whole head, hiding the hat of
+    /// the biome texture.
+    Full,
+}
+
+/// The `gui` section of a [`Texture`] `.mcmeta` file.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::texture::*;
+/// let meta: Texture = serde_json::from_str(r#"
+///     {
+///         "gui": {
+///             "scaling": {
+///                 "type": "nine_slice",
+///                 "width": 200,
+///                 "height": 20,
+///                 "border": { "left": 20, "top": 4, "right": 20, "bottom": 4 }
+///             }
+///         }
+///     }
+/// "#).unwrap();
+///
+/// match meta.gui.unwrap().scaling {
+///     GuiScaling::NineSlice { width, border, .. } => {
+///         assert_eq!(width, 200);
+///         assert_eq!(border.left(), 20);
+///         assert_eq!(border.top(), 4);
+///     }
+///     _ => unreachable!(),
+/// }
+///
+/// let meta: Texture = serde_json::from_str(r#"
+///     { "gui": { "scaling": { "type": "nine_slice", "width": 16, "height": 16, "border": 3 } } }
+/// "#).unwrap();
+///
+/// match meta.gui.unwrap().scaling {
+///     GuiScaling::NineSlice { border, .. } => assert_eq!(border.bottom(), 3),
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct GuiMeta {
+    /// How the sprite is scaled. Defaults to [`GuiScaling::Stretch`].
+    #[serde(default)]
+    pub scaling: GuiScaling,
+}
+
+/// How a GUI sprite is scaled to the size it is drawn at.
+///
+/// The scaling's `type` field determines which variant is used.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum GuiScaling {
+    /// The sprite is stretched to the drawn size.
+    #[default]
+    Stretch,
+
+    /// The sprite is repeated to fill the drawn size.
+    Tile {
+        /// The width, in pixels, the sprite is drawn at before repeating.
+        width: u32,
+
+        /// The height, in pixels, the sprite is drawn at before repeating.
+        height: u32,
+    },
+
+    /// The corners of the sprite are drawn as-is, while its edges and center
+    /// are repeated to fill the drawn size.
+    NineSlice {
+        /// The width, in pixels, the whole sprite is drawn at.
+        width: u32,
+
+        /// The height, in pixels, the whole sprite is drawn at.
+        height: u32,
 
-}
\ No newline at end of file
+        /// The size, in pixels, of the edges of the sprite.
+        border: NineSliceBorder,
+
+        /// Whether the center and edges are stretched rather than repeated.
+        /// Defaults to `false`.
+        ///
+        /// Added in 1.21.2.
+        #[serde(default)]
+        stretch_inner: bool,
+    },
+}
+
+/// The border sizes of a [`GuiScaling::NineSlice`].
+///
+/// This is either a single size for every edge, or a size for each edge.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum NineSliceBorder {
+    /// The same size for every edge.
+    Uniform(u32),
+
+    /// A size for each edge.
+    Sides {
+        /// The size of the left edge.
+        left: u32,
+
+        /// The size of the top edge.
+        top: u32,
+
+        /// The size of the right edge.
+        right: u32,
+
+        /// The size of the bottom edge.
+        bottom: u32,
+    },
+}
+
+impl NineSliceBorder {
+    /// Returns the size of the left edge.
+    pub fn left(&self) -> u32 {
+        match *self {
+            Self::Uniform(size) => size,
+            Self::Sides { left, .. } => left,
+        }
+    }
+
+    /// Returns the size of the top edge.
+    pub fn top(&self) -> u32 {
+        match *self {
+            Self::Uniform(size) => size,
+            Self::Sides { top, .. } => top,
+        }
+    }
+
+    /// Returns the size of the right edge.
+    pub fn right(&self) -> u32 {
+        match *self {
+            Self::Uniform(size) => size,
+            Self::Sides { right, .. } => right,
+        }
+    }
+
+    /// Returns the size of the bottom edge.
+    pub fn bottom(&self) -> u32 {
+        match *self {
+            Self::Uniform(size) => size,
+            Self::Sides { bottom, .. } => bottom,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn zero_frametime_shows_the_first_frame() {
+        let animation: TextureAnimation = serde_json::from_str(r#"{ "frametime": 0 }"#).unwrap();
+
+        assert_eq!(animation.frame_times(4), []);
+        assert_eq!(animation.total_time(4), 0);
+        assert_eq!(
+            animation.frame_at(4, 7),
+            AnimationFrame {
+                index: 0,
+                next_index: 0,
+                factor: 0.0
+            }
+        );
+    }
+}
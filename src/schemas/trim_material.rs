@@ -0,0 +1,85 @@
+//! Serde-(de)serializable data types for
+//! `data/<namespace>/trim_material/*.json`.
+//!
+//! Start here: [`TrimMaterial`].
+//!
+//! Trim materials were added in 1.19.4.
+//!
+//! See <https://minecraft.fandom.com/wiki/Armor_trim>.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{api::ResourceLocation, schemas::text::TextComponent};
+
+/// An armor trim material as stored in the `data/<namespace>/trim_material/`
+/// directory.
+///
+/// The material determines the color palette of the trim pattern.
+///
+/// See also the corresponding section of the [wiki page].
+///
+/// [wiki page]: <https://minecraft.fandom.com/wiki/Armor_trim#Trim_material>
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::trim_material::*;
+/// let material: TrimMaterial = serde_json::from_str(r##"
+///     {
+///         "asset_name": "gold",
+///         "description": { "color": "#DEB12D", "translate": "trim_material.minecraft.gold" },
+///         "ingredient": "minecraft:gold_ingot",
+///         "item_model_index": 0.6,
+///         "override_armor_materials": { "gold": "gold_darker" }
+///     }
+/// "##).unwrap();
+///
+/// assert_eq!(material.asset_name, "gold");
+/// assert_eq!(material.ingredient.unwrap(), "minecraft:gold_ingot");
+/// assert_eq!(material.override_armor_materials["gold"], "gold_darker");
+/// ```
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TrimMaterial {
+    /// The suffix used for the trim textures, e.g., `gold` for
+    /// `trims/models/armor/coast_gold`.
+    pub asset_name: String,
+
+    /// The name of the material shown in the item tooltip, as a JSON text
+    /// component.
+    pub description: TextComponent,
+
+    /// The namespaced ID of the item used to apply the material in a smithing
+    /// table.
+    ///
+    /// Removed in 1.21.5.
+    pub ingredient: Option<ResourceLocation<'static>>,
+
+    /// The value of the `trim_type` item model override predicate.
+    ///
+    /// Removed in 1.21.4.
+    pub item_model_index: Option<f32>,
+
+    /// Map of armor material names to the asset names used instead of
+    /// [`asset_name`][Self::asset_name] when applied to armor of that
+    /// material.
+    ///
+    /// Renamed to `override_armor_assets` in 1.21.4. Both names are accepted,
+    /// but it is always serialized as `override_armor_materials`.
+    #[serde(
+        default,
+        alias = "override_armor_assets",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    pub override_armor_materials: HashMap<String, String>,
+
+    /// The fields that this crate does not model (e.g., fields added by mods
+    /// or by newer versions), kept so that they survive a round trip.
+    ///
+    /// Requires the `preserve-unknown` feature.
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten, serialize_with = "crate::schemas::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
@@ -0,0 +1,69 @@
+//! Serde-(de)serializable data types for
+//! `data/<namespace>/trim_pattern/*.json`.
+//!
+//! Start here: [`TrimPattern`].
+//!
+//! Trim patterns were added in 1.19.4.
+//!
+//! See <https://minecraft.fandom.com/wiki/Armor_trim>.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{api::ResourceLocation, schemas::text::TextComponent};
+
+/// An armor trim pattern as stored in the `data/<namespace>/trim_pattern/`
+/// directory.
+///
+/// The pattern determines the shape of the trim on the armor.
+///
+/// See also the corresponding section of the [wiki page].
+///
+/// [wiki page]: <https://minecraft.fandom.com/wiki/Armor_trim#Trim_pattern>
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::trim_pattern::*;
+/// let pattern: TrimPattern = serde_json::from_str(r#"
+///     {
+///         "asset_id": "minecraft:coast",
+///         "description": { "translate": "trim_pattern.minecraft.coast" },
+///         "template_item": "minecraft:coast_armor_trim_smithing_template"
+///     }
+/// "#).unwrap();
+///
+/// assert_eq!(pattern.asset_id, "minecraft:coast");
+/// assert!(!pattern.decal);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TrimPattern {
+    /// The namespaced ID used for the trim textures, e.g., `minecraft:coast`
+    /// for `trims/models/armor/coast`.
+    pub asset_id: ResourceLocation<'static>,
+
+    /// The name of the pattern shown in the item tooltip, as a JSON text
+    /// component.
+    pub description: TextComponent,
+
+    /// The namespaced ID of the smithing template item used to apply the
+    /// pattern.
+    ///
+    /// Removed in 1.21.5.
+    pub template_item: Option<ResourceLocation<'static>>,
+
+    /// Whether the pattern texture is masked by the armor texture. Defaults
+    /// to `false`.
+    ///
+    /// Added in 1.20.2.
+    #[serde(default)]
+    pub decal: bool,
+
+    /// The fields that this crate does not model (e.g., fields added by mods
+    /// or by newer versions), kept so that they survive a round trip.
+    ///
+    /// Requires the `preserve-unknown` feature.
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten, serialize_with = "crate::schemas::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
@@ -0,0 +1,109 @@
+//! Serde-(de)serializable data types for
+//! `data/<namespace>/wolf_variant/*.json`.
+//!
+//! Start here: [`WolfVariant`].
+//!
+//! Wolf variants were added in 1.20.5.
+//!
+//! See <https://minecraft.fandom.com/wiki/Wolf_variant_definition>.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{api::ResourceLocation, schemas::reference::IdList};
+
+/// A wolf variant as stored in the `data/<namespace>/wolf_variant/`
+/// directory.
+///
+/// Prior to 1.21.5 the textures are given as the `*_texture` fields and the
+/// spawn biomes as [`biomes`][Self::biomes]. Since 1.21.5 the textures are
+/// given in [`assets`][Self::assets] and the spawn biomes are part of
+/// [`spawn_conditions`][Self::spawn_conditions].
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::wolf_variant::*;
+/// let variant: WolfVariant = serde_json::from_str(r##"
+///     {
+///         "wild_texture": "minecraft:entity/wolf/wolf_ashen",
+///         "tame_texture": "minecraft:entity/wolf/wolf_ashen_tame",
+///         "angry_texture": "minecraft:entity/wolf/wolf_ashen_angry",
+///         "biomes": "minecraft:snowy_taiga"
+///     }
+/// "##).unwrap();
+///
+/// assert_eq!(variant.textures().unwrap().tame, "minecraft:entity/wolf/wolf_ashen_tame");
+/// ```
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct WolfVariant {
+    /// The texture of a wild wolf.
+    ///
+    /// Replaced by [`assets`][Self::assets] in 1.21.5.
+    pub wild_texture: Option<ResourceLocation<'static>>,
+
+    /// The texture of a tamed wolf.
+    ///
+    /// Replaced by [`assets`][Self::assets] in 1.21.5.
+    pub tame_texture: Option<ResourceLocation<'static>>,
+
+    /// The texture of an angry wolf.
+    ///
+    /// Replaced by [`assets`][Self::assets] in 1.21.5.
+    pub angry_texture: Option<ResourceLocation<'static>>,
+
+    /// The biome(s) (or `#`-prefixed biome tag) in which this variant spawns.
+    ///
+    /// Replaced by [`spawn_conditions`][Self::spawn_conditions] in 1.21.5.
+    pub biomes: Option<IdList>,
+
+    /// The textures of the variant.
+    ///
+    /// Added in 1.21.5.
+    pub assets: Option<WolfAssets>,
+
+    /// The conditions under which this variant spawns, kept as raw JSON.
+    ///
+    /// Added in 1.21.5.
+    pub spawn_conditions: Option<serde_json::Value>,
+
+    /// The fields that this crate does not model (e.g., fields added by mods
+    /// or by newer versions), kept so that they survive a round trip.
+    ///
+    /// Requires the `preserve-unknown` feature.
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten, serialize_with = "crate::schemas::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl WolfVariant {
+    /// Returns the textures of the variant, from either the 1.21.5+
+    /// [`assets`][Self::assets] field or the older `*_texture` fields.
+    ///
+    /// Returns `None` if neither form is complete.
+    pub fn textures(&self) -> Option<WolfAssets> {
+        if let Some(ref assets) = self.assets {
+            return Some(assets.clone());
+        }
+
+        Some(WolfAssets {
+            wild: self.wild_texture.clone()?,
+            tame: self.tame_texture.clone()?,
+            angry: self.angry_texture.clone()?,
+        })
+    }
+}
+
+/// The textures of a [`WolfVariant`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct WolfAssets {
+    /// The texture of a wild wolf.
+    pub wild: ResourceLocation<'static>,
+
+    /// The texture of a tamed wolf.
+    pub tame: ResourceLocation<'static>,
+
+    /// The texture of an angry wolf.
+    pub angry: ResourceLocation<'static>,
+}
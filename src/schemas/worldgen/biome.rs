@@ -5,6 +5,14 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::{
+    api::ResourceLocation,
+    schemas::{
+        color::Rgb,
+        reference::{MaybeReference, MaybeReferenceList},
+        strict::{CheckRanges, StrictError, Violation},
+    },
+};
 
 /// A custom biome info stored in the
 /// `data/<namespace>/worldgen/biome/*.json`.
@@ -13,16 +21,39 @@ use serde::{Deserialize, Serialize};
 ///
 /// [wiki page]: <https://minecraft.fandom.com/wiki/Custom_biome>
 #[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "bevy", derive(bevy::asset::Asset, bevy::reflect::TypePath))]
 pub struct CustomeBiome {
-
-    /// Determines whether or not the biome has precipitation.
-    pub has_precipitation: bool,
-
-    /// Controls gameplay features like grass and foliage color, and a height adjusted temperature (which controls whether raining or snowing 
+    /// (1.19.4+) Determines whether or not the biome has precipitation.
+    ///
+    /// Use [`normalized_precipitation()`][Self::normalized_precipitation] to
+    /// read this in a way that also works for older biomes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_precipitation: Option<bool>,
+
+    /// (before 1.19.4) The type of precipitation in the biome.
+    ///
+    /// Replaced by [`has_precipitation`][Self::has_precipitation].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub precipitation: Option<Precipitation>,
+
+    /// (before 1.19) The category of the biome, e.g., `plains` or `ocean`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+
+    /// (before 1.18) The terrain height of the biome.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub depth: Option<f32>,
+
+    /// (before 1.18) The terrain height variation of the biome.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scale: Option<f32>,
+
+    /// Controls gameplay features like grass and foliage color, and a height adjusted temperature (which controls whether raining or snowing
     /// if precipitation is rain, and generation details of some features).
     pub temperature: f32,
 
-    /// Modifies temperature before calculating the height adjusted temperature. 
+    /// Modifies temperature before calculating the height adjusted temperature.
     /// If frozen, makes some places' temperature high enough to rain (0.2).
     #[serde(default)]
     pub temperature_modifier: TemperatureModifier,
@@ -30,168 +61,486 @@ pub struct CustomeBiome {
     /// Controls grass and foliage color.
     pub downfall: f32,
 
-    /// Ambient effects in this biome. 
+    /// Ambient effects in this biome.
     pub effects: Effects,
 
-    /// The carvers to use. 
+    /// The carvers to use.
     pub carvers: Carvers,
 
-    /// (Can be empty) A list of 11 elements. 
-    /// Each element can be a tag of placed feature, a list of placed feature IDs, or a list of placed feature objects. 
-    /// The features are applied to each chunk in order in each step. 
-    /// The same placed feature in the same step in two biomes cannot be in a different order. 
-    /// For each step, all feature IDs need to be ordered consistently across biomes. 
-    /// For example, in minecraft:plains in UNDERGROUND_ORES step, ore_dirt is before ore_gravel, 
-    /// so in other biomes' UNDERGROUND_ORES step, if there are ore_dirt and ore_gravel, 
+    /// (Can be empty) A list of 11 elements.
+    /// Each element can be a tag of placed feature, a list of placed feature IDs, or a list of placed feature objects.
+    /// The features are applied to each chunk in order in each step.
+    /// The same placed feature in the same step in two biomes cannot be in a different order.
+    /// For each step, all feature IDs need to be ordered consistently across biomes.
+    /// For example, in minecraft:plains in UNDERGROUND_ORES step, ore_dirt is before ore_gravel,
+    /// so in other biomes' UNDERGROUND_ORES step, if there are ore_dirt and ore_gravel,
     /// ore_gravel cannot be after ore_dirt. The generation steps are also used in [stucture features].
-    /// 
+    ///
     /// [structure features]: <https://minecraft.fandom.com/wiki/Custom_structure>
-    pub features: Vec<Vec<String>>, 
+    pub features: Vec<MaybeReferenceList<PlacedFeature>>,
 
-
-    /// (optional) Higher value results in more creatures spawned in world generation. 
+    /// (optional) Higher value results in more creatures spawned in world generation.
     /// Must be between 0.0 and 0.9999999 (both inclusive).
     #[serde(default)]
     pub creature_spawn_probability: Option<f32>,
 
     /// (Required, but can be empty. If this object doesn't contain a certain category, mobs in this category will not be spawned)
-    ///  Entity spawning settings. 
+    ///  Entity spawning settings.
     pub spawners: Spawners,
+
+    /// The fields that this crate does not model (e.g., fields added by mods
+    /// or by newer versions), kept so that they survive a round trip.
+    ///
+    /// Requires the `preserve-unknown` feature.
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten, serialize_with = "crate::schemas::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
+impl CustomeBiome {
+    /// Returns the type of precipitation in the biome, whichever version it is
+    /// from.
+    ///
+    /// Since 1.19.4, a biome only says whether it has precipitation, and the
+    /// game snows instead of raining where it is cold enough. This returns
+    /// [`Precipitation::Snow`] for such biomes when their base temperature is
+    /// below `0.15`, ignoring the height and noise adjustments.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::worldgen::biome::*;
+    /// let old = CustomeBiome {
+    ///     precipitation: Some(Precipitation::Snow),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(old.normalized_precipitation(), Precipitation::Snow);
+    ///
+    /// let new = CustomeBiome {
+    ///     has_precipitation: Some(true),
+    ///     temperature: 0.8,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(new.normalized_precipitation(), Precipitation::Rain);
+    /// assert!(new.precipitates());
+    /// ```
+    pub fn normalized_precipitation(&self) -> Precipitation {
+        if let Some(precipitation) = self.precipitation {
+            return precipitation;
+        }
+
+        match self.has_precipitation {
+            Some(true) if self.temperature < 0.15 => Precipitation::Snow,
+            Some(true) => Precipitation::Rain,
+            _ => Precipitation::None,
+        }
+    }
 
+    /// Returns whether the biome has precipitation, whichever version it is
+    /// from.
+    pub fn precipitates(&self) -> bool {
+        self.normalized_precipitation() != Precipitation::None
+    }
+}
 
-/// Modification methods applied to temperature before calculating the height adjusted temperature
-#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+/// The type of precipitation in a biome.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
-pub enum TemperatureModifier {
-    
-    /// No modification
+pub enum Precipitation {
+    /// No precipitation.
+    #[default]
     None,
 
-    ///  Makes some places' temperature high enough to rain (0.2).
-    Frozen
+    /// Rain.
+    Rain,
+
+    /// Snow.
+    Snow,
+}
+
+/// The steps in which the [`features`][CustomeBiome::features] of a biome
+/// are placed, in order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum GenerationStep {
+    RawGeneration,
+    Lakes,
+    LocalModifications,
+    UndergroundStructures,
+    SurfaceStructures,
+    Strongholds,
+    UndergroundOres,
+    UndergroundDecoration,
+    FluidSprings,
+    VegetalDecoration,
+    TopLayerModification,
+}
+
+impl GenerationStep {
+    /// All of the steps, in order.
+    pub const ALL: [Self; 11] = [
+        Self::RawGeneration,
+        Self::Lakes,
+        Self::LocalModifications,
+        Self::UndergroundStructures,
+        Self::SurfaceStructures,
+        Self::Strongholds,
+        Self::UndergroundOres,
+        Self::UndergroundDecoration,
+        Self::FluidSprings,
+        Self::VegetalDecoration,
+        Self::TopLayerModification,
+    ];
+
+    /// Returns the index of the step in the
+    /// [`features`][CustomeBiome::features] of a biome.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// A builder for [`CustomeBiome`]s, e.g., for tools that generate data packs.
+///
+/// The builder starts out with the climate and colors of plains, and checks
+/// the ranges of the values when the biome is built.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::{strict::StrictError, worldgen::biome::*, Rgb};
+/// let biome = BiomeBuilder::new()
+///     .temperature(0.25)
+///     .grass_color(Rgb(0x80b497))
+///     .feature(GenerationStep::VegetalDecoration, "minecraft:trees_taiga")
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(biome.features.len(), 10);
+/// assert_eq!(biome.features[9].as_slice()[0].reference(), Some("minecraft:trees_taiga"));
+///
+/// let error = BiomeBuilder::new()
+///     .creature_spawn_probability(1.5)
+///     .build()
+///     .unwrap_err();
+/// let StrictError::Violations(violations) = error else { panic!() };
+/// assert_eq!(
+///     violations[0].to_string(),
+///     "/creature_spawn_probability: 1.5 is not between 0 and 0.9999999"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct BiomeBuilder {
+    biome: CustomeBiome,
+}
+
+impl BiomeBuilder {
+    /// Returns a builder for a biome like plains, with no features, carvers,
+    /// or spawners.
+    pub fn new() -> Self {
+        let mut biome = CustomeBiome {
+            has_precipitation: Some(true),
+            temperature: 0.8,
+            downfall: 0.4,
+            ..Default::default()
+        };
+        biome.effects.fog_color = Rgb(0xc0d8ff);
+        biome.effects.sky_color = Rgb(0x78a7ff);
+        biome.effects.water_color = Rgb(0x3f76e4);
+        biome.effects.water_fog_color = Rgb(0x050533);
+
+        Self { biome }
+    }
+
+    /// Sets whether the biome has precipitation.
+    pub fn has_precipitation(mut self, has_precipitation: bool) -> Self {
+        self.biome.has_precipitation = Some(has_precipitation);
+        self
+    }
+
+    /// Sets the temperature.
+    pub fn temperature(mut self, temperature: f32) -> Self {
+        self.biome.temperature = temperature;
+        self
+    }
+
+    /// Sets how the temperature is modified.
+    pub fn temperature_modifier(mut self, modifier: TemperatureModifier) -> Self {
+        self.biome.temperature_modifier = modifier;
+        self
+    }
+
+    /// Sets the downfall.
+    pub fn downfall(mut self, downfall: f32) -> Self {
+        self.biome.downfall = downfall;
+        self
+    }
+
+    /// Sets all of the ambient effects at once.
+    pub fn effects(mut self, effects: Effects) -> Self {
+        self.biome.effects = effects;
+        self
+    }
+
+    /// Sets the color of fog.
+    pub fn fog_color(mut self, color: impl Into<Rgb>) -> Self {
+        self.biome.effects.fog_color = color.into();
+        self
+    }
+
+    /// Sets the color of the sky.
+    pub fn sky_color(mut self, color: impl Into<Rgb>) -> Self {
+        self.biome.effects.sky_color = color.into();
+        self
+    }
+
+    /// Sets the color of water.
+    pub fn water_color(mut self, color: impl Into<Rgb>) -> Self {
+        self.biome.effects.water_color = color.into();
+        self
+    }
+
+    /// Sets the color of fog under water.
+    pub fn water_fog_color(mut self, color: impl Into<Rgb>) -> Self {
+        self.biome.effects.water_fog_color = color.into();
+        self
+    }
+
+    /// Sets the color of grass, instead of taking it from the colormap.
+    pub fn grass_color(mut self, color: impl Into<Rgb>) -> Self {
+        self.biome.effects.grass_color = Some(color.into());
+        self
+    }
+
+    /// Sets the color of leaves and vines, instead of taking it from the
+    /// colormap.
+    pub fn foliage_color(mut self, color: impl Into<Rgb>) -> Self {
+        self.biome.effects.foliage_color = Some(color.into());
+        self
+    }
+
+    /// Sets how the color of grass is modified.
+    pub fn grass_color_modifier(mut self, modifier: EffectsGrassColorModifier) -> Self {
+        self.biome.effects.grass_color_modifier = modifier;
+        self
+    }
+
+    /// Sets the probability of spawning creatures during world generation.
+    ///
+    /// Must be between 0.0 and 0.9999999 (both inclusive).
+    pub fn creature_spawn_probability(mut self, probability: f32) -> Self {
+        self.biome.creature_spawn_probability = Some(probability);
+        self
+    }
+
+    /// Adds a configured carver (or carver tag) to the `air` carving step.
+    ///
+    /// [`build()`][Self::build] reports the id if it is not a valid
+    /// [`ResourceLocation`].
+    pub fn air_carver(mut self, id: impl Into<String>) -> Self {
+        push_reference(&mut self.biome.carvers.air, id.into());
+        self
+    }
+
+    /// Adds a configured carver (or carver tag) to the `liquid` carving step.
+    ///
+    /// [`build()`][Self::build] reports the id if it is not a valid
+    /// [`ResourceLocation`].
+    pub fn liquid_carver(mut self, id: impl Into<String>) -> Self {
+        push_reference(&mut self.biome.carvers.liquid, id.into());
+        self
+    }
+
+    /// Adds a placed feature (or placed feature tag) to the given step.
+    ///
+    /// The features of each step are placed in the order they were added.
+    ///
+    /// [`build()`][Self::build] reports the id if it is not a valid
+    /// [`ResourceLocation`].
+    pub fn feature(mut self, step: GenerationStep, id: impl Into<String>) -> Self {
+        let features = &mut self.biome.features;
+        if features.len() <= step.index() {
+            features.resize_with(step.index() + 1, Default::default);
+        }
+        push_reference(&mut features[step.index()], id.into());
+        self
+    }
+
+    /// Returns the biome, or [`StrictError::Violations`] listing the values
+    /// that are out of range and the invalid ids.
+    pub fn build(self) -> Result<CustomeBiome, StrictError> {
+        let mut violations = Vec::new();
+        self.biome.check_ranges(&mut violations);
+
+        let carvers = &self.biome.carvers;
+        check_references("/carvers/air", &carvers.air, &mut violations);
+        check_references("/carvers/liquid", &carvers.liquid, &mut violations);
+        for (step, features) in self.biome.features.iter().enumerate() {
+            check_references(&format!("/features/{}", step), features, &mut violations);
+        }
+
+        if violations.is_empty() {
+            Ok(self.biome)
+        } else {
+            Err(StrictError::Violations(violations))
+        }
+    }
 }
 
-impl Default for TemperatureModifier {
-    
+impl Default for BiomeBuilder {
     fn default() -> Self {
-        TemperatureModifier::None
+        Self::new()
     }
 }
 
+fn push_reference<T>(list: &mut MaybeReferenceList<T>, id: String) {
+    let mut values = match std::mem::take(list) {
+        MaybeReferenceList::Multiple(values) => values,
+        MaybeReferenceList::Single(value) => vec![value],
+    };
+    values.push(MaybeReference::Reference(ResourceLocation::new_unchecked(
+        id,
+    )));
+    *list = MaybeReferenceList::Multiple(values);
+}
+
+fn check_references<T>(
+    json_path: &str,
+    list: &MaybeReferenceList<T>,
+    violations: &mut Vec<Violation>,
+) {
+    for (i, value) in list.iter().enumerate() {
+        if let MaybeReference::Reference(id) = value {
+            if let Err(error) = id.validate() {
+                violations.push(Violation::InvalidIdentifier {
+                    json_path: format!("{}/{}", json_path, i),
+                    error,
+                });
+            }
+        }
+    }
+}
+
+/// Modification methods applied to temperature before calculating the height adjusted temperature
+#[derive(Deserialize, Serialize, Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum TemperatureModifier {
+    /// No modification
+    #[default]
+    None,
 
-/// Ambient effects of a biome. 
+    ///  Makes some places' temperature high enough to rain (0.2).
+    Frozen,
+}
+
+/// Ambient effects of a biome.
 #[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Effects {
-
     /// Decimal value converted from Hex color to use for fog.
-    pub fog_color: u32,
+    pub fog_color: Rgb,
 
     /// Decimal value converted from Hex color to use for the sky.
-    pub sky_color: u32,
+    pub sky_color: Rgb,
 
     /// Decimal value converted from Hex color to use for water blocks and cauldrons.
-    pub water_color: u32,
+    pub water_color: Rgb,
 
     /// Decimal value converted from Hex color to use for fog.
-    pub water_fog_color: u32,
+    pub water_fog_color: Rgb,
 
-    /// (optional) Decimal value converted from Hex color to use for tree leaves and vines. 
+    /// (optional) Decimal value converted from Hex color to use for tree leaves and vines.
     /// If not present, the value depends on downfall and the temperature.
     #[serde(default)]
-    pub foliage_color: Option<u32>,
+    pub foliage_color: Option<Rgb>,
 
-    /// (optional) Decimal value converted from Hex color to use for grass blocks, grass, tall grass, ferns, tall ferns, and sugar cane. 
+    /// (optional) Decimal value converted from Hex color to use for grass blocks, grass, tall grass, ferns, tall ferns, and sugar cane.
     /// If not present, the value depends on downfall and temperature.
     #[serde(default)]
-    pub grass_color: Option<u32>,
+    pub grass_color: Option<Rgb>,
 
     /// (optional, defaults to none) Can be none, dark_forest or swamp.
     #[serde(default)]
     pub grass_color_modifier: EffectsGrassColorModifier,
 
-    /// (optional) The particle to use throughout this biome. 
+    /// (optional) The particle to use throughout this biome.
     #[serde(default)]
     pub particle: Option<EffectsParticle>,
 
     /// (optional) The namespace ID of the sound event to use for ambient sound.
     #[serde(default)]
-    pub ambient_sound: Option<String>,
+    pub ambient_sound: Option<ResourceLocation<'static>>,
 
-    /// (optional) Settings for mood sound. 
+    /// (optional) Settings for mood sound.
     #[serde(default)]
     pub mood_sound: Option<EffectsMoodSound>,
 
-    /// (optional) Settings for additions sound. 
+    /// (optional) Settings for additions sound.
     #[serde(default)]
     pub additions_sound: Option<EffectsAdditionsSound>,
 
-    /// (optional) Specific music that should be played in the biome. 
+    /// (optional) Specific music that should be played in the biome.
     #[serde(default)]
     pub music: Option<EffectsMusic>,
 
-    /// (Required, but can be empty. If this object doesn't contain a certain category, mobs in this category will not be spawned) 
-    /// Entity spawning settings. 
+    /// (Required, but can be empty. If this object doesn't contain a certain category, mobs in this category will not be spawned)
+    /// Entity spawning settings.
     #[serde(default)]
     pub spawners: Spawners,
 
-    ///  (Required, but can be empty. Only mobs listed here use the spawn cost mechanism) 
-    /// See [Spawn#Spawn] costs for details. 
-    /// 
+    ///  (Required, but can be empty. Only mobs listed here use the spawn cost mechanism)
+    /// See [Spawn#Spawn] costs for details.
+    ///
     /// [Spawn#Spawn]: <https://minecraft.fandom.com/wiki/Spawn#Spawn_costs>
     #[serde(default)]
     pub spawn_costs: SpawnCosts,
-}
-
 
+    /// The fields that this crate does not model (e.g., fields added by mods
+    /// or by newer versions), kept so that they survive a round trip.
+    ///
+    /// Requires the `preserve-unknown` feature.
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten, serialize_with = "crate::schemas::strict::serialize_extra")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
 
 /// Modification methods applied to grass color.
-#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+#[derive(Deserialize, Serialize, Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum EffectsGrassColorModifier {
-
     /// No modification
+    #[default]
     None,
 
     /// as dark_forest
     DarkForest,
 
     /// as swamp
-    Swamp
+    Swamp,
 }
 
-impl Default for EffectsGrassColorModifier {
-    
-    fn default() -> Self {
-        EffectsGrassColorModifier::None
-    }
-}
-
-
-
 /// The settings for particle to use throughout this biome.
-/// 
+///
 ///  *unimplemented*
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
-pub struct EffectsParticle {
-
-}
-
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct EffectsParticle {}
 
 /// The settings for mood sound used in effects of biome.
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct EffectsMoodSound {
-
     /// The namespace ID of the sound event to use.
-    pub sound: String,
+    pub sound: ResourceLocation<'static>,
 
     /// The mininum delay between two plays. See also [Ambience#Mood_algorithm].
-    /// 
+    ///
     /// [Ambience#Mood_algorithm]: <https://minecraft.fandom.com/wiki/Ambience#Mood_algorithm>
     pub tick_delay: u32,
 
-    /// Determines the cubic range of possible positions to find place to play the mood sound. 
+    /// Determines the cubic range of possible positions to find place to play the mood sound.
     /// The player is at the center of the cubic range, and the edge length is `2 * block_search_extent`.
     pub block_search_extent: u32,
 
@@ -199,62 +548,132 @@ pub struct EffectsMoodSound {
     pub offset: f64,
 }
 
-
 /// The settings for additions sound  used in effects of biome.
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct EffectsAdditionsSound {
-
     /// The namespace ID of the sound event to use.
-    pub sound: String,
+    pub sound: ResourceLocation<'static>,
 
-    /// The propability to start playing the sound per tick. 
+    /// The propability to start playing the sound per tick.
     /// Value higher than 1 is regarded as 1, lower than 0 is regarded as 0.
     pub tick_chance: f64,
 }
 
-
-/// The settings for music that should be played in the biome. 
+/// The settings for music that should be played in the biome.
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct EffectsMusic {
-
     /// The namespace ID of the sound event to use.
-    pub sound: String,
+    pub sound: ResourceLocation<'static>,
 
-    /// The minimum delay between two plays. 
+    /// The minimum delay between two plays.
     pub min_delay: u32,
 
-    /// The maximum delay between two plays. 
+    /// The maximum delay between two plays.
     pub max_delay: u32,
 
     /// Whether or not to replace music which is already playing.
     pub replace_current_music: bool,
 }
 
-
-
 /// The settings for carvers to use in this biome.
-/// 
-///  *unimplemented*
 #[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Carvers {
+    /// (optional) Carvers used in the `air` carving step.
+    /// Can be a carver tag, a configured carver ID, or a list of configured carver IDs or objects.
+    #[serde(default)]
+    pub air: MaybeReferenceList<ConfiguredCarver>,
 
+    /// (optional) Carvers used in the `liquid` carving step.
+    /// Can be a carver tag, a configured carver ID, or a list of configured carver IDs or objects.
+    #[serde(default)]
+    pub liquid: MaybeReferenceList<ConfiguredCarver>,
 }
 
+/// An inline configured carver object.
+///
+///  *unimplemented; kept as raw JSON*
+pub type ConfiguredCarver = serde_json::Value;
+
+/// An inline placed feature object.
+///
+///  *unimplemented; kept as raw JSON*
+pub type PlacedFeature = serde_json::Value;
 
 /// The settings for spawning entities in this biome.
-/// 
+///
 ///  *unimplemented; should be Hashmap*
 #[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
-pub struct Spawners {
-
-}
-
-
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Spawners {}
 
 /// The settings for spawning cost in this biome.
-/// 
+///
 ///  *unimplemented; should be Hashmap*
 #[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
-pub struct SpawnCosts {
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SpawnCosts {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EFFECTS: &str = r#"{
+        "fog_color": 12638463,
+        "sky_color": 7907327,
+        "water_color": 4159204,
+        "water_fog_color": 329011
+    }"#;
+
+    #[test]
+    fn parses_all_versions() {
+        let legacy = format!(
+            r#"{{
+                "precipitation": "snow",
+                "category": "icy",
+                "depth": 0.125,
+                "scale": 0.05,
+                "temperature": 0.0,
+                "downfall": 0.5,
+                "effects": {EFFECTS},
+                "carvers": {{}},
+                "features": [],
+                "spawners": {{}}
+            }}"#
+        );
+        let biome: CustomeBiome = serde_json::from_str(&legacy).unwrap();
+        assert_eq!(biome.category.as_deref(), Some("icy"));
+        assert_eq!(biome.depth, Some(0.125));
+        assert_eq!(biome.normalized_precipitation(), Precipitation::Snow);
+
+        let modern = format!(
+            r#"{{
+                "has_precipitation": false,
+                "temperature": 2.0,
+                "downfall": 0.0,
+                "effects": {EFFECTS},
+                "carvers": {{}},
+                "features": [],
+                "spawners": {{}}
+            }}"#
+        );
+        let biome: CustomeBiome = serde_json::from_str(&modern).unwrap();
+        assert_eq!(biome.normalized_precipitation(), Precipitation::None);
+        assert!(!serde_json::to_string(&biome)
+            .unwrap()
+            .contains("\"precipitation\""));
+    }
 
-}
\ No newline at end of file
+    #[test]
+    fn builder_checks_feature_steps() {
+        let mut builder = BiomeBuilder::new();
+        builder.biome.features = vec![Default::default(); 12];
+
+        let Err(StrictError::Violations(violations)) = builder.build() else {
+            panic!("expected violations");
+        };
+        assert_eq!(violations[0].json_path(), "/features");
+    }
+}
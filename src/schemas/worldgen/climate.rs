@@ -0,0 +1,115 @@
+//! Serde-(de)serializable data types for the climate parameters used by the
+//! `minecraft:multi_noise` biome source.
+//!
+//! These types are shared between
+//! `data/<namespace>/worldgen/multi_noise_biome_source_parameter_list/*.json`
+//! and the biome source of a dimension.
+//!
+//! See <https://minecraft.fandom.com/wiki/Biome#Biome_parameters>.
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::ResourceLocation;
+
+/// The value of a single climate parameter.
+///
+/// A parameter can either be a single value or a `[min, max]` range.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::worldgen::climate::*;
+/// let point: Parameter = serde_json::from_str("0.5").unwrap();
+/// assert_eq!(point, Parameter::Point(0.5));
+///
+/// let range: Parameter = serde_json::from_str("[-1.0, 0.25]").unwrap();
+/// assert_eq!(range, Parameter::Range([-1.0, 0.25]));
+///
+/// assert_eq!(point.min(), 0.5);
+/// assert_eq!(range.max(), 0.25);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum Parameter {
+    /// A single value.
+    Point(f32),
+
+    /// An inclusive `[min, max]` range of values.
+    Range([f32; 2]),
+}
+
+impl Parameter {
+    /// Returns the lower bound of the parameter.
+    pub fn min(&self) -> f32 {
+        match self {
+            Self::Point(value) => *value,
+            Self::Range([min, _]) => *min,
+        }
+    }
+
+    /// Returns the upper bound of the parameter.
+    pub fn max(&self) -> f32 {
+        match self {
+            Self::Point(value) => *value,
+            Self::Range([_, max]) => *max,
+        }
+    }
+
+    /// Returns `true` if the given value lies within the parameter's bounds.
+    pub fn contains(&self, value: f32) -> bool {
+        self.min() <= value && value <= self.max()
+    }
+}
+
+impl Default for Parameter {
+    fn default() -> Self {
+        Self::Point(0.0)
+    }
+}
+
+/// A point (or region) in the climate parameter space at which a biome is
+/// placed.
+///
+/// All parameters except `offset` take values between -2.0 and 2.0.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ParameterPoint {
+    /// The temperature of the biome.
+    pub temperature: Parameter,
+
+    /// The humidity of the biome.
+    pub humidity: Parameter,
+
+    /// How far inland the biome is. Low values are oceans, high values are
+    /// inland.
+    pub continentalness: Parameter,
+
+    /// How flat the terrain of the biome is. Low values are mountainous, high
+    /// values are flat.
+    pub erosion: Parameter,
+
+    /// How weird the terrain of the biome is. Also used to determine peaks
+    /// and valleys.
+    pub weirdness: Parameter,
+
+    /// The depth below the surface at which the biome is placed. `0.0` is the
+    /// surface and `1.0` is 128 blocks below the surface.
+    pub depth: Parameter,
+
+    /// Similar to the other parameters, but is a single value between 0.0 and
+    /// 1.0. A higher value makes the biome less likely to be chosen.
+    pub offset: f32,
+}
+
+/// A biome and the point in the climate parameter space at which it is
+/// placed.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BiomeParameters {
+    /// The namespace ID of the biome.
+    pub biome: ResourceLocation<'static>,
+
+    /// The climate parameters of the biome.
+    pub parameters: ParameterPoint,
+}
@@ -1,9 +1,10 @@
 //! Serde-(de)serializable data types for
 //! `data/<namespace>/worldgen/` directory.
-//! 
+//!
 //! See <https://minecraft.fandom.com/wiki/Custom_world_generation>.
-//! 
-//! *currently only biome is implemented*
+//!
+//! *currently only biome and multi-noise parameter lists are implemented*
 
-
-pub mod biome;
\ No newline at end of file
+pub mod biome;
+pub mod climate;
+pub mod multi_noise_biome_source_parameter_list;
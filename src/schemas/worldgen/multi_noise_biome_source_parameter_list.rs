@@ -0,0 +1,36 @@
+//! Serde-(de)serializable data types for
+//! `data/<namespace>/worldgen/multi_noise_biome_source_parameter_list/*.json`.
+//!
+//! Start here: [`MultiNoiseBiomeSourceParameterList`].
+//!
+//! See <https://minecraft.fandom.com/wiki/Custom_dimension#Multi-noise_biome_source_parameter_list>.
+
+use serde::{Deserialize, Serialize};
+
+/// A named list of biome parameters stored in the
+/// `data/<namespace>/worldgen/multi_noise_biome_source_parameter_list/`
+/// directory.
+///
+/// Since 1.19.4, dimensions using the `minecraft:multi_noise` biome source can
+/// reference one of these files by ID instead of specifying a `preset` or an
+/// inline list of [`BiomeParameters`].
+///
+/// [`BiomeParameters`]: super::climate::BiomeParameters
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::worldgen::multi_noise_biome_source_parameter_list::*;
+/// let list: MultiNoiseBiomeSourceParameterList =
+///     serde_json::from_str(r#"{ "preset": "minecraft:overworld" }"#).unwrap();
+///
+/// assert_eq!(list.preset, "minecraft:overworld");
+/// ```
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct MultiNoiseBiomeSourceParameterList {
+    /// The hardcoded list of biome parameters to use.
+    ///
+    /// Can be `minecraft:overworld` or `minecraft:nether`.
+    pub preset: String,
+}
@@ -6,7 +6,9 @@
 //!
 //! **TODO**
 //!
-//! See the wiki page on [The Flattening].
+//! See the wiki page on [The Flattening]. Numeric block and item ids from
+//! before the flattening can be translated through the
+//! [`compat`][crate::compat] module.
 //!
 //! # Assets / Resource Packs Changelog
 //!
@@ -0,0 +1,17 @@
+#![cfg(feature = "tests-advancements")]
+
+use minecraft_assets::schemas::Advancement;
+
+mod common;
+
+fn parse_all_advancements_in_version(version: &str) {
+    common::parse_all_in_dir_recursive::<Advancement>(&format!(
+        "tests/assets-{}/data/minecraft/advancements",
+        version
+    ));
+}
+
+#[test]
+fn can_parse_all_advancements_1_18() {
+    parse_all_advancements_in_version("1.18");
+}
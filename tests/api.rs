@@ -1,11 +1,11 @@
 #![cfg(feature = "tests-api")]
 
 use assert_matches::assert_matches;
-use std::{io, path::PathBuf};
+use std::path::PathBuf;
 
 use minecraft_assets::api::{
-    AssetPack, EnumerateResources, FileSystemResourceProvider, ModelIdentifier, ResourceKind,
-    ResourceProvider,
+    AssetPack, EnumerateResources, FileSystemResourceProvider, ModelIdentifier, ResourceCategory,
+    ResourceKind, Translator, DEFAULT_LANGUAGE,
 };
 
 mod common;
@@ -53,17 +53,26 @@ fn load_block_states(assets: &AssetPack, flattening: Versions) {
     assert_eq!(variants.len(), 1);
 
     let model = &variants[&single_variant_name(flattening)].models()[0];
-    assert_eq!(ModelIdentifier::model_name(&model.model), "oak_planks");
+    assert_eq!(
+        ModelIdentifier::model_name(model.model.as_str()),
+        "oak_planks"
+    );
 }
 
 fn load_block_model(assets: &AssetPack) {
     // Try it with both a prefixed and non-prefixed path (both should work on
     // all versions).
     let model = assets.load_block_model("cube_all").unwrap();
-    assert_eq!(ModelIdentifier::model_name(&model.parent.unwrap()), "cube");
+    assert_eq!(
+        ModelIdentifier::model_name(model.parent.unwrap().as_str()),
+        "cube"
+    );
 
     let model = assets.load_block_model("block/cube_all").unwrap();
-    assert_eq!(ModelIdentifier::model_name(&model.parent.unwrap()), "cube");
+    assert_eq!(
+        ModelIdentifier::model_name(model.parent.unwrap().as_str()),
+        "cube"
+    );
 
     // Item model should fail.
     assert_matches!(assets.load_block_model("diamond_hoe"), Err(_));
@@ -80,13 +89,13 @@ fn load_item_model(assets: &AssetPack, version: &str) {
     // all versions).
     let model = assets.load_item_model("diamond_hoe").unwrap();
     assert_eq!(
-        ModelIdentifier::model_name(&model.parent.unwrap()),
+        ModelIdentifier::model_name(model.parent.unwrap().as_str()),
         expected_parent
     );
 
     let model = assets.load_item_model("item/diamond_hoe").unwrap();
     assert_eq!(
-        ModelIdentifier::model_name(&model.parent.unwrap()),
+        ModelIdentifier::model_name(model.parent.unwrap().as_str()),
         expected_parent
     );
 
@@ -262,3 +271,31 @@ fn for_each_texture_meta() {
         54,
     );
 }
+
+#[test]
+fn namespaces() {
+    let assets = get_asset_pack("1.18");
+
+    let assets_namespaces = assets.namespaces(ResourceCategory::Assets).unwrap();
+    let data_namespaces = assets.namespaces(ResourceCategory::Data).unwrap();
+    let all_namespaces = assets.all_namespaces().unwrap();
+
+    assert!(assets_namespaces.iter().any(|n| n == "minecraft"));
+    assert!(data_namespaces.iter().any(|n| n == "minecraft"));
+
+    for namespace in assets_namespaces.iter().chain(&data_namespaces) {
+        assert!(all_namespaces.contains(namespace));
+    }
+}
+
+#[test]
+fn translate_1_18() {
+    let assets = get_asset_pack("1.18");
+    let translator = Translator::load(&[&assets], DEFAULT_LANGUAGE).unwrap();
+
+    assert_eq!(translator.translate("block.minecraft.stone", &[]), "Stone");
+    assert_eq!(
+        translator.translate("death.attack.fall", &["Steve"]),
+        "Steve hit the ground too hard"
+    );
+}
@@ -33,10 +33,7 @@ fn do_single_variant_test(bytes: &[u8], version: Versions) {
 
     let expected = BlockStates::Variants {
         variants: hashmap! {
-            variant_name => Variant::Single(ModelProperties {
-                model: model_path("oak_planks", version),
-                ..Default::default()
-            })
+            variant_name => Variant::Single(ModelProperties::from(model_path("oak_planks", version)))
         },
     };
 
@@ -131,23 +128,15 @@ fn do_single_variant_multiple_models_test(bytes: &[u8], version: Versions) {
     let expected = BlockStates::Variants {
         variants: hashmap! {
             single_variant_name(version) => Variant::Multiple(vec![
+                ModelProperties::from(model_path("stone", version)),
+                ModelProperties::from(model_path("stone_mirrored", version)),
                 ModelProperties {
-                    model: model_path("stone", version),
-                    ..Default::default()
-                },
-                ModelProperties {
-                    model: model_path("stone_mirrored", version),
-                    ..Default::default()
-                },
-                ModelProperties {
-                    model: model_path("stone", version),
                     y: 180,
-                    ..Default::default()
+                    ..ModelProperties::from(model_path("stone", version))
                 },
                 ModelProperties {
-                    model: model_path("stone_mirrored", version),
                     y: 180,
-                    ..Default::default()
+                    ..ModelProperties::from(model_path("stone_mirrored", version))
                 }
             ])
         },
@@ -245,15 +234,9 @@ fn multiple_variants() {
 
     let expected = BlockStates::Variants {
         variants: hashmap! {
-            String::from("powered=false") => Variant::Single(ModelProperties {
-                model: String::from("block/stone_pressure_plate"),
-                ..Default::default()
-            }),
-
-            String::from("powered=true") => Variant::Single(ModelProperties {
-                model: String::from("block/stone_pressure_plate_down"),
-                ..Default::default()
-            })
+            String::from("powered=false") => Variant::Single(ModelProperties::new("block/stone_pressure_plate").unwrap()),
+
+            String::from("powered=true") => Variant::Single(ModelProperties::new("block/stone_pressure_plate_down").unwrap())
         },
     };
 
@@ -267,44 +250,40 @@ fn do_multipart_test(bytes: &[u8], version: Versions) {
         cases: vec![
             Case {
                 when: Some(WhenClause::Single(condition! { "up" => "true" })),
-                apply: Variant::Single(ModelProperties {
-                    model: model_path("cobblestone_wall_post", version),
-                    ..Default::default()
-                }),
+                apply: Variant::Single(ModelProperties::from(model_path(
+                    "cobblestone_wall_post",
+                    version,
+                ))),
             },
             Case {
                 when: Some(WhenClause::Single(condition! { "north" => "true" })),
                 apply: Variant::Single(ModelProperties {
-                    model: model_path("cobblestone_wall_side", version),
                     uv_lock: true,
-                    ..Default::default()
+                    ..ModelProperties::from(model_path("cobblestone_wall_side", version))
                 }),
             },
             Case {
                 when: Some(WhenClause::Single(condition! { "east" => "true" })),
                 apply: Variant::Single(ModelProperties {
-                    model: model_path("cobblestone_wall_side", version),
                     uv_lock: true,
                     y: 90,
-                    ..Default::default()
+                    ..ModelProperties::from(model_path("cobblestone_wall_side", version))
                 }),
             },
             Case {
                 when: Some(WhenClause::Single(condition! { "south" => "true" })),
                 apply: Variant::Single(ModelProperties {
-                    model: model_path("cobblestone_wall_side", version),
                     uv_lock: true,
                     y: 180,
-                    ..Default::default()
+                    ..ModelProperties::from(model_path("cobblestone_wall_side", version))
                 }),
             },
             Case {
                 when: Some(WhenClause::Single(condition! { "west" => "true" })),
                 apply: Variant::Single(ModelProperties {
-                    model: model_path("cobblestone_wall_side", version),
                     uv_lock: true,
                     y: 270,
-                    ..Default::default()
+                    ..ModelProperties::from(model_path("cobblestone_wall_side", version))
                 }),
             },
         ],
@@ -409,10 +388,7 @@ fn multipart_with_or() {
                 condition! {"west" => "side|up", "north" => "side|up"},
             ],
         }),
-        apply: Variant::Single(ModelProperties {
-            model: String::from("block/redstone_dust_dot"),
-            ..Default::default()
-        }),
+        apply: Variant::Single(ModelProperties::new("block/redstone_dust_dot").unwrap()),
     };
 
     assert_matches!(
@@ -434,10 +410,7 @@ fn multipart_with_boolean_values() {
 
     let expected_case = Case {
         when: Some(WhenClause::Single(condition! { "up" => true })),
-        apply: Variant::Single(ModelProperties {
-            model: String::from("block/mossy_cobblestone_wall_post"),
-            ..Default::default()
-        }),
+        apply: Variant::Single(ModelProperties::new("block/mossy_cobblestone_wall_post").unwrap()),
     };
 
     assert_matches!(
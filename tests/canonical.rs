@@ -0,0 +1,93 @@
+#![cfg(feature = "tests-canonical")]
+
+use std::{fmt::Debug, fs, path::Path};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use minecraft_assets::schemas::{canonical, BlockStates, Model};
+
+mod common;
+
+/// Asserts that every file in the given directory survives a round trip
+/// through the canonical format, and that the canonical output is identical to
+/// the files that the game's data generator wrote.
+fn assert_round_trips<T>(path: &str)
+where
+    T: DeserializeOwned + Serialize + PartialEq + Debug,
+{
+    let dir_path = common::get_path_relative_to_manifest_dir(path).unwrap();
+    for entry in fs::read_dir(dir_path).unwrap() {
+        let path = entry.unwrap().path();
+        if path.file_name().unwrap().to_string_lossy().starts_with('_') {
+            continue;
+        }
+
+        assert_round_trip::<T>(&path);
+    }
+}
+
+fn assert_round_trip<T>(path: &Path)
+where
+    T: DeserializeOwned + Serialize + PartialEq + Debug,
+{
+    println!("Round-tripping {}", path.to_string_lossy());
+
+    let original = fs::read_to_string(path).unwrap();
+    let value: T = serde_json::from_str(&original).unwrap();
+    let canonical = canonical::to_string(&value).unwrap();
+
+    let reparsed: T = serde_json::from_str(&canonical).unwrap();
+    assert_eq!(reparsed, value);
+    assert_eq!(canonical::to_string(&reparsed).unwrap(), canonical);
+
+    if is_data_generator_output(&original) {
+        assert_eq!(canonical, original);
+    }
+}
+
+/// Returns whether the given file was written by the game's data generator,
+/// rather than by hand (like all the files before 1.15 and the templates of
+/// later versions, which are indented with tabs or four spaces).
+fn is_data_generator_output(data: &str) -> bool {
+    data.starts_with("{\n  \"") && !data.contains(['\t', '\r']) && !data.ends_with('\n')
+}
+
+fn do_canonical_test(version: &str) {
+    assert_round_trips::<BlockStates>(&format!(
+        "tests/assets-{}/assets/minecraft/blockstates",
+        version
+    ));
+    assert_round_trips::<Model>(&format!(
+        "tests/assets-{}/assets/minecraft/models/block",
+        version
+    ));
+    assert_round_trips::<Model>(&format!(
+        "tests/assets-{}/assets/minecraft/models/item",
+        version
+    ));
+}
+
+#[test]
+fn canonical_1_8() {
+    do_canonical_test("1.8");
+}
+
+#[test]
+fn canonical_1_12() {
+    do_canonical_test("1.12");
+}
+
+#[test]
+fn canonical_1_14() {
+    do_canonical_test("1.14");
+}
+
+#[test]
+fn canonical_1_16_2() {
+    do_canonical_test("1.16.2");
+}
+
+#[test]
+fn canonical_1_18() {
+    do_canonical_test("1.18");
+}
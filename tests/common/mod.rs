@@ -1,3 +1,5 @@
+#![allow(dead_code)]
+
 use std::{
     env, fs,
     path::{Path, PathBuf},
@@ -5,6 +7,8 @@ use std::{
 
 use serde::Deserialize;
 
+use minecraft_assets::api::ResourceLocation;
+
 pub fn get_path_relative_to_manifest_dir(
     relative_path: impl AsRef<Path>,
 ) -> Result<PathBuf, env::VarError> {
@@ -35,6 +39,34 @@ pub fn parse_all_in_dir<T: for<'de> Deserialize<'de>>(path: &str) {
     }
 }
 
+pub fn parse_all_in_dir_recursive<T: for<'de> Deserialize<'de>>(path: &str) {
+    let dir_path = get_path_relative_to_manifest_dir(path).unwrap();
+
+    let mut dirs = vec![dir_path];
+
+    while let Some(dir_path) = dirs.pop() {
+        for entry in fs::read_dir(dir_path).unwrap() {
+            let entry = entry.unwrap();
+
+            let path = entry.path();
+
+            if path.file_name().unwrap().to_string_lossy().starts_with('_') {
+                continue;
+            }
+
+            if entry.file_type().unwrap().is_dir() {
+                dirs.push(path);
+                continue;
+            }
+
+            println!("Parsing {}", path.to_string_lossy());
+
+            let file = fs::File::open(path).unwrap();
+            serde_json::from_reader::<_, T>(file).unwrap();
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[allow(non_camel_case_types)]
 pub enum Versions {
@@ -53,10 +85,11 @@ pub fn single_variant_name(version: Versions) -> String {
 }
 
 // In versions >= 1.13, model paths are prefixed with "block/".
-pub fn model_path(model: &str, version: Versions) -> String {
-    match version {
+pub fn model_path(model: &str, version: Versions) -> ResourceLocation<'static> {
+    let path = match version {
         Versions::PreFlattening => String::from(model),
         Versions::PostFlattening => format!("block/{}", model),
         Versions::Post_1_16_2 => format!("minecraft:block/{}", model),
-    }
+    };
+    ResourceLocation::new_owned(path).unwrap()
 }
@@ -0,0 +1,17 @@
+#![cfg(feature = "tests-fonts")]
+
+use minecraft_assets::schemas::Font;
+
+mod common;
+
+fn parse_all_fonts_in_version(version: &str) {
+    common::parse_all_in_dir::<Font>(&format!(
+        "tests/assets-{}/assets/minecraft/font",
+        version
+    ));
+}
+
+#[test]
+fn can_parse_all_fonts_1_18() {
+    parse_all_fonts_in_version("1.18");
+}
@@ -0,0 +1,17 @@
+#![cfg(feature = "tests-loot-tables")]
+
+use minecraft_assets::schemas::LootTable;
+
+mod common;
+
+fn parse_all_loot_tables_in_version(version: &str) {
+    common::parse_all_in_dir_recursive::<LootTable>(&format!(
+        "tests/assets-{}/data/minecraft/loot_tables",
+        version
+    ));
+}
+
+#[test]
+fn can_parse_all_loot_tables_1_18() {
+    parse_all_loot_tables_in_version("1.18");
+}
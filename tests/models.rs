@@ -10,7 +10,7 @@ fn do_cube_all_test(bytes: &[u8]) {
     let actual: Model = serde_json::from_slice(bytes).unwrap();
 
     let expected = Model {
-        parent: Some(String::from("block/cube")),
+        parent: Some("block/cube".parse().unwrap()),
         textures: Some(Textures {
             variables: hashmap! {
                 String::from("particle") => "#all".into(),
@@ -0,0 +1,17 @@
+#![cfg(feature = "tests-particles")]
+
+use minecraft_assets::schemas::Particle;
+
+mod common;
+
+fn parse_all_particles_in_version(version: &str) {
+    common::parse_all_in_dir::<Particle>(&format!(
+        "tests/assets-{}/assets/minecraft/particles",
+        version
+    ));
+}
+
+#[test]
+fn can_parse_all_particles_1_18() {
+    parse_all_particles_in_version("1.18");
+}
@@ -0,0 +1,17 @@
+#![cfg(feature = "tests-recipes")]
+
+use minecraft_assets::schemas::Recipe;
+
+mod common;
+
+fn parse_all_recipes_in_version(version: &str) {
+    common::parse_all_in_dir::<Recipe>(&format!(
+        "tests/assets-{}/data/minecraft/recipes",
+        version
+    ));
+}
+
+#[test]
+fn can_parse_all_recipes_1_18() {
+    parse_all_recipes_in_version("1.18");
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "tests-sounds")]
+
+use minecraft_assets::api::{AssetPack, ResourceKind};
+
+mod common;
+
+fn load_sounds_in_version(version: &str) {
+    let root =
+        common::get_path_relative_to_manifest_dir(format!("tests/assets-{}", version)).unwrap();
+    let assets = AssetPack::at_path(root);
+
+    let ids = assets
+        .enumerate_resources("minecraft", ResourceKind::Sounds)
+        .unwrap();
+    assert_eq!(ids.len(), 1);
+
+    let sounds = assets.load_sounds("minecraft").unwrap();
+    assert!(!sounds.events["block.stone.break"].sounds.is_empty());
+}
+
+#[test]
+fn can_load_sounds_1_14() {
+    load_sounds_in_version("1.14");
+}
+
+#[test]
+fn can_load_sounds_1_18() {
+    load_sounds_in_version("1.18");
+}
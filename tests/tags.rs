@@ -0,0 +1,17 @@
+#![cfg(feature = "tests-tags")]
+
+use minecraft_assets::schemas::Tag;
+
+mod common;
+
+fn parse_all_tags_in_version(version: &str) {
+    common::parse_all_in_dir_recursive::<Tag>(&format!(
+        "tests/assets-{}/data/minecraft/tags",
+        version
+    ));
+}
+
+#[test]
+fn can_parse_all_tags_1_18() {
+    parse_all_tags_in_version("1.18");
+}
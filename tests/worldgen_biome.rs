@@ -1,7 +1,5 @@
 #![cfg(feature = "tests-worldgen-biome")]
 
-use maplit::hashmap;
-
 use minecraft_assets::schemas::worldgen::biome::CustomeBiome;
 
 mod common;